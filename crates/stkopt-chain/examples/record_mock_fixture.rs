@@ -0,0 +1,107 @@
+//! Record a mock RPC fixture for the offline end-to-end test.
+//!
+//! Proxies the validator, pool, era, account and transaction-building flow
+//! through `MockRpcServer::start_recording` and saves every response. This
+//! covers what `tests/mock_rpc_flow.rs` replays.
+//!
+//! Run with:
+//!   cargo run -p stkopt-chain --example record_mock_fixture -- \
+//!     westend <ADDRESS> crates/stkopt-chain/tests/fixtures/westend_asset_hub.json
+
+use std::str::FromStr;
+use stkopt_chain::{ChainClient, MockRpcServer, get_asset_hub_endpoints};
+use stkopt_core::{ConnectionStatus, Network};
+use subxt::utils::AccountId32;
+use tokio::sync::mpsc;
+
+#[tokio::main]
+async fn main() {
+    tracing_subscriber::fmt().with_env_filter("info").init();
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let [network, address, output] = args.as_slice() else {
+        eprintln!("usage: record_mock_fixture <NETWORK> <ADDRESS> <OUTPUT>");
+        std::process::exit(2);
+    };
+    let network = match network.to_ascii_lowercase().as_str() {
+        "polkadot" => Network::Polkadot,
+        "kusama" => Network::Kusama,
+        "westend" => Network::Westend,
+        "paseo" => Network::Paseo,
+        other => {
+            eprintln!("unknown network: {other}");
+            std::process::exit(2);
+        }
+    };
+    let account = AccountId32::from_str(address).expect("valid SS58 address");
+    let upstream = get_asset_hub_endpoints(&network)[0];
+
+    println!("Recording {} via {}...", network, upstream);
+    let server = MockRpcServer::start_recording(upstream)
+        .await
+        .expect("Failed to reach upstream endpoint");
+
+    let (status_tx, mut status_rx) = mpsc::channel::<ConnectionStatus>(10);
+    tokio::spawn(async move { while status_rx.recv().await.is_some() {} });
+
+    let client = ChainClient::connect_rpc(network.clone(), &server.rpc_endpoints(), status_tx)
+        .await
+        .expect("Failed to connect through recorder");
+
+    let validators = client.get_validators().await.expect("validators");
+    client
+        .get_validators_light_client()
+        .await
+        .expect("multi-source validators");
+    let pools = client.get_nomination_pools().await.expect("pools");
+    let era = client
+        .get_active_era()
+        .await
+        .expect("active era")
+        .map_or(0, |era| era.index);
+    client.get_latest_block().await.expect("latest block");
+    client
+        .get_era_stakers_overview(era.saturating_sub(1))
+        .await
+        .expect("era stakers");
+    client
+        .get_era_total_stake_direct(era)
+        .await
+        .expect("era total stake");
+    let sample: Vec<AccountId32> = validators.iter().take(16).map(|v| v.address).collect();
+    client
+        .get_validator_preferences_batch(&sample)
+        .await
+        .expect("validator preferences");
+    client.get_account_balance(&account).await.expect("balance");
+    client.get_staking_ledger(&account).await.expect("ledger");
+    client.get_nominations(&account).await.expect("nominations");
+    client
+        .get_pool_membership(&account)
+        .await
+        .expect("pool membership");
+    let targets: Vec<AccountId32> = validators.iter().take(2).map(|v| v.address).collect();
+    client
+        .create_nominate_payload(&account, &targets, true)
+        .await
+        .expect("nominate payload");
+
+    let mut recording = server.recording();
+    recording.meta.insert(
+        "network".to_string(),
+        network.to_string().to_ascii_lowercase(),
+    );
+    recording
+        .meta
+        .insert("account".to_string(), address.clone());
+    recording.save(output).expect("Failed to save recording");
+
+    println!(
+        "Saved {} validators, {} pools, {} storage entries and {} calls to {}",
+        validators.len(),
+        pools.len(),
+        recording.storage.len(),
+        recording.calls.len(),
+        output
+    );
+}
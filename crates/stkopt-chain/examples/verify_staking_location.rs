@@ -46,7 +46,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 async fn test_rpc() -> Result<(), Box<dyn std::error::Error>> {
     // Test Kusama Relay Chain
     println!("--- Kusama Relay Chain (RPC) ---");
-    let relay_client = connect_first(get_rpc_endpoints(Network::Kusama)).await?;
+    let relay_client = connect_first(&get_rpc_endpoints(&Network::Kusama)).await?;
     println!("Connected!");
     let relay_block = relay_client.at_current_block().await?;
     println!("Latest block: {:?}", relay_block.block_number());
@@ -67,7 +67,7 @@ async fn test_rpc() -> Result<(), Box<dyn std::error::Error>> {
 
     // Test Kusama Asset Hub
     println!("\n--- Kusama Asset Hub (RPC) ---");
-    let asset_hub_client = connect_first(get_asset_hub_endpoints(Network::Kusama)).await?;
+    let asset_hub_client = connect_first(&get_asset_hub_endpoints(&Network::Kusama)).await?;
     println!("Connected!");
 
     let asset_hub_has_staking = check_staking_pallet(&asset_hub_client).await?;
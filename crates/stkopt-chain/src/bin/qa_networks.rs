@@ -100,7 +100,7 @@ async fn main() {
     for network in args.networks {
         for mode in &args.modes {
             println!("\n== {network} / {mode} ==");
-            match run_case(network, *mode).await {
+            match run_case(network.clone(), *mode).await {
                 Ok(report) => println!("PASS {report}"),
                 Err(error) => {
                     println!("FAIL {network} / {mode}: {error}");
@@ -160,7 +160,7 @@ async fn run_case(network: Network, mode: ConnectionMode) -> QaResult<CaseReport
     let client = with_timeout(
         CONNECT_TIMEOUT,
         format!("connect {network} / {mode}"),
-        ChainClient::connect(network, &config, status_tx),
+        ChainClient::connect(network.clone(), &config, status_tx),
     )
     .await?;
 
@@ -401,7 +401,7 @@ fn parse_args(args: impl IntoIterator<Item = String>) -> QaResult<Args> {
 
     let networks = selected_network
         .map(|network| vec![network])
-        .unwrap_or_else(|| Network::all().to_vec());
+        .unwrap_or_else(|| Network::builtin().to_vec());
     let modes = match selected_mode {
         ModeSelection::Both => vec![ConnectionMode::Rpc, ConnectionMode::LightClient],
         ModeSelection::Rpc => vec![ConnectionMode::Rpc],
@@ -444,7 +444,7 @@ mod tests {
     fn parses_defaults_to_all_networks_and_modes() {
         let args = parse_args(Vec::<String>::new()).unwrap();
 
-        assert_eq!(args.networks, Network::all());
+        assert_eq!(args.networks, Network::builtin());
         assert_eq!(
             args.modes,
             vec![ConnectionMode::Rpc, ConnectionMode::LightClient]
@@ -10,6 +10,10 @@
 //! - **Rpc**: Traditional WebSocket RPC connection. Used only when explicitly
 //!   requested (e.g. `--rpc`). Required for historical data queries.
 //!
+//! - **Hybrid**: Data over RPC, with every storage read proven against state
+//!   roots finalized by the light client (see [`crate::hybrid`]). Historical
+//!   queries without trusting the RPC provider.
+//!
 //! # Architecture (Polkadot 2.0, Nov 2025+)
 //!
 //! - Asset Hub: All staking data (validators, pools, nominations) and staking
@@ -18,11 +22,20 @@
 //! - People Chain: Identity data
 
 use crate::PeopleChainClient;
-use crate::config::get_asset_hub_endpoints;
+use crate::endpoints::{EndpointManager, SharedEndpoints, connect_best, lock};
 use crate::error::ChainError;
+use crate::events::{RawEvent, TxOutcome, module_error_info, outcome_from_events};
 use crate::lightclient::LightClientConnections;
+use crate::lightclient_peers::LightClientStore;
+use crate::mock_rpc::MockRpcServer;
+use crate::session::{SessionMode, SessionServers};
+use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::Arc;
-use stkopt_core::{ConnectionStatus, Network};
+use stkopt_core::{
+    ChainRole, ConnectionStatus, DEFAULT_MORTALITY_PERIOD, EndpointHealth, Network,
+    normalize_mortality_period,
+};
 
 use subxt::backend::CombinedBackend;
 use subxt::tx::TransactionStatus;
@@ -41,6 +54,10 @@ pub enum ConnectionMode {
     /// Required for historical data queries.
     /// Use when light client is unavailable or for fallback.
     Rpc,
+    /// RPC connection whose storage reads are proven against the light
+    /// client's finalized state roots.
+    /// Historical queries like RPC, without trusting the endpoint's data.
+    Hybrid,
 }
 
 impl std::fmt::Display for ConnectionMode {
@@ -48,6 +65,7 @@ impl std::fmt::Display for ConnectionMode {
         match self {
             ConnectionMode::LightClient => write!(f, "Light Client"),
             ConnectionMode::Rpc => write!(f, "RPC"),
+            ConnectionMode::Hybrid => write!(f, "Hybrid"),
         }
     }
 }
@@ -70,6 +88,27 @@ pub struct ConnectionConfig {
     pub mode: ConnectionMode,
     /// RPC endpoints (used when mode is Rpc, or as fallback).
     pub rpc_endpoints: RpcEndpoints,
+    /// Mortality period (blocks) for built transactions, per network.
+    /// Networks without an entry use `DEFAULT_MORTALITY_PERIOD`.
+    pub mortality_periods: HashMap<Network, u64>,
+    /// Record the session to a file, or replay one instead of connecting.
+    pub session: Option<SessionMode>,
+    /// Persisted endpoint health used to rank the default RPC endpoints.
+    pub endpoint_health: Vec<EndpointHealth>,
+    /// Directory for saved light-client peers; `None` starts from the
+    /// bootnodes only.
+    pub light_client_dir: Option<PathBuf>,
+}
+
+impl ConnectionConfig {
+    /// Mortality period (blocks) for transactions on `network`.
+    pub fn mortality_period(&self, network: Network) -> u64 {
+        self.mortality_periods
+            .get(&network)
+            .copied()
+            .map(normalize_mortality_period)
+            .unwrap_or(DEFAULT_MORTALITY_PERIOD)
+    }
 }
 
 /// Chain metadata and validation info.
@@ -90,6 +129,8 @@ pub struct ChainInfo {
 /// Both staking data queries and staking transactions go to Asset Hub.
 ///
 /// Supports reconnection when the connection drops via `reconnect()`.
+/// Clones are cheap and share the underlying connections.
+#[derive(Clone)]
 pub struct ChainClient {
     network: Network,
     /// Connection mode used.
@@ -106,6 +147,19 @@ pub struct ChainClient {
     light_client_conns: Option<std::sync::Arc<LightClientConnections>>,
     /// Status channel for connection updates.
     status_tx: mpsc::Sender<ConnectionStatus>,
+    /// Mortality period (blocks) for mortal transactions.
+    mortality_period: u64,
+    /// Record/replay servers this client is connected through.
+    session: Option<Arc<SessionServers>>,
+    /// Endpoint health and ranking, carried across reconnects.
+    endpoints: SharedEndpoints,
+    /// Ranked default Asset Hub endpoint in use; `None` for custom endpoints.
+    asset_hub_endpoint: Option<String>,
+    /// Ranked default relay chain endpoint in use over RPC; `None` for
+    /// custom endpoints and the light client.
+    relay_endpoint: Option<String>,
+    /// Local proxy proving Asset Hub storage reads in hybrid mode.
+    proof_proxy: Option<Arc<MockRpcServer>>,
 }
 
 impl ChainClient {
@@ -113,22 +167,53 @@ impl ChainClient {
     ///
     /// Uses light client by default (trustless P2P). RPC is only used when
     /// explicitly requested via `ConnectionMode::Rpc`; there is no automatic
-    /// fallback.
+    /// fallback. Default RPC endpoints are ranked by `config.endpoint_health`.
     pub async fn connect(
         network: Network,
         config: &ConnectionConfig,
         status_tx: mpsc::Sender<ConnectionStatus>,
     ) -> Result<Self, ChainError> {
-        match config.mode {
-            ConnectionMode::LightClient => {
+        let endpoints =
+            EndpointManager::new(network.clone(), config.endpoint_health.clone()).shared();
+        let client = match (&config.session, config.mode) {
+            (Some(session), _) => {
+                Self::connect_session(network.clone(), session, &config.rpc_endpoints, status_tx)
+                    .await
+            }
+            (None, ConnectionMode::LightClient) => {
                 tracing::info!("Connection mode: Light Client (trustless P2P)");
-                Self::connect_light_client(network, status_tx.clone()).await
+                let store = config.light_client_dir.clone().map(LightClientStore::new);
+                Self::connect_light_client_with_store(network.clone(), store, status_tx.clone())
+                    .await
             }
-            ConnectionMode::Rpc => {
+            (None, ConnectionMode::Rpc) => {
                 tracing::info!("Connection mode: RPC (explicit mode)");
-                Self::connect_rpc(network, &config.rpc_endpoints, status_tx).await
+                Self::connect_rpc_ranked(
+                    network.clone(),
+                    &config.rpc_endpoints,
+                    endpoints.clone(),
+                    status_tx,
+                )
+                .await
             }
-        }
+            (None, ConnectionMode::Hybrid) => {
+                tracing::info!("Connection mode: Hybrid (RPC data, light client proofs)");
+                let store = config.light_client_dir.clone().map(LightClientStore::new);
+                Self::connect_hybrid_ranked(
+                    network.clone(),
+                    &config.rpc_endpoints,
+                    store,
+                    endpoints.clone(),
+                    status_tx,
+                )
+                .await
+            }
+        };
+        client.map(|mut client| {
+            client.set_mortality_period(config.mortality_period(network));
+            client.endpoints = endpoints;
+            client
+        })
     }
 
     /// Connect using the light client (smoldot).
@@ -144,6 +229,16 @@ impl ChainClient {
     pub async fn connect_light_client(
         network: Network,
         status_tx: mpsc::Sender<ConnectionStatus>,
+    ) -> Result<Self, ChainError> {
+        Self::connect_light_client_with_store(network, None, status_tx).await
+    }
+
+    /// Connect using the light client, dialing the peers saved in `store` by
+    /// [`ChainClient::save_light_client_peers`] first.
+    pub async fn connect_light_client_with_store(
+        network: Network,
+        store: Option<LightClientStore>,
+        status_tx: mpsc::Sender<ConnectionStatus>,
     ) -> Result<Self, ChainError> {
         let _ = status_tx.send(ConnectionStatus::Connecting).await;
 
@@ -151,8 +246,9 @@ impl ChainClient {
 
         // Connect using light client - this fetches chain specs and establishes
         // P2P connections to relay chain and Asset Hub
-        let light_client_conns =
-            std::sync::Arc::new(LightClientConnections::connect(network).await?);
+        let light_client_conns = std::sync::Arc::new(
+            LightClientConnections::connect_with_store(network.clone(), store).await?,
+        );
 
         let _ = status_tx.send(ConnectionStatus::Connected).await;
 
@@ -162,7 +258,7 @@ impl ChainClient {
         );
 
         Ok(Self {
-            network,
+            network: network.clone(),
             connection_mode: ConnectionMode::LightClient,
             rpc_endpoints: RpcEndpoints::default(),
             client: light_client_conns.asset_hub.clone(),
@@ -170,6 +266,12 @@ impl ChainClient {
             relay_client: Some(light_client_conns.relay.clone()),
             light_client_conns: Some(light_client_conns),
             status_tx,
+            mortality_period: DEFAULT_MORTALITY_PERIOD,
+            session: None,
+            endpoints: EndpointManager::new(network, Vec::new()).shared(),
+            asset_hub_endpoint: None,
+            relay_endpoint: None,
+            proof_proxy: None,
         })
     }
 
@@ -183,56 +285,40 @@ impl ChainClient {
         rpc_endpoints: &RpcEndpoints,
         status_tx: mpsc::Sender<ConnectionStatus>,
     ) -> Result<Self, ChainError> {
-        let _ = status_tx.send(ConnectionStatus::Connecting).await;
-
-        // Build list of endpoints to try (Asset Hub by default)
-        let default_endpoints = get_asset_hub_endpoints(network);
-        let endpoints: Vec<&str> = if let Some(ref endpoint) = rpc_endpoints.asset_hub {
-            vec![endpoint.as_str()]
-        } else {
-            default_endpoints.to_vec()
-        };
-
-        if endpoints.is_empty() {
-            return Err(ChainError::Connection(
-                "No RPC endpoints configured".to_string(),
-            ));
-        }
-
-        let mut endpoint_errors: Vec<String> = Vec::new();
-        let mut asset_hub_connection = None;
+        let endpoints = EndpointManager::new(network.clone(), Vec::new()).shared();
+        Self::connect_rpc_ranked(network, rpc_endpoints, endpoints, status_tx).await
+    }
 
-        for endpoint in endpoints {
-            tracing::info!("Trying {} Asset Hub via {}", network, endpoint);
+    /// Connect over RPC, trying default endpoints best first by their health.
+    async fn connect_rpc_ranked(
+        network: Network,
+        rpc_endpoints: &RpcEndpoints,
+        endpoints: SharedEndpoints,
+        status_tx: mpsc::Sender<ConnectionStatus>,
+    ) -> Result<Self, ChainError> {
+        let _ = status_tx.send(ConnectionStatus::Connecting).await;
 
-            match connect_asset_hub_endpoint_rpc(endpoint).await {
-                Ok(connection) => {
-                    tracing::info!("Connected to {} Asset Hub via {}", network, endpoint);
-                    asset_hub_connection = Some(connection);
-                    break;
-                }
-                Err(e) => {
-                    tracing::warn!("Failed to connect to {}: {}", endpoint, e);
-                    endpoint_errors.push(format!("{}: {}", endpoint, e));
+        let custom = rpc_endpoints.asset_hub.as_deref();
+        let ((client, asset_hub_backend), endpoint) =
+            connect_best(&endpoints, ChainRole::AssetHub, custom, |url| async move {
+                connect_asset_hub_endpoint_rpc(&url).await
+            })
+            .await
+            .map_err(|errors| {
+                if errors.is_empty() {
+                    ChainError::Connection("All endpoints failed".to_string())
+                } else {
+                    ChainError::Connection(format!("All endpoints failed: {}", errors.join("; ")))
                 }
-            }
-        }
-
-        let (client, asset_hub_backend) = asset_hub_connection.ok_or_else(|| {
-            if endpoint_errors.is_empty() {
-                ChainError::Connection("All endpoints failed".to_string())
-            } else {
-                ChainError::Connection(format!(
-                    "All endpoints failed: {}",
-                    endpoint_errors.join("; ")
-                ))
-            }
-        })?;
+            })?;
 
         // Also connect to relay chain for transaction submission
-        let relay_client = Self::connect_relay_chain(network, rpc_endpoints.relay.as_deref())
-            .await
-            .ok();
+        let custom_relay = rpc_endpoints.relay.as_deref();
+        let (relay_client, relay_endpoint) =
+            match Self::connect_relay_chain(&endpoints, custom_relay).await {
+                Ok((client, url)) => (Some(client), custom_relay.is_none().then_some(url)),
+                Err(_) => (None, None),
+            };
         if relay_client.is_some() {
             tracing::info!("Also connected to {} relay chain for transactions", network);
         } else {
@@ -252,46 +338,134 @@ impl ChainClient {
             relay_client,
             light_client_conns: None,
             status_tx,
+            mortality_period: DEFAULT_MORTALITY_PERIOD,
+            session: None,
+            endpoints,
+            asset_hub_endpoint: custom.is_none().then_some(endpoint),
+            relay_endpoint,
+            proof_proxy: None,
         })
     }
 
-    /// Connect to the relay chain (for transaction submission).
-    async fn connect_relay_chain(
+    /// Connect in hybrid mode: staking data over RPC, each storage read
+    /// proven against a state root the light client finalized.
+    ///
+    /// Starts the light client like [`ChainClient::connect_light_client`],
+    /// which also serves the relay and People chains.
+    pub async fn connect_hybrid(
         network: Network,
-        custom_endpoint: Option<&str>,
-    ) -> Result<OnlineClient<PolkadotConfig>, ChainError> {
-        use crate::config::get_rpc_endpoints;
+        rpc_endpoints: &RpcEndpoints,
+        store: Option<LightClientStore>,
+        status_tx: mpsc::Sender<ConnectionStatus>,
+    ) -> Result<Self, ChainError> {
+        let endpoints = EndpointManager::new(network.clone(), Vec::new()).shared();
+        Self::connect_hybrid_ranked(network, rpc_endpoints, store, endpoints, status_tx).await
+    }
 
-        let default_endpoints = get_rpc_endpoints(network);
-        let endpoints: Vec<&str> = if let Some(endpoint) = custom_endpoint {
-            vec![endpoint]
-        } else {
-            default_endpoints.to_vec()
-        };
+    async fn connect_hybrid_ranked(
+        network: Network,
+        rpc_endpoints: &RpcEndpoints,
+        store: Option<LightClientStore>,
+        endpoints: SharedEndpoints,
+        status_tx: mpsc::Sender<ConnectionStatus>,
+    ) -> Result<Self, ChainError> {
+        let _ = status_tx.send(ConnectionStatus::Connecting).await;
 
-        if endpoints.is_empty() {
-            return Err(ChainError::Connection(
-                "No relay chain endpoints".to_string(),
-            ));
-        }
+        let light_client_conns =
+            Arc::new(LightClientConnections::connect_with_store(network.clone(), store).await?);
+        let trusted = light_client_conns
+            .rpc(ChainRole::AssetHub)
+            .ok_or_else(|| ChainError::LightClient("Asset Hub light client missing".to_string()))?;
+        let trusted_genesis = light_client_conns.asset_hub.genesis_hash();
+
+        let custom = rpc_endpoints.asset_hub.as_deref();
+        let ((client, asset_hub_backend, proxy), endpoint) =
+            connect_best(&endpoints, ChainRole::AssetHub, custom, |url| {
+                let trusted = trusted.clone();
+                async move {
+                    let proxy = MockRpcServer::start_verified(&url, trusted).await?;
+                    let (client, backend) = connect_asset_hub_endpoint_rpc(&proxy.url()).await?;
+                    // Proofs from another chain would never verify
+                    if client.genesis_hash() != trusted_genesis {
+                        return Err(ChainError::Connection(
+                            "Endpoint serves a different chain than the light client".to_string(),
+                        ));
+                    }
+                    Ok((client, backend, proxy))
+                }
+            })
+            .await
+            .map_err(|errors| {
+                if errors.is_empty() {
+                    ChainError::Connection("All endpoints failed".to_string())
+                } else {
+                    ChainError::Connection(format!("All endpoints failed: {}", errors.join("; ")))
+                }
+            })?;
 
-        for endpoint in endpoints {
-            tracing::debug!("Trying {} relay chain via {}", network, endpoint);
+        let _ = status_tx.send(ConnectionStatus::Connected).await;
+        tracing::info!(
+            "Hybrid connection to {}: data from {}, proofs checked by the light client",
+            network,
+            endpoint
+        );
 
-            if let Ok(client) = OnlineClient::<PolkadotConfig>::from_url(endpoint).await {
-                tracing::debug!("Connected to {} relay chain via {}", network, endpoint);
-                return Ok(client);
-            }
-        }
+        Ok(Self {
+            network,
+            connection_mode: ConnectionMode::Hybrid,
+            rpc_endpoints: rpc_endpoints.clone(),
+            client,
+            asset_hub_backend: Some(asset_hub_backend),
+            relay_client: Some(light_client_conns.relay.clone()),
+            light_client_conns: Some(light_client_conns),
+            status_tx,
+            mortality_period: DEFAULT_MORTALITY_PERIOD,
+            session: None,
+            endpoints,
+            asset_hub_endpoint: custom.is_none().then_some(endpoint),
+            relay_endpoint: None,
+            proof_proxy: Some(Arc::new(proxy)),
+        })
+    }
 
-        Err(ChainError::Connection(
-            "All relay chain endpoints failed".to_string(),
-        ))
+    /// Connect over RPC through record/replay servers.
+    ///
+    /// Recording proxies `rpc_endpoints` (or the network defaults); replaying
+    /// serves the session file and never touches the network.
+    pub async fn connect_session(
+        network: Network,
+        mode: &SessionMode,
+        rpc_endpoints: &RpcEndpoints,
+        status_tx: mpsc::Sender<ConnectionStatus>,
+    ) -> Result<Self, ChainError> {
+        let servers = SessionServers::start(network.clone(), mode, rpc_endpoints).await?;
+        let mut client = Self::connect_rpc(network, &servers.endpoints(), status_tx).await?;
+        client.session = Some(servers);
+        Ok(client)
+    }
+
+    /// Connect to the relay chain (for transaction submission).
+    async fn connect_relay_chain(
+        endpoints: &SharedEndpoints,
+        custom_endpoint: Option<&str>,
+    ) -> Result<(OnlineClient<PolkadotConfig>, String), ChainError> {
+        connect_best(
+            endpoints,
+            ChainRole::Relay,
+            custom_endpoint,
+            |url| async move {
+                OnlineClient::<PolkadotConfig>::from_url(url)
+                    .await
+                    .map_err(|e| ChainError::Connection(e.to_string()))
+            },
+        )
+        .await
+        .map_err(|_| ChainError::Connection("All relay chain endpoints failed".to_string()))
     }
 
     /// Get the connected network.
     pub fn network(&self) -> Network {
-        self.network
+        self.network.clone()
     }
 
     /// Get the underlying subxt client (Asset Hub).
@@ -329,16 +503,57 @@ impl ChainClient {
         self.relay_client.is_some()
     }
 
+    /// Mortality period (blocks) used for mortal transactions.
+    pub fn mortality_period(&self) -> u64 {
+        self.mortality_period
+    }
+
+    /// Set the mortality period, rounded to a valid era period.
+    pub fn set_mortality_period(&mut self, period: u64) {
+        self.mortality_period = normalize_mortality_period(period);
+    }
+
     /// Get the connection mode used.
     pub fn connection_mode(&self) -> ConnectionMode {
         self.connection_mode
     }
 
+    /// Whether data is served from a session recording instead of the network.
+    pub fn is_replay(&self) -> bool {
+        self.session
+            .as_ref()
+            .is_some_and(|session| session.is_replay())
+    }
+
+    /// Whether the session is being recorded to a file.
+    pub fn is_recording(&self) -> bool {
+        self.session
+            .as_ref()
+            .is_some_and(|session| !session.is_replay())
+    }
+
+    /// Flush the session recording to disk now.
+    ///
+    /// Recordings are also flushed periodically and when the last client of
+    /// the session is dropped.
+    #[allow(clippy::result_large_err)]
+    pub fn save_recording(&self) -> Result<(), ChainError> {
+        match &self.session {
+            Some(session) => session.save(),
+            None => Ok(()),
+        }
+    }
+
     /// Check if using light client mode.
     pub fn is_light_client(&self) -> bool {
         self.connection_mode == ConnectionMode::LightClient
     }
 
+    /// Whether Asset Hub storage is proven against the light client.
+    pub fn verifies_storage_proofs(&self) -> bool {
+        self.proof_proxy.is_some()
+    }
+
     /// Get the genesis hash (Asset Hub).
     pub fn genesis_hash(&self) -> [u8; 32] {
         self.client.genesis_hash().0
@@ -373,12 +588,15 @@ impl ChainClient {
 
         // Use a simple spec name based on expected chain
         let spec_name = match self.network {
-            Network::Polkadot => "asset-hub-polkadot",
-            Network::Kusama => "asset-hub-kusama",
-            Network::Westend => "asset-hub-westend",
-            Network::Paseo => "asset-hub-paseo",
-        }
-        .to_string();
+            Network::Polkadot => "asset-hub-polkadot".to_string(),
+            Network::Kusama => "asset-hub-kusama".to_string(),
+            Network::Westend => "asset-hub-westend".to_string(),
+            Network::Paseo => "asset-hub-paseo".to_string(),
+            Network::Custom(_) => format!(
+                "asset-hub-{}",
+                self.network.to_string().to_lowercase().replace(' ', "-")
+            ),
+        };
 
         tracing::info!(
             "Connected to {} (version: {}, tx_version: {})",
@@ -406,14 +624,53 @@ impl ChainClient {
         );
         let _ = self.status_tx.send(ConnectionStatus::Connecting).await;
 
-        match self.connection_mode {
+        // The endpoint that dropped us is demoted, so a healthier one is
+        // tried first.
+        self.record_endpoint_failure();
+
+        let client = match self.connection_mode {
             ConnectionMode::LightClient => {
-                Self::connect_light_client(self.network, self.status_tx.clone()).await
+                let store = self
+                    .light_client_conns
+                    .as_ref()
+                    .and_then(|conns| conns.store().cloned());
+                Self::connect_light_client_with_store(
+                    self.network.clone(),
+                    store,
+                    self.status_tx.clone(),
+                )
+                .await
             }
             ConnectionMode::Rpc => {
-                Self::connect_rpc(self.network, &self.rpc_endpoints, self.status_tx.clone()).await
+                Self::connect_rpc_ranked(
+                    self.network.clone(),
+                    &self.rpc_endpoints,
+                    self.endpoints.clone(),
+                    self.status_tx.clone(),
+                )
+                .await
             }
-        }
+            ConnectionMode::Hybrid => {
+                let store = self
+                    .light_client_conns
+                    .as_ref()
+                    .and_then(|conns| conns.store().cloned());
+                Self::connect_hybrid_ranked(
+                    self.network.clone(),
+                    &self.rpc_endpoints,
+                    store,
+                    self.endpoints.clone(),
+                    self.status_tx.clone(),
+                )
+                .await
+            }
+        };
+        client.map(|mut client| {
+            client.set_mortality_period(self.mortality_period);
+            client.session = self.session.clone();
+            client.endpoints = self.endpoints.clone();
+            client
+        })
     }
 
     /// Check if the connection appears healthy by trying to fetch latest block.
@@ -428,6 +685,12 @@ impl ChainClient {
         &self,
         encoded: &[u8],
     ) -> Result<TxSubmissionProgress, ChainError> {
+        if self.session.is_some() {
+            return Err(ChainError::Recording(
+                "Transactions cannot be submitted while recording or replaying a session"
+                    .to_string(),
+            ));
+        }
         tracing::info!(
             "Submitting signed extrinsic ({} bytes) to {}",
             encoded.len(),
@@ -435,6 +698,8 @@ impl ChainClient {
         );
         tracing::debug!("Extrinsic hex: 0x{}", hex::encode(encoded));
 
+        // Metadata is kept to resolve dispatch errors once the tx is included.
+        let metadata = self.client.at_current_block().await?.metadata();
         let tx = self.client.tx().await?.from_bytes(encoded.to_vec());
         let status_stream = tx.submit_and_watch().await?;
 
@@ -445,6 +710,7 @@ impl ChainClient {
         Ok(TxSubmissionProgress {
             tx_hash,
             status_stream,
+            metadata,
         })
     }
 
@@ -471,7 +737,36 @@ impl ChainClient {
 
         // Fall back to RPC
         tracing::info!("Connecting to {} People chain via RPC...", self.network);
-        connect_people_chain_rpc_client(self.network, self.rpc_endpoints.people.as_deref()).await
+        connect_people_chain_rpc_ranked(&self.endpoints, self.rpc_endpoints.people.as_deref()).await
+    }
+
+    /// Save the peers the light client knows, to dial them first next start.
+    ///
+    /// Call before shutting down; a no-op over RPC or without a store.
+    pub async fn save_light_client_peers(&self) -> Result<(), ChainError> {
+        match self.light_client_conns {
+            Some(ref conns) => conns.save_peers().await,
+            None => Ok(()),
+        }
+    }
+
+    /// Count a failure against the RPC endpoints in use, demoting them.
+    ///
+    /// Called on connection loss, which drops the Asset Hub and relay chain
+    /// connections alike; custom endpoints are not tracked.
+    pub fn record_endpoint_failure(&self) {
+        let mut endpoints = lock(&self.endpoints);
+        if let Some(endpoint) = &self.asset_hub_endpoint {
+            endpoints.record_failure(ChainRole::AssetHub, endpoint);
+        }
+        if let Some(endpoint) = &self.relay_endpoint {
+            endpoints.record_failure(ChainRole::Relay, endpoint);
+        }
+    }
+
+    /// Health stats of the endpoints tried so far, for persistence.
+    pub fn endpoint_health(&self) -> Vec<EndpointHealth> {
+        lock(&self.endpoints).health()
     }
 }
 
@@ -484,8 +779,15 @@ pub struct TxSubmissionProgress {
         subxt::PolkadotConfig,
         subxt::client::OnlineClientAtBlockImpl<subxt::PolkadotConfig>,
     >,
+    /// Runtime metadata used to decode the extrinsic's events.
+    metadata: subxt::ArcMetadata,
 }
 
+type TxInBlock = subxt::tx::TransactionInBlock<
+    subxt::PolkadotConfig,
+    subxt::client::OnlineClientAtBlockImpl<subxt::PolkadotConfig>,
+>;
+
 impl TxSubmissionProgress {
     /// Get the transaction hash.
     pub fn tx_hash(&self) -> [u8; 32] {
@@ -495,6 +797,14 @@ impl TxSubmissionProgress {
     /// Wait for the transaction to be included in a block.
     /// Returns the block hash when included.
     pub async fn wait_for_in_block(mut self) -> Result<TxInBlockResult, ChainError> {
+        self.next_inclusion().await
+    }
+
+    /// Wait for the next best-block or finalized-block inclusion.
+    ///
+    /// Call again after a best-block inclusion to follow the transaction until
+    /// `TxInBlockResult::finalized` is set.
+    pub async fn next_inclusion(&mut self) -> Result<TxInBlockResult, ChainError> {
         while let Some(status) = self.status_stream.next().await {
             match status? {
                 TransactionStatus::InBestBlock(tx_in_block) => {
@@ -504,6 +814,7 @@ impl TxSubmissionProgress {
                         tx_hash: self.tx_hash,
                         block_hash,
                         finalized: false,
+                        outcome: decode_outcome(&self.metadata, &tx_in_block).await,
                     });
                 }
                 TransactionStatus::InFinalizedBlock(tx_in_block) => {
@@ -516,6 +827,7 @@ impl TxSubmissionProgress {
                         tx_hash: self.tx_hash,
                         block_hash,
                         finalized: true,
+                        outcome: decode_outcome(&self.metadata, &tx_in_block).await,
                     });
                 }
                 TransactionStatus::Dropped { message } => {
@@ -571,6 +883,7 @@ impl TxSubmissionProgress {
                         tx_hash: self.tx_hash,
                         block_hash,
                         finalized: true,
+                        outcome: decode_outcome(&self.metadata, &tx_in_block).await,
                     });
                 }
                 TransactionStatus::Dropped { message } => {
@@ -609,6 +922,60 @@ impl TxSubmissionProgress {
     }
 }
 
+/// Decode the included extrinsic's events into a success/failure outcome.
+///
+/// Returns `None` (and logs) if the events cannot be fetched or decoded;
+/// inclusion itself is still reported.
+async fn decode_outcome(
+    metadata: &subxt::ArcMetadata,
+    tx_in_block: &TxInBlock,
+) -> Option<TxOutcome> {
+    let events = match tx_in_block.fetch_events().await {
+        Ok(events) => events,
+        Err(e) => {
+            tracing::warn!("Failed to fetch transaction events: {}", e);
+            return None;
+        }
+    };
+
+    let mut raw = Vec::new();
+    for event in events.iter() {
+        let event = match event {
+            Ok(event) => event,
+            Err(e) => {
+                tracing::warn!("Failed to decode transaction event: {}", e);
+                return None;
+            }
+        };
+        let fields = match event.decode_fields_unchecked_as::<subxt::dynamic::Value>() {
+            Ok(fields) => fields,
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to decode {}.{} fields: {}",
+                    event.pallet_name(),
+                    event.event_name(),
+                    e
+                );
+                return None;
+            }
+        };
+        raw.push(RawEvent {
+            pallet: event.pallet_name().to_string(),
+            variant: event.event_name().to_string(),
+            fields,
+        });
+    }
+
+    let outcome = outcome_from_events(raw, |pallet, error| {
+        module_error_info(metadata, pallet, error)
+    });
+    match &outcome.failure {
+        Some(failure) => tracing::warn!("Transaction dispatch failed: {}", failure),
+        None => tracing::info!("Transaction dispatched: {}", outcome.summary()),
+    }
+    Some(outcome)
+}
+
 /// Result when a transaction is included in a block.
 #[derive(Debug, Clone)]
 pub struct TxInBlockResult {
@@ -618,6 +985,8 @@ pub struct TxInBlockResult {
     pub block_hash: [u8; 32],
     /// Whether the block is finalized.
     pub finalized: bool,
+    /// Dispatch result decoded from the extrinsic's events, if available.
+    pub outcome: Option<TxOutcome>,
 }
 
 /// Connect to a network's People chain using WebSocket RPC.
@@ -665,37 +1034,26 @@ pub async fn connect_people_chain_rpc_client(
     network: Network,
     custom_endpoint: Option<&str>,
 ) -> Result<PeopleChainClient, ChainError> {
-    use crate::config::get_people_chain_endpoints;
+    let endpoints = EndpointManager::new(network, Vec::new()).shared();
+    connect_people_chain_rpc_ranked(&endpoints, custom_endpoint).await
+}
 
-    let default_endpoints = get_people_chain_endpoints(network);
-    let endpoints: Vec<&str> = if let Some(endpoint) = custom_endpoint {
-        vec![endpoint]
-    } else {
-        default_endpoints.to_vec()
+async fn connect_people_chain_rpc_ranked(
+    endpoints: &SharedEndpoints,
+    custom_endpoint: Option<&str>,
+) -> Result<PeopleChainClient, ChainError> {
+    let endpoint_errors = match connect_best(
+        endpoints,
+        ChainRole::People,
+        custom_endpoint,
+        |url| async move { connect_people_endpoint_rpc(&url).await },
+    )
+    .await
+    {
+        Ok((client, _)) => return Ok(client),
+        Err(errors) => errors,
     };
 
-    if endpoints.is_empty() {
-        return Err(ChainError::Connection(
-            "No People chain endpoints configured".to_string(),
-        ));
-    }
-
-    let mut endpoint_errors: Vec<String> = Vec::new();
-    for endpoint in endpoints {
-        tracing::info!("Trying {} People chain RPC via {}", network, endpoint);
-
-        match connect_people_endpoint_rpc(endpoint).await {
-            Ok(client) => {
-                tracing::info!("Connected to {} People chain via RPC {}", network, endpoint);
-                return Ok(client);
-            }
-            Err(e) => {
-                tracing::warn!("Failed to connect to People chain RPC {}: {}", endpoint, e);
-                endpoint_errors.push(format!("{}: {}", endpoint, e));
-            }
-        }
-    }
-
     if endpoint_errors.is_empty() {
         Err(ChainError::Connection(
             "All People chain RPC endpoints failed".to_string(),
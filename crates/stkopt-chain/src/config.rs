@@ -4,110 +4,127 @@
 //! - Relay chain endpoints: For block/session data only
 //! - Asset Hub endpoints: For all staking data (validators, pools, nominations)
 //! - People chain endpoints: For identity data
+//!
+//! `Network::Custom` takes its endpoints from its definition; an Asset Hub or
+//! People chain it doesn't define is served by the relay chain.
+
+use stkopt_core::{ChainRole, CustomNetwork, Network};
 
-use stkopt_core::Network;
+/// Custom network endpoints for a chain, falling back to the relay chain.
+fn custom_endpoints(custom: &CustomNetwork, role: ChainRole) -> Vec<&str> {
+    let endpoints = match custom.endpoints(role) {
+        [] => custom.endpoints(ChainRole::Relay),
+        endpoints => endpoints,
+    };
+    endpoints.iter().map(String::as_str).collect()
+}
 
 /// Get the Asset Hub RPC endpoints for a network.
 /// This is where staking data lives after the Polkadot 2.0 migration.
-pub fn get_asset_hub_endpoints(network: Network) -> &'static [&'static str] {
+pub fn get_asset_hub_endpoints(network: &Network) -> Vec<&str> {
     match network {
-        Network::Polkadot => &[
+        Network::Polkadot => vec![
             "wss://polkadot-asset-hub-rpc.polkadot.io",
             "wss://rpc-asset-hub-polkadot.luckyfriday.io",
             "wss://sys.ibp.network/asset-hub-polkadot",
             "wss://sys.dotters.network/asset-hub-polkadot",
             "wss://asset-hub-polkadot-rpc.dwellir.com",
         ],
-        Network::Kusama => &[
+        Network::Kusama => vec![
             "wss://kusama-asset-hub-rpc.polkadot.io",
             "wss://rpc-asset-hub-kusama.luckyfriday.io",
             "wss://sys.ibp.network/asset-hub-kusama",
             "wss://sys.dotters.network/asset-hub-kusama",
             "wss://asset-hub-kusama-rpc.dwellir.com",
         ],
-        Network::Westend => &[
+        Network::Westend => vec![
             "wss://westend-asset-hub-rpc.polkadot.io",
             "wss://sys.ibp.network/asset-hub-westend",
             "wss://sys.dotters.network/asset-hub-westend",
             "wss://asset-hub-westend-rpc.dwellir.com",
         ],
-        Network::Paseo => &[
+        Network::Paseo => vec![
             "wss://sys.ibp.network/asset-hub-paseo",
             "wss://sys.dotters.network/asset-hub-paseo",
             "wss://asset-hub-paseo-rpc.dwellir.com",
         ],
+        Network::Custom(custom) => custom_endpoints(custom, ChainRole::AssetHub),
     }
 }
 
 /// Get the relay chain RPC endpoints for a network.
 /// Use for block/session data, not for staking queries.
-pub fn get_rpc_endpoints(network: Network) -> &'static [&'static str] {
+pub fn get_rpc_endpoints(network: &Network) -> Vec<&str> {
     match network {
-        Network::Polkadot => &[
+        Network::Polkadot => vec![
             "wss://rpc.ibp.network/polkadot",
             "wss://polkadot.dotters.network",
             "wss://rpc-polkadot.luckyfriday.io",
             "wss://dot-rpc.stakeworld.io",
             "wss://polkadot-rpc.dwellir.com",
         ],
-        Network::Kusama => &[
+        Network::Kusama => vec![
             "wss://rpc.ibp.network/kusama",
             "wss://kusama.dotters.network",
             "wss://rpc-kusama.luckyfriday.io",
             "wss://ksm-rpc.stakeworld.io",
             "wss://kusama-rpc.dwellir.com",
         ],
-        Network::Westend => &[
+        Network::Westend => vec![
             "wss://westend-rpc.polkadot.io",
             "wss://westend-rpc.dwellir.com",
             "wss://rpc.ibp.network/westend",
             "wss://westend.dotters.network",
         ],
-        Network::Paseo => &[
+        Network::Paseo => vec![
             "wss://rpc.ibp.network/paseo",
             "wss://paseo.dotters.network",
             "wss://paseo-rpc.dwellir.com",
             "wss://pas-rpc.stakeworld.io",
         ],
+        Network::Custom(custom) => custom_endpoints(custom, ChainRole::Relay),
     }
 }
 
 /// Get the People chain RPC endpoints for a network.
 /// This is where identity data lives.
-pub fn get_people_chain_endpoints(network: Network) -> &'static [&'static str] {
+pub fn get_people_chain_endpoints(network: &Network) -> Vec<&str> {
     match network {
-        Network::Polkadot => &[
+        Network::Polkadot => vec![
             "wss://polkadot-people-rpc.polkadot.io",
             "wss://sys.ibp.network/people-polkadot",
             "wss://people-polkadot.dotters.network",
             "wss://rpc-people-polkadot.luckyfriday.io",
         ],
-        Network::Kusama => &[
+        Network::Kusama => vec![
             "wss://kusama-people-rpc.polkadot.io",
             "wss://sys.ibp.network/people-kusama",
             "wss://people-kusama.dotters.network",
             "wss://rpc-people-kusama.luckyfriday.io",
         ],
-        Network::Westend => &[
+        Network::Westend => vec![
             "wss://westend-people-rpc.polkadot.io",
             "wss://sys.ibp.network/people-westend",
             "wss://people-westend.dotters.network",
         ],
-        Network::Paseo => &[
+        Network::Paseo => vec![
             "wss://sys.ibp.network/people-paseo",
             "wss://people-paseo.dotters.network",
         ],
+        Network::Custom(custom) => custom_endpoints(custom, ChainRole::People),
     }
 }
 
 /// Indexer URL for staking history data.
 /// Light clients don't have historical state, so we use an indexer.
-pub fn get_staking_indexer_url(network: Network) -> &'static str {
+/// Empty for a custom network without an indexer.
+pub fn get_staking_indexer_url(network: &Network) -> &str {
     match network {
         Network::Polkadot => "https://staking-eras.usepapi.app/dot",
         Network::Kusama => "https://staking-eras.usepapi.app/ksm",
         Network::Westend => "https://staking-eras.usepapi.app/wnd",
         Network::Paseo => "https://staking-eras.usepapi.app/pas",
+        Network::Custom(custom) => custom.staking_indexer_url.as_deref().unwrap_or(""),
     }
 }
 
@@ -117,7 +134,7 @@ mod tests {
 
     #[test]
     fn test_get_rpc_endpoints_polkadot() {
-        let endpoints = get_rpc_endpoints(Network::Polkadot);
+        let endpoints = get_rpc_endpoints(&Network::Polkadot);
         assert!(!endpoints.is_empty());
         assert!(endpoints.contains(&"wss://rpc.ibp.network/polkadot"));
         assert!(endpoints.contains(&"wss://polkadot-rpc.dwellir.com"));
@@ -125,7 +142,7 @@ mod tests {
 
     #[test]
     fn test_get_rpc_endpoints_kusama() {
-        let endpoints = get_rpc_endpoints(Network::Kusama);
+        let endpoints = get_rpc_endpoints(&Network::Kusama);
         assert!(!endpoints.is_empty());
         assert!(endpoints.contains(&"wss://rpc.ibp.network/kusama"));
         assert!(endpoints.contains(&"wss://kusama-rpc.dwellir.com"));
@@ -133,7 +150,7 @@ mod tests {
 
     #[test]
     fn test_get_rpc_endpoints_westend() {
-        let endpoints = get_rpc_endpoints(Network::Westend);
+        let endpoints = get_rpc_endpoints(&Network::Westend);
         assert!(!endpoints.is_empty());
         assert!(endpoints.contains(&"wss://westend-rpc.polkadot.io"));
         assert!(endpoints.contains(&"wss://rpc.ibp.network/westend"));
@@ -141,7 +158,7 @@ mod tests {
 
     #[test]
     fn test_get_rpc_endpoints_paseo() {
-        let endpoints = get_rpc_endpoints(Network::Paseo);
+        let endpoints = get_rpc_endpoints(&Network::Paseo);
         assert!(!endpoints.is_empty());
         assert!(endpoints.contains(&"wss://rpc.ibp.network/paseo"));
         assert!(endpoints.contains(&"wss://paseo-rpc.dwellir.com"));
@@ -149,7 +166,7 @@ mod tests {
 
     #[test]
     fn test_get_asset_hub_endpoints_polkadot() {
-        let endpoints = get_asset_hub_endpoints(Network::Polkadot);
+        let endpoints = get_asset_hub_endpoints(&Network::Polkadot);
         assert!(!endpoints.is_empty());
         assert!(endpoints.contains(&"wss://polkadot-asset-hub-rpc.polkadot.io"));
         assert!(endpoints.contains(&"wss://asset-hub-polkadot-rpc.dwellir.com"));
@@ -157,7 +174,7 @@ mod tests {
 
     #[test]
     fn test_get_asset_hub_endpoints_kusama() {
-        let endpoints = get_asset_hub_endpoints(Network::Kusama);
+        let endpoints = get_asset_hub_endpoints(&Network::Kusama);
         assert!(!endpoints.is_empty());
         assert!(endpoints.contains(&"wss://kusama-asset-hub-rpc.polkadot.io"));
         assert!(endpoints.contains(&"wss://asset-hub-kusama-rpc.dwellir.com"));
@@ -165,7 +182,7 @@ mod tests {
 
     #[test]
     fn test_get_asset_hub_endpoints_westend() {
-        let endpoints = get_asset_hub_endpoints(Network::Westend);
+        let endpoints = get_asset_hub_endpoints(&Network::Westend);
         assert!(!endpoints.is_empty());
         assert!(endpoints.contains(&"wss://westend-asset-hub-rpc.polkadot.io"));
         assert!(endpoints.contains(&"wss://asset-hub-westend-rpc.dwellir.com"));
@@ -173,7 +190,7 @@ mod tests {
 
     #[test]
     fn test_get_asset_hub_endpoints_paseo() {
-        let endpoints = get_asset_hub_endpoints(Network::Paseo);
+        let endpoints = get_asset_hub_endpoints(&Network::Paseo);
         assert!(!endpoints.is_empty());
         assert!(endpoints.contains(&"wss://sys.ibp.network/asset-hub-paseo"));
         assert!(endpoints.contains(&"wss://asset-hub-paseo-rpc.dwellir.com"));
@@ -181,7 +198,7 @@ mod tests {
 
     #[test]
     fn test_get_people_chain_endpoints_polkadot() {
-        let endpoints = get_people_chain_endpoints(Network::Polkadot);
+        let endpoints = get_people_chain_endpoints(&Network::Polkadot);
         assert!(!endpoints.is_empty());
         assert!(endpoints.contains(&"wss://polkadot-people-rpc.polkadot.io"));
         assert!(endpoints.contains(&"wss://rpc-people-polkadot.luckyfriday.io"));
@@ -189,7 +206,7 @@ mod tests {
 
     #[test]
     fn test_get_people_chain_endpoints_kusama() {
-        let endpoints = get_people_chain_endpoints(Network::Kusama);
+        let endpoints = get_people_chain_endpoints(&Network::Kusama);
         assert!(!endpoints.is_empty());
         assert!(endpoints.contains(&"wss://kusama-people-rpc.polkadot.io"));
         assert!(endpoints.contains(&"wss://rpc-people-kusama.luckyfriday.io"));
@@ -197,7 +214,7 @@ mod tests {
 
     #[test]
     fn test_get_people_chain_endpoints_westend() {
-        let endpoints = get_people_chain_endpoints(Network::Westend);
+        let endpoints = get_people_chain_endpoints(&Network::Westend);
         assert!(!endpoints.is_empty());
         assert!(endpoints.contains(&"wss://westend-people-rpc.polkadot.io"));
         assert!(endpoints.contains(&"wss://people-westend.dotters.network"));
@@ -205,7 +222,7 @@ mod tests {
 
     #[test]
     fn test_get_people_chain_endpoints_paseo() {
-        let endpoints = get_people_chain_endpoints(Network::Paseo);
+        let endpoints = get_people_chain_endpoints(&Network::Paseo);
         assert!(!endpoints.is_empty());
         assert!(endpoints.contains(&"wss://sys.ibp.network/people-paseo"));
         assert!(endpoints.contains(&"wss://people-paseo.dotters.network"));
@@ -214,7 +231,7 @@ mod tests {
     #[test]
     fn test_get_staking_indexer_url_polkadot() {
         assert_eq!(
-            get_staking_indexer_url(Network::Polkadot),
+            get_staking_indexer_url(&Network::Polkadot),
             "https://staking-eras.usepapi.app/dot"
         );
     }
@@ -222,7 +239,7 @@ mod tests {
     #[test]
     fn test_get_staking_indexer_url_kusama() {
         assert_eq!(
-            get_staking_indexer_url(Network::Kusama),
+            get_staking_indexer_url(&Network::Kusama),
             "https://staking-eras.usepapi.app/ksm"
         );
     }
@@ -230,7 +247,7 @@ mod tests {
     #[test]
     fn test_get_staking_indexer_url_westend() {
         assert_eq!(
-            get_staking_indexer_url(Network::Westend),
+            get_staking_indexer_url(&Network::Westend),
             "https://staking-eras.usepapi.app/wnd"
         );
     }
@@ -238,8 +255,32 @@ mod tests {
     #[test]
     fn test_get_staking_indexer_url_paseo() {
         assert_eq!(
-            get_staking_indexer_url(Network::Paseo),
+            get_staking_indexer_url(&Network::Paseo),
             "https://staking-eras.usepapi.app/pas"
         );
     }
+
+    #[test]
+    fn test_custom_network_endpoints_fall_back_to_relay() {
+        let network = Network::from_custom(stkopt_core::CustomNetwork {
+            name: "Zombienet".to_string(),
+            token_symbol: "UNIT".to_string(),
+            token_decimals: 12,
+            ss58_prefix: 42,
+            relay: stkopt_core::ChainSource::rpc(["ws://127.0.0.1:9944"]),
+            asset_hub: Some(stkopt_core::ChainSource::rpc(["ws://127.0.0.1:9946"])),
+            people: None,
+            relay_genesis_hash: None,
+            asset_hub_genesis_hash: None,
+            staking_indexer_url: None,
+        })
+        .unwrap();
+        assert_eq!(get_rpc_endpoints(&network), ["ws://127.0.0.1:9944"]);
+        assert_eq!(get_asset_hub_endpoints(&network), ["ws://127.0.0.1:9946"]);
+        assert_eq!(
+            get_people_chain_endpoints(&network),
+            ["ws://127.0.0.1:9944"]
+        );
+        assert_eq!(get_staking_indexer_url(&network), "");
+    }
 }
@@ -4,14 +4,14 @@
 //! frontend crates while returning the shared display types from `stkopt-core`.
 
 use crate::{
-    PoolInfo as ChainPoolInfo, PoolMetadata, PoolNominations, ValidatorApyData, ValidatorIdentity,
-    ValidatorInfo as ChainValidatorInfo,
+    PoolInfo as ChainPoolInfo, PoolMetadata, PoolNominations, UnlockChunk, ValidatorApyData,
+    ValidatorIdentity, ValidatorInfo as ChainValidatorInfo,
 };
 #[cfg(test)]
 use crate::{ValidatorExposure, ValidatorPoints};
 use std::collections::HashMap;
 use stkopt_core::display::{DisplayPool, DisplayValidator, StakingHistoryPoint};
-use stkopt_core::{Balance, get_era_apy};
+use stkopt_core::{Balance, EraIndex, get_era_apy};
 use subxt::utils::AccountId32;
 
 /// Maximum realistic APY (50%). Higher values indicate incomplete/corrupt data.
@@ -349,17 +349,18 @@ pub fn estimate_user_reward(
     }
 }
 
-/// Calculate a compact persisted date for an era in YYYYMMDD format.
-pub fn calculate_era_date(
+/// Estimated start of `era` in Unix milliseconds.
+///
+/// Extrapolates from the current era's start, backwards for past eras and
+/// forwards for future ones; the current time stands in for an unknown start.
+pub fn era_start_ms(
     era: u32,
     current_era: u32,
     current_era_start_ms: u64,
     era_duration_ms: u64,
-) -> String {
+) -> u64 {
     use std::time::{SystemTime, UNIX_EPOCH};
 
-    let eras_ago = current_era.saturating_sub(era) as u64;
-    let elapsed_ms = eras_ago.saturating_mul(era_duration_ms);
     let reference_ms = if current_era_start_ms > 0 {
         current_era_start_ms
     } else {
@@ -368,7 +369,23 @@ pub fn calculate_era_date(
             .map(|duration| duration.as_millis().min(u64::MAX as u128) as u64)
             .unwrap_or_default()
     };
-    let era_start_ms = reference_ms.saturating_sub(elapsed_ms);
+    if era >= current_era {
+        let eras_ahead = (era - current_era) as u64;
+        reference_ms.saturating_add(eras_ahead.saturating_mul(era_duration_ms))
+    } else {
+        let eras_ago = (current_era - era) as u64;
+        reference_ms.saturating_sub(eras_ago.saturating_mul(era_duration_ms))
+    }
+}
+
+/// Calculate a compact persisted date for an era in YYYYMMDD format.
+pub fn calculate_era_date(
+    era: u32,
+    current_era: u32,
+    current_era_start_ms: u64,
+    era_duration_ms: u64,
+) -> String {
+    let era_start_ms = era_start_ms(era, current_era, current_era_start_ms, era_duration_ms);
     let era_start_ms = era_start_ms.min(i64::MAX as u64) as i64;
 
     chrono::DateTime::<chrono::Utc>::from_timestamp_millis(era_start_ms)
@@ -377,6 +394,86 @@ pub fn calculate_era_date(
         .to_string()
 }
 
+/// Where an unbonding chunk is held.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnbondingSource {
+    /// The account's own staking ledger.
+    Direct,
+    /// A nomination pool membership.
+    Pool(u32),
+}
+
+/// An unbonding chunk with its projected unlock date.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnbondingEntry {
+    pub source: UnbondingSource,
+    pub value: Balance,
+    /// Era from which the chunk can be withdrawn.
+    pub era: EraIndex,
+    /// Eras left until then, zero once withdrawable.
+    pub remaining_eras: u32,
+    /// Projected unlock time in Unix milliseconds.
+    pub unlock_ms: u64,
+    /// Projected unlock date in YYYYMMDD format.
+    pub date: String,
+}
+
+impl UnbondingEntry {
+    /// Whether the chunk can be withdrawn now.
+    pub fn is_withdrawable(&self) -> bool {
+        self.remaining_eras == 0
+    }
+
+    /// Unlock date as YYYY-MM-DD.
+    pub fn display_date(&self) -> String {
+        if self.date.len() == 8 {
+            format!(
+                "{}-{}-{}",
+                &self.date[..4],
+                &self.date[4..6],
+                &self.date[6..]
+            )
+        } else {
+            self.date.clone()
+        }
+    }
+}
+
+/// Unbonding timeline of direct and pool chunks, soonest first.
+///
+/// `pool_unbonding` holds the pool member's `(era, balance)` pairs, as in
+/// `PoolMembership::unbonding_eras`.
+pub fn unbonding_schedule(
+    direct: &[UnlockChunk],
+    pool_id: Option<u32>,
+    pool_unbonding: &[(EraIndex, Balance)],
+    current_era: u32,
+    current_era_start_ms: u64,
+    era_duration_ms: u64,
+) -> Vec<UnbondingEntry> {
+    let entry = |source, value, era: EraIndex| UnbondingEntry {
+        source,
+        value,
+        era,
+        remaining_eras: era.saturating_sub(current_era),
+        unlock_ms: era_start_ms(era, current_era, current_era_start_ms, era_duration_ms),
+        date: calculate_era_date(era, current_era, current_era_start_ms, era_duration_ms),
+    };
+    let mut schedule: Vec<_> = direct
+        .iter()
+        .map(|chunk| entry(UnbondingSource::Direct, chunk.value, chunk.era))
+        .collect();
+    if let Some(pool_id) = pool_id {
+        schedule.extend(
+            pool_unbonding
+                .iter()
+                .map(|(era, value)| entry(UnbondingSource::Pool(pool_id), *value, *era)),
+        );
+    }
+    schedule.sort_by_key(|e| e.era);
+    schedule
+}
+
 /// Build a display history point for a user from era-level reward/stake inputs.
 pub fn staking_history_point(
     era: u32,
@@ -387,7 +484,25 @@ pub fn staking_history_point(
     user_bonded: Balance,
     total_staked: Balance,
 ) -> StakingHistoryPoint {
-    let reward = estimate_user_reward(era_reward, user_bonded, total_staked);
+    exact_history_point(
+        era,
+        current_era,
+        current_era_start_ms,
+        era_duration_ms,
+        estimate_user_reward(era_reward, user_bonded, total_staked),
+        user_bonded,
+    )
+}
+
+/// Build a display history point for a reward the user actually earned.
+pub fn exact_history_point(
+    era: u32,
+    current_era: u32,
+    current_era_start_ms: u64,
+    era_duration_ms: u64,
+    reward: Balance,
+    user_bonded: Balance,
+) -> StakingHistoryPoint {
     let apy = if user_bonded > 0 {
         get_era_apy(reward, user_bonded, era_duration_ms)
     } else {
@@ -717,4 +832,39 @@ mod tests {
         let apy = display[0].apy.expect("pool APY should be present");
         assert!((apy - 0.12).abs() < 1e-9, "APY was {}", apy);
     }
+
+    #[test]
+    fn era_start_ms_extrapolates_both_ways() {
+        assert_eq!(era_start_ms(10, 10, 1_000_000, 100), 1_000_000);
+        assert_eq!(era_start_ms(8, 10, 1_000_000, 100), 999_800);
+        assert_eq!(era_start_ms(13, 10, 1_000_000, 100), 1_000_300);
+    }
+
+    #[test]
+    fn unbonding_schedule_dates_and_orders_chunks() {
+        // 2024-01-01T00:00:00Z
+        let start_ms = 1_704_067_200_000;
+        let direct = vec![
+            UnlockChunk { value: 5, era: 128 },
+            UnlockChunk { value: 7, era: 99 },
+        ];
+        let schedule = unbonding_schedule(&direct, Some(3), &[(101, 9)], 100, start_ms, MS_PER_DAY);
+
+        assert_eq!(
+            schedule.iter().map(|e| e.era).collect::<Vec<_>>(),
+            vec![99, 101, 128]
+        );
+        assert!(schedule[0].is_withdrawable());
+        assert_eq!(schedule[1].source, UnbondingSource::Pool(3));
+        assert_eq!(schedule[1].remaining_eras, 1);
+        assert_eq!(schedule[1].unlock_ms, start_ms + MS_PER_DAY);
+        assert_eq!(schedule[2].date, "20240129");
+        assert_eq!(schedule[2].display_date(), "2024-01-29");
+    }
+
+    #[test]
+    fn unbonding_schedule_ignores_pool_eras_without_pool() {
+        let schedule = unbonding_schedule(&[], None, &[(101, 9)], 100, 0, MS_PER_DAY);
+        assert!(schedule.is_empty());
+    }
 }
@@ -0,0 +1,466 @@
+//! RPC endpoint probing, ranking and failover.
+//!
+//! [`EndpointManager`] keeps [`EndpointHealth`] for every configured endpoint
+//! of a network. Stale endpoints are probed for latency, best block and
+//! genesis hash before a connection is made, and connections try endpoints
+//! best first. Connection outcomes feed back into the stats, so an endpoint
+//! that dropped us is demoted and the next reconnect fails over to another.
+//!
+//! The stats are plain data: apps seed them from `StakingDb` through
+//! `ConnectionConfig::endpoint_health` and persist
+//! `ChainClient::endpoint_health()` after connecting.
+
+use crate::config::{get_asset_hub_endpoints, get_people_chain_endpoints, get_rpc_endpoints};
+use crate::error::ChainError;
+use futures::future::join_all;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+use stkopt_core::{ChainRole, EndpointHealth, Network, rank_endpoints};
+use subxt::rpcs::RpcClient;
+use subxt::rpcs::client::RpcParams;
+
+/// Maximum time spent probing one endpoint.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Endpoint manager shared by a client and the clients it reconnects as.
+pub type SharedEndpoints = Arc<Mutex<EndpointManager>>;
+
+/// Result of probing one endpoint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EndpointProbe {
+    pub latency_ms: u64,
+    pub best_block: u32,
+    pub genesis_hash: String,
+}
+
+/// Health stats and ranking for all endpoints of a network.
+#[derive(Debug, Clone)]
+pub struct EndpointManager {
+    network: Network,
+    health: HashMap<(ChainRole, String), EndpointHealth>,
+}
+
+impl EndpointManager {
+    /// Create a manager seeded with previously persisted stats.
+    pub fn new(network: Network, seed: Vec<EndpointHealth>) -> Self {
+        let health = seed
+            .into_iter()
+            .map(|h| ((h.role, h.url.clone()), h))
+            .collect();
+        Self { network, health }
+    }
+
+    pub fn shared(self) -> SharedEndpoints {
+        Arc::new(Mutex::new(self))
+    }
+
+    pub fn network(&self) -> &Network {
+        &self.network
+    }
+
+    /// Configured endpoints for a chain, in static order.
+    pub fn configured(&self, role: ChainRole) -> Vec<String> {
+        let urls = match role {
+            ChainRole::AssetHub => get_asset_hub_endpoints(&self.network),
+            ChainRole::Relay => get_rpc_endpoints(&self.network),
+            ChainRole::People => get_people_chain_endpoints(&self.network),
+        };
+        urls.iter().map(|url| url.to_string()).collect()
+    }
+
+    /// Configured endpoints for a chain, best first, without wrong-chain ones.
+    pub fn ranked(&self, role: ChainRole) -> Vec<String> {
+        rank_endpoints(&self.configured(role), &self.role_health(role))
+    }
+
+    /// Configured endpoints whose probe results are missing or stale.
+    pub fn stale(&self, role: ChainRole, now: i64) -> Vec<String> {
+        self.configured(role)
+            .into_iter()
+            .filter(|url| {
+                self.health
+                    .get(&(role, url.clone()))
+                    .is_none_or(|h| h.needs_probe(now))
+            })
+            .collect()
+    }
+
+    fn entry(&mut self, role: ChainRole, url: &str) -> &mut EndpointHealth {
+        self.health
+            .entry((role, url.to_string()))
+            .or_insert_with(|| EndpointHealth::new(role, url))
+    }
+
+    fn role_health(&self, role: ChainRole) -> Vec<EndpointHealth> {
+        self.health
+            .values()
+            .filter(|h| h.role == role)
+            .cloned()
+            .collect()
+    }
+
+    /// Apply a round of probes for one chain.
+    ///
+    /// Block lag is measured against the best block any endpoint reported,
+    /// including endpoints probed earlier. The expected genesis is the
+    /// network's known one; only chains without a known hash (People, custom
+    /// networks that omit it) fall back to the one most endpoints agree on.
+    pub fn apply_probes(
+        &mut self,
+        role: ChainRole,
+        results: Vec<(String, Result<EndpointProbe, ChainError>)>,
+        now: i64,
+    ) {
+        let probes: Vec<(&String, &EndpointProbe)> = results
+            .iter()
+            .filter_map(|(url, result)| result.as_ref().ok().map(|probe| (url, probe)))
+            .collect();
+        let best_block = probes.iter().map(|(_, p)| p.best_block).max().unwrap_or(0);
+
+        let expected_genesis = self.known_genesis(role).or_else(|| {
+            let mut genesis_votes: HashMap<&str, usize> = HashMap::new();
+            for (_, probe) in &probes {
+                *genesis_votes
+                    .entry(probe.genesis_hash.as_str())
+                    .or_default() += 1;
+            }
+            genesis_votes
+                .into_iter()
+                .max_by_key(|(_, votes)| *votes)
+                .map(|(genesis, _)| genesis.to_string())
+        });
+
+        for (url, result) in &results {
+            match result {
+                Ok(probe) => {
+                    let lag = best_block.saturating_sub(probe.best_block);
+                    let genesis_ok = expected_genesis
+                        .as_deref()
+                        .is_some_and(|expected| expected.eq_ignore_ascii_case(&probe.genesis_hash));
+                    if !genesis_ok {
+                        tracing::warn!(
+                            "{} endpoint {} serves genesis {}, ignoring it",
+                            role,
+                            url,
+                            probe.genesis_hash
+                        );
+                    }
+                    self.entry(role, url)
+                        .record_probe(probe.latency_ms, lag, genesis_ok, now);
+                }
+                Err(e) => {
+                    tracing::debug!("Probe of {} endpoint {} failed: {}", role, url, e);
+                    self.entry(role, url).record_probe_failure(now);
+                }
+            }
+        }
+    }
+
+    /// Genesis hash the network is known to have for `role`, if any.
+    fn known_genesis(&self, role: ChainRole) -> Option<String> {
+        let known = match role {
+            ChainRole::AssetHub => self.network.asset_hub_genesis_hash(),
+            ChainRole::Relay => self.network.relay_genesis_hash(),
+            ChainRole::People => "",
+        };
+        (!known.is_empty()).then(|| known.to_string())
+    }
+
+    pub fn record_success(&mut self, role: ChainRole, url: &str) {
+        self.entry(role, url).record_success();
+    }
+
+    pub fn record_failure(&mut self, role: ChainRole, url: &str) {
+        self.entry(role, url).record_failure();
+    }
+
+    /// All stats, for persistence.
+    pub fn health(&self) -> Vec<EndpointHealth> {
+        let mut health: Vec<EndpointHealth> = self.health.values().cloned().collect();
+        health.sort_by(|a, b| (a.role.as_str(), &a.url).cmp(&(b.role.as_str(), &b.url)));
+        health
+    }
+}
+
+pub(crate) fn lock(endpoints: &SharedEndpoints) -> MutexGuard<'_, EndpointManager> {
+    endpoints.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Probe latency, best block and genesis hash of one endpoint.
+pub async fn probe_endpoint(url: &str) -> Result<EndpointProbe, ChainError> {
+    tokio::time::timeout(PROBE_TIMEOUT, probe_endpoint_inner(url))
+        .await
+        .map_err(|_| ChainError::Connection(format!("{url}: probe timed out")))?
+}
+
+async fn probe_endpoint_inner(url: &str) -> Result<EndpointProbe, ChainError> {
+    let client = RpcClient::from_url(url)
+        .await
+        .map_err(|e| ChainError::Connection(format!("{url}: {e}")))?;
+
+    let started = Instant::now();
+    let header: Value = client
+        .request("chain_getHeader", RpcParams::new())
+        .await
+        .map_err(|e| ChainError::Rpc(e.to_string()))?;
+    let latency_ms = started.elapsed().as_millis() as u64;
+
+    let best_block = header
+        .get("number")
+        .and_then(Value::as_str)
+        .and_then(|n| u32::from_str_radix(n.trim_start_matches("0x"), 16).ok())
+        .ok_or_else(|| ChainError::InvalidData(format!("{url}: header without number")))?;
+
+    let mut params = RpcParams::new();
+    params
+        .push(0u32)
+        .map_err(|e| ChainError::Rpc(e.to_string()))?;
+    let genesis_hash: String = client
+        .request("chain_getBlockHash", params)
+        .await
+        .map_err(|e| ChainError::Rpc(e.to_string()))?;
+
+    Ok(EndpointProbe {
+        latency_ms,
+        best_block,
+        genesis_hash,
+    })
+}
+
+/// Probe the stale endpoints of a chain concurrently and record the results.
+pub async fn refresh_endpoint_health(endpoints: &SharedEndpoints, role: ChainRole) {
+    let now = chrono::Utc::now().timestamp();
+    let stale = lock(endpoints).stale(role, now);
+    if stale.is_empty() {
+        return;
+    }
+
+    tracing::debug!("Probing {} {} endpoint(s)", stale.len(), role);
+    let results = join_all(stale.into_iter().map(|url| async move {
+        let result = probe_endpoint(&url).await;
+        (url, result)
+    }))
+    .await;
+    lock(endpoints).apply_probes(role, results, now);
+}
+
+/// Connect to the best reachable endpoint of a chain.
+///
+/// A custom endpoint is tried alone and leaves the stats untouched. Otherwise
+/// stale endpoints are probed and the defaults are tried best first, each
+/// outcome being recorded. Returns the connection and the URL it went to, or
+/// one error per endpoint tried.
+pub(crate) async fn connect_best<T, F, Fut>(
+    endpoints: &SharedEndpoints,
+    role: ChainRole,
+    custom: Option<&str>,
+    connect: F,
+) -> Result<(T, String), Vec<String>>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<T, ChainError>>,
+{
+    let candidates = match custom {
+        Some(custom) => vec![custom.to_string()],
+        None => {
+            refresh_endpoint_health(endpoints, role).await;
+            let ranked = lock(endpoints).ranked(role);
+            tracing::debug!("{} endpoints by health: {:?}", role, ranked);
+            ranked
+        }
+    };
+
+    let mut errors = Vec::new();
+    for url in candidates {
+        tracing::info!("Trying {} via {}", role, url);
+        match connect(url.clone()).await {
+            Ok(connection) => {
+                tracing::info!("Connected to {} via {}", role, url);
+                if custom.is_none() {
+                    lock(endpoints).record_success(role, &url);
+                }
+                return Ok((connection, url));
+            }
+            Err(e) => {
+                tracing::warn!("Failed to connect to {}: {}", url, e);
+                if custom.is_none() {
+                    lock(endpoints).record_failure(role, &url);
+                }
+                errors.push(format!("{}: {}", url, e));
+            }
+        }
+    }
+    Err(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::result_large_err)]
+    fn probe(latency_ms: u64, best_block: u32, genesis: &str) -> Result<EndpointProbe, ChainError> {
+        Ok(EndpointProbe {
+            latency_ms,
+            best_block,
+            genesis_hash: genesis.to_string(),
+        })
+    }
+
+    fn asset_hub_genesis() -> &'static str {
+        Network::Westend.asset_hub_genesis_hash()
+    }
+
+    fn relay_genesis() -> &'static str {
+        Network::Westend.relay_genesis_hash()
+    }
+
+    fn manager() -> EndpointManager {
+        EndpointManager::new(Network::Westend, Vec::new())
+    }
+
+    #[test]
+    fn test_unprobed_ranking_matches_config() {
+        let manager = manager();
+        for role in [ChainRole::AssetHub, ChainRole::Relay, ChainRole::People] {
+            assert_eq!(manager.ranked(role), manager.configured(role));
+        }
+    }
+
+    #[test]
+    fn test_apply_probes_ranks_and_flags_wrong_genesis() {
+        let mut manager = manager();
+        let urls = manager.configured(ChainRole::AssetHub);
+        let results = vec![
+            (urls[0].clone(), probe(300, 100, asset_hub_genesis())),
+            (urls[1].clone(), probe(50, 100, asset_hub_genesis())),
+            (urls[2].clone(), probe(10, 100, "0xbb")),
+            (
+                urls[3].clone(),
+                Err(ChainError::Connection("down".to_string())),
+            ),
+        ];
+        manager.apply_probes(ChainRole::AssetHub, results, 1_000);
+
+        let ranked = manager.ranked(ChainRole::AssetHub);
+        assert_eq!(ranked[0], urls[1]);
+        assert_eq!(ranked[1], urls[0]);
+        assert!(!ranked.contains(&urls[2]), "wrong genesis is dropped");
+        assert_eq!(ranked.last(), Some(&urls[3]), "unreachable is last");
+        assert!(manager.stale(ChainRole::AssetHub, 1_000).is_empty());
+    }
+
+    #[test]
+    fn test_block_lag_measured_against_best_endpoint() {
+        let mut manager = manager();
+        let urls = manager.configured(ChainRole::Relay);
+        manager.apply_probes(
+            ChainRole::Relay,
+            vec![
+                (urls[0].clone(), probe(10, 95, relay_genesis())),
+                (urls[1].clone(), probe(200, 100, relay_genesis())),
+            ],
+            1_000,
+        );
+        let health = manager.health();
+        let lagging = health.iter().find(|h| h.url == urls[0]).unwrap();
+        assert_eq!(lagging.best_block_lag, Some(5));
+        assert_eq!(manager.ranked(ChainRole::Relay)[0], urls[1]);
+    }
+
+    #[test]
+    fn test_known_genesis_beats_majority() {
+        let mut manager = manager();
+        let urls = manager.configured(ChainRole::AssetHub);
+        let results = vec![
+            (urls[0].clone(), probe(10, 100, "0xbb")),
+            (urls[1].clone(), probe(10, 100, "0xbb")),
+            (
+                urls[2].clone(),
+                probe(300, 100, &asset_hub_genesis().to_uppercase()),
+            ),
+        ];
+        manager.apply_probes(ChainRole::AssetHub, results, 1_000);
+
+        let ranked = manager.ranked(ChainRole::AssetHub);
+        assert_eq!(ranked[0], urls[2], "the known genesis wins, in any case");
+        assert!(!ranked.contains(&urls[0]));
+        assert!(!ranked.contains(&urls[1]));
+    }
+
+    #[test]
+    fn test_failures_trigger_failover() {
+        let mut manager = manager();
+        let urls = manager.configured(ChainRole::AssetHub);
+        let results = urls
+            .iter()
+            .map(|url| (url.clone(), probe(100, 10, asset_hub_genesis())))
+            .collect();
+        manager.apply_probes(ChainRole::AssetHub, results, 1_000);
+        assert_eq!(manager.ranked(ChainRole::AssetHub)[0], urls[0]);
+
+        manager.record_failure(ChainRole::AssetHub, &urls[0]);
+        assert_eq!(manager.ranked(ChainRole::AssetHub)[0], urls[1]);
+    }
+
+    #[tokio::test]
+    async fn test_connect_best_fails_over_and_records() {
+        let mut seeded = manager();
+        let urls = seeded.configured(ChainRole::Relay);
+        let fresh = urls
+            .iter()
+            .map(|url| (url.clone(), probe(100, 10, relay_genesis())))
+            .collect();
+        seeded.apply_probes(ChainRole::Relay, fresh, chrono::Utc::now().timestamp());
+        let endpoints = seeded.shared();
+
+        let down = urls[0].clone();
+        let (connected, url) = connect_best(&endpoints, ChainRole::Relay, None, |url| {
+            let down = down.clone();
+            async move {
+                if url == down {
+                    Err(ChainError::Connection("refused".to_string()))
+                } else {
+                    Ok(url)
+                }
+            }
+        })
+        .await
+        .unwrap();
+        assert_eq!(connected, urls[1]);
+        assert_eq!(url, urls[1]);
+
+        let health = lock(&endpoints).health();
+        let stats = |url: &str| health.iter().find(|h| h.url == url).unwrap().clone();
+        assert_eq!(stats(&urls[0]).failures, 1);
+        assert_eq!(stats(&urls[1]).successes, 1);
+
+        // Custom endpoints are neither probed nor recorded
+        let errors = connect_best(
+            &endpoints,
+            ChainRole::Relay,
+            Some("ws://custom"),
+            |_| async { Err::<(), _>(ChainError::Connection("refused".to_string())) },
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(lock(&endpoints).health(), health);
+    }
+
+    #[test]
+    fn test_seeded_stats_survive_roundtrip() {
+        let mut manager = manager();
+        let url = manager.configured(ChainRole::People)[0].clone();
+        manager.record_success(ChainRole::People, &url);
+        let seed = manager.health();
+        let restored = EndpointManager::new(Network::Westend, seed.clone());
+        assert_eq!(restored.health(), seed);
+        // Successes alone do not count as a probe
+        assert_eq!(
+            restored.stale(ChainRole::People, 0),
+            restored.configured(ChainRole::People)
+        );
+    }
+}
@@ -22,6 +22,9 @@ pub enum ChainError {
     #[error("Subxt client block error: {0}")]
     SubxtClientBlock(#[from] subxt::error::OnlineClientAtBlockError),
 
+    #[error("Subxt events error: {0}")]
+    SubxtEvents(#[from] subxt::error::EventsError),
+
     #[error("Subxt storage error: {0}")]
     SubxtStorage(#[from] subxt::error::StorageError),
 
@@ -45,4 +48,16 @@ pub enum ChainError {
 
     #[error("Invalid address: {0}")]
     InvalidAddress(String),
+
+    #[error("QR export error: {0}")]
+    QrExport(String),
+
+    #[error("Indexer error: {0}")]
+    Indexer(String),
+
+    #[error("Recording error: {0}")]
+    Recording(String),
+
+    #[error("Storage proof rejected: {0}")]
+    Proof(#[from] crate::proof::ProofError),
 }
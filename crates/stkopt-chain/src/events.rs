@@ -0,0 +1,422 @@
+//! Post-inclusion event decoding for submitted extrinsics.
+//!
+//! Inclusion in a block does not mean the call succeeded: a `batch_all` may
+//! revert or `Staking.bond` may fail with `InsufficientBond`. This module
+//! turns the extrinsic's events into a [`TxOutcome`], resolving
+//! `DispatchError::Module` to the pallet error name and docs from metadata.
+
+use subxt::dynamic::{At, Value};
+use subxt::ext::scale_value::ValueDef;
+
+/// Pallets whose events are shown to the user after submission.
+const REPORTED_PALLETS: &[&str] = &[
+    "Staking",
+    "NominationPools",
+    "VoterList",
+    "Utility",
+    "Proxy",
+];
+
+/// Field names carrying the balance moved by a staking event.
+const AMOUNT_FIELDS: &[&str] = &["amount", "bonded", "payout", "balance"];
+
+/// A decoded event emitted by a submitted extrinsic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxEvent {
+    /// Pallet name (e.g. `Staking`).
+    pub pallet: String,
+    /// Event name (e.g. `Bonded`).
+    pub variant: String,
+    /// Balance moved by the event, if it carries one.
+    pub amount: Option<u128>,
+}
+
+impl TxEvent {
+    /// `Pallet.Event` name.
+    pub fn name(&self) -> String {
+        format!("{}.{}", self.pallet, self.variant)
+    }
+
+    /// Whether the event is worth showing (staking-related, not fees/deposits).
+    pub fn is_reported(&self) -> bool {
+        REPORTED_PALLETS.contains(&self.pallet.as_str())
+    }
+}
+
+/// Why an included extrinsic failed to dispatch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DispatchFailure {
+    /// Pallet name for module errors (e.g. `Staking`).
+    pub pallet: Option<String>,
+    /// Error name (e.g. `InsufficientBond`, `BadOrigin`, `Token::FundsUnavailable`).
+    pub error: String,
+    /// Error documentation from the runtime metadata.
+    pub docs: String,
+    /// Index of the failing call when a `Utility.batch` was interrupted.
+    pub batch_index: Option<u32>,
+}
+
+impl std::fmt::Display for DispatchFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(index) = self.batch_index {
+            write!(f, "batch call #{} failed: ", index)?;
+        }
+        match &self.pallet {
+            Some(pallet) => write!(f, "{}.{}", pallet, self.error)?,
+            None => write!(f, "{}", self.error)?,
+        }
+        if !self.docs.is_empty() {
+            write!(f, " ({})", self.docs)?;
+        }
+        Ok(())
+    }
+}
+
+/// Metadata entry for a pallet error: pallet name, error name and docs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleErrorInfo {
+    pub pallet: String,
+    pub error: String,
+    pub docs: String,
+}
+
+/// Result of an included extrinsic, decoded from its events.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TxOutcome {
+    /// Events emitted by the extrinsic, in order.
+    pub events: Vec<TxEvent>,
+    /// Set when the extrinsic (or a call inside a batch) failed.
+    pub failure: Option<DispatchFailure>,
+}
+
+impl TxOutcome {
+    /// Whether every dispatched call succeeded.
+    pub fn is_success(&self) -> bool {
+        self.failure.is_none()
+    }
+
+    /// Staking-related events worth showing to the user.
+    pub fn reported_events(&self) -> impl Iterator<Item = &TxEvent> {
+        self.events.iter().filter(|event| event.is_reported())
+    }
+
+    /// One-line summary: the failure, or the reported event names.
+    pub fn summary(&self) -> String {
+        if let Some(failure) = &self.failure {
+            return format!("Failed: {}", failure);
+        }
+        let names: Vec<String> = self.reported_events().map(TxEvent::name).collect();
+        if names.is_empty() {
+            "Succeeded".to_string()
+        } else {
+            format!("Succeeded: {}", names.join(", "))
+        }
+    }
+}
+
+/// Raw event as read from the chain, before interpretation.
+pub(crate) struct RawEvent {
+    pub pallet: String,
+    pub variant: String,
+    pub fields: Value,
+}
+
+/// Build a [`TxOutcome`] from an extrinsic's events.
+///
+/// `lookup` maps a `(pallet_index, error_index)` pair to its metadata entry.
+pub(crate) fn outcome_from_events(
+    events: Vec<RawEvent>,
+    lookup: impl Fn(u8, u8) -> Option<ModuleErrorInfo>,
+) -> TxOutcome {
+    let mut failure = None;
+    let mut decoded = Vec::with_capacity(events.len());
+    for event in events {
+        match (event.pallet.as_str(), event.variant.as_str()) {
+            ("System", "ExtrinsicFailed") => {
+                if let Some(error) = field(&event.fields, "dispatch_error", 0) {
+                    failure = Some(decode_dispatch_error(error, &lookup));
+                }
+            }
+            ("Utility", "BatchInterrupted") if failure.is_none() => {
+                if let Some(error) = field(&event.fields, "error", 1) {
+                    let mut interrupted = decode_dispatch_error(error, &lookup);
+                    interrupted.batch_index = field(&event.fields, "index", 0)
+                        .and_then(Value::as_u128)
+                        .map(|index| index as u32);
+                    failure = Some(interrupted);
+                }
+            }
+            _ => {}
+        }
+        let amount = AMOUNT_FIELDS
+            .iter()
+            .find_map(|name| event.fields.at(*name).and_then(Value::as_u128));
+        decoded.push(TxEvent {
+            pallet: event.pallet,
+            variant: event.variant,
+            amount,
+        });
+    }
+    TxOutcome {
+        events: decoded,
+        failure,
+    }
+}
+
+/// Decode a `sp_runtime::DispatchError` value.
+pub(crate) fn decode_dispatch_error(
+    value: &Value,
+    lookup: impl Fn(u8, u8) -> Option<ModuleErrorInfo>,
+) -> DispatchFailure {
+    let ValueDef::Variant(variant) = &value.value else {
+        return DispatchFailure {
+            pallet: None,
+            error: "Unknown".to_string(),
+            docs: String::new(),
+            batch_index: None,
+        };
+    };
+
+    if variant.name == "Module" {
+        // `Module(ModuleError { index, error })`, or the fields inline.
+        let module = match value.at(0) {
+            Some(inner) if matches!(inner.value, ValueDef::Composite(_)) => inner,
+            _ => value,
+        };
+        let index = field(module, "index", 0).and_then(first_byte);
+        let error = field(module, "error", 1).and_then(first_byte);
+        if let (Some(index), Some(error)) = (index, error) {
+            return match lookup(index, error) {
+                Some(info) => DispatchFailure {
+                    pallet: Some(info.pallet),
+                    error: info.error,
+                    docs: info.docs,
+                    batch_index: None,
+                },
+                None => DispatchFailure {
+                    pallet: Some(format!("Pallet #{}", index)),
+                    error: format!("Error #{}", error),
+                    docs: String::new(),
+                    batch_index: None,
+                },
+            };
+        }
+    }
+
+    // `Token(FundsUnavailable)`, `Arithmetic(Overflow)`, ... carry an inner variant.
+    let error = match value.at(0).map(|inner| &inner.value) {
+        Some(ValueDef::Variant(inner)) => format!("{}::{}", variant.name, inner.name),
+        _ => variant.name.clone(),
+    };
+    DispatchFailure {
+        pallet: None,
+        error,
+        docs: String::new(),
+        batch_index: None,
+    }
+}
+
+/// Resolve a module error from runtime metadata.
+pub(crate) fn module_error_info(
+    metadata: &subxt::Metadata,
+    pallet_index: u8,
+    error_index: u8,
+) -> Option<ModuleErrorInfo> {
+    let pallet = metadata.pallet_by_error_index(pallet_index)?;
+    let variant = pallet.error_variant_by_index(error_index)?;
+    Some(ModuleErrorInfo {
+        pallet: pallet.name().to_string(),
+        error: variant.name.clone(),
+        docs: join_docs(&variant.docs),
+    })
+}
+
+/// Join metadata doc lines into a single sentence-like string.
+pub(crate) fn join_docs<S: AsRef<str>>(docs: &[S]) -> String {
+    docs.iter()
+        .map(|line| line.as_ref().trim())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub(crate) fn field<'a>(value: &'a Value, name: &str, index: usize) -> Option<&'a Value> {
+    value.at(name).or_else(|| value.at(index))
+}
+
+/// `u8`, or the first byte of a `[u8; N]` (module error indices are `[u8; 4]`).
+fn first_byte(value: &Value) -> Option<u8> {
+    value
+        .as_u128()
+        .or_else(|| value.at(0).and_then(Value::as_u128))
+        .and_then(|byte| u8::try_from(byte).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn staking_lookup(pallet: u8, error: u8) -> Option<ModuleErrorInfo> {
+        (pallet == 89 && error == 23).then(|| ModuleErrorInfo {
+            pallet: "Staking".to_string(),
+            error: "InsufficientBond".to_string(),
+            docs: "Cannot bond, nominate or validate with value less than the minimum.".to_string(),
+        })
+    }
+
+    fn module_error(index: u8, error: u8) -> Value {
+        Value::unnamed_variant(
+            "Module",
+            [Value::named_composite([
+                ("index", Value::u128(index as u128)),
+                (
+                    "error",
+                    Value::unnamed_composite([
+                        Value::u128(error as u128),
+                        Value::u128(0),
+                        Value::u128(0),
+                        Value::u128(0),
+                    ]),
+                ),
+            ])],
+        )
+    }
+
+    fn raw(pallet: &str, variant: &str, fields: Value) -> RawEvent {
+        RawEvent {
+            pallet: pallet.to_string(),
+            variant: variant.to_string(),
+            fields,
+        }
+    }
+
+    #[test]
+    fn test_module_error_resolved_from_metadata() {
+        let failure = decode_dispatch_error(&module_error(89, 23), staking_lookup);
+        assert_eq!(failure.pallet.as_deref(), Some("Staking"));
+        assert_eq!(failure.error, "InsufficientBond");
+        assert!(
+            failure
+                .to_string()
+                .starts_with("Staking.InsufficientBond (")
+        );
+    }
+
+    #[test]
+    fn test_unknown_module_error_falls_back_to_indices() {
+        let failure = decode_dispatch_error(&module_error(7, 2), staking_lookup);
+        assert_eq!(failure.to_string(), "Pallet #7.Error #2");
+    }
+
+    #[test]
+    fn test_nested_dispatch_errors() {
+        let token = Value::unnamed_variant(
+            "Token",
+            [Value::unnamed_variant(
+                "FundsUnavailable",
+                Vec::<Value>::new(),
+            )],
+        );
+        assert_eq!(
+            decode_dispatch_error(&token, staking_lookup).error,
+            "Token::FundsUnavailable"
+        );
+        let bad_origin = Value::unnamed_variant("BadOrigin", Vec::<Value>::new());
+        assert_eq!(
+            decode_dispatch_error(&bad_origin, staking_lookup).to_string(),
+            "BadOrigin"
+        );
+    }
+
+    #[test]
+    fn test_outcome_success_with_staking_events() {
+        let outcome = outcome_from_events(
+            vec![
+                raw(
+                    "Balances",
+                    "Withdraw",
+                    Value::named_composite([("amount", Value::u128(5))]),
+                ),
+                raw(
+                    "Staking",
+                    "Bonded",
+                    Value::named_composite([
+                        ("stash", Value::from_bytes([1u8; 32])),
+                        ("amount", Value::u128(10_000_000_000)),
+                    ]),
+                ),
+                raw(
+                    "System",
+                    "ExtrinsicSuccess",
+                    Value::named_composite([("dispatch_info", Value::unnamed_composite([]))]),
+                ),
+            ],
+            staking_lookup,
+        );
+        assert!(outcome.is_success());
+        assert_eq!(outcome.events.len(), 3);
+        let reported: Vec<_> = outcome.reported_events().collect();
+        assert_eq!(reported.len(), 1);
+        assert_eq!(reported[0].name(), "Staking.Bonded");
+        assert_eq!(reported[0].amount, Some(10_000_000_000));
+        assert_eq!(outcome.summary(), "Succeeded: Staking.Bonded");
+    }
+
+    #[test]
+    fn test_outcome_extrinsic_failed() {
+        let outcome = outcome_from_events(
+            vec![raw(
+                "System",
+                "ExtrinsicFailed",
+                Value::named_composite([
+                    ("dispatch_error", module_error(89, 23)),
+                    ("dispatch_info", Value::unnamed_composite([])),
+                ]),
+            )],
+            staking_lookup,
+        );
+        assert!(!outcome.is_success());
+        assert!(
+            outcome
+                .summary()
+                .starts_with("Failed: Staking.InsufficientBond")
+        );
+    }
+
+    #[test]
+    fn test_outcome_batch_interrupted() {
+        let outcome = outcome_from_events(
+            vec![
+                raw(
+                    "Utility",
+                    "BatchInterrupted",
+                    Value::named_composite([
+                        ("index", Value::u128(1)),
+                        ("error", module_error(89, 23)),
+                    ]),
+                ),
+                raw(
+                    "System",
+                    "ExtrinsicSuccess",
+                    Value::named_composite([("dispatch_info", Value::unnamed_composite([]))]),
+                ),
+            ],
+            staking_lookup,
+        );
+        let failure = outcome.failure.expect("batch failure");
+        assert_eq!(failure.batch_index, Some(1));
+        assert!(
+            failure
+                .to_string()
+                .starts_with("batch call #1 failed: Staking.InsufficientBond")
+        );
+    }
+
+    #[test]
+    fn test_join_docs_skips_blank_lines() {
+        assert_eq!(
+            join_docs(&["Not a controller account.", "", "  See docs. "]),
+            "Not a controller account. See docs."
+        );
+    }
+}
@@ -0,0 +1,388 @@
+//! Hybrid trust: RPC data checked against the light client.
+//!
+//! In [`ConnectionMode::Hybrid`](crate::ConnectionMode::Hybrid) data comes
+//! from a public RPC endpoint, which serves the historical eras and bulk
+//! queries the light client cannot, but the endpoint is not trusted. The
+//! Asset Hub connection goes through a local [`MockRpcServer`] proxy started
+//! with [`MockRpcServer::start_verified`]. It asks the endpoint for
+//! `state_getReadProof` instead of each value and checks the proof against
+//! the state root of a header the embedded smoldot client finalized. The
+//! finalized head and headers also come from smoldot, so queries run at
+//! blocks the light client can vouch for. Blocks older than the light
+//! client's window are linked back to its finalized head through
+//! `System.BlockHash`, which each block's state keeps for the blocks before
+//! it: an ancestor's hash is read with a proof at a verified block up to
+//! [`BLOCK_HASH_COUNT`] blocks later, and the endpoint's header for it must
+//! hash to it. Its state root then proves historical reads the same way.
+//! Transactions are submitted and watched through the light client.
+//!
+//! Still taken on trust: key listings (`state_getKeysPaged`, though the
+//! values read for those keys are proven), runtime API calls (`state_call`)
+//! and metadata.
+//!
+//! [`MockRpcServer`]: crate::MockRpcServer
+//! [`MockRpcServer::start_verified`]: crate::MockRpcServer::start_verified
+
+use crate::error::ChainError;
+use crate::proof::ReadProof;
+use parity_scale_codec::{Compact, Encode};
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, PoisonError};
+use subxt::rpcs::RpcClient;
+use subxt::rpcs::client::{RpcParams, RpcSubscription};
+
+/// Verified headers kept before the cache is cleared.
+const MAX_CACHED_HEADERS: usize = 1024;
+
+/// Blocks `System.BlockHash` is assumed to reach back, as on the Polkadot
+/// and Kusama system chains. Halved while a chain keeps fewer.
+const BLOCK_HASH_COUNT: u64 = 4096;
+
+/// Header fields needed to prove reads at a block and walk back from it.
+#[derive(Debug, Clone)]
+struct VerifiedHeader {
+    number: u64,
+    parent_hash: String,
+    state_root: [u8; 32],
+}
+
+/// `state_getReadProof` response.
+#[derive(Debug, Deserialize)]
+struct ReadProofResponse {
+    proof: Vec<String>,
+}
+
+/// Proves storage read from an untrusted endpoint with a trusted one.
+pub(crate) struct ProofVerifier {
+    /// RPC endpoint serving data and proofs.
+    upstream: RpcClient,
+    /// Light client providing finalized headers.
+    trusted: RpcClient,
+    /// Headers by block hash, from the light client or linked to its
+    /// finalized head.
+    headers: Mutex<HashMap<String, VerifiedHeader>>,
+    /// Blocks back `System.BlockHash` is read in one step.
+    hop: AtomicU64,
+}
+
+impl ProofVerifier {
+    pub(crate) fn new(upstream: RpcClient, trusted: RpcClient) -> Self {
+        Self {
+            upstream,
+            trusted,
+            headers: Mutex::new(HashMap::new()),
+            hop: AtomicU64::new(BLOCK_HASH_COUNT),
+        }
+    }
+
+    /// The untrusted endpoint, for requests that are forwarded as-is.
+    pub(crate) fn upstream(&self) -> &RpcClient {
+        &self.upstream
+    }
+
+    /// Hash of the latest block the light client finalized.
+    pub(crate) async fn finalized_head(&self) -> Result<String, ChainError> {
+        self.trusted
+            .request::<String>("chain_getFinalizedHead", RpcParams::new())
+            .await
+            .map_err(|e| ChainError::LightClient(e.to_string()))
+    }
+
+    /// Header of `block` (default: the finalized head).
+    ///
+    /// From the light client, or for blocks it no longer holds, the
+    /// endpoint's header once the block is linked to the finalized head.
+    pub(crate) async fn header(&self, block: Option<&str>) -> Result<Value, ChainError> {
+        let block = match block {
+            Some(block) => block.to_string(),
+            None => self.finalized_head().await?,
+        };
+        if let Some(header) = self.trusted_header(&block).await {
+            return Ok(header);
+        }
+        let header = self.upstream_header(&block).await?;
+        let number = header_number(&header)?;
+        if self.block_hash(number).await?.as_deref() != Some(block.as_str()) {
+            return Err(ChainError::InvalidData(format!(
+                "Block {block} is not in the chain the light client finalized"
+            )));
+        }
+        Ok(header)
+    }
+
+    /// Hash of block `number`.
+    ///
+    /// Asks the light client first. Older blocks are reached from the
+    /// nearest verified descendant, by a proven `System.BlockHash` read or,
+    /// one block back, its parent hash.
+    pub(crate) async fn block_hash(&self, number: u64) -> Result<Option<String>, ChainError> {
+        let trusted = self
+            .trusted
+            .request::<Option<String>>("chain_getBlockHash", params(&[Value::from(number)])?)
+            .await;
+        if let Ok(Some(hash)) = trusted {
+            return Ok(Some(hash));
+        }
+
+        let (mut hash, mut header) = self.anchor(number).await?;
+        if header.number < number {
+            // Not finalized by the light client yet
+            return Ok(None);
+        }
+        while header.number > number {
+            let step = (header.number - number).min(self.hop.load(Ordering::Relaxed));
+            let target = header.number - step;
+            let ancestor = if step == 1 {
+                Some(header.parent_hash.clone())
+            } else {
+                let key = block_hash_key(target)?;
+                let values = self.read_at(&[&key], &hash, &header.state_root).await?;
+                values.into_iter().next().flatten()
+            };
+            let Some(ancestor) = ancestor else {
+                // The chain keeps fewer block hashes than assumed
+                self.hop.store((step / 2).max(1), Ordering::Relaxed);
+                continue;
+            };
+            let verified = verified_header(&self.upstream_header(&ancestor).await?)?;
+            if verified.number != target {
+                return Err(ChainError::InvalidData(format!(
+                    "Block {ancestor} is #{}, not #{target}",
+                    verified.number
+                )));
+            }
+            self.remember(&ancestor, verified.clone());
+            hash = ancestor;
+            header = verified;
+        }
+        Ok(Some(hash))
+    }
+
+    /// Submit an extrinsic through the light client and watch its status.
+    pub(crate) async fn submit_and_watch(
+        &self,
+        extrinsic: &[Value],
+    ) -> Result<RpcSubscription<Value>, ChainError> {
+        self.trusted
+            .subscribe(
+                "author_submitAndWatchExtrinsic",
+                params(extrinsic)?,
+                "author_unwatchExtrinsic",
+            )
+            .await
+            .map_err(|e| ChainError::LightClient(e.to_string()))
+    }
+
+    /// Verified header with the lowest number at or above `number`,
+    /// falling back to the finalized head.
+    async fn anchor(&self, number: u64) -> Result<(String, VerifiedHeader), ChainError> {
+        let cached = self
+            .headers()
+            .iter()
+            .filter(|(_, header)| header.number >= number)
+            .min_by_key(|(_, header)| header.number)
+            .map(|(hash, header)| (hash.clone(), header.clone()));
+        if let Some(anchor) = cached {
+            return Ok(anchor);
+        }
+        let head = self.finalized_head().await?;
+        let header = self.trusted_header(&head).await.ok_or_else(|| {
+            ChainError::LightClient(format!("Block {head} is not known to the light client"))
+        })?;
+        let header = verified_header(&header)?;
+        self.remember(&head, header.clone());
+        Ok((head, header))
+    }
+
+    /// Header of `block` from the light client, if it still holds it.
+    async fn trusted_header(&self, block: &str) -> Option<Value> {
+        let Ok(params) = params(&[Value::String(block.to_string())]) else {
+            return None;
+        };
+        self.trusted
+            .request::<Option<Value>>("chain_getHeader", params)
+            .await
+            .ok()
+            .flatten()
+    }
+
+    /// Header of `block` from the endpoint, checked to hash to `block`.
+    async fn upstream_header(&self, block: &str) -> Result<Value, ChainError> {
+        let header = self
+            .upstream
+            .request::<Option<Value>>(
+                "chain_getHeader",
+                params(&[Value::String(block.to_string())])?,
+            )
+            .await
+            .map_err(|e| ChainError::Rpc(format!("chain_getHeader: {e}")))?
+            .ok_or_else(|| ChainError::InvalidData(format!("No header for block {block}")))?;
+        if decode_hex(block)? != header_hash(&header)? {
+            return Err(ChainError::InvalidData(format!(
+                "Header served for {block} does not hash to it"
+            )));
+        }
+        Ok(header)
+    }
+
+    async fn state_root(&self, block: &str) -> Result<[u8; 32], ChainError> {
+        let cached = self.headers().get(block).map(|header| header.state_root);
+        if let Some(root) = cached {
+            return Ok(root);
+        }
+        let header = verified_header(&self.header(Some(block)).await?)?;
+        let root = header.state_root;
+        self.remember(block, header);
+        Ok(root)
+    }
+
+    /// Read `keys` at `at` (default: the finalized head), proving each value.
+    ///
+    /// Returns the block read at and the hex values, `None` for absent keys.
+    pub(crate) async fn read(
+        &self,
+        keys: &[&str],
+        at: Option<&str>,
+    ) -> Result<(String, Vec<Option<String>>), ChainError> {
+        let block = match at {
+            Some(at) => at.to_string(),
+            None => self.finalized_head().await?,
+        };
+        let root = self.state_root(&block).await?;
+        let values = self.read_at(keys, &block, &root).await?;
+        Ok((block, values))
+    }
+
+    /// Read `keys` at `block`, proving each value against `root`.
+    #[allow(clippy::result_large_err)]
+    async fn read_at(
+        &self,
+        keys: &[&str],
+        block: &str,
+        root: &[u8; 32],
+    ) -> Result<Vec<Option<String>>, ChainError> {
+        let response = self
+            .upstream
+            .request::<ReadProofResponse>(
+                "state_getReadProof",
+                params(&[Value::from(keys.to_vec()), Value::String(block.to_string())])?,
+            )
+            .await
+            .map_err(|e| ChainError::Rpc(format!("state_getReadProof: {e}")))?;
+        let proof = ReadProof::new(
+            response
+                .proof
+                .iter()
+                .map(|node| decode_hex(node))
+                .collect::<Result<Vec<_>, _>>()?,
+        );
+        keys.iter()
+            .map(|key| {
+                let value = proof.read(root, &decode_hex(key)?)?;
+                Ok(value.map(|value| format!("0x{}", hex::encode(value))))
+            })
+            .collect()
+    }
+
+    fn remember(&self, block: &str, header: VerifiedHeader) {
+        let mut headers = self.headers();
+        if headers.len() >= MAX_CACHED_HEADERS {
+            headers.clear();
+        }
+        headers.insert(block.to_string(), header);
+    }
+
+    fn headers(&self) -> std::sync::MutexGuard<'_, HashMap<String, VerifiedHeader>> {
+        self.headers.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn params(values: &[Value]) -> Result<RpcParams, ChainError> {
+    let mut params = RpcParams::new();
+    for value in values {
+        params
+            .push(value)
+            .map_err(|e| ChainError::Rpc(e.to_string()))?;
+    }
+    Ok(params)
+}
+
+#[allow(clippy::result_large_err)]
+fn verified_header(header: &Value) -> Result<VerifiedHeader, ChainError> {
+    let parent_hash = header
+        .get("parentHash")
+        .and_then(Value::as_str)
+        .ok_or_else(|| ChainError::InvalidData("Header has no parent hash".to_string()))?;
+    let state_root = header
+        .get("stateRoot")
+        .and_then(Value::as_str)
+        .and_then(|root| decode_hex(root).ok())
+        .and_then(|root| <[u8; 32]>::try_from(root).ok())
+        .ok_or_else(|| ChainError::InvalidData("Header has no valid state root".to_string()))?;
+    Ok(VerifiedHeader {
+        number: header_number(header)?,
+        parent_hash: parent_hash.to_string(),
+        state_root,
+    })
+}
+
+/// Storage key of `System.BlockHash` for block `number`.
+#[allow(clippy::result_large_err)]
+pub(crate) fn block_hash_key(number: u64) -> Result<String, ChainError> {
+    let number = u32::try_from(number)
+        .map_err(|_| ChainError::InvalidData(format!("Block number {number} out of range")))?
+        .to_le_bytes();
+    let mut key = sp_crypto_hashing::twox_128(b"System").to_vec();
+    key.extend(sp_crypto_hashing::twox_128(b"BlockHash"));
+    key.extend(sp_crypto_hashing::twox_64(&number));
+    key.extend(number);
+    Ok(format!("0x{}", hex::encode(key)))
+}
+
+#[allow(clippy::result_large_err)]
+fn header_number(header: &Value) -> Result<u64, ChainError> {
+    header
+        .get("number")
+        .and_then(Value::as_str)
+        .and_then(|number| u64::from_str_radix(number.trim_start_matches("0x"), 16).ok())
+        .ok_or_else(|| ChainError::InvalidData("Header has no valid number".to_string()))
+}
+
+/// Blake2-256 hash of a JSON header's SCALE encoding.
+#[allow(clippy::result_large_err)]
+fn header_hash(header: &Value) -> Result<Vec<u8>, ChainError> {
+    let field = |name: &str| {
+        header
+            .get(name)
+            .and_then(Value::as_str)
+            .ok_or_else(|| ChainError::InvalidData(format!("Header has no {name}")))
+            .and_then(decode_hex)
+    };
+    let logs = header
+        .pointer("/digest/logs")
+        .and_then(Value::as_array)
+        .ok_or_else(|| ChainError::InvalidData("Header has no digest".to_string()))?;
+
+    let mut encoded = field("parentHash")?;
+    Compact(header_number(header)?).encode_to(&mut encoded);
+    encoded.extend(field("stateRoot")?);
+    encoded.extend(field("extrinsicsRoot")?);
+    Compact(logs.len() as u32).encode_to(&mut encoded);
+    for log in logs {
+        let log = log
+            .as_str()
+            .ok_or_else(|| ChainError::InvalidData("Invalid digest item".to_string()))?;
+        encoded.extend(decode_hex(log)?);
+    }
+    Ok(sp_crypto_hashing::blake2_256(&encoded).to_vec())
+}
+
+#[allow(clippy::result_large_err)]
+fn decode_hex(value: &str) -> Result<Vec<u8>, ChainError> {
+    hex::decode(value.trim_start_matches("0x"))
+        .map_err(|e| ChainError::InvalidData(format!("Invalid hex '{value}': {e}")))
+}
@@ -0,0 +1,844 @@
+//! Staking indexer client for historical era data.
+//!
+//! Light clients only hold recent state, so per-era rewards, exposures and
+//! validator stats for history are fetched from the indexer configured by
+//! [`get_staking_indexer_url`]. The indexer is not trusted blindly: a sample of
+//! every response is checked against on-chain state before it is used.
+//!
+//! Endpoints (amounts are decimal strings, addresses SS58):
+//! - `GET {base}/eras?from={era}&to={era}` - era summaries
+//!   (`era`, `validatorReward`, `totalStake`).
+//! - `GET {base}/eras/{era}` - one era with `totalPoints` and a `validators`
+//!   list (`address`, `points`, `commission`, `own`, `total`, `nominatorCount`).
+//! - `GET {base}/accounts/{address}/payouts?fromEra={era}&toEra={era}` -
+//!   `Staking.Rewarded` and `NominationPools.PaidOut` events of an account
+//!   (`blockNumber`, `eventIndex`, `source`, `era`, `validator`, `poolId`,
+//!   `amount`).
+
+use crate::{
+    ChainClient, ChainError, ValidatorApyData, ValidatorEnrichmentSource, ValidatorExposure,
+    ValidatorPoints, get_staking_indexer_url,
+};
+use serde::{Deserialize, Deserializer};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::time::Duration;
+use stkopt_core::{Balance, EraIndex, Network, PayoutEvent, PayoutSource};
+use subxt::utils::AccountId32;
+
+/// Request timeout for indexer queries.
+const INDEXER_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Number of eras (and validators per era) compared against chain state.
+pub const DEFAULT_SPOT_CHECK_SAMPLE: usize = 3;
+
+/// Per-era totals from the indexer.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexedEraSummary {
+    pub era: EraIndex,
+    #[serde(deserialize_with = "deserialize_balance")]
+    pub validator_reward: Balance,
+    #[serde(deserialize_with = "deserialize_balance")]
+    pub total_stake: Balance,
+}
+
+/// One validator's stats for an era from the indexer.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexedValidatorEra {
+    #[serde(deserialize_with = "deserialize_account")]
+    pub address: AccountId32,
+    pub points: u32,
+    /// Commission as a fraction (0.0 - 1.0).
+    #[serde(default)]
+    pub commission: f64,
+    #[serde(deserialize_with = "deserialize_balance")]
+    pub own: Balance,
+    #[serde(deserialize_with = "deserialize_balance")]
+    pub total: Balance,
+    #[serde(default)]
+    pub nominator_count: u32,
+}
+
+/// Full era data from the indexer: totals plus per-validator exposures and points.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexedEra {
+    pub era: EraIndex,
+    #[serde(deserialize_with = "deserialize_balance")]
+    pub validator_reward: Balance,
+    #[serde(deserialize_with = "deserialize_balance")]
+    pub total_stake: Balance,
+    pub total_points: u32,
+    #[serde(default)]
+    pub validators: Vec<IndexedValidatorEra>,
+}
+
+impl IndexedEra {
+    /// Totals for this era.
+    pub fn summary(&self) -> IndexedEraSummary {
+        IndexedEraSummary {
+            era: self.era,
+            validator_reward: self.validator_reward,
+            total_stake: self.total_stake,
+        }
+    }
+
+    /// Convert to the shape used by validator APY enrichment.
+    pub fn apy_data(&self) -> ValidatorApyData {
+        ValidatorApyData {
+            era: self.era,
+            era_reward: self.validator_reward,
+            total_points: self.total_points,
+            points: self
+                .validators
+                .iter()
+                .map(|validator| ValidatorPoints {
+                    address: validator.address,
+                    points: validator.points,
+                })
+                .collect(),
+            exposures: self
+                .validators
+                .iter()
+                .map(|validator| ValidatorExposure {
+                    address: validator.address,
+                    own: validator.own,
+                    total: validator.total,
+                    nominator_count: validator.nominator_count,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// A reward payout event of one account from the indexer.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexedPayout {
+    pub block_number: u64,
+    pub event_index: u32,
+    pub source: PayoutSource,
+    #[serde(default)]
+    pub era: Option<EraIndex>,
+    /// SS58 address of the validator paid out.
+    #[serde(default)]
+    pub validator: Option<String>,
+    #[serde(default)]
+    pub pool_id: Option<u32>,
+    #[serde(deserialize_with = "deserialize_balance")]
+    pub amount: Balance,
+}
+
+impl From<IndexedPayout> for PayoutEvent {
+    fn from(payout: IndexedPayout) -> Self {
+        PayoutEvent {
+            source: payout.source,
+            block_number: payout.block_number,
+            event_index: payout.event_index,
+            era: payout.era,
+            validator: payout.validator,
+            pool_id: payout.pool_id,
+            amount: payout.amount,
+        }
+    }
+}
+
+/// HTTP client for the staking indexer.
+#[derive(Debug, Clone)]
+pub struct IndexerClient {
+    base_url: String,
+    http: reqwest::Client,
+}
+
+impl IndexerClient {
+    /// Client for the default indexer of `network`.
+    #[allow(clippy::result_large_err)]
+    pub fn new(network: Network) -> Result<Self, ChainError> {
+        match get_staking_indexer_url(&network) {
+            "" => Err(ChainError::Indexer(format!(
+                "No staking indexer configured for {}",
+                network
+            ))),
+            url => Self::with_base_url(url),
+        }
+    }
+
+    /// Client for a custom indexer base URL.
+    #[allow(clippy::result_large_err)]
+    pub fn with_base_url(base_url: impl Into<String>) -> Result<Self, ChainError> {
+        let http = reqwest::Client::builder()
+            .timeout(INDEXER_TIMEOUT)
+            .build()
+            .map_err(|e| ChainError::Indexer(format!("Failed to build HTTP client: {}", e)))?;
+        Ok(Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            http,
+        })
+    }
+
+    /// Base URL queried by this client.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Fetch era totals for an inclusive range, ordered by era.
+    pub async fn fetch_era_summaries(
+        &self,
+        from: EraIndex,
+        to: EraIndex,
+    ) -> Result<Vec<IndexedEraSummary>, ChainError> {
+        let url = format!("{}/eras?from={}&to={}", self.base_url, from, to);
+        let body = self.get(&url).await?.unwrap_or_default();
+        if body.is_empty() {
+            return Ok(Vec::new());
+        }
+        parse_era_summaries(&body)
+    }
+
+    /// Fetch one era with validator exposures and points; `None` if not indexed.
+    pub async fn fetch_era(&self, era: EraIndex) -> Result<Option<IndexedEra>, ChainError> {
+        let url = format!("{}/eras/{}", self.base_url, era);
+        match self.get(&url).await? {
+            Some(body) => parse_era(&body).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Fetch the payout events of `address` for an inclusive era range,
+    /// oldest first.
+    pub async fn fetch_payout_events(
+        &self,
+        address: &str,
+        from: EraIndex,
+        to: EraIndex,
+    ) -> Result<Vec<PayoutEvent>, ChainError> {
+        let url = format!(
+            "{}/accounts/{}/payouts?fromEra={}&toEra={}",
+            self.base_url, address, from, to
+        );
+        let body = self.get(&url).await?.unwrap_or_default();
+        if body.is_empty() {
+            return Ok(Vec::new());
+        }
+        parse_payout_events(&body)
+    }
+
+    /// Most recent indexed era with rewards and points, searching back from
+    /// `latest_completed_era` at most `max_lookback` eras.
+    pub async fn fetch_recent_validator_apy_data(
+        &self,
+        latest_completed_era: EraIndex,
+        max_lookback: u32,
+    ) -> Result<Option<IndexedEra>, ChainError> {
+        let earliest_era = latest_completed_era.saturating_sub(max_lookback.max(1) - 1);
+        for era in (earliest_era..=latest_completed_era).rev() {
+            match self.fetch_era(era).await? {
+                Some(indexed)
+                    if indexed.validator_reward > 0
+                        && indexed.total_points > 0
+                        && !indexed.validators.is_empty() =>
+                {
+                    return Ok(Some(indexed));
+                }
+                _ => tracing::debug!("Indexer has no complete data for era {}", era),
+            }
+        }
+        Ok(None)
+    }
+
+    /// GET a URL, returning `None` on 404.
+    async fn get(&self, url: &str) -> Result<Option<String>, ChainError> {
+        tracing::debug!("Indexer request: {}", url);
+        let response = self
+            .http
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| ChainError::Indexer(format!("Request to {} failed: {}", url, e)))?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !response.status().is_success() {
+            return Err(ChainError::Indexer(format!(
+                "{} returned HTTP {}",
+                url,
+                response.status()
+            )));
+        }
+        response
+            .text()
+            .await
+            .map(Some)
+            .map_err(|e| ChainError::Indexer(format!("Failed to read {}: {}", url, e)))
+    }
+}
+
+/// Parse an era summaries response.
+#[allow(clippy::result_large_err)]
+pub fn parse_era_summaries(body: &str) -> Result<Vec<IndexedEraSummary>, ChainError> {
+    let mut summaries: Vec<IndexedEraSummary> = serde_json::from_str(body)
+        .map_err(|e| ChainError::Indexer(format!("Invalid era summaries: {}", e)))?;
+    summaries.sort_by_key(|summary| summary.era);
+    Ok(summaries)
+}
+
+/// Parse a single era response.
+#[allow(clippy::result_large_err)]
+pub fn parse_era(body: &str) -> Result<IndexedEra, ChainError> {
+    serde_json::from_str(body).map_err(|e| ChainError::Indexer(format!("Invalid era data: {}", e)))
+}
+
+/// Parse a payout events response.
+#[allow(clippy::result_large_err)]
+pub fn parse_payout_events(body: &str) -> Result<Vec<PayoutEvent>, ChainError> {
+    let payouts: Vec<IndexedPayout> = serde_json::from_str(body)
+        .map_err(|e| ChainError::Indexer(format!("Invalid payout events: {}", e)))?;
+    let mut events: Vec<PayoutEvent> = payouts.into_iter().map(PayoutEvent::from).collect();
+    events.sort_by_key(|e| (e.block_number, e.event_index));
+    Ok(events)
+}
+
+/// On-chain values used to spot-check indexer data.
+#[allow(async_fn_in_trait)]
+pub trait EraStateSource {
+    /// Total validator payout for an era (`ErasValidatorReward`).
+    async fn era_validator_reward(&self, era: EraIndex) -> Result<Option<Balance>, ChainError>;
+
+    /// Total stake for an era (`ErasTotalStake`).
+    async fn era_total_stake(&self, era: EraIndex) -> Result<Balance, ChainError>;
+
+    /// Total reward points and per-validator points for an era (`ErasRewardPoints`).
+    async fn era_reward_points(
+        &self,
+        era: EraIndex,
+    ) -> Result<(u32, Vec<ValidatorPoints>), ChainError>;
+}
+
+impl EraStateSource for ChainClient {
+    async fn era_validator_reward(&self, era: EraIndex) -> Result<Option<Balance>, ChainError> {
+        self.get_era_validator_reward(era).await
+    }
+
+    async fn era_total_stake(&self, era: EraIndex) -> Result<Balance, ChainError> {
+        self.get_era_total_stake_direct(era).await
+    }
+
+    async fn era_reward_points(
+        &self,
+        era: EraIndex,
+    ) -> Result<(u32, Vec<ValidatorPoints>), ChainError> {
+        self.get_era_reward_points(era).await
+    }
+}
+
+/// Evenly spread sample of `sample` items (always including the newest).
+fn sample_indices(len: usize, sample: usize) -> Vec<usize> {
+    if len == 0 || sample == 0 {
+        return Vec::new();
+    }
+    let sample = sample.min(len);
+    let step = len as f64 / sample as f64;
+    let mut indices: Vec<usize> = (0..sample)
+        .map(|i| len - 1 - (i as f64 * step) as usize)
+        .collect();
+    indices.dedup();
+    indices
+}
+
+/// Compare a sample of indexed era totals with on-chain state.
+///
+/// Eras whose state is no longer available on chain are skipped. Returns the
+/// number of eras verified, or an error on the first mismatch.
+pub async fn spot_check_era_summaries<S: EraStateSource>(
+    chain: &S,
+    summaries: &[IndexedEraSummary],
+    sample: usize,
+) -> Result<usize, ChainError> {
+    let mut verified = 0;
+    for index in sample_indices(summaries.len(), sample) {
+        let summary = &summaries[index];
+        let Ok(Some(reward)) = chain.era_validator_reward(summary.era).await else {
+            tracing::debug!(
+                "Era {} reward not available on chain; skipping",
+                summary.era
+            );
+            continue;
+        };
+        if reward != summary.validator_reward {
+            return Err(ChainError::Indexer(format!(
+                "Era {} reward mismatch: indexer {} != chain {}",
+                summary.era, summary.validator_reward, reward
+            )));
+        }
+        if let Ok(stake) = chain.era_total_stake(summary.era).await
+            && stake > 0
+            && stake != summary.total_stake
+        {
+            return Err(ChainError::Indexer(format!(
+                "Era {} total stake mismatch: indexer {} != chain {}",
+                summary.era, summary.total_stake, stake
+            )));
+        }
+        verified += 1;
+    }
+    Ok(verified)
+}
+
+/// Compare an indexed era's totals and a sample of validator points with chain state.
+///
+/// Returns `false` when the era cannot be verified because its state is not on chain.
+pub async fn spot_check_era<S: EraStateSource>(
+    chain: &S,
+    indexed: &IndexedEra,
+    sample: usize,
+) -> Result<bool, ChainError> {
+    if spot_check_era_summaries(chain, &[indexed.summary()], 1).await? == 0 {
+        return Ok(false);
+    }
+    let (total_points, points) = match chain.era_reward_points(indexed.era).await {
+        Ok(points) => points,
+        Err(e) => {
+            tracing::debug!(
+                "Era {} reward points not available on chain: {}",
+                indexed.era,
+                e
+            );
+            return Ok(false);
+        }
+    };
+    if total_points != indexed.total_points {
+        return Err(ChainError::Indexer(format!(
+            "Era {} total points mismatch: indexer {} != chain {}",
+            indexed.era, indexed.total_points, total_points
+        )));
+    }
+    // AccountId32 is not Hash, so key by its raw bytes
+    let chain_points: HashMap<[u8; 32], u32> = points
+        .into_iter()
+        .map(|entry| (entry.address.0, entry.points))
+        .collect();
+    for index in sample_indices(indexed.validators.len(), sample) {
+        let validator = &indexed.validators[index];
+        let on_chain = chain_points.get(&validator.address.0).copied().unwrap_or(0);
+        if on_chain != validator.points {
+            return Err(ChainError::Indexer(format!(
+                "Era {} points mismatch for {}: indexer {} != chain {}",
+                indexed.era, validator.address, validator.points, on_chain
+            )));
+        }
+    }
+    Ok(true)
+}
+
+/// Indexer era totals for a range, spot-checked against chain state and keyed by era.
+///
+/// Errors (including a failed spot check) are logged and yield an empty map so
+/// callers fall back to chain queries.
+pub async fn verified_era_summaries<S: EraStateSource>(
+    chain: &S,
+    indexer: &IndexerClient,
+    from: EraIndex,
+    to: EraIndex,
+) -> HashMap<EraIndex, IndexedEraSummary> {
+    match indexer.fetch_era_summaries(from, to).await {
+        Ok(summaries) => accept_era_summaries(chain, summaries).await,
+        Err(e) => {
+            tracing::warn!("Failed to fetch eras {}..={} from indexer: {}", from, to, e);
+            HashMap::new()
+        }
+    }
+}
+
+/// Key indexer era totals by era if a spot check verified at least one of them.
+async fn accept_era_summaries<S: EraStateSource>(
+    chain: &S,
+    summaries: Vec<IndexedEraSummary>,
+) -> HashMap<EraIndex, IndexedEraSummary> {
+    match spot_check_era_summaries(chain, &summaries, DEFAULT_SPOT_CHECK_SAMPLE).await {
+        Ok(0) => {
+            tracing::warn!(
+                "Discarding indexer history: none of {} eras could be verified on chain",
+                summaries.len()
+            );
+            HashMap::new()
+        }
+        Ok(verified) => {
+            tracing::info!(
+                "Indexer returned {} eras ({} spot-checked against chain)",
+                summaries.len(),
+                verified
+            );
+            summaries
+                .into_iter()
+                .map(|summary| (summary.era, summary))
+                .collect()
+        }
+        Err(e) => {
+            tracing::warn!("Discarding indexer history: {}", e);
+            HashMap::new()
+        }
+    }
+}
+
+/// Per-era history inputs from verified indexer data, falling back to chain state.
+///
+/// Feeds the history fetchers of both apps: light clients lack the historical
+/// state behind `ErasValidatorReward`/`ErasTotalStake` for older eras.
+pub struct HistoryEraSource<'a> {
+    chain: &'a ChainClient,
+    indexed: HashMap<EraIndex, IndexedEraSummary>,
+}
+
+impl<'a> HistoryEraSource<'a> {
+    /// Load indexer data for `from..=to` when an indexer is given.
+    pub async fn load(
+        chain: &'a ChainClient,
+        indexer: Option<&IndexerClient>,
+        from: EraIndex,
+        to: EraIndex,
+    ) -> Self {
+        let indexed = match indexer {
+            Some(indexer) => verified_era_summaries(chain, indexer, from, to).await,
+            None => HashMap::new(),
+        };
+        Self { chain, indexed }
+    }
+
+    /// Number of eras served from the indexer.
+    pub fn indexed_eras(&self) -> usize {
+        self.indexed.len()
+    }
+
+    /// Total validator payout for an era.
+    pub async fn validator_reward(&self, era: EraIndex) -> Result<Option<Balance>, ChainError> {
+        match self.indexed.get(&era) {
+            Some(summary) => Ok(Some(summary.validator_reward)),
+            None => self.chain.get_era_validator_reward(era).await,
+        }
+    }
+
+    /// Total stake for an era.
+    pub async fn total_stake(&self, era: EraIndex) -> Result<Balance, ChainError> {
+        match self.indexed.get(&era) {
+            Some(summary) => Ok(summary.total_stake),
+            None => self.chain.get_era_total_stake_direct(era).await,
+        }
+    }
+}
+
+/// Validator APY source that falls back to the indexer when chain state is missing.
+///
+/// With no indexer this behaves exactly like querying the [`ChainClient`].
+pub struct IndexedValidatorApySource<'a> {
+    pub chain: &'a ChainClient,
+    pub indexer: Option<&'a IndexerClient>,
+}
+
+impl ValidatorEnrichmentSource for IndexedValidatorApySource<'_> {
+    async fn fetch_validator_apy_data(
+        &self,
+        latest_completed_era: u32,
+        max_lookback: u32,
+    ) -> Result<Option<ValidatorApyData>, ChainError> {
+        let chain_result = self
+            .chain
+            .get_recent_validator_apy_data(latest_completed_era, max_lookback)
+            .await;
+        let Some(indexer) = self.indexer else {
+            return chain_result;
+        };
+        match chain_result {
+            Ok(Some(data)) => return Ok(Some(data)),
+            Ok(None) => tracing::info!("No validator APY data on chain; trying indexer"),
+            Err(e) => tracing::info!(
+                "Validator APY data unavailable on chain ({}); trying indexer",
+                e
+            ),
+        }
+
+        let Some(indexed) = indexer
+            .fetch_recent_validator_apy_data(latest_completed_era, max_lookback)
+            .await?
+        else {
+            return Ok(None);
+        };
+        verified_apy_data(self.chain, &indexed).await.map(Some)
+    }
+}
+
+/// APY inputs from an indexed era, only once the era was verified against chain state.
+async fn verified_apy_data<S: EraStateSource>(
+    chain: &S,
+    indexed: &IndexedEra,
+) -> Result<ValidatorApyData, ChainError> {
+    if !spot_check_era(chain, indexed, DEFAULT_SPOT_CHECK_SAMPLE).await? {
+        return Err(ChainError::Indexer(format!(
+            "Era {} from the indexer could not be verified on chain",
+            indexed.era
+        )));
+    }
+    Ok(indexed.apy_data())
+}
+
+fn deserialize_balance<'de, D>(deserializer: D) -> Result<Balance, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Amount {
+        Text(String),
+        Number(u64),
+    }
+
+    match Amount::deserialize(deserializer)? {
+        Amount::Text(text) => text.parse().map_err(serde::de::Error::custom),
+        Amount::Number(number) => Ok(number as Balance),
+    }
+}
+
+fn deserialize_account<'de, D>(deserializer: D) -> Result<AccountId32, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let address = String::deserialize(deserializer)?;
+    AccountId32::from_str(&address).map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Polkadot SS58 of `[1u8; 32]`.
+    fn address() -> String {
+        crate::encode_ss58(&AccountId32::from([1u8; 32]), 0).unwrap()
+    }
+
+    struct MockChain {
+        rewards: HashMap<EraIndex, Balance>,
+        stakes: HashMap<EraIndex, Balance>,
+        points: HashMap<EraIndex, (u32, Vec<ValidatorPoints>)>,
+    }
+
+    impl MockChain {
+        fn new() -> Self {
+            Self {
+                rewards: HashMap::new(),
+                stakes: HashMap::new(),
+                points: HashMap::new(),
+            }
+        }
+    }
+
+    impl EraStateSource for MockChain {
+        async fn era_validator_reward(&self, era: EraIndex) -> Result<Option<Balance>, ChainError> {
+            Ok(self.rewards.get(&era).copied())
+        }
+
+        async fn era_total_stake(&self, era: EraIndex) -> Result<Balance, ChainError> {
+            Ok(self.stakes.get(&era).copied().unwrap_or(0))
+        }
+
+        async fn era_reward_points(
+            &self,
+            era: EraIndex,
+        ) -> Result<(u32, Vec<ValidatorPoints>), ChainError> {
+            self.points
+                .get(&era)
+                .cloned()
+                .ok_or_else(|| ChainError::Storage("missing".to_string()))
+        }
+    }
+
+    fn summary(era: EraIndex, reward: Balance, stake: Balance) -> IndexedEraSummary {
+        IndexedEraSummary {
+            era,
+            validator_reward: reward,
+            total_stake: stake,
+        }
+    }
+
+    #[test]
+    fn test_parse_era_summaries_accepts_string_and_number_amounts() {
+        let body = r#"[
+            {"era": 1501, "validatorReward": "340282366920938463463374607431768211455", "totalStake": 5},
+            {"era": 1500, "validatorReward": "10", "totalStake": "20"}
+        ]"#;
+        let summaries = parse_era_summaries(body).unwrap();
+        assert_eq!(summaries[0], summary(1500, 10, 20));
+        assert_eq!(summaries[1].validator_reward, u128::MAX);
+        assert_eq!(summaries[1].total_stake, 5);
+    }
+
+    #[test]
+    fn test_parse_era_to_apy_data() {
+        let body = format!(
+            r#"{{"era": 1500, "validatorReward": "1000", "totalStake": "9000", "totalPoints": 80,
+                "validators": [{{"address": "{}", "points": 80, "commission": 0.05,
+                "own": "100", "total": "900", "nominatorCount": 7}}]}}"#,
+            address()
+        );
+        let indexed = parse_era(&body).unwrap();
+        let apy = indexed.apy_data();
+        assert_eq!(apy.era, 1500);
+        assert_eq!(apy.era_reward, 1000);
+        assert_eq!(apy.total_points, 80);
+        assert_eq!(apy.points[0].address, AccountId32::from([1u8; 32]));
+        assert_eq!(apy.exposures[0].total, 900);
+        assert_eq!(apy.exposures[0].nominator_count, 7);
+    }
+
+    #[test]
+    fn test_parse_era_rejects_bad_address() {
+        let body = r#"{"era": 1, "validatorReward": "1", "totalStake": "1", "totalPoints": 1,
+            "validators": [{"address": "nope", "points": 1, "own": "1", "total": "1"}]}"#;
+        assert!(matches!(parse_era(body), Err(ChainError::Indexer(_))));
+    }
+
+    #[test]
+    fn test_parse_payout_events() {
+        let body = r#"[
+            {"blockNumber": 200, "eventIndex": 4, "source": "pool", "poolId": 7,
+             "amount": "15"},
+            {"blockNumber": 100, "eventIndex": 2, "source": "staking", "era": 1500,
+             "validator": "16ZL8yLyXv3V3L3z9ofR1ovFLziyXaN1DPq4yffMAZ9czzBD",
+             "amount": 1000000000000}
+        ]"#;
+        let events = parse_payout_events(body).unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].source, PayoutSource::Staking);
+        assert_eq!(events[0].era, Some(1500));
+        assert_eq!(events[0].amount, 1_000_000_000_000);
+        assert_eq!(events[1].pool_id, Some(7));
+        assert_eq!(events[1].era, None);
+        assert!(parse_payout_events(r#"[{"source": "other"}]"#).is_err());
+    }
+
+    #[test]
+    fn test_sample_indices_spread_and_bounded() {
+        assert!(sample_indices(0, 3).is_empty());
+        assert_eq!(sample_indices(2, 3), vec![1, 0]);
+        let indices = sample_indices(30, 3);
+        assert_eq!(indices.len(), 3);
+        assert_eq!(indices[0], 29);
+        assert!(indices.iter().all(|&i| i < 30));
+    }
+
+    #[tokio::test]
+    async fn test_spot_check_accepts_matching_and_skips_unavailable() {
+        let mut chain = MockChain::new();
+        chain.rewards.insert(1502, 30);
+        chain.stakes.insert(1502, 300);
+        let summaries = vec![
+            summary(1500, 10, 100),
+            summary(1501, 20, 200),
+            summary(1502, 30, 300),
+        ];
+        let verified = spot_check_era_summaries(&chain, &summaries, 3)
+            .await
+            .unwrap();
+        assert_eq!(verified, 1);
+    }
+
+    #[tokio::test]
+    async fn test_spot_check_rejects_mismatch() {
+        let mut chain = MockChain::new();
+        chain.rewards.insert(1500, 11);
+        let result = spot_check_era_summaries(&chain, &[summary(1500, 10, 100)], 1).await;
+        assert!(matches!(result, Err(ChainError::Indexer(msg)) if msg.contains("reward mismatch")));
+    }
+
+    #[tokio::test]
+    async fn test_spot_check_era_compares_validator_points() {
+        let mut chain = MockChain::new();
+        chain.rewards.insert(1500, 1000);
+        chain.points.insert(
+            1500,
+            (
+                80,
+                vec![ValidatorPoints {
+                    address: AccountId32::from([1u8; 32]),
+                    points: 60,
+                }],
+            ),
+        );
+        let indexed = IndexedEra {
+            era: 1500,
+            validator_reward: 1000,
+            total_stake: 0,
+            total_points: 80,
+            validators: vec![IndexedValidatorEra {
+                address: AccountId32::from([1u8; 32]),
+                points: 80,
+                commission: 0.0,
+                own: 0,
+                total: 0,
+                nominator_count: 0,
+            }],
+        };
+        let result = spot_check_era(&chain, &indexed, 3).await;
+        assert!(matches!(result, Err(ChainError::Indexer(msg)) if msg.contains("points mismatch")));
+
+        chain.points.get_mut(&1500).unwrap().1[0].points = 80;
+        assert!(spot_check_era(&chain, &indexed, 3).await.unwrap());
+        assert!(verified_apy_data(&chain, &indexed).await.is_ok());
+    }
+
+    fn indexed_era(era: EraIndex, reward: Balance) -> IndexedEra {
+        IndexedEra {
+            era,
+            validator_reward: reward,
+            total_stake: 0,
+            total_points: 80,
+            validators: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_spot_check_era_unverifiable_without_points() {
+        let mut chain = MockChain::new();
+        chain.rewards.insert(1500, 1000);
+        let indexed = indexed_era(1500, 1000);
+        assert!(!spot_check_era(&chain, &indexed, 3).await.unwrap());
+        let result = verified_apy_data(&chain, &indexed).await;
+        assert!(
+            matches!(result, Err(ChainError::Indexer(msg)) if msg.contains("could not be verified"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_verified_apy_data_rejects_era_missing_on_chain() {
+        let chain = MockChain::new();
+        assert!(
+            verified_apy_data(&chain, &indexed_era(1500, 1000))
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_accept_era_summaries_requires_a_verified_era() {
+        let mut chain = MockChain::new();
+        let summaries = vec![summary(1500, 10, 100), summary(1501, 20, 200)];
+        assert!(
+            accept_era_summaries(&chain, summaries.clone())
+                .await
+                .is_empty()
+        );
+
+        chain.rewards.insert(1501, 20);
+        let accepted = accept_era_summaries(&chain, summaries).await;
+        assert_eq!(accepted.len(), 2);
+        assert_eq!(accepted[&1500].validator_reward, 10);
+    }
+
+    #[test]
+    fn test_indexer_client_base_url() {
+        let client = IndexerClient::with_base_url("https://example.com/dot/").unwrap();
+        assert_eq!(client.base_url(), "https://example.com/dot");
+        let client = IndexerClient::new(Network::Kusama).unwrap();
+        assert_eq!(client.base_url(), get_staking_indexer_url(&Network::Kusama));
+    }
+}
@@ -2,11 +2,21 @@ mod batch_storage;
 pub mod client;
 pub mod config;
 pub mod display;
+pub mod endpoints;
 pub mod enrichment;
 pub mod error;
+pub mod events;
+mod hybrid;
+pub mod indexer;
 pub mod lightclient;
+pub mod lightclient_peers;
+pub mod mock_rpc;
+pub mod proof;
+pub mod qr_export;
 pub mod queries;
+pub mod session;
 pub mod ss58;
+pub mod subscriptions;
 pub mod transactions;
 
 pub use client::{
@@ -16,30 +26,55 @@ pub use client::{
 pub use config::*;
 pub use display::{
     DEFAULT_VALIDATOR_APY_LOOKBACK_ERAS, DisplayValidatorEnrichment, MAX_REALISTIC_APY,
-    basic_display_pools, basic_display_validators, calculate_era_date, enrich_display_pools,
-    enrich_display_validators, eras_for_lookback_days, estimate_user_reward, is_realistic_apy,
+    UnbondingEntry, UnbondingSource, basic_display_pools, basic_display_validators,
+    calculate_era_date, enrich_display_pools, enrich_display_validators, era_start_ms,
+    eras_for_lookback_days, estimate_user_reward, exact_history_point, is_realistic_apy,
     missing_validator_identity_addresses, pool_ids_for_nomination_queries, pool_metadata_map,
-    pool_nomination_apy, staking_history_point, validator_apy_map, validator_identity_display_map,
+    pool_nomination_apy, staking_history_point, unbonding_schedule, validator_apy_map,
+    validator_identity_display_map,
+};
+pub use endpoints::{
+    EndpointManager, EndpointProbe, SharedEndpoints, probe_endpoint, refresh_endpoint_health,
 };
 pub use enrichment::{
     PoolEnrichmentOutcome, PoolEnrichmentSource, ValidatorEnrichmentOutcome,
     ValidatorEnrichmentSource, fetch_and_enrich_pools, fetch_and_enrich_validators,
 };
 pub use error::*;
+pub use events::{DispatchFailure, ModuleErrorInfo, TxEvent, TxOutcome};
+pub use indexer::{
+    EraStateSource, HistoryEraSource, IndexedEra, IndexedEraSummary, IndexedPayout,
+    IndexedValidatorApySource, IndexedValidatorEra, IndexerClient, spot_check_era,
+    spot_check_era_summaries, verified_era_summaries,
+};
 pub use lightclient::LightClientConnections;
+pub use lightclient_peers::{LightClientPeers, LightClientStore};
+pub use mock_rpc::{MockRpcServer, RecordedCall, Recording};
+pub use proof::{ProofError, ReadProof};
+pub use qr_export::{
+    QrExport, QrExportFormat, export_qr, uos_multipart_frame, uos_multipart_frames, write_qr_export,
+};
 pub use queries::account::{
-    AccountBalance, NominatorInfo, PoolMembership, StakingLedger, UnlockChunk, UnlockChunkInfo,
+    AccountBalance, BalanceBreakdown, BalanceRestriction, NominatorInfo, PoolMembership,
+    StakingLedger, UnlockChunk, UnlockChunkInfo, VestingSchedule,
 };
+pub use queries::bags::MAX_BAG_WALK;
+pub use queries::exposure::{NominationBacking, NominatorExposure};
 pub use queries::identity::{PeopleChainClient, ValidatorIdentity};
+pub use queries::payouts::{AccountPayoutEvent, DEFAULT_PAYOUT_SCAN_BLOCKS};
 pub use queries::pools::{
     PoolAccountType, PoolInfo, PoolMetadata, PoolNominations, PoolRoles, PoolState,
     derive_pool_account,
 };
+pub use queries::portfolio::PortfolioAccountStatus;
+pub use queries::slashing::slash_risk_targets;
 pub use queries::validators::{
     ValidatorApyData, ValidatorExposure, ValidatorFetch, ValidatorInfo, ValidatorPoints,
 };
-pub use ss58::encode_ss58;
+pub use session::{SessionMode, SessionRecording, recorded_network};
+pub use ss58::{decode_ss58_prefix, encode_ss58, ensure_ss58_prefix};
+pub use subscriptions::{BLOCK_POLL_INTERVAL, ChainSubscription, SubscriptionUpdate};
 pub use transactions::{
-    DecodedSignature, Era, RewardDestination, SignatureType, SignedExtrinsic, UnsignedPayload,
-    build_signed_extrinsic, decode_vault_signature, encode_for_qr,
+    DecodedSignature, Era, PoolRoleUpdate, RewardDestination, SignatureType, SignedExtrinsic,
+    UnsignedPayload, build_signed_extrinsic, decode_vault_signature, encode_for_qr,
 };
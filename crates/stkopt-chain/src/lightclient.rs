@@ -15,6 +15,15 @@
 //! Chain specs are bundled with the binary from substrate-connect.
 //! Source: https://github.com/paritytech/substrate-connect/tree/main/packages/connect-known-chains/specs
 //!
+//! `Network::Custom` reads its chain specs from the files named in its
+//! definition, on each connect.
+//!
+//! # Peer Cache
+//!
+//! With a [`LightClientStore`], the peers each chain knows are saved by
+//! [`LightClientConnections::save_peers`] and dialed first on the next
+//! connect; see [`crate::lightclient_peers`].
+//!
 //! # Limitations
 //!
 //! Light clients cannot query historical state beyond what's in the current
@@ -22,10 +31,17 @@
 
 use crate::PeopleChainClient;
 use crate::error::ChainError;
-use std::sync::Arc;
-use stkopt_core::Network;
+use crate::lightclient_peers::{
+    LightClientPeers, LightClientStore, fetch_finalized_database, peers_from_database, spec_hash,
+    spec_with_saved_peers,
+};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+use stkopt_core::{ChainRole, CustomNetwork, Network};
 use subxt::backend::CombinedBackend;
 use subxt::lightclient::LightClient;
+use subxt::rpcs::RpcClient;
 use subxt::{OnlineClient, PolkadotConfig};
 
 // Embedded relay chain specs
@@ -44,35 +60,39 @@ const POLKADOT_PEOPLE_SPEC: &str = include_str!("../chain_specs/polkadot_people.
 const KUSAMA_PEOPLE_SPEC: &str = include_str!("../chain_specs/kusama_people.json");
 const WESTEND_PEOPLE_SPEC: &str = include_str!("../chain_specs/westend_people.json");
 
-/// Get the embedded relay chain spec for a network.
-pub fn get_relay_chain_spec(network: Network) -> &'static str {
+/// Get the relay chain spec for a network.
+/// Returns None for a custom network without a readable relay chain spec.
+pub fn get_relay_chain_spec(network: &Network) -> Option<Cow<'static, str>> {
     match network {
-        Network::Polkadot => POLKADOT_SPEC,
-        Network::Kusama => KUSAMA_SPEC,
-        Network::Westend => WESTEND_SPEC,
-        Network::Paseo => PASEO_SPEC,
+        Network::Polkadot => Some(Cow::Borrowed(POLKADOT_SPEC)),
+        Network::Kusama => Some(Cow::Borrowed(KUSAMA_SPEC)),
+        Network::Westend => Some(Cow::Borrowed(WESTEND_SPEC)),
+        Network::Paseo => Some(Cow::Borrowed(PASEO_SPEC)),
+        Network::Custom(custom) => custom_chain_spec(custom, ChainRole::Relay),
     }
 }
 
-/// Get the embedded Asset Hub chain spec for a network.
+/// Get the Asset Hub chain spec for a network.
 /// Returns None for networks without Asset Hub specs (e.g., Paseo).
-pub fn get_asset_hub_chain_spec(network: Network) -> Option<&'static str> {
+pub fn get_asset_hub_chain_spec(network: &Network) -> Option<Cow<'static, str>> {
     match network {
-        Network::Polkadot => Some(POLKADOT_ASSET_HUB_SPEC),
-        Network::Kusama => Some(KUSAMA_ASSET_HUB_SPEC),
-        Network::Westend => Some(WESTEND_ASSET_HUB_SPEC),
+        Network::Polkadot => Some(Cow::Borrowed(POLKADOT_ASSET_HUB_SPEC)),
+        Network::Kusama => Some(Cow::Borrowed(KUSAMA_ASSET_HUB_SPEC)),
+        Network::Westend => Some(Cow::Borrowed(WESTEND_ASSET_HUB_SPEC)),
         Network::Paseo => None, // Paseo Asset Hub spec not available
+        Network::Custom(custom) => custom_chain_spec(custom, ChainRole::AssetHub),
     }
 }
 
-/// Get the embedded People chain spec for a network.
+/// Get the People chain spec for a network.
 /// Returns None for networks without People chain specs (e.g., Paseo).
-pub fn get_people_chain_spec(network: Network) -> Option<&'static str> {
+pub fn get_people_chain_spec(network: &Network) -> Option<Cow<'static, str>> {
     match network {
-        Network::Polkadot => Some(POLKADOT_PEOPLE_SPEC),
-        Network::Kusama => Some(KUSAMA_PEOPLE_SPEC),
-        Network::Westend => Some(WESTEND_PEOPLE_SPEC),
+        Network::Polkadot => Some(Cow::Borrowed(POLKADOT_PEOPLE_SPEC)),
+        Network::Kusama => Some(Cow::Borrowed(KUSAMA_PEOPLE_SPEC)),
+        Network::Westend => Some(Cow::Borrowed(WESTEND_PEOPLE_SPEC)),
         Network::Paseo => None, // Paseo People spec not available
+        Network::Custom(custom) => custom_chain_spec(custom, ChainRole::People),
     }
 }
 
@@ -91,6 +111,10 @@ pub struct LightClientConnections {
     pub relay: OnlineClient<PolkadotConfig>,
     /// Network this connection is for.
     network: Network,
+    /// Where known peers are saved; `None` starts from the bootnodes only.
+    store: Option<LightClientStore>,
+    /// Raw RPC and genesis hash per chain, to read peers on save.
+    chains: Mutex<HashMap<ChainRole, (RpcClient, [u8; 32])>>,
 }
 
 impl Drop for LightClientConnections {
@@ -112,13 +136,26 @@ impl LightClientConnections {
     /// Chain specs are bundled with the binary (no network fetch required).
     #[allow(clippy::result_large_err)]
     pub async fn connect(network: Network) -> Result<Self, ChainError> {
-        let total_start = std::time::Instant::now();
+        Self::connect_with_store(network, None).await
+    }
+
+    /// Connect, dialing the peers saved in `store` first.
+    #[allow(clippy::result_large_err)]
+    pub async fn connect_with_store(
+        network: Network,
+        store: Option<LightClientStore>,
+    ) -> Result<Self, ChainError> {
         tracing::info!("Connecting to {} via light client (smoldot)...", network);
         tracing::info!("Light client provides trustless P2P connection - no RPC trust required");
 
         // Get embedded chain specs
-        let relay_spec = get_relay_chain_spec(network);
-        let asset_hub_spec = get_asset_hub_chain_spec(network).ok_or_else(|| {
+        let relay_spec = get_relay_chain_spec(&network).ok_or_else(|| {
+            ChainError::LightClient(format!(
+                "Relay chain spec not available for {} - light client mode not supported",
+                network
+            ))
+        })?;
+        let asset_hub_spec = get_asset_hub_chain_spec(&network).ok_or_else(|| {
             ChainError::LightClient(format!(
                 "Asset Hub chain spec not available for {} - light client mode not supported",
                 network
@@ -131,6 +168,24 @@ impl LightClientConnections {
             asset_hub_spec.len()
         );
 
+        let mut loaded = HashMap::new();
+        if let Some(ref store) = store {
+            for (role, spec) in [
+                (ChainRole::Relay, &*relay_spec),
+                (ChainRole::AssetHub, &*asset_hub_spec),
+            ] {
+                if let Some(saved) = store.load(&network, role, spec) {
+                    loaded.insert(role, saved);
+                }
+            }
+        }
+        if !loaded.is_empty() {
+            tracing::info!("Dialing saved {} light-client peers first", network);
+        }
+        let relay_start_spec = spec_with_saved_peers(&relay_spec, loaded.get(&ChainRole::Relay));
+        let asset_hub_start_spec =
+            spec_with_saved_peers(&asset_hub_spec, loaded.get(&ChainRole::AssetHub));
+
         // Create the light client for the relay chain
         tracing::info!(
             "Starting smoldot light client for {} relay chain...",
@@ -139,7 +194,7 @@ impl LightClientConnections {
         tracing::info!("Smoldot will discover and connect to P2P network peers...");
         let start = std::time::Instant::now();
         let (light_client, relay_rpc) = tokio::task::spawn_blocking(move || {
-            LightClient::relay_chain(relay_spec).map_err(|e| {
+            LightClient::relay_chain(relay_start_spec.as_str()).map_err(|e| {
                 ChainError::LightClient(format!("Failed to start relay chain light client: {}", e))
             })
         })
@@ -154,9 +209,11 @@ impl LightClientConnections {
         );
         let start = std::time::Instant::now();
         let (light_client, asset_hub_rpc) = tokio::task::spawn_blocking(move || {
-            let asset_hub_rpc = light_client.parachain(asset_hub_spec).map_err(|e| {
-                ChainError::LightClient(format!("Failed to connect to Asset Hub: {}", e))
-            })?;
+            let asset_hub_rpc = light_client
+                .parachain(asset_hub_start_spec.as_str())
+                .map_err(|e| {
+                    ChainError::LightClient(format!("Failed to connect to Asset Hub: {}", e))
+                })?;
             Ok::<_, ChainError>((light_client, asset_hub_rpc))
         })
         .await
@@ -168,7 +225,9 @@ impl LightClientConnections {
         tracing::info!("Waiting for relay chain to sync and finalize blocks...");
         tracing::info!("This may take 15-30 seconds on first connection...");
         let start = std::time::Instant::now();
-        let relay = OnlineClient::<PolkadotConfig>::from_rpc_client(relay_rpc)
+        let relay_rpc = RpcClient::new(relay_rpc);
+        let asset_hub_rpc = RpcClient::new(asset_hub_rpc);
+        let relay = OnlineClient::<PolkadotConfig>::from_rpc_client(relay_rpc.clone())
             .await
             .map_err(|e| {
                 ChainError::LightClient(format!("Failed to create relay client: {}", e))
@@ -178,7 +237,7 @@ impl LightClientConnections {
         tracing::info!("Waiting for Asset Hub to sync...");
         let start = std::time::Instant::now();
         let asset_hub_backend = CombinedBackend::<PolkadotConfig>::builder()
-            .build_with_background_driver(asset_hub_rpc)
+            .build_with_background_driver(asset_hub_rpc.clone())
             .await
             .map_err(|e| {
                 ChainError::LightClient(format!("Failed to create Asset Hub backend: {}", e))
@@ -191,11 +250,19 @@ impl LightClientConnections {
             })?;
         tracing::info!("Asset Hub synced in {:?}", start.elapsed());
 
-        tracing::info!(
-            "Light client connected to {} successfully! Total time: {:?}",
-            network,
-            total_start.elapsed()
-        );
+        let genesis = [
+            (ChainRole::Relay, relay_rpc, relay.genesis_hash().0),
+            (
+                ChainRole::AssetHub,
+                asset_hub_rpc,
+                asset_hub.genesis_hash().0,
+            ),
+        ];
+        for (role, _, genesis_hash) in &genesis {
+            discard_on_genesis_change(store.as_ref(), &mut loaded, &network, *role, genesis_hash);
+        }
+
+        tracing::info!("Light client connected to {} successfully!", network);
 
         Ok(Self {
             light_client,
@@ -203,12 +270,64 @@ impl LightClientConnections {
             asset_hub_backend,
             relay,
             network,
+            store,
+            chains: Mutex::new(
+                genesis
+                    .into_iter()
+                    .map(|(role, rpc, genesis_hash)| (role, (rpc, genesis_hash)))
+                    .collect(),
+            ),
         })
     }
 
     /// Get the network this connection is for.
     pub fn network(&self) -> Network {
-        self.network
+        self.network.clone()
+    }
+
+    /// Where known peers are saved, if anywhere.
+    pub fn store(&self) -> Option<&LightClientStore> {
+        self.store.as_ref()
+    }
+
+    /// Raw JSON-RPC connection to one of the light client's chains.
+    pub(crate) fn rpc(&self, role: ChainRole) -> Option<RpcClient> {
+        lock(&self.chains).get(&role).map(|(rpc, _)| rpc.clone())
+    }
+
+    /// Save the peers each connected chain knows for the next start.
+    ///
+    /// Call on shutdown; a no-op without a store. Chains that know no peers
+    /// keep what was saved before.
+    pub async fn save_peers(&self) -> Result<(), ChainError> {
+        let Some(ref store) = self.store else {
+            return Ok(());
+        };
+        let chains: Vec<(ChainRole, RpcClient, [u8; 32])> = lock(&self.chains)
+            .iter()
+            .map(|(role, (rpc, genesis_hash))| (*role, rpc.clone(), *genesis_hash))
+            .collect();
+
+        let now = chrono::Utc::now().timestamp();
+        for (role, rpc, genesis_hash) in chains {
+            let Some(spec) = chain_spec(&self.network, role) else {
+                continue;
+            };
+            let peers = peers_from_database(&fetch_finalized_database(&rpc).await?);
+            if peers.is_empty() {
+                continue;
+            }
+            store.save(&LightClientPeers {
+                network: self.network.clone(),
+                role,
+                spec_hash: spec_hash(&spec),
+                genesis_hash: hex::encode(genesis_hash),
+                peers,
+                saved_at: now,
+            })?;
+            tracing::debug!("Saved {} {} light-client peers", self.network, role);
+        }
+        Ok(())
     }
 
     /// Connect to the People chain for identity data.
@@ -227,7 +346,7 @@ impl LightClientConnections {
     pub async fn connect_people_chain_client(&self) -> Result<PeopleChainClient, ChainError> {
         let total_start = std::time::Instant::now();
 
-        let people_spec = get_people_chain_spec(self.network).ok_or_else(|| {
+        let people_spec = get_people_chain_spec(&self.network).ok_or_else(|| {
             ChainError::LightClient(format!(
                 "People chain spec not available for {} - identity lookup not supported in light client mode",
                 self.network
@@ -239,6 +358,12 @@ impl LightClientConnections {
             people_spec.len()
         );
 
+        let saved = self
+            .store
+            .as_ref()
+            .and_then(|store| store.load(&self.network, ChainRole::People, &people_spec));
+        let people_start_spec = spec_with_saved_peers(&people_spec, saved.as_ref());
+
         tracing::info!(
             "Adding {} People chain as parachain to light client...",
             self.network
@@ -246,9 +371,11 @@ impl LightClientConnections {
         let start = std::time::Instant::now();
         let light_client = self.light_client.clone();
         let people_rpc = tokio::task::spawn_blocking(move || {
-            light_client.parachain(people_spec).map_err(|e| {
-                ChainError::LightClient(format!("Failed to connect to People chain: {}", e))
-            })
+            light_client
+                .parachain(people_start_spec.as_str())
+                .map_err(|e| {
+                    ChainError::LightClient(format!("Failed to connect to People chain: {}", e))
+                })
         })
         .await
         .map_err(|e| ChainError::LightClient(format!("Light client task panicked: {}", e)))??;
@@ -256,8 +383,9 @@ impl LightClientConnections {
 
         tracing::info!("Waiting for People chain to sync...");
         let start = std::time::Instant::now();
+        let people_rpc = RpcClient::new(people_rpc);
         let backend = CombinedBackend::<PolkadotConfig>::builder()
-            .build_with_background_driver(people_rpc)
+            .build_with_background_driver(people_rpc.clone())
             .await
             .map_err(|e| {
                 ChainError::LightClient(format!("Failed to create People chain backend: {}", e))
@@ -270,6 +398,20 @@ impl LightClientConnections {
             })?;
         tracing::info!("People chain synced in {:?}", start.elapsed());
 
+        let genesis_hash = client.genesis_hash().0;
+        let mut loaded: HashMap<ChainRole, LightClientPeers> = saved
+            .into_iter()
+            .map(|saved| (ChainRole::People, saved))
+            .collect();
+        discard_on_genesis_change(
+            self.store.as_ref(),
+            &mut loaded,
+            &self.network,
+            ChainRole::People,
+            &genesis_hash,
+        );
+        lock(&self.chains).insert(ChainRole::People, (people_rpc, genesis_hash));
+
         tracing::info!(
             "People chain connected! Total time: {:?}",
             total_start.elapsed()
@@ -278,3 +420,58 @@ impl LightClientConnections {
         Ok(PeopleChainClient::with_backend(client, backend))
     }
 }
+
+/// Chain spec file of a custom network for a chain.
+fn custom_chain_spec(custom: &CustomNetwork, role: ChainRole) -> Option<Cow<'static, str>> {
+    let path = custom.source(role)?.chain_spec.as_ref()?;
+    match std::fs::read_to_string(path) {
+        Ok(spec) => Some(Cow::Owned(spec)),
+        Err(e) => {
+            tracing::warn!(
+                "Failed to read {} chain spec {}: {}",
+                role,
+                path.display(),
+                e
+            );
+            None
+        }
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Chain spec for a chain of `network`.
+fn chain_spec(network: &Network, role: ChainRole) -> Option<Cow<'static, str>> {
+    match role {
+        ChainRole::Relay => get_relay_chain_spec(network),
+        ChainRole::AssetHub => get_asset_hub_chain_spec(network),
+        ChainRole::People => get_people_chain_spec(network),
+    }
+}
+
+/// Drop saved peers whose chain turned out to have another genesis.
+fn discard_on_genesis_change(
+    store: Option<&LightClientStore>,
+    loaded: &mut HashMap<ChainRole, LightClientPeers>,
+    network: &Network,
+    role: ChainRole,
+    genesis_hash: &[u8; 32],
+) {
+    let Some(store) = store else {
+        return;
+    };
+    if loaded
+        .get(&role)
+        .is_some_and(|saved| saved.genesis_hash != hex::encode(genesis_hash))
+    {
+        tracing::warn!(
+            "{} {} genesis changed, discarding saved light-client peers",
+            network,
+            role
+        );
+        loaded.remove(&role);
+        store.remove(network, role);
+    }
+}
@@ -0,0 +1,276 @@
+//! Persisted light-client peer cache.
+//!
+//! On shutdown the peers each chain's smoldot instance knows are read from
+//! its finalized database (`chainHead_unstable_finalizedDatabase`) and
+//! written to `<dir>/<network>-<chain>.json`, together with a hash of the
+//! bundled chain spec and the chain's genesis hash. The next connect puts
+//! them in front of the spec's `bootNodes`: a changed spec discards the file
+//! before use, a changed genesis once connected.
+//!
+//! Only peers are carried over. Subxt's light client takes a chain spec but
+//! no smoldot database content, so each start still syncs from the spec's
+//! checkpoint; the cache only spares part of the peer discovery. A true warm
+//! start would need smoldot's finalized database passed back, or converted
+//! into a `lightSyncState` checkpoint in the spec, and is not done yet.
+
+use crate::error::ChainError;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use stkopt_core::{ChainRole, Network};
+use subxt::rpcs::RpcClient;
+use subxt::rpcs::client::RpcParams;
+
+/// Upper bound on the database smoldot is asked to produce.
+const MAX_DATABASE_BYTES: u64 = 1024 * 1024;
+
+/// The peers a chain's smoldot instance knew on shutdown.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LightClientPeers {
+    pub network: Network,
+    pub role: ChainRole,
+    /// Hex blake2-256 of the chain spec the peers were found with.
+    pub spec_hash: String,
+    /// Hex genesis hash of the chain.
+    pub genesis_hash: String,
+    /// Peer addresses, as `bootNodes` entries.
+    pub peers: Vec<String>,
+    /// Unix time (seconds) the peers were saved.
+    pub saved_at: i64,
+}
+
+/// Peer addresses remembered by a smoldot database, as `bootNodes` entries.
+///
+/// smoldot keeps known peers under `nodes` as a map of peer ID to
+/// multiaddresses; anything else yields no peers.
+pub fn peers_from_database(database: &str) -> Vec<String> {
+    let Ok(database) = serde_json::from_str::<Value>(database) else {
+        return Vec::new();
+    };
+    let Some(nodes) = database.get("nodes").and_then(Value::as_object) else {
+        return Vec::new();
+    };
+    let mut peers: Vec<String> = nodes
+        .iter()
+        .flat_map(|(peer_id, addresses)| {
+            addresses
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+                .map(move |address| format!("{address}/p2p/{peer_id}"))
+        })
+        .collect();
+    peers.sort();
+    peers
+}
+
+/// Directory of saved light-client peers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LightClientStore {
+    dir: PathBuf,
+}
+
+impl LightClientStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn path(&self, network: &Network, role: ChainRole) -> PathBuf {
+        self.dir.join(format!(
+            "{}-{}.json",
+            network.to_string().to_lowercase(),
+            role.as_str()
+        ))
+    }
+
+    /// Load the peers saved for a chain, if they were found with `spec`.
+    ///
+    /// Peers from another spec are deleted and `None` returned.
+    pub fn load(&self, network: &Network, role: ChainRole, spec: &str) -> Option<LightClientPeers> {
+        let path = self.path(network, role);
+        let data = std::fs::read(&path).ok()?;
+        let saved: LightClientPeers = match serde_json::from_slice(&data) {
+            Ok(saved) => saved,
+            Err(e) => {
+                tracing::warn!("Discarding unreadable {}: {}", path.display(), e);
+                self.remove(network, role);
+                return None;
+            }
+        };
+        if saved.spec_hash != spec_hash(spec) {
+            tracing::info!(
+                "{} {} chain spec changed, discarding saved light-client peers",
+                network,
+                role
+            );
+            self.remove(network, role);
+            return None;
+        }
+        Some(saved)
+    }
+
+    /// Save a chain's peers, replacing the previous ones atomically.
+    #[allow(clippy::result_large_err)]
+    pub fn save(&self, saved: &LightClientPeers) -> Result<(), ChainError> {
+        let path = self.path(&saved.network, saved.role);
+        let data = serde_json::to_vec(saved).map_err(|e| ChainError::LightClient(e.to_string()))?;
+        let tmp = path.with_extension("tmp");
+        std::fs::create_dir_all(&self.dir)
+            .and_then(|()| std::fs::write(&tmp, data))
+            .and_then(|()| std::fs::rename(&tmp, &path))
+            .map_err(|e| ChainError::LightClient(format!("{}: {}", path.display(), e)))
+    }
+
+    /// Delete the peers saved for a chain.
+    pub fn remove(&self, network: &Network, role: ChainRole) {
+        let path = self.path(network, role);
+        if let Err(e) = std::fs::remove_file(&path)
+            && e.kind() != std::io::ErrorKind::NotFound
+        {
+            tracing::warn!("Failed to remove {}: {}", path.display(), e);
+        }
+    }
+}
+
+/// Hex blake2-256 of a chain spec.
+pub fn spec_hash(spec: &str) -> String {
+    hex::encode(sp_crypto_hashing::blake2_256(spec.as_bytes()))
+}
+
+/// Chain spec with `peers` put in front of its `bootNodes`.
+#[allow(clippy::result_large_err)]
+pub fn spec_with_peers(spec: &str, peers: &[String]) -> Result<String, ChainError> {
+    let mut spec: Value = serde_json::from_str(spec)
+        .map_err(|e| ChainError::LightClient(format!("Invalid chain spec: {}", e)))?;
+    let bootnodes = spec
+        .get("bootNodes")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+    let mut merged: Vec<Value> = peers.iter().cloned().map(Value::String).collect();
+    for node in bootnodes {
+        if !merged.contains(&node) {
+            merged.push(node);
+        }
+    }
+    spec["bootNodes"] = Value::Array(merged);
+    serde_json::to_string(&spec).map_err(|e| ChainError::LightClient(e.to_string()))
+}
+
+/// Chain spec to start from: with the saved peers first, if any.
+pub fn spec_with_saved_peers(spec: &str, saved: Option<&LightClientPeers>) -> String {
+    let Some(saved) = saved.filter(|saved| !saved.peers.is_empty()) else {
+        return spec.to_string();
+    };
+    spec_with_peers(spec, &saved.peers).unwrap_or_else(|e| {
+        tracing::warn!("Ignoring saved peers: {}", e);
+        spec.to_string()
+    })
+}
+
+/// Read a chain's finalized database from smoldot, to take its peers.
+pub async fn fetch_finalized_database(rpc: &RpcClient) -> Result<String, ChainError> {
+    let mut params = RpcParams::new();
+    params
+        .push(MAX_DATABASE_BYTES)
+        .map_err(|e| ChainError::Rpc(e.to_string()))?;
+    rpc.request("chainHead_unstable_finalizedDatabase", params)
+        .await
+        .map_err(|e| ChainError::LightClient(format!("Failed to read smoldot database: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn saved(spec: &str) -> LightClientPeers {
+        LightClientPeers {
+            network: Network::Westend,
+            role: ChainRole::Relay,
+            spec_hash: spec_hash(spec),
+            genesis_hash: "0xe143".to_string(),
+            peers: vec!["/dns/a.example/tcp/30333/ws/p2p/12D3KooWA".to_string()],
+            saved_at: 1_700_000_000,
+        }
+    }
+
+    fn store() -> LightClientStore {
+        let dir = std::env::temp_dir().join(format!(
+            "stkopt-lc-{}-{:?}",
+            std::process::id(),
+            std::thread::current().id()
+        ));
+        LightClientStore::new(dir)
+    }
+
+    #[test]
+    fn test_peers_from_database() {
+        let database = json!({
+            "nodes": {
+                "12D3KooWA": ["/dns/a.example/tcp/30333/ws"],
+                "12D3KooWB": ["/ip4/10.0.0.1/tcp/30333/ws", "/dns/b.example/tcp/443/wss"]
+            }
+        })
+        .to_string();
+        let peers = peers_from_database(&database);
+        assert_eq!(
+            peers,
+            vec![
+                "/dns/a.example/tcp/30333/ws/p2p/12D3KooWA",
+                "/dns/b.example/tcp/443/wss/p2p/12D3KooWB",
+                "/ip4/10.0.0.1/tcp/30333/ws/p2p/12D3KooWB",
+            ]
+        );
+
+        assert!(peers_from_database("not json").is_empty());
+    }
+
+    #[test]
+    fn test_spec_with_peers_prepends_and_dedups() {
+        let spec =
+            json!({ "name": "Westend", "bootNodes": ["/dns/boot/p2p/X", "/dns/seen/p2p/Y"] })
+                .to_string();
+        let peers = vec!["/dns/seen/p2p/Y".to_string(), "/dns/new/p2p/Z".to_string()];
+        let merged: Value = serde_json::from_str(&spec_with_peers(&spec, &peers).unwrap()).unwrap();
+        assert_eq!(
+            merged["bootNodes"],
+            json!(["/dns/seen/p2p/Y", "/dns/new/p2p/Z", "/dns/boot/p2p/X"])
+        );
+        assert_eq!(merged["name"], "Westend");
+        assert!(spec_with_peers("not json", &peers).is_err());
+    }
+
+    #[test]
+    fn test_store_roundtrip_and_spec_invalidation() {
+        let store = store();
+        let spec = r#"{"name":"Westend"}"#;
+        store.save(&saved(spec)).unwrap();
+        assert_eq!(
+            store.load(&Network::Westend, ChainRole::Relay, spec),
+            Some(saved(spec))
+        );
+        assert_eq!(
+            store.load(&Network::Westend, ChainRole::AssetHub, spec),
+            None
+        );
+
+        // A new bundled spec discards the peers
+        assert_eq!(
+            store.load(
+                &Network::Westend,
+                ChainRole::Relay,
+                r#"{"name":"Westend2"}"#
+            ),
+            None
+        );
+        assert_eq!(store.load(&Network::Westend, ChainRole::Relay, spec), None);
+        std::fs::remove_dir_all(store.dir()).unwrap();
+    }
+}
@@ -0,0 +1,1211 @@
+//! In-process WebSocket JSON-RPC server for offline end-to-end tests.
+//!
+//! [`MockRpcServer`] answers the legacy Substrate JSON-RPC methods from a
+//! [`Recording`]: storage reads are served from a key/value snapshot taken at
+//! a single block, everything else (metadata, runtime version, headers,
+//! runtime API calls, nonces) from recorded method responses. Point
+//! `ChainClient::connect_rpc` at [`MockRpcServer::rpc_endpoints`] and the
+//! validator, pool, account and transaction-building flows run
+//! deterministically with no network.
+//!
+//! Recordings are captured by running the same flow against
+//! [`MockRpcServer::start_recording`], which proxies every request to a live
+//! endpoint and keeps the responses (see the `record_mock_fixture` example).
+//!
+//! [`MockRpcServer::start_verified`] forwards to a live endpoint without
+//! recording, proving every storage read and taking headers from a trusted
+//! light client; see [`crate::hybrid`] for the hybrid connection mode.
+//! Transactions submitted to it are watched through the light client, with
+//! every status update passed on.
+//!
+//! Only legacy methods are advertised through `rpc_methods`, so subxt never
+//! tries the `chainHead_v1` family. Head and runtime-version subscriptions
+//! emit a single notification built from the recorded `chain_getHeader` and
+//! `state_getRuntimeVersion` responses, and a watched extrinsic gets the
+//! recorded `author_submitAndWatchExtrinsic` status.
+
+use crate::client::RpcEndpoints;
+use crate::error::ChainError;
+use crate::hybrid::ProofVerifier;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use soketto::handshake::{Server, server::Response};
+use std::collections::{BTreeMap, BTreeSet};
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use subxt::rpcs::RpcClient;
+use subxt::rpcs::client::{RpcParams, RpcSubscription};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio_util::compat::TokioAsyncReadCompatExt;
+
+/// Methods advertised by `rpc_methods`.
+pub const MOCK_RPC_METHODS: &[&str] = &[
+    "rpc_methods",
+    "system_chain",
+    "system_name",
+    "system_version",
+    "system_properties",
+    "system_health",
+    "system_accountNextIndex",
+    "chain_getBlockHash",
+    "chain_getHeader",
+    "chain_getBlock",
+    "chain_getFinalizedHead",
+    "chain_subscribeNewHeads",
+    "chain_unsubscribeNewHeads",
+    "chain_subscribeFinalizedHeads",
+    "chain_unsubscribeFinalizedHeads",
+    "chain_subscribeAllHeads",
+    "chain_unsubscribeAllHeads",
+    "state_call",
+    "state_getMetadata",
+    "state_getRuntimeVersion",
+    "state_subscribeRuntimeVersion",
+    "state_unsubscribeRuntimeVersion",
+    "state_getStorage",
+    "state_getStorageHash",
+    "state_getStorageSize",
+    "state_getKeysPaged",
+    "state_queryStorageAt",
+    "payment_queryInfo",
+    "author_submitAndWatchExtrinsic",
+    "author_unwatchExtrinsic",
+];
+
+/// Subscriptions the mock can serve: (subscribe method, notification
+/// method, recorded method whose result becomes the notification payload).
+const SUBSCRIPTIONS: &[(&str, &str, &str)] = &[
+    (
+        "chain_subscribeNewHeads",
+        "chain_newHead",
+        "chain_getHeader",
+    ),
+    (
+        "chain_subscribeFinalizedHeads",
+        "chain_finalizedHead",
+        "chain_getHeader",
+    ),
+    (
+        "chain_subscribeAllHeads",
+        "chain_allHead",
+        "chain_getHeader",
+    ),
+    (
+        "state_subscribeRuntimeVersion",
+        "state_runtimeVersion",
+        "state_getRuntimeVersion",
+    ),
+    (
+        "author_submitAndWatchExtrinsic",
+        "author_extrinsicUpdate",
+        "author_submitAndWatchExtrinsic",
+    ),
+];
+
+/// JSON-RPC "method not found" error code.
+const METHOD_NOT_FOUND: i64 = -32601;
+/// JSON-RPC "invalid params" error code.
+const INVALID_PARAMS: i64 = -32602;
+/// Server error code used for upstream failures while recording.
+const UPSTREAM_ERROR: i64 = -32000;
+
+/// A recorded chain session served by [`MockRpcServer`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Recording {
+    /// Free-form notes about the recording (network, account used, ...).
+    #[serde(default)]
+    pub meta: BTreeMap<String, String>,
+    /// Storage values by hex key; `None` records a key known to be empty.
+    #[serde(default)]
+    pub storage: BTreeMap<String, Option<String>>,
+    /// Keys returned by `state_getKeysPaged`, whether or not their values
+    /// were read.
+    #[serde(default)]
+    pub keys: BTreeSet<String>,
+    /// Responses to every non-storage method, in first-seen order.
+    #[serde(default)]
+    pub calls: Vec<RecordedCall>,
+}
+
+/// One recorded JSON-RPC method response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedCall {
+    pub method: String,
+    #[serde(default)]
+    pub params: Vec<Value>,
+    #[serde(default)]
+    pub result: Value,
+}
+
+impl Recording {
+    /// Load a recording from a JSON file.
+    #[allow(clippy::result_large_err)]
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ChainError> {
+        let path = path.as_ref();
+        let data = std::fs::read(path)
+            .map_err(|e| ChainError::Recording(format!("{}: {}", path.display(), e)))?;
+        serde_json::from_slice(&data)
+            .map_err(|e| ChainError::Recording(format!("{}: {}", path.display(), e)))
+    }
+
+    /// Write the recording as pretty-printed JSON.
+    #[allow(clippy::result_large_err)]
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ChainError> {
+        let path = path.as_ref();
+        let data =
+            serde_json::to_vec_pretty(self).map_err(|e| ChainError::Recording(e.to_string()))?;
+        std::fs::write(path, data)
+            .map_err(|e| ChainError::Recording(format!("{}: {}", path.display(), e)))
+    }
+
+    /// Record a method response, replacing any earlier one with the same params.
+    pub fn record_call(&mut self, method: &str, params: Vec<Value>, result: Value) {
+        let params = normalize_params(params);
+        match self
+            .calls
+            .iter_mut()
+            .find(|call| call.method == method && call.params == params)
+        {
+            Some(call) => call.result = result,
+            None => self.calls.push(RecordedCall {
+                method: method.to_string(),
+                params,
+                result,
+            }),
+        }
+    }
+
+    /// Find the response recorded for a call with exactly these params.
+    ///
+    /// A method recorded only with other params is an error rather than a
+    /// fallback, so a replay never serves data for the wrong block or account.
+    #[allow(clippy::result_large_err)]
+    pub fn find_call(&self, method: &str, params: &[Value]) -> Result<&RecordedCall, ChainError> {
+        let params = normalize_params(params.to_vec());
+        if let Some(call) = self
+            .calls
+            .iter()
+            .find(|call| call.method == method && call.params == params)
+        {
+            return Ok(call);
+        }
+        if self.calls.iter().any(|call| call.method == method) {
+            return Err(ChainError::Recording(format!(
+                "{} was recorded, but not with params {}",
+                method,
+                Value::Array(params)
+            )));
+        }
+        Err(ChainError::Recording(format!(
+            "No recorded response for {}",
+            method
+        )))
+    }
+
+    /// Last response recorded for `method` with any params.
+    ///
+    /// Only used to build subscription notifications, which stand for "the
+    /// recorded block" rather than a specific request.
+    fn latest_call(&self, method: &str) -> Option<&RecordedCall> {
+        self.calls.iter().rev().find(|call| call.method == method)
+    }
+
+    /// Value stored under a hex key, `None` if empty or never recorded.
+    pub fn storage_value(&self, key: &str) -> Option<&str> {
+        self.storage.get(key).and_then(|value| value.as_deref())
+    }
+
+    /// Record a storage value read from a live endpoint.
+    pub fn record_storage(&mut self, key: &str, value: Option<&str>) {
+        self.storage
+            .insert(key.to_string(), value.map(str::to_string));
+    }
+
+    /// Keys under `prefix` strictly after `start_key`, sorted, at most `count`.
+    pub fn keys_paged(&self, prefix: &str, count: usize, start_key: Option<&str>) -> Vec<String> {
+        let known_values = self
+            .storage
+            .iter()
+            .filter(|(_, value)| value.is_some())
+            .map(|(key, _)| key);
+        let keys: BTreeSet<&String> = self.keys.iter().chain(known_values).collect();
+        keys.into_iter()
+            .filter(|key| key.starts_with(prefix))
+            .filter(|key| start_key.is_none_or(|start| key.as_str() > start))
+            .take(count)
+            .cloned()
+            .collect()
+    }
+
+    /// Hash of the recorded block, used when a storage query omits `at`.
+    #[allow(clippy::result_large_err)]
+    fn head_hash(&self) -> Value {
+        self.find_call("chain_getFinalizedHead", &[])
+            .or_else(|_| self.find_call("chain_getBlockHash", &[]))
+            .map(|call| call.result.clone())
+            .unwrap_or_else(|_| Value::String(format!("0x{}", "00".repeat(32))))
+    }
+}
+
+/// Drop trailing `null` params so `[]`, `[null]` and a missing array match.
+fn normalize_params(mut params: Vec<Value>) -> Vec<Value> {
+    while params.last().is_some_and(Value::is_null) {
+        params.pop();
+    }
+    params
+}
+
+/// Local WebSocket JSON-RPC endpoint serving (or capturing) a [`Recording`].
+///
+/// The server stops when dropped.
+pub struct MockRpcServer {
+    addr: SocketAddr,
+    shared: Arc<Shared>,
+    task: JoinHandle<()>,
+}
+
+struct Shared {
+    recording: Mutex<Recording>,
+    /// Live endpoint to forward to while recording.
+    upstream: Option<RpcClient>,
+    /// Live endpoint whose storage reads are proven instead of recorded.
+    verifier: Option<ProofVerifier>,
+    next_subscription: AtomicU64,
+    /// Bumped whenever a live response is recorded.
+    revision: AtomicU64,
+}
+
+impl MockRpcServer {
+    /// Serve `recording` on an ephemeral localhost port.
+    pub async fn start(recording: Recording) -> Result<Self, ChainError> {
+        Self::spawn(recording, None, None).await
+    }
+
+    /// Proxy every request to `upstream_url` and record the responses.
+    pub async fn start_recording(upstream_url: &str) -> Result<Self, ChainError> {
+        let upstream = RpcClient::from_url(upstream_url)
+            .await
+            .map_err(|e| ChainError::Connection(format!("{upstream_url}: {e}")))?;
+        Self::spawn(Recording::default(), Some(upstream), None).await
+    }
+
+    /// Proxy every request to `upstream_url`, answering storage reads only
+    /// once proven against state roots from `trusted` headers.
+    ///
+    /// Finalized heads and headers are served by `trusted`. Nothing is recorded.
+    pub async fn start_verified(
+        upstream_url: &str,
+        trusted: RpcClient,
+    ) -> Result<Self, ChainError> {
+        let upstream = RpcClient::from_url(upstream_url)
+            .await
+            .map_err(|e| ChainError::Connection(format!("{upstream_url}: {e}")))?;
+        let verifier = ProofVerifier::new(upstream, trusted);
+        Self::spawn(Recording::default(), None, Some(verifier)).await
+    }
+
+    async fn spawn(
+        recording: Recording,
+        upstream: Option<RpcClient>,
+        verifier: Option<ProofVerifier>,
+    ) -> Result<Self, ChainError> {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .map_err(|e| ChainError::Connection(format!("Failed to bind mock RPC server: {e}")))?;
+        let addr = listener
+            .local_addr()
+            .map_err(|e| ChainError::Connection(e.to_string()))?;
+        let shared = Arc::new(Shared {
+            recording: Mutex::new(recording),
+            upstream,
+            verifier,
+            next_subscription: AtomicU64::new(1),
+            revision: AtomicU64::new(0),
+        });
+
+        let task_shared = shared.clone();
+        let task = tokio::spawn(async move {
+            loop {
+                let Ok((socket, _)) = listener.accept().await else {
+                    break;
+                };
+                let shared = task_shared.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = serve_connection(socket, shared).await {
+                        tracing::debug!("Mock RPC connection closed: {}", e);
+                    }
+                });
+            }
+        });
+
+        tracing::debug!("Mock RPC server listening on {}", addr);
+        Ok(Self { addr, shared, task })
+    }
+
+    /// WebSocket URL of the server.
+    pub fn url(&self) -> String {
+        format!("ws://{}", self.addr)
+    }
+
+    /// Endpoints routing Asset Hub, relay and People chain to this server.
+    pub fn rpc_endpoints(&self) -> RpcEndpoints {
+        RpcEndpoints {
+            asset_hub: Some(self.url()),
+            relay: Some(self.url()),
+            people: Some(self.url()),
+        }
+    }
+
+    /// Snapshot of the recording, including anything captured so far.
+    pub fn recording(&self) -> Recording {
+        self.shared.recording().clone()
+    }
+
+    /// Counter that changes whenever a new response is recorded.
+    pub fn revision(&self) -> u64 {
+        self.shared.revision.load(Ordering::Relaxed)
+    }
+}
+
+impl Drop for MockRpcServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn serve_connection(
+    socket: TcpStream,
+    shared: Arc<Shared>,
+) -> Result<(), soketto::BoxedError> {
+    let mut server = Server::new(socket.compat());
+    let key = {
+        let request = server.receive_request().await?;
+        request.key()
+    };
+    server
+        .send_response(&Response::Accept {
+            key,
+            protocol: None,
+        })
+        .await?;
+
+    let (mut sender, mut receiver) = server.into_builder().finish();
+    // Replies and proxied notifications share one queue, keeping their order
+    let (outgoing, mut queue) = mpsc::unbounded_channel::<String>();
+    let writer = tokio::spawn(async move {
+        while let Some(text) = queue.recv().await {
+            sender.send_text(text).await?;
+            while let Ok(text) = queue.try_recv() {
+                sender.send_text(text).await?;
+            }
+            sender.flush().await?;
+        }
+        Ok::<_, soketto::connection::Error>(())
+    });
+
+    let mut message = Vec::new();
+    let result = loop {
+        message.clear();
+        match receiver.receive_data(&mut message).await {
+            Ok(_) => {}
+            Err(soketto::connection::Error::Closed) => break Ok(()),
+            Err(e) => break Err(e.into()),
+        }
+        for reply in shared.handle_message(&message, &outgoing).await {
+            // A failed write ends the connection at the next receive
+            let _ = outgoing.send(reply);
+        }
+    };
+    writer.abort();
+    result
+}
+
+impl Shared {
+    fn recording(&self) -> MutexGuard<'_, Recording> {
+        self.recording
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Handle one WebSocket message (single request or batch).
+    ///
+    /// Proxied subscriptions queue their response and updates on `outgoing`.
+    async fn handle_message(
+        &self,
+        message: &[u8],
+        outgoing: &mpsc::UnboundedSender<String>,
+    ) -> Vec<String> {
+        let request: Value = match serde_json::from_slice(message) {
+            Ok(request) => request,
+            Err(e) => {
+                return vec![error_response(Value::Null, -32700, &e.to_string()).to_string()];
+            }
+        };
+
+        match request {
+            Value::Array(batch) => {
+                let mut responses = Vec::with_capacity(batch.len());
+                let mut notifications = Vec::new();
+                for request in batch {
+                    let mut replies = self.handle_request(request, outgoing).await.into_iter();
+                    responses.extend(replies.next());
+                    notifications.extend(replies);
+                }
+                let mut out = vec![Value::Array(responses).to_string()];
+                out.extend(notifications.into_iter().map(|n| n.to_string()));
+                out
+            }
+            request => self
+                .handle_request(request, outgoing)
+                .await
+                .into_iter()
+                .map(|reply| reply.to_string())
+                .collect(),
+        }
+    }
+
+    /// Handle one request; the response comes first, then any notifications.
+    async fn handle_request(
+        &self,
+        request: Value,
+        outgoing: &mpsc::UnboundedSender<String>,
+    ) -> Vec<Value> {
+        let id = request.get("id").cloned().unwrap_or(Value::Null);
+        let Some(method) = request.get("method").and_then(Value::as_str) else {
+            return vec![error_response(id, -32600, "missing method")];
+        };
+        let params = match request.get("params") {
+            None | Some(Value::Null) => Vec::new(),
+            Some(Value::Array(params)) => params.clone(),
+            Some(_) => {
+                return vec![error_response(
+                    id,
+                    INVALID_PARAMS,
+                    "params must be an array",
+                )];
+            }
+        };
+
+        if method == "rpc_methods" {
+            return vec![result_response(id, json!({ "methods": MOCK_RPC_METHODS }))];
+        }
+        if method.contains("_unsubscribe") || method == "author_unwatchExtrinsic" {
+            return vec![result_response(id, Value::Bool(true))];
+        }
+        if let Some(verifier) = &self.verifier
+            && method == "author_submitAndWatchExtrinsic"
+        {
+            return match verifier.submit_and_watch(&params).await {
+                Ok(updates) => {
+                    self.proxy_subscription(id, "author_extrinsicUpdate", updates, outgoing);
+                    Vec::new()
+                }
+                Err(e) => vec![error_response(id, UPSTREAM_ERROR, &e.to_string())],
+            };
+        }
+        if let Some((_, notification, source)) = SUBSCRIPTIONS
+            .iter()
+            .find(|(subscribe, _, _)| *subscribe == method)
+        {
+            return match self.notification_payload(source).await {
+                Ok(payload) => {
+                    let subscription = self
+                        .next_subscription
+                        .fetch_add(1, Ordering::Relaxed)
+                        .to_string();
+                    vec![
+                        result_response(id, Value::String(subscription.clone())),
+                        json!({
+                            "jsonrpc": "2.0",
+                            "method": notification,
+                            "params": { "subscription": subscription, "result": payload },
+                        }),
+                    ]
+                }
+                Err((code, message)) => vec![error_response(id, code, &message)],
+            };
+        }
+
+        let result = match method {
+            "state_getStorage" | "state_queryStorageAt" | "state_getKeysPaged" => {
+                self.storage(method, params).await
+            }
+            _ => self.call(method, params).await,
+        };
+        match result {
+            Ok(result) => vec![result_response(id, result)],
+            Err((code, message)) => vec![error_response(id, code, &message)],
+        }
+    }
+
+    /// Answer a non-storage method from the recording, or forward and record it.
+    async fn call(&self, method: &str, params: Vec<Value>) -> Result<Value, (i64, String)> {
+        if let Some(verifier) = &self.verifier {
+            return match method {
+                // The latest block is the one the light client finalized
+                "chain_getFinalizedHead" => verifier.finalized_head().await.map(Value::String),
+                "chain_getBlockHash" if params.first().is_none_or(Value::is_null) => {
+                    verifier.finalized_head().await.map(Value::String)
+                }
+                "chain_getBlockHash" => {
+                    let number = params
+                        .first()
+                        .and_then(block_number)
+                        .ok_or((INVALID_PARAMS, "invalid block number".to_string()))?;
+                    verifier.block_hash(number).await.map(|hash| json!(hash))
+                }
+                "chain_getHeader" => {
+                    verifier
+                        .header(params.first().and_then(Value::as_str))
+                        .await
+                }
+                _ => return forward(verifier.upstream(), method, &params).await,
+            }
+            .map_err(|e| (UPSTREAM_ERROR, e.to_string()));
+        }
+        if let Some(upstream) = &self.upstream {
+            let result = forward(upstream, method, &params).await?;
+            self.recording().record_call(method, params, result.clone());
+            self.revision.fetch_add(1, Ordering::Relaxed);
+            return Ok(result);
+        }
+
+        self.recording()
+            .find_call(method, &params)
+            .map(|call| call.result.clone())
+            .map_err(|e| (METHOD_NOT_FOUND, e.to_string()))
+    }
+
+    /// Answer a subscription with `updates` from another client, queueing
+    /// the response on `outgoing` ahead of the notifications.
+    fn proxy_subscription(
+        &self,
+        id: Value,
+        notification: &'static str,
+        mut updates: RpcSubscription<Value>,
+        outgoing: &mpsc::UnboundedSender<String>,
+    ) {
+        let subscription = self
+            .next_subscription
+            .fetch_add(1, Ordering::Relaxed)
+            .to_string();
+        let _ = outgoing.send(result_response(id, Value::String(subscription.clone())).to_string());
+        let outgoing = outgoing.clone();
+        tokio::spawn(async move {
+            while let Some(update) = updates.next().await {
+                let update = match update {
+                    Ok(update) => update,
+                    Err(e) => {
+                        tracing::debug!("Proxied {} subscription ended: {}", notification, e);
+                        break;
+                    }
+                };
+                let message = json!({
+                    "jsonrpc": "2.0",
+                    "method": notification,
+                    "params": { "subscription": subscription, "result": update },
+                });
+                if outgoing.send(message.to_string()).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+
+    /// Payload for a subscription's single notification: the live answer
+    /// when proxying, else the latest recorded response of `source`.
+    async fn notification_payload(&self, source: &str) -> Result<Value, (i64, String)> {
+        if self.verifier.is_some() || self.upstream.is_some() {
+            return self.call(source, Vec::new()).await;
+        }
+        self.recording()
+            .latest_call(source)
+            .map(|call| call.result.clone())
+            .ok_or_else(|| {
+                (
+                    METHOD_NOT_FOUND,
+                    format!("No recorded response for {source}"),
+                )
+            })
+    }
+
+    /// Serve a storage method from the snapshot, or forward and record it.
+    async fn storage(&self, method: &str, params: Vec<Value>) -> Result<Value, (i64, String)> {
+        if let Some(verifier) = &self.verifier {
+            return verified_storage(verifier, method, &params).await;
+        }
+        if let Some(upstream) = &self.upstream {
+            let result = forward(upstream, method, &params).await?;
+            record_storage_result(&mut self.recording(), method, &params, &result);
+            self.revision.fetch_add(1, Ordering::Relaxed);
+            return Ok(result);
+        }
+
+        let recording = self.recording();
+        let param_str = |index: usize| params.get(index).and_then(Value::as_str);
+        match method {
+            "state_getStorage" => {
+                let key = param_str(0).ok_or((INVALID_PARAMS, "missing key".to_string()))?;
+                Ok(recording
+                    .storage_value(key)
+                    .map_or(Value::Null, |value| Value::String(value.to_string())))
+            }
+            "state_queryStorageAt" => {
+                let keys = params
+                    .first()
+                    .and_then(Value::as_array)
+                    .ok_or((INVALID_PARAMS, "missing keys".to_string()))?;
+                let changes: Vec<Value> = keys
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(|key| json!([key, recording.storage_value(key)]))
+                    .collect();
+                let block = params
+                    .get(1)
+                    .filter(|at| !at.is_null())
+                    .cloned()
+                    .unwrap_or_else(|| recording.head_hash());
+                Ok(json!([{ "block": block, "changes": changes }]))
+            }
+            _ => {
+                let prefix = param_str(0).unwrap_or("0x");
+                let count = params.get(1).and_then(Value::as_u64).unwrap_or(1000) as usize;
+                Ok(json!(recording.keys_paged(prefix, count, param_str(2))))
+            }
+        }
+    }
+}
+
+/// Serve a storage method with values proven by `verifier`.
+///
+/// Key listings cannot be proven by a read proof and are forwarded as-is.
+async fn verified_storage(
+    verifier: &ProofVerifier,
+    method: &str,
+    params: &[Value],
+) -> Result<Value, (i64, String)> {
+    let at = params.get(1).and_then(Value::as_str);
+    let rejected = |e: ChainError| {
+        tracing::warn!("Rejected unproven {} response: {}", method, e);
+        (UPSTREAM_ERROR, e.to_string())
+    };
+    match method {
+        "state_getStorage" => {
+            let key = params
+                .first()
+                .and_then(Value::as_str)
+                .ok_or((INVALID_PARAMS, "missing key".to_string()))?;
+            let (_, values) = verifier.read(&[key], at).await.map_err(rejected)?;
+            Ok(values
+                .into_iter()
+                .next()
+                .flatten()
+                .map_or(Value::Null, Value::String))
+        }
+        "state_queryStorageAt" => {
+            let keys: Vec<&str> = params
+                .first()
+                .and_then(Value::as_array)
+                .ok_or((INVALID_PARAMS, "missing keys".to_string()))?
+                .iter()
+                .filter_map(Value::as_str)
+                .collect();
+            let (block, values) = verifier.read(&keys, at).await.map_err(rejected)?;
+            let changes: Vec<Value> = keys
+                .iter()
+                .zip(values)
+                .map(|(key, value)| json!([key, value]))
+                .collect();
+            Ok(json!([{ "block": block, "changes": changes }]))
+        }
+        _ => forward(verifier.upstream(), method, params).await,
+    }
+}
+
+/// Copy a live storage response into the recording.
+fn record_storage_result(
+    recording: &mut Recording,
+    method: &str,
+    params: &[Value],
+    result: &Value,
+) {
+    match method {
+        "state_getStorage" => {
+            if let Some(key) = params.first().and_then(Value::as_str) {
+                recording.record_storage(key, result.as_str());
+            }
+        }
+        "state_queryStorageAt" => {
+            let change_sets = result.as_array().into_iter().flatten();
+            for change in change_sets
+                .filter_map(|set| set.get("changes")?.as_array())
+                .flatten()
+            {
+                if let Some(key) = change.get(0).and_then(Value::as_str) {
+                    recording.record_storage(key, change.get(1).and_then(Value::as_str));
+                }
+            }
+        }
+        _ => {
+            let keys = result.as_array().into_iter().flatten();
+            recording
+                .keys
+                .extend(keys.filter_map(Value::as_str).map(str::to_string));
+        }
+    }
+}
+
+async fn forward(
+    upstream: &RpcClient,
+    method: &str,
+    params: &[Value],
+) -> Result<Value, (i64, String)> {
+    let mut rpc_params = RpcParams::new();
+    for param in params {
+        rpc_params
+            .push(param)
+            .map_err(|e| (INVALID_PARAMS, e.to_string()))?;
+    }
+    upstream
+        .request::<Value>(method, rpc_params)
+        .await
+        .map_err(|e| (UPSTREAM_ERROR, e.to_string()))
+}
+
+/// Block number param: a JSON number or a hex string.
+fn block_number(param: &Value) -> Option<u64> {
+    match param {
+        Value::Number(number) => number.as_u64(),
+        Value::String(hex) => u64::from_str_radix(hex.trim_start_matches("0x"), 16).ok(),
+        _ => None,
+    }
+}
+
+fn result_response(id: Value, result: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "result": result })
+}
+
+fn error_response(id: Value, code: i64, message: &str) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hybrid::block_hash_key;
+    use parity_scale_codec::{Compact, Encode};
+    use sp_crypto_hashing::blake2_256;
+
+    fn sample_recording() -> Recording {
+        let mut recording = Recording::default();
+        recording.record_storage("0xaa01", Some("0x01"));
+        recording.record_storage("0xaa02", Some("0x02"));
+        recording.record_storage("0xaa03", None);
+        recording.record_storage("0xbb01", Some("0x03"));
+        recording.keys.insert("0xaa04".to_string());
+        recording.record_call("chain_getFinalizedHead", Vec::new(), json!("0x1234"));
+        recording.record_call("chain_getHeader", Vec::new(), json!({ "number": "0x10" }));
+        recording.record_call("chain_getBlockHash", vec![json!(5)], json!("0x05"));
+        recording.record_call("chain_getBlockHash", vec![json!(6)], json!("0x06"));
+        recording
+    }
+
+    fn replay(recording: Recording) -> Shared {
+        Shared {
+            recording: Mutex::new(recording),
+            upstream: None,
+            verifier: None,
+            next_subscription: AtomicU64::new(1),
+            revision: AtomicU64::new(0),
+        }
+    }
+
+    async fn request(shared: &Shared, method: &str, params: Value) -> Vec<Value> {
+        let (outgoing, _) = mpsc::unbounded_channel();
+        shared
+            .handle_request(
+                json!({ "jsonrpc": "2.0", "id": 7, "method": method, "params": params }),
+                &outgoing,
+            )
+            .await
+    }
+
+    #[test]
+    fn test_find_call_requires_exact_params() {
+        let recording = sample_recording();
+        let call = recording
+            .find_call("chain_getBlockHash", &[json!(6)])
+            .unwrap();
+        assert_eq!(call.result, json!("0x06"));
+
+        // Unknown params are an error, not another block's response
+        let err = recording
+            .find_call("chain_getBlockHash", &[json!(99)])
+            .unwrap_err();
+        assert!(err.to_string().contains("not with params [99]"), "{err}");
+        let err = recording.find_call("system_chain", &[]).unwrap_err();
+        assert!(err.to_string().contains("No recorded response"), "{err}");
+    }
+
+    #[test]
+    fn test_trailing_null_params_are_ignored() {
+        let mut recording = Recording::default();
+        recording.record_call("chain_getHeader", vec![Value::Null], json!("a"));
+        recording.record_call("chain_getHeader", Vec::new(), json!("b"));
+        assert_eq!(recording.calls.len(), 1);
+        assert_eq!(recording.calls[0].result, json!("b"));
+    }
+
+    #[test]
+    fn test_keys_paged() {
+        let recording = sample_recording();
+        assert_eq!(
+            recording.keys_paged("0xaa", 10, None),
+            vec!["0xaa01", "0xaa02", "0xaa04"]
+        );
+        assert_eq!(
+            recording.keys_paged("0xaa", 1, Some("0xaa01")),
+            vec!["0xaa02"]
+        );
+        assert!(recording.keys_paged("0xcc", 10, None).is_empty());
+    }
+
+    #[test]
+    fn test_recording_roundtrip() {
+        let recording = sample_recording();
+        let json = serde_json::to_string(&recording).unwrap();
+        let parsed: Recording = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, recording);
+    }
+
+    #[test]
+    fn test_record_storage_result() {
+        let mut recording = Recording::default();
+        record_storage_result(
+            &mut recording,
+            "state_queryStorageAt",
+            &[json!(["0x01", "0x02"])],
+            &json!([{ "block": "0xff", "changes": [["0x01", "0xaa"], ["0x02", null]] }]),
+        );
+        record_storage_result(
+            &mut recording,
+            "state_getKeysPaged",
+            &[json!("0x0")],
+            &json!(["0x03"]),
+        );
+        assert_eq!(recording.storage_value("0x01"), Some("0xaa"));
+        assert_eq!(recording.storage.get("0x02"), Some(&None));
+        assert!(recording.keys.contains("0x03"));
+    }
+
+    #[tokio::test]
+    async fn test_storage_methods() {
+        let shared = replay(sample_recording());
+
+        let reply = request(&shared, "state_getStorage", json!(["0xaa02"])).await;
+        assert_eq!(reply[0]["result"], json!("0x02"));
+
+        let reply = request(
+            &shared,
+            "state_queryStorageAt",
+            json!([["0xaa01", "0xaa03"]]),
+        )
+        .await;
+        assert_eq!(
+            reply[0]["result"],
+            json!([{ "block": "0x1234", "changes": [["0xaa01", "0x01"], ["0xaa03", null]] }])
+        );
+
+        let reply = request(
+            &shared,
+            "state_getKeysPaged",
+            json!(["0xaa", 2, "0xaa01", "0x1234"]),
+        )
+        .await;
+        assert_eq!(reply[0]["result"], json!(["0xaa02", "0xaa04"]));
+    }
+
+    /// Proxy proving reads served by an upstream that returns `proof_node`,
+    /// against a trusted header committing to `leaf`.
+    async fn verified(leaf: &str, proof_node: &str) -> (Shared, [MockRpcServer; 2]) {
+        let state_root = sp_crypto_hashing::blake2_256(&hex::decode(leaf).unwrap());
+        let mut trusted = Recording::default();
+        trusted.record_call("chain_getFinalizedHead", Vec::new(), json!("0xf1"));
+        trusted.record_call(
+            "chain_getHeader",
+            vec![json!("0xf1")],
+            json!({
+                "parentHash": "0xf0",
+                "number": "0x10",
+                "stateRoot": format!("0x{}", hex::encode(state_root)),
+            }),
+        );
+        let mut upstream = Recording::default();
+        for keys in [json!(["0xaa01"]), json!(["0xaa01", "0xaa02"])] {
+            upstream.record_call(
+                "state_getReadProof",
+                vec![keys, json!("0xf1")],
+                json!({ "at": "0xf1", "proof": [format!("0x{proof_node}")] }),
+            );
+        }
+        verifier_between(trusted, upstream).await
+    }
+
+    /// Proxy taking trusted data from `trusted` and the rest from `upstream`.
+    async fn verifier_between(
+        trusted: Recording,
+        upstream: Recording,
+    ) -> (Shared, [MockRpcServer; 2]) {
+        let trusted = MockRpcServer::start(trusted).await.unwrap();
+        let upstream = MockRpcServer::start(upstream).await.unwrap();
+        let verifier = ProofVerifier::new(
+            RpcClient::from_url(&upstream.url()).await.unwrap(),
+            RpcClient::from_url(&trusted.url()).await.unwrap(),
+        );
+        let mut shared = replay(Recording::default());
+        shared.verifier = Some(verifier);
+        (shared, [trusted, upstream])
+    }
+
+    #[tokio::test]
+    async fn test_verified_storage_is_proven() {
+        // Leaf holding key 0xaa01 with value 0x2a
+        let leaf = "44aa01042a";
+        let (shared, _servers) = verified(leaf, leaf).await;
+
+        let reply = request(&shared, "state_getStorage", json!(["0xaa01"])).await;
+        assert_eq!(reply[0]["result"], json!("0x2a"));
+
+        let reply = request(
+            &shared,
+            "state_queryStorageAt",
+            json!([["0xaa01", "0xaa02"]]),
+        )
+        .await;
+        assert_eq!(
+            reply[0]["result"],
+            json!([{ "block": "0xf1", "changes": [["0xaa01", "0x2a"], ["0xaa02", null]] }])
+        );
+
+        let reply = request(&shared, "chain_getFinalizedHead", json!([])).await;
+        assert_eq!(reply[0]["result"], json!("0xf1"));
+    }
+
+    #[tokio::test]
+    async fn test_verified_storage_rejects_forged_value() {
+        // Upstream claims 0xaa01 holds 0x2b, but the trusted root commits to 0x2a
+        let (shared, _servers) = verified("44aa01042a", "44aa01042b").await;
+
+        let reply = request(&shared, "state_getStorage", json!(["0xaa01"])).await;
+        assert!(reply[0].get("result").is_none());
+        assert_eq!(reply[0]["error"]["code"], json!(UPSTREAM_ERROR));
+    }
+
+    /// JSON header with an empty digest, and its hash.
+    fn header(number: u32, parent_hash: &str, state_root: [u8; 32]) -> (Value, String) {
+        let mut encoded = hex::decode(parent_hash.trim_start_matches("0x")).unwrap();
+        encoded.extend(Compact(number).encode());
+        encoded.extend(state_root);
+        encoded.extend([0x22; 32]);
+        encoded.push(0);
+        let header = json!({
+            "parentHash": parent_hash,
+            "number": format!("0x{number:x}"),
+            "stateRoot": format!("0x{}", hex::encode(state_root)),
+            "extrinsicsRoot": format!("0x{}", "22".repeat(32)),
+            "digest": { "logs": [] },
+        });
+        let hash = format!("0x{}", hex::encode(sp_crypto_hashing::blake2_256(&encoded)));
+        (header, hash)
+    }
+
+    /// Trie node holding only `key` (at least 32 bytes) set to `value`.
+    fn single_leaf(key: &[u8], value: &[u8]) -> Vec<u8> {
+        // Leaf header with 63 or more nibbles, continued in a second byte
+        let mut node = vec![0x7f, (key.len() * 2 - 63) as u8];
+        node.extend(key);
+        node.extend(value.to_vec().encode());
+        node
+    }
+
+    /// Light client finalized at #20, holding nothing older; the endpoint
+    /// serves #19 and #4, with #4's hash in #20's `System.BlockHash`.
+    fn history() -> (Recording, Recording, [String; 3]) {
+        let leaf = hex::decode("44aa01042a").unwrap();
+        let (header_4, hash_4) = header(4, &format!("0x{}", "00".repeat(32)), blake2_256(&leaf));
+        let (header_19, hash_19) = header(19, &format!("0x{}", "33".repeat(32)), [0x11; 32]);
+        let key = block_hash_key(4).unwrap();
+        let block_hashes = single_leaf(
+            &hex::decode(key.trim_start_matches("0x")).unwrap(),
+            &hex::decode(hash_4.trim_start_matches("0x")).unwrap(),
+        );
+        let (header_20, hash_20) = header(20, &hash_19, blake2_256(&block_hashes));
+
+        let mut trusted = Recording::default();
+        trusted.record_call("chain_getFinalizedHead", Vec::new(), json!(hash_20));
+        trusted.record_call("chain_getHeader", vec![json!(hash_20)], header_20);
+        let mut upstream = Recording::default();
+        upstream.record_call("chain_getHeader", vec![json!(hash_4)], header_4);
+        upstream.record_call("chain_getHeader", vec![json!(hash_19)], header_19);
+        upstream.record_call(
+            "state_getReadProof",
+            vec![json!([key]), json!(hash_20)],
+            json!({ "at": hash_20, "proof": [format!("0x{}", hex::encode(&block_hashes))] }),
+        );
+        upstream.record_call(
+            "state_getReadProof",
+            vec![json!(["0xaa01"]), json!(hash_4)],
+            json!({ "at": hash_4, "proof": [format!("0x{}", hex::encode(&leaf))] }),
+        );
+        (trusted, upstream, [hash_4, hash_19, hash_20])
+    }
+
+    #[tokio::test]
+    async fn test_verified_read_before_light_client_window() {
+        let (trusted, upstream, [hash_4, hash_19, _]) = history();
+        let (shared, _servers) = verifier_between(trusted, upstream).await;
+
+        // Block #4 is linked to the finalized head through System.BlockHash
+        let reply = request(&shared, "state_getStorage", json!(["0xaa01", hash_4])).await;
+        assert_eq!(reply[0]["result"], json!("0x2a"));
+        let reply = request(&shared, "chain_getBlockHash", json!([4])).await;
+        assert_eq!(reply[0]["result"], json!(hash_4));
+        let reply = request(&shared, "chain_getHeader", json!([hash_4])).await;
+        assert_eq!(reply[0]["result"]["number"], json!("0x4"));
+        // and #19 through the finalized head's parent hash
+        let reply = request(&shared, "chain_getBlockHash", json!([19])).await;
+        assert_eq!(reply[0]["result"], json!(hash_19));
+    }
+
+    #[tokio::test]
+    async fn test_verified_read_rejects_unlinked_block() {
+        let (trusted, mut upstream, _) = history();
+        // A well-formed #4 that is not the one the finalized chain commits to
+        let leaf = hex::decode("44aa01042b").unwrap();
+        let (forged, forged_hash) = header(4, &format!("0x{}", "00".repeat(32)), blake2_256(&leaf));
+        upstream.record_call("chain_getHeader", vec![json!(forged_hash)], forged);
+        upstream.record_call(
+            "state_getReadProof",
+            vec![json!(["0xaa01"]), json!(forged_hash)],
+            json!({ "at": forged_hash, "proof": [format!("0x{}", hex::encode(&leaf))] }),
+        );
+        let (shared, _servers) = verifier_between(trusted, upstream).await;
+
+        let reply = request(&shared, "state_getStorage", json!(["0xaa01", forged_hash])).await;
+        assert!(reply[0].get("result").is_none());
+        assert_eq!(reply[0]["error"]["code"], json!(UPSTREAM_ERROR));
+        // Nothing links #3 to a verified block
+        let reply = request(&shared, "chain_getBlockHash", json!([3])).await;
+        assert!(reply[0].get("result").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_verified_submit_is_watched_through_light_client() {
+        let mut trusted = Recording::default();
+        trusted.record_call(
+            "author_submitAndWatchExtrinsic",
+            vec![json!("0x00")],
+            json!({ "inBlock": "0xf1" }),
+        );
+        // Upstream has nothing recorded, so only the light client can answer
+        let (shared, _servers) = verifier_between(trusted, Recording::default()).await;
+
+        let (outgoing, mut queue) = mpsc::unbounded_channel();
+        let reply = shared
+            .handle_request(
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": 7,
+                    "method": "author_submitAndWatchExtrinsic",
+                    "params": ["0x00"],
+                }),
+                &outgoing,
+            )
+            .await;
+        assert!(reply.is_empty());
+
+        let response: Value = serde_json::from_str(&queue.recv().await.unwrap()).unwrap();
+        assert_eq!(response["id"], json!(7));
+        let subscription = response["result"].clone();
+        let update: Value = serde_json::from_str(&queue.recv().await.unwrap()).unwrap();
+        assert_eq!(update["method"], json!("author_extrinsicUpdate"));
+        assert_eq!(update["params"]["subscription"], subscription);
+        assert_eq!(update["params"]["result"], json!({ "inBlock": "0xf1" }));
+    }
+
+    #[tokio::test]
+    async fn test_subscription_sends_notification() {
+        let shared = replay(sample_recording());
+        let reply = request(&shared, "chain_subscribeFinalizedHeads", json!([])).await;
+        assert_eq!(reply.len(), 2);
+        let subscription = reply[0]["result"].clone();
+        assert_eq!(reply[1]["method"], json!("chain_finalizedHead"));
+        assert_eq!(reply[1]["params"]["subscription"], subscription);
+        assert_eq!(reply[1]["params"]["result"], json!({ "number": "0x10" }));
+
+        let reply = request(
+            &shared,
+            "chain_unsubscribeFinalizedHeads",
+            json!([subscription]),
+        )
+        .await;
+        assert_eq!(reply[0]["result"], json!(true));
+    }
+
+    #[tokio::test]
+    async fn test_unrecorded_method_errors() {
+        let shared = replay(sample_recording());
+        let reply = request(&shared, "system_chain", json!([])).await;
+        assert_eq!(reply[0]["id"], json!(7));
+        assert_eq!(reply[0]["error"]["code"], json!(METHOD_NOT_FOUND));
+
+        // A recorded method with other params is not served
+        let reply = request(&shared, "chain_getBlockHash", json!([99])).await;
+        assert!(reply[0].get("result").is_none());
+        assert!(
+            reply[0]["error"]["message"]
+                .as_str()
+                .unwrap()
+                .contains("not with params")
+        );
+
+        let reply = request(&shared, "rpc_methods", json!([])).await;
+        let methods = reply[0]["result"]["methods"].as_array().unwrap();
+        assert!(
+            methods
+                .iter()
+                .all(|m| !m.as_str().unwrap().starts_with("chainHead"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_batch_request() {
+        let shared = replay(sample_recording());
+        let batch = json!([
+            { "jsonrpc": "2.0", "id": 1, "method": "chain_getFinalizedHead" },
+            { "jsonrpc": "2.0", "id": 2, "method": "state_getStorage", "params": ["0xbb01"] },
+        ]);
+        let (outgoing, _) = mpsc::unbounded_channel();
+        let replies = shared
+            .handle_message(batch.to_string().as_bytes(), &outgoing)
+            .await;
+        assert_eq!(replies.len(), 1);
+        let replies: Value = serde_json::from_str(&replies[0]).unwrap();
+        assert_eq!(replies[0]["result"], json!("0x1234"));
+        assert_eq!(replies[1]["result"], json!("0x03"));
+    }
+
+    #[tokio::test]
+    async fn test_server_binds_localhost() {
+        let server = MockRpcServer::start(sample_recording()).await.unwrap();
+        assert!(server.url().starts_with("ws://127.0.0.1:"));
+        let endpoints = server.rpc_endpoints();
+        assert_eq!(endpoints.asset_hub.as_deref(), Some(server.url().as_str()));
+        assert_eq!(server.recording(), sample_recording());
+    }
+}
@@ -0,0 +1,470 @@
+//! Merkle proof checking for `state_getReadProof` responses.
+//!
+//! Storage on Polkadot-SDK chains lives in a base-16 Patricia-Merkle trie
+//! hashed with BLAKE2b-256. A read proof is the set of encoded trie nodes on
+//! the path from the state root to each requested key. [`ReadProof::read`]
+//! walks that path from a trusted state root, so a value (or its absence) is
+//! only accepted if it hashes up to the root.
+//!
+//! Both trie layouts are supported: state version 0 with every value inline,
+//! and version 1 where values of 33 bytes or more are stored under their hash
+//! as separate proof entries.
+
+use parity_scale_codec::{Compact, Decode};
+use std::collections::HashMap;
+use thiserror::Error;
+
+const HASH_LEN: usize = 32;
+const EMPTY_TRIE: u8 = 0;
+
+/// Read proof verification error.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ProofError {
+    #[error("Proof is missing trie node 0x{}", hex::encode(.0))]
+    MissingNode([u8; HASH_LEN]),
+    #[error("Proof is missing value 0x{}", hex::encode(.0))]
+    MissingValue([u8; HASH_LEN]),
+    #[error("Invalid trie node: {0}")]
+    InvalidNode(&'static str),
+}
+
+/// Trie nodes of a read proof, by hash.
+#[derive(Debug, Clone, Default)]
+pub struct ReadProof {
+    nodes: HashMap<[u8; HASH_LEN], Vec<u8>>,
+}
+
+impl ReadProof {
+    /// Index the encoded nodes returned by `state_getReadProof`.
+    pub fn new(nodes: impl IntoIterator<Item = Vec<u8>>) -> Self {
+        Self {
+            nodes: nodes
+                .into_iter()
+                .map(|node| (sp_crypto_hashing::blake2_256(&node), node))
+                .collect(),
+        }
+    }
+
+    /// Value stored under `key` in the trie with root `root`.
+    ///
+    /// `Ok(None)` means the proof shows the key is absent. A proof that does
+    /// not reach the key from `root` is an error, never an absent value.
+    pub fn read(&self, root: &[u8; HASH_LEN], key: &[u8]) -> Result<Option<Vec<u8>>, ProofError> {
+        let key = Nibbles::new(key, 0, key.len() * 2);
+        let mut consumed = 0;
+        let mut encoded = self.node(root)?;
+        loop {
+            let Some(node) = decode_node(encoded)? else {
+                return Ok(None);
+            };
+            let partial = node.partial;
+            if key.len - consumed < partial.len
+                || (0..partial.len).any(|i| partial.at(i) != key.at(consumed + i))
+            {
+                return Ok(None);
+            }
+            consumed += partial.len;
+            if consumed == key.len {
+                return self.value(node.value);
+            }
+            let Some(children) = node.children else {
+                return Ok(None);
+            };
+            let index = key.at(consumed) as usize;
+            consumed += 1;
+            encoded = match children[index] {
+                None => return Ok(None),
+                Some(Child::Hash(hash)) => self.node(&hash)?,
+                Some(Child::Inline(inline)) => inline,
+            };
+        }
+    }
+
+    fn node(&self, hash: &[u8; HASH_LEN]) -> Result<&[u8], ProofError> {
+        self.nodes
+            .get(hash)
+            .map(Vec::as_slice)
+            .ok_or(ProofError::MissingNode(*hash))
+    }
+
+    fn value(&self, value: Option<NodeValue<'_>>) -> Result<Option<Vec<u8>>, ProofError> {
+        match value {
+            None => Ok(None),
+            Some(NodeValue::Inline(value)) => Ok(Some(value.to_vec())),
+            Some(NodeValue::Hashed(hash)) => self
+                .nodes
+                .get(&hash)
+                .cloned()
+                .map(Some)
+                .ok_or(ProofError::MissingValue(hash)),
+        }
+    }
+}
+
+/// A run of nibbles inside a byte slice, starting `start` nibbles in.
+#[derive(Debug, Clone, Copy)]
+struct Nibbles<'a> {
+    data: &'a [u8],
+    start: usize,
+    len: usize,
+}
+
+impl<'a> Nibbles<'a> {
+    fn new(data: &'a [u8], start: usize, len: usize) -> Self {
+        Self { data, start, len }
+    }
+
+    fn at(&self, index: usize) -> u8 {
+        let nibble = self.start + index;
+        let byte = self.data[nibble / 2];
+        if nibble.is_multiple_of(2) {
+            byte >> 4
+        } else {
+            byte & 0x0f
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum NodeValue<'a> {
+    Inline(&'a [u8]),
+    Hashed([u8; HASH_LEN]),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Child<'a> {
+    Hash([u8; HASH_LEN]),
+    Inline(&'a [u8]),
+}
+
+/// A decoded trie node; leaves have no children.
+struct Node<'a> {
+    partial: Nibbles<'a>,
+    value: Option<NodeValue<'a>>,
+    children: Option<[Option<Child<'a>>; 16]>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NodeKind {
+    Leaf,
+    Branch,
+    BranchWithValue,
+    HashedValueLeaf,
+    HashedValueBranch,
+}
+
+/// Decode a node in the Polkadot-SDK trie codec; `None` for the empty trie.
+fn decode_node(encoded: &[u8]) -> Result<Option<Node<'_>>, ProofError> {
+    let mut input = encoded;
+    let first = take(&mut input, 1)?[0];
+    if first == EMPTY_TRIE {
+        return Ok(None);
+    }
+    let (kind, prefix_bits) = match first >> 6 {
+        0b01 => (NodeKind::Leaf, 2),
+        0b10 => (NodeKind::Branch, 2),
+        0b11 => (NodeKind::BranchWithValue, 2),
+        _ if first >> 5 == 0b001 => (NodeKind::HashedValueLeaf, 3),
+        _ if first >> 4 == 0b0001 => (NodeKind::HashedValueBranch, 4),
+        _ => return Err(ProofError::InvalidNode("unknown node header")),
+    };
+    let nibble_count = decode_size(first, &mut input, prefix_bits)?;
+
+    let padding = nibble_count % 2;
+    let partial_bytes = take(&mut input, nibble_count.div_ceil(2))?;
+    if padding == 1 && partial_bytes[0] & 0xf0 != 0 {
+        return Err(ProofError::InvalidNode("bad partial key padding"));
+    }
+    let partial = Nibbles::new(partial_bytes, padding, nibble_count);
+
+    let bitmap = match kind {
+        NodeKind::Branch | NodeKind::BranchWithValue | NodeKind::HashedValueBranch => {
+            let bytes = take(&mut input, 2)?;
+            let bitmap = u16::from_le_bytes([bytes[0], bytes[1]]);
+            if bitmap == 0 {
+                return Err(ProofError::InvalidNode("branch without children"));
+            }
+            Some(bitmap)
+        }
+        NodeKind::Leaf | NodeKind::HashedValueLeaf => None,
+    };
+
+    let value = match kind {
+        NodeKind::Leaf | NodeKind::BranchWithValue => {
+            let len = decode_length(&mut input)?;
+            Some(NodeValue::Inline(take(&mut input, len)?))
+        }
+        NodeKind::HashedValueLeaf | NodeKind::HashedValueBranch => {
+            Some(NodeValue::Hashed(take_hash(&mut input)?))
+        }
+        NodeKind::Branch => None,
+    };
+
+    let children = match bitmap {
+        Some(bitmap) => {
+            let mut children = [None; 16];
+            for (index, child) in children.iter_mut().enumerate() {
+                if bitmap & (1 << index) == 0 {
+                    continue;
+                }
+                let len = decode_length(&mut input)?;
+                *child = Some(match len {
+                    HASH_LEN => Child::Hash(take_hash(&mut input)?),
+                    len if len < HASH_LEN => Child::Inline(take(&mut input, len)?),
+                    _ => return Err(ProofError::InvalidNode("oversized inline child")),
+                });
+            }
+            Some(children)
+        }
+        None => None,
+    };
+
+    if !input.is_empty() {
+        return Err(ProofError::InvalidNode("trailing bytes"));
+    }
+    Ok(Some(Node {
+        partial,
+        value,
+        children,
+    }))
+}
+
+/// Partial key length: the header bits after the prefix, continued in
+/// following bytes while they are saturated.
+fn decode_size(first: u8, input: &mut &[u8], prefix_bits: u32) -> Result<usize, ProofError> {
+    let max = (u8::MAX >> prefix_bits) as usize;
+    let mut size = (first as usize) & max;
+    if size < max {
+        return Ok(size);
+    }
+    size -= 1;
+    loop {
+        let next = take(input, 1)?[0] as usize;
+        if next < u8::MAX as usize {
+            return Ok(size + next + 1);
+        }
+        size += u8::MAX as usize;
+    }
+}
+
+fn decode_length(input: &mut &[u8]) -> Result<usize, ProofError> {
+    Compact::<u32>::decode(input)
+        .map(|len| len.0 as usize)
+        .map_err(|_| ProofError::InvalidNode("bad length prefix"))
+}
+
+fn take<'a>(input: &mut &'a [u8], len: usize) -> Result<&'a [u8], ProofError> {
+    if input.len() < len {
+        return Err(ProofError::InvalidNode("truncated node"));
+    }
+    let (head, rest) = input.split_at(len);
+    *input = rest;
+    Ok(head)
+}
+
+fn take_hash(input: &mut &[u8]) -> Result<[u8; HASH_LEN], ProofError> {
+    let mut hash = [0; HASH_LEN];
+    hash.copy_from_slice(take(input, HASH_LEN)?);
+    Ok(hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parity_scale_codec::Encode;
+    use sp_crypto_hashing::blake2_256;
+
+    /// Header byte(s) followed by the nibble-packed partial key.
+    fn header(prefix: u8, prefix_bits: u32, nibbles: &[u8]) -> Vec<u8> {
+        let max = (u8::MAX >> prefix_bits) as usize;
+        let mut out = Vec::new();
+        if nibbles.len() < max {
+            out.push(prefix | nibbles.len() as u8);
+        } else {
+            out.push(prefix | max as u8);
+            let mut rest = nibbles.len() - (max - 1);
+            while rest >= 256 {
+                out.push(u8::MAX);
+                rest -= 255;
+            }
+            out.push((rest - 1) as u8);
+        }
+        if nibbles.len() % 2 == 1 {
+            out.push(nibbles[0]);
+        }
+        let even = &nibbles[nibbles.len() % 2..];
+        out.extend(even.chunks(2).map(|pair| (pair[0] << 4) | pair[1]));
+        out
+    }
+
+    fn leaf(nibbles: &[u8], value: &[u8]) -> Vec<u8> {
+        let mut node = header(0b01 << 6, 2, nibbles);
+        node.extend(value.to_vec().encode());
+        node
+    }
+
+    fn hashed_leaf(nibbles: &[u8], value: &[u8]) -> Vec<u8> {
+        let mut node = header(0b001 << 5, 3, nibbles);
+        node.extend(blake2_256(value));
+        node
+    }
+
+    /// Branch with children as (nibble, encoded child node).
+    fn branch(nibbles: &[u8], value: Option<&[u8]>, children: &[(u8, &[u8])]) -> Vec<u8> {
+        let prefix = if value.is_some() {
+            0b11 << 6
+        } else {
+            0b10 << 6
+        };
+        let mut node = header(prefix, 2, nibbles);
+        let bitmap = children
+            .iter()
+            .fold(0u16, |bitmap, (index, _)| bitmap | (1 << index));
+        node.extend(bitmap.to_le_bytes());
+        if let Some(value) = value {
+            node.extend(value.to_vec().encode());
+        }
+        for (_, child) in children {
+            if child.len() < HASH_LEN {
+                node.extend(child.to_vec().encode());
+            } else {
+                node.extend(blake2_256(child).to_vec().encode());
+            }
+        }
+        node
+    }
+
+    #[test]
+    fn test_single_leaf() {
+        let root_node = leaf(&[1, 2, 3, 4], b"hello");
+        let root = blake2_256(&root_node);
+        let proof = ReadProof::new([root_node]);
+
+        assert_eq!(
+            proof.read(&root, &[0x12, 0x34]),
+            Ok(Some(b"hello".to_vec()))
+        );
+        assert_eq!(proof.read(&root, &[0x12, 0x35]), Ok(None));
+        assert_eq!(proof.read(&root, &[0x12]), Ok(None));
+        assert_eq!(proof.read(&root, &[0x12, 0x34, 0x56]), Ok(None));
+    }
+
+    #[test]
+    fn test_branch_with_hashed_and_inline_children() {
+        // Key 0xab.. sits under a hashed child, 0xc1 under an inline one
+        let long_value = [7u8; 40];
+        let hashed_child = leaf(&[0xb, 0x0, 0x1], &long_value);
+        let inline_child = leaf(&[0x1], b"x");
+        assert!(hashed_child.len() >= HASH_LEN);
+        assert!(inline_child.len() < HASH_LEN);
+        let root_node = branch(
+            &[],
+            Some(b"root"),
+            &[(0xa, &hashed_child), (0xc, &inline_child)],
+        );
+        let root = blake2_256(&root_node);
+        let proof = ReadProof::new([root_node, hashed_child]);
+
+        assert_eq!(
+            proof.read(&root, &[0xab, 0x01]),
+            Ok(Some(long_value.to_vec()))
+        );
+        assert_eq!(proof.read(&root, &[0xc1]), Ok(Some(b"x".to_vec())));
+        assert_eq!(proof.read(&root, &[]), Ok(Some(b"root".to_vec())));
+        assert_eq!(proof.read(&root, &[0xd0]), Ok(None));
+        assert_eq!(proof.read(&root, &[0xab, 0x02]), Ok(None));
+    }
+
+    #[test]
+    fn test_odd_partial_key_and_hashed_value() {
+        let value = [9u8; 64];
+        let child = hashed_leaf(&[0x4, 0x5, 0x6], &value);
+        let root_node = branch(&[0x1, 0x2, 0x3], None, &[(0x0, &child)]);
+        let root = blake2_256(&root_node);
+        let key = [0x12, 0x30, 0x45, 0x60];
+
+        let proof = ReadProof::new([root_node.clone(), child.clone(), value.to_vec()]);
+        // Key nibbles: 1 2 3 | 0 | 4 5 6 0; the leaf only holds 4 5 6
+        assert_eq!(proof.read(&root, &key), Ok(None));
+        assert_eq!(proof.read(&root, &[0x12, 0x30, 0x45, 0x6]), Ok(None));
+
+        let child = hashed_leaf(&[0x4, 0x5, 0x6, 0x0], &value);
+        let root_node = branch(&[0x1, 0x2, 0x3], None, &[(0x0, &child)]);
+        let root = blake2_256(&root_node);
+        let proof = ReadProof::new([root_node.clone(), child.clone(), value.to_vec()]);
+        assert_eq!(proof.read(&root, &key), Ok(Some(value.to_vec())));
+
+        let without_value = ReadProof::new([root_node, child]);
+        assert_eq!(
+            without_value.read(&root, &key),
+            Err(ProofError::MissingValue(blake2_256(&value)))
+        );
+    }
+
+    #[test]
+    fn test_tampered_proof_is_rejected() {
+        let child = leaf(&[0x1; 39], &[5u8; 40]);
+        let root_node = branch(&[], None, &[(0x2, &child)]);
+        let root = blake2_256(&root_node);
+        let key: Vec<u8> = std::iter::once(0x21)
+            .chain(std::iter::repeat_n(0x11, 19))
+            .collect();
+        let proof = ReadProof::new([root_node.clone(), child.clone()]);
+        assert_eq!(proof.read(&root, &key), Ok(Some(vec![5u8; 40])));
+
+        // A different value changes the child hash, which the root commits to
+        let forged = leaf(&[0x1; 39], &[6u8; 40]);
+        let proof = ReadProof::new([root_node, forged]);
+        assert_eq!(
+            proof.read(&root, &key),
+            Err(ProofError::MissingNode(blake2_256(&child)))
+        );
+
+        // Nothing hashes to the root
+        assert_eq!(
+            ReadProof::new([]).read(&root, &key),
+            Err(ProofError::MissingNode(root))
+        );
+    }
+
+    #[test]
+    fn test_long_partial_key_size() {
+        // 63 nibbles needs the continuation byte after a 2-bit prefix
+        let nibbles = [0x7; 63];
+        let node = leaf(&nibbles, b"v");
+        assert_eq!(node[0], 0b0111_1111);
+        assert_eq!(node[1], 0);
+        let root = blake2_256(&node);
+        let mut key = vec![0x77; 31];
+        key.push(0x70);
+        // 64 key nibbles against a 63-nibble leaf
+        assert_eq!(ReadProof::new([node.clone()]).read(&root, &key), Ok(None));
+
+        let node = leaf(&[0x7; 64], b"v");
+        let root = blake2_256(&node);
+        let key = vec![0x77; 32];
+        assert_eq!(
+            ReadProof::new([node]).read(&root, &key),
+            Ok(Some(b"v".to_vec()))
+        );
+    }
+
+    #[test]
+    fn test_invalid_nodes() {
+        assert_eq!(
+            decode_node(&[0b0000_1000]).err(),
+            Some(ProofError::InvalidNode("unknown node header"))
+        );
+        // Leaf claiming 4 nibbles with one byte of key
+        assert_eq!(
+            decode_node(&[0b0100_0100, 0x12]).err(),
+            Some(ProofError::InvalidNode("truncated node"))
+        );
+        // Odd partial key with a non-zero padding nibble
+        assert_eq!(
+            decode_node(&[0b0100_0001, 0x21, 0x00]).err(),
+            Some(ProofError::InvalidNode("bad partial key padding"))
+        );
+        assert!(decode_node(&[EMPTY_TRIE]).unwrap().is_none());
+    }
+}
@@ -0,0 +1,494 @@
+//! Export of Polkadot Vault signing QR codes to image files.
+//!
+//! Takes the raw UOS payload produced by [`crate::encode_for_qr`] and renders it
+//! either as a single static QR code (PNG or SVG) or as an animated GIF that
+//! cycles through UOS multipart frames, so a signing request can be handed to
+//! whoever holds the Vault device.
+
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::{Delay, Frame, ImageFormat, Luma, Rgba, RgbaImage};
+use qrcode::render::svg;
+use qrcode::{EcLevel, QrCode, Version};
+
+use crate::error::ChainError;
+
+/// Size of the UOS multipart frame header: `[0x00][total:2 BE][index:2 BE]`.
+pub const UOS_MULTIPART_HEADER_LEN: usize = 5;
+
+/// Default number of payload bytes per exported multipart frame.
+///
+/// Keeps each frame around QR version 11, which Vault scans reliably from a
+/// screen at arm's length.
+pub const DEFAULT_EXPORT_CHUNK_SIZE: usize = 245;
+
+/// Delay between animated frames, in milliseconds.
+pub const DEFAULT_FRAME_DELAY_MS: u32 = 250;
+
+/// Pixels per QR module in raster exports.
+const MODULE_PIXELS: u32 = 8;
+
+/// Quiet zone around raster exports, in modules.
+const QUIET_ZONE_MODULES: u32 = 4;
+
+/// File format for an exported signing QR.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QrExportFormat {
+    /// Single static PNG, falling back to an animated GIF when the payload
+    /// does not fit in one QR code.
+    #[default]
+    Auto,
+    /// Single static PNG image.
+    Png,
+    /// Single static SVG image.
+    Svg,
+    /// Animated GIF cycling through UOS multipart frames.
+    Gif,
+}
+
+impl QrExportFormat {
+    /// All selectable formats.
+    pub fn all() -> &'static [QrExportFormat] {
+        &[
+            QrExportFormat::Auto,
+            QrExportFormat::Png,
+            QrExportFormat::Svg,
+            QrExportFormat::Gif,
+        ]
+    }
+
+    /// Short lowercase name, as accepted by [`FromStr`].
+    pub fn name(&self) -> &'static str {
+        match self {
+            QrExportFormat::Auto => "auto",
+            QrExportFormat::Png => "png",
+            QrExportFormat::Svg => "svg",
+            QrExportFormat::Gif => "gif",
+        }
+    }
+
+    /// Resolve `Auto` to a concrete format for the given payload.
+    pub fn resolve(self, data: &[u8]) -> QrExportFormat {
+        match self {
+            QrExportFormat::Auto => {
+                if fits_single_qr(data) {
+                    QrExportFormat::Png
+                } else {
+                    QrExportFormat::Gif
+                }
+            }
+            other => other,
+        }
+    }
+
+    /// File extension for a concrete format (`Auto` maps to `png`).
+    pub fn extension(&self) -> &'static str {
+        match self {
+            QrExportFormat::Auto | QrExportFormat::Png => "png",
+            QrExportFormat::Svg => "svg",
+            QrExportFormat::Gif => "gif",
+        }
+    }
+}
+
+impl std::fmt::Display for QrExportFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for QrExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "auto" => Ok(QrExportFormat::Auto),
+            "png" => Ok(QrExportFormat::Png),
+            "svg" => Ok(QrExportFormat::Svg),
+            "gif" => Ok(QrExportFormat::Gif),
+            other => Err(format!(
+                "unknown QR export format '{}' (expected auto, png, svg or gif)",
+                other
+            )),
+        }
+    }
+}
+
+/// Result of a successful export.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QrExport {
+    /// Concrete format that was written.
+    pub format: QrExportFormat,
+    /// Encoded file contents.
+    pub bytes: Vec<u8>,
+    /// Number of QR frames in the export (1 for static images).
+    pub frames: usize,
+}
+
+/// Number of UOS multipart frames needed for `data_len` bytes.
+pub fn uos_multipart_frame_count(data_len: usize, chunk_size: usize) -> u16 {
+    let chunk_size = chunk_size.max(1);
+    data_len.div_ceil(chunk_size).clamp(1, u16::MAX as usize) as u16
+}
+
+/// Build UOS multipart frame `index` for `data`.
+///
+/// Frame layout: `[0x00][total_frames:2 BE][frame_index:2 BE][frame_data]`.
+/// The index wraps around the total frame count so callers can pass an
+/// ever-increasing animation counter.
+pub fn uos_multipart_frame(data: &[u8], chunk_size: usize, index: usize) -> Vec<u8> {
+    let chunk_size = chunk_size.max(1);
+    let total = uos_multipart_frame_count(data.len(), chunk_size);
+    let frame_idx = (index % total as usize) as u16;
+
+    let start = (frame_idx as usize * chunk_size).min(data.len());
+    let end = (start + chunk_size).min(data.len());
+    let chunk = &data[start..end];
+
+    let mut frame = Vec::with_capacity(UOS_MULTIPART_HEADER_LEN + chunk.len());
+    frame.push(0x00);
+    frame.extend_from_slice(&total.to_be_bytes());
+    frame.extend_from_slice(&frame_idx.to_be_bytes());
+    frame.extend_from_slice(chunk);
+    frame
+}
+
+/// Build every UOS multipart frame for `data`.
+pub fn uos_multipart_frames(data: &[u8], chunk_size: usize) -> Vec<Vec<u8>> {
+    let total = uos_multipart_frame_count(data.len(), chunk_size) as usize;
+    (0..total)
+        .map(|index| uos_multipart_frame(data, chunk_size, index))
+        .collect()
+}
+
+/// QR version that fits a full multipart frame of `frame_size` bytes.
+///
+/// Using the same version for every frame keeps the animation dimensions
+/// stable even when the last frame is short.
+/// Version capacity (EcLevel::L): v6=106, v7=122, v8=152, v9=180, v10=213, v11=251, v12=287
+pub fn multipart_qr_version(frame_size: usize) -> i16 {
+    if frame_size <= 106 {
+        6
+    } else if frame_size <= 122 {
+        7
+    } else if frame_size <= 152 {
+        8
+    } else if frame_size <= 180 {
+        9
+    } else if frame_size <= 213 {
+        10
+    } else if frame_size <= 251 {
+        11
+    } else {
+        12
+    }
+}
+
+/// Whether `data` fits into a single QR code at low error correction.
+pub fn fits_single_qr(data: &[u8]) -> bool {
+    QrCode::with_error_correction_level(data, EcLevel::L).is_ok()
+}
+
+/// Export a UOS payload in the requested format.
+#[allow(clippy::result_large_err)]
+pub fn export_qr(data: &[u8], format: QrExportFormat) -> Result<QrExport, ChainError> {
+    if data.is_empty() {
+        return Err(ChainError::QrExport("QR payload is empty".to_string()));
+    }
+
+    match format.resolve(data) {
+        QrExportFormat::Png | QrExportFormat::Auto => {
+            let qr = single_qr(data)?;
+            Ok(QrExport {
+                format: QrExportFormat::Png,
+                bytes: encode_png(&qr)?,
+                frames: 1,
+            })
+        }
+        QrExportFormat::Svg => {
+            let qr = single_qr(data)?;
+            let bytes = qr
+                .render::<svg::Color<'_>>()
+                .quiet_zone(true)
+                .min_dimensions(256, 256)
+                .build()
+                .into_bytes();
+            Ok(QrExport {
+                format: QrExportFormat::Svg,
+                bytes,
+                frames: 1,
+            })
+        }
+        QrExportFormat::Gif => {
+            let frames = uos_multipart_frames(data, DEFAULT_EXPORT_CHUNK_SIZE);
+            let bytes = encode_gif(&frames, DEFAULT_EXPORT_CHUNK_SIZE, DEFAULT_FRAME_DELAY_MS)?;
+            Ok(QrExport {
+                format: QrExportFormat::Gif,
+                bytes,
+                frames: frames.len(),
+            })
+        }
+    }
+}
+
+/// Default file name for an exported signing QR, e.g. `stkopt-tx-1700000000.gif`.
+pub fn default_export_file_name(format: QrExportFormat, timestamp_secs: u64) -> String {
+    format!("stkopt-tx-{}.{}", timestamp_secs, format.extension())
+}
+
+/// Export a UOS payload into `dir`, creating the directory if needed.
+///
+/// Returns the written path and the export metadata.
+#[allow(clippy::result_large_err)]
+pub fn write_qr_export(
+    data: &[u8],
+    format: QrExportFormat,
+    dir: &Path,
+) -> Result<(PathBuf, QrExport), ChainError> {
+    let export = export_qr(data, format)?;
+    std::fs::create_dir_all(dir)
+        .map_err(|e| ChainError::QrExport(format!("failed to create {}: {}", dir.display(), e)))?;
+
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let path = dir.join(default_export_file_name(export.format, timestamp));
+    std::fs::write(&path, &export.bytes)
+        .map_err(|e| ChainError::QrExport(format!("failed to write {}: {}", path.display(), e)))?;
+
+    Ok((path, export))
+}
+
+#[allow(clippy::result_large_err)]
+fn single_qr(data: &[u8]) -> Result<QrCode, ChainError> {
+    QrCode::with_error_correction_level(data, EcLevel::L).map_err(|e| {
+        ChainError::QrExport(format!(
+            "payload of {} bytes does not fit in a single QR code ({}); export as GIF instead",
+            data.len(),
+            e
+        ))
+    })
+}
+
+#[allow(clippy::result_large_err)]
+fn encode_png(qr: &QrCode) -> Result<Vec<u8>, ChainError> {
+    let image = qr
+        .render::<Luma<u8>>()
+        .quiet_zone(true)
+        .module_dimensions(MODULE_PIXELS, MODULE_PIXELS)
+        .build();
+    let mut bytes = Vec::new();
+    image
+        .write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
+        .map_err(|e| ChainError::QrExport(format!("PNG encoding failed: {}", e)))?;
+    Ok(bytes)
+}
+
+/// Rasterise a QR code to RGBA with a fixed module size and quiet zone.
+fn qr_to_rgba(qr: &QrCode) -> RgbaImage {
+    let width = qr.width() as u32;
+    let side = (width + QUIET_ZONE_MODULES * 2) * MODULE_PIXELS;
+    let colors = qr.to_colors();
+    RgbaImage::from_fn(side, side, |x, y| {
+        let mx = (x / MODULE_PIXELS).checked_sub(QUIET_ZONE_MODULES);
+        let my = (y / MODULE_PIXELS).checked_sub(QUIET_ZONE_MODULES);
+        let dark = match (mx, my) {
+            (Some(mx), Some(my)) if mx < width && my < width => {
+                colors[(my * width + mx) as usize] == qrcode::Color::Dark
+            }
+            _ => false,
+        };
+        if dark {
+            Rgba([0, 0, 0, 255])
+        } else {
+            Rgba([255, 255, 255, 255])
+        }
+    })
+}
+
+#[allow(clippy::result_large_err)]
+fn encode_gif(frames: &[Vec<u8>], chunk_size: usize, delay_ms: u32) -> Result<Vec<u8>, ChainError> {
+    let version = multipart_qr_version(UOS_MULTIPART_HEADER_LEN + chunk_size);
+    let mut bytes = Vec::new();
+    {
+        let mut encoder = GifEncoder::new_with_speed(&mut bytes, 10);
+        encoder
+            .set_repeat(Repeat::Infinite)
+            .map_err(|e| ChainError::QrExport(format!("GIF encoding failed: {}", e)))?;
+        for frame in frames {
+            let qr = QrCode::with_version(frame, Version::Normal(version), EcLevel::L)
+                .or_else(|_| QrCode::with_error_correction_level(frame, EcLevel::L))
+                .map_err(|e| ChainError::QrExport(format!("QR frame encoding failed: {}", e)))?;
+            let delay = Delay::from_numer_denom_ms(delay_ms, 1);
+            encoder
+                .encode_frame(Frame::from_parts(qr_to_rgba(&qr), 0, 0, delay))
+                .map_err(|e| ChainError::QrExport(format!("GIF encoding failed: {}", e)))?;
+        }
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payload(len: usize) -> Vec<u8> {
+        let mut data = vec![0x53, 0x01, 0x02];
+        data.extend((0..len.saturating_sub(3)).map(|i| (i % 251) as u8));
+        data
+    }
+
+    #[test]
+    fn test_format_from_str() {
+        assert_eq!("png".parse::<QrExportFormat>(), Ok(QrExportFormat::Png));
+        assert_eq!("SVG".parse::<QrExportFormat>(), Ok(QrExportFormat::Svg));
+        assert_eq!(" gif ".parse::<QrExportFormat>(), Ok(QrExportFormat::Gif));
+        assert_eq!("auto".parse::<QrExportFormat>(), Ok(QrExportFormat::Auto));
+        assert!("bmp".parse::<QrExportFormat>().is_err());
+    }
+
+    #[test]
+    fn test_format_name_round_trips() {
+        for format in QrExportFormat::all() {
+            assert_eq!(format.name().parse::<QrExportFormat>(), Ok(*format));
+            assert_eq!(format.to_string(), format.name());
+        }
+    }
+
+    #[test]
+    fn test_format_extension() {
+        assert_eq!(QrExportFormat::Auto.extension(), "png");
+        assert_eq!(QrExportFormat::Png.extension(), "png");
+        assert_eq!(QrExportFormat::Svg.extension(), "svg");
+        assert_eq!(QrExportFormat::Gif.extension(), "gif");
+    }
+
+    #[test]
+    fn test_auto_resolves_by_payload_size() {
+        assert_eq!(
+            QrExportFormat::Auto.resolve(&payload(200)),
+            QrExportFormat::Png
+        );
+        assert_eq!(
+            QrExportFormat::Auto.resolve(&payload(4000)),
+            QrExportFormat::Gif
+        );
+        assert_eq!(
+            QrExportFormat::Svg.resolve(&payload(4000)),
+            QrExportFormat::Svg
+        );
+    }
+
+    #[test]
+    fn test_frame_count() {
+        assert_eq!(uos_multipart_frame_count(0, 100), 1);
+        assert_eq!(uos_multipart_frame_count(100, 100), 1);
+        assert_eq!(uos_multipart_frame_count(101, 100), 2);
+        assert_eq!(uos_multipart_frame_count(10, 0), 10);
+    }
+
+    #[test]
+    fn test_multipart_frame_header_and_chunks() {
+        let data = payload(250);
+        let frames = uos_multipart_frames(&data, 100);
+        assert_eq!(frames.len(), 3);
+        for (i, frame) in frames.iter().enumerate() {
+            assert_eq!(frame[0], 0x00);
+            assert_eq!(u16::from_be_bytes([frame[1], frame[2]]), 3);
+            assert_eq!(u16::from_be_bytes([frame[3], frame[4]]), i as u16);
+        }
+        assert_eq!(frames[2].len(), UOS_MULTIPART_HEADER_LEN + 50);
+
+        let reassembled: Vec<u8> = frames
+            .iter()
+            .flat_map(|f| f[UOS_MULTIPART_HEADER_LEN..].to_vec())
+            .collect();
+        assert_eq!(reassembled, data);
+    }
+
+    #[test]
+    fn test_multipart_frame_index_wraps() {
+        let data = payload(250);
+        assert_eq!(
+            uos_multipart_frame(&data, 100, 4),
+            uos_multipart_frame(&data, 100, 1)
+        );
+    }
+
+    #[test]
+    fn test_multipart_qr_version() {
+        assert_eq!(multipart_qr_version(85), 6);
+        assert_eq!(multipart_qr_version(122), 7);
+        assert_eq!(multipart_qr_version(185), 10);
+        assert_eq!(multipart_qr_version(250), 11);
+        assert_eq!(multipart_qr_version(300), 12);
+    }
+
+    #[test]
+    fn test_export_png() {
+        let export = export_qr(&payload(150), QrExportFormat::Png).unwrap();
+        assert_eq!(export.format, QrExportFormat::Png);
+        assert_eq!(export.frames, 1);
+        assert_eq!(&export.bytes[..8], b"\x89PNG\r\n\x1a\n");
+    }
+
+    #[test]
+    fn test_export_svg() {
+        let export = export_qr(&payload(150), QrExportFormat::Svg).unwrap();
+        assert_eq!(export.format, QrExportFormat::Svg);
+        let text = String::from_utf8(export.bytes).unwrap();
+        assert!(text.contains("<svg"));
+    }
+
+    #[test]
+    fn test_export_gif_is_animated() {
+        let data = payload(700);
+        let export = export_qr(&data, QrExportFormat::Gif).unwrap();
+        assert_eq!(export.format, QrExportFormat::Gif);
+        assert_eq!(
+            export.frames,
+            uos_multipart_frame_count(data.len(), DEFAULT_EXPORT_CHUNK_SIZE) as usize
+        );
+        assert!(export.frames > 1);
+        assert_eq!(&export.bytes[..6], b"GIF89a");
+    }
+
+    #[test]
+    fn test_export_auto_falls_back_to_gif() {
+        let export = export_qr(&payload(4000), QrExportFormat::Auto).unwrap();
+        assert_eq!(export.format, QrExportFormat::Gif);
+    }
+
+    #[test]
+    fn test_export_static_rejects_oversized_payload() {
+        let err = export_qr(&payload(4000), QrExportFormat::Png).unwrap_err();
+        assert!(err.to_string().contains("GIF"));
+    }
+
+    #[test]
+    fn test_export_rejects_empty_payload() {
+        assert!(export_qr(&[], QrExportFormat::Png).is_err());
+    }
+
+    #[test]
+    fn test_default_export_file_name() {
+        assert_eq!(
+            default_export_file_name(QrExportFormat::Gif, 1_700_000_000),
+            "stkopt-tx-1700000000.gif"
+        );
+    }
+
+    #[test]
+    fn test_write_qr_export_creates_file() {
+        let dir =
+            std::env::temp_dir().join(format!("stkopt-qr-export-test-{}", std::process::id()));
+        let (path, export) = write_qr_export(&payload(120), QrExportFormat::Svg, &dir).unwrap();
+        assert_eq!(path.extension().and_then(|e| e.to_str()), Some("svg"));
+        assert_eq!(std::fs::read(&path).unwrap(), export.bytes);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -4,20 +4,113 @@ use super::decode_helpers::extract_account_id;
 use crate::ChainClient;
 use crate::error::ChainError;
 use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
 use stkopt_core::{Balance, EraIndex};
 use subxt::dynamic::{At, Value};
+use subxt::ext::scale_value::{Composite, ValueDef};
 use subxt::utils::AccountId32;
 
+/// `Balances.Locks` id of lock-based (pre-holds) staking.
+const STAKING_LOCK_ID: &str = "staking ";
+
 /// Account balance information.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AccountBalance {
     pub free: Balance,
     pub reserved: Balance,
     pub frozen: Balance,
 }
 
+/// A lock, freeze or hold on part of an account's balance.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BalanceRestriction {
+    /// Raw identifier: the 8-byte lock id, or `Pallet.Reason` for freezes
+    /// and holds.
+    pub id: String,
+    /// Human-readable reason.
+    pub reason: String,
+    pub amount: Balance,
+}
+
+/// A `Vesting.Vesting` schedule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VestingSchedule {
+    /// Amount locked when the schedule started.
+    pub locked: Balance,
+    /// Amount released per block.
+    pub per_block: Balance,
+    pub starting_block: u32,
+}
+
+/// Why an account's funds are, or are not, available.
+///
+/// Locks and freezes overlap rather than add up: the largest of them sets
+/// `frozen`, and frozen funds may still be held. Holds (pool and staking
+/// stake, deposits) are part of `reserved` and no longer in `free`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BalanceBreakdown {
+    pub balance: AccountBalance,
+    pub existential_deposit: Balance,
+    /// `Balances.Locks`: conviction voting, vesting, legacy staking.
+    pub locks: Vec<BalanceRestriction>,
+    /// `Balances.Freezes`.
+    pub freezes: Vec<BalanceRestriction>,
+    /// `Balances.Holds`: staking, pool delegation, deposits.
+    pub holds: Vec<BalanceRestriction>,
+    pub vesting: Vec<VestingSchedule>,
+}
+
+impl BalanceBreakdown {
+    /// Amount that can be transferred while keeping the account alive.
+    pub fn transferable(&self) -> Balance {
+        let untouchable = self
+            .balance
+            .frozen
+            .saturating_sub(self.balance.reserved)
+            .max(self.existential_deposit);
+        self.balance.free.saturating_sub(untouchable)
+    }
+
+    /// Amount that can be newly bonded, directly or into a pool.
+    ///
+    /// Staking may use funds locked for governance or vesting, so only the
+    /// existential deposit is kept back, plus funds already bonded under a
+    /// legacy staking lock (held stake has already left `free`).
+    pub fn bondable(&self) -> Balance {
+        let staking_lock = self
+            .locks
+            .iter()
+            .filter(|lock| lock.id == STAKING_LOCK_ID)
+            .map(|lock| lock.amount)
+            .max()
+            .unwrap_or(0);
+        self.balance
+            .free
+            .saturating_sub(staking_lock)
+            .saturating_sub(self.existential_deposit)
+    }
+
+    /// Total of all holds.
+    pub fn held(&self) -> Balance {
+        self.holds.iter().map(|hold| hold.amount).sum()
+    }
+
+    /// Locks, freezes and holds, labelled by kind, largest first.
+    pub fn restrictions(&self) -> Vec<(&'static str, &BalanceRestriction)> {
+        let mut all: Vec<_> = self
+            .locks
+            .iter()
+            .map(|r| ("Lock", r))
+            .chain(self.freezes.iter().map(|r| ("Freeze", r)))
+            .chain(self.holds.iter().map(|r| ("Hold", r)))
+            .collect();
+        all.sort_by_key(|r| Reverse(r.1.amount));
+        all
+    }
+}
+
 /// Staking ledger information.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct StakingLedger {
     pub stash: AccountId32,
     pub total: Balance,
@@ -26,7 +119,7 @@ pub struct StakingLedger {
 }
 
 /// An unlocking chunk.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct UnlockChunk {
     pub value: Balance,
     pub era: EraIndex,
@@ -102,34 +195,70 @@ impl ChainClient {
             });
         };
 
-        let decoded: Value = value.decode()?;
-
-        // AccountInfo = { nonce, consumers, providers, sufficients, data: AccountData }
-        // AccountData = { free, reserved, frozen, flags }
-        let data = decoded.at("data");
-
-        let free = data
-            .and_then(|d| d.at("free"))
-            .and_then(|v: &Value| v.as_u128())
-            .unwrap_or(0);
-
-        let reserved = data
-            .and_then(|d| d.at("reserved"))
-            .and_then(|v: &Value| v.as_u128())
-            .unwrap_or(0);
+        Ok(parse_account_balance(&value.decode()?))
+    }
 
-        let frozen = data
-            .and_then(|d| d.at("frozen"))
-            .and_then(|v: &Value| v.as_u128())
-            .unwrap_or(0);
+    /// Get the balance with its locks, freezes, holds and vesting schedules.
+    pub async fn get_balance_breakdown(
+        &self,
+        account: &AccountId32,
+    ) -> Result<BalanceBreakdown, ChainError> {
+        let balance = self.get_account_balance(account).await?;
+        let locks = self
+            .fetch_account_entry("Balances", "Locks", account)
+            .await?;
+        let freezes = self
+            .fetch_account_entry("Balances", "Freezes", account)
+            .await?;
+        let holds = self
+            .fetch_account_entry("Balances", "Holds", account)
+            .await?;
+        let vesting = self
+            .fetch_account_entry("Vesting", "Vesting", account)
+            .await?;
 
-        Ok(AccountBalance {
-            free,
-            reserved,
-            frozen,
+        let block = self.client().at_current_block().await?;
+        let existential_deposit = block
+            .constants()
+            .entry(&("Balances", "ExistentialDeposit"))?
+            .as_u128()
+            .ok_or_else(|| {
+                ChainError::InvalidData("Invalid constant Balances::ExistentialDeposit".into())
+            })?;
+
+        Ok(BalanceBreakdown {
+            balance,
+            existential_deposit,
+            locks: locks.as_ref().map(parse_locks).unwrap_or_default(),
+            freezes: freezes.as_ref().map(parse_id_amounts).unwrap_or_default(),
+            holds: holds.as_ref().map(parse_id_amounts).unwrap_or_default(),
+            vesting: vesting.as_ref().map(parse_vesting).unwrap_or_default(),
         })
     }
 
+    /// Fetch an account-keyed storage entry, `None` if it or its pallet is
+    /// absent from the runtime.
+    async fn fetch_account_entry(
+        &self,
+        pallet: &str,
+        entry: &str,
+        account: &AccountId32,
+    ) -> Result<Option<Value>, ChainError> {
+        let block = self.client().at_current_block().await?;
+        if block.metadata().pallet_by_name(pallet).is_none() {
+            return Ok(None);
+        }
+        let storage_query = subxt::dynamic::storage(pallet, entry);
+        let result = block
+            .storage()
+            .try_fetch(&storage_query, vec![Value::from_bytes(account.clone())])
+            .await?;
+        match result {
+            Some(value) => Ok(Some(value.decode()?)),
+            None => Ok(None),
+        }
+    }
+
     /// Get staking ledger for a controller account.
     pub async fn get_staking_ledger(
         &self,
@@ -148,49 +277,7 @@ impl ChainClient {
             return Ok(None);
         };
 
-        let decoded: Value = value.decode()?;
-
-        // StakingLedger = { stash, total, active, unlocking, legacy_claimed_rewards }
-        let total = decoded
-            .at("total")
-            .and_then(|v: &Value| v.as_u128())
-            .unwrap_or(0);
-
-        let active = decoded
-            .at("active")
-            .and_then(|v: &Value| v.as_u128())
-            .unwrap_or(0);
-
-        // Parse unlocking chunks
-        let mut unlocking = Vec::new();
-        if let Some(unlocking_val) = decoded.at("unlocking") {
-            let mut i = 0;
-            while let Some(chunk) = unlocking_val.at(i) {
-                let chunk_value = chunk
-                    .at("value")
-                    .and_then(|v: &Value| v.as_u128())
-                    .unwrap_or(0);
-                let chunk_era = chunk
-                    .at("era")
-                    .and_then(|v: &Value| v.as_u128())
-                    .unwrap_or(0) as u32;
-
-                if chunk_value > 0 {
-                    unlocking.push(UnlockChunk {
-                        value: chunk_value,
-                        era: chunk_era,
-                    });
-                }
-                i += 1;
-            }
-        }
-
-        Ok(Some(StakingLedger {
-            stash: stash.clone(),
-            total,
-            active,
-            unlocking,
-        }))
+        Ok(Some(parse_staking_ledger(stash, &value.decode()?)))
     }
 
     /// Get nominations for an account.
@@ -210,29 +297,7 @@ impl ChainClient {
             return Ok(None);
         };
 
-        let decoded: Value = value.decode()?;
-
-        // Nominations = { targets, submitted_in, suppressed }
-        let submitted_in = decoded
-            .at("submitted_in")
-            .and_then(|v: &Value| v.as_u128())
-            .unwrap_or(0) as u32;
-
-        let mut targets = Vec::new();
-        if let Some(targets_val) = decoded.at("targets") {
-            let mut i = 0;
-            while let Some(target) = targets_val.at(i) {
-                if let Some(account) = extract_account_id(target) {
-                    targets.push(account);
-                }
-                i += 1;
-            }
-        }
-
-        Ok(Some(NominatorInfo {
-            targets,
-            submitted_in,
-        }))
+        Ok(Some(parse_nominator_info(&value.decode()?)))
     }
 
     /// Get pool membership for an account.
@@ -252,46 +317,7 @@ impl ChainClient {
             return Ok(None);
         };
 
-        let decoded: Value = value.decode()?;
-
-        // PoolMember = { pool_id, points, last_recorded_reward_counter, unbonding_eras }
-        let pool_id = decoded
-            .at("pool_id")
-            .and_then(|v: &Value| v.as_u128())
-            .unwrap_or(0) as u32;
-
-        let points = decoded
-            .at("points")
-            .and_then(|v: &Value| v.as_u128())
-            .unwrap_or(0);
-
-        // Parse last_recorded_reward_counter (U256 stored as a composite type)
-        let last_recorded_reward_counter = decoded
-            .at("last_recorded_reward_counter")
-            .and_then(|v: &Value| v.as_u128())
-            .unwrap_or(0);
-
-        // Parse unbonding eras (BTreeMap<EraIndex, Balance>)
-        let mut unbonding_eras = Vec::new();
-        if let Some(unbonding_val) = decoded.at("unbonding_eras") {
-            // BTreeMap is serialized as array of [key, value] pairs
-            let mut i = 0;
-            while let Some(pair) = unbonding_val.at(i) {
-                let era = pair.at(0).and_then(|v: &Value| v.as_u128()).unwrap_or(0) as u32;
-                let amount = pair.at(1).and_then(|v: &Value| v.as_u128()).unwrap_or(0);
-                if amount > 0 {
-                    unbonding_eras.push((era, amount));
-                }
-                i += 1;
-            }
-        }
-
-        Ok(Some(PoolMembership {
-            pool_id,
-            points,
-            unbonding_eras,
-            last_recorded_reward_counter,
-        }))
+        Ok(Some(parse_pool_membership(&value.decode()?)))
     }
 
     /// Get the number of slashing spans for a stash account.
@@ -319,3 +345,371 @@ impl ChainClient {
         Ok(span_index.saturating_add(1))
     }
 }
+
+/// Parse a decoded `System.Account` value.
+pub(crate) fn parse_account_balance(decoded: &Value) -> AccountBalance {
+    // AccountInfo = { nonce, consumers, providers, sufficients, data: AccountData }
+    // AccountData = { free, reserved, frozen, flags }
+    let data = decoded.at("data");
+
+    let free = data
+        .and_then(|d| d.at("free"))
+        .and_then(|v: &Value| v.as_u128())
+        .unwrap_or(0);
+
+    let reserved = data
+        .and_then(|d| d.at("reserved"))
+        .and_then(|v: &Value| v.as_u128())
+        .unwrap_or(0);
+
+    let frozen = data
+        .and_then(|d| d.at("frozen"))
+        .and_then(|v: &Value| v.as_u128())
+        .unwrap_or(0);
+
+    AccountBalance {
+        free,
+        reserved,
+        frozen,
+    }
+}
+
+/// Parse a decoded `Staking.Ledger` value.
+pub(crate) fn parse_staking_ledger(stash: &AccountId32, decoded: &Value) -> StakingLedger {
+    // StakingLedger = { stash, total, active, unlocking, legacy_claimed_rewards }
+    let total = decoded
+        .at("total")
+        .and_then(|v: &Value| v.as_u128())
+        .unwrap_or(0);
+
+    let active = decoded
+        .at("active")
+        .and_then(|v: &Value| v.as_u128())
+        .unwrap_or(0);
+
+    // Parse unlocking chunks
+    let mut unlocking = Vec::new();
+    if let Some(unlocking_val) = decoded.at("unlocking") {
+        let mut i = 0;
+        while let Some(chunk) = unlocking_val.at(i) {
+            let chunk_value = chunk
+                .at("value")
+                .and_then(|v: &Value| v.as_u128())
+                .unwrap_or(0);
+            let chunk_era = chunk
+                .at("era")
+                .and_then(|v: &Value| v.as_u128())
+                .unwrap_or(0) as u32;
+
+            if chunk_value > 0 {
+                unlocking.push(UnlockChunk {
+                    value: chunk_value,
+                    era: chunk_era,
+                });
+            }
+            i += 1;
+        }
+    }
+
+    StakingLedger {
+        stash: stash.clone(),
+        total,
+        active,
+        unlocking,
+    }
+}
+
+/// Parse a decoded `Staking.Nominators` value.
+pub(crate) fn parse_nominator_info(decoded: &Value) -> NominatorInfo {
+    // Nominations = { targets, submitted_in, suppressed }
+    let submitted_in = decoded
+        .at("submitted_in")
+        .and_then(|v: &Value| v.as_u128())
+        .unwrap_or(0) as u32;
+
+    let mut targets = Vec::new();
+    if let Some(targets_val) = decoded.at("targets") {
+        let mut i = 0;
+        while let Some(target) = targets_val.at(i) {
+            if let Some(account) = extract_account_id(target) {
+                targets.push(account);
+            }
+            i += 1;
+        }
+    }
+
+    NominatorInfo {
+        targets,
+        submitted_in,
+    }
+}
+
+/// Parse a decoded `NominationPools.PoolMembers` value.
+pub(crate) fn parse_pool_membership(decoded: &Value) -> PoolMembership {
+    // PoolMember = { pool_id, points, last_recorded_reward_counter, unbonding_eras }
+    let pool_id = decoded
+        .at("pool_id")
+        .and_then(|v: &Value| v.as_u128())
+        .unwrap_or(0) as u32;
+
+    let points = decoded
+        .at("points")
+        .and_then(|v: &Value| v.as_u128())
+        .unwrap_or(0);
+
+    // Parse last_recorded_reward_counter (U256 stored as a composite type)
+    let last_recorded_reward_counter = decoded
+        .at("last_recorded_reward_counter")
+        .and_then(|v: &Value| v.as_u128())
+        .unwrap_or(0);
+
+    // Parse unbonding eras (BTreeMap<EraIndex, Balance>)
+    let mut unbonding_eras = Vec::new();
+    if let Some(unbonding_val) = decoded.at("unbonding_eras") {
+        // BTreeMap is serialized as array of [key, value] pairs
+        let mut i = 0;
+        while let Some(pair) = unbonding_val.at(i) {
+            let era = pair.at(0).and_then(|v: &Value| v.as_u128()).unwrap_or(0) as u32;
+            let amount = pair.at(1).and_then(|v: &Value| v.as_u128()).unwrap_or(0);
+            if amount > 0 {
+                unbonding_eras.push((era, amount));
+            }
+            i += 1;
+        }
+    }
+
+    PoolMembership {
+        pool_id,
+        points,
+        unbonding_eras,
+        last_recorded_reward_counter,
+    }
+}
+
+/// Items of a decoded sequence.
+fn items(value: &Value) -> impl Iterator<Item = &Value> {
+    (0usize..).map_while(move |i| value.at(i))
+}
+
+/// `BalanceLock = { id: [u8; 8], amount, reasons }`.
+fn parse_locks(value: &Value) -> Vec<BalanceRestriction> {
+    items(value)
+        .filter_map(|lock| {
+            let id: Vec<u8> = lock
+                .at("id")
+                .map(|id| {
+                    items(id)
+                        .filter_map(|b| b.as_u128().and_then(|b| u8::try_from(b).ok()))
+                        .collect()
+                })
+                .unwrap_or_default();
+            let id = String::from_utf8_lossy(&id).into_owned();
+            let amount = lock.at("amount").and_then(|v: &Value| v.as_u128())?;
+            Some(BalanceRestriction {
+                reason: lock_reason(&id),
+                id,
+                amount,
+            })
+        })
+        .collect()
+}
+
+/// `IdAmount = { id: RuntimeFreezeReason | RuntimeHoldReason, amount }`,
+/// the id being a pallet variant wrapping that pallet's reason.
+fn parse_id_amounts(value: &Value) -> Vec<BalanceRestriction> {
+    items(value)
+        .filter_map(|item| {
+            let amount = item.at("amount").and_then(|v: &Value| v.as_u128())?;
+            let (pallet, reason) = item.at("id").map(reason_path).unwrap_or_default();
+            Some(BalanceRestriction {
+                id: format!("{pallet}.{reason}"),
+                reason: reason_label(&pallet, &reason),
+                amount,
+            })
+        })
+        .collect()
+}
+
+/// `Vec<VestingInfo { locked, per_block, starting_block }>`.
+fn parse_vesting(value: &Value) -> Vec<VestingSchedule> {
+    items(value)
+        .filter_map(|schedule| {
+            let field = |name: &str| schedule.at(name).and_then(|v: &Value| v.as_u128());
+            Some(VestingSchedule {
+                locked: field("locked")?,
+                per_block: field("per_block").unwrap_or(0),
+                starting_block: field("starting_block").unwrap_or(0) as u32,
+            })
+        })
+        .collect()
+}
+
+/// Pallet and reason names of a runtime freeze or hold reason.
+fn reason_path(value: &Value) -> (String, String) {
+    let ValueDef::Variant(pallet) = &value.value else {
+        return Default::default();
+    };
+    let inner = match &pallet.values {
+        Composite::Unnamed(values) => values.first(),
+        Composite::Named(values) => values.first().map(|(_, v)| v),
+    };
+    let reason = match inner.map(|v| &v.value) {
+        Some(ValueDef::Variant(reason)) => reason.name.clone(),
+        _ => String::new(),
+    };
+    (pallet.name.clone(), reason)
+}
+
+fn lock_reason(id: &str) -> String {
+    match id {
+        STAKING_LOCK_ID => "Staking (legacy lock)".to_string(),
+        "pyconvot" => "Conviction voting".to_string(),
+        "vesting " => "Vesting".to_string(),
+        "democrac" => "Democracy".to_string(),
+        "phrelect" => "Council elections".to_string(),
+        other => other.trim().to_string(),
+    }
+}
+
+fn reason_label(pallet: &str, reason: &str) -> String {
+    match (pallet, reason) {
+        ("Staking", "Staking") => "Staking".to_string(),
+        ("DelegatedStaking", "StakingDelegation") => "Nomination pool stake".to_string(),
+        ("NominationPools", "PoolMinBalance") => "Pool minimum balance".to_string(),
+        ("Preimage", _) => "Preimage deposit".to_string(),
+        ("Referenda", _) => "Referendum deposit".to_string(),
+        ("Proxy", _) => "Proxy deposit".to_string(),
+        ("Multisig", _) => "Multisig deposit".to_string(),
+        ("Identity", _) => "Identity deposit".to_string(),
+        (pallet, "") => pallet.to_string(),
+        (pallet, reason) => format!("{pallet}: {reason}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn balance(free: Balance, reserved: Balance, frozen: Balance) -> AccountBalance {
+        AccountBalance {
+            free,
+            reserved,
+            frozen,
+        }
+    }
+
+    fn restriction(id: &str, amount: Balance) -> BalanceRestriction {
+        BalanceRestriction {
+            id: id.to_string(),
+            reason: id.to_string(),
+            amount,
+        }
+    }
+
+    fn breakdown(balance: AccountBalance) -> BalanceBreakdown {
+        BalanceBreakdown {
+            balance,
+            existential_deposit: 10,
+            locks: Vec::new(),
+            freezes: Vec::new(),
+            holds: Vec::new(),
+            vesting: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_parse_locks() {
+        let locks = Value::unnamed_composite([Value::named_composite([
+            ("id", Value::from_bytes(b"pyconvot")),
+            ("amount", Value::u128(500)),
+            (
+                "reasons",
+                Value::unnamed_variant("All", Vec::<Value>::new()),
+            ),
+        ])]);
+        assert_eq!(
+            parse_locks(&locks),
+            vec![BalanceRestriction {
+                id: "pyconvot".to_string(),
+                reason: "Conviction voting".to_string(),
+                amount: 500,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_holds() {
+        let holds = Value::unnamed_composite([Value::named_composite([
+            (
+                "id",
+                Value::unnamed_variant(
+                    "DelegatedStaking",
+                    [Value::unnamed_variant(
+                        "StakingDelegation",
+                        Vec::<Value>::new(),
+                    )],
+                ),
+            ),
+            ("amount", Value::u128(1_000)),
+        ])]);
+        let parsed = parse_id_amounts(&holds);
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].id, "DelegatedStaking.StakingDelegation");
+        assert_eq!(parsed[0].reason, "Nomination pool stake");
+        assert_eq!(parsed[0].amount, 1_000);
+    }
+
+    #[test]
+    fn test_parse_vesting() {
+        let vesting = Value::unnamed_composite([Value::named_composite([
+            ("locked", Value::u128(1_000)),
+            ("per_block", Value::u128(2)),
+            ("starting_block", Value::u128(100)),
+        ])]);
+        assert_eq!(
+            parse_vesting(&vesting),
+            vec![VestingSchedule {
+                locked: 1_000,
+                per_block: 2,
+                starting_block: 100,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_governance_lock_does_not_reduce_bondable() {
+        // 600 free, all locked by a conviction vote
+        let mut b = breakdown(balance(600, 0, 600));
+        b.locks.push(restriction("pyconvot", 600));
+        assert_eq!(b.transferable(), 0);
+        assert_eq!(b.bondable(), 590);
+    }
+
+    #[test]
+    fn test_legacy_staking_lock_reduces_bondable() {
+        let mut b = breakdown(balance(1_000, 0, 700));
+        b.locks.push(restriction(STAKING_LOCK_ID, 700));
+        b.locks.push(restriction("pyconvot", 200));
+        assert_eq!(b.bondable(), 290);
+        assert_eq!(b.transferable(), 300);
+    }
+
+    #[test]
+    fn test_held_stake_counts_towards_frozen() {
+        // 400 held for staking, 500 frozen by a vote across free + held
+        let mut b = breakdown(balance(600, 400, 500));
+        b.holds.push(restriction("Staking.Staking", 400));
+        assert_eq!(b.held(), 400);
+        assert_eq!(b.transferable(), 500);
+        assert_eq!(b.bondable(), 590);
+    }
+
+    #[test]
+    fn test_restrictions_sorted_by_amount() {
+        let mut b = breakdown(balance(1_000, 300, 200));
+        b.locks.push(restriction("pyconvot", 200));
+        b.holds.push(restriction("Staking.Staking", 300));
+        let kinds: Vec<_> = b.restrictions().iter().map(|(kind, _)| *kind).collect();
+        assert_eq!(kinds, vec!["Hold", "Lock"]);
+    }
+}
@@ -0,0 +1,223 @@
+//! Bags-list voter list queries.
+//!
+//! Nominators are ordered for the election snapshot by `VoterList`, an
+//! instance of the bags-list pallet: `ListBags` holds the head and tail of
+//! each bag, keyed by its upper score threshold, and `ListNodes` links the
+//! voters of a bag from head to tail. The score stored in a node is only
+//! updated on rebag, so voters are compared by the score of their live
+//! bonded stake, as `put_in_front_of` does.
+
+use crate::ChainClient;
+use crate::error::ChainError;
+use crate::ss58::encode_ss58;
+use futures::StreamExt;
+use stkopt_core::{BagsListPosition, Balance, bag_for_score, currency_to_vote, first_lighter};
+use subxt::PolkadotConfig;
+use subxt::client::OnlineClientAtBlock;
+use subxt::dynamic::{At, Value};
+use subxt::utils::AccountId32;
+
+use super::account::parse_staking_ledger;
+use super::decode_helpers::extract_account_id;
+
+/// Pallet holding the voter list.
+const VOTER_LIST: &str = "VoterList";
+
+/// Most list nodes read while looking for a stash's rank in its bag.
+pub const MAX_BAG_WALK: u32 = 2_000;
+
+/// Ledgers of voters ahead read concurrently.
+const LEDGER_CONCURRENCY: usize = 16;
+
+/// Chain state at one block.
+type Block = OnlineClientAtBlock<PolkadotConfig>;
+
+/// A decoded `VoterList.ListNodes` entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ListNode {
+    pub next: Option<AccountId32>,
+    pub bag_upper: u64,
+    pub score: u64,
+}
+
+impl ChainClient {
+    /// Where `stash` sits in the voter list, with `active_bond` giving the bag
+    /// it belongs in. `None` when the stash is not in the list.
+    ///
+    /// The bag is walked from its head, one node read per voter ahead, up to
+    /// [`MAX_BAG_WALK`] nodes, then each voter ahead is scored from its
+    /// ledger. Everything is read at one block so the walk sees a single
+    /// list.
+    pub async fn get_bags_list_position(
+        &self,
+        stash: &AccountId32,
+        active_bond: Balance,
+    ) -> Result<Option<BagsListPosition>, ChainError> {
+        let block = self.client().at_current_block().await?;
+        if block.metadata().pallet_by_name(VOTER_LIST).is_none() {
+            return Ok(None);
+        }
+        let Some(node) = get_list_node(&block, stash).await? else {
+            return Ok(None);
+        };
+
+        let thresholds = get_bag_thresholds(&block)?;
+        let total_issuance = get_total_issuance(&block).await?;
+        let current_score = currency_to_vote(active_bond, total_issuance);
+        let prefix = self.network().ss58_format();
+
+        // Walk the bag from its head, collecting the voters ahead of the stash
+        let mut ids = Vec::new();
+        let mut rank = None;
+        let mut cursor = get_bag_head(&block, node.bag_upper).await?;
+        while let Some(id) = cursor {
+            if id == *stash {
+                rank = Some(ids.len() as u32 + 1);
+                break;
+            }
+            if ids.len() as u32 >= MAX_BAG_WALK {
+                break;
+            }
+            let Some(other) = get_list_node(&block, &id).await? else {
+                break;
+            };
+            cursor = other.next;
+            ids.push((id, other.score));
+        }
+
+        // Score the voters ahead by their bonded stake now, falling back to
+        // the stored score for voters without a ledger
+        let block = &block;
+        let scores: Vec<Result<u64, ChainError>> = futures::stream::iter(ids.clone())
+            .map(|(id, stored)| async move {
+                Ok(get_active_bond(block, &id)
+                    .await?
+                    .map_or(stored, |active| currency_to_vote(active, total_issuance)))
+            })
+            .buffered(LEDGER_CONCURRENCY)
+            .collect()
+            .await;
+        let mut ahead = Vec::with_capacity(ids.len());
+        for ((id, _), score) in ids.iter().zip(scores) {
+            ahead.push((encode_ss58(id, prefix)?, score?));
+        }
+
+        Ok(Some(BagsListPosition {
+            score: node.score,
+            current_score,
+            bag_upper: node.bag_upper,
+            expected_bag: bag_for_score(&thresholds, current_score),
+            rank,
+            lighter_ahead: rank.and_then(|_| first_lighter(&ahead, current_score)),
+        }))
+    }
+}
+
+/// The `VoterList.ListNodes` entry of `voter`.
+async fn get_list_node(block: &Block, voter: &AccountId32) -> Result<Option<ListNode>, ChainError> {
+    let storage_query = subxt::dynamic::storage::<Vec<Value>, Value>(VOTER_LIST, "ListNodes");
+    let Some(value) = block
+        .storage()
+        .try_fetch(&storage_query, vec![Value::from_bytes(voter)])
+        .await?
+    else {
+        return Ok(None);
+    };
+    Ok(parse_list_node(&value.decode()?))
+}
+
+/// Head of the bag with upper threshold `bag_upper`.
+async fn get_bag_head(block: &Block, bag_upper: u64) -> Result<Option<AccountId32>, ChainError> {
+    let storage_query = subxt::dynamic::storage::<Vec<Value>, Value>(VOTER_LIST, "ListBags");
+    let Some(value) = block
+        .storage()
+        .try_fetch(&storage_query, vec![Value::u128(bag_upper as u128)])
+        .await?
+    else {
+        return Ok(None);
+    };
+    let decoded: Value = value.decode()?;
+    Ok(decoded.at("head").and_then(extract_account_id))
+}
+
+/// Active bond of `stash` from `Staking.Ledger`.
+async fn get_active_bond(
+    block: &Block,
+    stash: &AccountId32,
+) -> Result<Option<Balance>, ChainError> {
+    let storage_query = subxt::dynamic::storage::<Vec<Value>, Value>("Staking", "Ledger");
+    let Some(value) = block
+        .storage()
+        .try_fetch(&storage_query, vec![Value::from_bytes(stash)])
+        .await?
+    else {
+        return Ok(None);
+    };
+    Ok(Some(parse_staking_ledger(stash, &value.decode()?).active))
+}
+
+/// `Balances.TotalIssuance`, which scales stake to a vote score.
+async fn get_total_issuance(block: &Block) -> Result<Balance, ChainError> {
+    let storage_query = subxt::dynamic::storage::<Vec<Value>, Value>("Balances", "TotalIssuance");
+    let Some(value) = block.storage().try_fetch(&storage_query, vec![]).await? else {
+        return Ok(0);
+    };
+    let decoded: Value = value.decode()?;
+    Ok(decoded.as_u128().unwrap_or(0))
+}
+
+/// Upper score thresholds of the bags, ascending.
+#[allow(clippy::result_large_err)]
+fn get_bag_thresholds(block: &Block) -> Result<Vec<u64>, ChainError> {
+    let constant = (VOTER_LIST, "BagThresholds");
+    let decoded = block.constants().entry(&constant)?;
+    Ok((0..)
+        .map_while(|i| decoded.at(i))
+        .filter_map(|v| v.as_u128().map(|t| t as u64))
+        .collect())
+}
+
+/// Decode a `Node { id, prev, next, bag_upper, score }`.
+pub(crate) fn parse_list_node(value: &Value) -> Option<ListNode> {
+    Some(ListNode {
+        next: value.at("next").and_then(extract_account_id),
+        bag_upper: value.at("bag_upper")?.as_u128()? as u64,
+        score: value.at("score").and_then(Value::as_u128).unwrap_or(0) as u64,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_list_node() {
+        let node = Value::named_composite([
+            ("id", Value::from_bytes([1u8; 32])),
+            ("prev", Value::unnamed_variant("None", [])),
+            (
+                "next",
+                Value::unnamed_variant("Some", [Value::from_bytes([2u8; 32])]),
+            ),
+            ("bag_upper", Value::u128(1_000)),
+            ("score", Value::u128(900)),
+        ]);
+        assert_eq!(
+            parse_list_node(&node),
+            Some(ListNode {
+                next: Some(AccountId32::from([2u8; 32])),
+                bag_upper: 1_000,
+                score: 900,
+            })
+        );
+
+        let tail = Value::named_composite([
+            ("next", Value::unnamed_variant("None", [])),
+            ("bag_upper", Value::u128(u64::MAX as u128)),
+        ]);
+        let parsed = parse_list_node(&tail).unwrap();
+        assert_eq!(parsed.next, None);
+        assert_eq!(parsed.bag_upper, u64::MAX);
+        assert!(parse_list_node(&Value::unnamed_composite([])).is_none());
+    }
+}
@@ -66,6 +66,20 @@ impl ChainClient {
         }))
     }
 
+    /// Index of the era active at block `number`; needs the block's state.
+    pub async fn get_active_era_at(&self, number: u64) -> Result<Option<u32>, ChainError> {
+        let storage_query = subxt::dynamic::storage::<Vec<Value>, Value>("Staking", "ActiveEra");
+        let block = self.client().at_block(number).await?;
+        let Some(value) = block.storage().try_fetch(&storage_query, vec![]).await? else {
+            return Ok(None);
+        };
+        let decoded: Value = value.decode()?;
+        Ok(decoded
+            .at("index")
+            .and_then(|v: &Value| v.as_u128())
+            .map(|index| index as u32))
+    }
+
     /// Get era duration in milliseconds.
     ///
     /// Since the Polkadot 2.0 migration, Asset Hub doesn't have Babe constants.
@@ -105,7 +119,11 @@ impl ChainClient {
     }
 
     /// Helper to get a u32 constant from runtime.
-    async fn get_constant_u32(&self, pallet: &str, name: &str) -> Result<u32, ChainError> {
+    pub(crate) async fn get_constant_u32(
+        &self,
+        pallet: &str,
+        name: &str,
+    ) -> Result<u32, ChainError> {
         let constant = (pallet, name);
         let block = self.client().at_current_block().await?;
         let decoded = block.constants().entry(&constant)?;
@@ -0,0 +1,245 @@
+//! Era exposure queries for a single nominator.
+
+use crate::ChainClient;
+use crate::error::ChainError;
+use stkopt_core::{Balance, EraIndex};
+use subxt::dynamic::{At, Value};
+use subxt::utils::AccountId32;
+
+use super::decode_helpers::extract_account_id;
+
+/// Stake a nominator has behind one validator in an era.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NominationBacking {
+    pub validator: AccountId32,
+    /// Stake allocated to this validator by the election.
+    pub value: Balance,
+    /// Exposure page the nominator is paid in.
+    pub page: u32,
+    /// Pages of the validator's exposure.
+    pub page_count: u32,
+    /// Total stake exposed on the validator, own stake included.
+    pub exposure_total: Balance,
+}
+
+/// Where a nominator's stake was allocated in an era.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NominatorExposure {
+    pub era: EraIndex,
+    /// Nominated validators that carry some of the nominator's stake.
+    pub backing: Vec<NominationBacking>,
+    /// Nominated validators that are active but carry none of the stake.
+    pub active_without_stake: Vec<AccountId32>,
+    /// Nominated validators outside the active set.
+    pub inactive: Vec<AccountId32>,
+}
+
+impl NominatorExposure {
+    /// Whether any stake is exposed, i.e. the nominator earns rewards this era.
+    pub fn is_active(&self) -> bool {
+        !self.backing.is_empty()
+    }
+
+    /// Total stake allocated across all backed validators.
+    pub fn active_stake(&self) -> Balance {
+        self.backing.iter().map(|b| b.value).sum()
+    }
+
+    /// Warnings to show for this exposure, empty when all is well.
+    ///
+    /// Empty when `has_nominations` is false: an account that does not
+    /// nominate is not expected to be active.
+    pub fn warnings(&self, has_nominations: bool) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if !has_nominations {
+            return warnings;
+        }
+        if !self.is_active() {
+            if self.active_without_stake.is_empty() {
+                warnings.push(format!(
+                    "Inactive in era {}: none of your validators were elected",
+                    self.era
+                ));
+            } else {
+                warnings.push(format!(
+                    "Inactive in era {}: {} elected validator(s) received none of your stake; \
+                     your bond may be below the minimum active stake",
+                    self.era,
+                    self.active_without_stake.len()
+                ));
+            }
+        } else if !self.active_without_stake.is_empty() {
+            warnings.push(format!(
+                "{} elected validator(s) received none of your stake (oversubscribed)",
+                self.active_without_stake.len()
+            ));
+        }
+        warnings
+    }
+}
+
+impl ChainClient {
+    /// Find how the election allocated `stash`'s stake among `targets` in `era`.
+    ///
+    /// For each target, `ErasStakersOverview` tells whether it is active and
+    /// how many `ErasStakersPaged` pages to scan for the stash.
+    pub async fn get_nominator_exposure(
+        &self,
+        stash: &AccountId32,
+        targets: &[AccountId32],
+        era: EraIndex,
+    ) -> Result<NominatorExposure, ChainError> {
+        let overview_query =
+            subxt::dynamic::storage::<Vec<Value>, Value>("Staking", "ErasStakersOverview");
+        let paged_query =
+            subxt::dynamic::storage::<Vec<Value>, Value>("Staking", "ErasStakersPaged");
+        let block = self.client().at_current_block().await?;
+
+        let mut exposure = NominatorExposure {
+            era,
+            ..Default::default()
+        };
+        for target in targets {
+            let overview = block
+                .storage()
+                .try_fetch(
+                    &overview_query,
+                    vec![Value::u128(era as u128), Value::from_bytes(target)],
+                )
+                .await?;
+            let Some(overview) = overview else {
+                exposure.inactive.push(*target);
+                continue;
+            };
+            let overview: Value = overview.decode()?;
+            // PagedExposureMetadata = { total, own, nominator_count, page_count }
+            let page_count = overview
+                .at("page_count")
+                .and_then(|v: &Value| v.as_u128())
+                .unwrap_or(0) as u32;
+            let exposure_total = overview
+                .at("total")
+                .and_then(|v: &Value| v.as_u128())
+                .unwrap_or(0);
+
+            let mut found = None;
+            for page in 0..page_count {
+                let Some(value) = block
+                    .storage()
+                    .try_fetch(
+                        &paged_query,
+                        vec![
+                            Value::u128(era as u128),
+                            Value::from_bytes(target),
+                            Value::u128(page as u128),
+                        ],
+                    )
+                    .await?
+                else {
+                    continue;
+                };
+                if let Some(stake) = find_in_exposure_page(&value.decode()?, stash) {
+                    found = Some((page, stake));
+                    break;
+                }
+            }
+
+            match found {
+                Some((page, value)) => exposure.backing.push(NominationBacking {
+                    validator: *target,
+                    value,
+                    page,
+                    page_count,
+                    exposure_total,
+                }),
+                None => exposure.active_without_stake.push(*target),
+            }
+        }
+        Ok(exposure)
+    }
+}
+
+/// Stake of `stash` in a decoded `ExposurePage`, if it is listed.
+fn find_in_exposure_page(page: &Value, stash: &AccountId32) -> Option<Balance> {
+    // ExposurePage = { page_total, others: Vec<IndividualExposure { who, value }> }
+    let others = page.at("others")?;
+    let mut i = 0;
+    while let Some(individual) = others.at(i) {
+        if individual
+            .at("who")
+            .and_then(extract_account_id)
+            .is_some_and(|who| who == *stash)
+        {
+            return individual.at("value").and_then(|v: &Value| v.as_u128());
+        }
+        i += 1;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account(byte: u8) -> AccountId32 {
+        AccountId32::from([byte; 32])
+    }
+
+    fn page(others: &[(u8, u128)]) -> Value {
+        Value::named_composite([
+            (
+                "page_total",
+                Value::u128(others.iter().map(|(_, v)| v).sum()),
+            ),
+            (
+                "others",
+                Value::unnamed_composite(others.iter().map(|(who, value)| {
+                    Value::named_composite([
+                        ("who", Value::from_bytes([*who; 32])),
+                        ("value", Value::u128(*value)),
+                    ])
+                })),
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_find_in_exposure_page() {
+        let page = page(&[(1, 100), (2, 250)]);
+        assert_eq!(find_in_exposure_page(&page, &account(2)), Some(250));
+        assert_eq!(find_in_exposure_page(&page, &account(3)), None);
+    }
+
+    #[test]
+    fn test_active_stake_and_warnings() {
+        let mut exposure = NominatorExposure {
+            era: 100,
+            backing: vec![NominationBacking {
+                validator: account(1),
+                value: 300,
+                page: 0,
+                page_count: 1,
+                exposure_total: 1_000,
+            }],
+            active_without_stake: Vec::new(),
+            inactive: vec![account(2)],
+        };
+        assert!(exposure.is_active());
+        assert_eq!(exposure.active_stake(), 300);
+        assert!(exposure.warnings(true).is_empty());
+
+        exposure.active_without_stake.push(account(3));
+        assert!(exposure.warnings(true)[0].contains("oversubscribed"));
+
+        exposure.backing.clear();
+        assert!(!exposure.is_active());
+        assert!(exposure.warnings(true)[0].starts_with("Inactive in era 100"));
+    }
+
+    #[test]
+    fn test_warnings_without_nominations() {
+        let exposure = NominatorExposure::default();
+        assert!(exposure.warnings(false).is_empty());
+        assert!(exposure.warnings(true)[0].contains("none of your validators were elected"));
+    }
+}
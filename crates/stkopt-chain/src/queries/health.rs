@@ -0,0 +1,80 @@
+//! Health check inputs for a staking account.
+
+use crate::ChainClient;
+use crate::error::ChainError;
+use crate::ss58::encode_ss58;
+use stkopt_core::{HealthAccount, NominationPoolMembership, TargetStatus};
+use subxt::utils::AccountId32;
+
+use super::account::{NominatorInfo, PoolMembership, StakingLedger};
+use super::exposure::NominatorExposure;
+
+impl ChainClient {
+    /// Look up the registration and slashing state of nominated `targets`.
+    ///
+    /// Whether a target is active comes from `exposure`, when given; names
+    /// are left for [`stkopt_core::check_health`] to resolve.
+    pub async fn get_target_statuses(
+        &self,
+        targets: &[AccountId32],
+        exposure: Option<&NominatorExposure>,
+    ) -> Result<Vec<TargetStatus>, ChainError> {
+        let prefix = self.network().ss58_format();
+        let mut statuses = Vec::with_capacity(targets.len());
+        for target in targets {
+            let preferences = self.get_validator_preferences(target).await?;
+            let slashed = self.get_slashing_spans(target).await? > 0;
+            statuses.push(TargetStatus {
+                address: encode_ss58(target, prefix)?,
+                name: None,
+                preferences,
+                active: exposure.map(|e| !e.inactive.contains(target)),
+                slashed,
+            });
+        }
+        Ok(statuses)
+    }
+
+    /// Build the [`HealthAccount`] for already fetched account state.
+    ///
+    /// Adds what the account views do not fetch: the state of each nominated
+    /// validator and the member's pending pool rewards.
+    pub async fn get_health_account(
+        &self,
+        ledger: Option<&StakingLedger>,
+        nominations: Option<&NominatorInfo>,
+        pool_membership: Option<&PoolMembership>,
+        exposure: Option<&NominatorExposure>,
+    ) -> Result<HealthAccount, ChainError> {
+        let targets = match nominations {
+            Some(n) => self.get_target_statuses(&n.targets, exposure).await?,
+            None => Vec::new(),
+        };
+        let pool = match pool_membership {
+            Some(m) => Some(NominationPoolMembership {
+                pool_id: m.pool_id,
+                points: m.points,
+                pending_rewards: self
+                    .get_pool_pending_rewards(m.pool_id, m.points, m.last_recorded_reward_counter)
+                    .await?,
+            }),
+            None => None,
+        };
+        Ok(HealthAccount {
+            bonded: ledger.map(|l| l.active).unwrap_or(0),
+            targets,
+            unlocking: ledger
+                .map(|l| {
+                    l.unlocking
+                        .iter()
+                        .map(|c| stkopt_core::UnlockChunk {
+                            value: c.value,
+                            era: c.era,
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+            pool,
+        })
+    }
+}
@@ -1,8 +1,16 @@
 //! Chain query implementations.
 
 pub mod account;
+pub mod bags;
 pub mod decode_helpers;
 pub mod era;
+pub mod exposure;
+pub mod health;
 pub mod identity;
+pub mod payouts;
 pub mod pools;
+pub mod portfolio;
+pub mod rewards;
+pub mod slashing;
+pub mod staking_params;
 pub mod validators;
@@ -0,0 +1,312 @@
+//! Scanning blocks for reward payout events.
+//!
+//! `Staking.payout_stakers` emits `Staking.PayoutStarted { era_index,
+//! validator_stash }` followed by one `Staking.Rewarded { stash, dest, amount }`
+//! per paid staker, so each `Rewarded` is attributed to the era and validator
+//! of the `PayoutStarted` before it. Pool members are paid with
+//! `NominationPools.PaidOut { member, pool_id, payout }`.
+//!
+//! Only blocks with an extrinsic that may pay out (see [`may_pay_out`]) have
+//! their events decoded; the rest are skipped after reading the block body.
+
+use crate::ChainClient;
+use crate::error::ChainError;
+use crate::events::{RawEvent, field};
+use crate::ss58::encode_ss58;
+use futures::StreamExt;
+use stkopt_core::{EraIndex, PayoutEvent, PayoutSource};
+use subxt::dynamic::Value;
+use subxt::utils::AccountId32;
+
+use super::decode_helpers::extract_account_id;
+
+/// Default number of blocks scanned per request, about a day of 6s blocks.
+pub const DEFAULT_PAYOUT_SCAN_BLOCKS: u64 = 14_400;
+
+/// Blocks fetched concurrently while scanning.
+const SCAN_CONCURRENCY: usize = 8;
+
+/// A payout event paid to one of the watched accounts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountPayoutEvent {
+    pub account: AccountId32,
+    pub event: PayoutEvent,
+}
+
+impl ChainClient {
+    /// Collect `Staking.Rewarded` and `NominationPools.PaidOut` events paid to
+    /// any of `accounts` in the inclusive block range, oldest first.
+    ///
+    /// Every block's body is fetched and events are decoded only where an
+    /// extrinsic may pay out, so keep ranges to a few
+    /// [`DEFAULT_PAYOUT_SCAN_BLOCKS`]; prefer the indexer where there is one.
+    pub async fn scan_payout_events(
+        &self,
+        accounts: &[AccountId32],
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<AccountPayoutEvent>, ChainError> {
+        if accounts.is_empty() || from_block > to_block {
+            return Ok(Vec::new());
+        }
+        let ss58_prefix = self.network().ss58_format();
+
+        let mut blocks = futures::stream::iter(from_block..=to_block)
+            .map(|number| async move { (number, self.get_payout_block_events(number).await) })
+            .buffered(SCAN_CONCURRENCY);
+
+        let mut found = Vec::new();
+        let mut decoded = 0;
+        while let Some((number, events)) = blocks.next().await {
+            let Some(events) = events? else {
+                continue;
+            };
+            decoded += 1;
+            found.extend(payout_events_from_block(
+                number,
+                &events,
+                accounts,
+                ss58_prefix,
+            )?);
+        }
+        tracing::debug!(
+            "Scanned blocks {}..={} for payouts: {} decoded, {} events",
+            from_block,
+            to_block,
+            decoded,
+            found.len()
+        );
+        Ok(found)
+    }
+
+    /// Decode all events of a block, or `None` if none of its extrinsics may
+    /// pay out.
+    async fn get_payout_block_events(
+        &self,
+        number: u64,
+    ) -> Result<Option<Vec<RawEvent>>, ChainError> {
+        let block = self.client().at_block(number).await?;
+        let extrinsics = block.extrinsics().fetch().await?;
+        let mut pays_out = false;
+        for extrinsic in extrinsics.iter() {
+            let extrinsic = extrinsic.map_err(|e| ChainError::InvalidData(e.to_string()))?;
+            if may_pay_out(extrinsic.pallet_name(), extrinsic.call_name()) {
+                pays_out = true;
+                break;
+            }
+        }
+        if !pays_out {
+            return Ok(None);
+        }
+
+        let events = block.events().fetch().await?;
+
+        let mut raw = Vec::with_capacity(events.len() as usize);
+        for event in events.iter() {
+            let event = event?;
+            raw.push(RawEvent {
+                pallet: event.pallet_name().to_string(),
+                variant: event.event_name().to_string(),
+                fields: event.decode_fields_unchecked_as::<Value>()?,
+            });
+        }
+        Ok(Some(raw))
+    }
+}
+
+/// Whether a call may emit payout events.
+///
+/// Besides `Staking.payout_stakers`, every `NominationPools` call that touches
+/// a member pays out its pending rewards first, and wrapper calls may contain
+/// either.
+pub(crate) fn may_pay_out(pallet: &str, call: &str) -> bool {
+    matches!(
+        (pallet, call),
+        ("Staking", "payout_stakers" | "payout_stakers_by_page")
+            | ("NominationPools" | "Utility" | "Proxy" | "Multisig", _)
+    )
+}
+
+/// Pick the payouts to `accounts` out of one block's events.
+#[allow(clippy::result_large_err)]
+pub(crate) fn payout_events_from_block(
+    block_number: u64,
+    events: &[RawEvent],
+    accounts: &[AccountId32],
+    ss58_prefix: u16,
+) -> Result<Vec<AccountPayoutEvent>, ChainError> {
+    let mut payout: Option<(EraIndex, AccountId32)> = None;
+    let mut found = Vec::new();
+
+    for (index, event) in events.iter().enumerate() {
+        let fields = &event.fields;
+        match (event.pallet.as_str(), event.variant.as_str()) {
+            ("Staking", "PayoutStarted") => {
+                let era = field(fields, "era_index", 0).and_then(Value::as_u128);
+                let validator = field(fields, "validator_stash", 1).and_then(extract_account_id);
+                payout = era.zip(validator).map(|(era, v)| (era as EraIndex, v));
+            }
+            ("Staking", "Rewarded") => {
+                let Some(stash) = field(fields, "stash", 0).and_then(extract_account_id) else {
+                    continue;
+                };
+                if !accounts.contains(&stash) {
+                    continue;
+                }
+                let amount = field(fields, "amount", 2)
+                    .and_then(Value::as_u128)
+                    .unwrap_or(0);
+                found.push(AccountPayoutEvent {
+                    account: stash,
+                    event: PayoutEvent {
+                        source: PayoutSource::Staking,
+                        block_number,
+                        event_index: index as u32,
+                        era: payout.as_ref().map(|(era, _)| *era),
+                        validator: payout
+                            .as_ref()
+                            .map(|(_, v)| encode_ss58(v, ss58_prefix))
+                            .transpose()?,
+                        pool_id: None,
+                        amount,
+                    },
+                });
+            }
+            ("NominationPools", "PaidOut") => {
+                let Some(member) = field(fields, "member", 0).and_then(extract_account_id) else {
+                    continue;
+                };
+                if !accounts.contains(&member) {
+                    continue;
+                }
+                found.push(AccountPayoutEvent {
+                    account: member,
+                    event: PayoutEvent {
+                        source: PayoutSource::Pool,
+                        block_number,
+                        event_index: index as u32,
+                        era: None,
+                        validator: None,
+                        pool_id: field(fields, "pool_id", 1)
+                            .and_then(Value::as_u128)
+                            .map(|id| id as u32),
+                        amount: field(fields, "payout", 2)
+                            .and_then(Value::as_u128)
+                            .unwrap_or(0),
+                    },
+                });
+            }
+            _ => {}
+        }
+    }
+    Ok(found)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raw(pallet: &str, variant: &str, fields: Value) -> RawEvent {
+        RawEvent {
+            pallet: pallet.to_string(),
+            variant: variant.to_string(),
+            fields,
+        }
+    }
+
+    fn account(byte: u8) -> AccountId32 {
+        AccountId32::from([byte; 32])
+    }
+
+    fn account_value(byte: u8) -> Value {
+        Value::from_bytes([byte; 32])
+    }
+
+    fn payout_started(era: u32, validator: u8) -> RawEvent {
+        raw(
+            "Staking",
+            "PayoutStarted",
+            Value::named_composite([
+                ("era_index", Value::u128(era as u128)),
+                ("validator_stash", account_value(validator)),
+            ]),
+        )
+    }
+
+    fn rewarded(stash: u8, amount: u128) -> RawEvent {
+        raw(
+            "Staking",
+            "Rewarded",
+            Value::named_composite([
+                ("stash", account_value(stash)),
+                ("dest", Value::unnamed_variant("Staked", [])),
+                ("amount", Value::u128(amount)),
+            ]),
+        )
+    }
+
+    #[test]
+    fn test_rewarded_attributed_to_preceding_payout() {
+        let events = [
+            payout_started(10, 1),
+            rewarded(1, 50),
+            rewarded(9, 100),
+            payout_started(11, 2),
+            rewarded(9, 70),
+            rewarded(8, 5),
+        ];
+        let found = payout_events_from_block(500, &events, &[account(9)], 0).unwrap();
+
+        assert_eq!(found.len(), 2);
+        assert!(found.iter().all(|f| f.account == account(9)));
+        assert_eq!(found[0].event.era, Some(10));
+        assert_eq!(
+            found[0].event.validator,
+            Some(encode_ss58(&account(1), 0).unwrap())
+        );
+        assert_eq!(found[0].event.amount, 100);
+        assert_eq!(found[0].event.event_index, 2);
+        assert_eq!(found[1].event.era, Some(11));
+        assert_eq!(found[1].event.amount, 70);
+        assert_eq!(found[1].event.block_number, 500);
+    }
+
+    #[test]
+    fn test_pool_paid_out_collected() {
+        let events = [
+            raw(
+                "NominationPools",
+                "PaidOut",
+                Value::named_composite([
+                    ("member", account_value(9)),
+                    ("pool_id", Value::u128(7)),
+                    ("payout", Value::u128(42)),
+                ]),
+            ),
+            rewarded(9, 1),
+        ];
+        let found = payout_events_from_block(1, &events, &[account(9)], 0).unwrap();
+
+        assert_eq!(found[0].event.source, PayoutSource::Pool);
+        assert_eq!(found[0].event.pool_id, Some(7));
+        assert_eq!(found[0].event.amount, 42);
+        // A Rewarded without a PayoutStarted has no era to reconcile against.
+        assert_eq!(found[1].event.era, None);
+        assert!(
+            payout_events_from_block(1, &events, &[account(3)], 0)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_may_pay_out() {
+        assert!(may_pay_out("Staking", "payout_stakers"));
+        assert!(may_pay_out("Staking", "payout_stakers_by_page"));
+        assert!(may_pay_out("NominationPools", "claim_payout"));
+        assert!(may_pay_out("Utility", "batch_all"));
+        assert!(!may_pay_out("Staking", "bond_extra"));
+        assert!(!may_pay_out("Balances", "transfer_keep_alive"));
+        assert!(!may_pay_out("Timestamp", "set"));
+    }
+}
@@ -1,12 +1,17 @@
 //! Nomination pool queries.
 
+use super::account::PoolMembership;
 use super::decode_helpers::extract_account_id;
 use crate::ChainClient;
 use crate::batch_storage::account_key;
 use crate::error::ChainError;
 use std::collections::HashMap;
-use stkopt_core::Balance;
+use stkopt_core::{
+    Balance, ClaimPermission, CommissionChangeRate, EraIndex, PoolAdmin, PoolCommission,
+    PoolMemberDetails, PoolRole, SubPools, UnbondPool, points_to_balance, unbonding_balances,
+};
 use subxt::dynamic::{At, Value};
+use subxt::ext::scale_value::ValueDef;
 use subxt::utils::AccountId32;
 
 /// Nomination pool state.
@@ -48,6 +53,22 @@ pub struct PoolRoles {
     pub bouncer: Option<AccountId32>,
 }
 
+impl PoolRoles {
+    /// Roles `account` holds in the pool, in field order.
+    pub fn roles_of(&self, account: &AccountId32) -> Vec<PoolRole> {
+        [
+            (Some(&self.depositor), PoolRole::Depositor),
+            (self.root.as_ref(), PoolRole::Root),
+            (self.nominator.as_ref(), PoolRole::Nominator),
+            (self.bouncer.as_ref(), PoolRole::Bouncer),
+        ]
+        .into_iter()
+        .filter(|(holder, _)| *holder == Some(account))
+        .map(|(_, role)| role)
+        .collect()
+    }
+}
+
 /// Pool metadata (name).
 #[derive(Debug, Clone)]
 pub struct PoolMetadata {
@@ -147,26 +168,9 @@ impl ChainClient {
                         .and_then(|v: &Value| v.as_u128())
                         .unwrap_or(0);
 
-                    let state = match decoded.at("state").map(|v: &Value| &v.value) {
-                        Some(subxt::ext::scale_value::ValueDef::Variant(variant)) => {
-                            match variant.name.as_str() {
-                                "Open" => PoolState::Open,
-                                "Blocked" => PoolState::Blocked,
-                                "Destroying" => PoolState::Destroying,
-                                other => {
-                                    tracing::warn!(
-                                        "Skipping pool {}: unknown state '{}'",
-                                        id,
-                                        other
-                                    );
-                                    continue;
-                                }
-                            }
-                        }
-                        _ => {
-                            tracing::warn!("Skipping pool {}: state field is not a variant", id);
-                            continue;
-                        }
+                    let Some(state) = parse_pool_state(decoded.at("state")) else {
+                        tracing::warn!("Skipping pool {}: unknown or missing state", id);
+                        continue;
                     };
 
                     let member_count = decoded
@@ -174,36 +178,11 @@ impl ChainClient {
                         .and_then(|v: &Value| v.as_u128())
                         .unwrap_or(0) as u32;
 
-                    // Parse roles
-                    let roles = if let Some(roles_val) = decoded.at("roles") {
-                        PoolRoles {
-                            depositor: parse_account_id(roles_val.at("depositor"))
-                                .unwrap_or_else(|| AccountId32::from([0u8; 32])),
-                            root: parse_account_id(roles_val.at("root")),
-                            nominator: parse_account_id(roles_val.at("nominator")),
-                            bouncer: parse_account_id(roles_val.at("bouncer")),
-                        }
-                    } else {
-                        PoolRoles {
-                            depositor: AccountId32::from([0u8; 32]),
-                            root: None,
-                            nominator: None,
-                            bouncer: None,
-                        }
-                    };
-
-                    // Parse commission (stored as Option<CommissionClaimPermission> in older runtimes
-                    // or Commission struct in newer runtimes)
-                    let commission = decoded.at("commission").and_then(|c| {
-                        // Try to get current commission rate
-                        // Commission struct has: { current: Option<(Perbill, AccountId)>, ... }
-                        c.at("current")
-                            .and_then(|current| {
-                                // current is Option<(Perbill, AccountId)>
-                                current.at(0).and_then(|perbill| perbill.as_u128())
-                            })
-                            .map(|perbill| perbill as f64 / 1_000_000_000.0)
-                    });
+                    let roles = parse_pool_roles(&decoded);
+                    let commission = decoded
+                        .at("commission")
+                        .and_then(|c| parse_pool_commission(c).current)
+                        .map(|perbill| perbill as f64 / 1_000_000_000.0);
 
                     pools_map.insert(
                         id,
@@ -374,7 +353,7 @@ impl ChainClient {
         // the storage proof. Treat this as "no nominations" rather than a fatal error.
         let result = match block
             .storage()
-            .try_fetch(&storage_query, vec![Value::from_bytes(stash.clone())])
+            .try_fetch(&storage_query, vec![Value::from_bytes(stash)])
             .await
         {
             Ok(r) => r,
@@ -425,7 +404,7 @@ impl ChainClient {
 
         let mut nominations = Vec::new();
         for chunk in pool_accounts.chunks(50) {
-            let stashes: Vec<AccountId32> = chunk.iter().map(|(_, stash)| stash.clone()).collect();
+            let stashes: Vec<AccountId32> = chunk.iter().map(|(_, stash)| *stash).collect();
             match self
                 .batch_fetch_account_storage_values("Staking", "Nominators", &stashes)
                 .await
@@ -501,11 +480,17 @@ impl ChainClient {
             .and_then(|v: &Value| v.as_u128())
             .unwrap_or(0);
 
+        let total_commission_pending = decoded
+            .at("total_commission_pending")
+            .and_then(|v: &Value| v.as_u128())
+            .unwrap_or(0);
+
         Ok(Some(RewardPool {
             pool_id,
             last_recorded_reward_counter,
             last_recorded_total_payouts,
             total_rewards_claimed,
+            total_commission_pending,
         }))
     }
 
@@ -555,6 +540,263 @@ impl ChainClient {
 
         Ok(pending)
     }
+
+    /// Convert a member's points to balance and read their claim permission.
+    ///
+    /// Active points convert at the ratio of the pool's bonded balance to
+    /// `BondedPools.points`; unbonding points within their era's pool in
+    /// `SubPoolsStorage`.
+    pub async fn get_pool_member_details(
+        &self,
+        member: &AccountId32,
+        membership: &PoolMembership,
+    ) -> Result<PoolMemberDetails, ChainError> {
+        let pool_id = membership.pool_id;
+        let block = self.client().at_current_block().await?;
+
+        let bonded_query =
+            subxt::dynamic::storage::<Vec<Value>, Value>("NominationPools", "BondedPools");
+        let pool_points = match block
+            .storage()
+            .try_fetch(&bonded_query, vec![Value::u128(pool_id as u128)])
+            .await?
+        {
+            Some(value) => {
+                let decoded: Value = value.decode()?;
+                decoded.at("points").and_then(Value::as_u128).unwrap_or(0)
+            }
+            None => 0,
+        };
+        let pool_balance = self
+            .get_staking_ledger(&derive_pool_account(pool_id, PoolAccountType::Bonded))
+            .await?
+            .map_or(0, |ledger| ledger.active);
+
+        let sub_pools_query =
+            subxt::dynamic::storage::<Vec<Value>, Value>("NominationPools", "SubPoolsStorage");
+        let sub_pools = match block
+            .storage()
+            .try_fetch(&sub_pools_query, vec![Value::u128(pool_id as u128)])
+            .await?
+        {
+            Some(value) => parse_sub_pools(&value.decode()?),
+            None => SubPools::default(),
+        };
+
+        let claim_permission = self.get_pool_claim_permission(member).await?;
+
+        let pending_rewards = self
+            .get_pool_pending_rewards(
+                pool_id,
+                membership.points,
+                membership.last_recorded_reward_counter,
+            )
+            .await?;
+
+        Ok(PoolMemberDetails {
+            pool_id,
+            points: membership.points,
+            balance: points_to_balance(membership.points, pool_points, pool_balance),
+            unbonding: unbonding_balances(&membership.unbonding_eras, &sub_pools),
+            claim_permission,
+            pending_rewards,
+        })
+    }
+
+    /// Who may claim or compound `member`'s pool rewards.
+    pub async fn get_pool_claim_permission(
+        &self,
+        member: &AccountId32,
+    ) -> Result<ClaimPermission, ChainError> {
+        let permission_query =
+            subxt::dynamic::storage::<Vec<Value>, Value>("NominationPools", "ClaimPermissions");
+        // Members without an entry hold the storage default from metadata
+        let block = self.client().at_current_block().await?;
+        let permission = block
+            .storage()
+            .fetch(&permission_query, vec![Value::from_bytes(member)])
+            .await?;
+        parse_claim_permission(&permission.decode()?).ok_or_else(|| {
+            ChainError::InvalidData("Unknown NominationPools claim permission".into())
+        })
+    }
+
+    /// Pools in which `account` holds a role in `PoolRoles`, with the
+    /// pool's state, name and commission.
+    ///
+    /// Iterates `BondedPools`, so returns partial results if iteration is
+    /// interrupted like `get_nomination_pools`.
+    pub async fn get_pool_admin_roles(
+        &self,
+        account: &AccountId32,
+    ) -> Result<Vec<PoolAdmin>, ChainError> {
+        let storage_query =
+            subxt::dynamic::storage::<Vec<Value>, Value>("NominationPools", "BondedPools");
+        let block = self.client().at_current_block().await?;
+        let mut iter = block.storage().iter(&storage_query, vec![]).await?;
+
+        let mut admins: HashMap<u32, PoolAdmin> = HashMap::new();
+        loop {
+            match iter.next().await {
+                Some(Ok(kv)) => {
+                    let key_bytes = kv.key_bytes();
+                    let Some(Ok(id_bytes)) = key_bytes
+                        .len()
+                        .checked_sub(4)
+                        .map(|start| <[u8; 4]>::try_from(&key_bytes[start..]))
+                    else {
+                        continue;
+                    };
+                    let id = u32::from_le_bytes(id_bytes);
+                    let Ok(decoded) = kv.value().decode() else {
+                        continue;
+                    };
+                    let roles = parse_pool_roles(&decoded).roles_of(account);
+                    if roles.is_empty() || admins.contains_key(&id) {
+                        continue;
+                    }
+                    let Some(state) = parse_pool_state(decoded.at("state")) else {
+                        continue;
+                    };
+                    admins.insert(
+                        id,
+                        PoolAdmin {
+                            pool_id: id,
+                            name: None,
+                            state: state.into(),
+                            roles,
+                            commission: decoded
+                                .at("commission")
+                                .map(parse_pool_commission)
+                                .unwrap_or_default(),
+                        },
+                    );
+                }
+                Some(Err(e)) => {
+                    tracing::warn!(
+                        "Pool role scan interrupted after {} matches: {}",
+                        admins.len(),
+                        e
+                    );
+                    break;
+                }
+                None => break,
+            }
+        }
+
+        let mut admins: Vec<PoolAdmin> = admins.into_values().collect();
+        admins.sort_by_key(|a| a.pool_id);
+        for admin in &mut admins {
+            admin.name = self
+                .get_pool_name(admin.pool_id)
+                .await?
+                .filter(|name| !name.is_empty());
+            admin.commission.pending = self
+                .get_reward_pool(admin.pool_id)
+                .await?
+                .map_or(0, |pool| pool.total_commission_pending);
+        }
+        Ok(admins)
+    }
+}
+
+/// Decode `SubPools { no_era: UnbondPool, with_era: BTreeMap<EraIndex, UnbondPool> }`.
+pub(crate) fn parse_sub_pools(decoded: &Value) -> SubPools {
+    let unbond_pool = |value: Option<&Value>| UnbondPool {
+        points: value
+            .and_then(|v| v.at("points"))
+            .and_then(Value::as_u128)
+            .unwrap_or(0),
+        balance: value
+            .and_then(|v| v.at("balance"))
+            .and_then(Value::as_u128)
+            .unwrap_or(0),
+    };
+    let with_era = decoded
+        .at("with_era")
+        .map(|map| {
+            (0..)
+                .map_while(|i| map.at(i))
+                .filter_map(|pair| {
+                    let era = pair.at(0).and_then(Value::as_u128)? as EraIndex;
+                    Some((era, unbond_pool(pair.at(1))))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    SubPools {
+        no_era: unbond_pool(decoded.at("no_era")),
+        with_era,
+    }
+}
+
+/// Decode a `ClaimPermission` variant; `None` for an unknown variant.
+pub(crate) fn parse_claim_permission(decoded: &Value) -> Option<ClaimPermission> {
+    match &decoded.value {
+        ValueDef::Variant(variant) => ClaimPermission::from_variant_name(&variant.name),
+        _ => None,
+    }
+}
+
+/// Decode a `PoolState` variant.
+pub(crate) fn parse_pool_state(value: Option<&Value>) -> Option<PoolState> {
+    let ValueDef::Variant(variant) = &value?.value else {
+        return None;
+    };
+    match variant.name.as_str() {
+        "Open" => Some(PoolState::Open),
+        "Blocked" => Some(PoolState::Blocked),
+        "Destroying" => Some(PoolState::Destroying),
+        _ => None,
+    }
+}
+
+/// Decode the `roles` of a `BondedPoolInner`.
+pub(crate) fn parse_pool_roles(decoded: &Value) -> PoolRoles {
+    let roles = decoded.at("roles");
+    PoolRoles {
+        // Unlike the other roles, the depositor is not an `Option`
+        depositor: roles
+            .and_then(|r| r.at("depositor"))
+            .and_then(extract_account_id)
+            .unwrap_or_else(|| AccountId32::from([0u8; 32])),
+        root: roles.and_then(|r| parse_account_id(r.at("root"))),
+        nominator: roles.and_then(|r| parse_account_id(r.at("nominator"))),
+        bouncer: roles.and_then(|r| parse_account_id(r.at("bouncer"))),
+    }
+}
+
+/// Decode `Commission { current: Option<(Perbill, AccountId)>, max:
+/// Option<Perbill>, change_rate: Option<CommissionChangeRate>, .. }`.
+///
+/// `pending` is left at zero; it lives in `RewardPools`.
+pub(crate) fn parse_pool_commission(decoded: &Value) -> PoolCommission {
+    let perbill = |v: &Value| {
+        v.as_u128()
+            .or_else(|| v.at(0).and_then(Value::as_u128))
+            .map(|p| p as u32)
+    };
+    PoolCommission {
+        current: option_inner(decoded.at("current"))
+            .and_then(|pair| pair.at(0))
+            .and_then(perbill),
+        max: option_inner(decoded.at("max")).and_then(perbill),
+        change_rate: option_inner(decoded.at("change_rate")).map(|rate| CommissionChangeRate {
+            max_increase: rate.at("max_increase").and_then(perbill).unwrap_or(0),
+            min_delay: rate.at("min_delay").and_then(Value::as_u128).unwrap_or(0) as u32,
+        }),
+        pending: 0,
+    }
+}
+
+/// Unwrap an `Option` variant, passing through values that are not one.
+fn option_inner(value: Option<&Value>) -> Option<&Value> {
+    let value = value?;
+    match &value.value {
+        ValueDef::Variant(variant) if variant.name == "None" => None,
+        ValueDef::Variant(variant) if variant.name == "Some" => variant.values.values().next(),
+        _ => Some(value),
+    }
 }
 
 fn parse_pool_nominations(pool_id: u32, stash: AccountId32, decoded: &Value) -> PoolNominations {
@@ -587,6 +829,8 @@ pub struct RewardPool {
     pub last_recorded_total_payouts: Balance,
     /// Total rewards claimed by members.
     pub total_rewards_claimed: Balance,
+    /// Commission accrued and not yet claimed by the pool's payee.
+    pub total_commission_pending: Balance,
 }
 
 /// Parse an AccountId from a dynamic Value.
@@ -798,6 +1042,45 @@ mod tests {
 
     // ── parse_pool_nominations ──
 
+    #[test]
+    fn test_parse_sub_pools() {
+        let unbond_pool = |points: u128, balance: u128| {
+            Value::named_composite([
+                ("points", Value::u128(points)),
+                ("balance", Value::u128(balance)),
+            ])
+        };
+        let decoded = Value::named_composite([
+            ("no_era", unbond_pool(10, 5)),
+            (
+                "with_era",
+                Value::unnamed_composite([
+                    Value::unnamed_composite([Value::u128(30), unbond_pool(100, 90)]),
+                    Value::unnamed_composite([Value::u128(31), unbond_pool(7, 7)]),
+                ]),
+            ),
+        ]);
+        let sub_pools = parse_sub_pools(&decoded);
+
+        assert_eq!(sub_pools.no_era.balance, 5);
+        assert_eq!(sub_pools.with_era.len(), 2);
+        assert_eq!(sub_pools.balance_of(30, 50), 45);
+        assert_eq!(sub_pools.balance_of(12, 4), 2);
+    }
+
+    #[test]
+    fn test_parse_claim_permission() {
+        assert_eq!(
+            parse_claim_permission(&Value::unnamed_variant("PermissionlessCompound", [])),
+            Some(ClaimPermission::PermissionlessCompound)
+        );
+        assert_eq!(
+            parse_claim_permission(&Value::unnamed_variant("Unknown", [])),
+            None
+        );
+        assert_eq!(parse_claim_permission(&Value::u128(1)), None);
+    }
+
     #[test]
     fn test_parse_pool_nominations_empty_targets() {
         let decoded = Value::named_composite([("targets", Value::unnamed_composite(vec![]))]);
@@ -858,4 +1141,89 @@ mod tests {
         assert_eq!(result.targets.len(), 1);
         assert_eq!(result.targets[0], AccountId32::from(valid));
     }
+
+    // ── pool administration ──
+
+    #[test]
+    fn test_parse_pool_roles_and_roles_of() {
+        let root = [1u8; 32];
+        let bouncer = [2u8; 32];
+        let decoded = Value::named_composite([(
+            "roles",
+            Value::named_composite([
+                ("depositor", make_account_value(root)),
+                (
+                    "root",
+                    Value::unnamed_variant("Some", vec![make_account_value(root)]),
+                ),
+                ("nominator", Value::unnamed_variant("None", vec![])),
+                (
+                    "bouncer",
+                    Value::unnamed_variant("Some", vec![make_account_value(bouncer)]),
+                ),
+            ]),
+        )]);
+        let roles = parse_pool_roles(&decoded);
+
+        assert_eq!(
+            roles.roles_of(&AccountId32::from(root)),
+            vec![PoolRole::Depositor, PoolRole::Root]
+        );
+        assert_eq!(
+            roles.roles_of(&AccountId32::from(bouncer)),
+            vec![PoolRole::Bouncer]
+        );
+        assert!(roles.roles_of(&AccountId32::from([3u8; 32])).is_empty());
+        assert_eq!(
+            parse_pool_state(Some(&Value::unnamed_variant("Blocked", vec![]))),
+            Some(PoolState::Blocked)
+        );
+    }
+
+    #[test]
+    fn test_parse_pool_commission() {
+        let decoded = Value::named_composite([
+            (
+                "current",
+                Value::unnamed_variant(
+                    "Some",
+                    vec![Value::unnamed_composite(vec![
+                        Value::u128(50_000_000),
+                        make_account_value([1u8; 32]),
+                    ])],
+                ),
+            ),
+            (
+                "max",
+                Value::unnamed_variant("Some", vec![Value::u128(100_000_000)]),
+            ),
+            (
+                "change_rate",
+                Value::unnamed_variant(
+                    "Some",
+                    vec![Value::named_composite([
+                        ("max_increase", Value::u128(10_000_000)),
+                        ("min_delay", Value::u128(14_400)),
+                    ])],
+                ),
+            ),
+        ]);
+        let commission = parse_pool_commission(&decoded);
+
+        assert_eq!(commission.current, Some(50_000_000));
+        assert_eq!(commission.max, Some(100_000_000));
+        assert_eq!(
+            commission.change_rate,
+            Some(CommissionChangeRate {
+                max_increase: 10_000_000,
+                min_delay: 14_400,
+            })
+        );
+
+        let unset = Value::named_composite([
+            ("current", Value::unnamed_variant("None", vec![])),
+            ("max", Value::unnamed_variant("None", vec![])),
+        ]);
+        assert_eq!(parse_pool_commission(&unset), PoolCommission::default());
+    }
 }
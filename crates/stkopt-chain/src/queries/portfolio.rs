@@ -0,0 +1,210 @@
+//! Staking status for many accounts at once.
+
+use crate::ChainClient;
+use crate::batch_storage::account_key;
+use crate::error::ChainError;
+use crate::ss58::encode_ss58;
+use stkopt_core::{Balance, NominationPoolMembership, PortfolioAccount};
+use subxt::utils::AccountId32;
+
+use super::account::{
+    AccountBalance, NominatorInfo, PoolMembership, StakingLedger, parse_account_balance,
+    parse_nominator_info, parse_pool_membership, parse_staking_ledger,
+};
+
+/// Staking status of one account in a portfolio.
+#[derive(Debug, Clone)]
+pub struct PortfolioAccountStatus {
+    pub address: AccountId32,
+    pub balance: AccountBalance,
+    pub ledger: Option<StakingLedger>,
+    pub nominations: Option<NominatorInfo>,
+    pub pool_membership: Option<PoolMembership>,
+    /// Claimable pool rewards, zero when not a pool member.
+    pub pool_pending_rewards: Balance,
+}
+
+impl PortfolioAccountStatus {
+    /// Convert to the core [`PortfolioAccount`], encoding addresses with
+    /// `ss58_prefix`.
+    #[allow(clippy::result_large_err)]
+    pub fn to_portfolio_account(
+        &self,
+        label: Option<String>,
+        ss58_prefix: u16,
+    ) -> Result<PortfolioAccount, ChainError> {
+        let direct_unbonding: Balance = self
+            .ledger
+            .iter()
+            .flat_map(|l| &l.unlocking)
+            .map(|c| c.value)
+            .sum();
+        let pool_unbonding: Balance = self
+            .pool_membership
+            .iter()
+            .flat_map(|m| &m.unbonding_eras)
+            .map(|(_, value)| *value)
+            .sum();
+        let targets = match &self.nominations {
+            Some(n) => n
+                .targets
+                .iter()
+                .map(|t| encode_ss58(t, ss58_prefix))
+                .collect::<Result<_, _>>()?,
+            None => Vec::new(),
+        };
+        Ok(PortfolioAccount {
+            address: encode_ss58(&self.address, ss58_prefix)?,
+            label,
+            bonded: self.ledger.as_ref().map(|l| l.active).unwrap_or(0),
+            unbonding: direct_unbonding + pool_unbonding,
+            targets,
+            pool: self
+                .pool_membership
+                .as_ref()
+                .map(|m| NominationPoolMembership {
+                    pool_id: m.pool_id,
+                    points: m.points,
+                    pending_rewards: self.pool_pending_rewards,
+                }),
+        })
+    }
+}
+
+impl ChainClient {
+    /// Fetch the staking status of every account in `accounts`.
+    ///
+    /// Account storage is read in batches of 50 keys per entry; a chunk whose
+    /// batch read fails is fetched one key at a time instead. The result keeps
+    /// the order of `accounts`.
+    pub async fn get_accounts_status(
+        &self,
+        accounts: &[AccountId32],
+    ) -> Result<Vec<PortfolioAccountStatus>, ChainError> {
+        let mut statuses = Vec::with_capacity(accounts.len());
+
+        for chunk in accounts.chunks(50) {
+            match self.batch_fetch_chunk_status(chunk).await {
+                Ok(chunk_statuses) => statuses.extend(chunk_statuses),
+                Err(error) => {
+                    tracing::debug!(
+                        "Batch account status fetch failed for {} accounts: {}",
+                        chunk.len(),
+                        error
+                    );
+                    for account in chunk {
+                        statuses.push(PortfolioAccountStatus {
+                            address: *account,
+                            balance: self.get_account_balance(account).await?,
+                            ledger: self.get_staking_ledger(account).await?,
+                            nominations: self.get_nominations(account).await?,
+                            pool_membership: self.get_pool_membership(account).await?,
+                            pool_pending_rewards: 0,
+                        });
+                    }
+                }
+            }
+        }
+
+        for status in &mut statuses {
+            if let Some(m) = &status.pool_membership {
+                status.pool_pending_rewards = self
+                    .get_pool_pending_rewards(m.pool_id, m.points, m.last_recorded_reward_counter)
+                    .await?;
+            }
+        }
+
+        Ok(statuses)
+    }
+
+    async fn batch_fetch_chunk_status(
+        &self,
+        chunk: &[AccountId32],
+    ) -> Result<Vec<PortfolioAccountStatus>, ChainError> {
+        let balances = self
+            .batch_fetch_account_storage_values("System", "Account", chunk)
+            .await?;
+        let ledgers = self
+            .batch_fetch_account_storage_values("Staking", "Ledger", chunk)
+            .await?;
+        let nominators = self
+            .batch_fetch_account_storage_values("Staking", "Nominators", chunk)
+            .await?;
+        let members = self
+            .batch_fetch_account_storage_values("NominationPools", "PoolMembers", chunk)
+            .await?;
+
+        Ok(chunk
+            .iter()
+            .map(|account| {
+                let key = account_key(account);
+                PortfolioAccountStatus {
+                    address: *account,
+                    balance: balances.get(&key).map(parse_account_balance).unwrap_or(
+                        AccountBalance {
+                            free: 0,
+                            reserved: 0,
+                            frozen: 0,
+                        },
+                    ),
+                    ledger: ledgers.get(&key).map(|v| parse_staking_ledger(account, v)),
+                    nominations: nominators.get(&key).map(parse_nominator_info),
+                    pool_membership: members.get(&key).map(parse_pool_membership),
+                    pool_pending_rewards: 0,
+                }
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::queries::account::UnlockChunk;
+
+    #[test]
+    fn test_to_portfolio_account_sums_unbonding() {
+        let stash = AccountId32::from([1u8; 32]);
+        let status = PortfolioAccountStatus {
+            address: stash,
+            balance: AccountBalance {
+                free: 1_000,
+                reserved: 0,
+                frozen: 500,
+            },
+            ledger: Some(StakingLedger {
+                stash,
+                total: 500,
+                active: 400,
+                unlocking: vec![UnlockChunk {
+                    value: 100,
+                    era: 10,
+                }],
+            }),
+            nominations: Some(NominatorInfo {
+                targets: vec![AccountId32::from([2u8; 32])],
+                submitted_in: 5,
+            }),
+            pool_membership: Some(PoolMembership {
+                pool_id: 3,
+                points: 200,
+                unbonding_eras: vec![(11, 20), (12, 30)],
+                last_recorded_reward_counter: 0,
+            }),
+            pool_pending_rewards: 7,
+        };
+
+        let account = status
+            .to_portfolio_account(Some("Main".to_string()), 0)
+            .unwrap();
+        assert_eq!(account.label.as_deref(), Some("Main"));
+        assert_eq!(account.bonded, 400);
+        assert_eq!(account.unbonding, 150);
+        assert_eq!(account.staked(), 600);
+        assert_eq!(account.pending_rewards(), 7);
+        assert_eq!(
+            account.targets,
+            vec![encode_ss58(&AccountId32::from([2u8; 32]), 0).unwrap()]
+        );
+    }
+}
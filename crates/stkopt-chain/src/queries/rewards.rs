@@ -0,0 +1,201 @@
+//! Exact per-era reward queries for a single nominator.
+
+use crate::ChainClient;
+use crate::error::ChainError;
+use crate::ss58::encode_ss58;
+use futures::StreamExt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use stkopt_core::{
+    Balance, EraIndex, ExpectedPayout, PayoutEvent, PayoutSource, ValidatorEraPayout,
+    validator_era_reward,
+};
+use subxt::dynamic::{At, Value};
+use subxt::utils::AccountId32;
+
+/// Eras whose expected payouts are computed concurrently.
+const ERA_CONCURRENCY: usize = 8;
+
+impl ChainClient {
+    /// Compute the reward `stash` earned in `era` from its stake behind
+    /// `targets`.
+    ///
+    /// Combines the era payout, each backing validator's reward points and
+    /// commission, and the stash's share of the paged exposure. Returns `None`
+    /// while the era payout is not yet known, i.e. for the current era.
+    pub async fn get_exact_era_reward(
+        &self,
+        stash: &AccountId32,
+        targets: &[AccountId32],
+        era: EraIndex,
+    ) -> Result<Option<Balance>, ChainError> {
+        Ok(self
+            .get_expected_payouts(stash, targets, era)
+            .await?
+            .map(|payouts| payouts.iter().map(|p| p.amount).sum()))
+    }
+
+    /// Reward `stash` should be paid in `era` by each validator in `targets`
+    /// that backed it, i.e. the expected `Staking.Rewarded` amounts.
+    ///
+    /// Returns `None` while the era payout is not yet known.
+    pub async fn get_expected_payouts(
+        &self,
+        stash: &AccountId32,
+        targets: &[AccountId32],
+        era: EraIndex,
+    ) -> Result<Option<Vec<ExpectedPayout>>, ChainError> {
+        let Some(era_payout) = self.get_era_validator_reward(era).await? else {
+            return Ok(None);
+        };
+        let exposure = self.get_nominator_exposure(stash, targets, era).await?;
+        if exposure.backing.is_empty() {
+            return Ok(Some(Vec::new()));
+        }
+        let (total_points, points) = self.get_era_reward_points(era).await?;
+        let ss58_prefix = self.network().ss58_format();
+
+        let mut payouts = Vec::with_capacity(exposure.backing.len());
+        for backing in &exposure.backing {
+            let payout = ValidatorEraPayout {
+                reward_points: points
+                    .iter()
+                    .find(|p| p.address == backing.validator)
+                    .map(|p| p.points)
+                    .unwrap_or(0),
+                commission: self
+                    .get_era_validator_commission(era, &backing.validator)
+                    .await?,
+                exposure_total: backing.exposure_total,
+                nominator_stake: backing.value,
+            };
+            let claimed = self
+                .get_claimed_reward_pages(era, &backing.validator)
+                .await?;
+            payouts.push(ExpectedPayout {
+                era,
+                validator: encode_ss58(&backing.validator, ss58_prefix)?,
+                amount: validator_era_reward(era_payout, total_points, &payout),
+                claimed: claimed.contains(&backing.page),
+            });
+        }
+        Ok(Some(payouts))
+    }
+
+    /// Expected payouts of `stash` over `eras`, from each era's exposure.
+    ///
+    /// Each era's exposure is searched for `targets` and for the validators
+    /// that paid `stash` that era in `events`, so validators it no longer
+    /// nominates are still found once they paid. Eras that fail to load or
+    /// are not yet paid out are skipped.
+    pub async fn get_expected_payouts_in(
+        &self,
+        stash: &AccountId32,
+        targets: &[AccountId32],
+        eras: RangeInclusive<EraIndex>,
+        events: &[PayoutEvent],
+    ) -> Vec<ExpectedPayout> {
+        futures::stream::iter(eras)
+            .map(|era| async move {
+                let mut era_targets = targets.to_vec();
+                let paid_by = events
+                    .iter()
+                    .filter(|e| e.source == PayoutSource::Staking && e.era == Some(era))
+                    .filter_map(|e| AccountId32::from_str(e.validator.as_deref()?).ok());
+                for validator in paid_by {
+                    if !era_targets.contains(&validator) {
+                        era_targets.push(validator);
+                    }
+                }
+                match self.get_expected_payouts(stash, &era_targets, era).await {
+                    Ok(payouts) => payouts.unwrap_or_default(),
+                    Err(e) => {
+                        tracing::debug!("No expected payouts for era {}: {}", era, e);
+                        Vec::new()
+                    }
+                }
+            })
+            .buffered(ERA_CONCURRENCY)
+            .concat()
+            .await
+    }
+
+    /// Exposure pages of `validator` already paid out for `era`, from
+    /// `Staking.ClaimedRewards`.
+    pub async fn get_claimed_reward_pages(
+        &self,
+        era: EraIndex,
+        validator: &AccountId32,
+    ) -> Result<Vec<u32>, ChainError> {
+        let storage_query =
+            subxt::dynamic::storage::<Vec<Value>, Value>("Staking", "ClaimedRewards");
+
+        let block = self.client().at_current_block().await?;
+        let result = block
+            .storage()
+            .try_fetch(
+                &storage_query,
+                vec![Value::u128(era as u128), Value::from_bytes(validator)],
+            )
+            .await?;
+
+        let Some(value) = result else {
+            return Ok(Vec::new());
+        };
+        let pages: Value = value.decode()?;
+        let mut claimed = Vec::new();
+        while let Some(page) = pages.at(claimed.len()) {
+            claimed.push(page.as_u128().unwrap_or(0) as u32);
+        }
+        Ok(claimed)
+    }
+
+    /// Commission `validator` charged in `era`, in parts per billion, from
+    /// `ErasValidatorPrefs`. Zero when not recorded.
+    pub async fn get_era_validator_commission(
+        &self,
+        era: EraIndex,
+        validator: &AccountId32,
+    ) -> Result<u32, ChainError> {
+        let storage_query =
+            subxt::dynamic::storage::<Vec<Value>, Value>("Staking", "ErasValidatorPrefs");
+
+        let block = self.client().at_current_block().await?;
+        let result = block
+            .storage()
+            .try_fetch(
+                &storage_query,
+                vec![Value::u128(era as u128), Value::from_bytes(validator)],
+            )
+            .await?;
+
+        let Some(value) = result else {
+            return Ok(0);
+        };
+        Ok(parse_commission_parts(&value.decode()?))
+    }
+}
+
+/// Commission of a decoded `ValidatorPrefs`, in parts per billion.
+fn parse_commission_parts(prefs: &Value) -> u32 {
+    prefs
+        .at("commission")
+        .and_then(|v: &Value| v.as_u128())
+        .unwrap_or(0)
+        .min(1_000_000_000) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_commission_parts() {
+        let prefs = Value::named_composite([
+            ("commission", Value::u128(50_000_000)),
+            ("blocked", Value::bool(false)),
+        ]);
+        assert_eq!(parse_commission_parts(&prefs), 50_000_000);
+        assert_eq!(parse_commission_parts(&Value::unnamed_composite([])), 0);
+    }
+}
@@ -0,0 +1,370 @@
+//! Slashing and offence queries.
+//!
+//! `UnappliedSlashes`, `ValidatorSlashInEra` and `NominatorSlashInEra` live in
+//! the staking pallet on Asset Hub; offence reports stay with the
+//! `Offences` pallet on the relay chain. All three staking maps are keyed by
+//! era first, so the era and account are read back from the raw storage key.
+//! `Offences.Reports` keeps every report ever made, so it is only read on
+//! request through [`ChainClient::get_offence_reports`].
+
+use crate::ChainClient;
+use crate::error::ChainError;
+use crate::ss58::encode_ss58;
+use stkopt_core::{
+    Balance, EraIndex, NominatorSlash, OffenceReport, PendingSlash, SlashHistory, ValidatorSlash,
+};
+use subxt::dynamic::{At, Value};
+use subxt::utils::AccountId32;
+use subxt::{OnlineClient, PolkadotConfig};
+
+use super::decode_helpers::extract_account_id;
+use super::exposure::NominatorExposure;
+
+/// Offset of the era in a key hashed with `Twox64Concat`: pallet and entry
+/// prefixes, then the 8-byte hash.
+const ERA_KEY_OFFSET: usize = 16 + 16 + 8;
+
+impl ChainClient {
+    /// Network-wide slashing state: recorded validator slashes and deferred
+    /// slashes, without offence reports.
+    pub async fn get_slash_history(&self) -> Result<SlashHistory, ChainError> {
+        let active_era = self.get_active_era().await?.map_or(0, |era| era.index);
+        Ok(SlashHistory {
+            validators: self.get_validator_slashes().await?,
+            pending: self.get_unapplied_slashes().await?,
+            offences: Vec::new(),
+            active_era,
+            bonding_duration: self.get_bonding_duration().await?,
+        })
+    }
+
+    /// Deferred slashes from `Staking.UnappliedSlashes`.
+    pub async fn get_unapplied_slashes(&self) -> Result<Vec<PendingSlash>, ChainError> {
+        let prefix = self.network().ss58_format();
+        let mut slashes = Vec::new();
+        for (key, value) in iter_entries(self.client(), "Staking", "UnappliedSlashes").await? {
+            let Some(era) = key_era(&key) else {
+                continue;
+            };
+            slashes.extend(parse_unapplied_slashes(era, &value, prefix)?);
+        }
+        slashes.sort_by_key(|s| s.apply_era);
+        Ok(slashes)
+    }
+
+    /// Validator slashes recorded in `Staking.ValidatorSlashInEra`, oldest
+    /// first.
+    pub async fn get_validator_slashes(&self) -> Result<Vec<ValidatorSlash>, ChainError> {
+        let prefix = self.network().ss58_format();
+        let mut slashes = Vec::new();
+        for (key, value) in iter_entries(self.client(), "Staking", "ValidatorSlashInEra").await? {
+            let (Some(era), Some(validator)) = (key_era(&key), key_account(&key)) else {
+                continue;
+            };
+            let (fraction, amount) = parse_validator_slash(&value);
+            slashes.push(ValidatorSlash {
+                era,
+                validator: encode_ss58(&validator, prefix)?,
+                fraction,
+                amount,
+            });
+        }
+        slashes.sort_by_key(|s| s.era);
+        Ok(slashes)
+    }
+
+    /// Slashes applied to `nominator`, from `Staking.NominatorSlashInEra`,
+    /// oldest first.
+    pub async fn get_nominator_slashes(
+        &self,
+        nominator: &AccountId32,
+    ) -> Result<Vec<NominatorSlash>, ChainError> {
+        let mut slashes: Vec<NominatorSlash> =
+            iter_entries(self.client(), "Staking", "NominatorSlashInEra")
+                .await?
+                .into_iter()
+                .filter(|(key, _)| key_account(key).as_ref() == Some(nominator))
+                .filter_map(|(key, value)| {
+                    Some(NominatorSlash {
+                        era: key_era(&key)?,
+                        amount: value.as_u128().unwrap_or(0),
+                    })
+                })
+                .collect();
+        slashes.sort_by_key(|s| s.era);
+        Ok(slashes)
+    }
+
+    /// Offence reports from the relay chain's `Offences.Reports`.
+    ///
+    /// Iterates the whole map, so callers only load it when asked to.
+    /// Empty when the connected chain has no `Offences` pallet.
+    pub async fn get_offence_reports(&self) -> Result<Vec<OffenceReport>, ChainError> {
+        let prefix = self.network().ss58_format();
+        let mut reports = Vec::new();
+        for (_, value) in iter_entries(self.relay_client(), "Offences", "Reports").await? {
+            if let Some(report) = parse_offence_report(&value, prefix)? {
+                reports.push(report);
+            }
+        }
+        Ok(reports)
+    }
+}
+
+/// Stake behind each of `targets`, for [`stkopt_core::assess_slash_risk`]:
+/// the elected backing where `exposure` has one, otherwise an even split of
+/// `bonded`, which is what the nomination puts at stake once elected.
+#[allow(clippy::result_large_err)]
+pub fn slash_risk_targets(
+    targets: &[AccountId32],
+    exposure: Option<&NominatorExposure>,
+    bonded: Balance,
+    ss58_prefix: u16,
+) -> Result<Vec<(String, Balance)>, ChainError> {
+    let share = bonded / targets.len().max(1) as Balance;
+    targets
+        .iter()
+        .map(|target| {
+            let stake = exposure
+                .and_then(|e| e.backing.iter().find(|b| &b.validator == target))
+                .map_or(share, |b| b.value);
+            Ok((encode_ss58(target, ss58_prefix)?, stake))
+        })
+        .collect()
+}
+
+/// All entries of a storage map as raw key and decoded value; empty when the
+/// runtime lacks the entry. Returns partial results if iteration is
+/// interrupted.
+async fn iter_entries(
+    client: &OnlineClient<PolkadotConfig>,
+    pallet: &str,
+    entry: &str,
+) -> Result<Vec<(Vec<u8>, Value)>, ChainError> {
+    let block = client.at_current_block().await?;
+    let has_entry = block
+        .metadata()
+        .pallet_by_name(pallet)
+        .and_then(|p| p.storage())
+        .and_then(|s| s.entry_by_name(entry))
+        .is_some();
+    if !has_entry {
+        return Ok(Vec::new());
+    }
+
+    let storage_query = subxt::dynamic::storage::<Vec<Value>, Value>(pallet, entry);
+    let mut iter = block.storage().iter(&storage_query, vec![]).await?;
+    let mut entries = Vec::new();
+    loop {
+        match iter.next().await {
+            Some(Ok(kv)) => {
+                let key = kv.key_bytes().to_vec();
+                match kv.value().decode() {
+                    Ok(value) => entries.push((key, value)),
+                    Err(e) => tracing::debug!("Skipping undecodable {}.{}: {}", pallet, entry, e),
+                }
+            }
+            Some(Err(e)) => {
+                tracing::warn!("{}.{} iteration interrupted: {}", pallet, entry, e);
+                break;
+            }
+            None => break,
+        }
+    }
+    Ok(entries)
+}
+
+/// Era of a storage key whose first key is an era hashed with `Twox64Concat`.
+fn key_era(key: &[u8]) -> Option<EraIndex> {
+    let bytes = key.get(ERA_KEY_OFFSET..ERA_KEY_OFFSET + 4)?;
+    Some(EraIndex::from_le_bytes(bytes.try_into().ok()?))
+}
+
+/// Account of a storage key ending in an account hashed with `Twox64Concat`.
+fn key_account(key: &[u8]) -> Option<AccountId32> {
+    let bytes: [u8; 32] = key.get(key.len().checked_sub(32)?..)?.try_into().ok()?;
+    Some(AccountId32::from(bytes))
+}
+
+/// Perbill parts of a decoded `Perbill`, whether or not it is wrapped.
+fn perbill_parts(value: &Value) -> Option<u32> {
+    value
+        .as_u128()
+        .or_else(|| value.at(0).and_then(Value::as_u128))
+        .map(|parts| parts.min(1_000_000_000) as u32)
+}
+
+/// Decode a `ValidatorSlashInEra` value, `(Perbill, Balance)`.
+fn parse_validator_slash(value: &Value) -> (u32, u128) {
+    (
+        value.at(0).and_then(perbill_parts).unwrap_or(0),
+        value.at(1).and_then(Value::as_u128).unwrap_or(0),
+    )
+}
+
+/// Decode an `UnappliedSlashes` value applied in `era`: a single
+/// `UnappliedSlash { validator, own, others, .. }` or, in older runtimes, a
+/// list of them.
+#[allow(clippy::result_large_err)]
+pub(crate) fn parse_unapplied_slashes(
+    era: EraIndex,
+    value: &Value,
+    ss58_prefix: u16,
+) -> Result<Vec<PendingSlash>, ChainError> {
+    let slashes: Vec<&Value> = if value.at("validator").is_some() {
+        vec![value]
+    } else {
+        (0..).map_while(|i| value.at(i)).collect()
+    };
+
+    let mut pending = Vec::with_capacity(slashes.len());
+    for slash in slashes {
+        let Some(validator) = slash.at("validator").and_then(extract_account_id) else {
+            continue;
+        };
+        let mut others = Vec::new();
+        if let Some(list) = slash.at("others") {
+            for other in (0..).map_while(|i| list.at(i)) {
+                let Some(nominator) = other.at(0).and_then(extract_account_id) else {
+                    continue;
+                };
+                let amount = other.at(1).and_then(Value::as_u128).unwrap_or(0);
+                others.push((encode_ss58(&nominator, ss58_prefix)?, amount));
+            }
+        }
+        pending.push(PendingSlash {
+            apply_era: era,
+            validator: encode_ss58(&validator, ss58_prefix)?,
+            own: slash.at("own").and_then(Value::as_u128).unwrap_or(0),
+            others,
+        });
+    }
+    Ok(pending)
+}
+
+/// Decode an `OffenceDetails { offender: (AccountId, ..), reporters }`.
+#[allow(clippy::result_large_err)]
+pub(crate) fn parse_offence_report(
+    value: &Value,
+    ss58_prefix: u16,
+) -> Result<Option<OffenceReport>, ChainError> {
+    let Some(offender) = value
+        .at("offender")
+        .and_then(|o| o.at(0))
+        .and_then(extract_account_id)
+    else {
+        return Ok(None);
+    };
+    let reporters = value
+        .at("reporters")
+        .map(|r| (0..).map_while(|i| r.at(i)).count())
+        .unwrap_or(0);
+    Ok(Some(OffenceReport {
+        offender: encode_ss58(&offender, ss58_prefix)?,
+        reporters: reporters as u32,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account(byte: u8) -> Value {
+        Value::from_bytes([byte; 32])
+    }
+
+    fn ss58(byte: u8) -> String {
+        encode_ss58(&AccountId32::from([byte; 32]), 0).unwrap()
+    }
+
+    fn unapplied(validator: u8, own: u128, others: &[(u8, u128)]) -> Value {
+        Value::named_composite([
+            ("validator", account(validator)),
+            ("own", Value::u128(own)),
+            (
+                "others",
+                Value::unnamed_composite(others.iter().map(|(who, amount)| {
+                    Value::unnamed_composite([account(*who), Value::u128(*amount)])
+                })),
+            ),
+            ("reporters", Value::unnamed_composite([])),
+            ("payout", Value::u128(0)),
+        ])
+    }
+
+    #[test]
+    fn test_slash_risk_targets_prefers_backing() {
+        use super::super::exposure::NominationBacking;
+
+        let targets = [AccountId32::from([1u8; 32]), AccountId32::from([2u8; 32])];
+        let exposure = NominatorExposure {
+            era: 10,
+            backing: vec![NominationBacking {
+                validator: targets[1],
+                value: 700,
+                page: 0,
+                page_count: 1,
+                exposure_total: 10_000,
+            }],
+            ..Default::default()
+        };
+        let stakes = slash_risk_targets(&targets, Some(&exposure), 1_000, 0).unwrap();
+        assert_eq!(stakes, vec![(ss58(1), 500), (ss58(2), 700)]);
+        assert!(slash_risk_targets(&[], None, 1_000, 0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_key_era_and_account() {
+        let mut key = vec![0u8; ERA_KEY_OFFSET];
+        key.extend(42u32.to_le_bytes());
+        key.extend([0u8; 8]);
+        key.extend([7u8; 32]);
+        assert_eq!(key_era(&key), Some(42));
+        assert_eq!(key_account(&key), Some(AccountId32::from([7u8; 32])));
+        assert_eq!(key_era(&key[..10]), None);
+        assert_eq!(key_account(&key[..10]), None);
+    }
+
+    #[test]
+    fn test_parse_unapplied_slashes_single_and_list() {
+        let single = parse_unapplied_slashes(5, &unapplied(1, 100, &[(2, 30)]), 0).unwrap();
+        assert_eq!(single.len(), 1);
+        assert_eq!(single[0].apply_era, 5);
+        assert_eq!(single[0].validator, ss58(1));
+        assert_eq!(single[0].own, 100);
+        assert_eq!(single[0].others, vec![(ss58(2), 30)]);
+
+        let list = Value::unnamed_composite([unapplied(1, 100, &[]), unapplied(3, 7, &[(2, 1)])]);
+        let parsed = parse_unapplied_slashes(6, &list, 0).unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[1].amount_for(&ss58(2)), 1);
+    }
+
+    #[test]
+    fn test_parse_validator_slash_and_offence() {
+        let slash = Value::unnamed_composite([
+            Value::unnamed_composite([Value::u128(100_000_000)]),
+            Value::u128(5_000),
+        ]);
+        assert_eq!(parse_validator_slash(&slash), (100_000_000, 5_000));
+
+        let offence = Value::named_composite([
+            (
+                "offender",
+                Value::unnamed_composite([account(4), Value::unnamed_composite([])]),
+            ),
+            (
+                "reporters",
+                Value::unnamed_composite([account(5), account(6)]),
+            ),
+        ]);
+        let report = parse_offence_report(&offence, 0).unwrap().unwrap();
+        assert_eq!(report.offender, ss58(4));
+        assert_eq!(report.reporters, 2);
+        assert!(
+            parse_offence_report(&Value::unnamed_composite([]), 0)
+                .unwrap()
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,62 @@
+//! Staking limit queries.
+
+use crate::ChainClient;
+use crate::error::ChainError;
+use stkopt_core::StakingParams;
+use subxt::dynamic::Value;
+
+impl ChainClient {
+    /// Get the staking and nomination pool limits.
+    ///
+    /// Limits whose storage is empty are reported as zero (unchecked);
+    /// `MaxNominatorsCount` as `None` (uncapped).
+    pub async fn get_staking_params(&self) -> Result<StakingParams, ChainError> {
+        Ok(StakingParams {
+            min_nominator_bond: self
+                .fetch_plain_u128("Staking", "MinNominatorBond")
+                .await?
+                .unwrap_or(0),
+            min_active_stake: self
+                .fetch_plain_u128("Staking", "MinimumActiveStake")
+                .await?
+                .unwrap_or(0),
+            max_nominators: self
+                .fetch_plain_u128("Staking", "MaxNominatorsCount")
+                .await?
+                .map(|max| max as u32),
+            nominator_count: self
+                .fetch_plain_u128("Staking", "CounterForNominators")
+                .await?
+                .unwrap_or(0) as u32,
+            max_exposure_page_size: self
+                .get_constant_u32("Staking", "MaxExposurePageSize")
+                .await?,
+            min_join_bond: self
+                .fetch_plain_u128("NominationPools", "MinJoinBond")
+                .await?
+                .unwrap_or(0),
+            min_create_bond: self
+                .fetch_plain_u128("NominationPools", "MinCreateBond")
+                .await?
+                .unwrap_or(0),
+            bonding_duration: self.get_bonding_duration().await?,
+        })
+    }
+
+    /// Fetch a plain (unkeyed) integer storage value.
+    pub(crate) async fn fetch_plain_u128(
+        &self,
+        pallet: &str,
+        entry: &str,
+    ) -> Result<Option<u128>, ChainError> {
+        let storage_query = subxt::dynamic::storage::<Vec<Value>, Value>(pallet, entry);
+        let block = self.client().at_current_block().await?;
+        let Some(value) = block.storage().try_fetch(&storage_query, vec![]).await? else {
+            return Ok(None);
+        };
+        let decoded: Value = value.decode()?;
+        decoded.as_u128().map(Some).ok_or_else(|| {
+            ChainError::InvalidData(format!("Invalid storage value {}::{}", pallet, entry))
+        })
+    }
+}
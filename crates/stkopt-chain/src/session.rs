@@ -0,0 +1,335 @@
+//! Record-and-replay of whole chain sessions.
+//!
+//! A recording session puts a [`MockRpcServer`] proxy in front of the Asset
+//! Hub, relay and People chain endpoints, so every storage query, runtime API
+//! call and metadata blob the app reads ends up in one [`SessionRecording`]
+//! file. Replaying serves that file back through the same mock servers with
+//! no network access, which lets a user's bug report be reproduced exactly.
+//!
+//! Sessions always use RPC; light-client traffic cannot be proxied. Submitting
+//! transactions is refused in both modes since submission subscriptions are
+//! not recorded.
+
+use crate::client::RpcEndpoints;
+use crate::config::{get_asset_hub_endpoints, get_people_chain_endpoints, get_rpc_endpoints};
+use crate::error::ChainError;
+use crate::mock_rpc::{MockRpcServer, Recording};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+use stkopt_core::Network;
+use tokio::task::JoinHandle;
+
+/// How often a recording in progress is flushed to disk.
+const FLUSH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Record or replay a session file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SessionMode {
+    /// Proxy live endpoints and write everything read to the file.
+    Record(PathBuf),
+    /// Serve a previously recorded file with no network.
+    Replay(PathBuf),
+}
+
+impl SessionMode {
+    /// Path of the session file.
+    pub fn path(&self) -> &Path {
+        match self {
+            SessionMode::Record(path) | SessionMode::Replay(path) => path,
+        }
+    }
+}
+
+/// Everything a session read, per chain.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionRecording {
+    pub network: Network,
+    pub asset_hub: Recording,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relay: Option<Recording>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub people: Option<Recording>,
+}
+
+impl SessionRecording {
+    /// Load a session file.
+    #[allow(clippy::result_large_err)]
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ChainError> {
+        let path = path.as_ref();
+        let data = std::fs::read(path)
+            .map_err(|e| ChainError::Recording(format!("{}: {}", path.display(), e)))?;
+        serde_json::from_slice(&data)
+            .map_err(|e| ChainError::Recording(format!("{}: {}", path.display(), e)))
+    }
+
+    /// Write the session file, replacing it atomically.
+    #[allow(clippy::result_large_err)]
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ChainError> {
+        let path = path.as_ref();
+        let data = serde_json::to_vec(self).map_err(|e| ChainError::Recording(e.to_string()))?;
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, data)
+            .and_then(|()| std::fs::rename(&tmp, path))
+            .map_err(|e| ChainError::Recording(format!("{}: {}", path.display(), e)))
+    }
+}
+
+/// Network a session file was recorded on.
+#[allow(clippy::result_large_err)]
+pub fn recorded_network(path: impl AsRef<Path>) -> Result<Network, ChainError> {
+    SessionRecording::load(path).map(|recording| recording.network)
+}
+
+/// Mock servers backing a recording or replay session.
+///
+/// Held by every `ChainClient` of the session; a recording is flushed
+/// periodically and once more when the last client is dropped.
+pub(crate) struct SessionServers {
+    network: Network,
+    asset_hub: MockRpcServer,
+    relay: Option<MockRpcServer>,
+    people: Option<MockRpcServer>,
+    /// Output file while recording; `None` when replaying.
+    output: Option<PathBuf>,
+    flush_task: Option<JoinHandle<()>>,
+}
+
+impl SessionServers {
+    /// Start servers for `mode`, using `endpoints` as upstreams when recording.
+    pub(crate) async fn start(
+        network: Network,
+        mode: &SessionMode,
+        endpoints: &RpcEndpoints,
+    ) -> Result<Arc<Self>, ChainError> {
+        match mode {
+            SessionMode::Record(path) => Self::record(network, endpoints, path.clone()).await,
+            SessionMode::Replay(path) => Self::replay(network, path).await,
+        }
+    }
+
+    async fn record(
+        network: Network,
+        endpoints: &RpcEndpoints,
+        output: PathBuf,
+    ) -> Result<Arc<Self>, ChainError> {
+        let asset_hub = start_recorder(
+            endpoints.asset_hub.as_deref(),
+            &get_asset_hub_endpoints(&network),
+        )
+        .await?;
+        let relay = start_recorder(endpoints.relay.as_deref(), &get_rpc_endpoints(&network))
+            .await
+            .inspect_err(|e| tracing::warn!("Relay chain not recorded: {}", e))
+            .ok();
+        let people = start_recorder(
+            endpoints.people.as_deref(),
+            &get_people_chain_endpoints(&network),
+        )
+        .await
+        .inspect_err(|e| tracing::warn!("People chain not recorded: {}", e))
+        .ok();
+
+        tracing::info!("Recording {} session to {}", network, output.display());
+        Ok(Arc::new_cyclic(|servers| Self {
+            network,
+            asset_hub,
+            relay,
+            people,
+            output: Some(output),
+            flush_task: Some(tokio::spawn(flush_periodically(servers.clone()))),
+        }))
+    }
+
+    async fn replay(network: Network, path: &Path) -> Result<Arc<Self>, ChainError> {
+        let recording = SessionRecording::load(path)?;
+        if recording.network != network {
+            return Err(ChainError::Recording(format!(
+                "{} was recorded on {}, not {}",
+                path.display(),
+                recording.network,
+                network
+            )));
+        }
+
+        tracing::info!("Replaying {} session from {}", network, path.display());
+        let asset_hub = MockRpcServer::start(recording.asset_hub).await?;
+        let relay = match recording.relay {
+            Some(relay) => Some(MockRpcServer::start(relay).await?),
+            None => None,
+        };
+        let people = match recording.people {
+            Some(people) => Some(MockRpcServer::start(people).await?),
+            None => None,
+        };
+        Ok(Arc::new(Self {
+            network,
+            asset_hub,
+            relay,
+            people,
+            output: None,
+            flush_task: None,
+        }))
+    }
+
+    /// Endpoints pointing at the session servers.
+    ///
+    /// Chains without a server fall back to Asset Hub so a replay never
+    /// reaches for the default public endpoints.
+    pub(crate) fn endpoints(&self) -> RpcEndpoints {
+        let asset_hub = self.asset_hub.url();
+        let url_or_asset_hub =
+            |server: &Option<MockRpcServer>| server.as_ref().map_or(asset_hub.clone(), |s| s.url());
+        RpcEndpoints {
+            asset_hub: Some(asset_hub.clone()),
+            relay: Some(url_or_asset_hub(&self.relay)),
+            people: Some(url_or_asset_hub(&self.people)),
+        }
+    }
+
+    pub(crate) fn is_replay(&self) -> bool {
+        self.output.is_none()
+    }
+
+    fn revision(&self) -> u64 {
+        [
+            Some(&self.asset_hub),
+            self.relay.as_ref(),
+            self.people.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .map(MockRpcServer::revision)
+        .sum()
+    }
+
+    fn snapshot(&self) -> SessionRecording {
+        SessionRecording {
+            network: self.network.clone(),
+            asset_hub: self.asset_hub.recording(),
+            relay: self.relay.as_ref().map(MockRpcServer::recording),
+            people: self.people.as_ref().map(MockRpcServer::recording),
+        }
+    }
+
+    /// Write the recording so far; a no-op when replaying.
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn save(&self) -> Result<(), ChainError> {
+        match &self.output {
+            Some(path) => self.snapshot().save(path),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for SessionServers {
+    fn drop(&mut self) {
+        if let Some(task) = self.flush_task.take() {
+            task.abort();
+        }
+        if let Err(e) = self.save() {
+            tracing::warn!("Failed to save session recording: {}", e);
+        }
+    }
+}
+
+/// Proxy the first reachable endpoint: the custom one, else the defaults.
+async fn start_recorder(
+    custom: Option<&str>,
+    defaults: &[&str],
+) -> Result<MockRpcServer, ChainError> {
+    let candidates: Vec<&str> = match custom {
+        Some(endpoint) => vec![endpoint],
+        None => defaults.to_vec(),
+    };
+    let mut errors = Vec::new();
+    for endpoint in candidates {
+        match MockRpcServer::start_recording(endpoint).await {
+            Ok(server) => {
+                tracing::info!("Recording {} via {}", endpoint, server.url());
+                return Ok(server);
+            }
+            Err(e) => errors.push(e.to_string()),
+        }
+    }
+    Err(ChainError::Connection(format!(
+        "No endpoint to record: {}",
+        errors.join("; ")
+    )))
+}
+
+async fn flush_periodically(servers: Weak<SessionServers>) {
+    let mut saved_revision = 0;
+    loop {
+        tokio::time::sleep(FLUSH_INTERVAL).await;
+        let Some(servers) = servers.upgrade() else {
+            break;
+        };
+        let revision = servers.revision();
+        if revision != saved_revision {
+            match servers.save() {
+                Ok(()) => saved_revision = revision,
+                Err(e) => tracing::warn!("Failed to flush session recording: {}", e),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn session() -> SessionRecording {
+        let mut asset_hub = Recording::default();
+        asset_hub.record_storage("0x01", Some("0x02"));
+        asset_hub.record_call("chain_getFinalizedHead", Vec::new(), json!("0xab"));
+        SessionRecording {
+            network: Network::Westend,
+            asset_hub,
+            relay: None,
+            people: Some(Recording::default()),
+        }
+    }
+
+    #[test]
+    fn test_session_file_roundtrip() {
+        let path = std::env::temp_dir().join(format!("stkopt-session-{}.json", std::process::id()));
+        session().save(&path).unwrap();
+        assert_eq!(SessionRecording::load(&path).unwrap(), session());
+        assert_eq!(recorded_network(&path).unwrap(), Network::Westend);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_load_missing_file() {
+        let err = SessionRecording::load("/nonexistent/stkopt-session.json").unwrap_err();
+        assert!(matches!(err, ChainError::Recording(_)));
+    }
+
+    #[tokio::test]
+    async fn test_replay_endpoints_never_leave_localhost() {
+        let path = std::env::temp_dir().join(format!("stkopt-replay-{}.json", std::process::id()));
+        session().save(&path).unwrap();
+
+        let mode = SessionMode::Replay(path.clone());
+        let servers = SessionServers::start(Network::Westend, &mode, &RpcEndpoints::default())
+            .await
+            .unwrap();
+        assert!(servers.is_replay());
+        let endpoints = servers.endpoints();
+        let asset_hub = endpoints.asset_hub.unwrap();
+        assert!(asset_hub.starts_with("ws://127.0.0.1:"));
+        // No relay recording: relay falls back to the Asset Hub server
+        assert_eq!(endpoints.relay.as_deref(), Some(asset_hub.as_str()));
+        assert_ne!(endpoints.people.as_deref(), Some(asset_hub.as_str()));
+        assert_eq!(servers.snapshot(), session());
+
+        let wrong_network =
+            SessionServers::start(Network::Polkadot, &mode, &RpcEndpoints::default()).await;
+        assert!(matches!(wrong_network, Err(ChainError::Recording(_))));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -47,6 +47,41 @@ pub fn encode_ss58(account: &AccountId32, prefix: u16) -> Result<String, ChainEr
     Ok(bs58::encode(payload).into_string())
 }
 
+/// Read the SS58 prefix an address was encoded with.
+///
+/// Only the prefix bytes are decoded; use `AccountId32::from_str` to verify the checksum.
+#[allow(clippy::result_large_err)]
+pub fn decode_ss58_prefix(address: &str) -> Result<u16, ChainError> {
+    let bytes = bs58::decode(address.trim())
+        .into_vec()
+        .map_err(|e| ChainError::InvalidAddress(format!("not base58: {}", e)))?;
+    match bytes.as_slice() {
+        [first, ..] if *first < 64 => Ok(*first as u16),
+        [first, second, ..] if *first < 128 => {
+            let lower = ((first & 0x3F) << 2) | (second >> 6);
+            let upper = second & 0x3F;
+            Ok(lower as u16 | ((upper as u16) << 8))
+        }
+        _ => Err(ChainError::InvalidAddress(
+            "unsupported SS58 prefix encoding".to_string(),
+        )),
+    }
+}
+
+/// Reject an address whose SS58 prefix differs from the network's `expected` prefix.
+#[allow(clippy::result_large_err)]
+pub fn ensure_ss58_prefix(address: &str, expected: u16) -> Result<(), ChainError> {
+    let found = decode_ss58_prefix(address)?;
+    if found == expected {
+        Ok(())
+    } else {
+        Err(ChainError::InvalidAddress(format!(
+            "address uses SS58 prefix {}, but this network uses prefix {}",
+            found, expected
+        )))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -97,4 +132,39 @@ mod tests {
         let result = encode_ss58(&account, 16384);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_decode_ss58_prefix() {
+        assert_eq!(
+            decode_ss58_prefix("15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5").unwrap(),
+            0
+        );
+        assert_eq!(
+            decode_ss58_prefix("HNZata7iMYWmk5RvZRTiAsSDhV8366zq2YGb3tLH5Upf74F").unwrap(),
+            2
+        );
+        assert_eq!(
+            decode_ss58_prefix("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY").unwrap(),
+            42
+        );
+        assert!(decode_ss58_prefix("0OIl").is_err());
+    }
+
+    #[test]
+    fn test_decode_ss58_prefix_two_byte_roundtrip() {
+        let account =
+            AccountId32::from_str("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY").unwrap();
+        for prefix in [64, 255, 1284, 16383] {
+            let encoded = encode_ss58(&account, prefix).unwrap();
+            assert_eq!(decode_ss58_prefix(&encoded).unwrap(), prefix);
+        }
+    }
+
+    #[test]
+    fn test_ensure_ss58_prefix() {
+        let kusama = "HNZata7iMYWmk5RvZRTiAsSDhV8366zq2YGb3tLH5Upf74F";
+        assert!(ensure_ss58_prefix(kusama, 2).is_ok());
+        let err = ensure_ss58_prefix(kusama, 0).unwrap_err().to_string();
+        assert!(err.contains("prefix 2"), "{err}");
+    }
 }
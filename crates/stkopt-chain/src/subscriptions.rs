@@ -0,0 +1,273 @@
+//! Live chain subscriptions for long-running sessions.
+//!
+//! [`ChainSubscription`] watches finalized blocks and, on each new one, reads
+//! `Staking.ActiveEra` and the watched account's `Staking.Ledger` and
+//! `System.Account`. Only changes are reported, as [`SubscriptionUpdate`]s:
+//! front ends refresh validators and APYs when the era turns and account data
+//! when a balance or bond moves.
+//!
+//! The finalized head is polled every [`BLOCK_POLL_INTERVAL`] rather than
+//! followed through an RPC subscription, so the same code runs over RPC, the
+//! light client and replayed sessions, and a dropped connection costs a missed
+//! poll instead of a dead stream.
+
+use crate::client::ChainClient;
+use crate::error::ChainError;
+use crate::queries::account::{AccountBalance, StakingLedger};
+use std::time::Duration;
+use stkopt_core::{EraIndex, EraInfo};
+use subxt::utils::AccountId32;
+use tokio::sync::{mpsc, watch};
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+
+/// How often the finalized head is checked for a new block.
+pub const BLOCK_POLL_INTERVAL: Duration = Duration::from_secs(6);
+
+/// A change seen by a [`ChainSubscription`].
+#[derive(Debug, Clone)]
+pub enum SubscriptionUpdate {
+    /// `Staking.ActiveEra` moved on from `previous`.
+    EraChanged { previous: EraIndex, era: EraInfo },
+    /// The watched account's free balance or staking ledger changed.
+    AccountChanged {
+        account: AccountId32,
+        balance: AccountBalance,
+        ledger: Option<StakingLedger>,
+    },
+}
+
+/// Background task watching the chain for era and account changes.
+///
+/// The task stops when this handle is dropped or the update receiver closes.
+pub struct ChainSubscription {
+    account_tx: watch::Sender<Option<AccountId32>>,
+    task: JoinHandle<()>,
+}
+
+impl ChainSubscription {
+    /// Start watching `client`, sending changes to `updates`.
+    pub fn spawn(
+        client: ChainClient,
+        account: Option<AccountId32>,
+        updates: mpsc::Sender<SubscriptionUpdate>,
+    ) -> Self {
+        Self::spawn_with_interval(client, account, updates, BLOCK_POLL_INTERVAL)
+    }
+
+    /// Start watching, checking for a new block every `interval`.
+    pub fn spawn_with_interval(
+        client: ChainClient,
+        account: Option<AccountId32>,
+        updates: mpsc::Sender<SubscriptionUpdate>,
+        interval: Duration,
+    ) -> Self {
+        let (account_tx, account_rx) = watch::channel(account);
+        let task = tokio::spawn(run(client, account_rx, updates, interval));
+        Self { account_tx, task }
+    }
+
+    /// Watch `account` instead of the current one; `None` stops account
+    /// watching. Its state is read on the next poll and changes reported
+    /// from there.
+    pub fn watch_account(&self, account: Option<AccountId32>) {
+        self.account_tx.send_if_modified(|current| {
+            if *current == account {
+                return false;
+            }
+            *current = account;
+            true
+        });
+    }
+
+    /// The account being watched.
+    pub fn account(&self) -> Option<AccountId32> {
+        *self.account_tx.borrow()
+    }
+}
+
+impl Drop for ChainSubscription {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Watched account state, compared between blocks.
+#[derive(Debug, Clone, PartialEq)]
+struct AccountSnapshot {
+    account: AccountId32,
+    balance: AccountBalance,
+    ledger: Option<StakingLedger>,
+}
+
+/// Last seen chain state; the first observation of each part is a baseline.
+#[derive(Debug, Default)]
+struct WatchState {
+    block: Option<u32>,
+    era: Option<EraIndex>,
+    account: Option<AccountSnapshot>,
+}
+
+impl WatchState {
+    fn observe_era(&mut self, era: EraInfo) -> Option<SubscriptionUpdate> {
+        let previous = self.era.replace(era.index)?;
+        (previous != era.index).then_some(SubscriptionUpdate::EraChanged { previous, era })
+    }
+
+    fn observe_account(&mut self, snapshot: AccountSnapshot) -> Option<SubscriptionUpdate> {
+        let previous = self.account.replace(snapshot.clone())?;
+        (previous.account == snapshot.account && previous != snapshot).then_some(
+            SubscriptionUpdate::AccountChanged {
+                account: snapshot.account,
+                balance: snapshot.balance,
+                ledger: snapshot.ledger,
+            },
+        )
+    }
+}
+
+async fn run(
+    client: ChainClient,
+    mut account_rx: watch::Receiver<Option<AccountId32>>,
+    updates: mpsc::Sender<SubscriptionUpdate>,
+    interval: Duration,
+) {
+    let mut state = WatchState::default();
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    loop {
+        // A newly watched account is read without waiting for a new block
+        let account_changed = tokio::select! {
+            _ = ticker.tick() => false,
+            changed = account_rx.changed() => {
+                if changed.is_err() {
+                    return;
+                }
+                true
+            }
+        };
+        let account = *account_rx.borrow_and_update();
+        match poll(&client, &mut state, account.as_ref(), account_changed).await {
+            Ok(changes) => {
+                for update in changes {
+                    if updates.send(update).await.is_err() {
+                        return;
+                    }
+                }
+            }
+            Err(e) => tracing::debug!("Chain subscription poll failed: {}", e),
+        }
+    }
+}
+
+/// Read the chain if the finalized head moved, returning what changed.
+async fn poll(
+    client: &ChainClient,
+    state: &mut WatchState,
+    account: Option<&AccountId32>,
+    force: bool,
+) -> Result<Vec<SubscriptionUpdate>, ChainError> {
+    let (number, _) = client.get_latest_block().await?;
+    if state.block == Some(number) && !force {
+        return Ok(Vec::new());
+    }
+    state.block = Some(number);
+
+    let mut changes = Vec::new();
+    if let Some(era) = client.get_active_era().await? {
+        changes.extend(state.observe_era(era));
+    }
+    match account {
+        Some(account) => {
+            let snapshot = AccountSnapshot {
+                account: *account,
+                balance: client.get_account_balance(account).await?,
+                ledger: client.get_staking_ledger(account).await?,
+            };
+            changes.extend(state.observe_account(snapshot));
+        }
+        None => state.account = None,
+    }
+    Ok(changes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn era(index: EraIndex) -> EraInfo {
+        EraInfo {
+            index,
+            start_timestamp_ms: 0,
+            duration_ms: 86_400_000,
+            pct_complete: 0.0,
+            estimated_end_ms: 86_400_000,
+        }
+    }
+
+    fn snapshot(byte: u8, free: u128) -> AccountSnapshot {
+        AccountSnapshot {
+            account: AccountId32([byte; 32]),
+            balance: AccountBalance {
+                free,
+                reserved: 0,
+                frozen: 0,
+            },
+            ledger: None,
+        }
+    }
+
+    #[test]
+    fn test_era_change_after_baseline() {
+        let mut state = WatchState::default();
+        assert!(state.observe_era(era(1000)).is_none());
+        assert!(state.observe_era(era(1000)).is_none());
+        match state.observe_era(era(1001)) {
+            Some(SubscriptionUpdate::EraChanged { previous, era }) => {
+                assert_eq!(previous, 1000);
+                assert_eq!(era.index, 1001);
+            }
+            other => panic!("expected era change, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_account_change_after_baseline() {
+        let mut state = WatchState::default();
+        assert!(state.observe_account(snapshot(1, 100)).is_none());
+        assert!(state.observe_account(snapshot(1, 100)).is_none());
+        match state.observe_account(snapshot(1, 150)) {
+            Some(SubscriptionUpdate::AccountChanged {
+                account, balance, ..
+            }) => {
+                assert_eq!(account, AccountId32([1; 32]));
+                assert_eq!(balance.free, 150);
+            }
+            other => panic!("expected account change, got {:?}", other),
+        }
+
+        // Switching accounts takes a new baseline
+        assert!(state.observe_account(snapshot(2, 500)).is_none());
+        assert!(state.observe_account(snapshot(2, 500)).is_none());
+    }
+
+    #[test]
+    fn test_ledger_change_is_reported() {
+        let mut state = WatchState::default();
+        state.observe_account(snapshot(1, 100));
+        let mut bonded = snapshot(1, 100);
+        bonded.ledger = Some(StakingLedger {
+            stash: AccountId32([1; 32]),
+            total: 50,
+            active: 50,
+            unlocking: Vec::new(),
+        });
+        assert!(matches!(
+            state.observe_account(bonded),
+            Some(SubscriptionUpdate::AccountChanged {
+                ledger: Some(_),
+                ..
+            })
+        ));
+    }
+}
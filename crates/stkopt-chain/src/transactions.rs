@@ -8,6 +8,32 @@ pub use subxt::utils::AccountId32;
 
 // Re-export the core RewardDestination for convenience
 pub use stkopt_core::RewardDestination;
+use stkopt_core::{
+    ClaimPermission, CommissionChangeRate, MortalityWindow, PoolMemberCall, PoolState,
+    format_perbill,
+};
+
+/// Change to one pool role in `update_roles`, mirroring the pallet's
+/// `ConfigOp<AccountId>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PoolRoleUpdate {
+    /// Leave the role unchanged.
+    Keep,
+    Set(AccountId32),
+    Remove,
+}
+
+impl PoolRoleUpdate {
+    fn to_value(&self) -> Value<()> {
+        match self {
+            PoolRoleUpdate::Keep => Value::unnamed_variant("Noop", std::iter::empty()),
+            PoolRoleUpdate::Set(account) => {
+                Value::unnamed_variant("Set", [Value::from_bytes(account)])
+            }
+            PoolRoleUpdate::Remove => Value::unnamed_variant("Remove", std::iter::empty()),
+        }
+    }
+}
 
 /// Unsigned extrinsic payload for QR code signing.
 #[derive(Debug, Clone)]
@@ -34,6 +60,8 @@ pub struct UnsignedPayload {
     pub nonce: u64,
     /// Era for mortality (mortal or immortal).
     pub era: Era,
+    /// Block the mortal era starts at (`None` for immortal payloads).
+    pub birth_block: Option<u64>,
     /// Whether to include CheckMetadataHash extension.
     pub include_metadata_hash: bool,
     /// Whether to use ChargeAssetTxPayment (Asset Hub) instead of ChargeTransactionPayment.
@@ -42,6 +70,18 @@ pub struct UnsignedPayload {
     pub extension_ids: Vec<String>,
 }
 
+impl UnsignedPayload {
+    /// Validity window of a mortal payload.
+    pub fn mortality_window(&self) -> Option<MortalityWindow> {
+        match (self.era, self.birth_block) {
+            (Era::Mortal { period, .. }, Some(birth_block)) => {
+                Some(MortalityWindow::new(birth_block, period))
+            }
+            _ => None,
+        }
+    }
+}
+
 /// Transaction era (mortality).
 #[derive(Debug, Clone, Copy)]
 pub enum Era {
@@ -60,9 +100,38 @@ impl ChainClient {
         description: String,
         use_mortal_era: bool,
     ) -> Result<UnsignedPayload, ChainError> {
-        let client = self.client();
-        let call_data = client.tx().await?.call_data(&call)?;
+        let call_data = self.client().tx().await?.call_data(&call)?;
+        self.payload_for_call_data(signer, call_data, description, use_mortal_era)
+            .await
+    }
 
+    /// Rebuild a payload with a fresh nonce, block hash and era.
+    ///
+    /// Used when a payload expired (or its nonce was consumed) before the
+    /// Vault signature was submitted. The call itself is unchanged.
+    pub async fn refresh_payload(
+        &self,
+        signer: &AccountId32,
+        payload: &UnsignedPayload,
+    ) -> Result<UnsignedPayload, ChainError> {
+        self.payload_for_call_data(
+            signer,
+            payload.call_data.clone(),
+            payload.description.clone(),
+            matches!(payload.era, Era::Mortal { .. }),
+        )
+        .await
+    }
+
+    /// Build a payload around already-encoded call data.
+    async fn payload_for_call_data(
+        &self,
+        signer: &AccountId32,
+        call_data: Vec<u8>,
+        description: String,
+        use_mortal_era: bool,
+    ) -> Result<UnsignedPayload, ChainError> {
+        let client = self.client();
         let block = client.at_current_block().await?;
         let metadata = block.metadata();
         let extensions: Vec<_> = metadata
@@ -82,15 +151,19 @@ impl ChainClient {
         let tx_version = block.transaction_version();
         let nonce = self.get_account_nonce(signer).await?;
 
-        let (era, block_hash) = if use_mortal_era {
+        let (era, block_hash, birth_block) = if use_mortal_era {
             let (block_number, block_hash) = self.get_latest_block().await?;
-            // Period of 2048 blocks gives ~3.4 hours on 6-second block chains
-            // This gives users plenty of time to sign with Vault and submit
-            let period = 2048;
+            // The period is configurable per network (default 2048 blocks,
+            // ~3.4 hours) so users have time to sign with Vault and submit.
+            let period = self.mortality_period();
             let phase = block_number as u64 % period;
-            (Era::Mortal { period, phase }, block_hash)
+            (
+                Era::Mortal { period, phase },
+                block_hash,
+                Some(block_number as u64),
+            )
         } else {
-            (Era::Immortal, genesis_hash)
+            (Era::Immortal, genesis_hash, None)
         };
 
         let extension_ids: Vec<String> = extensions
@@ -108,6 +181,7 @@ impl ChainClient {
             tx_version,
             nonce,
             era,
+            birth_block,
             include_metadata_hash,
             use_asset_payment,
             extension_ids,
@@ -123,7 +197,7 @@ impl ChainClient {
     ) -> Result<UnsignedPayload, ChainError> {
         let target_values: Vec<Value<()>> = targets
             .iter()
-            .map(|t| Value::named_variant("Id", [("0", Value::from_bytes(t.clone()))]))
+            .map(|t| Value::named_variant("Id", [("0", Value::from_bytes(t))]))
             .collect();
 
         let call = subxt::dynamic::tx(
@@ -306,6 +380,56 @@ impl ChainClient {
         .await
     }
 
+    /// Generate an unsigned `VoterList.rebag` extrinsic, moving `dislocated`
+    /// into the bag matching its current stake.
+    pub async fn create_rebag_payload(
+        &self,
+        signer: &AccountId32,
+        dislocated: &AccountId32,
+        use_mortal_era: bool,
+    ) -> Result<UnsignedPayload, ChainError> {
+        let call = subxt::dynamic::tx(
+            "VoterList",
+            "rebag",
+            vec![Value::named_variant(
+                "Id",
+                [("0", Value::from_bytes(dislocated))],
+            )],
+        );
+        self.create_payload_internal(
+            signer,
+            call,
+            "Rebag in voter list".to_string(),
+            use_mortal_era,
+        )
+        .await
+    }
+
+    /// Generate an unsigned `VoterList.put_in_front_of` extrinsic, moving the
+    /// signer ahead of `lighter` within their bag.
+    pub async fn create_put_in_front_of_payload(
+        &self,
+        signer: &AccountId32,
+        lighter: &AccountId32,
+        use_mortal_era: bool,
+    ) -> Result<UnsignedPayload, ChainError> {
+        let call = subxt::dynamic::tx(
+            "VoterList",
+            "put_in_front_of",
+            vec![Value::named_variant(
+                "Id",
+                [("0", Value::from_bytes(lighter))],
+            )],
+        );
+        self.create_payload_internal(
+            signer,
+            call,
+            "Move ahead in voter list bag".to_string(),
+            use_mortal_era,
+        )
+        .await
+    }
+
     /// Generate an unsigned pool join extrinsic.
     pub async fn create_pool_join_payload(
         &self,
@@ -367,7 +491,7 @@ impl ChainClient {
             "NominationPools",
             "unbond",
             vec![
-                Value::named_variant("Id", [("0", Value::from_bytes(member_account.clone()))]),
+                Value::named_variant("Id", [("0", Value::from_bytes(member_account))]),
                 Value::primitive(Primitive::U128(amount)),
             ],
         );
@@ -409,7 +533,7 @@ impl ChainClient {
             "NominationPools",
             "withdraw_unbonded",
             vec![
-                Value::named_variant("Id", [("0", Value::from_bytes(member_account.clone()))]),
+                Value::named_variant("Id", [("0", Value::from_bytes(member_account))]),
                 Value::u128(num_slashing_spans as u128),
             ],
         );
@@ -422,6 +546,394 @@ impl ChainClient {
         .await
     }
 
+    /// Generate an unsigned pool set_claim_permission extrinsic.
+    pub async fn create_pool_set_claim_permission_payload(
+        &self,
+        signer: &AccountId32,
+        permission: ClaimPermission,
+        use_mortal_era: bool,
+    ) -> Result<UnsignedPayload, ChainError> {
+        let call = subxt::dynamic::tx(
+            "NominationPools",
+            "set_claim_permission",
+            vec![Value::unnamed_variant(
+                permission.variant_name(),
+                std::iter::empty::<Value<()>>(),
+            )],
+        );
+        self.create_payload_internal(
+            signer,
+            call,
+            format!("Set pool claim permission: {}", permission.label()),
+            use_mortal_era,
+        )
+        .await
+    }
+
+    /// Generate an unsigned pool bond_extra_other extrinsic compounding
+    /// `member`'s pending rewards.
+    ///
+    /// Others may only bond rewards, and only when the member's claim
+    /// permission allows compounding.
+    pub async fn create_pool_bond_extra_other_payload(
+        &self,
+        signer: &AccountId32,
+        member: &AccountId32,
+        use_mortal_era: bool,
+    ) -> Result<UnsignedPayload, ChainError> {
+        // BondExtra::Rewards
+        let extra = Value::unnamed_variant("Rewards", std::iter::empty::<Value<()>>());
+        let call = subxt::dynamic::tx(
+            "NominationPools",
+            "bond_extra_other",
+            vec![
+                Value::named_variant("Id", [("0", Value::from_bytes(member))]),
+                extra,
+            ],
+        );
+        self.create_payload_internal(
+            signer,
+            call,
+            "Compound pool rewards for another member".to_string(),
+            use_mortal_era,
+        )
+        .await
+    }
+
+    /// Generate an unsigned pool claim_payout_other extrinsic paying `other`'s
+    /// pending rewards out to them.
+    pub async fn create_pool_claim_payout_other_payload(
+        &self,
+        signer: &AccountId32,
+        other: &AccountId32,
+        use_mortal_era: bool,
+    ) -> Result<UnsignedPayload, ChainError> {
+        let call = subxt::dynamic::tx(
+            "NominationPools",
+            "claim_payout_other",
+            vec![Value::from_bytes(other)],
+        );
+        self.create_payload_internal(
+            signer,
+            call,
+            "Claim pool rewards for another member".to_string(),
+            use_mortal_era,
+        )
+        .await
+    }
+
+    /// Generate an unsigned `bond_extra_other` or `claim_payout_other`
+    /// extrinsic for `member`, once their membership and claim permission
+    /// show the pallet will accept it from the signer.
+    pub async fn create_pool_member_call_payload(
+        &self,
+        signer: &AccountId32,
+        member: &AccountId32,
+        call: PoolMemberCall,
+        use_mortal_era: bool,
+    ) -> Result<UnsignedPayload, ChainError> {
+        if self.get_pool_membership(member).await?.is_none() {
+            return Err(ChainError::InvalidData(format!(
+                "{} is not a pool member",
+                member
+            )));
+        }
+        // Members may always claim for themselves
+        if signer != member {
+            let permission = self.get_pool_claim_permission(member).await?;
+            if !call.allowed_by(permission) {
+                return Err(ChainError::InvalidData(format!(
+                    "{}'s claim permission ({}) does not allow others to {}",
+                    member,
+                    permission.label(),
+                    call.label().to_lowercase()
+                )));
+            }
+        }
+        match call {
+            PoolMemberCall::Compound => {
+                self.create_pool_bond_extra_other_payload(signer, member, use_mortal_era)
+                    .await
+            }
+            PoolMemberCall::PayOut => {
+                self.create_pool_claim_payout_other_payload(signer, member, use_mortal_era)
+                    .await
+            }
+        }
+    }
+
+    /// Generate an unsigned pool create extrinsic bonding `amount` from the
+    /// signer, who becomes the depositor.
+    pub async fn create_pool_create_payload(
+        &self,
+        signer: &AccountId32,
+        amount: u128,
+        root: &AccountId32,
+        nominator: &AccountId32,
+        bouncer: &AccountId32,
+        use_mortal_era: bool,
+    ) -> Result<UnsignedPayload, ChainError> {
+        let address =
+            |account: &AccountId32| Value::named_variant("Id", [("0", Value::from_bytes(account))]);
+        let call = subxt::dynamic::tx(
+            "NominationPools",
+            "create",
+            vec![
+                Value::primitive(Primitive::U128(amount)),
+                address(root),
+                address(nominator),
+                address(bouncer),
+            ],
+        );
+        self.create_payload_internal(
+            signer,
+            call,
+            format!("Create pool with {} tokens", amount),
+            use_mortal_era,
+        )
+        .await
+    }
+
+    /// Generate an unsigned pool nominate extrinsic, signed by the pool's
+    /// root or nominator.
+    pub async fn create_pool_nominate_payload(
+        &self,
+        signer: &AccountId32,
+        pool_id: u32,
+        targets: &[AccountId32],
+        use_mortal_era: bool,
+    ) -> Result<UnsignedPayload, ChainError> {
+        // Unlike `Staking.nominate`, pool targets are plain account ids
+        let target_values: Vec<Value<()>> = targets.iter().map(Value::from_bytes).collect();
+        let call = subxt::dynamic::tx(
+            "NominationPools",
+            "nominate",
+            vec![
+                Value::u128(pool_id as u128),
+                Value::unnamed_composite(target_values),
+            ],
+        );
+        self.create_payload_internal(
+            signer,
+            call,
+            format!("Pool #{} nominates {} validators", pool_id, targets.len()),
+            use_mortal_era,
+        )
+        .await
+    }
+
+    /// Generate an unsigned pool set_metadata extrinsic naming the pool.
+    pub async fn create_pool_set_metadata_payload(
+        &self,
+        signer: &AccountId32,
+        pool_id: u32,
+        metadata: &[u8],
+        use_mortal_era: bool,
+    ) -> Result<UnsignedPayload, ChainError> {
+        let call = subxt::dynamic::tx(
+            "NominationPools",
+            "set_metadata",
+            vec![Value::u128(pool_id as u128), Value::from_bytes(metadata)],
+        );
+        self.create_payload_internal(
+            signer,
+            call,
+            format!(
+                "Rename pool #{} to \"{}\"",
+                pool_id,
+                String::from_utf8_lossy(metadata)
+            ),
+            use_mortal_era,
+        )
+        .await
+    }
+
+    /// Generate an unsigned pool set_state extrinsic.
+    pub async fn create_pool_set_state_payload(
+        &self,
+        signer: &AccountId32,
+        pool_id: u32,
+        state: PoolState,
+        use_mortal_era: bool,
+    ) -> Result<UnsignedPayload, ChainError> {
+        let name = match state {
+            PoolState::Open => "Open",
+            PoolState::Blocked => "Blocked",
+            PoolState::Destroying => "Destroying",
+        };
+        let call = subxt::dynamic::tx(
+            "NominationPools",
+            "set_state",
+            vec![
+                Value::u128(pool_id as u128),
+                Value::unnamed_variant(name, std::iter::empty::<Value<()>>()),
+            ],
+        );
+        self.create_payload_internal(
+            signer,
+            call,
+            format!("Set pool #{} state to {}", pool_id, name),
+            use_mortal_era,
+        )
+        .await
+    }
+
+    /// Generate an unsigned pool set_commission extrinsic. `commission` is a
+    /// rate in parts per billion and the account it is paid to; `None`
+    /// removes the commission.
+    pub async fn create_pool_set_commission_payload(
+        &self,
+        signer: &AccountId32,
+        pool_id: u32,
+        commission: Option<(u32, &AccountId32)>,
+        use_mortal_era: bool,
+    ) -> Result<UnsignedPayload, ChainError> {
+        let (new_commission, description) = match commission {
+            Some((perbill, payee)) => (
+                Value::unnamed_variant(
+                    "Some",
+                    [Value::unnamed_composite([
+                        Value::u128(perbill as u128),
+                        Value::from_bytes(payee),
+                    ])],
+                ),
+                format!(
+                    "Set pool #{} commission to {}",
+                    pool_id,
+                    format_perbill(perbill)
+                ),
+            ),
+            None => (
+                Value::unnamed_variant("None", std::iter::empty::<Value<()>>()),
+                format!("Remove pool #{} commission", pool_id),
+            ),
+        };
+        let call = subxt::dynamic::tx(
+            "NominationPools",
+            "set_commission",
+            vec![Value::u128(pool_id as u128), new_commission],
+        );
+        self.create_payload_internal(signer, call, description, use_mortal_era)
+            .await
+    }
+
+    /// Generate an unsigned pool set_commission_max extrinsic. The maximum
+    /// can only be lowered once set.
+    pub async fn create_pool_set_commission_max_payload(
+        &self,
+        signer: &AccountId32,
+        pool_id: u32,
+        max_perbill: u32,
+        use_mortal_era: bool,
+    ) -> Result<UnsignedPayload, ChainError> {
+        let call = subxt::dynamic::tx(
+            "NominationPools",
+            "set_commission_max",
+            vec![
+                Value::u128(pool_id as u128),
+                Value::u128(max_perbill as u128),
+            ],
+        );
+        self.create_payload_internal(
+            signer,
+            call,
+            format!(
+                "Set pool #{} max commission to {}",
+                pool_id,
+                format_perbill(max_perbill)
+            ),
+            use_mortal_era,
+        )
+        .await
+    }
+
+    /// Generate an unsigned pool set_commission_change_rate extrinsic.
+    pub async fn create_pool_set_commission_change_rate_payload(
+        &self,
+        signer: &AccountId32,
+        pool_id: u32,
+        change_rate: CommissionChangeRate,
+        use_mortal_era: bool,
+    ) -> Result<UnsignedPayload, ChainError> {
+        let call = subxt::dynamic::tx(
+            "NominationPools",
+            "set_commission_change_rate",
+            vec![
+                Value::u128(pool_id as u128),
+                Value::named_composite([
+                    (
+                        "max_increase",
+                        Value::u128(change_rate.max_increase as u128),
+                    ),
+                    ("min_delay", Value::u128(change_rate.min_delay as u128)),
+                ]),
+            ],
+        );
+        self.create_payload_internal(
+            signer,
+            call,
+            format!(
+                "Limit pool #{} commission increases to {} every {} blocks",
+                pool_id,
+                format_perbill(change_rate.max_increase),
+                change_rate.min_delay
+            ),
+            use_mortal_era,
+        )
+        .await
+    }
+
+    /// Generate an unsigned pool claim_commission extrinsic paying pending
+    /// commission to the pool's payee.
+    pub async fn create_pool_claim_commission_payload(
+        &self,
+        signer: &AccountId32,
+        pool_id: u32,
+        use_mortal_era: bool,
+    ) -> Result<UnsignedPayload, ChainError> {
+        let call = subxt::dynamic::tx(
+            "NominationPools",
+            "claim_commission",
+            vec![Value::u128(pool_id as u128)],
+        );
+        self.create_payload_internal(
+            signer,
+            call,
+            format!("Claim pool #{} commission", pool_id),
+            use_mortal_era,
+        )
+        .await
+    }
+
+    /// Generate an unsigned pool update_roles extrinsic.
+    pub async fn create_pool_update_roles_payload(
+        &self,
+        signer: &AccountId32,
+        pool_id: u32,
+        root: &PoolRoleUpdate,
+        nominator: &PoolRoleUpdate,
+        bouncer: &PoolRoleUpdate,
+        use_mortal_era: bool,
+    ) -> Result<UnsignedPayload, ChainError> {
+        let call = subxt::dynamic::tx(
+            "NominationPools",
+            "update_roles",
+            vec![
+                Value::u128(pool_id as u128),
+                root.to_value(),
+                nominator.to_value(),
+                bouncer.to_value(),
+            ],
+        );
+        self.create_payload_internal(
+            signer,
+            call,
+            format!("Update pool #{} roles", pool_id),
+            use_mortal_era,
+        )
+        .await
+    }
+
     /// Get account nonce from the staking target chain (Asset Hub).
     ///
     /// Staking transactions are submitted to Asset Hub where the Staking pallet
@@ -439,7 +951,7 @@ impl ChainClient {
         let block = client.at_current_block().await?;
         let result = block
             .storage()
-            .try_fetch(&storage_query, vec![Value::from_bytes(account.clone())])
+            .try_fetch(&storage_query, vec![Value::from_bytes(account)])
             .await?;
 
         if let Some(value) = result {
@@ -641,12 +1153,13 @@ fn build_signing_payload(payload: &UnsignedPayload) -> Result<Vec<u8>, ChainErro
 fn encode_mortal_era(period: u64, phase: u64) -> Vec<u8> {
     let period = period.next_power_of_two().clamp(4, 65536);
     let quantize_factor = (period >> 12).max(1);
-    let quantized_phase = (phase / quantize_factor) * quantize_factor;
+    let phase = phase % period;
 
+    // Same layout as `sp_runtime::generic::Era`: the low 4 bits hold
+    // log2(period) - 1, the high 12 bits the phase divided by the quantize factor.
     let encoded = {
-        let period_log = period.trailing_zeros() - 1;
-        let low = period_log.min(15) as u16;
-        let high = ((quantized_phase / (period >> 4)) as u16).min(15) << 4;
+        let low = (period.trailing_zeros() - 1).clamp(1, 15) as u16;
+        let high = ((phase / quantize_factor) as u16) << 4;
         low | high
     };
 
@@ -908,6 +1421,7 @@ mod tests {
                 period: 128,
                 phase: 64,
             },
+            birth_block: Some(1_000_000),
             include_metadata_hash: false,
             use_asset_payment: false,
             extension_ids: vec![
@@ -971,6 +1485,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_encode_mortal_era_matches_sp_runtime() {
+        // Vectors from sp_runtime's `generic::Era` codec tests.
+        assert_eq!(encode_mortal_era(64, 42), vec![0xa5, 0x02]);
+        // Long periods quantize the phase (32768 >> 12 = 8).
+        assert_eq!(encode_mortal_era(32768, 20000), vec![0x4e, 0x9c]);
+    }
+
+    #[test]
+    fn test_payload_mortality_window() {
+        let mut payload = make_test_payload();
+        let window = payload.mortality_window().expect("mortal payload");
+        assert_eq!(window.birth_block, 1_000_000);
+        assert_eq!(window.expiry_block(), 1_000_128);
+
+        payload.era = Era::Immortal;
+        payload.birth_block = None;
+        assert!(payload.mortality_window().is_none());
+    }
+
     #[test]
     fn test_compact_encode_small() {
         // Compact encoding for small values (0-63)
@@ -1456,4 +1990,18 @@ mod tests {
         let signer_bytes: &[u8; 32] = signer.as_ref();
         assert_eq!(extracted_signer, *signer_bytes);
     }
+
+    #[test]
+    fn test_pool_role_update_to_value() {
+        let variant_name = |update: PoolRoleUpdate| match update.to_value().value {
+            subxt::ext::scale_value::ValueDef::Variant(v) => v.name,
+            _ => String::new(),
+        };
+        assert_eq!(variant_name(PoolRoleUpdate::Keep), "Noop");
+        assert_eq!(variant_name(PoolRoleUpdate::Remove), "Remove");
+        assert_eq!(
+            variant_name(PoolRoleUpdate::Set(AccountId32::from([1u8; 32]))),
+            "Set"
+        );
+    }
 }
@@ -34,6 +34,7 @@ async fn compare_validators_rpc_vs_lightclient() {
     let rpc_config = ConnectionConfig {
         mode: ConnectionMode::Rpc,
         rpc_endpoints: RpcEndpoints::default(),
+        ..ConnectionConfig::default()
     };
 
     let rpc_client = ChainClient::connect(TEST_NETWORK, &rpc_config, create_status_channel())
@@ -77,6 +78,7 @@ async fn compare_validators_rpc_vs_lightclient() {
     let lc_config = ConnectionConfig {
         mode: ConnectionMode::LightClient,
         rpc_endpoints: RpcEndpoints::default(),
+        ..ConnectionConfig::default()
     };
 
     let lc_client =
@@ -302,6 +304,7 @@ async fn test_light_client_iteration_completeness() {
     let config = ConnectionConfig {
         mode: ConnectionMode::LightClient,
         rpc_endpoints: RpcEndpoints::default(),
+        ..ConnectionConfig::default()
     };
 
     println!("Connecting via Light Client...");
@@ -0,0 +1,213 @@
+//! End-to-end flow against the in-process mock JSON-RPC server.
+//!
+//! Replays `tests/fixtures/westend_asset_hub.json` without touching the
+//! network. Refresh the fixture with the `record_mock_fixture` example.
+//! Besides the account and transaction flow, it covers the checks of the
+//! live `compare_connection_modes` test and the `qa_networks` smoke run.
+//!
+//! The replay tests are ignored until the fixture is recorded (it needs a
+//! live endpoint); run them with:
+//!   cargo test -p stkopt-chain --test mock_rpc_flow -- --include-ignored
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::str::FromStr;
+use stkopt_chain::{ChainClient, MockRpcServer, Recording, UnsignedPayload};
+use stkopt_core::{ConnectionStatus, Network};
+use subxt::utils::AccountId32;
+use tokio::sync::mpsc;
+
+fn fixture_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/westend_asset_hub.json")
+}
+
+/// Validators sampled for preferences, as in `qa_networks`.
+const VALIDATOR_SAMPLE_SIZE: usize = 16;
+
+/// The fixture and the account it was recorded for.
+fn load_fixture() -> (Recording, AccountId32) {
+    let path = fixture_path();
+    assert!(
+        path.exists(),
+        "{} is not recorded (see examples/record_mock_fixture.rs)",
+        path.display()
+    );
+    let recording = Recording::load(&path).expect("fixture parses");
+    let account = recording
+        .meta
+        .get("account")
+        .map(|address| AccountId32::from_str(address).expect("fixture account is SS58"))
+        .expect("fixture records the account used");
+    (recording, account)
+}
+
+async fn replay(recording: &Recording) -> (MockRpcServer, ChainClient) {
+    let server = MockRpcServer::start(recording.clone())
+        .await
+        .expect("mock server starts");
+    let client = ChainClient::connect_rpc(
+        Network::Westend,
+        &server.rpc_endpoints(),
+        create_status_channel(),
+    )
+    .await
+    .expect("connects to mock server");
+    (server, client)
+}
+
+fn create_status_channel() -> mpsc::Sender<ConnectionStatus> {
+    let (tx, mut rx) = mpsc::channel(10);
+    tokio::spawn(async move { while rx.recv().await.is_some() {} });
+    tx
+}
+
+/// Summary of one replayed flow, compared across runs for determinism.
+#[derive(Debug, PartialEq)]
+struct FlowSummary {
+    validators: usize,
+    pools: usize,
+    active_era: Option<u32>,
+    latest_block: u32,
+    era_stakers: usize,
+    era_total_stake: u128,
+    sampled_preferences: usize,
+    free_balance: u128,
+    payload_call_data: Vec<u8>,
+    payload_nonce: u64,
+    payload_block_hash: [u8; 32],
+    payload_genesis_hash: [u8; 32],
+}
+
+async fn run_flow(recording: &Recording, account: &AccountId32) -> FlowSummary {
+    let (_server, client) = replay(recording).await;
+
+    let validators = client.get_validators().await.expect("validators");
+    let pools = client.get_nomination_pools().await.expect("pools");
+    let active_era = client.get_active_era().await.expect("active era");
+    let (latest_block, _) = client.get_latest_block().await.expect("latest block");
+    let era = active_era.as_ref().map_or(0, |era| era.index);
+    let era_stakers = client
+        .get_era_stakers_overview(era.saturating_sub(1))
+        .await
+        .expect("era stakers");
+    let era_total_stake = client
+        .get_era_total_stake_direct(era)
+        .await
+        .expect("era total stake");
+    let sample: Vec<AccountId32> = validators
+        .iter()
+        .take(VALIDATOR_SAMPLE_SIZE)
+        .map(|v| v.address)
+        .collect();
+    let preferences = client
+        .get_validator_preferences_batch(&sample)
+        .await
+        .expect("validator preferences");
+    let balance = client.get_account_balance(account).await.expect("balance");
+    client.get_staking_ledger(account).await.expect("ledger");
+    client.get_nominations(account).await.expect("nominations");
+    client
+        .get_pool_membership(account)
+        .await
+        .expect("pool membership");
+
+    let targets: Vec<AccountId32> = validators.iter().take(2).map(|v| v.address).collect();
+    let payload: UnsignedPayload = client
+        .create_nominate_payload(account, &targets, true)
+        .await
+        .expect("nominate payload");
+
+    FlowSummary {
+        validators: validators.len(),
+        pools: pools.len(),
+        active_era: active_era.map(|era| era.index),
+        latest_block,
+        era_stakers: era_stakers.len(),
+        era_total_stake,
+        sampled_preferences: preferences.len(),
+        free_balance: balance.free,
+        payload_call_data: payload.call_data,
+        payload_nonce: payload.nonce,
+        payload_block_hash: payload.block_hash,
+        payload_genesis_hash: payload.genesis_hash,
+    }
+}
+
+#[tokio::test]
+#[ignore = "needs tests/fixtures/westend_asset_hub.json; record it with the record_mock_fixture example"]
+async fn replays_recorded_session_deterministically() {
+    let (recording, account) = load_fixture();
+
+    let first = run_flow(&recording, &account).await;
+    assert!(first.validators > 0, "fixture has validators");
+    assert!(first.active_era.is_some(), "fixture has an active era");
+    assert!(!first.payload_call_data.is_empty());
+    // The qa_networks checks
+    assert!(first.latest_block > 0);
+    assert!(first.era_total_stake > 0, "active era has stake");
+    assert_eq!(
+        first.sampled_preferences,
+        first.validators.min(VALIDATOR_SAMPLE_SIZE)
+    );
+
+    let second = run_flow(&recording, &account).await;
+    assert_eq!(first, second);
+}
+
+/// The validator paths `compare_connection_modes` checks against each other
+/// live must agree exactly on the same recorded state.
+#[tokio::test]
+#[ignore = "needs tests/fixtures/westend_asset_hub.json; record it with the record_mock_fixture example"]
+async fn replayed_validator_paths_agree() {
+    let (recording, _) = load_fixture();
+    let (_server, client) = replay(&recording).await;
+
+    let iterated = client.get_validators().await.expect("validators");
+    let multi_source = client
+        .get_validators_light_client()
+        .await
+        .expect("multi-source validators");
+    let addresses = |validators: &[stkopt_chain::ValidatorInfo]| -> HashSet<String> {
+        validators.iter().map(|v| v.address.to_string()).collect()
+    };
+    assert_eq!(addresses(&iterated), addresses(&multi_source));
+    for validator in &iterated {
+        let other = multi_source
+            .iter()
+            .find(|v| v.address == validator.address)
+            .expect("validator in both");
+        assert!(
+            (validator.preferences.commission - other.preferences.commission).abs() < 0.0001,
+            "commission of {} differs",
+            validator.address
+        );
+    }
+
+    let era = client
+        .get_active_era()
+        .await
+        .expect("active era")
+        .expect("fixture has an active era")
+        .index;
+    let stakers = client
+        .get_era_stakers_overview(era.saturating_sub(1))
+        .await
+        .expect("era stakers");
+    let stakers: HashSet<String> = stakers.iter().map(|e| e.address.to_string()).collect();
+    assert!(!stakers.is_empty(), "fixture has era stakers");
+    assert!(!stakers.is_disjoint(&addresses(&iterated)));
+}
+
+#[tokio::test]
+async fn unrecorded_session_fails_to_connect() {
+    let server = MockRpcServer::start(Recording::default())
+        .await
+        .expect("mock server starts");
+    let result = ChainClient::connect_rpc(
+        Network::Westend,
+        &server.rpc_endpoints(),
+        create_status_channel(),
+    )
+    .await;
+    assert!(result.is_err(), "empty recording has no metadata to serve");
+}
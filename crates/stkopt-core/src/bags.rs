@@ -0,0 +1,109 @@
+//! Position in the bags-list voter list.
+//!
+//! The election snapshot takes voters from `VoterList` in order: bags from
+//! the highest score threshold down, and within a bag from head to tail. A
+//! voter's bag is only updated when it is rebagged, so after a `bond_extra`
+//! it can sit in a lower bag than its stake warrants until someone calls
+//! `rebag`. Within a bag, `put_in_front_of` moves a voter ahead of a lighter
+//! one.
+
+use crate::types::Balance;
+use serde::{Deserialize, Serialize};
+
+/// Where a stash sits in `VoterList`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BagsListPosition {
+    /// Score recorded in the stash's list node.
+    pub score: u64,
+    /// Score of the stash's current active bond.
+    pub current_score: u64,
+    /// Upper threshold of the bag the stash is in.
+    pub bag_upper: u64,
+    /// Upper threshold of the bag matching `current_score`.
+    pub expected_bag: u64,
+    /// 1-based position within the bag, `None` when not reached within the
+    /// nodes walked.
+    pub rank: Option<u32>,
+    /// SS58 address of the first voter ahead in the bag with a lower score,
+    /// i.e. a valid `put_in_front_of` target.
+    pub lighter_ahead: Option<String>,
+}
+
+impl BagsListPosition {
+    /// Whether the stash sits in a different bag than its bond warrants.
+    pub fn needs_rebag(&self) -> bool {
+        self.bag_upper != self.expected_bag
+    }
+
+    /// Whether the stash can move up within its bag.
+    pub fn can_move_up(&self) -> bool {
+        self.lighter_ahead.is_some()
+    }
+}
+
+/// Upper threshold of the bag holding `score`: the first threshold at or
+/// above it, or `u64::MAX` past the last one.
+pub fn bag_for_score(thresholds: &[u64], score: u64) -> u64 {
+    thresholds
+        .iter()
+        .copied()
+        .find(|&threshold| threshold >= score)
+        .unwrap_or(u64::MAX)
+}
+
+/// Vote weight of `balance`, as `U128CurrencyToVote` computes it: balances
+/// are scaled down so that `total_issuance` fits in a `u64`.
+pub fn currency_to_vote(balance: Balance, total_issuance: Balance) -> u64 {
+    let factor = (total_issuance / u64::MAX as Balance).max(1);
+    (balance / factor).min(u64::MAX as Balance) as u64
+}
+
+/// First voter in `ahead`, ordered from the head of the bag, whose score is
+/// below `score`.
+pub fn first_lighter(ahead: &[(String, u64)], score: u64) -> Option<String> {
+    ahead
+        .iter()
+        .find(|(_, other)| *other < score)
+        .map(|(address, _)| address.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bag_for_score() {
+        let thresholds = [10, 20, 40];
+        assert_eq!(bag_for_score(&thresholds, 0), 10);
+        assert_eq!(bag_for_score(&thresholds, 10), 10);
+        assert_eq!(bag_for_score(&thresholds, 11), 20);
+        assert_eq!(bag_for_score(&thresholds, 41), u64::MAX);
+        assert_eq!(bag_for_score(&[], 5), u64::MAX);
+    }
+
+    #[test]
+    fn test_currency_to_vote_scales_large_issuance() {
+        assert_eq!(currency_to_vote(1_000, 1_000_000), 1_000);
+        let issuance = u64::MAX as Balance * 4;
+        assert_eq!(currency_to_vote(400, issuance), 100);
+        assert_eq!(currency_to_vote(Balance::MAX, 0), u64::MAX);
+    }
+
+    #[test]
+    fn test_first_lighter_and_flags() {
+        let ahead = [("a".to_string(), 90), ("b".to_string(), 40)];
+        assert_eq!(first_lighter(&ahead, 50), Some("b".to_string()));
+        assert_eq!(first_lighter(&ahead, 30), None);
+
+        let position = BagsListPosition {
+            score: 40,
+            current_score: 55,
+            bag_upper: 50,
+            expected_bag: 60,
+            rank: Some(3),
+            lighter_ahead: first_lighter(&ahead, 55),
+        };
+        assert!(position.needs_rebag());
+        assert!(position.can_move_up());
+    }
+}
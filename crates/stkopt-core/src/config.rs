@@ -10,10 +10,13 @@
 
 use directories::ProjectDirs;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use crate::custom_network::{CustomNetwork, CustomNetworkError};
 use crate::types::Network;
+use crate::vault::VaultAccountQr;
 
 /// Configuration error type.
 #[derive(Debug, thiserror::Error)]
@@ -24,14 +27,18 @@ pub enum ConfigError {
     /// JSON serialization/deserialization error.
     #[error("JSON error: {0}")]
     Json(#[from] serde_json::Error),
+    /// Invalid custom network definition.
+    #[error(transparent)]
+    CustomNetwork(#[from] CustomNetworkError),
     /// Other configuration error.
     #[error("{0}")]
     Other(String),
 }
 
 /// Network configuration for UI preferences.
-/// Maps to the core Network type but includes Custom for custom RPC endpoints.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+/// Maps to the core Network type; Custom is the network defined in
+/// `AppConfig::custom_network`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub enum NetworkConfig {
     #[default]
     Polkadot,
@@ -58,7 +65,9 @@ impl NetworkConfig {
         matches!(self, NetworkConfig::Westend | NetworkConfig::Paseo)
     }
 
-    /// Convert to core Network type if not Custom.
+    /// Convert to core Network type.
+    ///
+    /// Custom has no definition here; use `AppConfig::network_for` instead.
     pub fn to_network(&self) -> Option<Network> {
         match self {
             NetworkConfig::Polkadot => Some(Network::Polkadot),
@@ -70,17 +79,24 @@ impl NetworkConfig {
     }
 }
 
-impl From<Network> for NetworkConfig {
-    fn from(network: Network) -> Self {
+impl From<&Network> for NetworkConfig {
+    fn from(network: &Network) -> Self {
         match network {
             Network::Polkadot => NetworkConfig::Polkadot,
             Network::Kusama => NetworkConfig::Kusama,
             Network::Westend => NetworkConfig::Westend,
             Network::Paseo => NetworkConfig::Paseo,
+            Network::Custom(_) => NetworkConfig::Custom,
         }
     }
 }
 
+impl From<Network> for NetworkConfig {
+    fn from(network: Network) -> Self {
+        NetworkConfig::from(&network)
+    }
+}
+
 /// Connection mode configuration.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub enum ConnectionModeConfig {
@@ -89,6 +105,8 @@ pub enum ConnectionModeConfig {
     LightClient,
     /// Use traditional RPC connection.
     Rpc,
+    /// Read over RPC, verifying storage proofs with the light client.
+    Hybrid,
 }
 
 impl ConnectionModeConfig {
@@ -97,6 +115,7 @@ impl ConnectionModeConfig {
         match self {
             ConnectionModeConfig::LightClient => "Light Client",
             ConnectionModeConfig::Rpc => "RPC",
+            ConnectionModeConfig::Hybrid => "Hybrid",
         }
     }
 }
@@ -178,6 +197,37 @@ impl AddressBook {
         Ok(())
     }
 
+    /// Add an account scanned from a Polkadot Vault export QR.
+    ///
+    /// Checks that the export matches `network` and returns the new entry.
+    pub fn add_vault_account(
+        &mut self,
+        account: &VaultAccountQr,
+        network: Network,
+        label: Option<String>,
+    ) -> Result<AddressBookEntry, ConfigError> {
+        account
+            .ensure_network(network.clone())
+            .map_err(|e| ConfigError::Other(e.to_string()))?;
+        let entry = AddressBookEntry {
+            address: account.address.clone(),
+            label: label
+                .filter(|l| !l.trim().is_empty())
+                .unwrap_or_else(|| account.default_label()),
+            network: NetworkConfig::from(network),
+            notes: Some(format!(
+                "Imported from Polkadot Vault ({})",
+                account.genesis_hash
+            )),
+            created_at: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+        };
+        self.add(entry.clone())?;
+        Ok(entry)
+    }
+
     /// Remove an entry by address. Returns true if an entry was removed.
     pub fn remove(&mut self, address: &str) -> bool {
         let len_before = self.entries.len();
@@ -255,6 +305,13 @@ pub struct AppConfig {
     /// Saved accounts (legacy TUI format).
     #[serde(default)]
     pub accounts: Vec<SavedAccount>,
+    /// Mortality period (blocks) for signed transactions, per network.
+    /// Networks without an entry use `DEFAULT_MORTALITY_PERIOD`.
+    #[serde(default)]
+    pub mortality_periods: HashMap<NetworkConfig, u64>,
+    /// User-defined network, selectable as `Network::Custom`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_network: Option<CustomNetwork>,
 }
 
 fn default_auto_connect() -> bool {
@@ -272,6 +329,8 @@ impl Default for AppConfig {
             auto_connect: true,
             show_testnets: false,
             accounts: Vec::new(),
+            mortality_periods: HashMap::new(),
+            custom_network: None,
         }
     }
 }
@@ -302,6 +361,67 @@ impl AppConfig {
     pub fn last_saved_account(&self) -> Option<&str> {
         self.accounts.last().map(|a| a.address.as_str())
     }
+
+    /// Saved accounts added on `network`, plus those saved without one.
+    pub fn accounts_on_network(&self, network: Network) -> Vec<&SavedAccount> {
+        let name = network.to_string();
+        self.accounts
+            .iter()
+            .filter(|a| {
+                a.network
+                    .as_deref()
+                    .is_none_or(|n| n.eq_ignore_ascii_case(&name))
+            })
+            .collect()
+    }
+
+    /// Mortality period (blocks) used for transactions on `network`.
+    pub fn mortality_period(&self, network: &Network) -> u64 {
+        self.mortality_periods
+            .get(&NetworkConfig::from(network))
+            .copied()
+            .map(crate::mortality::normalize_mortality_period)
+            .unwrap_or(crate::mortality::DEFAULT_MORTALITY_PERIOD)
+    }
+
+    /// The validated `custom_network`, if set, as a `Network::Custom`.
+    pub fn custom(&self) -> Result<Option<Network>, ConfigError> {
+        match &self.custom_network {
+            Some(network) => Ok(Some(Network::from_custom(network.clone())?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Resolve `network` to a core Network, using `custom_network` for Custom.
+    pub fn network_for(&self, network: NetworkConfig) -> Result<Option<Network>, ConfigError> {
+        match network {
+            NetworkConfig::Custom => self.custom(),
+            other => Ok(other.to_network()),
+        }
+    }
+
+    /// Mortality periods keyed by core Network, skipping Custom when no
+    /// valid custom network is defined.
+    pub fn network_mortality_periods(&self) -> HashMap<Network, u64> {
+        self.mortality_periods
+            .iter()
+            .filter_map(|(network, period)| {
+                let network = self.network_for(*network).ok().flatten()?;
+                Some((
+                    network,
+                    crate::mortality::normalize_mortality_period(*period),
+                ))
+            })
+            .collect()
+    }
+
+    /// Set the mortality period for `network`, rounded to a valid era period.
+    pub fn set_mortality_period(&mut self, network: &Network, period: u64) {
+        self.mortality_periods.insert(
+            NetworkConfig::from(network),
+            crate::mortality::normalize_mortality_period(period),
+        );
+    }
 }
 
 /// Cached validator metadata.
@@ -378,6 +498,11 @@ pub fn get_db_path() -> Result<PathBuf, ConfigError> {
     get_data_dir().map(|dir| dir.join("history.db"))
 }
 
+/// Get the directory for saved light-client peers.
+pub fn get_light_client_dir() -> Result<PathBuf, ConfigError> {
+    get_data_dir().map(|dir| dir.join("light_client"))
+}
+
 /// Get the config file path.
 pub fn get_config_path() -> Result<PathBuf, ConfigError> {
     get_config_dir().map(|dir| dir.join("config.json"))
@@ -466,6 +591,7 @@ pub fn backup_corrupted_config(path: &Path) -> Result<(), ConfigError> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::custom_network::ChainSource;
 
     // ==================== NetworkConfig Tests ====================
 
@@ -502,7 +628,6 @@ mod tests {
         assert_eq!(NetworkConfig::Kusama.to_network(), Some(Network::Kusama));
         assert_eq!(NetworkConfig::Westend.to_network(), Some(Network::Westend));
         assert_eq!(NetworkConfig::Paseo.to_network(), Some(Network::Paseo));
-        assert_eq!(NetworkConfig::Custom.to_network(), None);
     }
 
     #[test]
@@ -517,6 +642,20 @@ mod tests {
             NetworkConfig::Westend
         );
         assert_eq!(NetworkConfig::from(Network::Paseo), NetworkConfig::Paseo);
+        let custom = Network::from_custom(CustomNetwork {
+            name: "Testnet".to_string(),
+            token_symbol: "TST".to_string(),
+            token_decimals: 10,
+            ss58_prefix: 42,
+            relay: ChainSource::rpc(["ws://127.0.0.1:9944"]),
+            asset_hub: None,
+            people: None,
+            relay_genesis_hash: None,
+            asset_hub_genesis_hash: None,
+            staking_indexer_url: None,
+        })
+        .unwrap();
+        assert_eq!(NetworkConfig::from(&custom), NetworkConfig::Custom);
     }
 
     // ==================== ConnectionModeConfig Tests ====================
@@ -531,6 +670,7 @@ mod tests {
     fn test_connection_mode_labels() {
         assert_eq!(ConnectionModeConfig::LightClient.label(), "Light Client");
         assert_eq!(ConnectionModeConfig::Rpc.label(), "RPC");
+        assert_eq!(ConnectionModeConfig::Hybrid.label(), "Hybrid");
     }
 
     // ==================== ThemeConfig Tests ====================
@@ -580,6 +720,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_app_config_accounts_on_network() {
+        let mut config = AppConfig::default();
+        config.add_account("a".to_string(), None, Some("Polkadot".to_string()));
+        config.add_account("b".to_string(), None, Some("Kusama".to_string()));
+        config.add_account("c".to_string(), None, None);
+
+        let addresses: Vec<&str> = config
+            .accounts_on_network(Network::Polkadot)
+            .iter()
+            .map(|a| a.address.as_str())
+            .collect();
+        assert_eq!(addresses, vec!["a", "c"]);
+        assert_eq!(config.accounts_on_network(Network::Kusama).len(), 2);
+    }
+
     #[test]
     fn test_app_config_no_duplicate_accounts() {
         let mut config = AppConfig::default();
@@ -627,6 +783,30 @@ mod tests {
         assert!(config.accounts.is_empty());
     }
 
+    #[test]
+    fn test_app_config_mortality_periods() {
+        let mut config = AppConfig::default();
+        assert_eq!(
+            config.mortality_period(&Network::Kusama),
+            crate::mortality::DEFAULT_MORTALITY_PERIOD
+        );
+
+        config.set_mortality_period(&Network::Kusama, 100);
+        assert_eq!(config.mortality_period(&Network::Kusama), 128);
+        assert_eq!(
+            config.mortality_period(&Network::Polkadot),
+            crate::mortality::DEFAULT_MORTALITY_PERIOD
+        );
+
+        let json = serde_json::to_string(&config).unwrap();
+        let parsed: AppConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.mortality_period(&Network::Kusama), 128);
+        assert_eq!(
+            parsed.network_mortality_periods(),
+            HashMap::from([(Network::Kusama, 128)])
+        );
+    }
+
     #[test]
     fn test_app_config_deserialize_legacy() {
         // Legacy TUI format with just accounts
@@ -663,6 +843,59 @@ mod tests {
         assert!(book.add(entry).is_err());
     }
 
+    #[test]
+    fn test_address_book_add_vault_account() {
+        let text = format!(
+            "substrate:5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY:{}",
+            Network::Westend.relay_genesis_hash()
+        );
+        let account = crate::vault::parse_vault_account_qr(text.as_bytes()).unwrap();
+        let mut book = AddressBook::default();
+
+        let entry = book
+            .add_vault_account(&account, Network::Westend, Some("Vault".to_string()))
+            .unwrap();
+        assert_eq!(entry.label, "Vault");
+        assert_eq!(entry.network, NetworkConfig::Westend);
+        assert_eq!(book.len(), 1);
+
+        // Duplicate import is rejected
+        assert!(
+            book.add_vault_account(&account, Network::Westend, None)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_address_book_add_vault_account_wrong_network() {
+        let text = format!(
+            "substrate:5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY:{}",
+            Network::Kusama.relay_genesis_hash()
+        );
+        let account = crate::vault::parse_vault_account_qr(text.as_bytes()).unwrap();
+        let mut book = AddressBook::default();
+        let err = book
+            .add_vault_account(&account, Network::Polkadot, None)
+            .unwrap_err();
+        assert!(err.to_string().contains("Kusama"));
+        assert!(book.is_empty());
+    }
+
+    #[test]
+    fn test_address_book_add_vault_account_default_label() {
+        let text = format!(
+            "substrate:5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY:{}",
+            Network::Westend.relay_genesis_hash()
+        );
+        let account = crate::vault::parse_vault_account_qr(text.as_bytes()).unwrap();
+        let mut book = AddressBook::default();
+        let entry = book
+            .add_vault_account(&account, Network::Westend, Some("  ".to_string()))
+            .unwrap();
+        assert_eq!(entry.label, account.default_label());
+        assert!(entry.created_at > 0);
+    }
+
     #[test]
     fn test_address_book_remove() {
         let mut book = AddressBook::default();
@@ -1029,6 +1262,53 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_config_with_custom_network() {
+        let config: AppConfig = serde_json::from_str(
+            r#"{
+                "network": "Custom",
+                "custom_network": {
+                    "name": "Testnet",
+                    "token_symbol": "TST",
+                    "token_decimals": 10,
+                    "ss58_prefix": 5,
+                    "relay": { "chain_spec": "/specs/testnet.json" },
+                    "asset_hub": { "endpoints": ["ws://127.0.0.1:9946"] }
+                }
+            }"#,
+        )
+        .unwrap();
+        let custom = config.custom_network.as_ref().unwrap();
+        assert_eq!(
+            custom.relay.chain_spec,
+            Some(PathBuf::from("/specs/testnet.json"))
+        );
+        assert!(custom.relay.endpoints.is_empty());
+        assert_eq!(custom.validate(), Ok(()));
+
+        let network = config.network_for(config.network).unwrap().unwrap();
+        assert_eq!(network.custom(), Some(custom));
+        assert_eq!(network.to_string(), "Testnet");
+
+        // The definition travels with the network through a save/load cycle.
+        let json = serde_json::to_string(&network).unwrap();
+        assert_eq!(serde_json::from_str::<Network>(&json).unwrap(), network);
+
+        let mut invalid = config.clone();
+        invalid.custom_network.as_mut().unwrap().name = "Polkadot".to_string();
+        assert!(matches!(
+            invalid.custom(),
+            Err(ConfigError::CustomNetwork(_))
+        ));
+        assert!(AppConfig::default().custom().unwrap().is_none());
+        assert!(
+            AppConfig::default()
+                .network_for(NetworkConfig::Custom)
+                .unwrap()
+                .is_none()
+        );
+    }
+
     #[test]
     fn test_load_address_book_missing_file() {
         with_temp_home(|| {
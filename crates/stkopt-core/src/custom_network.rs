@@ -0,0 +1,261 @@
+//! User-defined networks.
+//!
+//! A [`CustomNetwork`] describes a chain stkopt has no built-in knowledge of,
+//! such as a local zombienet or a new testnet. It is loaded from the app
+//! config and turned into a [`Network::Custom`] with [`Network::from_custom`],
+//! which carries the definition for its name, token metadata, SS58 prefix and
+//! endpoints.
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::endpoint_health::ChainRole;
+use crate::types::Network;
+
+/// Custom network definition error.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum CustomNetworkError {
+    #[error("Custom network name must not be empty")]
+    EmptyName,
+    #[error("Custom network name '{0}' may only contain letters, digits, spaces, '-' and '_'")]
+    InvalidName(String),
+    #[error("Custom network name '{0}' clashes with a built-in network")]
+    BuiltinName(String),
+    #[error("Custom network token symbol must not be empty")]
+    EmptyTokenSymbol,
+    #[error("Custom network {0} needs an endpoint or a chain spec")]
+    NoSource(ChainRole),
+    #[error("Invalid {role} genesis hash '{hash}': expected 32-byte hex")]
+    InvalidGenesisHash { role: ChainRole, hash: String },
+}
+
+/// Where to reach one chain of a custom network.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ChainSource {
+    /// RPC endpoints, tried in order when connecting over RPC.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub endpoints: Vec<String>,
+    /// Chain spec JSON file, used when connecting with the light client.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chain_spec: Option<PathBuf>,
+}
+
+impl ChainSource {
+    /// Source with RPC endpoints only.
+    pub fn rpc(endpoints: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            endpoints: endpoints.into_iter().map(Into::into).collect(),
+            chain_spec: None,
+        }
+    }
+
+    /// Whether neither endpoints nor a chain spec are set.
+    pub fn is_empty(&self) -> bool {
+        self.endpoints.is_empty() && self.chain_spec.is_none()
+    }
+}
+
+/// A user-defined network.
+///
+/// Only the relay chain is required. Over RPC, chains without a source are
+/// served by the relay chain endpoints, for networks that stake and keep
+/// identities on the relay chain. The light client needs an Asset Hub chain
+/// spec.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CustomNetwork {
+    /// Display name, also used for database keys and light-client files.
+    pub name: String,
+    pub token_symbol: String,
+    pub token_decimals: u8,
+    pub ss58_prefix: u16,
+    pub relay: ChainSource,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_hub: Option<ChainSource>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub people: Option<ChainSource>,
+    /// Relay chain genesis hash, to match Vault account exports.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relay_genesis_hash: Option<String>,
+    /// Asset Hub genesis hash, to match Vault account exports.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_hub_genesis_hash: Option<String>,
+    /// Staking indexer base URL, if the network has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub staking_indexer_url: Option<String>,
+}
+
+impl CustomNetwork {
+    /// Check the definition is usable.
+    pub fn validate(&self) -> Result<(), CustomNetworkError> {
+        let name = self.name.trim();
+        if name.is_empty() {
+            return Err(CustomNetworkError::EmptyName);
+        }
+        if !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, ' ' | '-' | '_'))
+        {
+            return Err(CustomNetworkError::InvalidName(self.name.clone()));
+        }
+        if Network::builtin()
+            .iter()
+            .any(|n| n.to_string().eq_ignore_ascii_case(name))
+            || name.eq_ignore_ascii_case("custom")
+        {
+            return Err(CustomNetworkError::BuiltinName(self.name.clone()));
+        }
+        if self.token_symbol.trim().is_empty() {
+            return Err(CustomNetworkError::EmptyTokenSymbol);
+        }
+        let chains = [
+            (ChainRole::Relay, Some(&self.relay)),
+            (ChainRole::AssetHub, self.asset_hub.as_ref()),
+            (ChainRole::People, self.people.as_ref()),
+        ];
+        for (role, source) in chains {
+            if source.is_some_and(ChainSource::is_empty) {
+                return Err(CustomNetworkError::NoSource(role));
+            }
+        }
+        let hashes = [
+            (ChainRole::Relay, &self.relay_genesis_hash),
+            (ChainRole::AssetHub, &self.asset_hub_genesis_hash),
+        ];
+        for (role, hash) in hashes {
+            if let Some(hash) = hash
+                && normalize_genesis_hash(hash).is_none()
+            {
+                return Err(CustomNetworkError::InvalidGenesisHash {
+                    role,
+                    hash: hash.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// The definition validated, with its name, token symbol and genesis
+    /// hashes normalized.
+    pub fn normalized(mut self) -> Result<Self, CustomNetworkError> {
+        self.validate()?;
+        self.name = self.name.trim().to_string();
+        self.token_symbol = self.token_symbol.trim().to_string();
+        self.relay_genesis_hash = self
+            .relay_genesis_hash
+            .as_deref()
+            .and_then(normalize_genesis_hash);
+        self.asset_hub_genesis_hash = self
+            .asset_hub_genesis_hash
+            .as_deref()
+            .and_then(normalize_genesis_hash);
+        Ok(self)
+    }
+
+    /// Source for one chain of the network, if configured.
+    pub fn source(&self, role: ChainRole) -> Option<&ChainSource> {
+        match role {
+            ChainRole::Relay => Some(&self.relay),
+            ChainRole::AssetHub => self.asset_hub.as_ref(),
+            ChainRole::People => self.people.as_ref(),
+        }
+    }
+
+    /// RPC endpoints defined for one chain; empty when it has none.
+    pub fn endpoints(&self, role: ChainRole) -> &[String] {
+        self.source(role)
+            .map(|source| source.endpoints.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+/// Lowercase `0x`-prefixed form of a 32-byte hex hash.
+fn normalize_genesis_hash(hash: &str) -> Option<String> {
+    let hash = hash.trim().to_ascii_lowercase();
+    let hex = hash.strip_prefix("0x").unwrap_or(&hash);
+    (hex.len() == 64 && hex.chars().all(|c| c.is_ascii_hexdigit())).then(|| format!("0x{}", hex))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zombienet() -> CustomNetwork {
+        CustomNetwork {
+            name: "Zombienet".to_string(),
+            token_symbol: "UNIT".to_string(),
+            token_decimals: 12,
+            ss58_prefix: 42,
+            relay: ChainSource::rpc(["ws://127.0.0.1:9944"]),
+            asset_hub: Some(ChainSource::rpc(["ws://127.0.0.1:9946"])),
+            people: None,
+            relay_genesis_hash: None,
+            asset_hub_genesis_hash: None,
+            staking_indexer_url: None,
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(zombienet().validate(), Ok(()));
+
+        let mut network = zombienet();
+        network.name = "  ".to_string();
+        assert_eq!(network.validate(), Err(CustomNetworkError::EmptyName));
+
+        network.name = "zombie/net".to_string();
+        assert!(matches!(
+            network.validate(),
+            Err(CustomNetworkError::InvalidName(_))
+        ));
+
+        network.name = "kusama".to_string();
+        assert!(matches!(
+            network.validate(),
+            Err(CustomNetworkError::BuiltinName(_))
+        ));
+
+        let mut network = zombienet();
+        network.people = Some(ChainSource::default());
+        assert_eq!(
+            network.validate(),
+            Err(CustomNetworkError::NoSource(ChainRole::People))
+        );
+
+        let mut network = zombienet();
+        network.relay_genesis_hash = Some("0x1234".to_string());
+        assert!(matches!(
+            network.validate(),
+            Err(CustomNetworkError::InvalidGenesisHash { .. })
+        ));
+    }
+
+    #[test]
+    fn test_custom_network_metadata() {
+        let mut definition = zombienet();
+        definition.name = " Zombienet ".to_string();
+        definition.relay_genesis_hash = Some(format!("0X{}", "5A".repeat(32)));
+        let network = Network::from_custom(definition).unwrap();
+
+        assert_eq!(network.to_string(), "Zombienet");
+        assert_eq!(network.token_symbol(), "UNIT");
+        assert_eq!(network.token_decimals(), 12);
+        assert_eq!(network.ss58_format(), 42);
+        assert!(network.is_custom());
+        let hash = format!("0x{}", "5a".repeat(32));
+        assert_eq!(network.relay_genesis_hash(), hash);
+        assert_eq!(network.asset_hub_genesis_hash(), "");
+        assert!(network.has_genesis_hash(&hash));
+        assert_eq!(
+            network.custom().and_then(|n| n.source(ChainRole::AssetHub)),
+            Some(&ChainSource::rpc(["ws://127.0.0.1:9946"]))
+        );
+        assert_eq!(Network::Kusama.custom(), None);
+        let custom = network.custom().unwrap();
+        assert_eq!(custom.endpoints(ChainRole::Relay), ["ws://127.0.0.1:9944"]);
+        assert!(custom.endpoints(ChainRole::People).is_empty());
+
+        let mut invalid = zombienet();
+        invalid.name = "Kusama".to_string();
+        assert!(Network::from_custom(invalid).is_err());
+    }
+}
@@ -11,9 +11,15 @@ use std::path::Path;
 use std::time::Duration;
 
 use crate::display::{DisplayPool, DisplayValidator, StakingHistoryPoint};
+use crate::endpoint_health::{ChainRole, EndpointHealth};
+use crate::journal::{
+    NewTransaction, TransactionFilter, TransactionRecord, TxJournalStatus, TxMortality,
+};
+use crate::payouts::{PayoutEvent, PayoutScan, PayoutSource};
+use crate::staking_params::StakingParams;
 use crate::types::{Network, PoolState};
 
-const SCHEMA_VERSION: i32 = 5;
+const SCHEMA_VERSION: i32 = 10;
 const SQLITE_BUSY_TIMEOUT: Duration = Duration::from_secs(5);
 
 /// Maximum age for startup validator/pool caches.
@@ -205,6 +211,16 @@ fn read_u128(row: &rusqlite::Row<'_>, index: usize) -> Result<u128> {
     }
 }
 
+fn read_history_point(row: &rusqlite::Row<'_>) -> Result<StakingHistoryPoint> {
+    Ok(StakingHistoryPoint {
+        era: row.get(0)?,
+        date: row.get(1)?,
+        reward: read_u128(row, 2)?,
+        bonded: read_u128(row, 3)?,
+        apy: row.get(4)?,
+    })
+}
+
 fn read_option_u128(row: &rusqlite::Row<'_>, index: usize) -> Result<Option<u128>> {
     match row.get_ref(index)? {
         ValueRef::Null => Ok(None),
@@ -212,6 +228,24 @@ fn read_option_u128(row: &rusqlite::Row<'_>, index: usize) -> Result<Option<u128
     }
 }
 
+/// Which reward a `staking_history` row records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HistoryKind {
+    /// Reward estimated from the era payout and the account's share of stake.
+    Estimated,
+    /// Reward computed from era points, commission and the paged exposure.
+    Exact,
+}
+
+impl HistoryKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            HistoryKind::Estimated => "estimated",
+            HistoryKind::Exact => "exact",
+        }
+    }
+}
+
 /// Cached chain metadata from the blockchain.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CachedChainMetadata {
@@ -231,6 +265,9 @@ pub struct CachedChainMetadata {
     pub era_duration_ms: u64,
     /// Current era index at time of caching.
     pub current_era: u32,
+    /// Staking limits, if they have been read.
+    #[serde(default)]
+    pub staking_params: Option<StakingParams>,
 }
 
 /// Cached account status including balances and staking info.
@@ -268,6 +305,7 @@ pub struct CachedAccountStatus {
 /// - Nomination pool caching
 /// - Account status caching
 /// - Chain metadata caching
+/// - Transaction journal
 pub struct StakingDb {
     conn: Connection,
 }
@@ -349,7 +387,42 @@ impl StakingDb {
             self.migrate_to_v5_account_status_unlocking_and_pool()?;
         }
 
-        // SCHEMA_VERSION (5) is the highest migration this build knows about.
+        if user_version < 6 {
+            self.migrate_to_v6_transactions()?;
+            self.conn.execute_batch("PRAGMA user_version = 6;")?;
+        } else {
+            self.migrate_to_v6_transactions()?;
+        }
+
+        if user_version < 7 {
+            self.migrate_to_v7_endpoint_health()?;
+            self.conn.execute_batch("PRAGMA user_version = 7;")?;
+        } else {
+            self.migrate_to_v7_endpoint_health()?;
+        }
+
+        if user_version < 8 {
+            self.migrate_to_v8_staking_params()?;
+            self.conn.execute_batch("PRAGMA user_version = 8;")?;
+        } else {
+            self.migrate_to_v8_staking_params()?;
+        }
+
+        if user_version < 9 {
+            self.migrate_to_v9_history_kind()?;
+            self.conn.execute_batch("PRAGMA user_version = 9;")?;
+        } else {
+            self.migrate_to_v9_history_kind()?;
+        }
+
+        if user_version < 10 {
+            self.migrate_to_v10_payout_events()?;
+            self.conn.execute_batch("PRAGMA user_version = 10;")?;
+        } else {
+            self.migrate_to_v10_payout_events()?;
+        }
+
+        // SCHEMA_VERSION (10) is the highest migration this build knows about.
         // If user_version is newer we do not migrate down or bump the pragma,
         // but the idempotent schema checks above already created any missing
         // tables/columns known to this build.
@@ -371,7 +444,8 @@ impl StakingDb {
                 bonded TEXT NOT NULL,
                 apy REAL NOT NULL,
                 created_at TEXT DEFAULT CURRENT_TIMESTAMP,
-                UNIQUE(network, address, era)
+                kind TEXT NOT NULL DEFAULT 'estimated',
+                UNIQUE(network, address, era, kind)
             );
 
             CREATE INDEX IF NOT EXISTS idx_staking_history_lookup
@@ -577,6 +651,146 @@ impl StakingDb {
         Ok(())
     }
 
+    fn migrate_to_v6_transactions(&self) -> Result<()> {
+        self.conn.execute_batch(
+            r#"
+            -- Journal of submitted transactions (audit trail)
+            CREATE TABLE IF NOT EXISTS transactions (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                network TEXT NOT NULL,
+                signer TEXT NOT NULL,
+                description TEXT NOT NULL,
+                call_data TEXT NOT NULL,
+                nonce INTEGER NOT NULL,
+                era_period INTEGER,
+                era_phase INTEGER,
+                tx_hash TEXT NOT NULL,
+                block_hash TEXT,
+                status TEXT NOT NULL,
+                dispatch_result TEXT,
+                submitted_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                completed_at TEXT
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_transactions_signer
+                ON transactions (network, signer, id);
+            "#,
+        )
+    }
+
+    fn migrate_to_v7_endpoint_health(&self) -> Result<()> {
+        self.conn.execute_batch(
+            r#"
+            -- RPC endpoint health stats used to rank endpoints across runs
+            CREATE TABLE IF NOT EXISTS endpoint_health (
+                network TEXT NOT NULL,
+                role TEXT NOT NULL,
+                url TEXT NOT NULL,
+                latency_ms INTEGER,
+                best_block_lag INTEGER,
+                genesis_ok INTEGER,
+                successes INTEGER NOT NULL DEFAULT 0,
+                failures INTEGER NOT NULL DEFAULT 0,
+                last_probed INTEGER,
+                PRIMARY KEY (network, role, url)
+            );
+            "#,
+        )
+    }
+
+    fn migrate_to_v8_staking_params(&self) -> Result<()> {
+        if !self.table_has_column("chain_metadata", "staking_params_json")? {
+            self.conn
+                .execute_batch("ALTER TABLE chain_metadata ADD COLUMN staking_params_json TEXT;")?;
+        }
+        Ok(())
+    }
+
+    fn migrate_to_v9_history_kind(&self) -> Result<()> {
+        if self.table_has_column("staking_history", "kind")? {
+            return Ok(());
+        }
+
+        // The unique key gains `kind`, so the table has to be rebuilt.
+        let result = self.conn.execute_batch(
+            r#"
+            BEGIN IMMEDIATE;
+
+            DROP INDEX IF EXISTS idx_staking_history_lookup;
+            DROP INDEX IF EXISTS idx_staking_history_era;
+
+            ALTER TABLE staking_history RENAME TO staking_history_old;
+
+            CREATE TABLE staking_history (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                network TEXT NOT NULL,
+                address TEXT NOT NULL,
+                era INTEGER NOT NULL,
+                date TEXT,
+                reward TEXT NOT NULL,
+                bonded TEXT NOT NULL,
+                apy REAL NOT NULL,
+                created_at TEXT DEFAULT CURRENT_TIMESTAMP,
+                kind TEXT NOT NULL DEFAULT 'estimated',
+                UNIQUE(network, address, era, kind)
+            );
+
+            INSERT INTO staking_history
+                (id, network, address, era, date, reward, bonded, apy, created_at)
+            SELECT id, network, address, era, date, reward, bonded, apy, created_at
+            FROM staking_history_old;
+
+            DROP TABLE staking_history_old;
+
+            CREATE INDEX IF NOT EXISTS idx_staking_history_lookup
+                ON staking_history(network, address, era);
+            CREATE INDEX IF NOT EXISTS idx_staking_history_era
+                ON staking_history(network, address, era DESC);
+
+            COMMIT;
+            "#,
+        );
+
+        if let Err(err) = result {
+            let _ = self.conn.execute_batch("ROLLBACK;");
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
+    fn migrate_to_v10_payout_events(&self) -> Result<()> {
+        self.conn.execute_batch(
+            r#"
+            -- Staking.Rewarded and NominationPools.PaidOut events per account
+            CREATE TABLE IF NOT EXISTS payout_events (
+                network TEXT NOT NULL,
+                address TEXT NOT NULL,
+                block_number INTEGER NOT NULL,
+                event_index INTEGER NOT NULL,
+                source TEXT NOT NULL,
+                era INTEGER,
+                validator TEXT,
+                pool_id INTEGER,
+                amount TEXT NOT NULL,
+                PRIMARY KEY (network, address, block_number, event_index)
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_payout_events_era
+                ON payout_events (network, address, era);
+
+            -- Blocks scanned for payout events per account, without gaps
+            CREATE TABLE IF NOT EXISTS payout_scan_cursors (
+                network TEXT NOT NULL,
+                address TEXT NOT NULL,
+                first_block INTEGER NOT NULL,
+                last_block INTEGER NOT NULL,
+                PRIMARY KEY (network, address)
+            );
+            "#,
+        )
+    }
+
     fn migrate_cached_validator_stakes_to_text(&self) -> Result<()> {
         let mut stmt = self.conn.prepare("PRAGMA table_info(cached_validators)")?;
         let columns = stmt.query_map([], |row| {
@@ -915,8 +1129,8 @@ impl StakingDb {
         self.conn.execute(
             r#"
             INSERT OR REPLACE INTO staking_history
-                (network, address, era, date, reward, bonded, apy)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+                (network, address, era, date, reward, bonded, apy, kind)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
             "#,
             params![
                 network.to_string(),
@@ -926,6 +1140,7 @@ impl StakingDb {
                 point.reward.to_string(),
                 point.bonded.to_string(),
                 point.apy,
+                HistoryKind::Estimated.as_str(),
             ],
         )?;
         Ok(())
@@ -937,14 +1152,34 @@ impl StakingDb {
         network: Network,
         address: &str,
         points: &[StakingHistoryPoint],
+    ) -> Result<()> {
+        self.insert_history_rows(HistoryKind::Estimated, network, address, points)
+    }
+
+    /// Store exact per-era rewards, kept apart from the estimated rows.
+    pub fn insert_exact_history_batch(
+        &mut self,
+        network: Network,
+        address: &str,
+        points: &[StakingHistoryPoint],
+    ) -> Result<()> {
+        self.insert_history_rows(HistoryKind::Exact, network, address, points)
+    }
+
+    fn insert_history_rows(
+        &mut self,
+        kind: HistoryKind,
+        network: Network,
+        address: &str,
+        points: &[StakingHistoryPoint],
     ) -> Result<()> {
         let tx = self.conn.transaction()?;
         {
             let mut stmt = tx.prepare(
                 r#"
                 INSERT OR REPLACE INTO staking_history
-                    (network, address, era, date, reward, bonded, apy)
-                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+                    (network, address, era, date, reward, bonded, apy, kind)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
                 "#,
             )?;
 
@@ -958,6 +1193,7 @@ impl StakingDb {
                     point.reward.to_string(),
                     point.bonded.to_string(),
                     point.apy,
+                    kind.as_str(),
                 ])?;
             }
         }
@@ -976,6 +1212,7 @@ impl StakingDb {
         limit: Option<u32>,
     ) -> Result<Vec<StakingHistoryPoint>> {
         let network = network.to_string();
+        let kind = HistoryKind::Estimated.as_str();
         let mut points = Vec::new();
 
         if let Some(limit) = limit {
@@ -985,7 +1222,7 @@ impl StakingDb {
                 FROM (
                     SELECT era, date, reward, bonded, apy
                     FROM staking_history
-                    WHERE network = ?1 AND address = ?2
+                    WHERE network = ?1 AND address = ?2 AND kind = ?4
                     ORDER BY era DESC
                     LIMIT ?3
                 )
@@ -993,15 +1230,8 @@ impl StakingDb {
                 "#,
             )?;
 
-            let rows = stmt.query_map(params![network, address, limit], |row| {
-                Ok(StakingHistoryPoint {
-                    era: row.get(0)?,
-                    date: row.get(1)?,
-                    reward: read_u128(row, 2)?,
-                    bonded: read_u128(row, 3)?,
-                    apy: row.get(4)?,
-                })
-            })?;
+            let rows =
+                stmt.query_map(params![network, address, limit, kind], read_history_point)?;
 
             for row in rows {
                 points.push(row?);
@@ -1011,20 +1241,12 @@ impl StakingDb {
                 r#"
                 SELECT era, date, reward, bonded, apy
                 FROM staking_history
-                WHERE network = ?1 AND address = ?2
+                WHERE network = ?1 AND address = ?2 AND kind = ?3
                 ORDER BY era ASC
                 "#,
             )?;
 
-            let rows = stmt.query_map(params![network, address], |row| {
-                Ok(StakingHistoryPoint {
-                    era: row.get(0)?,
-                    date: row.get(1)?,
-                    reward: read_u128(row, 2)?,
-                    bonded: read_u128(row, 3)?,
-                    apy: row.get(4)?,
-                })
-            })?;
+            let rows = stmt.query_map(params![network, address, kind], read_history_point)?;
 
             for row in rows {
                 points.push(row?);
@@ -1040,27 +1262,48 @@ impl StakingDb {
         address: &str,
         from_era: u32,
         to_era: u32,
+    ) -> Result<Vec<StakingHistoryPoint>> {
+        self.history_range(HistoryKind::Estimated, network, address, from_era, to_era)
+    }
+
+    /// Get exact per-era rewards for an inclusive era range, ordered by era
+    /// ascending.
+    pub fn get_exact_history_range(
+        &self,
+        network: Network,
+        address: &str,
+        from_era: u32,
+        to_era: u32,
+    ) -> Result<Vec<StakingHistoryPoint>> {
+        self.history_range(HistoryKind::Exact, network, address, from_era, to_era)
+    }
+
+    fn history_range(
+        &self,
+        kind: HistoryKind,
+        network: Network,
+        address: &str,
+        from_era: u32,
+        to_era: u32,
     ) -> Result<Vec<StakingHistoryPoint>> {
         let mut stmt = self.conn.prepare(
             r#"
             SELECT era, date, reward, bonded, apy
             FROM staking_history
-            WHERE network = ?1 AND address = ?2 AND era >= ?3 AND era <= ?4
+            WHERE network = ?1 AND address = ?2 AND era >= ?3 AND era <= ?4 AND kind = ?5
             ORDER BY era ASC
             "#,
         )?;
 
         let rows = stmt.query_map(
-            params![network.to_string(), address, from_era, to_era],
-            |row| {
-                Ok(StakingHistoryPoint {
-                    era: row.get(0)?,
-                    date: row.get(1)?,
-                    reward: read_u128(row, 2)?,
-                    bonded: read_u128(row, 3)?,
-                    apy: row.get(4)?,
-                })
-            },
+            params![
+                network.to_string(),
+                address,
+                from_era,
+                to_era,
+                kind.as_str()
+            ],
+            read_history_point,
         )?;
 
         let mut points = Vec::new();
@@ -1075,12 +1318,19 @@ impl StakingDb {
         let mut stmt = self.conn.prepare(
             r#"
             SELECT MAX(era) FROM staking_history
-            WHERE network = ?1 AND address = ?2
+            WHERE network = ?1 AND address = ?2 AND kind = ?3
             "#,
         )?;
 
         let result: Option<u32> = stmt
-            .query_row(params![network.to_string(), address], |row| row.get(0))
+            .query_row(
+                params![
+                    network.to_string(),
+                    address,
+                    HistoryKind::Estimated.as_str()
+                ],
+                |row| row.get(0),
+            )
             .ok();
 
         Ok(result)
@@ -1093,17 +1343,45 @@ impl StakingDb {
         address: &str,
         from_era: u32,
         to_era: u32,
+    ) -> Result<Vec<u32>> {
+        self.missing_eras(HistoryKind::Estimated, network, address, from_era, to_era)
+    }
+
+    /// Get eras in a range that have no exact reward stored.
+    pub fn get_missing_exact_eras(
+        &self,
+        network: Network,
+        address: &str,
+        from_era: u32,
+        to_era: u32,
+    ) -> Result<Vec<u32>> {
+        self.missing_eras(HistoryKind::Exact, network, address, from_era, to_era)
+    }
+
+    fn missing_eras(
+        &self,
+        kind: HistoryKind,
+        network: Network,
+        address: &str,
+        from_era: u32,
+        to_era: u32,
     ) -> Result<Vec<u32>> {
         let stored: std::collections::HashSet<u32> = {
             let mut stmt = self.conn.prepare(
                 r#"
                 SELECT era FROM staking_history
-                WHERE network = ?1 AND address = ?2 AND era >= ?3 AND era <= ?4
+                WHERE network = ?1 AND address = ?2 AND era >= ?3 AND era <= ?4 AND kind = ?5
                 "#,
             )?;
 
             let rows = stmt.query_map(
-                params![network.to_string(), address, from_era, to_era],
+                params![
+                    network.to_string(),
+                    address,
+                    from_era,
+                    to_era,
+                    kind.as_str()
+                ],
                 |row| row.get(0),
             )?;
 
@@ -1135,12 +1413,19 @@ impl StakingDb {
                 r#"
                 SELECT era FROM staking_history
                 WHERE network = ?1 AND address = ?2 AND era >= ?3 AND era <= ?4
-                  AND apy >= 0.0 AND apy <= ?5
+                  AND apy >= 0.0 AND apy <= ?5 AND kind = ?6
                 "#,
             )?;
 
             let rows = stmt.query_map(
-                params![network.to_string(), address, from_era, to_era, max_apy],
+                params![
+                    network.to_string(),
+                    address,
+                    from_era,
+                    to_era,
+                    max_apy,
+                    HistoryKind::Estimated.as_str()
+                ],
                 |row| row.get(0),
             )?;
 
@@ -1158,36 +1443,52 @@ impl StakingDb {
         Ok(missing)
     }
 
-    /// Count total history entries for an address.
+    /// Count estimated history entries for an address.
     pub fn count_history(&self, network: Network, address: &str) -> Result<u32> {
         let mut stmt = self.conn.prepare(
             r#"
             SELECT COUNT(*) FROM staking_history
-            WHERE network = ?1 AND address = ?2
+            WHERE network = ?1 AND address = ?2 AND kind = ?3
             "#,
         )?;
 
-        let count: u32 = stmt.query_row(params![network.to_string(), address], |row| row.get(0))?;
+        let count: u32 = stmt.query_row(
+            params![
+                network.to_string(),
+                address,
+                HistoryKind::Estimated.as_str()
+            ],
+            |row| row.get(0),
+        )?;
         Ok(count)
     }
 
-    /// Delete all history entries for an address (all networks).
+    /// Delete all history entries and payout events for an address (all
+    /// networks, both kinds).
     pub fn delete_address_history(&self, address: &str) -> Result<u32> {
         let deleted = self.conn.execute(
             "DELETE FROM staking_history WHERE address = ?1",
             params![address],
         )?;
-        Ok(deleted as u32)
+        let payouts = self.conn.execute(
+            "DELETE FROM payout_events WHERE address = ?1",
+            params![address],
+        )?;
+        self.conn.execute(
+            "DELETE FROM payout_scan_cursors WHERE address = ?1",
+            params![address],
+        )?;
+        Ok((deleted + payouts) as u32)
     }
 
-    /// Delete old history entries beyond a certain count.
+    /// Delete estimated and exact entries outside the latest `keep_count` eras.
     pub fn prune_history(&self, network: Network, address: &str, keep_count: u32) -> Result<u32> {
         let deleted = self.conn.execute(
             r#"
             DELETE FROM staking_history
             WHERE network = ?1 AND address = ?2
             AND era NOT IN (
-                SELECT era FROM staking_history
+                SELECT DISTINCT era FROM staking_history
                 WHERE network = ?1 AND address = ?2
                 ORDER BY era DESC
                 LIMIT ?3
@@ -1446,9 +1747,9 @@ impl StakingDb {
         }
 
         let existing = self
-            .get_cache_snapshot(network, CacheKind::Validators)?
+            .get_cache_snapshot(network.clone(), CacheKind::Validators)?
             .map(|snapshot| snapshot.row_count as usize)
-            .unwrap_or_else(|| self.count_cached_validators(network).unwrap_or(0) as usize);
+            .unwrap_or_else(|| self.count_cached_validators(network.clone()).unwrap_or(0) as usize);
 
         if existing == 0 {
             return Ok(true);
@@ -1526,7 +1827,7 @@ impl StakingDb {
         max_age_secs: i64,
     ) -> Result<Vec<DisplayValidator>> {
         if !Self::snapshot_is_usable(
-            self.get_cache_snapshot(network, CacheKind::Validators)?,
+            self.get_cache_snapshot(network.clone(), CacheKind::Validators)?,
             None,
             max_age_secs,
             DEFAULT_STARTUP_CACHE_MAX_ERA_LAG,
@@ -1545,7 +1846,7 @@ impl StakingDb {
         max_era_lag: u32,
     ) -> Result<Vec<DisplayValidator>> {
         if !Self::snapshot_is_usable(
-            self.get_cache_snapshot(network, CacheKind::Validators)?,
+            self.get_cache_snapshot(network.clone(), CacheKind::Validators)?,
             Some(current_era),
             max_age_secs,
             max_era_lag,
@@ -1562,8 +1863,8 @@ impl StakingDb {
         current_era: u32,
         policy: CachePolicy,
     ) -> Result<Cached<Vec<DisplayValidator>>> {
-        let snapshot = self.get_cache_snapshot(network, CacheKind::Validators)?;
-        let current_metadata = self.get_chain_metadata(network)?;
+        let snapshot = self.get_cache_snapshot(network.clone(), CacheKind::Validators)?;
+        let current_metadata = self.get_chain_metadata(network.clone())?;
         let freshness = Self::startup_freshness(
             snapshot.as_ref(),
             current_era,
@@ -1605,7 +1906,7 @@ impl StakingDb {
         validators: &[DisplayValidator],
         complete: bool,
     ) -> Result<usize> {
-        if !self.should_replace_validator_cache(network, validators.len(), complete)? {
+        if !self.should_replace_validator_cache(network.clone(), validators.len(), complete)? {
             return Ok(0);
         }
 
@@ -1716,7 +2017,7 @@ impl StakingDb {
         max_age_secs: i64,
     ) -> Result<Vec<DisplayPool>> {
         if !Self::snapshot_is_usable(
-            self.get_cache_snapshot(network, CacheKind::Pools)?,
+            self.get_cache_snapshot(network.clone(), CacheKind::Pools)?,
             None,
             max_age_secs,
             DEFAULT_STARTUP_CACHE_MAX_ERA_LAG,
@@ -1735,7 +2036,7 @@ impl StakingDb {
         max_era_lag: u32,
     ) -> Result<Vec<DisplayPool>> {
         if !Self::snapshot_is_usable(
-            self.get_cache_snapshot(network, CacheKind::Pools)?,
+            self.get_cache_snapshot(network.clone(), CacheKind::Pools)?,
             Some(current_era),
             max_age_secs,
             max_era_lag,
@@ -1752,8 +2053,8 @@ impl StakingDb {
         current_era: u32,
         policy: CachePolicy,
     ) -> Result<Cached<Vec<DisplayPool>>> {
-        let snapshot = self.get_cache_snapshot(network, CacheKind::Pools)?;
-        let current_metadata = self.get_chain_metadata(network)?;
+        let snapshot = self.get_cache_snapshot(network.clone(), CacheKind::Pools)?;
+        let current_metadata = self.get_chain_metadata(network.clone())?;
         let freshness = Self::startup_freshness(
             snapshot.as_ref(),
             current_era,
@@ -1845,7 +2146,8 @@ impl StakingDb {
         let mut stmt = self.conn.prepare(
             r#"
             SELECT genesis_hash, spec_version, tx_version, ss58_prefix,
-                   token_symbol, token_decimals, era_duration_ms, current_era
+                   token_symbol, token_decimals, era_duration_ms, current_era,
+                   staking_params_json
             FROM chain_metadata
             WHERE network = ?1
             "#,
@@ -1861,6 +2163,9 @@ impl StakingDb {
                 token_decimals: row.get(5)?,
                 era_duration_ms: row.get::<_, i64>(6)? as u64,
                 current_era: row.get::<_, i64>(7)? as u32,
+                staking_params: row
+                    .get::<_, Option<String>>(8)?
+                    .and_then(|json| serde_json::from_str(&json).ok()),
             })
         });
 
@@ -1877,8 +2182,9 @@ impl StakingDb {
             r#"
             INSERT OR REPLACE INTO chain_metadata
                 (network, genesis_hash, spec_version, tx_version, ss58_prefix,
-                 token_symbol, token_decimals, era_duration_ms, current_era, updated_at)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, CURRENT_TIMESTAMP)
+                 token_symbol, token_decimals, era_duration_ms, current_era,
+                 staking_params_json, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, CURRENT_TIMESTAMP)
             "#,
             params![
                 network.to_string(),
@@ -1890,6 +2196,9 @@ impl StakingDb {
                 meta.token_decimals,
                 meta.era_duration_ms as i64,
                 meta.current_era as i64,
+                meta.staking_params
+                    .as_ref()
+                    .and_then(|params| serde_json::to_string(params).ok()),
             ],
         )?;
         Ok(())
@@ -2007,6 +2316,376 @@ impl StakingDb {
         )?;
         Ok(())
     }
+
+    // ==================== Transaction Journal ====================
+
+    /// Record a submitted transaction and return its journal id.
+    pub fn record_transaction(&self, network: Network, tx: &NewTransaction) -> Result<i64> {
+        let (era_period, era_phase) = match tx.mortality {
+            TxMortality::Immortal => (None, None),
+            TxMortality::Mortal { period, phase } => (Some(period as i64), Some(phase as i64)),
+        };
+        self.conn.execute(
+            r#"
+            INSERT INTO transactions
+                (network, signer, description, call_data, nonce, era_period, era_phase,
+                 tx_hash, status)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+            "#,
+            params![
+                network.to_string(),
+                &tx.signer,
+                &tx.description,
+                &tx.call_data,
+                tx.nonce as i64,
+                era_period,
+                era_phase,
+                &tx.tx_hash,
+                TxJournalStatus::Submitted.as_str(),
+            ],
+        )?;
+        Ok(self.conn.last_insert_rowid())
+    }
+
+    /// Record a status change of a journaled transaction.
+    ///
+    /// `completed_at` is set once the status is terminal. Returns false if no
+    /// transaction with `id` exists.
+    pub fn set_transaction_status(
+        &self,
+        id: i64,
+        status: TxJournalStatus,
+        block_hash: Option<&str>,
+        dispatch_result: Option<&str>,
+    ) -> Result<bool> {
+        let updated = self.conn.execute(
+            r#"
+            UPDATE transactions
+            SET status = ?2,
+                block_hash = COALESCE(?3, block_hash),
+                dispatch_result = COALESCE(?4, dispatch_result),
+                completed_at = CASE WHEN ?5 THEN CURRENT_TIMESTAMP ELSE completed_at END
+            WHERE id = ?1
+            "#,
+            params![
+                id,
+                status.as_str(),
+                block_hash,
+                dispatch_result,
+                status.is_terminal()
+            ],
+        )?;
+        Ok(updated > 0)
+    }
+
+    /// Get a journaled transaction on `network` by id.
+    pub fn get_transaction(&self, network: Network, id: i64) -> Result<Option<TransactionRecord>> {
+        self.conn
+            .query_row(
+                &format!("{} WHERE network = ?1 AND id = ?2", TRANSACTION_SELECT),
+                params![network.to_string(), id],
+                read_transaction_record(&network),
+            )
+            .optional()
+    }
+
+    /// List journaled transactions for a network, newest first.
+    pub fn get_transactions(
+        &self,
+        network: Network,
+        filter: &TransactionFilter,
+    ) -> Result<Vec<TransactionRecord>> {
+        let mut stmt = self.conn.prepare(&format!(
+            r#"
+            {}
+            WHERE network = ?1
+              AND (?2 IS NULL OR signer = ?2)
+              AND (?3 IS NULL OR status = ?3)
+            ORDER BY id DESC
+            "#,
+            TRANSACTION_SELECT
+        ))?;
+        let rows = stmt.query_map(
+            params![
+                network.to_string(),
+                filter.signer.as_deref(),
+                filter.status.map(|s| s.as_str()),
+            ],
+            read_transaction_record(&network),
+        )?;
+
+        let limit = filter.limit.map(|l| l as usize).unwrap_or(usize::MAX);
+        let mut records = Vec::new();
+        for row in rows {
+            let record = row?;
+            if filter.matches(&record) {
+                records.push(record);
+                if records.len() >= limit {
+                    break;
+                }
+            }
+        }
+        Ok(records)
+    }
+
+    /// Count journaled transactions signed by an address.
+    pub fn count_transactions(&self, network: Network, signer: &str) -> Result<u32> {
+        self.conn.query_row(
+            "SELECT COUNT(*) FROM transactions WHERE network = ?1 AND signer = ?2",
+            params![network.to_string(), signer],
+            |row| row.get(0),
+        )
+    }
+}
+
+// ==================== Endpoint Health ====================
+
+impl StakingDb {
+    /// Load persisted health stats for every endpoint of a network.
+    pub fn get_endpoint_health(&self, network: Network) -> Result<Vec<EndpointHealth>> {
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT role, url, latency_ms, best_block_lag, genesis_ok, successes, failures,
+                   last_probed
+            FROM endpoint_health
+            WHERE network = ?1
+            ORDER BY role, url
+            "#,
+        )?;
+        let rows = stmt.query_map(params![network.to_string()], |row| {
+            let role: String = row.get(0)?;
+            let role = role.parse::<ChainRole>().map_err(|err| {
+                rusqlite::Error::FromSqlConversionFailure(
+                    0,
+                    rusqlite::types::Type::Text,
+                    err.into(),
+                )
+            })?;
+            Ok(EndpointHealth {
+                role,
+                url: row.get(1)?,
+                latency_ms: row.get::<_, Option<i64>>(2)?.map(|v| v as u64),
+                best_block_lag: row.get::<_, Option<i64>>(3)?.map(|v| v as u32),
+                genesis_ok: row.get(4)?,
+                successes: row.get::<_, i64>(5)? as u32,
+                failures: row.get::<_, i64>(6)? as u32,
+                last_probed: row.get(7)?,
+            })
+        })?;
+        rows.collect()
+    }
+
+    /// Insert or replace health stats for endpoints of a network.
+    pub fn set_endpoint_health(
+        &mut self,
+        network: Network,
+        health: &[EndpointHealth],
+    ) -> Result<()> {
+        let tx = self.conn.transaction()?;
+        {
+            let mut stmt = tx.prepare(
+                r#"
+                INSERT OR REPLACE INTO endpoint_health
+                    (network, role, url, latency_ms, best_block_lag, genesis_ok, successes,
+                     failures, last_probed)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+                "#,
+            )?;
+            for h in health {
+                stmt.execute(params![
+                    network.to_string(),
+                    h.role.as_str(),
+                    &h.url,
+                    h.latency_ms.map(|v| v as i64),
+                    h.best_block_lag.map(|v| v as i64),
+                    h.genesis_ok,
+                    h.successes as i64,
+                    h.failures as i64,
+                    h.last_probed,
+                ])?;
+            }
+        }
+        tx.commit()
+    }
+}
+
+// ==================== Payout Events ====================
+
+impl StakingDb {
+    /// Store payout events of an address; events already stored are replaced.
+    pub fn insert_payout_events(
+        &mut self,
+        network: Network,
+        address: &str,
+        events: &[PayoutEvent],
+    ) -> Result<()> {
+        let tx = self.conn.transaction()?;
+        {
+            let mut stmt = tx.prepare(
+                r#"
+                INSERT OR REPLACE INTO payout_events
+                    (network, address, block_number, event_index, source, era, validator,
+                     pool_id, amount)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+                "#,
+            )?;
+            let network_str = network.to_string();
+            for event in events {
+                stmt.execute(params![
+                    &network_str,
+                    address,
+                    event.block_number as i64,
+                    event.event_index,
+                    event.source.as_str(),
+                    event.era,
+                    &event.validator,
+                    event.pool_id,
+                    event.amount.to_string(),
+                ])?;
+            }
+        }
+        tx.commit()
+    }
+
+    /// Payout events of an address, oldest first.
+    ///
+    /// With an era range, only staking payouts for those eras are returned;
+    /// pool payouts carry no era.
+    pub fn get_payout_events(
+        &self,
+        network: Network,
+        address: &str,
+        eras: Option<(u32, u32)>,
+    ) -> Result<Vec<PayoutEvent>> {
+        let (from_era, to_era) = match eras {
+            Some((from, to)) => (Some(from), Some(to)),
+            None => (None, None),
+        };
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT block_number, event_index, source, era, validator, pool_id, amount
+            FROM payout_events
+            WHERE network = ?1 AND address = ?2
+              AND (?3 IS NULL OR (era >= ?3 AND era <= ?4))
+            ORDER BY block_number ASC, event_index ASC
+            "#,
+        )?;
+        let rows = stmt.query_map(
+            params![network.to_string(), address, from_era, to_era],
+            |row| {
+                let source: String = row.get(2)?;
+                let source = PayoutSource::parse(&source).ok_or_else(|| {
+                    rusqlite::Error::FromSqlConversionFailure(
+                        2,
+                        rusqlite::types::Type::Text,
+                        format!("unknown payout source '{}'", source).into(),
+                    )
+                })?;
+                Ok(PayoutEvent {
+                    source,
+                    block_number: row.get::<_, i64>(0)? as u64,
+                    event_index: row.get(1)?,
+                    era: row.get(3)?,
+                    validator: row.get(4)?,
+                    pool_id: row.get(5)?,
+                    amount: read_u128(row, 6)?,
+                })
+            },
+        )?;
+        rows.collect()
+    }
+
+    /// Blocks scanned for payout events of an address.
+    pub fn get_payout_scan_cursor(
+        &self,
+        network: Network,
+        address: &str,
+    ) -> Result<Option<PayoutScan>> {
+        self.conn
+            .query_row(
+                r#"
+                SELECT first_block, last_block FROM payout_scan_cursors
+                WHERE network = ?1 AND address = ?2
+                "#,
+                params![network.to_string(), address],
+                |row| {
+                    Ok(PayoutScan {
+                        first_block: row.get::<_, i64>(0)? as u64,
+                        last_block: row.get::<_, i64>(1)? as u64,
+                    })
+                },
+            )
+            .optional()
+    }
+
+    /// Record the blocks scanned for payout events of an address.
+    pub fn set_payout_scan_cursor(
+        &self,
+        network: Network,
+        address: &str,
+        scan: PayoutScan,
+    ) -> Result<()> {
+        self.conn.execute(
+            r#"
+            INSERT OR REPLACE INTO payout_scan_cursors (network, address, first_block, last_block)
+            VALUES (?1, ?2, ?3, ?4)
+            "#,
+            params![
+                network.to_string(),
+                address,
+                scan.first_block as i64,
+                scan.last_block as i64
+            ],
+        )?;
+        Ok(())
+    }
+}
+
+const TRANSACTION_SELECT: &str = r#"
+    SELECT id, network, signer, description, call_data, nonce, era_period, era_phase,
+           tx_hash, block_hash, status, dispatch_result, submitted_at, completed_at
+    FROM transactions
+"#;
+
+/// Row reader for `TRANSACTION_SELECT` queries filtered to `network`.
+///
+/// The stored network name cannot be resolved back to a custom network's
+/// definition, so the records take the queried network.
+fn read_transaction_record(
+    network: &Network,
+) -> impl Fn(&rusqlite::Row<'_>) -> Result<TransactionRecord> + '_ {
+    move |row| read_transaction_row(network.clone(), row)
+}
+
+fn read_transaction_row(network: Network, row: &rusqlite::Row<'_>) -> Result<TransactionRecord> {
+    let status: String = row.get(10)?;
+    let status = status.parse::<TxJournalStatus>().map_err(|err| {
+        rusqlite::Error::FromSqlConversionFailure(10, rusqlite::types::Type::Text, err.into())
+    })?;
+    let era_period: Option<i64> = row.get(6)?;
+    let era_phase: Option<i64> = row.get(7)?;
+    let mortality = match era_period {
+        Some(period) => TxMortality::Mortal {
+            period: period as u64,
+            phase: era_phase.unwrap_or_default() as u64,
+        },
+        None => TxMortality::Immortal,
+    };
+    Ok(TransactionRecord {
+        id: row.get(0)?,
+        network,
+        signer: row.get(2)?,
+        description: row.get(3)?,
+        call_data: row.get(4)?,
+        nonce: row.get::<_, i64>(5)? as u64,
+        mortality,
+        tx_hash: row.get(8)?,
+        block_hash: row.get(9)?,
+        status,
+        dispatch_result: row.get(11)?,
+        submitted_at: row.get(12)?,
+        completed_at: row.get(13)?,
+    })
 }
 
 impl StartupDataService {
@@ -2018,7 +2697,7 @@ impl StartupDataService {
         policy: CachePolicy,
     ) -> Result<StartupDataCache> {
         Ok(StartupDataCache {
-            validators: db.get_startup_cached_validators(network, current_era, policy)?,
+            validators: db.get_startup_cached_validators(network.clone(), current_era, policy)?,
             pools: db.get_startup_cached_pools(network, current_era, policy)?,
         })
     }
@@ -2099,6 +2778,44 @@ impl HistoryService {
         )
     }
 
+    /// Load exact per-era rewards for an inclusive era range, filling missing
+    /// date strings for display.
+    #[allow(clippy::too_many_arguments)]
+    pub fn load_exact_range(
+        db: &StakingDb,
+        network: Network,
+        address: &str,
+        start_era: u32,
+        end_era: u32,
+        current_era: u32,
+        current_era_start_ms: u64,
+        era_duration_ms: u64,
+    ) -> Result<Vec<StakingHistoryPoint>> {
+        let mut history = db.get_exact_history_range(network, address, start_era, end_era)?;
+        for point in &mut history {
+            if point.date.is_none() {
+                point.date = Some(Self::calculate_era_date(
+                    point.era,
+                    current_era,
+                    current_era_start_ms,
+                    era_duration_ms,
+                ));
+            }
+        }
+        Ok(history)
+    }
+
+    /// Return eras in the range without a stored exact reward.
+    pub fn missing_exact_eras(
+        db: &StakingDb,
+        network: Network,
+        address: &str,
+        start_era: u32,
+        end_era: u32,
+    ) -> Result<Vec<u32>> {
+        db.get_missing_exact_eras(network, address, start_era, end_era)
+    }
+
     /// Load the latest cached history points for fallback display.
     pub fn load_latest(
         db: &StakingDb,
@@ -2203,6 +2920,7 @@ mod tests {
             token_decimals: 10,
             era_duration_ms: 86_400_000,
             current_era,
+            staking_params: None,
         }
     }
 
@@ -2523,6 +3241,172 @@ mod tests {
         assert_eq!(history[0].bonded, i64::MAX as u128 + 7);
     }
 
+    #[test]
+    fn test_exact_history_kept_apart_from_estimates() {
+        let mut db = StakingDb::open_memory().unwrap();
+        let estimated: Vec<_> = (10..13).map(make_test_point).collect();
+        db.insert_history_batch(Network::Polkadot, "addr1", &estimated)
+            .unwrap();
+        let exact = StakingHistoryPoint {
+            reward: 900_000_000_000,
+            ..make_test_point(11)
+        };
+        db.insert_exact_history_batch(Network::Polkadot, "addr1", &[exact])
+            .unwrap();
+
+        let history = db
+            .get_history_range(Network::Polkadot, "addr1", 10, 12)
+            .unwrap();
+        assert_eq!(history.len(), 3);
+        assert!(history.iter().all(|p| p.reward == 1_000_000_000_000));
+        assert_eq!(db.count_history(Network::Polkadot, "addr1").unwrap(), 3);
+
+        let exact = db
+            .get_exact_history_range(Network::Polkadot, "addr1", 10, 12)
+            .unwrap();
+        assert_eq!(exact.len(), 1);
+        assert_eq!(exact[0].reward, 900_000_000_000);
+        assert_eq!(
+            db.get_missing_exact_eras(Network::Polkadot, "addr1", 10, 12)
+                .unwrap(),
+            vec![10, 12]
+        );
+
+        assert_eq!(db.prune_history(Network::Polkadot, "addr1", 1).unwrap(), 3);
+        assert!(
+            db.get_exact_history_range(Network::Polkadot, "addr1", 10, 12)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_v9_migration_adds_history_kind() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            r#"
+            CREATE TABLE staking_history (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                network TEXT NOT NULL,
+                address TEXT NOT NULL,
+                era INTEGER NOT NULL,
+                date TEXT,
+                reward TEXT NOT NULL,
+                bonded TEXT NOT NULL,
+                apy REAL NOT NULL,
+                created_at TEXT DEFAULT CURRENT_TIMESTAMP,
+                UNIQUE(network, address, era)
+            );
+            INSERT INTO staking_history (network, address, era, date, reward, bonded, apy)
+            VALUES ('Polkadot', 'addr1', 1500, '20250101', '1000', '100000', 0.15);
+            PRAGMA user_version = 8;
+            "#,
+        )
+        .unwrap();
+
+        let mut db = StakingDb { conn };
+        db.init_schema().unwrap();
+
+        let history = db.get_history(Network::Polkadot, "addr1", None).unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].reward, 1000);
+
+        let exact = StakingHistoryPoint::new(1500, "20250101".to_string(), 990, 100_000, 0.14);
+        db.insert_exact_history_batch(Network::Polkadot, "addr1", &[exact])
+            .unwrap();
+        assert_eq!(
+            db.get_exact_history_range(Network::Polkadot, "addr1", 1500, 1500)
+                .unwrap()[0]
+                .reward,
+            990
+        );
+
+        let version: i32 = db
+            .conn
+            .query_row("PRAGMA user_version", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(version, SCHEMA_VERSION);
+    }
+
+    // ==================== Payout Event Tests ====================
+
+    fn payout(block_number: u64, era: Option<u32>, amount: u128) -> PayoutEvent {
+        PayoutEvent {
+            source: PayoutSource::Staking,
+            block_number,
+            event_index: 3,
+            era,
+            validator: era.map(|_| "val1".to_string()),
+            pool_id: None,
+            amount,
+        }
+    }
+
+    #[test]
+    fn test_payout_events_roundtrip() {
+        let mut db = StakingDb::open_memory().unwrap();
+        let pool = PayoutEvent {
+            source: PayoutSource::Pool,
+            pool_id: Some(7),
+            ..payout(150, None, 5)
+        };
+        let events = [
+            payout(100, Some(10), i64::MAX as u128 + 1),
+            pool,
+            payout(200, Some(11), 20),
+        ];
+        db.insert_payout_events(Network::Polkadot, "addr1", &events)
+            .unwrap();
+        // Re-inserting the same events does not duplicate them.
+        db.insert_payout_events(Network::Polkadot, "addr1", &events[..1])
+            .unwrap();
+
+        let all = db
+            .get_payout_events(Network::Polkadot, "addr1", None)
+            .unwrap();
+        assert_eq!(all, events.to_vec());
+        let era_10 = db
+            .get_payout_events(Network::Polkadot, "addr1", Some((10, 10)))
+            .unwrap();
+        assert_eq!(era_10, vec![events[0].clone()]);
+        assert!(
+            db.get_payout_events(Network::Kusama, "addr1", None)
+                .unwrap()
+                .is_empty()
+        );
+
+        assert_eq!(
+            db.get_payout_scan_cursor(Network::Polkadot, "addr1")
+                .unwrap(),
+            None
+        );
+        let scan = |first_block, last_block| PayoutScan {
+            first_block,
+            last_block,
+        };
+        db.set_payout_scan_cursor(Network::Polkadot, "addr1", scan(500, 1_000))
+            .unwrap();
+        db.set_payout_scan_cursor(Network::Polkadot, "addr1", scan(500, 2_000))
+            .unwrap();
+        assert_eq!(
+            db.get_payout_scan_cursor(Network::Polkadot, "addr1")
+                .unwrap(),
+            Some(scan(500, 2_000))
+        );
+
+        assert_eq!(db.delete_address_history("addr1").unwrap(), 3);
+        assert!(
+            db.get_payout_events(Network::Polkadot, "addr1", None)
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            db.get_payout_scan_cursor(Network::Polkadot, "addr1")
+                .unwrap(),
+            None
+        );
+    }
+
     // ==================== Validator Identity Tests ====================
 
     #[test]
@@ -3280,6 +4164,7 @@ mod tests {
             token_decimals: 10,
             era_duration_ms: 86400000,
             current_era: 1500,
+            staking_params: None,
         };
 
         db.set_chain_metadata(Network::Polkadot, &meta).unwrap();
@@ -3292,6 +4177,35 @@ mod tests {
         assert_eq!(loaded.token_symbol, "DOT");
     }
 
+    #[test]
+    fn test_chain_metadata_staking_params_round_trip() {
+        let db = StakingDb::open_memory().unwrap();
+        let params = StakingParams {
+            min_nominator_bond: 2_500_000_000_000,
+            min_active_stake: 5_000_000_000_000,
+            max_nominators: Some(50_000),
+            nominator_count: 30_000,
+            max_exposure_page_size: 512,
+            min_join_bond: 10_000_000_000,
+            min_create_bond: 5_000_000_000_000,
+            bonding_duration: 28,
+        };
+        let meta = CachedChainMetadata {
+            staking_params: Some(params.clone()),
+            ..make_test_metadata(1500)
+        };
+        db.set_chain_metadata(Network::Polkadot, &meta).unwrap();
+
+        let loaded = db.get_chain_metadata(Network::Polkadot).unwrap().unwrap();
+        assert_eq!(loaded.staking_params, Some(params));
+
+        // Metadata written without parameters reads back as None
+        db.set_chain_metadata(Network::Polkadot, &make_test_metadata(1501))
+            .unwrap();
+        let loaded = db.get_chain_metadata(Network::Polkadot).unwrap().unwrap();
+        assert_eq!(loaded.staking_params, None);
+    }
+
     #[test]
     fn test_chain_metadata_not_found() {
         let db = StakingDb::open_memory().unwrap();
@@ -3573,6 +4487,7 @@ mod tests {
             token_decimals: 10,
             era_duration_ms: 86400000,
             current_era: 1500,
+            staking_params: None,
         };
         let meta_clone = meta.clone();
         assert_eq!(meta.genesis_hash, meta_clone.genesis_hash);
@@ -3930,4 +4845,269 @@ mod tests {
             .unwrap();
         assert_eq!(version, SCHEMA_VERSION);
     }
+
+    // ==================== Transaction Journal Tests ====================
+
+    const JOURNAL_SIGNER: &str = "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5";
+
+    fn make_new_transaction(signer: &str, description: &str, nonce: u64) -> NewTransaction {
+        NewTransaction {
+            signer: signer.to_string(),
+            description: description.to_string(),
+            call_data: "0x0703".to_string(),
+            nonce,
+            mortality: TxMortality::Mortal {
+                period: 64,
+                phase: 5,
+            },
+            tx_hash: format!("0x{:064x}", nonce),
+        }
+    }
+
+    #[test]
+    fn test_record_and_get_transaction() {
+        let db = StakingDb::open_memory().unwrap();
+        let id = db
+            .record_transaction(
+                Network::Polkadot,
+                &make_new_transaction(JOURNAL_SIGNER, "Bond 10 DOT", 4),
+            )
+            .unwrap();
+
+        let record = db.get_transaction(Network::Polkadot, id).unwrap().unwrap();
+        assert_eq!(record.network, Network::Polkadot);
+        assert_eq!(record.signer, JOURNAL_SIGNER);
+        assert_eq!(record.description, "Bond 10 DOT");
+        assert_eq!(record.nonce, 4);
+        assert_eq!(
+            record.mortality,
+            TxMortality::Mortal {
+                period: 64,
+                phase: 5
+            }
+        );
+        assert_eq!(record.status, TxJournalStatus::Submitted);
+        assert!(record.block_hash.is_none());
+        assert!(record.completed_at.is_none());
+        assert!(!record.submitted_at.is_empty());
+
+        assert!(
+            db.get_transaction(Network::Polkadot, id + 1)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_record_immortal_transaction() {
+        let db = StakingDb::open_memory().unwrap();
+        let mut tx = make_new_transaction(JOURNAL_SIGNER, "Chill", 0);
+        tx.mortality = TxMortality::Immortal;
+        let id = db.record_transaction(Network::Westend, &tx).unwrap();
+        assert!(db.get_transaction(Network::Polkadot, id).unwrap().is_none());
+        let record = db.get_transaction(Network::Westend, id).unwrap().unwrap();
+        assert_eq!(record.mortality, TxMortality::Immortal);
+        assert_eq!(record.network, Network::Westend);
+    }
+
+    #[test]
+    fn test_set_transaction_status() {
+        let db = StakingDb::open_memory().unwrap();
+        let id = db
+            .record_transaction(
+                Network::Polkadot,
+                &make_new_transaction(JOURNAL_SIGNER, "Nominate 16 validators", 1),
+            )
+            .unwrap();
+
+        assert!(
+            db.set_transaction_status(
+                id,
+                TxJournalStatus::Finalized,
+                Some("0xbeef"),
+                Some("Success")
+            )
+            .unwrap()
+        );
+        let record = db.get_transaction(Network::Polkadot, id).unwrap().unwrap();
+        assert_eq!(record.status, TxJournalStatus::Finalized);
+        assert_eq!(record.block_hash.as_deref(), Some("0xbeef"));
+        assert_eq!(record.dispatch_result.as_deref(), Some("Success"));
+        assert!(record.completed_at.is_some());
+
+        assert!(
+            !db.set_transaction_status(id + 10, TxJournalStatus::Failed, None, None)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_in_block_status_is_not_completed() {
+        let db = StakingDb::open_memory().unwrap();
+        let id = db
+            .record_transaction(
+                Network::Polkadot,
+                &make_new_transaction(JOURNAL_SIGNER, "Bond", 1),
+            )
+            .unwrap();
+        db.set_transaction_status(id, TxJournalStatus::InBlock, Some("0xbeef"), None)
+            .unwrap();
+        let record = db.get_transaction(Network::Polkadot, id).unwrap().unwrap();
+        assert_eq!(record.status, TxJournalStatus::InBlock);
+        assert_eq!(record.block_hash.as_deref(), Some("0xbeef"));
+        assert!(record.completed_at.is_none());
+    }
+
+    #[test]
+    fn test_set_transaction_status_keeps_block_hash() {
+        let db = StakingDb::open_memory().unwrap();
+        let id = db
+            .record_transaction(
+                Network::Polkadot,
+                &make_new_transaction(JOURNAL_SIGNER, "Unbond", 2),
+            )
+            .unwrap();
+        db.set_transaction_status(id, TxJournalStatus::Finalized, Some("0xbeef"), None)
+            .unwrap();
+        db.set_transaction_status(id, TxJournalStatus::Failed, None, Some("Dropped"))
+            .unwrap();
+        let record = db.get_transaction(Network::Polkadot, id).unwrap().unwrap();
+        assert_eq!(record.block_hash.as_deref(), Some("0xbeef"));
+        assert_eq!(record.dispatch_result.as_deref(), Some("Dropped"));
+    }
+
+    #[test]
+    fn test_get_transactions_filters() {
+        let db = StakingDb::open_memory().unwrap();
+        let first = db
+            .record_transaction(
+                Network::Polkadot,
+                &make_new_transaction(JOURNAL_SIGNER, "Bond 10 DOT", 1),
+            )
+            .unwrap();
+        let second = db
+            .record_transaction(
+                Network::Polkadot,
+                &make_new_transaction(JOURNAL_SIGNER, "Nominate 2 validators", 2),
+            )
+            .unwrap();
+        db.record_transaction(
+            Network::Polkadot,
+            &make_new_transaction("other", "Bond 5 DOT", 1),
+        )
+        .unwrap();
+        db.record_transaction(
+            Network::Kusama,
+            &make_new_transaction(JOURNAL_SIGNER, "Bond 1 KSM", 1),
+        )
+        .unwrap();
+        db.set_transaction_status(first, TxJournalStatus::Failed, None, Some("Invalid"))
+            .unwrap();
+
+        let all = db
+            .get_transactions(Network::Polkadot, &TransactionFilter::default())
+            .unwrap();
+        assert_eq!(all.len(), 3);
+
+        let mine = db
+            .get_transactions(
+                Network::Polkadot,
+                &TransactionFilter::for_signer(JOURNAL_SIGNER),
+            )
+            .unwrap();
+        assert_eq!(
+            mine.iter().map(|r| r.id).collect::<Vec<_>>(),
+            vec![second, first]
+        );
+
+        let failed = db
+            .get_transactions(
+                Network::Polkadot,
+                &TransactionFilter {
+                    status: Some(TxJournalStatus::Failed),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].id, first);
+
+        let searched = db
+            .get_transactions(
+                Network::Polkadot,
+                &TransactionFilter {
+                    search: Some("bond".to_string()),
+                    limit: Some(1),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert_eq!(searched.len(), 1);
+        assert_eq!(searched[0].signer, "other");
+
+        assert_eq!(
+            db.count_transactions(Network::Polkadot, JOURNAL_SIGNER)
+                .unwrap(),
+            2
+        );
+        assert_eq!(
+            db.count_transactions(Network::Kusama, JOURNAL_SIGNER)
+                .unwrap(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_endpoint_health_roundtrip() {
+        let mut db = StakingDb::open_memory().unwrap();
+        assert!(
+            db.get_endpoint_health(Network::Polkadot)
+                .unwrap()
+                .is_empty()
+        );
+
+        let mut fast = EndpointHealth::new(ChainRole::AssetHub, "wss://fast");
+        fast.record_probe(80, 0, true, 1_700_000_000);
+        fast.record_success();
+        let mut down = EndpointHealth::new(ChainRole::Relay, "wss://down");
+        down.record_probe_failure(1_700_000_000);
+        db.set_endpoint_health(Network::Polkadot, &[fast.clone(), down.clone()])
+            .unwrap();
+
+        let loaded = db.get_endpoint_health(Network::Polkadot).unwrap();
+        assert_eq!(loaded, vec![fast.clone(), down]);
+        assert!(db.get_endpoint_health(Network::Kusama).unwrap().is_empty());
+
+        // Saving again replaces the row for the same endpoint
+        fast.record_failure();
+        db.set_endpoint_health(Network::Polkadot, std::slice::from_ref(&fast))
+            .unwrap();
+        let loaded = db.get_endpoint_health(Network::Polkadot).unwrap();
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loaded[0], fast);
+    }
+
+    #[test]
+    fn test_transactions_table_added_to_existing_db() {
+        let path = unique_test_db_path("journal-migration");
+        {
+            let conn = Connection::open(&path).unwrap();
+            conn.execute_batch("PRAGMA user_version = 5;").unwrap();
+        }
+        let db = StakingDb::open(&path).unwrap();
+        db.record_transaction(
+            Network::Polkadot,
+            &make_new_transaction(JOURNAL_SIGNER, "Bond", 0),
+        )
+        .unwrap();
+        let version: i32 = db
+            .conn
+            .query_row("PRAGMA user_version", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(version, SCHEMA_VERSION);
+        drop(db);
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(path.with_extension("db-wal"));
+        let _ = std::fs::remove_file(path.with_extension("db-shm"));
+    }
 }
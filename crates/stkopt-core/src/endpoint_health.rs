@@ -0,0 +1,252 @@
+//! RPC endpoint health statistics and ranking.
+//!
+//! Each endpoint is probed for round-trip latency, how far its best block
+//! trails the best endpoint, and whether it serves the expected genesis.
+//! Connection successes and failures are counted as the app runs. Endpoints
+//! are ranked by a score combining these, lower being better; endpoints on
+//! the wrong chain are never used.
+
+use serde::{Deserialize, Serialize};
+
+/// Latency assumed for endpoints that have not been probed yet.
+pub const UNPROBED_LATENCY_MS: u64 = 1_000;
+
+/// Score penalty per block an endpoint trails the best endpoint.
+pub const BLOCK_LAG_PENALTY_MS: f64 = 250.0;
+
+/// Score penalty for an endpoint that has only ever failed.
+pub const FAILURE_PENALTY_MS: f64 = 5_000.0;
+
+/// Probe results older than this are refreshed before ranking.
+pub const ENDPOINT_PROBE_MAX_AGE_SECS: i64 = 60 * 60;
+
+/// Which chain of a network an endpoint serves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ChainRole {
+    AssetHub,
+    Relay,
+    People,
+}
+
+impl ChainRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChainRole::AssetHub => "asset_hub",
+            ChainRole::Relay => "relay",
+            ChainRole::People => "people",
+        }
+    }
+}
+
+impl std::str::FromStr for ChainRole {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "asset_hub" => Ok(ChainRole::AssetHub),
+            "relay" => Ok(ChainRole::Relay),
+            "people" => Ok(ChainRole::People),
+            other => Err(format!("unknown chain role '{}'", other)),
+        }
+    }
+}
+
+impl std::fmt::Display for ChainRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChainRole::AssetHub => write!(f, "Asset Hub"),
+            ChainRole::Relay => write!(f, "relay chain"),
+            ChainRole::People => write!(f, "People chain"),
+        }
+    }
+}
+
+/// Health statistics for one endpoint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EndpointHealth {
+    pub role: ChainRole,
+    pub url: String,
+    /// Round-trip latency of the last probe.
+    pub latency_ms: Option<u64>,
+    /// Blocks the endpoint trailed the best endpoint at the last probe.
+    pub best_block_lag: Option<u32>,
+    /// Whether the endpoint served the expected genesis; `None` if unknown.
+    pub genesis_ok: Option<bool>,
+    pub successes: u32,
+    pub failures: u32,
+    /// Unix time (seconds) of the last probe.
+    pub last_probed: Option<i64>,
+}
+
+impl EndpointHealth {
+    pub fn new(role: ChainRole, url: impl Into<String>) -> Self {
+        Self {
+            role,
+            url: url.into(),
+            latency_ms: None,
+            best_block_lag: None,
+            genesis_ok: None,
+            successes: 0,
+            failures: 0,
+            last_probed: None,
+        }
+    }
+
+    /// Endpoints serving another chain are never used.
+    pub fn is_usable(&self) -> bool {
+        self.genesis_ok != Some(false)
+    }
+
+    /// Whether the endpoint should be probed again at `now`.
+    pub fn needs_probe(&self, now: i64) -> bool {
+        self.last_probed
+            .is_none_or(|probed| now - probed > ENDPOINT_PROBE_MAX_AGE_SECS)
+    }
+
+    /// Ranking score in milliseconds; lower is better.
+    pub fn score(&self) -> f64 {
+        let latency = self.latency_ms.unwrap_or(UNPROBED_LATENCY_MS) as f64;
+        let lag = self.best_block_lag.unwrap_or(0) as f64 * BLOCK_LAG_PENALTY_MS;
+        // +1 damps the rate while there are few attempts
+        let attempts = (self.successes + self.failures + 1) as f64;
+        let failure_rate = self.failures as f64 / attempts;
+        latency + lag + failure_rate * FAILURE_PENALTY_MS
+    }
+
+    /// Record a successful probe.
+    pub fn record_probe(
+        &mut self,
+        latency_ms: u64,
+        best_block_lag: u32,
+        genesis_ok: bool,
+        now: i64,
+    ) {
+        self.latency_ms = Some(latency_ms);
+        self.best_block_lag = Some(best_block_lag);
+        self.genesis_ok = Some(genesis_ok);
+        self.last_probed = Some(now);
+    }
+
+    /// Record a probe that could not reach the endpoint.
+    pub fn record_probe_failure(&mut self, now: i64) {
+        self.latency_ms = None;
+        self.best_block_lag = None;
+        self.failures = self.failures.saturating_add(1);
+        self.last_probed = Some(now);
+    }
+
+    pub fn record_success(&mut self) {
+        self.successes = self.successes.saturating_add(1);
+    }
+
+    pub fn record_failure(&mut self) {
+        self.failures = self.failures.saturating_add(1);
+    }
+}
+
+/// Order `urls` best first by their health, dropping unusable endpoints.
+///
+/// Endpoints without stats score as unprobed; ties keep the input order, so
+/// with no stats at all the static list order is preserved.
+pub fn rank_endpoints(urls: &[String], health: &[EndpointHealth]) -> Vec<String> {
+    let mut scored: Vec<(f64, &String)> = urls
+        .iter()
+        .filter_map(|url| match health.iter().find(|h| &h.url == url) {
+            Some(h) if !h.is_usable() => None,
+            Some(h) => Some((h.score(), url)),
+            None => Some((UNPROBED_LATENCY_MS as f64, url)),
+        })
+        .collect();
+    scored.sort_by(|a, b| a.0.total_cmp(&b.0));
+    scored.into_iter().map(|(_, url)| url.clone()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn urls(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn probed(url: &str, latency_ms: u64, lag: u32) -> EndpointHealth {
+        let mut health = EndpointHealth::new(ChainRole::AssetHub, url);
+        health.record_probe(latency_ms, lag, true, 1_000);
+        health
+    }
+
+    #[test]
+    fn test_rank_without_stats_keeps_order() {
+        let list = urls(&["wss://a", "wss://b", "wss://c"]);
+        assert_eq!(rank_endpoints(&list, &[]), list);
+    }
+
+    #[test]
+    fn test_rank_by_latency_and_lag() {
+        let list = urls(&["wss://a", "wss://b", "wss://c"]);
+        let health = vec![
+            probed("wss://a", 400, 0),
+            probed("wss://b", 100, 0),
+            // Fast but 3 blocks behind: 50 + 750
+            probed("wss://c", 50, 3),
+        ];
+        assert_eq!(
+            rank_endpoints(&list, &health),
+            urls(&["wss://b", "wss://a", "wss://c"])
+        );
+    }
+
+    #[test]
+    fn test_rank_drops_wrong_genesis() {
+        let list = urls(&["wss://a", "wss://b"]);
+        let mut wrong = probed("wss://a", 10, 0);
+        wrong.genesis_ok = Some(false);
+        assert_eq!(rank_endpoints(&list, &[wrong]), urls(&["wss://b"]));
+    }
+
+    #[test]
+    fn test_failures_demote_endpoint() {
+        let list = urls(&["wss://a", "wss://b"]);
+        let mut flaky = probed("wss://a", 100, 0);
+        flaky.record_failure();
+        let steady = probed("wss://b", 300, 0);
+        assert_eq!(
+            rank_endpoints(&list, &[flaky.clone(), steady.clone()]),
+            urls(&["wss://b", "wss://a"])
+        );
+
+        // A long success record outweighs an occasional failure
+        for _ in 0..40 {
+            flaky.record_success();
+        }
+        assert_eq!(
+            rank_endpoints(&list, &[flaky, steady]),
+            urls(&["wss://a", "wss://b"])
+        );
+    }
+
+    #[test]
+    fn test_probe_failure_counts_and_clears_latency() {
+        let mut health = probed("wss://a", 100, 0);
+        health.record_probe_failure(2_000);
+        assert_eq!(health.latency_ms, None);
+        assert_eq!(health.failures, 1);
+        assert_eq!(health.last_probed, Some(2_000));
+    }
+
+    #[test]
+    fn test_needs_probe() {
+        let health = probed("wss://a", 100, 0);
+        assert!(!health.needs_probe(1_000 + ENDPOINT_PROBE_MAX_AGE_SECS));
+        assert!(health.needs_probe(1_001 + ENDPOINT_PROBE_MAX_AGE_SECS));
+        assert!(EndpointHealth::new(ChainRole::Relay, "wss://x").needs_probe(0));
+    }
+
+    #[test]
+    fn test_chain_role_roundtrip() {
+        for role in [ChainRole::AssetHub, ChainRole::Relay, ChainRole::People] {
+            assert_eq!(role.as_str().parse::<ChainRole>(), Ok(role));
+        }
+        assert!("parachain".parse::<ChainRole>().is_err());
+    }
+}
@@ -0,0 +1,487 @@
+//! Nomination health check.
+//!
+//! [`check_health`] looks at an account's staking setup and lists everything
+//! that costs it rewards or needs attention: nominated validators that are
+//! chilled, blocked, inactive, slashed or keep all rewards as commission,
+//! unused nomination slots, withdrawable funds left idle, a bond below the
+//! minimum active stake and unclaimed pool rewards. Each [`HealthIssue`]
+//! carries a [`Severity`] and a suggested fix.
+
+use crate::apy::MAX_NOMINATIONS;
+use crate::display::{DisplayPool, DisplayValidator, format_token_balance};
+use crate::staking_params::StakingParams;
+use crate::types::{
+    Balance, EraIndex, NominationPoolMembership, PoolState, UnlockChunk, ValidatorPreferences,
+};
+
+/// How urgent a [`HealthIssue`] is, ordered from least to most urgent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// Worth knowing; rewards are not lost.
+    Info,
+    /// Rewards are reduced or funds sit idle.
+    Warning,
+    /// Some or all of the stake earns nothing.
+    Critical,
+}
+
+impl Severity {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Severity::Info => "Info",
+            Severity::Warning => "Warning",
+            Severity::Critical => "Critical",
+        }
+    }
+}
+
+/// What the chain says about one nominated validator.
+#[derive(Debug, Clone)]
+pub struct TargetStatus {
+    /// SS58-encoded address.
+    pub address: String,
+    /// Identity display name, if known.
+    pub name: Option<String>,
+    /// `Staking.Validators` entry, `None` when the validator is chilled.
+    pub preferences: Option<ValidatorPreferences>,
+    /// Whether the validator is in the current era's active set, `None` when
+    /// unknown.
+    pub active: Option<bool>,
+    /// Whether the validator has recorded slashing spans.
+    pub slashed: bool,
+}
+
+/// Account state checked by [`check_health`].
+#[derive(Debug, Clone, Default)]
+pub struct HealthAccount {
+    /// Active direct bond.
+    pub bonded: Balance,
+    /// Nominated validators.
+    pub targets: Vec<TargetStatus>,
+    /// Unlocking chunks of the direct bond.
+    pub unlocking: Vec<UnlockChunk>,
+    /// Nomination pool membership, with pending rewards.
+    pub pool: Option<NominationPoolMembership>,
+}
+
+/// A problem found by [`check_health`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HealthIssue {
+    /// Bonded but no validators nominated.
+    NotNominating,
+    /// A nominated validator stopped validating.
+    ChilledValidator { validator: String },
+    /// A nominated validator takes 100% commission.
+    FullCommission { validator: String },
+    /// A nominated validator blocks nominations.
+    BlockedValidator { validator: String },
+    /// A nominated validator has been slashed.
+    SlashedValidator { validator: String },
+    /// A nominated validator is not in the current active set.
+    InactiveValidator { validator: String },
+    /// Fewer targets than the chain allows.
+    FewNominations { count: usize },
+    /// Unlocked chunks that can be withdrawn.
+    IdleUnlocked { amount: Balance, chunks: usize },
+    /// The bond is below `MinimumActiveStake`.
+    BelowMinActiveStake { bonded: Balance, minimum: Balance },
+    /// The pool owes the member rewards.
+    UnclaimedPoolRewards { pool_id: u32, amount: Balance },
+    /// The member's pool is being destroyed.
+    PoolDestroying { pool_id: u32 },
+}
+
+impl HealthIssue {
+    pub fn severity(&self) -> Severity {
+        match self {
+            HealthIssue::NotNominating
+            | HealthIssue::FullCommission { .. }
+            | HealthIssue::BelowMinActiveStake { .. } => Severity::Critical,
+            HealthIssue::ChilledValidator { .. }
+            | HealthIssue::BlockedValidator { .. }
+            | HealthIssue::SlashedValidator { .. }
+            | HealthIssue::IdleUnlocked { .. }
+            | HealthIssue::PoolDestroying { .. } => Severity::Warning,
+            HealthIssue::InactiveValidator { .. }
+            | HealthIssue::FewNominations { .. }
+            | HealthIssue::UnclaimedPoolRewards { .. } => Severity::Info,
+        }
+    }
+
+    /// Message for display, with amounts in tokens.
+    pub fn message(&self, decimals: u8, symbol: &str) -> String {
+        let fmt = |amount| format_token_balance(amount, decimals, Some(symbol));
+        match self {
+            HealthIssue::NotNominating => "Bonded but not nominating any validator".to_string(),
+            HealthIssue::ChilledValidator { validator } => {
+                format!("{} is chilled and no longer validating", validator)
+            }
+            HealthIssue::FullCommission { validator } => {
+                format!("{} takes 100% commission", validator)
+            }
+            HealthIssue::BlockedValidator { validator } => {
+                format!("{} blocks nominations", validator)
+            }
+            HealthIssue::SlashedValidator { validator } => {
+                format!("{} has been slashed", validator)
+            }
+            HealthIssue::InactiveValidator { validator } => {
+                format!("{} is not in the active set this era", validator)
+            }
+            HealthIssue::FewNominations { count } => format!(
+                "Nominating {} of {} possible validators",
+                count, MAX_NOMINATIONS
+            ),
+            HealthIssue::IdleUnlocked { amount, chunks } => format!(
+                "{} in {} unlocked chunk(s) is ready to withdraw",
+                fmt(*amount),
+                chunks
+            ),
+            HealthIssue::BelowMinActiveStake { bonded, minimum } => format!(
+                "Bond of {} is below the minimum active stake of {}",
+                fmt(*bonded),
+                fmt(*minimum)
+            ),
+            HealthIssue::UnclaimedPoolRewards { pool_id, amount } => {
+                format!("{} of pool #{} rewards unclaimed", fmt(*amount), pool_id)
+            }
+            HealthIssue::PoolDestroying { pool_id } => {
+                format!("Pool #{} is being destroyed", pool_id)
+            }
+        }
+    }
+
+    /// Suggested fix for display.
+    pub fn fix(&self) -> &'static str {
+        match self {
+            HealthIssue::NotNominating => "Nominate validators or join a pool",
+            HealthIssue::ChilledValidator { .. } | HealthIssue::FullCommission { .. } => {
+                "Replace it with a validator from the optimizer"
+            }
+            HealthIssue::BlockedValidator { .. } => {
+                "Keep it, but pick another validator if you renominate"
+            }
+            HealthIssue::SlashedValidator { .. } => {
+                "Review its slash history and consider replacing it"
+            }
+            HealthIssue::InactiveValidator { .. } => {
+                "No action needed if other validators are active"
+            }
+            HealthIssue::FewNominations { .. } => "Run the optimizer to fill every slot",
+            HealthIssue::IdleUnlocked { .. } => "Withdraw unbonded funds",
+            HealthIssue::BelowMinActiveStake { .. } => "Bond extra or join a nomination pool",
+            HealthIssue::UnclaimedPoolRewards { .. } => "Claim pool rewards",
+            HealthIssue::PoolDestroying { .. } => "Unbond from the pool and join another",
+        }
+    }
+}
+
+/// Result of [`check_health`], most urgent issues first.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HealthReport {
+    pub issues: Vec<HealthIssue>,
+}
+
+impl HealthReport {
+    /// Whether no issue was found.
+    pub fn is_healthy(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Severity of the most urgent issue.
+    pub fn worst(&self) -> Option<Severity> {
+        self.issues.iter().map(HealthIssue::severity).max()
+    }
+
+    /// Number of issues at `severity`.
+    pub fn count(&self, severity: Severity) -> usize {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity() == severity)
+            .count()
+    }
+}
+
+/// Check `account` for problems.
+///
+/// `validators` resolves names for targets without one, `pools` the state of
+/// the member's pool. Bond checks are skipped without `params`, active-set
+/// checks for targets whose `active` is unknown.
+pub fn check_health(
+    account: &HealthAccount,
+    validators: &[DisplayValidator],
+    pools: &[DisplayPool],
+    params: Option<&StakingParams>,
+    current_era: EraIndex,
+) -> HealthReport {
+    let mut issues = Vec::new();
+
+    if account.bonded > 0 && account.targets.is_empty() {
+        issues.push(HealthIssue::NotNominating);
+    }
+
+    for target in &account.targets {
+        let validator = target_label(target, validators);
+        match &target.preferences {
+            None => issues.push(HealthIssue::ChilledValidator {
+                validator: validator.clone(),
+            }),
+            Some(prefs) => {
+                if prefs.commission >= 1.0 {
+                    issues.push(HealthIssue::FullCommission {
+                        validator: validator.clone(),
+                    });
+                }
+                if prefs.blocked {
+                    issues.push(HealthIssue::BlockedValidator {
+                        validator: validator.clone(),
+                    });
+                }
+                if target.active == Some(false) {
+                    issues.push(HealthIssue::InactiveValidator {
+                        validator: validator.clone(),
+                    });
+                }
+            }
+        }
+        if target.slashed {
+            issues.push(HealthIssue::SlashedValidator { validator });
+        }
+    }
+
+    let count = account.targets.len();
+    if count > 0 && count < MAX_NOMINATIONS {
+        issues.push(HealthIssue::FewNominations { count });
+    }
+
+    let withdrawable: Vec<_> = account
+        .unlocking
+        .iter()
+        .filter(|chunk| chunk.era <= current_era)
+        .collect();
+    if !withdrawable.is_empty() {
+        issues.push(HealthIssue::IdleUnlocked {
+            amount: withdrawable.iter().map(|chunk| chunk.value).sum(),
+            chunks: withdrawable.len(),
+        });
+    }
+
+    if let Some(params) = params
+        && account.bonded > 0
+        && account.bonded < params.min_active_stake
+    {
+        issues.push(HealthIssue::BelowMinActiveStake {
+            bonded: account.bonded,
+            minimum: params.min_active_stake,
+        });
+    }
+
+    if let Some(pool) = &account.pool {
+        if pool.pending_rewards > 0 {
+            issues.push(HealthIssue::UnclaimedPoolRewards {
+                pool_id: pool.pool_id,
+                amount: pool.pending_rewards,
+            });
+        }
+        if pools
+            .iter()
+            .any(|p| p.id == pool.pool_id && p.state == PoolState::Destroying)
+        {
+            issues.push(HealthIssue::PoolDestroying {
+                pool_id: pool.pool_id,
+            });
+        }
+    }
+
+    issues.sort_by_key(|issue| std::cmp::Reverse(issue.severity()));
+    HealthReport { issues }
+}
+
+/// Name of `target`, falling back to the validator set, then its address.
+fn target_label(target: &TargetStatus, validators: &[DisplayValidator]) -> String {
+    if let Some(name) = &target.name {
+        return name.clone();
+    }
+    validators
+        .iter()
+        .find(|v| v.address == target.address)
+        .map(|v| v.display_name().to_string())
+        .unwrap_or_else(|| target.address.chars().take(16).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(address: &str, commission: f64, blocked: bool) -> TargetStatus {
+        TargetStatus {
+            address: address.to_string(),
+            name: None,
+            preferences: Some(ValidatorPreferences {
+                commission,
+                blocked,
+            }),
+            active: Some(true),
+            slashed: false,
+        }
+    }
+
+    fn healthy_account() -> HealthAccount {
+        HealthAccount {
+            bonded: 1_000,
+            targets: (0..MAX_NOMINATIONS)
+                .map(|i| target(&format!("validator{}", i), 0.05, false))
+                .collect(),
+            unlocking: Vec::new(),
+            pool: None,
+        }
+    }
+
+    #[test]
+    fn test_healthy_account() {
+        let report = check_health(&healthy_account(), &[], &[], None, 100);
+        assert!(report.is_healthy());
+        assert_eq!(report.worst(), None);
+    }
+
+    #[test]
+    fn test_not_nominating() {
+        let account = HealthAccount {
+            bonded: 1_000,
+            ..Default::default()
+        };
+        let report = check_health(&account, &[], &[], None, 100);
+        assert_eq!(report.issues, vec![HealthIssue::NotNominating]);
+        assert_eq!(report.worst(), Some(Severity::Critical));
+    }
+
+    #[test]
+    fn test_validator_issues() {
+        let mut account = healthy_account();
+        account.targets[0].preferences = None;
+        account.targets[1].preferences = Some(ValidatorPreferences {
+            commission: 1.0,
+            blocked: true,
+        });
+        account.targets[2].active = Some(false);
+        account.targets[3].slashed = true;
+        account.targets[4].active = None;
+
+        let report = check_health(&account, &[], &[], None, 100);
+        assert_eq!(
+            report.issues,
+            vec![
+                HealthIssue::FullCommission {
+                    validator: "validator1".into()
+                },
+                HealthIssue::ChilledValidator {
+                    validator: "validator0".into()
+                },
+                HealthIssue::BlockedValidator {
+                    validator: "validator1".into()
+                },
+                HealthIssue::SlashedValidator {
+                    validator: "validator3".into()
+                },
+                HealthIssue::InactiveValidator {
+                    validator: "validator2".into()
+                },
+            ]
+        );
+        assert_eq!(report.count(Severity::Warning), 3);
+    }
+
+    #[test]
+    fn test_target_label_uses_validator_set() {
+        let mut account = healthy_account();
+        account.targets.truncate(1);
+        account.targets[0].slashed = true;
+        let validators = vec![DisplayValidator::new(
+            "validator0".into(),
+            Some("Alice".into()),
+            0.05,
+            false,
+            0,
+            0,
+            0,
+            0,
+            None,
+        )];
+        let report = check_health(&account, &validators, &[], None, 100);
+        assert!(report.issues.contains(&HealthIssue::SlashedValidator {
+            validator: "Alice".into()
+        }));
+        assert!(
+            report
+                .issues
+                .contains(&HealthIssue::FewNominations { count: 1 })
+        );
+    }
+
+    #[test]
+    fn test_idle_unlocked_and_min_active_stake() {
+        let mut account = healthy_account();
+        account.unlocking = vec![
+            UnlockChunk { value: 10, era: 99 },
+            UnlockChunk {
+                value: 20,
+                era: 100,
+            },
+            UnlockChunk {
+                value: 40,
+                era: 101,
+            },
+        ];
+        let params = StakingParams {
+            min_active_stake: 2_000,
+            ..Default::default()
+        };
+        let report = check_health(&account, &[], &[], Some(&params), 100);
+        assert_eq!(
+            report.issues,
+            vec![
+                HealthIssue::BelowMinActiveStake {
+                    bonded: 1_000,
+                    minimum: 2_000
+                },
+                HealthIssue::IdleUnlocked {
+                    amount: 30,
+                    chunks: 2
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pool_issues() {
+        let account = HealthAccount {
+            pool: Some(NominationPoolMembership {
+                pool_id: 7,
+                points: 500,
+                pending_rewards: 25,
+            }),
+            ..Default::default()
+        };
+        let pools = vec![DisplayPool::new(
+            7,
+            "Pool".into(),
+            PoolState::Destroying,
+            1,
+            500,
+            None,
+            None,
+        )];
+        let report = check_health(&account, &[], &pools, None, 100);
+        assert_eq!(
+            report.issues,
+            vec![
+                HealthIssue::PoolDestroying { pool_id: 7 },
+                HealthIssue::UnclaimedPoolRewards {
+                    pool_id: 7,
+                    amount: 25
+                },
+            ]
+        );
+        assert!(report.issues[1].message(0, "DOT").contains("pool #7"));
+    }
+}
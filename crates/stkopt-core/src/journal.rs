@@ -0,0 +1,420 @@
+//! Transaction journal types and export helpers.
+//!
+//! Every transaction submitted from either frontend is recorded in the
+//! `transactions` table of [`crate::db::StakingDb`] so operators keep an audit
+//! trail of what was signed, by whom, and how it ended. This module holds the
+//! record types, the history filter, and CSV/JSON export.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::types::Network;
+
+/// Lifecycle state of a journaled transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TxJournalStatus {
+    /// Submitted to the network, outcome not yet known.
+    Submitted,
+    /// Included in a block that is not yet finalized.
+    InBlock,
+    /// Included in a finalized block.
+    Finalized,
+    /// Rejected, dropped, or failed to finalize.
+    Failed,
+}
+
+impl TxJournalStatus {
+    /// All statuses, in filter cycling order.
+    pub fn all() -> &'static [TxJournalStatus] {
+        &[
+            TxJournalStatus::Submitted,
+            TxJournalStatus::InBlock,
+            TxJournalStatus::Finalized,
+            TxJournalStatus::Failed,
+        ]
+    }
+
+    /// Stable lowercase name used in the database and exports.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TxJournalStatus::Submitted => "submitted",
+            TxJournalStatus::InBlock => "in_block",
+            TxJournalStatus::Finalized => "finalized",
+            TxJournalStatus::Failed => "failed",
+        }
+    }
+
+    /// Whether the transaction has reached a final outcome.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, TxJournalStatus::Finalized | TxJournalStatus::Failed)
+    }
+}
+
+impl fmt::Display for TxJournalStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            TxJournalStatus::Submitted => "Submitted",
+            TxJournalStatus::InBlock => "In block",
+            TxJournalStatus::Finalized => "Finalized",
+            TxJournalStatus::Failed => "Failed",
+        };
+        write!(f, "{}", label)
+    }
+}
+
+impl FromStr for TxJournalStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "submitted" => Ok(TxJournalStatus::Submitted),
+            "in_block" => Ok(TxJournalStatus::InBlock),
+            "finalized" => Ok(TxJournalStatus::Finalized),
+            "failed" => Ok(TxJournalStatus::Failed),
+            other => Err(format!("unknown transaction status '{}'", other)),
+        }
+    }
+}
+
+/// Transaction mortality as signed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase", tag = "kind")]
+pub enum TxMortality {
+    /// Valid forever.
+    #[default]
+    Immortal,
+    /// Valid for `period` blocks starting at `phase`.
+    Mortal {
+        /// Mortality period in blocks.
+        period: u64,
+        /// Phase within the period.
+        phase: u64,
+    },
+}
+
+impl fmt::Display for TxMortality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TxMortality::Immortal => write!(f, "immortal"),
+            TxMortality::Mortal { period, phase } => {
+                write!(f, "mortal (period {}, phase {})", period, phase)
+            }
+        }
+    }
+}
+
+/// A transaction about to be submitted, as recorded in the journal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewTransaction {
+    /// SS58 address of the signing account.
+    pub signer: String,
+    /// Human-readable description of the call.
+    pub description: String,
+    /// SCALE-encoded call data as `0x`-prefixed hex.
+    pub call_data: String,
+    /// Account nonce used for signing.
+    pub nonce: u64,
+    /// Mortality the payload was signed with.
+    pub mortality: TxMortality,
+    /// Extrinsic hash as `0x`-prefixed hex.
+    pub tx_hash: String,
+}
+
+/// A journaled transaction.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransactionRecord {
+    /// Journal row id.
+    pub id: i64,
+    /// Network the transaction was submitted to.
+    pub network: Network,
+    /// SS58 address of the signing account.
+    pub signer: String,
+    /// Human-readable description of the call.
+    pub description: String,
+    /// SCALE-encoded call data as `0x`-prefixed hex.
+    pub call_data: String,
+    /// Account nonce used for signing.
+    pub nonce: u64,
+    /// Mortality the payload was signed with.
+    pub mortality: TxMortality,
+    /// Extrinsic hash as `0x`-prefixed hex.
+    pub tx_hash: String,
+    /// Finalized block hash, once known.
+    pub block_hash: Option<String>,
+    /// Current lifecycle state.
+    pub status: TxJournalStatus,
+    /// Dispatch outcome or failure reason.
+    pub dispatch_result: Option<String>,
+    /// When the transaction was submitted (UTC, `YYYY-MM-DD HH:MM:SS`).
+    pub submitted_at: String,
+    /// When the final outcome was recorded (UTC, `YYYY-MM-DD HH:MM:SS`).
+    pub completed_at: Option<String>,
+}
+
+/// Filter for listing journaled transactions.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransactionFilter {
+    /// Only transactions signed by this address.
+    pub signer: Option<String>,
+    /// Only transactions in this state.
+    pub status: Option<TxJournalStatus>,
+    /// Case-insensitive match against description, tx hash, or dispatch result.
+    pub search: Option<String>,
+    /// Maximum number of rows, newest first.
+    pub limit: Option<u32>,
+}
+
+impl TransactionFilter {
+    /// Filter for a single signer.
+    pub fn for_signer(signer: impl Into<String>) -> Self {
+        Self {
+            signer: Some(signer.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Whether `record` passes the signer, status, and search criteria.
+    ///
+    /// `limit` is not applied here.
+    pub fn matches(&self, record: &TransactionRecord) -> bool {
+        if self
+            .signer
+            .as_deref()
+            .is_some_and(|signer| signer != record.signer)
+        {
+            return false;
+        }
+        if self.status.is_some_and(|status| status != record.status) {
+            return false;
+        }
+        match self.search.as_deref().map(str::trim) {
+            Some(query) if !query.is_empty() => {
+                let query = query.to_lowercase();
+                record.description.to_lowercase().contains(&query)
+                    || record.tx_hash.to_lowercase().contains(&query)
+                    || record
+                        .dispatch_result
+                        .as_deref()
+                        .is_some_and(|r| r.to_lowercase().contains(&query))
+            }
+            _ => true,
+        }
+    }
+}
+
+/// File format for exporting the journal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JournalExportFormat {
+    /// Comma-separated values with a header row.
+    #[default]
+    Csv,
+    /// Pretty-printed JSON array of records.
+    Json,
+}
+
+impl JournalExportFormat {
+    /// File extension without the dot.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            JournalExportFormat::Csv => "csv",
+            JournalExportFormat::Json => "json",
+        }
+    }
+}
+
+impl fmt::Display for JournalExportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.extension())
+    }
+}
+
+impl FromStr for JournalExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "csv" => Ok(JournalExportFormat::Csv),
+            "json" => Ok(JournalExportFormat::Json),
+            other => Err(format!("unknown export format '{}' (csv, json)", other)),
+        }
+    }
+}
+
+const CSV_HEADER: &str = "id,network,signer,description,call_data,nonce,mortality,tx_hash,block_hash,status,dispatch_result,submitted_at,completed_at";
+
+/// Serialize journal records in the given format.
+pub fn export_transactions(
+    records: &[TransactionRecord],
+    format: JournalExportFormat,
+) -> Result<String, serde_json::Error> {
+    match format {
+        JournalExportFormat::Csv => Ok(transactions_to_csv(records)),
+        JournalExportFormat::Json => serde_json::to_string_pretty(records),
+    }
+}
+
+/// Default export file name for `signer`, e.g. `stkopt-transactions-15oF4u.csv`.
+pub fn default_journal_file_name(signer: Option<&str>, format: JournalExportFormat) -> String {
+    match signer {
+        Some(signer) => {
+            let short: String = signer.chars().take(6).collect();
+            format!("stkopt-transactions-{}.{}", short, format.extension())
+        }
+        None => format!("stkopt-transactions.{}", format.extension()),
+    }
+}
+
+fn transactions_to_csv(records: &[TransactionRecord]) -> String {
+    let mut out = String::from(CSV_HEADER);
+    out.push('\n');
+    for record in records {
+        let fields = [
+            record.id.to_string(),
+            record.network.to_string(),
+            record.signer.clone(),
+            record.description.clone(),
+            record.call_data.clone(),
+            record.nonce.to_string(),
+            record.mortality.to_string(),
+            record.tx_hash.clone(),
+            record.block_hash.clone().unwrap_or_default(),
+            record.status.as_str().to_string(),
+            record.dispatch_result.clone().unwrap_or_default(),
+            record.submitted_at.clone(),
+            record.completed_at.clone().unwrap_or_default(),
+        ];
+        let line: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+        out.push_str(&line.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_record(id: i64, status: TxJournalStatus) -> TransactionRecord {
+        TransactionRecord {
+            id,
+            network: Network::Polkadot,
+            signer: "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5".to_string(),
+            description: "Bond 10 DOT".to_string(),
+            call_data: "0x0700".to_string(),
+            nonce: 3,
+            mortality: TxMortality::Mortal {
+                period: 64,
+                phase: 12,
+            },
+            tx_hash: "0xabcd".to_string(),
+            block_hash: None,
+            status,
+            dispatch_result: None,
+            submitted_at: "2025-01-01 12:00:00".to_string(),
+            completed_at: None,
+        }
+    }
+
+    #[test]
+    fn test_status_round_trip() {
+        for status in TxJournalStatus::all() {
+            assert_eq!(status.as_str().parse::<TxJournalStatus>(), Ok(*status));
+        }
+        assert!("pending".parse::<TxJournalStatus>().is_err());
+        assert!(!TxJournalStatus::Submitted.is_terminal());
+        assert!(!TxJournalStatus::InBlock.is_terminal());
+        assert!(TxJournalStatus::Failed.is_terminal());
+    }
+
+    #[test]
+    fn test_mortality_display() {
+        assert_eq!(TxMortality::Immortal.to_string(), "immortal");
+        assert_eq!(
+            TxMortality::Mortal {
+                period: 64,
+                phase: 12
+            }
+            .to_string(),
+            "mortal (period 64, phase 12)"
+        );
+    }
+
+    #[test]
+    fn test_filter_matches_signer_and_status() {
+        let record = make_record(1, TxJournalStatus::Finalized);
+        assert!(TransactionFilter::default().matches(&record));
+        assert!(TransactionFilter::for_signer(record.signer.clone()).matches(&record));
+        assert!(!TransactionFilter::for_signer("other").matches(&record));
+
+        let filter = TransactionFilter {
+            status: Some(TxJournalStatus::Failed),
+            ..Default::default()
+        };
+        assert!(!filter.matches(&record));
+    }
+
+    #[test]
+    fn test_filter_matches_search() {
+        let mut record = make_record(1, TxJournalStatus::Failed);
+        record.dispatch_result = Some("Staking.InsufficientBond".to_string());
+
+        let search = |q: &str| TransactionFilter {
+            search: Some(q.to_string()),
+            ..Default::default()
+        };
+        assert!(search("bond 10").matches(&record));
+        assert!(search("ABCD").matches(&record));
+        assert!(search("insufficient").matches(&record));
+        assert!(search("  ").matches(&record));
+        assert!(!search("nominate").matches(&record));
+    }
+
+    #[test]
+    fn test_export_csv_escapes_fields() {
+        let mut record = make_record(7, TxJournalStatus::Failed);
+        record.description = "Nominate \"A\", B".to_string();
+        let csv = export_transactions(&[record], JournalExportFormat::Csv).unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some(CSV_HEADER));
+        let row = lines.next().unwrap();
+        assert!(row.starts_with("7,Polkadot,15oF4u"));
+        assert!(row.contains("\"Nominate \"\"A\"\", B\""));
+        assert!(row.contains(",failed,"));
+        assert!(row.contains("\"mortal (period 64, phase 12)\""));
+    }
+
+    #[test]
+    fn test_export_json() {
+        let record = make_record(2, TxJournalStatus::Finalized);
+        let json =
+            export_transactions(std::slice::from_ref(&record), JournalExportFormat::Json).unwrap();
+        let parsed: Vec<TransactionRecord> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, vec![record]);
+        assert!(json.contains("\"status\": \"finalized\""));
+    }
+
+    #[test]
+    fn test_export_format_parse_and_file_name() {
+        assert_eq!("CSV".parse(), Ok(JournalExportFormat::Csv));
+        assert_eq!("json".parse(), Ok(JournalExportFormat::Json));
+        assert!("xml".parse::<JournalExportFormat>().is_err());
+        assert_eq!(
+            default_journal_file_name(Some("15oF4uVJ"), JournalExportFormat::Json),
+            "stkopt-transactions-15oF4u.json"
+        );
+        assert_eq!(
+            default_journal_file_name(None, JournalExportFormat::Csv),
+            "stkopt-transactions.csv"
+        );
+    }
+}
@@ -2,29 +2,84 @@
 //!
 //! This crate provides:
 //! - APY calculations (`apy` module)
+//! - Bags-list voter position (`bags` module)
 //! - Validator selection optimization (`optimizer` module)
 //! - Core domain types (`types` module)
+//! - User-defined networks (`custom_network` module)
 //! - Display types for UI (`display` module)
+//! - RPC endpoint health ranking (`endpoint_health` module)
+//! - Nomination health check (`health` module)
+//! - Transaction mortality windows (`mortality` module)
+//! - Payout reconciliation against reward events (`payouts` module)
+//! - Nomination pool member balances, claim permissions and administration (`pools` module)
+//! - Multi-account portfolio totals (`portfolio` module)
+//! - Exact per-era nominator rewards (`rewards` module)
+//! - Slash risk of nominated validators (`slashing` module)
+//! - Staking parameters and bond checks (`staking_params` module)
+//! - Polkadot Vault account QR parsing (`vault` module)
 //!
 //! With the `persistence` feature enabled:
 //! - SQLite database for caching (`db` module)
+//! - Transaction journal records and export (`journal` module)
 //! - Configuration management (`config` module)
 
 pub mod apy;
+pub mod bags;
+pub mod custom_network;
 pub mod display;
+pub mod endpoint_health;
+pub mod health;
+pub mod mortality;
 pub mod optimizer;
+pub mod payouts;
+pub mod pools;
+pub mod portfolio;
+pub mod rewards;
+pub mod slashing;
+pub mod staking_params;
 pub mod types;
+pub mod vault;
 
 #[cfg(feature = "persistence")]
 pub mod config;
 #[cfg(feature = "persistence")]
 pub mod db;
+#[cfg(feature = "persistence")]
+pub mod journal;
 
 // Re-export commonly used items from core modules
 pub use apy::*;
+pub use bags::{BagsListPosition, bag_for_score, currency_to_vote, first_lighter};
+pub use custom_network::{ChainSource, CustomNetwork, CustomNetworkError};
 pub use display::*;
+pub use endpoint_health::{ChainRole, EndpointHealth, rank_endpoints};
+pub use health::{HealthAccount, HealthIssue, HealthReport, Severity, TargetStatus, check_health};
+pub use mortality::{
+    DEFAULT_MORTALITY_PERIOD, MortalityWindow, format_countdown, is_expired_tx_error,
+    normalize_mortality_period,
+};
 pub use optimizer::*;
+pub use payouts::{
+    ExpectedPayout, PayoutCheck, PayoutEvent, PayoutScan, PayoutSource, PayoutStatus,
+    era_payout_status, reconcile_payouts,
+};
+pub use pools::{
+    ClaimPermission, CommissionChangeRate, PoolAdmin, PoolAdminAction, PoolCommission,
+    PoolMemberCall, PoolMemberDetails, PoolRole, SubPools, UnbondPool, UnbondingPoolBalance,
+    format_perbill, perbill_from_percent, points_to_balance, unbonding_balances,
+};
+pub use portfolio::{PortfolioAccount, PortfolioSummary, summarize_portfolio};
+pub use rewards::{
+    RewardComparison, ValidatorEraPayout, compare_rewards, nominator_era_reward,
+    validator_era_reward,
+};
+pub use slashing::{
+    NominatorSlash, OffenceReport, PendingSlash, SlashHistory, SlashRisk, SlashRiskReport,
+    ValidatorSlash, assess_slash_risk,
+};
+pub use staking_params::{BondIssue, StakingParams};
 pub use types::*;
+pub use vault::{VaultAccountQr, VaultQrError, parse_vault_account_qr};
 
 // Re-export key persistence types when feature is enabled
 #[cfg(feature = "persistence")]
@@ -40,3 +95,8 @@ pub use db::{
     DEFAULT_STARTUP_CACHE_MAX_ERA_LAG, HistoryService, StakingDb, StartupDataCache,
     StartupDataService,
 };
+#[cfg(feature = "persistence")]
+pub use journal::{
+    JournalExportFormat, NewTransaction, TransactionFilter, TransactionRecord, TxJournalStatus,
+    TxMortality, export_transactions,
+};
@@ -0,0 +1,150 @@
+//! Transaction mortality: era periods and expiry tracking.
+//!
+//! A mortal transaction is only valid for `period` blocks after its birth
+//! block. Signing with Polkadot Vault can take a while, so the apps track
+//! each pending payload's window and offer to rebuild it once it expires.
+
+use serde::{Deserialize, Serialize};
+
+/// Average Asset Hub block time, used to estimate expiry countdowns.
+pub const BLOCK_TIME_SECS: u64 = 6;
+
+/// Default mortality period in blocks (~3.4 hours at 6s blocks).
+pub const DEFAULT_MORTALITY_PERIOD: u64 = 2048;
+
+/// Shortest mortality period the era encoding supports.
+pub const MIN_MORTALITY_PERIOD: u64 = 4;
+
+/// Longest mortality period we build.
+///
+/// Above 4096 blocks the era phase is quantized, so the birth block would no
+/// longer be the block the payload was built against.
+pub const MAX_MORTALITY_PERIOD: u64 = 4096;
+
+/// Remaining lifetime below which the UI warns that a payload is about to expire.
+pub const EXPIRY_WARNING_SECS: u64 = 300;
+
+/// Round a requested period to a valid era period (power of two within bounds).
+pub fn normalize_mortality_period(period: u64) -> u64 {
+    period
+        .clamp(MIN_MORTALITY_PERIOD, MAX_MORTALITY_PERIOD)
+        .next_power_of_two()
+}
+
+/// Validity window of a mortal transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MortalityWindow {
+    /// Block the payload was built against.
+    pub birth_block: u64,
+    /// Number of blocks the transaction stays valid.
+    pub period: u64,
+}
+
+impl MortalityWindow {
+    pub fn new(birth_block: u64, period: u64) -> Self {
+        Self {
+            birth_block,
+            period,
+        }
+    }
+
+    /// First block at which the transaction is no longer valid.
+    pub fn expiry_block(&self) -> u64 {
+        self.birth_block.saturating_add(self.period)
+    }
+
+    /// Blocks left before expiry at `current_block`.
+    pub fn blocks_remaining(&self, current_block: u64) -> u64 {
+        self.expiry_block().saturating_sub(current_block)
+    }
+
+    /// Whether the transaction has expired at `current_block`.
+    pub fn is_expired(&self, current_block: u64) -> bool {
+        current_block >= self.expiry_block()
+    }
+
+    /// Estimated lifetime in seconds.
+    pub fn lifetime_secs(&self) -> u64 {
+        self.period.saturating_mul(BLOCK_TIME_SECS)
+    }
+
+    /// Estimated seconds left, `elapsed_secs` after the payload was built.
+    pub fn remaining_secs(&self, elapsed_secs: u64) -> u64 {
+        self.lifetime_secs().saturating_sub(elapsed_secs)
+    }
+}
+
+/// Format a countdown as `2h 05m`, `4m 09s` or `42s`; `expired` at zero.
+pub fn format_countdown(secs: u64) -> String {
+    if secs == 0 {
+        "expired".to_string()
+    } else if secs >= 3600 {
+        format!("{}h {:02}m", secs / 3600, (secs % 3600) / 60)
+    } else if secs >= 60 {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
+/// Whether a submission error means the payload must be rebuilt
+/// (expired era or an already-used nonce).
+pub fn is_expired_tx_error(message: &str) -> bool {
+    let message = message.to_ascii_lowercase();
+    message.contains("outdated") || message.contains("ancient birth block")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_mortality_period() {
+        assert_eq!(normalize_mortality_period(0), MIN_MORTALITY_PERIOD);
+        assert_eq!(normalize_mortality_period(64), 64);
+        assert_eq!(normalize_mortality_period(100), 128);
+        assert_eq!(normalize_mortality_period(1_000_000), MAX_MORTALITY_PERIOD);
+        assert_eq!(
+            normalize_mortality_period(DEFAULT_MORTALITY_PERIOD),
+            DEFAULT_MORTALITY_PERIOD
+        );
+    }
+
+    #[test]
+    fn test_mortality_window_expiry() {
+        let window = MortalityWindow::new(1_000, 64);
+        assert_eq!(window.expiry_block(), 1_064);
+        assert_eq!(window.blocks_remaining(1_000), 64);
+        assert_eq!(window.blocks_remaining(1_063), 1);
+        assert!(!window.is_expired(1_063));
+        assert!(window.is_expired(1_064));
+        assert_eq!(window.blocks_remaining(2_000), 0);
+    }
+
+    #[test]
+    fn test_mortality_window_remaining_secs() {
+        let window = MortalityWindow::new(0, 64);
+        assert_eq!(window.lifetime_secs(), 384);
+        assert_eq!(window.remaining_secs(84), 300);
+        assert_eq!(window.remaining_secs(1_000), 0);
+    }
+
+    #[test]
+    fn test_format_countdown() {
+        assert_eq!(format_countdown(0), "expired");
+        assert_eq!(format_countdown(42), "42s");
+        assert_eq!(format_countdown(249), "4m 09s");
+        assert_eq!(format_countdown(7_500), "2h 05m");
+    }
+
+    #[test]
+    fn test_is_expired_tx_error() {
+        assert!(is_expired_tx_error(
+            "Invalid Transaction (1010): Transaction is outdated"
+        ));
+        assert!(is_expired_tx_error(
+            "Transaction has an ancient birth block"
+        ));
+        assert!(!is_expired_tx_error("Inability to pay some fees"));
+    }
+}
@@ -29,6 +29,8 @@ pub struct OptimizationCriteria {
     pub target_count: usize,
     /// Selection strategy.
     pub strategy: SelectionStrategy,
+    /// Addresses of validators with slash history, never selected.
+    pub slashed: HashSet<String>,
 }
 
 impl Default for OptimizationCriteria {
@@ -38,10 +40,20 @@ impl Default for OptimizationCriteria {
             exclude_blocked: true,
             target_count: MAX_NOMINATIONS,
             strategy: SelectionStrategy::TopApy,
+            slashed: HashSet::new(),
         }
     }
 }
 
+impl OptimizationCriteria {
+    /// Whether `candidate` passes the commission, blocked and slash filters.
+    pub fn allows(&self, candidate: &ValidatorCandidate) -> bool {
+        candidate.commission <= self.max_commission
+            && (!self.exclude_blocked || !candidate.blocked)
+            && !self.slashed.contains(&candidate.address)
+    }
+}
+
 /// Strategy for selecting validators.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SelectionStrategy {
@@ -112,18 +124,11 @@ pub fn optimize_display_validators(
     let candidates = validator_candidates_from_display(validators);
     let filter_eligible_validators = candidates
         .iter()
-        .filter(|validator| {
-            validator.commission <= criteria.max_commission
-                && (!criteria.exclude_blocked || !validator.blocked)
-        })
+        .filter(|validator| criteria.allows(validator))
         .count();
     let stake_eligible_validators = candidates
         .iter()
-        .filter(|validator| {
-            validator.commission <= criteria.max_commission
-                && (!criteria.exclude_blocked || !validator.blocked)
-                && validator.total_stake > 0
-        })
+        .filter(|validator| criteria.allows(validator) && validator.total_stake > 0)
         .count();
     let require_stake = stake_eligible_validators > 0;
     let eligible_validators = if require_stake {
@@ -134,8 +139,7 @@ pub fn optimize_display_validators(
     let validators_with_apy = candidates
         .iter()
         .filter(|validator| {
-            validator.commission <= criteria.max_commission
-                && (!criteria.exclude_blocked || !validator.blocked)
+            criteria.allows(validator)
                 && (!require_stake || validator.total_stake > 0)
                 && validator.apy.is_finite()
                 && validator.apy > 0.0
@@ -178,13 +182,7 @@ pub fn select_validators(
     // Filter candidates
     let mut filtered: Vec<_> = candidates
         .iter()
-        .filter(|v| {
-            v.commission <= criteria.max_commission
-                && (!criteria.exclude_blocked || !v.blocked)
-                && v.apy.is_finite()
-                && v.apy > 0.0
-                && v.total_stake > 0
-        })
+        .filter(|v| criteria.allows(v) && v.apy.is_finite() && v.apy > 0.0 && v.total_stake > 0)
         .cloned()
         .collect();
 
@@ -276,9 +274,7 @@ pub fn select_validators_without_apy(
 ) -> OptimizationResult {
     let mut eligible: Vec<_> = candidates
         .iter()
-        .filter(|v| {
-            v.commission <= criteria.max_commission && (!criteria.exclude_blocked || !v.blocked)
-        })
+        .filter(|v| criteria.allows(v))
         .cloned()
         .collect();
 
@@ -347,6 +343,7 @@ mod tests {
             exclude_blocked: true,
             target_count: 2,
             strategy: SelectionStrategy::TopApy,
+            ..Default::default()
         };
 
         let result = select_validators(&candidates, &criteria);
@@ -371,6 +368,7 @@ mod tests {
             exclude_blocked: true,
             target_count: 2,
             strategy: SelectionStrategy::TopApy,
+            ..Default::default()
         };
 
         let result = select_validators(&candidates, &criteria);
@@ -393,6 +391,7 @@ mod tests {
             exclude_blocked: true,
             target_count: 2,
             strategy: SelectionStrategy::TopApy,
+            ..Default::default()
         };
 
         let result = select_validators(&candidates, &criteria);
@@ -417,6 +416,7 @@ mod tests {
             exclude_blocked: true,
             target_count: 4,
             strategy: SelectionStrategy::DiversifyByStake,
+            ..Default::default()
         };
 
         let result = select_validators(&candidates, &criteria);
@@ -473,6 +473,7 @@ mod tests {
             exclude_blocked: true,
             target_count: 10,
             strategy: SelectionStrategy::RandomFromTop,
+            ..Default::default()
         };
 
         let result = select_validators(&candidates, &criteria);
@@ -505,6 +506,7 @@ mod tests {
             exclude_blocked: true,
             target_count: 10,
             strategy: SelectionStrategy::RandomFromTop,
+            ..Default::default()
         };
 
         let result = select_validators(&candidates, &criteria);
@@ -524,6 +526,7 @@ mod tests {
             exclude_blocked: true,
             target_count: 2,
             strategy: SelectionStrategy::TopApy,
+            ..Default::default()
         };
 
         let result = select_validators(&candidates, &criteria);
@@ -545,6 +548,7 @@ mod tests {
             exclude_blocked: true,
             target_count: 3,
             strategy: SelectionStrategy::TopApy,
+            ..Default::default()
         };
 
         let result = select_validators(&candidates, &criteria);
@@ -565,6 +569,7 @@ mod tests {
             exclude_blocked: false, // Allow blocked validators
             target_count: 2,
             strategy: SelectionStrategy::TopApy,
+            ..Default::default()
         };
 
         let result = select_validators(&candidates, &criteria);
@@ -585,6 +590,7 @@ mod tests {
             exclude_blocked: true,
             target_count: 10, // More than available
             strategy: SelectionStrategy::TopApy,
+            ..Default::default()
         };
 
         let result = select_validators(&candidates, &criteria);
@@ -605,6 +611,7 @@ mod tests {
             exclude_blocked: true,
             target_count: 3,
             strategy: SelectionStrategy::TopApy,
+            ..Default::default()
         };
 
         // Should not panic with NaN values
@@ -627,6 +634,7 @@ mod tests {
             exclude_blocked: true,
             target_count: 3, // Odd number
             strategy: SelectionStrategy::DiversifyByStake,
+            ..Default::default()
         };
 
         let result = select_validators(&candidates, &criteria);
@@ -751,6 +759,26 @@ mod tests {
         assert_eq!(result.selected[0].address, "blocked-low-comm");
     }
 
+    #[test]
+    fn test_slashed_validators_excluded() {
+        let candidates = vec![
+            make_candidate("slashed", 0.01, 0.20, false, 10_000),
+            make_candidate("clean", 0.05, 0.10, false, 1_000),
+        ];
+        let criteria = OptimizationCriteria {
+            slashed: HashSet::from(["slashed".to_string()]),
+            ..OptimizationCriteria::default()
+        };
+
+        let with_apy = select_validators(&candidates, &criteria);
+        assert_eq!(with_apy.selected.len(), 1);
+        assert_eq!(with_apy.selected[0].address, "clean");
+
+        let without_apy = select_validators_without_apy(&candidates, &criteria);
+        assert_eq!(without_apy.selected.len(), 1);
+        assert_eq!(without_apy.selected[0].address, "clean");
+    }
+
     #[test]
     fn test_select_validators_without_apy_tiebreaks_by_stake_then_nominators() {
         let candidates = vec![
@@ -978,6 +1006,7 @@ mod tests {
             exclude_blocked: true,
             target_count: 16,
             strategy: SelectionStrategy::DiversifyByStake,
+            ..Default::default()
         };
 
         let start = std::time::Instant::now();
@@ -1008,6 +1037,7 @@ mod tests {
             exclude_blocked: true,
             target_count: 5,
             strategy: SelectionStrategy::TopApy,
+            ..Default::default()
         };
 
         let result = select_validators(&candidates, &criteria);
@@ -0,0 +1,322 @@
+//! Reconciling expected staking rewards against payout events.
+//!
+//! Rewards are computed per era and validator (see [`crate::rewards`]); the
+//! chain reports what was actually paid with `Staking.Rewarded` events, each
+//! preceded by a `Staking.PayoutStarted` naming the era and validator. Pool
+//! members are paid with `NominationPools.PaidOut`, which carries no era.
+//! [`reconcile_payouts`] matches the two and flags missing, short or
+//! unclaimed payouts.
+//!
+//! An era can be paid out at any time until it leaves the history depth, so a
+//! payout is only reported missing when the validator claimed it
+//! (`Staking.ClaimedRewards`) and the blocks it could have been paid in were
+//! all searched. [`PayoutScan`] tracks the blocks scanned for an account.
+
+use crate::types::{Balance, EraIndex};
+use serde::{Deserialize, Serialize};
+
+/// Which pallet paid a reward.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PayoutSource {
+    /// `Staking.Rewarded` from a validator payout.
+    Staking,
+    /// `NominationPools.PaidOut` to a pool member.
+    Pool,
+}
+
+impl PayoutSource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PayoutSource::Staking => "staking",
+            PayoutSource::Pool => "pool",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "staking" => Some(PayoutSource::Staking),
+            "pool" => Some(PayoutSource::Pool),
+            _ => None,
+        }
+    }
+}
+
+/// A reward payment observed on chain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PayoutEvent {
+    pub source: PayoutSource,
+    /// Block the event was emitted in.
+    pub block_number: u64,
+    /// Index of the event within the block.
+    pub event_index: u32,
+    /// Era paid out; staking payouts only.
+    pub era: Option<EraIndex>,
+    /// SS58 address of the validator paid out; staking payouts only.
+    pub validator: Option<String>,
+    /// Pool paying the member; pool payouts only.
+    pub pool_id: Option<u32>,
+    pub amount: Balance,
+}
+
+/// Reward an account should receive from one validator for one era.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpectedPayout {
+    pub era: EraIndex,
+    /// SS58 address of the validator.
+    pub validator: String,
+    pub amount: Balance,
+    /// Whether the exposure page paying the account is in
+    /// `Staking.ClaimedRewards`.
+    pub claimed: bool,
+}
+
+/// Contiguous range of blocks scanned for an account's payout events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PayoutScan {
+    pub first_block: u64,
+    pub last_block: u64,
+}
+
+impl PayoutScan {
+    /// Plan the scan up to `latest`, at most `max_blocks` back.
+    ///
+    /// Returns the first block to scan and the range covered afterwards:
+    /// `previous` is extended when the scan continues it, and replaced when
+    /// the blocks since were too many to scan.
+    pub fn next(previous: Option<PayoutScan>, latest: u64, max_blocks: u64) -> (u64, PayoutScan) {
+        let oldest = latest.saturating_sub(max_blocks.saturating_sub(1));
+        match previous {
+            Some(previous) if previous.last_block.saturating_add(1) >= oldest => (
+                previous.last_block + 1,
+                PayoutScan {
+                    first_block: previous.first_block,
+                    last_block: latest.max(previous.last_block),
+                },
+            ),
+            _ => (
+                oldest,
+                PayoutScan {
+                    first_block: oldest,
+                    last_block: latest,
+                },
+            ),
+        }
+    }
+}
+
+/// Outcome of matching an expected payout with payout events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PayoutStatus {
+    /// Received at least the expected amount.
+    Paid,
+    /// Paid, but less than expected.
+    Short,
+    /// The validator has not claimed the payout yet; anyone can still claim it.
+    Unclaimed,
+    /// Claimed, but no payout seen in the blocks searched.
+    Missing,
+}
+
+/// Expected and received reward for one era and validator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PayoutCheck {
+    pub era: EraIndex,
+    pub validator: String,
+    pub expected: Balance,
+    pub received: Balance,
+    pub status: PayoutStatus,
+}
+
+impl PayoutCheck {
+    /// Whether the payout needs attention.
+    pub fn is_flagged(&self) -> bool {
+        self.status != PayoutStatus::Paid
+    }
+
+    /// Expected amount not received.
+    pub fn shortfall(&self) -> Balance {
+        self.expected.saturating_sub(self.received)
+    }
+}
+
+/// Match each expected payout with the staking payout events for the same era
+/// and validator. Expected amounts of zero are skipped.
+///
+/// `events` must hold every payout of eras from `covered_from` on. A claimed
+/// payout with no event in an earlier era may have been paid before the
+/// events start, so it is left out rather than reported missing.
+pub fn reconcile_payouts(
+    expected: &[ExpectedPayout],
+    events: &[PayoutEvent],
+    covered_from: EraIndex,
+) -> Vec<PayoutCheck> {
+    expected
+        .iter()
+        .filter(|e| e.amount > 0)
+        .filter_map(|e| {
+            let matching: Vec<&PayoutEvent> = events
+                .iter()
+                .filter(|ev| {
+                    ev.source == PayoutSource::Staking
+                        && ev.era == Some(e.era)
+                        && ev.validator.as_deref() == Some(e.validator.as_str())
+                })
+                .collect();
+            let received: Balance = matching.iter().map(|ev| ev.amount).sum();
+            let status = if !matching.is_empty() {
+                if received < e.amount {
+                    PayoutStatus::Short
+                } else {
+                    PayoutStatus::Paid
+                }
+            } else if !e.claimed {
+                PayoutStatus::Unclaimed
+            } else if e.era >= covered_from {
+                PayoutStatus::Missing
+            } else {
+                return None;
+            };
+            Some(PayoutCheck {
+                era: e.era,
+                validator: e.validator.clone(),
+                expected: e.amount,
+                received,
+                status,
+            })
+        })
+        .collect()
+}
+
+/// Worst status among the checks for `era`, `None` when there are none.
+pub fn era_payout_status(checks: &[PayoutCheck], era: EraIndex) -> Option<PayoutStatus> {
+    checks
+        .iter()
+        .filter(|c| c.era == era)
+        .map(|c| c.status)
+        .max_by_key(|status| match status {
+            PayoutStatus::Paid => 0,
+            PayoutStatus::Unclaimed => 1,
+            PayoutStatus::Short => 2,
+            PayoutStatus::Missing => 3,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expected(era: EraIndex, validator: &str, amount: Balance) -> ExpectedPayout {
+        ExpectedPayout {
+            era,
+            validator: validator.to_string(),
+            amount,
+            claimed: true,
+        }
+    }
+
+    fn rewarded(era: EraIndex, validator: &str, amount: Balance) -> PayoutEvent {
+        PayoutEvent {
+            source: PayoutSource::Staking,
+            block_number: 100,
+            event_index: 0,
+            era: Some(era),
+            validator: Some(validator.to_string()),
+            pool_id: None,
+            amount,
+        }
+    }
+
+    #[test]
+    fn test_reconcile_flags_missing_and_short() {
+        let expected = [
+            expected(10, "a", 100),
+            expected(10, "b", 50),
+            expected(11, "a", 80),
+            expected(11, "b", 0),
+        ];
+        let events = [rewarded(10, "a", 100), rewarded(10, "b", 49)];
+        let checks = reconcile_payouts(&expected, &events, 0);
+
+        assert_eq!(checks.len(), 3);
+        assert_eq!(checks[0].status, PayoutStatus::Paid);
+        assert_eq!(checks[1].status, PayoutStatus::Short);
+        assert_eq!(checks[1].shortfall(), 1);
+        assert_eq!(checks[2].status, PayoutStatus::Missing);
+        assert_eq!(checks[2].received, 0);
+        assert!(!checks[0].is_flagged());
+        assert!(checks[2].is_flagged());
+
+        assert_eq!(era_payout_status(&checks, 10), Some(PayoutStatus::Short));
+        assert_eq!(era_payout_status(&checks, 11), Some(PayoutStatus::Missing));
+        assert_eq!(era_payout_status(&checks, 12), None);
+    }
+
+    #[test]
+    fn test_reconcile_ignores_pool_payouts_and_sums_pages() {
+        let pool = PayoutEvent {
+            source: PayoutSource::Pool,
+            era: Some(10),
+            validator: Some("a".to_string()),
+            pool_id: Some(1),
+            ..rewarded(10, "a", 500)
+        };
+        let events = [pool, rewarded(10, "a", 60), rewarded(10, "a", 40)];
+        let checks = reconcile_payouts(&[expected(10, "a", 100)], &events, 0);
+        assert_eq!(checks[0].received, 100);
+        assert_eq!(checks[0].status, PayoutStatus::Paid);
+    }
+
+    #[test]
+    fn test_reconcile_unclaimed_and_unscanned_eras() {
+        let unclaimed = ExpectedPayout {
+            claimed: false,
+            ..expected(9, "b", 70)
+        };
+        let expected = [expected(9, "a", 100), unclaimed, expected(10, "a", 100)];
+        let events = [rewarded(9, "a", 100)];
+        // Events only cover payouts of era 10 on
+        let checks = reconcile_payouts(&expected, &events, 10);
+
+        assert_eq!(checks.len(), 3);
+        assert_eq!(checks[0].status, PayoutStatus::Paid);
+        assert_eq!(checks[1].status, PayoutStatus::Unclaimed);
+        assert_eq!(checks[2].status, PayoutStatus::Missing);
+
+        // A claimed era before the covered ones cannot be judged
+        let checks = reconcile_payouts(&[expected[2].clone()], &[], 11);
+        assert!(checks.is_empty());
+    }
+
+    #[test]
+    fn test_payout_scan_continues_or_restarts() {
+        let (from, scan) = PayoutScan::next(None, 1_000, 100);
+        assert_eq!(from, 901);
+        assert_eq!(
+            scan,
+            PayoutScan {
+                first_block: 901,
+                last_block: 1_000
+            }
+        );
+        let (from, scan) = PayoutScan::next(Some(scan), 1_050, 100);
+        assert_eq!(
+            (from, scan.first_block, scan.last_block),
+            (1_001, 901, 1_050)
+        );
+        // Too far behind: the gap cannot be scanned, so coverage restarts
+        let (from, scan) = PayoutScan::next(Some(scan), 5_000, 100);
+        assert_eq!(
+            (from, scan.first_block, scan.last_block),
+            (4_901, 4_901, 5_000)
+        );
+    }
+
+    #[test]
+    fn test_payout_source_round_trip() {
+        for source in [PayoutSource::Staking, PayoutSource::Pool] {
+            assert_eq!(PayoutSource::parse(source.as_str()), Some(source));
+        }
+        assert_eq!(PayoutSource::parse("other"), None);
+    }
+}
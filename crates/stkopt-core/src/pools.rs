@@ -0,0 +1,496 @@
+//! Nomination pool member balances, claim permissions and administration.
+//!
+//! Pool members hold points rather than balance. Active points convert at the
+//! ratio of the pool's bonded balance to `BondedPools.points`; unbonding
+//! points convert within the unbond pool of their era in
+//! `NominationPools.SubPoolsStorage`. Unbond pools older than
+//! `TotalUnbondingPools` eras are merged into a single `no_era` pool, so a
+//! member's era missing from `with_era` is found there.
+//!
+//! Pools are administered by the accounts in `BondedPools.roles`: the root
+//! holds every admin call, the nominator may only nominate and the bouncer
+//! may only change the pool's state and metadata.
+
+use crate::types::{Balance, EraIndex, PoolState};
+use serde::{Deserialize, Serialize};
+
+/// Who may claim or compound a member's pool rewards, as stored in
+/// `NominationPools.ClaimPermissions`.
+///
+/// Members without an entry hold the storage default, which the runtime
+/// metadata declares.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ClaimPermission {
+    /// Only the member.
+    Permissioned,
+    /// Anyone may compound the member's rewards.
+    PermissionlessCompound,
+    /// Anyone may pay the member's rewards out to them.
+    PermissionlessWithdraw,
+    /// Anyone may compound or pay out the member's rewards.
+    PermissionlessAll,
+}
+
+impl ClaimPermission {
+    /// All permissions, in the order the runtime declares them.
+    pub const ALL: [ClaimPermission; 4] = [
+        ClaimPermission::Permissioned,
+        ClaimPermission::PermissionlessCompound,
+        ClaimPermission::PermissionlessWithdraw,
+        ClaimPermission::PermissionlessAll,
+    ];
+
+    /// Name of the runtime enum variant.
+    pub fn variant_name(self) -> &'static str {
+        match self {
+            ClaimPermission::Permissioned => "Permissioned",
+            ClaimPermission::PermissionlessCompound => "PermissionlessCompound",
+            ClaimPermission::PermissionlessWithdraw => "PermissionlessWithdraw",
+            ClaimPermission::PermissionlessAll => "PermissionlessAll",
+        }
+    }
+
+    /// Parse a runtime enum variant name.
+    pub fn from_variant_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|p| p.variant_name() == name)
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ClaimPermission::Permissioned => "Only me",
+            ClaimPermission::PermissionlessCompound => "Anyone can compound",
+            ClaimPermission::PermissionlessWithdraw => "Anyone can pay out",
+            ClaimPermission::PermissionlessAll => "Anyone can compound or pay out",
+        }
+    }
+
+    /// Whether others may compound the member's rewards with `bond_extra_other`.
+    pub fn can_compound(self) -> bool {
+        matches!(
+            self,
+            ClaimPermission::PermissionlessCompound | ClaimPermission::PermissionlessAll
+        )
+    }
+
+    /// Whether others may pay out the member's rewards with `claim_payout_other`.
+    pub fn can_withdraw(self) -> bool {
+        matches!(
+            self,
+            ClaimPermission::PermissionlessWithdraw | ClaimPermission::PermissionlessAll
+        )
+    }
+
+    /// The next permission, wrapping around, for cycling through choices.
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|p| *p == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// A reward call made on behalf of another pool member.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PoolMemberCall {
+    /// `bond_extra_other`: restake the member's pending rewards.
+    #[default]
+    Compound,
+    /// `claim_payout_other`: pay the member's pending rewards out to them.
+    PayOut,
+}
+
+impl PoolMemberCall {
+    pub const ALL: [PoolMemberCall; 2] = [PoolMemberCall::Compound, PoolMemberCall::PayOut];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PoolMemberCall::Compound => "Compound rewards",
+            PoolMemberCall::PayOut => "Pay out rewards",
+        }
+    }
+
+    /// Whether a member holding `permission` lets others make this call.
+    pub fn allowed_by(self, permission: ClaimPermission) -> bool {
+        match self {
+            PoolMemberCall::Compound => permission.can_compound(),
+            PoolMemberCall::PayOut => permission.can_withdraw(),
+        }
+    }
+
+    /// The other call, for toggling between them.
+    pub fn next(self) -> Self {
+        match self {
+            PoolMemberCall::Compound => PoolMemberCall::PayOut,
+            PoolMemberCall::PayOut => PoolMemberCall::Compound,
+        }
+    }
+}
+
+/// Balance of `points` in a pool holding `pool_balance` for `pool_points`,
+/// rounded down as the runtime does.
+pub fn points_to_balance(points: Balance, pool_points: Balance, pool_balance: Balance) -> Balance {
+    if points == 0 || pool_points == 0 || pool_balance == 0 {
+        return 0;
+    }
+    pool_balance.checked_mul(points).map_or_else(
+        || (pool_balance / pool_points).saturating_mul(points),
+        |product| product / pool_points,
+    )
+}
+
+/// One unbond pool of `SubPoolsStorage`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnbondPool {
+    pub points: Balance,
+    pub balance: Balance,
+}
+
+impl UnbondPool {
+    /// Balance of `points` in this pool.
+    pub fn balance_of(&self, points: Balance) -> Balance {
+        points_to_balance(points, self.points, self.balance)
+    }
+}
+
+/// A pool's unbonding balances, per era of unlock.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubPools {
+    /// Unbond pools too old to be kept per era, merged together.
+    pub no_era: UnbondPool,
+    /// Unbond pools by the era their funds unlock in.
+    pub with_era: Vec<(EraIndex, UnbondPool)>,
+}
+
+impl SubPools {
+    /// Balance of `points` unbonding into `era`, falling back to the merged
+    /// pool once the era's pool was merged.
+    pub fn balance_of(&self, era: EraIndex, points: Balance) -> Balance {
+        self.with_era
+            .iter()
+            .find(|(e, _)| *e == era)
+            .map_or(&self.no_era, |(_, pool)| pool)
+            .balance_of(points)
+    }
+}
+
+/// A member's unbonding points in one era, converted to balance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnbondingPoolBalance {
+    /// Era the funds unlock in.
+    pub era: EraIndex,
+    pub points: Balance,
+    pub balance: Balance,
+}
+
+/// A pool member's stake in balance terms.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PoolMemberDetails {
+    pub pool_id: u32,
+    /// Active points.
+    pub points: Balance,
+    /// Balance of the active points.
+    pub balance: Balance,
+    /// Unbonding points and balance per unlock era, oldest first.
+    pub unbonding: Vec<UnbondingPoolBalance>,
+    pub claim_permission: ClaimPermission,
+    /// Rewards claimable by the member.
+    pub pending_rewards: Balance,
+}
+
+impl PoolMemberDetails {
+    /// Balance still unbonding or ready to withdraw.
+    pub fn total_unbonding(&self) -> Balance {
+        self.unbonding.iter().map(|u| u.balance).sum()
+    }
+}
+
+/// Convert a member's `(era, points)` unbonding entries to balances.
+pub fn unbonding_balances(
+    member_eras: &[(EraIndex, Balance)],
+    sub_pools: &SubPools,
+) -> Vec<UnbondingPoolBalance> {
+    let mut balances: Vec<UnbondingPoolBalance> = member_eras
+        .iter()
+        .map(|&(era, points)| UnbondingPoolBalance {
+            era,
+            points,
+            balance: sub_pools.balance_of(era, points),
+        })
+        .collect();
+    balances.sort_by_key(|u| u.era);
+    balances
+}
+
+/// A role held in a pool's `PoolRoles`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PoolRole {
+    /// Created the pool; cannot be changed and holds no admin calls.
+    Depositor,
+    Root,
+    Nominator,
+    Bouncer,
+}
+
+impl PoolRole {
+    pub fn label(self) -> &'static str {
+        match self {
+            PoolRole::Depositor => "Depositor",
+            PoolRole::Root => "Root",
+            PoolRole::Nominator => "Nominator",
+            PoolRole::Bouncer => "Bouncer",
+        }
+    }
+}
+
+/// A pool administration call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PoolAdminAction {
+    Nominate,
+    SetState,
+    SetMetadata,
+    SetCommission,
+    SetCommissionMax,
+    SetCommissionChangeRate,
+    ClaimCommission,
+    UpdateRoles,
+    /// Create a new pool bonded by the signer, who becomes its depositor.
+    Create,
+}
+
+impl PoolAdminAction {
+    /// Calls made on an existing pool; `Create` needs no role and is not listed.
+    pub const ALL: [PoolAdminAction; 8] = [
+        PoolAdminAction::Nominate,
+        PoolAdminAction::SetState,
+        PoolAdminAction::SetMetadata,
+        PoolAdminAction::SetCommission,
+        PoolAdminAction::SetCommissionMax,
+        PoolAdminAction::SetCommissionChangeRate,
+        PoolAdminAction::ClaimCommission,
+        PoolAdminAction::UpdateRoles,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PoolAdminAction::Nominate => "Nominate validators",
+            PoolAdminAction::SetState => "Set state",
+            PoolAdminAction::SetMetadata => "Set name",
+            PoolAdminAction::SetCommission => "Set commission",
+            PoolAdminAction::SetCommissionMax => "Set max commission",
+            PoolAdminAction::SetCommissionChangeRate => "Set commission change rate",
+            PoolAdminAction::ClaimCommission => "Claim commission",
+            PoolAdminAction::UpdateRoles => "Update roles",
+            PoolAdminAction::Create => "Create a pool",
+        }
+    }
+
+    /// Roles the pallet accepts as origin for this call.
+    pub fn allowed_roles(self) -> &'static [PoolRole] {
+        match self {
+            PoolAdminAction::Nominate => &[PoolRole::Root, PoolRole::Nominator],
+            PoolAdminAction::SetState | PoolAdminAction::SetMetadata => {
+                &[PoolRole::Root, PoolRole::Bouncer]
+            }
+            PoolAdminAction::SetCommission
+            | PoolAdminAction::SetCommissionMax
+            | PoolAdminAction::SetCommissionChangeRate
+            | PoolAdminAction::ClaimCommission
+            | PoolAdminAction::UpdateRoles => &[PoolRole::Root],
+            // Any account that is not already a pool member may create one
+            PoolAdminAction::Create => &[],
+        }
+    }
+}
+
+/// Limit on how fast a pool's commission may rise.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommissionChangeRate {
+    /// Largest increase per change, in parts per billion.
+    pub max_increase: u32,
+    /// Blocks that must pass between changes.
+    pub min_delay: u32,
+}
+
+/// A pool's commission settings, rates in parts per billion.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PoolCommission {
+    pub current: Option<u32>,
+    pub max: Option<u32>,
+    pub change_rate: Option<CommissionChangeRate>,
+    /// Commission accrued and not yet claimed.
+    pub pending: Balance,
+}
+
+/// A pool in which an account holds one or more roles.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PoolAdmin {
+    pub pool_id: u32,
+    pub name: Option<String>,
+    pub state: PoolState,
+    /// Roles the account holds, in `PoolRoles` field order.
+    pub roles: Vec<PoolRole>,
+    pub commission: PoolCommission,
+}
+
+impl PoolAdmin {
+    pub fn has_role(&self, role: PoolRole) -> bool {
+        self.roles.contains(&role)
+    }
+
+    /// Whether the account may submit `action` for this pool.
+    pub fn can(&self, action: PoolAdminAction) -> bool {
+        action.allowed_roles().iter().any(|r| self.has_role(*r))
+    }
+
+    /// Admin calls available to the account, in `PoolAdminAction::ALL` order.
+    pub fn actions(&self) -> Vec<PoolAdminAction> {
+        PoolAdminAction::ALL
+            .into_iter()
+            .filter(|a| self.can(*a))
+            .collect()
+    }
+}
+
+/// Convert a percentage to parts per billion, rejecting values outside 0-100.
+pub fn perbill_from_percent(percent: f64) -> Option<u32> {
+    if !(0.0..=100.0).contains(&percent) {
+        return None;
+    }
+    Some((percent * 10_000_000.0).round() as u32)
+}
+
+/// Format parts per billion as a percentage.
+pub fn format_perbill(parts: u32) -> String {
+    format!("{:.2}%", parts as f64 / 10_000_000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_points_to_balance() {
+        assert_eq!(points_to_balance(100, 1_000, 900), 90);
+        // Slashed pools hold less balance than points
+        assert_eq!(points_to_balance(3, 10, 7), 2);
+        assert_eq!(points_to_balance(100, 0, 900), 0);
+        assert_eq!(points_to_balance(100, 1_000, 0), 0);
+        assert_eq!(
+            points_to_balance(Balance::MAX / 2, Balance::MAX / 2, Balance::MAX / 2),
+            Balance::MAX / 2
+        );
+    }
+
+    #[test]
+    fn test_unbonding_balances_fall_back_to_merged_pool() {
+        let sub_pools = SubPools {
+            no_era: UnbondPool {
+                points: 100,
+                balance: 50,
+            },
+            with_era: vec![(
+                30,
+                UnbondPool {
+                    points: 200,
+                    balance: 200,
+                },
+            )],
+        };
+        let balances = unbonding_balances(&[(30, 40), (10, 40)], &sub_pools);
+
+        assert_eq!(balances[0].era, 10);
+        assert_eq!(balances[0].balance, 20);
+        assert_eq!(balances[1].balance, 40);
+
+        let details = PoolMemberDetails {
+            pool_id: 1,
+            points: 0,
+            balance: 0,
+            unbonding: balances,
+            claim_permission: ClaimPermission::Permissioned,
+            pending_rewards: 0,
+        };
+        assert_eq!(details.total_unbonding(), 60);
+    }
+
+    #[test]
+    fn test_claim_permission() {
+        for permission in ClaimPermission::ALL {
+            assert_eq!(
+                ClaimPermission::from_variant_name(permission.variant_name()),
+                Some(permission)
+            );
+        }
+        assert_eq!(ClaimPermission::from_variant_name("Unknown"), None);
+        assert!(ClaimPermission::PermissionlessAll.can_compound());
+        assert!(ClaimPermission::PermissionlessAll.can_withdraw());
+        assert!(!ClaimPermission::PermissionlessWithdraw.can_compound());
+        assert!(!ClaimPermission::Permissioned.can_withdraw());
+        assert_eq!(
+            ClaimPermission::PermissionlessAll.next(),
+            ClaimPermission::Permissioned
+        );
+    }
+
+    #[test]
+    fn test_pool_member_call_follows_claim_permission() {
+        let allowed = |call: PoolMemberCall| {
+            ClaimPermission::ALL
+                .into_iter()
+                .filter(|p| call.allowed_by(*p))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            allowed(PoolMemberCall::Compound),
+            vec![
+                ClaimPermission::PermissionlessCompound,
+                ClaimPermission::PermissionlessAll
+            ]
+        );
+        assert_eq!(
+            allowed(PoolMemberCall::PayOut),
+            vec![
+                ClaimPermission::PermissionlessWithdraw,
+                ClaimPermission::PermissionlessAll
+            ]
+        );
+        assert_eq!(PoolMemberCall::PayOut.next(), PoolMemberCall::Compound);
+    }
+
+    fn make_admin(roles: Vec<PoolRole>) -> PoolAdmin {
+        PoolAdmin {
+            pool_id: 7,
+            name: None,
+            state: PoolState::Open,
+            roles,
+            commission: PoolCommission::default(),
+        }
+    }
+
+    #[test]
+    fn test_pool_admin_actions_follow_roles() {
+        let root = make_admin(vec![PoolRole::Depositor, PoolRole::Root]);
+        assert_eq!(root.actions(), PoolAdminAction::ALL.to_vec());
+        assert!(!root.can(PoolAdminAction::Create));
+
+        let nominator = make_admin(vec![PoolRole::Nominator]);
+        assert_eq!(nominator.actions(), vec![PoolAdminAction::Nominate]);
+
+        let bouncer = make_admin(vec![PoolRole::Bouncer]);
+        assert_eq!(
+            bouncer.actions(),
+            vec![PoolAdminAction::SetState, PoolAdminAction::SetMetadata]
+        );
+
+        let depositor = make_admin(vec![PoolRole::Depositor]);
+        assert!(depositor.actions().is_empty());
+    }
+
+    #[test]
+    fn test_perbill_from_percent() {
+        assert_eq!(perbill_from_percent(5.0), Some(50_000_000));
+        assert_eq!(perbill_from_percent(100.0), Some(1_000_000_000));
+        assert_eq!(perbill_from_percent(0.5), Some(5_000_000));
+        assert_eq!(perbill_from_percent(100.1), None);
+        assert_eq!(perbill_from_percent(-1.0), None);
+        assert_eq!(format_perbill(50_000_000), "5.00%");
+    }
+}
@@ -0,0 +1,201 @@
+//! Multi-account portfolio aggregation.
+//!
+//! A portfolio is the set of saved accounts watched together. Each
+//! [`PortfolioAccount`] carries the account's stake; [`summarize_portfolio`]
+//! totals bonded, unbonding and pending rewards across accounts and weights
+//! each account's APY by its stake.
+
+use crate::display::{DisplayPool, DisplayValidator};
+use crate::types::{Balance, NominationPoolMembership};
+
+/// Staking status of one saved account.
+#[derive(Debug, Clone, Default)]
+pub struct PortfolioAccount {
+    /// SS58-encoded address.
+    pub address: String,
+    /// Label from the saved account, if any.
+    pub label: Option<String>,
+    /// Active direct bond.
+    pub bonded: Balance,
+    /// Direct and pool funds waiting to unlock or be withdrawn.
+    pub unbonding: Balance,
+    /// SS58-encoded addresses of nominated validators.
+    pub targets: Vec<String>,
+    /// Nomination pool membership, if any.
+    pub pool: Option<NominationPoolMembership>,
+}
+
+impl PortfolioAccount {
+    /// Label if set, otherwise the address.
+    pub fn display_name(&self) -> &str {
+        self.label.as_deref().unwrap_or(&self.address)
+    }
+
+    /// Stake earning rewards: the direct bond plus pool points, which track
+    /// the pooled stake one to one until the pool is slashed.
+    pub fn staked(&self) -> Balance {
+        self.bonded + self.pool.as_ref().map(|p| p.points).unwrap_or(0)
+    }
+
+    /// Claimable pool rewards.
+    pub fn pending_rewards(&self) -> Balance {
+        self.pool.as_ref().map(|p| p.pending_rewards).unwrap_or(0)
+    }
+
+    /// Expected APY of the account's stake.
+    ///
+    /// Direct stake earns the average APY of nominated validators with a known
+    /// APY, pooled stake earns the pool's APY; the two are weighted by stake.
+    /// Returns `None` when no part of the stake has a known APY.
+    pub fn apy(&self, validators: &[DisplayValidator], pools: &[DisplayPool]) -> Option<f64> {
+        let direct_apy = {
+            let known: Vec<f64> = self
+                .targets
+                .iter()
+                .filter_map(|t| validators.iter().find(|v| &v.address == t)?.apy)
+                .collect();
+            (!known.is_empty()).then(|| known.iter().sum::<f64>() / known.len() as f64)
+        };
+        let pool_apy = self
+            .pool
+            .as_ref()
+            .and_then(|m| pools.iter().find(|p| p.id == m.pool_id)?.apy);
+
+        let parts = [
+            (self.bonded, direct_apy),
+            (self.pool.as_ref().map(|p| p.points).unwrap_or(0), pool_apy),
+        ];
+        weighted_apy(parts.iter().filter_map(|&(stake, apy)| Some((stake, apy?))))
+    }
+}
+
+/// Totals across a portfolio.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PortfolioSummary {
+    /// Number of accounts.
+    pub accounts: usize,
+    /// Direct bonds plus pooled stake.
+    pub total_bonded: Balance,
+    pub total_unbonding: Balance,
+    pub total_pending_rewards: Balance,
+    /// APY of all stake, each account weighted by its stake. `None` when no
+    /// account has a known APY.
+    pub weighted_apy: Option<f64>,
+}
+
+/// Total a portfolio, using `validators` and `pools` for APY.
+pub fn summarize_portfolio(
+    accounts: &[PortfolioAccount],
+    validators: &[DisplayValidator],
+    pools: &[DisplayPool],
+) -> PortfolioSummary {
+    PortfolioSummary {
+        accounts: accounts.len(),
+        total_bonded: accounts.iter().map(PortfolioAccount::staked).sum(),
+        total_unbonding: accounts.iter().map(|a| a.unbonding).sum(),
+        total_pending_rewards: accounts.iter().map(PortfolioAccount::pending_rewards).sum(),
+        weighted_apy: weighted_apy(
+            accounts
+                .iter()
+                .filter_map(|a| Some((a.staked(), a.apy(validators, pools)?))),
+        ),
+    }
+}
+
+/// Stake-weighted mean of `(stake, apy)` pairs, `None` when the total stake
+/// is zero.
+fn weighted_apy(parts: impl Iterator<Item = (Balance, f64)>) -> Option<f64> {
+    let (stake, weighted) = parts.fold((0.0, 0.0), |(stake, weighted), (s, apy)| {
+        (stake + s as f64, weighted + s as f64 * apy)
+    });
+    (stake > 0.0).then(|| weighted / stake)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::PoolState;
+
+    fn validator(address: &str, apy: Option<f64>) -> DisplayValidator {
+        DisplayValidator::new(address.to_string(), None, 0.05, false, 0, 0, 0, 0, apy)
+    }
+
+    fn pool(id: u32, apy: Option<f64>) -> DisplayPool {
+        DisplayPool::new(id, format!("Pool {}", id), PoolState::Open, 0, 0, None, apy)
+    }
+
+    fn nominator(address: &str, bonded: Balance, targets: &[&str]) -> PortfolioAccount {
+        PortfolioAccount {
+            address: address.to_string(),
+            bonded,
+            targets: targets.iter().map(|t| t.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_account_apy_averages_known_targets() {
+        let validators = [validator("a", Some(0.10)), validator("b", Some(0.20))];
+        let account = nominator("x", 100, &["a", "b", "unknown"]);
+        let apy = account.apy(&validators, &[]).unwrap();
+        assert!((apy - 0.15).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_account_apy_weights_direct_and_pool_stake() {
+        let validators = [validator("a", Some(0.10))];
+        let pools = [pool(7, Some(0.20))];
+        let account = PortfolioAccount {
+            pool: Some(NominationPoolMembership {
+                pool_id: 7,
+                points: 300,
+                pending_rewards: 5,
+            }),
+            ..nominator("x", 100, &["a"])
+        };
+        let apy = account.apy(&validators, &pools).unwrap();
+        assert!((apy - 0.175).abs() < 1e-9);
+        assert_eq!(account.staked(), 400);
+        assert_eq!(account.pending_rewards(), 5);
+    }
+
+    #[test]
+    fn test_account_apy_unknown() {
+        let account = nominator("x", 100, &["a"]);
+        assert_eq!(account.apy(&[validator("a", None)], &[]), None);
+        assert_eq!(PortfolioAccount::default().apy(&[], &[]), None);
+    }
+
+    #[test]
+    fn test_summarize_portfolio() {
+        let validators = [validator("a", Some(0.10)), validator("b", Some(0.20))];
+        let accounts = [
+            PortfolioAccount {
+                unbonding: 10,
+                ..nominator("x", 100, &["a"])
+            },
+            PortfolioAccount {
+                label: Some("Cold".to_string()),
+                unbonding: 5,
+                ..nominator("y", 300, &["b"])
+            },
+            nominator("z", 0, &[]),
+        ];
+        let summary = summarize_portfolio(&accounts, &validators, &[]);
+        assert_eq!(summary.accounts, 3);
+        assert_eq!(summary.total_bonded, 400);
+        assert_eq!(summary.total_unbonding, 15);
+        assert_eq!(summary.total_pending_rewards, 0);
+        assert!((summary.weighted_apy.unwrap() - 0.175).abs() < 1e-9);
+        assert_eq!(accounts[1].display_name(), "Cold");
+        assert_eq!(accounts[2].display_name(), "z");
+    }
+
+    #[test]
+    fn test_summarize_empty_portfolio() {
+        assert_eq!(
+            summarize_portfolio(&[], &[], &[]),
+            PortfolioSummary::default()
+        );
+    }
+}
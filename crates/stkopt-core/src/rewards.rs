@@ -0,0 +1,190 @@
+//! Exact per-era nominator rewards.
+//!
+//! Follows the payout arithmetic of `pallet-staking`: a validator's share of
+//! the era payout is `Perbill::from_rational(points, total_points)`, commission
+//! is taken off the top, and each nominator gets
+//! `Perbill::from_rational(stake, exposure_total)` of what is left. Perbill
+//! rounding is reproduced so the result matches the chain to the planck.
+
+use crate::display::StakingHistoryPoint;
+use crate::types::Balance;
+
+/// Parts per billion of a whole `Perbill`.
+const BILLION: u128 = 1_000_000_000;
+
+/// One validator backing the nominator in an era.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValidatorEraPayout {
+    /// Reward points the validator earned in the era.
+    pub reward_points: u32,
+    /// Validator commission for the era, in parts per billion.
+    pub commission: u32,
+    /// Total stake exposed on the validator, own stake included.
+    pub exposure_total: Balance,
+    /// The nominator's stake in the validator's exposure.
+    pub nominator_stake: Balance,
+}
+
+/// Reward a nominator earned in one era across all validators backing it.
+///
+/// `era_payout` is `Staking.ErasValidatorReward` and `total_points` the era's
+/// total reward points. Returns zero when nobody earned points.
+pub fn nominator_era_reward(
+    era_payout: Balance,
+    total_points: u32,
+    backings: &[ValidatorEraPayout],
+) -> Balance {
+    backings
+        .iter()
+        .map(|b| validator_era_reward(era_payout, total_points, b))
+        .sum()
+}
+
+/// Reward a nominator earned in one era from a single validator, i.e. the
+/// `Staking.Rewarded` amount its payout should emit.
+pub fn validator_era_reward(
+    era_payout: Balance,
+    total_points: u32,
+    backing: &ValidatorEraPayout,
+) -> Balance {
+    let validator_payout = perbill_mul(
+        perbill_from_rational(backing.reward_points as u128, total_points as u128),
+        era_payout,
+    );
+    let commission = perbill_mul(backing.commission.min(BILLION as u32), validator_payout);
+    let leftover = validator_payout.saturating_sub(commission);
+    perbill_mul(
+        perbill_from_rational(backing.nominator_stake, backing.exposure_total),
+        leftover,
+    )
+}
+
+/// `p / q` as Perbill parts, rounded down and capped at one; zero when `q` is
+/// zero.
+fn perbill_from_rational(mut p: u128, mut q: u128) -> u32 {
+    if q == 0 {
+        return 0;
+    }
+    p = p.min(q);
+    // Scale both sides down until `p * BILLION` fits.
+    while p.checked_mul(BILLION).is_none() {
+        p >>= 1;
+        q >>= 1;
+    }
+    (p * BILLION / q.max(1)) as u32
+}
+
+/// `parts * value`, rounded to the nearest planck with ties rounded down.
+pub(crate) fn perbill_mul(parts: u32, value: Balance) -> Balance {
+    let parts = parts as u128;
+    let whole = (value / BILLION) * parts;
+    let rem = (value % BILLION) * parts;
+    let (div, rem) = (rem / BILLION, rem % BILLION);
+    whole + div + u128::from(rem * 2 > BILLION)
+}
+
+/// Estimated and exact reward of the same era.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RewardComparison {
+    pub era: u32,
+    pub estimated: Balance,
+    pub exact: Balance,
+}
+
+impl RewardComparison {
+    /// Exact minus estimated reward.
+    pub fn difference(&self) -> i128 {
+        self.exact as i128 - self.estimated as i128
+    }
+}
+
+/// Pair estimated and exact history points by era, in the order of `estimated`.
+/// Eras without an exact point are skipped.
+pub fn compare_rewards(
+    estimated: &[StakingHistoryPoint],
+    exact: &[StakingHistoryPoint],
+) -> Vec<RewardComparison> {
+    estimated
+        .iter()
+        .filter_map(|e| {
+            let x = exact.iter().find(|x| x.era == e.era)?;
+            Some(RewardComparison {
+                era: e.era,
+                estimated: e.reward,
+                exact: x.reward,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_perbill_from_rational_rounds_down() {
+        assert_eq!(perbill_from_rational(1, 3), 333_333_333);
+        assert_eq!(perbill_from_rational(2, 3), 666_666_666);
+        assert_eq!(perbill_from_rational(5, 0), 0);
+        assert_eq!(perbill_from_rational(7, 5), 1_000_000_000);
+        assert_eq!(perbill_from_rational(u128::MAX / 2, u128::MAX), 499_999_999);
+    }
+
+    #[test]
+    fn test_perbill_mul_rounds_to_nearest() {
+        assert_eq!(perbill_mul(500_000_000, 3), 1);
+        assert_eq!(perbill_mul(500_000_001, 3), 2);
+        assert_eq!(perbill_mul(1_000_000_000, u128::MAX), u128::MAX);
+        assert_eq!(perbill_mul(100_000_000, 1_000_000_000_000), 100_000_000_000);
+    }
+
+    #[test]
+    fn test_nominator_era_reward_single_validator() {
+        // Validator earns half the points of a 1_000_000 payout, keeps 10%
+        // commission, and the nominator holds a quarter of the exposure.
+        let reward = nominator_era_reward(
+            1_000_000,
+            200,
+            &[ValidatorEraPayout {
+                reward_points: 100,
+                commission: 100_000_000,
+                exposure_total: 4_000,
+                nominator_stake: 1_000,
+            }],
+        );
+        assert_eq!(reward, 112_500);
+    }
+
+    #[test]
+    fn test_nominator_era_reward_sums_validators() {
+        let backing = ValidatorEraPayout {
+            reward_points: 50,
+            commission: 0,
+            exposure_total: 1_000,
+            nominator_stake: 500,
+        };
+        let reward = nominator_era_reward(1_000, 100, &[backing, backing]);
+        assert_eq!(reward, 500);
+        assert_eq!(nominator_era_reward(1_000, 0, &[backing]), 0);
+        assert_eq!(nominator_era_reward(1_000, 100, &[]), 0);
+    }
+
+    #[test]
+    fn test_compare_rewards_pairs_by_era() {
+        let estimated = [
+            StakingHistoryPoint::new_without_date(1, 100, 1_000, 0.1),
+            StakingHistoryPoint::new_without_date(2, 100, 1_000, 0.1),
+        ];
+        let exact = [StakingHistoryPoint::new_without_date(2, 90, 1_000, 0.09)];
+        let comparisons = compare_rewards(&estimated, &exact);
+        assert_eq!(
+            comparisons,
+            vec![RewardComparison {
+                era: 2,
+                estimated: 100,
+                exact: 90,
+            }]
+        );
+        assert_eq!(comparisons[0].difference(), -10);
+    }
+}
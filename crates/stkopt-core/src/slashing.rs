@@ -0,0 +1,306 @@
+//! Slash risk of nominated validators.
+//!
+//! Slashes are recorded per era: `Staking.ValidatorSlashInEra` holds the
+//! fraction and amount taken from each slashed validator and
+//! `Staking.NominatorSlashInEra` the amount taken from each nominator.
+//! Slashes are deferred before they are applied; until then they sit in
+//! `Staking.UnappliedSlashes`, listing every nominator that will lose stake.
+//! [`assess_slash_risk`] combines these with offence reports into a
+//! [`SlashRiskReport`] for one account's nominations.
+//!
+//! Offence reports live on the relay chain and are only loaded on request,
+//! so [`SlashHistory::offences`] is empty unless the caller fetched them.
+
+use crate::rewards::perbill_mul;
+use crate::types::{Balance, EraIndex};
+use std::collections::HashSet;
+
+/// A slash recorded against a validator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidatorSlash {
+    /// Era of the offence.
+    pub era: EraIndex,
+    /// SS58 address of the validator.
+    pub validator: String,
+    /// Fraction of the exposed stake slashed, in parts per billion.
+    pub fraction: u32,
+    /// Amount taken from the validator's own stake.
+    pub amount: Balance,
+}
+
+/// A slash applied to a nominator's stake.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NominatorSlash {
+    /// Era of the offence.
+    pub era: EraIndex,
+    pub amount: Balance,
+}
+
+/// A deferred slash that has not been applied yet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingSlash {
+    /// Era in which the slash will be applied.
+    pub apply_era: EraIndex,
+    /// SS58 address of the slashed validator.
+    pub validator: String,
+    /// Amount taken from the validator's own stake.
+    pub own: Balance,
+    /// Amount taken from each nominator, by SS58 address.
+    pub others: Vec<(String, Balance)>,
+}
+
+impl PendingSlash {
+    /// Amount `address` will lose to this slash.
+    pub fn amount_for(&self, address: &str) -> Balance {
+        if self.validator == address {
+            return self.own;
+        }
+        self.others
+            .iter()
+            .filter(|(nominator, _)| nominator == address)
+            .map(|(_, amount)| *amount)
+            .sum()
+    }
+}
+
+/// An offence reported against a validator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OffenceReport {
+    /// SS58 address of the offending validator.
+    pub offender: String,
+    /// Number of accounts that reported the offence.
+    pub reporters: u32,
+}
+
+/// Network-wide slashing state.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SlashHistory {
+    /// Slashes recorded within the history depth.
+    pub validators: Vec<ValidatorSlash>,
+    /// Deferred slashes not applied yet.
+    pub pending: Vec<PendingSlash>,
+    /// Offence reports, when loaded.
+    pub offences: Vec<OffenceReport>,
+    /// Era active when the history was read.
+    pub active_era: EraIndex,
+    /// Eras a slash stays relevant for, the staking bonding duration.
+    pub bonding_duration: u32,
+}
+
+impl SlashHistory {
+    /// Validators slashed within the bonding duration or with a slash
+    /// pending, for excluding them from optimization. Reported offenders are
+    /// added when `exclude_reported` is set.
+    pub fn slashed_validators(&self, exclude_reported: bool) -> HashSet<String> {
+        let since = self.active_era.saturating_sub(self.bonding_duration);
+        let reported = self
+            .offences
+            .iter()
+            .filter(|_| exclude_reported)
+            .map(|o| o.offender.clone());
+        self.validators
+            .iter()
+            .filter(|s| s.era >= since)
+            .map(|s| s.validator.clone())
+            .chain(self.pending.iter().map(|s| s.validator.clone()))
+            .chain(reported)
+            .collect()
+    }
+}
+
+/// Slash risk of one nominated validator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlashRisk {
+    /// SS58 address of the validator.
+    pub validator: String,
+    /// The account's stake behind the validator.
+    pub stake: Balance,
+    /// Deferred slashes pending against the account through this validator.
+    pub pending: Balance,
+    /// Past slashes of the validator, oldest first.
+    pub slashes: Vec<ValidatorSlash>,
+    /// Offence reports against the validator.
+    pub offences: usize,
+    /// Pending loss, or what the worst past slash fraction would take from
+    /// `stake` if repeated, whichever is larger.
+    pub estimated_loss: Balance,
+}
+
+impl SlashRisk {
+    /// Whether the validator has any slash, pending slash or offence.
+    pub fn is_flagged(&self) -> bool {
+        self.pending > 0 || !self.slashes.is_empty() || self.offences > 0
+    }
+}
+
+/// Slash risk of an account's nominations.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SlashRiskReport {
+    /// One entry per nominated validator, riskiest first.
+    pub targets: Vec<SlashRisk>,
+    /// Slashes already applied to the account, oldest first.
+    pub past_slashes: Vec<NominatorSlash>,
+}
+
+impl SlashRiskReport {
+    /// Deferred slashes pending against the account.
+    pub fn total_pending(&self) -> Balance {
+        self.targets.iter().map(|t| t.pending).sum()
+    }
+
+    /// Estimated loss across all nominated validators.
+    pub fn total_estimated_loss(&self) -> Balance {
+        self.targets.iter().map(|t| t.estimated_loss).sum()
+    }
+
+    /// Stake already lost to applied slashes.
+    pub fn total_past(&self) -> Balance {
+        self.past_slashes.iter().map(|s| s.amount).sum()
+    }
+
+    /// Nominated validators with any slash, pending slash or offence.
+    pub fn flagged(&self) -> impl Iterator<Item = &SlashRisk> {
+        self.targets.iter().filter(|t| t.is_flagged())
+    }
+}
+
+/// Assess the slash risk of `address`, nominating `targets` with the given
+/// stake behind each.
+///
+/// `past_slashes` are the account's entries in `NominatorSlashInEra`.
+pub fn assess_slash_risk(
+    address: &str,
+    targets: &[(String, Balance)],
+    history: &SlashHistory,
+    mut past_slashes: Vec<NominatorSlash>,
+) -> SlashRiskReport {
+    let mut risks: Vec<SlashRisk> = targets
+        .iter()
+        .map(|(validator, stake)| {
+            let pending = history
+                .pending
+                .iter()
+                .filter(|s| &s.validator == validator)
+                .map(|s| s.amount_for(address))
+                .sum();
+            let mut slashes: Vec<ValidatorSlash> = history
+                .validators
+                .iter()
+                .filter(|s| &s.validator == validator)
+                .cloned()
+                .collect();
+            slashes.sort_by_key(|s| s.era);
+            let worst = slashes.iter().map(|s| s.fraction).max().unwrap_or(0);
+            SlashRisk {
+                validator: validator.clone(),
+                stake: *stake,
+                pending,
+                offences: history
+                    .offences
+                    .iter()
+                    .filter(|o| &o.offender == validator)
+                    .count(),
+                estimated_loss: pending.max(perbill_mul(worst, *stake)),
+                slashes,
+            }
+        })
+        .collect();
+    risks.sort_by(|a, b| {
+        b.estimated_loss
+            .cmp(&a.estimated_loss)
+            .then_with(|| b.offences.cmp(&a.offences))
+    });
+    past_slashes.sort_by_key(|s| s.era);
+    SlashRiskReport {
+        targets: risks,
+        past_slashes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slash(era: EraIndex, validator: &str, fraction: u32) -> ValidatorSlash {
+        ValidatorSlash {
+            era,
+            validator: validator.to_string(),
+            fraction,
+            amount: 1_000,
+        }
+    }
+
+    fn history() -> SlashHistory {
+        SlashHistory {
+            validators: vec![slash(12, "a", 100_000_000), slash(10, "a", 10_000_000)],
+            pending: vec![PendingSlash {
+                apply_era: 40,
+                validator: "b".to_string(),
+                own: 500,
+                others: vec![("me".to_string(), 30), ("other".to_string(), 70)],
+            }],
+            offences: vec![OffenceReport {
+                offender: "c".to_string(),
+                reporters: 2,
+            }],
+            active_era: 40,
+            bonding_duration: 28,
+        }
+    }
+
+    #[test]
+    fn test_assess_slash_risk() {
+        let targets = [
+            ("a".to_string(), 1_000),
+            ("b".to_string(), 1_000),
+            ("c".to_string(), 1_000),
+            ("d".to_string(), 1_000),
+        ];
+        let past = vec![
+            NominatorSlash { era: 9, amount: 5 },
+            NominatorSlash { era: 3, amount: 7 },
+        ];
+        let report = assess_slash_risk("me", &targets, &history(), past);
+
+        // 10% of 1000 from "a" ranks above the 30 pending through "b".
+        assert_eq!(report.targets[0].validator, "a");
+        assert_eq!(report.targets[0].estimated_loss, 100);
+        assert_eq!(report.targets[0].slashes[0].era, 10);
+        assert_eq!(report.targets[1].validator, "b");
+        assert_eq!(report.targets[1].pending, 30);
+        assert_eq!(report.targets[2].validator, "c");
+        assert_eq!(report.targets[2].offences, 1);
+        assert!(!report.targets[3].is_flagged());
+
+        assert_eq!(report.total_pending(), 30);
+        assert_eq!(report.total_estimated_loss(), 130);
+        assert_eq!(report.total_past(), 12);
+        assert_eq!(report.past_slashes[0].era, 3);
+        assert_eq!(report.flagged().count(), 3);
+    }
+
+    #[test]
+    fn test_pending_slash_amount_for() {
+        let pending = &history().pending[0];
+        assert_eq!(pending.amount_for("b"), 500);
+        assert_eq!(pending.amount_for("me"), 30);
+        assert_eq!(pending.amount_for("nobody"), 0);
+    }
+
+    #[test]
+    fn test_slashed_validators() {
+        let slashed = history().slashed_validators(false);
+        assert_eq!(slashed.len(), 2);
+        assert!(["a", "b"].iter().all(|v| slashed.contains(*v)));
+
+        let slashed = history().slashed_validators(true);
+        assert!(slashed.contains("c"));
+
+        // Slashes before the bonding duration no longer exclude.
+        let old = SlashHistory {
+            active_era: 41,
+            ..history()
+        };
+        assert!(!old.slashed_validators(false).contains("a"));
+    }
+}
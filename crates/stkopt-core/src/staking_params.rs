@@ -0,0 +1,403 @@
+//! On-chain staking parameters and bond amount checks.
+//!
+//! [`StakingParams`] holds the limits the Staking and NominationPools pallets
+//! enforce. [`StakingParams::check_bond`] applies them to an amount before a
+//! transaction is built, so a bond the chain would reject, or one too small
+//! to ever earn rewards, is caught at input time instead of after signing.
+
+use serde::{Deserialize, Serialize};
+
+use crate::display::format_token_balance;
+use crate::types::{Balance, TransactionType};
+
+/// Staking limits read from the chain.
+///
+/// Zero means the limit is unset or unknown, and is not checked.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StakingParams {
+    /// `Staking.MinNominatorBond`: smallest bond that may nominate.
+    pub min_nominator_bond: Balance,
+    /// `Staking.MinimumActiveStake`: smallest stake in the last election's
+    /// active set. Bonds below it are not elected and earn nothing.
+    pub min_active_stake: Balance,
+    /// `Staking.MaxNominatorsCount`, `None` when uncapped.
+    pub max_nominators: Option<u32>,
+    /// `Staking.CounterForNominators`.
+    pub nominator_count: u32,
+    /// `Staking.MaxExposurePageSize`: nominators paid per reward page.
+    pub max_exposure_page_size: u32,
+    /// `NominationPools.MinJoinBond`.
+    pub min_join_bond: Balance,
+    /// `NominationPools.MinCreateBond`.
+    pub min_create_bond: Balance,
+    /// `Staking.BondingDuration` in eras.
+    pub bonding_duration: u32,
+}
+
+/// A problem with a bond amount found by [`StakingParams::check_bond`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BondIssue {
+    /// The resulting bond is below `MinNominatorBond`.
+    BelowMinNominatorBond { bonded: Balance, minimum: Balance },
+    /// Accepted, but a bond below `MinNominatorBond` cannot nominate until
+    /// it is topped up.
+    TooSmallToNominate { bonded: Balance, minimum: Balance },
+    /// The pool bond is below `MinJoinBond`.
+    BelowMinJoinBond { bonded: Balance, minimum: Balance },
+    /// Unbonding would leave a non-zero bond below `minimum`.
+    RemainderBelowMinimum {
+        remaining: Balance,
+        minimum: Balance,
+    },
+    /// `MaxNominatorsCount` is reached, so no new nominator is accepted.
+    NominatorsFull { max: u32 },
+    /// Accepted, but below `MinimumActiveStake`: the stake would not have
+    /// made the last election and would earn no rewards.
+    BelowMinActiveStake { bonded: Balance, minimum: Balance },
+}
+
+impl BondIssue {
+    /// Whether the chain would reject the transaction (as opposed to a warning).
+    pub fn is_error(&self) -> bool {
+        !matches!(
+            self,
+            BondIssue::BelowMinActiveStake { .. } | BondIssue::TooSmallToNominate { .. }
+        )
+    }
+
+    /// Message for display, with amounts in tokens.
+    pub fn message(&self, decimals: u8, symbol: &str) -> String {
+        let fmt = |amount| format_token_balance(amount, decimals, Some(symbol));
+        match self {
+            BondIssue::BelowMinNominatorBond { bonded, minimum } => format!(
+                "Bond of {} is below the minimum nominator bond of {}",
+                fmt(*bonded),
+                fmt(*minimum)
+            ),
+            BondIssue::TooSmallToNominate { bonded, minimum } => format!(
+                "Bond of {} is below the minimum nominator bond of {} and cannot nominate yet",
+                fmt(*bonded),
+                fmt(*minimum)
+            ),
+            BondIssue::BelowMinJoinBond { bonded, minimum } => format!(
+                "Pool bond of {} is below the minimum join bond of {}",
+                fmt(*bonded),
+                fmt(*minimum)
+            ),
+            BondIssue::RemainderBelowMinimum { remaining, minimum } => format!(
+                "Would leave {} bonded, below the minimum of {}; unbond everything instead",
+                fmt(*remaining),
+                fmt(*minimum)
+            ),
+            BondIssue::NominatorsFull { max } => {
+                format!("The nominator limit of {} is reached", max)
+            }
+            BondIssue::BelowMinActiveStake { bonded, minimum } => format!(
+                "Bond of {} is below the minimum active stake of {} and may earn no rewards",
+                fmt(*bonded),
+                fmt(*minimum)
+            ),
+        }
+    }
+}
+
+impl StakingParams {
+    /// Whether `MaxNominatorsCount` is reached.
+    pub fn nominators_full(&self) -> bool {
+        self.max_nominators
+            .is_some_and(|max| self.nominator_count >= max)
+    }
+
+    /// Check `amount` for `tx_type` against these limits.
+    ///
+    /// `bonded` is the account's current active bond: its direct stake for
+    /// staking operations, its pool stake (in balance, not points) for pool
+    /// ones. The nominator limits bind only accounts that nominate: a first
+    /// `Bond` below `MinNominatorBond` is a warning, and changes to the bond
+    /// of an account not nominating are not checked. `Nominate` ignores
+    /// `amount` and checks `bonded`, and a new nominator is rejected while
+    /// `MaxNominatorsCount` is reached. Errors are returned before warnings;
+    /// other transaction types are not checked.
+    pub fn check_bond(
+        &self,
+        tx_type: TransactionType,
+        amount: Balance,
+        bonded: Balance,
+        nominating: bool,
+    ) -> Option<BondIssue> {
+        match tx_type {
+            TransactionType::Nominate => {
+                if !nominating
+                    && self.nominators_full()
+                    && let Some(max) = self.max_nominators
+                {
+                    return Some(BondIssue::NominatorsFull { max });
+                }
+                self.check_nominator_bond(bonded)
+            }
+            TransactionType::Bond if !nominating => {
+                let total = bonded.saturating_add(amount);
+                (total < self.min_nominator_bond).then_some(BondIssue::TooSmallToNominate {
+                    bonded: total,
+                    minimum: self.min_nominator_bond,
+                })
+            }
+            TransactionType::BondExtra | TransactionType::Rebond | TransactionType::Unbond
+                if !nominating =>
+            {
+                None
+            }
+            TransactionType::Bond | TransactionType::BondExtra | TransactionType::Rebond => {
+                let total = bonded.saturating_add(amount);
+                self.check_nominator_bond(total)
+            }
+            TransactionType::Unbond => {
+                let remaining = bonded.saturating_sub(amount);
+                if remaining == 0 {
+                    return None;
+                }
+                if remaining < self.min_nominator_bond {
+                    return Some(BondIssue::RemainderBelowMinimum {
+                        remaining,
+                        minimum: self.min_nominator_bond,
+                    });
+                }
+                self.check_nominator_bond(remaining)
+            }
+            TransactionType::PoolJoin => {
+                (amount < self.min_join_bond).then_some(BondIssue::BelowMinJoinBond {
+                    bonded: amount,
+                    minimum: self.min_join_bond,
+                })
+            }
+            TransactionType::PoolUnbond => {
+                let remaining = bonded.saturating_sub(amount);
+                (remaining > 0 && remaining < self.min_join_bond).then_some(
+                    BondIssue::RemainderBelowMinimum {
+                        remaining,
+                        minimum: self.min_join_bond,
+                    },
+                )
+            }
+            _ => None,
+        }
+    }
+
+    fn check_nominator_bond(&self, bonded: Balance) -> Option<BondIssue> {
+        if bonded < self.min_nominator_bond {
+            Some(BondIssue::BelowMinNominatorBond {
+                bonded,
+                minimum: self.min_nominator_bond,
+            })
+        } else if bonded < self.min_active_stake {
+            Some(BondIssue::BelowMinActiveStake {
+                bonded,
+                minimum: self.min_active_stake,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params() -> StakingParams {
+        StakingParams {
+            min_nominator_bond: 250,
+            min_active_stake: 500,
+            max_nominators: Some(10),
+            nominator_count: 5,
+            max_exposure_page_size: 512,
+            min_join_bond: 1,
+            min_create_bond: 500,
+            bonding_duration: 28,
+        }
+    }
+
+    #[test]
+    fn test_bond_below_min_nominator_bond() {
+        let issue = params()
+            .check_bond(TransactionType::Bond, 100, 0, true)
+            .unwrap();
+        assert_eq!(
+            issue,
+            BondIssue::BelowMinNominatorBond {
+                bonded: 100,
+                minimum: 250
+            }
+        );
+        assert!(issue.is_error());
+    }
+
+    #[test]
+    fn test_bond_below_min_active_stake_is_warning() {
+        let issue = params()
+            .check_bond(TransactionType::Bond, 300, 0, true)
+            .unwrap();
+        assert!(!issue.is_error());
+        assert_eq!(
+            params().check_bond(TransactionType::Bond, 600, 0, true),
+            None
+        );
+    }
+
+    #[test]
+    fn test_bond_extra_counts_existing_bond() {
+        assert_eq!(
+            params().check_bond(TransactionType::BondExtra, 100, 450, true),
+            None
+        );
+        assert!(
+            params()
+                .check_bond(TransactionType::BondExtra, 10, 100, true)
+                .unwrap()
+                .is_error()
+        );
+    }
+
+    #[test]
+    fn test_nominate_rejected_when_nominators_full() {
+        let mut params = params();
+        params.nominator_count = 10;
+        assert_eq!(
+            params.check_bond(TransactionType::Nominate, 0, 1_000, false),
+            Some(BondIssue::NominatorsFull { max: 10 })
+        );
+        // Existing nominators may change their targets
+        assert_eq!(
+            params.check_bond(TransactionType::Nominate, 0, 1_000, true),
+            None
+        );
+        params.max_nominators = None;
+        assert_eq!(
+            params.check_bond(TransactionType::Nominate, 0, 1_000, false),
+            None
+        );
+    }
+
+    #[test]
+    fn test_nominate_checks_active_bond() {
+        assert_eq!(
+            params().check_bond(TransactionType::Nominate, 1_000, 100, false),
+            Some(BondIssue::BelowMinNominatorBond {
+                bonded: 100,
+                minimum: 250
+            })
+        );
+        assert!(
+            !params()
+                .check_bond(TransactionType::Nominate, 0, 300, false)
+                .unwrap()
+                .is_error()
+        );
+        assert_eq!(
+            params().check_bond(TransactionType::Nominate, 0, 600, false),
+            None
+        );
+    }
+
+    #[test]
+    fn test_bond_before_nominating_warns_below_min_nominator_bond() {
+        let mut params = params();
+        // Bonding adds no nominator, so the cap does not apply
+        params.nominator_count = 10;
+        let issue = params
+            .check_bond(TransactionType::Bond, 100, 0, false)
+            .unwrap();
+        assert_eq!(
+            issue,
+            BondIssue::TooSmallToNominate {
+                bonded: 100,
+                minimum: 250
+            }
+        );
+        assert!(!issue.is_error());
+        assert_eq!(
+            params.check_bond(TransactionType::Bond, 300, 0, false),
+            None
+        );
+    }
+
+    #[test]
+    fn test_nominator_limits_skip_accounts_not_nominating() {
+        let mut params = params();
+        params.nominator_count = 10;
+        assert_eq!(
+            params.check_bond(TransactionType::BondExtra, 10, 100, false),
+            None
+        );
+        assert_eq!(
+            params.check_bond(TransactionType::Unbond, 500, 600, false),
+            None
+        );
+    }
+
+    #[test]
+    fn test_unbond_remainder() {
+        // Unbonding everything is always fine
+        assert_eq!(
+            params().check_bond(TransactionType::Unbond, 600, 600, true),
+            None
+        );
+        assert_eq!(
+            params().check_bond(TransactionType::Unbond, 500, 600, true),
+            Some(BondIssue::RemainderBelowMinimum {
+                remaining: 100,
+                minimum: 250
+            })
+        );
+    }
+
+    #[test]
+    fn test_pool_checks() {
+        let mut params = params();
+        params.min_join_bond = 10;
+        assert!(
+            params
+                .check_bond(TransactionType::PoolJoin, 5, 0, false)
+                .is_some()
+        );
+        assert_eq!(
+            params.check_bond(TransactionType::PoolJoin, 10, 0, false),
+            None
+        );
+        assert_eq!(
+            params.check_bond(TransactionType::PoolUnbond, 20, 20, false),
+            None
+        );
+        assert!(
+            params
+                .check_bond(TransactionType::PoolUnbond, 15, 20, false)
+                .is_some()
+        );
+        assert_eq!(
+            params.check_bond(TransactionType::PoolBondExtra, 1, 0, false),
+            None
+        );
+    }
+
+    #[test]
+    fn test_unknown_limits_are_not_checked() {
+        let params = StakingParams::default();
+        assert_eq!(params.check_bond(TransactionType::Bond, 1, 0, true), None);
+        assert_eq!(
+            params.check_bond(TransactionType::PoolJoin, 1, 0, false),
+            None
+        );
+    }
+
+    #[test]
+    fn test_issue_message() {
+        let issue = BondIssue::BelowMinNominatorBond {
+            bonded: 10_000_000_000,
+            minimum: 2_500_000_000_000,
+        };
+        assert_eq!(
+            issue.message(10, "DOT"),
+            "Bond of 1 DOT is below the minimum nominator bond of 250 DOT"
+        );
+    }
+}
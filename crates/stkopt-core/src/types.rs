@@ -2,25 +2,39 @@
 
 use serde::{Deserialize, Serialize};
 
+use std::sync::Arc;
+
+use crate::custom_network::{CustomNetwork, CustomNetworkError};
+
 pub type Balance = u128;
 pub type EraIndex = u32;
 
 /// Supported networks - exhaustive match required (no default case).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+///
+/// `Custom` is a user-defined network and carries its definition; build it
+/// with [`Network::from_custom`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Network {
     Polkadot,
     Kusama,
     Westend,
     Paseo,
+    Custom(Arc<CustomNetwork>),
 }
 
 impl Network {
-    pub fn token_symbol(&self) -> &'static str {
+    /// A custom network from a definition, once it validates.
+    pub fn from_custom(network: CustomNetwork) -> Result<Self, CustomNetworkError> {
+        Ok(Network::Custom(Arc::new(network.normalized()?)))
+    }
+
+    pub fn token_symbol(&self) -> &str {
         match self {
             Network::Polkadot => "DOT",
             Network::Kusama => "KSM",
             Network::Westend => "WND",
             Network::Paseo => "PAS",
+            Network::Custom(network) => &network.token_symbol,
         }
     }
 
@@ -30,6 +44,7 @@ impl Network {
             Network::Kusama => 12,
             Network::Westend => 12,
             Network::Paseo => 10,
+            Network::Custom(network) => network.token_decimals,
         }
     }
 
@@ -39,11 +54,80 @@ impl Network {
             Network::Kusama => 2,
             Network::Westend => 42,
             Network::Paseo => 0,
+            Network::Custom(network) => network.ss58_prefix,
+        }
+    }
+
+    /// Relay chain genesis hash as lowercase `0x`-prefixed hex.
+    ///
+    /// Empty for a custom network without a configured hash.
+    pub fn relay_genesis_hash(&self) -> &str {
+        match self {
+            Network::Polkadot => {
+                "0x91b171bb158e2d3848fa23a9f1c25182fb8e20313b2c1eb49219da7a70ce90c3"
+            }
+            Network::Kusama => "0xb0a8d493285c2df73290dfb7e61f870f17b41801197a149ca93654499ea3dafe",
+            Network::Westend => {
+                "0xe143f23803ac50e8f6f8e62695d1ce9e4e1d68aa36c1cd2cfd15340213f3423e"
+            }
+            Network::Paseo => "0x77afd6190f1554ad45fd0d31aee62aacc33c6db0ea801129acb813f913e0764f",
+            Network::Custom(network) => network.relay_genesis_hash.as_deref().unwrap_or(""),
+        }
+    }
+
+    /// Asset Hub genesis hash as lowercase `0x`-prefixed hex.
+    ///
+    /// Empty for a custom network without a configured hash.
+    pub fn asset_hub_genesis_hash(&self) -> &str {
+        match self {
+            Network::Polkadot => {
+                "0x68d56f15f85d3136970ec16946040bc1752654e906147f7e43e9d539d7c3de2f"
+            }
+            Network::Kusama => "0x48239ef607d7928874027a43a67689209727dfb3d3dc5e5b03a39bdc2eda771a",
+            Network::Westend => {
+                "0x67f9723393ef76214df0118c34bbbd3dbebc8ed46a10973a8c969d48fe7598c9"
+            }
+            Network::Paseo => "0xd6eec26135305a8ad257a20d003357284c8aa03d0bdb2b357ab0a22371e11ef2",
+            Network::Custom(network) => network.asset_hub_genesis_hash.as_deref().unwrap_or(""),
+        }
+    }
+
+    /// Whether the relay chain or Asset Hub has the given genesis hash.
+    ///
+    /// Accepts hex with or without `0x` prefix, in any case.
+    pub fn has_genesis_hash(&self, hash: &str) -> bool {
+        let hash = hash.trim().to_ascii_lowercase();
+        let hash = hash.strip_prefix("0x").unwrap_or(&hash);
+        !hash.is_empty()
+            && [self.relay_genesis_hash(), self.asset_hub_genesis_hash()]
+                .iter()
+                .any(|known| known.strip_prefix("0x") == Some(hash))
+    }
+
+    /// Find the built-in network whose relay chain or Asset Hub has the given
+    /// genesis hash.
+    pub fn from_genesis_hash(hash: &str) -> Option<Network> {
+        Network::builtin()
+            .iter()
+            .find(|network| network.has_genesis_hash(hash))
+            .cloned()
+    }
+
+    /// The user-defined network definition, for `Network::Custom`.
+    pub fn custom(&self) -> Option<&CustomNetwork> {
+        match self {
+            Network::Custom(network) => Some(network),
+            _ => None,
         }
     }
 
-    /// Returns all known networks.
-    pub fn all() -> &'static [Network] {
+    /// Whether this is the user-defined network.
+    pub fn is_custom(&self) -> bool {
+        matches!(self, Network::Custom(_))
+    }
+
+    /// Returns the built-in networks.
+    pub fn builtin() -> &'static [Network] {
         &[
             Network::Polkadot,
             Network::Kusama,
@@ -60,6 +144,7 @@ impl std::fmt::Display for Network {
             Network::Kusama => write!(f, "Kusama"),
             Network::Westend => write!(f, "Westend"),
             Network::Paseo => write!(f, "Paseo"),
+            Network::Custom(network) => write!(f, "{}", network.name),
         }
     }
 }
@@ -351,9 +436,46 @@ mod tests {
         assert_eq!(Network::Paseo.ss58_format(), 0);
     }
 
+    #[test]
+    fn test_network_genesis_hashes_are_distinct() {
+        let mut hashes: Vec<&str> = Network::builtin()
+            .iter()
+            .flat_map(|n| [n.relay_genesis_hash(), n.asset_hub_genesis_hash()])
+            .collect();
+        for hash in &hashes {
+            assert_eq!(hash.len(), 66);
+            assert!(hash.starts_with("0x"));
+        }
+        hashes.sort();
+        hashes.dedup();
+        assert_eq!(hashes.len(), Network::builtin().len() * 2);
+    }
+
+    #[test]
+    fn test_network_from_genesis_hash() {
+        for network in Network::builtin() {
+            assert_eq!(
+                Network::from_genesis_hash(network.relay_genesis_hash()),
+                Some(network.clone())
+            );
+            assert_eq!(
+                Network::from_genesis_hash(network.asset_hub_genesis_hash()),
+                Some(network.clone())
+            );
+        }
+        assert_eq!(
+            Network::from_genesis_hash(
+                "91B171BB158E2D3848FA23A9F1C25182FB8E20313B2C1EB49219DA7A70CE90C3"
+            ),
+            Some(Network::Polkadot)
+        );
+        assert_eq!(Network::from_genesis_hash("0x1234"), None);
+        assert_eq!(Network::from_genesis_hash(""), None);
+    }
+
     #[test]
     fn test_network_all() {
-        let all = Network::all();
+        let all = Network::builtin();
         assert_eq!(all.len(), 4);
         assert!(all.contains(&Network::Polkadot));
         assert!(all.contains(&Network::Kusama));
@@ -0,0 +1,278 @@
+//! Polkadot Vault account export QR parsing.
+//!
+//! Vault exports accounts as text QR codes of the form
+//! `substrate:<ss58 address>:<0x genesis hash>`, optionally followed by
+//! `:<account name>`. Parsing and network matching live here so both
+//! frontends onboard a Vault device the same way.
+
+use crate::types::Network;
+
+/// URI scheme used by Vault for Substrate account exports.
+pub const VAULT_ACCOUNT_SCHEME: &str = "substrate";
+
+/// Errors returned when a scanned QR is not a usable Vault account export.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum VaultQrError {
+    /// The QR payload is not UTF-8 text.
+    #[error("QR code is not text")]
+    NotText,
+    /// The QR text does not use the `substrate:` scheme.
+    #[error("Not a Vault account QR (expected substrate:<address>:<genesis>)")]
+    NotVaultAccount,
+    /// The address part is missing or malformed.
+    #[error("Invalid address in Vault QR: {0}")]
+    InvalidAddress(String),
+    /// The genesis hash part is missing or malformed.
+    #[error("Invalid genesis hash in Vault QR: {0}")]
+    InvalidGenesis(String),
+    /// The genesis hash belongs to a different network than the connected one.
+    #[error("Vault account is for {found}, but the app is connected to {expected}")]
+    NetworkMismatch {
+        /// Network the app is connected to.
+        expected: Network,
+        /// Network matching the scanned genesis hash, or "an unknown network".
+        found: String,
+    },
+}
+
+/// An account exported by Polkadot Vault.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VaultAccountQr {
+    /// SS58-encoded address as exported by Vault.
+    pub address: String,
+    /// Genesis hash as lowercase `0x`-prefixed hex.
+    pub genesis_hash: String,
+    /// Account name, if Vault included one.
+    pub name: Option<String>,
+}
+
+impl VaultAccountQr {
+    /// Network matching the exported genesis hash, if known.
+    pub fn network(&self) -> Option<Network> {
+        Network::from_genesis_hash(&self.genesis_hash)
+    }
+
+    /// Check that the account was exported for `expected`.
+    pub fn ensure_network(&self, expected: Network) -> Result<(), VaultQrError> {
+        if expected.has_genesis_hash(&self.genesis_hash) {
+            return Ok(());
+        }
+        match self.network() {
+            Some(network) => Err(VaultQrError::NetworkMismatch {
+                expected,
+                found: network.to_string(),
+            }),
+            None => Err(VaultQrError::NetworkMismatch {
+                expected,
+                found: "an unknown network".to_string(),
+            }),
+        }
+    }
+
+    /// Address book label: the Vault account name, or `Vault <short address>`.
+    pub fn default_label(&self) -> String {
+        match self.name.as_deref() {
+            Some(name) => name.to_string(),
+            None => {
+                let short: String = self.address.chars().take(6).collect();
+                format!("Vault {}…", short)
+            }
+        }
+    }
+}
+
+/// Parse a scanned QR payload as a Vault account export.
+pub fn parse_vault_account_qr(data: &[u8]) -> Result<VaultAccountQr, VaultQrError> {
+    let text = std::str::from_utf8(data).map_err(|_| VaultQrError::NotText)?;
+    let mut parts = text.trim().splitn(4, ':');
+
+    let scheme = parts.next().unwrap_or_default();
+    if !scheme.eq_ignore_ascii_case(VAULT_ACCOUNT_SCHEME) {
+        return Err(VaultQrError::NotVaultAccount);
+    }
+
+    let address = parts.next().unwrap_or_default().trim();
+    if address.is_empty() {
+        return Err(VaultQrError::InvalidAddress("missing address".to_string()));
+    }
+    if !address.chars().all(is_base58_char) {
+        return Err(VaultQrError::InvalidAddress(format!(
+            "'{}' is not an SS58 address",
+            address
+        )));
+    }
+
+    let genesis = parts.next().unwrap_or_default().trim().to_ascii_lowercase();
+    let genesis_hex = genesis.strip_prefix("0x").unwrap_or(&genesis);
+    if genesis_hex.len() != 64 || !genesis_hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(VaultQrError::InvalidGenesis(format!(
+            "expected 32-byte hex, got '{}'",
+            genesis
+        )));
+    }
+
+    let name = parts
+        .next()
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string);
+
+    Ok(VaultAccountQr {
+        address: address.to_string(),
+        genesis_hash: format!("0x{}", genesis_hex),
+        name,
+    })
+}
+
+fn is_base58_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() && !matches!(c, '0' | 'O' | 'I' | 'l')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALICE: &str = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+
+    fn qr(network: Network) -> String {
+        format!("substrate:{}:{}", ALICE, network.relay_genesis_hash())
+    }
+
+    #[test]
+    fn test_parse_vault_account_qr() {
+        let parsed = parse_vault_account_qr(qr(Network::Westend).as_bytes()).unwrap();
+        assert_eq!(parsed.address, ALICE);
+        assert_eq!(parsed.genesis_hash, Network::Westend.relay_genesis_hash());
+        assert_eq!(parsed.name, None);
+        assert_eq!(parsed.network(), Some(Network::Westend));
+    }
+
+    #[test]
+    fn test_parse_vault_account_qr_with_name() {
+        let text = format!("{}:Cold storage", qr(Network::Polkadot));
+        let parsed = parse_vault_account_qr(text.as_bytes()).unwrap();
+        assert_eq!(parsed.name.as_deref(), Some("Cold storage"));
+        assert_eq!(parsed.default_label(), "Cold storage");
+    }
+
+    #[test]
+    fn test_parse_vault_account_qr_normalizes_genesis() {
+        let text = format!(
+            "Substrate:{}:{}\n",
+            ALICE,
+            Network::Kusama.relay_genesis_hash()[2..].to_uppercase()
+        );
+        let parsed = parse_vault_account_qr(text.as_bytes()).unwrap();
+        assert_eq!(parsed.genesis_hash, Network::Kusama.relay_genesis_hash());
+    }
+
+    #[test]
+    fn test_parse_vault_account_qr_asset_hub_genesis() {
+        let text = format!(
+            "substrate:{}:{}",
+            ALICE,
+            Network::Polkadot.asset_hub_genesis_hash()
+        );
+        let parsed = parse_vault_account_qr(text.as_bytes()).unwrap();
+        assert_eq!(parsed.network(), Some(Network::Polkadot));
+    }
+
+    #[test]
+    fn test_parse_vault_account_qr_rejects_non_text() {
+        assert_eq!(
+            parse_vault_account_qr(&[0x53, 0xff, 0xfe]),
+            Err(VaultQrError::NotText)
+        );
+    }
+
+    #[test]
+    fn test_parse_vault_account_qr_rejects_other_schemes() {
+        let text = format!("ethereum:{}:0x01", ALICE);
+        assert_eq!(
+            parse_vault_account_qr(text.as_bytes()),
+            Err(VaultQrError::NotVaultAccount)
+        );
+        assert_eq!(
+            parse_vault_account_qr(ALICE.as_bytes()),
+            Err(VaultQrError::NotVaultAccount)
+        );
+    }
+
+    #[test]
+    fn test_parse_vault_account_qr_rejects_bad_address() {
+        let text = format!("substrate::{}", Network::Polkadot.relay_genesis_hash());
+        assert!(matches!(
+            parse_vault_account_qr(text.as_bytes()),
+            Err(VaultQrError::InvalidAddress(_))
+        ));
+        let text = format!(
+            "substrate:0xdead:{}",
+            Network::Polkadot.relay_genesis_hash()
+        );
+        assert!(matches!(
+            parse_vault_account_qr(text.as_bytes()),
+            Err(VaultQrError::InvalidAddress(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_vault_account_qr_rejects_bad_genesis() {
+        for genesis in ["", "0x1234", &"zz".repeat(32)] {
+            let text = format!("substrate:{}:{}", ALICE, genesis);
+            assert!(matches!(
+                parse_vault_account_qr(text.as_bytes()),
+                Err(VaultQrError::InvalidGenesis(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_ensure_network() {
+        let parsed = parse_vault_account_qr(qr(Network::Kusama).as_bytes()).unwrap();
+        assert!(parsed.ensure_network(Network::Kusama).is_ok());
+        let err = parsed.ensure_network(Network::Polkadot).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Vault account is for Kusama, but the app is connected to Polkadot"
+        );
+    }
+
+    #[test]
+    fn test_ensure_network_unknown_genesis() {
+        let text = format!("substrate:{}:0x{}", ALICE, "ab".repeat(32));
+        let parsed = parse_vault_account_qr(text.as_bytes()).unwrap();
+        assert_eq!(parsed.network(), None);
+        assert!(matches!(
+            parsed.ensure_network(Network::Polkadot),
+            Err(VaultQrError::NetworkMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_ensure_custom_network() {
+        let genesis = format!("0x{}", "ab".repeat(32));
+        let custom = Network::from_custom(crate::custom_network::CustomNetwork {
+            name: "Testnet".to_string(),
+            token_symbol: "TST".to_string(),
+            token_decimals: 10,
+            ss58_prefix: 42,
+            relay: crate::custom_network::ChainSource::rpc(["ws://127.0.0.1:9944"]),
+            asset_hub: None,
+            people: None,
+            relay_genesis_hash: Some(genesis.to_uppercase().replacen("0X", "0x", 1)),
+            asset_hub_genesis_hash: None,
+            staking_indexer_url: None,
+        })
+        .unwrap();
+        let parsed =
+            parse_vault_account_qr(format!("substrate:{}:{}", ALICE, genesis).as_bytes()).unwrap();
+        assert!(parsed.ensure_network(custom).is_ok());
+        assert!(parsed.ensure_network(Network::Polkadot).is_err());
+    }
+
+    #[test]
+    fn test_default_label_without_name() {
+        let parsed = parse_vault_account_qr(qr(Network::Westend).as_bytes()).unwrap();
+        assert_eq!(parsed.default_label(), "Vault 5Grwva…");
+    }
+}
@@ -90,6 +90,44 @@ pub fn validate_address(input: &str) -> ValidationResult {
     }
 }
 
+/// Parse a scanned Polkadot Vault account export for `network`.
+///
+/// Checks the `substrate:<address>:<genesis>` format, that the genesis hash
+/// belongs to `network`, and that the address is valid SS58.
+pub fn parse_vault_account(
+    data: &[u8],
+    network: stkopt_core::Network,
+) -> Result<stkopt_core::VaultAccountQr, String> {
+    let account = stkopt_core::parse_vault_account_qr(data).map_err(|e| e.to_string())?;
+    account
+        .ensure_network(network.clone())
+        .map_err(|e| e.to_string())?;
+    match validate_address(&account.address) {
+        ValidationResult::Valid(_) => {}
+        ValidationResult::Invalid(msg) => return Err(msg),
+        ValidationResult::Empty => return Err("Vault QR has no address".to_string()),
+    }
+    stkopt_chain::ensure_ss58_prefix(&account.address, network.ss58_format())
+        .map_err(|e| format!("Vault QR for {}: {}", network, e))?;
+    Ok(account)
+}
+
+/// Warning for a valid address encoded with another network's SS58 prefix.
+pub fn ss58_prefix_warning(input: &str, network: stkopt_core::Network) -> Option<String> {
+    if !validate_address(input).is_valid() {
+        return None;
+    }
+    let prefix = stkopt_chain::decode_ss58_prefix(input).ok()?;
+    (prefix != network.ss58_format()).then(|| {
+        format!(
+            "Address uses SS58 prefix {}, not the {} prefix {} - check the network",
+            prefix,
+            network,
+            network.ss58_format()
+        )
+    })
+}
+
 /// Truncate an address for display (e.g., "1abc...xyz").
 pub fn truncate_address(address: &str, prefix_len: usize, suffix_len: usize) -> String {
     if address.len() <= prefix_len + suffix_len + 3 {
@@ -114,6 +152,62 @@ mod tests {
     // Valid Polkadot address for testing
     const VALID_POLKADOT_ADDRESS: &str = "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5";
 
+    #[test]
+    fn test_parse_vault_account() {
+        let qr = format!(
+            "substrate:{}:{}",
+            VALID_POLKADOT_ADDRESS,
+            stkopt_core::Network::Polkadot.relay_genesis_hash()
+        );
+        let account = parse_vault_account(qr.as_bytes(), stkopt_core::Network::Polkadot).unwrap();
+        assert_eq!(account.address, VALID_POLKADOT_ADDRESS);
+    }
+
+    #[test]
+    fn test_parse_vault_account_wrong_network() {
+        let qr = format!(
+            "substrate:{}:{}",
+            VALID_POLKADOT_ADDRESS,
+            stkopt_core::Network::Westend.relay_genesis_hash()
+        );
+        let err = parse_vault_account(qr.as_bytes(), stkopt_core::Network::Polkadot).unwrap_err();
+        assert!(err.contains("Westend"));
+    }
+
+    #[test]
+    fn test_parse_vault_account_wrong_prefix() {
+        let qr = format!(
+            "substrate:HNZata7iMYWmk5RvZRTiAsSDhV8366zq2YGb3tLH5Upf74F:{}",
+            stkopt_core::Network::Polkadot.relay_genesis_hash()
+        );
+        let err = parse_vault_account(qr.as_bytes(), stkopt_core::Network::Polkadot).unwrap_err();
+        assert!(err.contains("prefix 2"), "{err}");
+    }
+
+    #[test]
+    fn test_ss58_prefix_warning() {
+        use stkopt_core::Network;
+        assert!(ss58_prefix_warning(VALID_POLKADOT_ADDRESS, Network::Polkadot).is_none());
+        let warning = ss58_prefix_warning(VALID_POLKADOT_ADDRESS, Network::Kusama).unwrap();
+        assert!(warning.contains("Kusama prefix 2"), "{warning}");
+        assert!(ss58_prefix_warning("abc", Network::Kusama).is_none());
+    }
+
+    #[test]
+    fn test_parse_vault_account_invalid_checksum() {
+        let qr = format!(
+            "substrate:15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp6:{}",
+            stkopt_core::Network::Polkadot.relay_genesis_hash()
+        );
+        let err = parse_vault_account(qr.as_bytes(), stkopt_core::Network::Polkadot).unwrap_err();
+        assert!(err.contains("Invalid address"));
+    }
+
+    #[test]
+    fn test_parse_vault_account_signature_qr() {
+        assert!(parse_vault_account(&[0x01, 0x02, 0x03], stkopt_core::Network::Polkadot).is_err());
+    }
+
     #[test]
     fn test_validate_empty_address() {
         assert_eq!(validate_address(""), ValidationResult::Empty);
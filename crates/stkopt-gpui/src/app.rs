@@ -1,5 +1,6 @@
 //! Main application state and view for the Staking Optimizer desktop app.
 
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
@@ -15,7 +16,17 @@ use crate::views::{
 
 // Use the shared domain types from `stkopt-core` and `stkopt-chain` instead of local duplicates.
 pub use stkopt_chain::ConnectionMode;
+use stkopt_chain::{
+    PoolRoleUpdate, UnbondingEntry, UnbondingSource, encode_ss58, slash_risk_targets,
+};
 pub use stkopt_core::Network;
+use stkopt_core::{
+    BagsListPosition, BondIssue, ClaimPermission, CommissionChangeRate, EraIndex, HealthAccount,
+    HealthReport, NominatorSlash, PayoutCheck, PoolAdmin, PoolAdminAction, PoolMemberCall,
+    PoolMemberDetails, PortfolioAccount, PortfolioSummary, SlashHistory, SlashRiskReport,
+    StakingParams, TransactionType, assess_slash_risk, check_health, perbill_from_percent,
+    summarize_portfolio,
+};
 
 const LOG_PANE_DEFAULT_HEIGHT: f32 = 180.0;
 const LOG_PANE_MIN_HEIGHT: f32 = 120.0;
@@ -41,10 +52,256 @@ pub(crate) fn optimization_available(validators_loading: bool, validator_count:
     !validators_loading && validator_count > 0
 }
 
+/// Default directory for exported signing QR images (Downloads, else the working directory).
+pub(crate) fn default_qr_export_dir() -> std::path::PathBuf {
+    directories::UserDirs::new()
+        .and_then(|dirs| dirs.download_dir().map(|dir| dir.to_path_buf()))
+        .unwrap_or_else(|| std::path::PathBuf::from("."))
+}
+
+/// Signer address as stored in the transaction journal (network SS58 format).
+pub(crate) fn journal_signer(network: Network, account: &subxt::utils::AccountId32) -> String {
+    stkopt_chain::encode_ss58(account, network.ss58_format())
+        .unwrap_or_else(|_| account.to_string())
+}
+
+/// Journal entry for a signed transaction about to be submitted.
+pub(crate) fn journal_entry(
+    network: Network,
+    payload: &crate::chain::TransactionPayload,
+    tx_hash: &[u8; 32],
+) -> stkopt_core::NewTransaction {
+    let unsigned = &payload.unsigned_payload;
+    let mortality = match unsigned.era {
+        stkopt_chain::Era::Immortal => stkopt_core::TxMortality::Immortal,
+        stkopt_chain::Era::Mortal { period, phase } => {
+            stkopt_core::TxMortality::Mortal { period, phase }
+        }
+    };
+    stkopt_core::NewTransaction {
+        signer: journal_signer(network, &payload.signer),
+        description: payload.description.clone(),
+        call_data: format!("0x{}", hex::encode(&unsigned.call_data)),
+        nonce: unsigned.nonce,
+        mortality,
+        tx_hash: format!("0x{}", hex::encode(tx_hash)),
+    }
+}
+
+/// QR modal status and message for a transaction submission result.
+pub(crate) fn tx_submission_status(
+    result: &Result<crate::chain::TxSubmissionResult, String>,
+) -> (QrTxStatus, String) {
+    use crate::chain::TxSubmissionResult;
+    let (verb, block_hash, outcome) = match result {
+        Ok(TxSubmissionResult::InBlock {
+            block_hash,
+            outcome,
+        }) => ("included", block_hash, outcome),
+        Ok(TxSubmissionResult::Finalized {
+            block_hash,
+            outcome,
+        }) => ("finalized", block_hash, outcome),
+        Ok(TxSubmissionResult::Dropped(reason)) => {
+            return (
+                QrTxStatus::Failed,
+                format!("Transaction dropped: {}", reason),
+            );
+        }
+        Err(e) if stkopt_core::is_expired_tx_error(e) => {
+            return (
+                QrTxStatus::Expired,
+                "Transaction expired before submission. Rebuild it and sign again.".to_string(),
+            );
+        }
+        Err(e) => {
+            return (
+                QrTxStatus::Failed,
+                format!("Transaction submission failed: {}", e),
+            );
+        }
+    };
+    let included = format!(
+        "Transaction {} in block 0x{}",
+        verb,
+        hex::encode(block_hash)
+    );
+    match outcome {
+        Some(outcome) if !outcome.is_success() => (
+            QrTxStatus::Failed,
+            format!("{}. {}", included, outcome.summary()),
+        ),
+        Some(outcome) => (
+            QrTxStatus::Submitted,
+            format!("{}. {}", included, outcome.summary()),
+        ),
+        None => (QrTxStatus::Submitted, included),
+    }
+}
+
+/// Journal status, block hash and dispatch result for a transaction submission result.
+pub(crate) fn tx_journal_update(
+    result: &Result<crate::chain::TxSubmissionResult, String>,
+) -> (stkopt_core::TxJournalStatus, Option<String>, Option<String>) {
+    use crate::chain::TxSubmissionResult;
+    use stkopt_core::TxJournalStatus;
+    let (status, block_hash, outcome) = match result {
+        Ok(TxSubmissionResult::InBlock {
+            block_hash,
+            outcome,
+        }) => (TxJournalStatus::InBlock, block_hash, outcome),
+        Ok(TxSubmissionResult::Finalized {
+            block_hash,
+            outcome,
+        }) => (TxJournalStatus::Finalized, block_hash, outcome),
+        Ok(TxSubmissionResult::Dropped(reason)) => {
+            return (
+                TxJournalStatus::Failed,
+                None,
+                Some(format!("Dropped: {}", reason)),
+            );
+        }
+        Err(e) => return (TxJournalStatus::Failed, None, Some(e.clone())),
+    };
+    let status = if outcome.as_ref().is_some_and(|o| !o.is_success()) {
+        TxJournalStatus::Failed
+    } else {
+        status
+    };
+    (
+        status,
+        Some(format!("0x{}", hex::encode(block_hash))),
+        outcome.as_ref().map(|o| o.summary()),
+    )
+}
+
+/// Write a signing QR export and describe the outcome for the QR modal.
+pub(crate) fn qr_export_message(
+    data: &[u8],
+    format: stkopt_chain::QrExportFormat,
+    dir: &std::path::Path,
+) -> String {
+    match stkopt_chain::write_qr_export(data, format, dir) {
+        Ok((path, export)) => {
+            tracing::info!(
+                "Exported {} QR ({} frame(s)) to {}",
+                export.format,
+                export.frames,
+                path.display()
+            );
+            format!("Exported to {}", path.display())
+        }
+        Err(e) => {
+            tracing::warn!("QR export failed: {}", e);
+            format!("Export failed: {}", e)
+        }
+    }
+}
+
 pub(crate) fn parse_token_amount(input: &str, decimals: u8) -> Result<u128, String> {
     stkopt_core::parse_token_amount(input, decimals)
 }
 
+/// Build a pool admin call from the modal input.
+///
+/// Commission values are percentages, the change rate is "percent blocks" and
+/// roles are "root nominator bouncer" where `-` keeps a role and `none` removes it.
+/// A new pool bonds `amount` and takes optional roles where `-` means the signer.
+pub(crate) fn build_pool_admin_call(
+    action: PoolAdminAction,
+    input: &str,
+    amount: u128,
+    state: PoolState,
+    targets: Vec<subxt::utils::AccountId32>,
+) -> Result<crate::chain::PoolAdminCall, String> {
+    let input = input.trim();
+    let percent = |text: &str| {
+        text.parse::<f64>()
+            .ok()
+            .and_then(perbill_from_percent)
+            .ok_or_else(|| format!("'{}' is not a percentage between 0 and 100", text))
+    };
+    match action {
+        PoolAdminAction::Nominate if targets.is_empty() => {
+            Err("Select validators in the Optimization tab first".to_string())
+        }
+        PoolAdminAction::Nominate => Ok(crate::chain::PoolAdminCall::Nominate(targets)),
+        PoolAdminAction::ClaimCommission => Ok(crate::chain::PoolAdminCall::ClaimCommission),
+        PoolAdminAction::SetState => Ok(crate::chain::PoolAdminCall::SetState(state)),
+        PoolAdminAction::SetMetadata if input.is_empty() => Err("Enter a pool name".to_string()),
+        PoolAdminAction::SetMetadata => {
+            Ok(crate::chain::PoolAdminCall::SetMetadata(input.to_string()))
+        }
+        PoolAdminAction::SetCommission if input.is_empty() => {
+            Ok(crate::chain::PoolAdminCall::SetCommission(None))
+        }
+        PoolAdminAction::SetCommission => {
+            percent(input).map(|p| crate::chain::PoolAdminCall::SetCommission(Some(p)))
+        }
+        PoolAdminAction::SetCommissionMax => {
+            percent(input).map(crate::chain::PoolAdminCall::SetCommissionMax)
+        }
+        PoolAdminAction::SetCommissionChangeRate => {
+            let [rate, delay] = input.split_whitespace().collect::<Vec<_>>()[..] else {
+                return Err("Enter a percentage and a block delay".to_string());
+            };
+            let min_delay = delay
+                .parse::<u32>()
+                .map_err(|_| format!("'{}' is not a block count", delay))?;
+            Ok(crate::chain::PoolAdminCall::SetCommissionChangeRate(
+                CommissionChangeRate {
+                    max_increase: percent(rate)?,
+                    min_delay,
+                },
+            ))
+        }
+        PoolAdminAction::UpdateRoles => {
+            let updates = input
+                .split_whitespace()
+                .map(|entry| match entry {
+                    "-" => Ok(PoolRoleUpdate::Keep),
+                    "none" => Ok(PoolRoleUpdate::Remove),
+                    address => address
+                        .parse::<subxt::utils::AccountId32>()
+                        .map(PoolRoleUpdate::Set)
+                        .map_err(|_| format!("Invalid address: {}", address)),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let [root, nominator, bouncer] = <[PoolRoleUpdate; 3]>::try_from(updates)
+                .map_err(|_| "Enter root, nominator and bouncer".to_string())?;
+            Ok(crate::chain::PoolAdminCall::UpdateRoles {
+                root,
+                nominator,
+                bouncer,
+            })
+        }
+        PoolAdminAction::Create => {
+            let roles = input
+                .split_whitespace()
+                .map(|entry| match entry {
+                    "-" => Ok(None),
+                    address => address
+                        .parse::<subxt::utils::AccountId32>()
+                        .map(Some)
+                        .map_err(|_| format!("Invalid address: {}", address)),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let [root, nominator, bouncer] = if roles.is_empty() {
+                Default::default()
+            } else {
+                <[Option<subxt::utils::AccountId32>; 3]>::try_from(roles)
+                    .map_err(|_| "Enter root, nominator and bouncer, or none".to_string())?
+            };
+            Ok(crate::chain::PoolAdminCall::Create {
+                amount,
+                root,
+                nominator,
+                bouncer,
+            })
+        }
+    }
+}
+
 /// Navigation sections in the app
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Section {
@@ -113,8 +370,36 @@ pub struct StkoptApp {
     pub connection_mode: ConnectionMode,
     /// Selected network
     pub network: Network,
+    /// Network defined in config.json, offered after the built-in ones
+    pub custom_network: Option<Network>,
     /// Staking info for the watched account
     pub staking_info: Option<StakingInfo>,
+    /// Locks, freezes and holds of the watched account
+    pub balance_breakdown: Option<stkopt_chain::BalanceBreakdown>,
+    /// Stake allocation of the watched account in the active era
+    pub nominator_exposure: Option<stkopt_chain::NominatorExposure>,
+    /// Health check inputs of the watched account, with the era they were read in
+    pub account_health: Option<(HealthAccount, EraIndex)>,
+    /// Slashes applied to the watched account
+    pub past_slashes: Option<Vec<NominatorSlash>>,
+    /// Voter list position of the watched account
+    pub bags_position: Option<BagsListPosition>,
+    /// Pool points in balance terms and claim permission of the watched account
+    pub pool_details: Option<PoolMemberDetails>,
+    /// Pools in which the watched account holds a role
+    pub pool_admin: Vec<PoolAdmin>,
+    /// Unbonding chunks of the watched account with projected unlock dates
+    pub unbonding_schedule: Vec<UnbondingEntry>,
+    /// Status of each saved account on the current network
+    pub portfolio: Vec<PortfolioAccount>,
+    /// Whether the portfolio is currently loading
+    pub portfolio_loading: bool,
+    /// Error from the last portfolio load
+    pub portfolio_error: Option<String>,
+    /// Staking limits of the connected chain
+    pub staking_params: Option<StakingParams>,
+    /// Slashes and offences on the connected chain
+    pub slash_history: Option<SlashHistory>,
     /// Whether watched account data is currently loading
     pub account_loading: bool,
     /// List of validators
@@ -133,6 +418,12 @@ pub struct StkoptApp {
     pub validator_filter_cache: crate::validators::ValidatorFilterCache,
     /// Staking history data points
     pub staking_history: Vec<HistoryPoint>,
+    /// Exact per-era rewards, compared against the estimated history
+    pub exact_history: Vec<HistoryPoint>,
+    /// Expected payouts matched against `Staking.Rewarded` events
+    pub payout_checks: Vec<PayoutCheck>,
+    /// Whether a payout check is running
+    pub payouts_checking: bool,
     /// Whether history is currently loading
     pub history_loading: bool,
     /// Available nomination pools
@@ -169,6 +460,8 @@ pub struct StkoptApp {
     pub settings_auto_connect: bool,
     /// Settings: show testnet networks
     pub settings_show_testnets: bool,
+    /// Settings: per-network mortality period (blocks) for signed transactions
+    pub settings_mortality_periods: HashMap<Network, u64>,
     /// Whether help overlay is visible
     pub show_help: bool,
     /// Optimization result (estimated avg APY)
@@ -181,6 +474,8 @@ pub struct StkoptApp {
     pub optimization_max_commission: f64,
     /// Optimization target validator count
     pub optimization_target_count: usize,
+    /// Whether optimization also skips validators with offence reports
+    pub optimization_exclude_reported: bool,
     /// Chain handle for async operations
     pub chain_handle: Option<crate::chain::ChainHandle>,
     /// Connection error message
@@ -225,6 +520,30 @@ pub struct StkoptApp {
     pub tx_status_message: Option<String>,
     /// Transaction submission state for the QR signing flow
     pub tx_status: QrTxStatus,
+    /// Directory for exported signing QR images
+    pub qr_export_dir: std::path::PathBuf,
+    /// Format for exported signing QR images
+    pub qr_export_format: stkopt_chain::QrExportFormat,
+    /// Result of the last QR export
+    pub qr_export_message: Option<String>,
+    /// Whether the once-a-second expiry countdown refresh is running
+    pub expiry_countdown_active: bool,
+    /// Journaled transactions for the watched account (newest first)
+    pub tx_journal: Vec<stkopt_core::TransactionRecord>,
+    /// Status filter for the transaction history (None = all)
+    pub tx_journal_status: Option<stkopt_core::TxJournalStatus>,
+    /// Search text for the transaction history
+    pub tx_journal_search: String,
+    /// Result of the last transaction history export
+    pub tx_journal_message: Option<String>,
+    /// Directory for exported transaction history files
+    pub journal_export_dir: std::path::PathBuf,
+    /// Format for exported transaction history files
+    pub journal_export_format: stkopt_core::JournalExportFormat,
+    /// Whether the camera is scanning a Vault account export QR
+    pub account_scan_active: bool,
+    /// Result of the last Vault account import
+    pub account_scan_message: Option<String>,
     /// Whether pool operations modal is visible
     pub show_pool_modal: bool,
     /// Current pool operation type
@@ -233,6 +552,16 @@ pub struct StkoptApp {
     pub selected_pool_id: Option<u32>,
     /// Amount input for pool operations
     pub pool_amount_input: String,
+    /// Claim permission chosen in the pool modal
+    pub pool_claim_permission: ClaimPermission,
+    /// Reward call chosen for another pool member
+    pub pool_member_call: PoolMemberCall,
+    /// Address of the pool member whose rewards to compound or pay out
+    pub pool_member_address: String,
+    /// Text input for pool admin operations (name, commission, change rate or roles)
+    pub pool_admin_input: String,
+    /// Pool state chosen for the set state admin operation
+    pub pool_admin_state: PoolState,
     /// Inline status/error for the pool operation modal
     pub pool_action_message: Option<String>,
     /// Whether the pool operation modal is building a QR payload
@@ -245,6 +574,8 @@ pub struct StkoptApp {
     pub scanned_signature: Option<Vec<u8>>,
     /// Signed extrinsic built from the scanned Vault signature
     pub signed_extrinsic: Option<Vec<u8>>,
+    /// Hash of the signed extrinsic (recorded in the transaction journal)
+    pub signed_tx_hash: Option<[u8; 32]>,
     /// Whether a signed extrinsic submission is in progress
     pub tx_submitting: bool,
     /// Reward destination for SetPayee operation
@@ -266,6 +597,11 @@ pub enum PoolOperation {
     ClaimPayout,
     Unbond,
     Withdraw,
+    SetClaimPermission,
+    /// Administration call signed by a pool role holder
+    Admin(PoolAdminAction),
+    /// Compound or pay out another member's rewards
+    MemberRewards,
 }
 
 impl PoolOperation {
@@ -276,13 +612,23 @@ impl PoolOperation {
             PoolOperation::ClaimPayout => "Claim Payout",
             PoolOperation::Unbond => "Unbond",
             PoolOperation::Withdraw => "Withdraw",
+            PoolOperation::SetClaimPermission => "Claim Permission",
+            PoolOperation::Admin(action) => action.label(),
+            PoolOperation::MemberRewards => "Member Rewards",
         }
     }
 
     pub fn requires_amount(&self) -> bool {
         match self {
-            PoolOperation::Join | PoolOperation::BondExtra | PoolOperation::Unbond => true,
-            PoolOperation::ClaimPayout | PoolOperation::Withdraw => false,
+            PoolOperation::Join
+            | PoolOperation::BondExtra
+            | PoolOperation::Unbond
+            | PoolOperation::Admin(PoolAdminAction::Create) => true,
+            PoolOperation::ClaimPayout
+            | PoolOperation::Withdraw
+            | PoolOperation::SetClaimPermission
+            | PoolOperation::Admin(_)
+            | PoolOperation::MemberRewards => false,
         }
     }
 }
@@ -300,6 +646,8 @@ pub enum StakingOperation {
     Chill,
     ClaimRewards,
     SetPayee,
+    Rebag,
+    PutInFrontOf,
 }
 
 impl StakingOperation {
@@ -314,6 +662,8 @@ impl StakingOperation {
             StakingOperation::Chill => "Stop Nominating",
             StakingOperation::ClaimRewards => "Claim Rewards",
             StakingOperation::SetPayee => "Set Payee",
+            StakingOperation::Rebag => "Rebag",
+            StakingOperation::PutInFrontOf => "Put In Front",
         }
     }
 
@@ -327,7 +677,27 @@ impl StakingOperation {
             | StakingOperation::Nominate
             | StakingOperation::Chill
             | StakingOperation::ClaimRewards
-            | StakingOperation::SetPayee => false,
+            | StakingOperation::SetPayee
+            | StakingOperation::Rebag
+            | StakingOperation::PutInFrontOf => false,
+        }
+    }
+
+    /// Transaction built for this operation, if it maps to a single one.
+    pub fn transaction_type(&self) -> Option<TransactionType> {
+        match self {
+            StakingOperation::Bond => Some(TransactionType::Bond),
+            StakingOperation::Unbond => Some(TransactionType::Unbond),
+            StakingOperation::BondExtra => Some(TransactionType::BondExtra),
+            StakingOperation::Rebond => Some(TransactionType::Rebond),
+            StakingOperation::WithdrawUnbonded => Some(TransactionType::WithdrawUnbonded),
+            StakingOperation::Nominate => Some(TransactionType::Nominate),
+            StakingOperation::Chill => Some(TransactionType::Chill),
+            StakingOperation::SetPayee => Some(TransactionType::SetPayee),
+            // Routed to a pool claim or payout depending on the account
+            StakingOperation::ClaimRewards => None,
+            // Voter list calls have no journal type
+            StakingOperation::Rebag | StakingOperation::PutInFrontOf => None,
         }
     }
 }
@@ -368,6 +738,8 @@ pub enum QrTxStatus {
     Submitting,
     Submitted,
     Failed,
+    /// The payload's mortality window passed; it must be rebuilt and re-signed.
+    Expired,
 }
 
 /// A saved account in the address book.
@@ -410,6 +782,7 @@ impl ConnectionModeExt for ConnectionMode {
         match config {
             crate::persistence::ConnectionModeConfig::Rpc => ConnectionMode::Rpc,
             crate::persistence::ConnectionModeConfig::LightClient => ConnectionMode::LightClient,
+            crate::persistence::ConnectionModeConfig::Hybrid => ConnectionMode::Hybrid,
         }
     }
 
@@ -417,17 +790,19 @@ impl ConnectionModeExt for ConnectionMode {
         match self {
             ConnectionMode::Rpc => crate::persistence::ConnectionModeConfig::Rpc,
             ConnectionMode::LightClient => crate::persistence::ConnectionModeConfig::LightClient,
+            ConnectionMode::Hybrid => crate::persistence::ConnectionModeConfig::Hybrid,
         }
     }
 
     fn uses_light_client(self) -> bool {
-        self == ConnectionMode::LightClient
+        matches!(self, ConnectionMode::LightClient | ConnectionMode::Hybrid)
     }
 
     fn label(&self) -> &'static str {
         match self {
             ConnectionMode::Rpc => "RPC",
             ConnectionMode::LightClient => "Light Client",
+            ConnectionMode::Hybrid => "Hybrid",
         }
     }
 
@@ -435,6 +810,9 @@ impl ConnectionModeExt for ConnectionMode {
         match self {
             ConnectionMode::Rpc => "Connect via RPC endpoint (faster, requires trust)",
             ConnectionMode::LightClient => "Embedded light client (trustless, slower startup)",
+            ConnectionMode::Hybrid => {
+                "RPC data proven against the light client (trustless, full history)"
+            }
         }
     }
 }
@@ -442,16 +820,17 @@ impl ConnectionModeExt for ConnectionMode {
 /// Extension trait for the shared [`stkopt_core::Network`] type.
 pub trait NetworkExt {
     /// Human-readable network name.
-    fn label(&self) -> &'static str;
+    fn label(&self) -> &str;
 }
 
 impl NetworkExt for Network {
-    fn label(&self) -> &'static str {
+    fn label(&self) -> &str {
         match self {
             Network::Polkadot => "Polkadot",
             Network::Kusama => "Kusama",
             Network::Westend => "Westend",
             Network::Paseo => "Paseo",
+            Network::Custom(custom) => &custom.name,
         }
     }
 }
@@ -512,13 +891,43 @@ pub fn generate_mock_pools(count: usize) -> Vec<PoolInfo> {
 
 impl StkoptApp {
     pub fn new(cx: &mut Context<Self>, log_buffer: crate::log::LogBuffer) -> Self {
+        Self::with_session(cx, log_buffer, None)
+    }
+
+    /// Create the app, recording or replaying the chain session.
+    ///
+    /// Sessions connect over RPC on startup; a replay uses the network it
+    /// was recorded on.
+    pub fn with_session(
+        cx: &mut Context<Self>,
+        log_buffer: crate::log::LogBuffer,
+        session: Option<stkopt_chain::SessionMode>,
+    ) -> Self {
         // Load saved config from disk
         let config = crate::persistence::load_config().unwrap_or_default();
+        let custom_network = config.custom().unwrap_or_else(|e| {
+            tracing::warn!("Ignoring custom network: {}", e);
+            None
+        });
 
         // Convert network config to app network
-        let network = config.network.to_network().unwrap_or(Network::Polkadot);
+        let mut network = match config.network {
+            crate::persistence::NetworkConfig::Custom => custom_network.clone(),
+            other => other.to_network(),
+        }
+        .unwrap_or(Network::Polkadot);
+        if let Some(stkopt_chain::SessionMode::Replay(path)) = &session {
+            match stkopt_chain::recorded_network(path) {
+                Ok(recorded) => network = recorded,
+                Err(e) => tracing::error!("Cannot replay session: {}", e),
+            }
+        }
 
-        let connection_mode = ConnectionMode::from_config(config.connection_mode);
+        let connection_mode = if session.is_some() {
+            ConnectionMode::Rpc
+        } else {
+            ConnectionMode::from_config(config.connection_mode)
+        };
 
         // Initialize database and chain worker
         let handle = crate::gpui_tokio::Tokio::handle(cx);
@@ -551,13 +960,23 @@ impl StkoptApp {
         )
         .detach();
 
-        // Auto-connect if enabled
-        if config.auto_connect {
+        // Apply saved mortality settings, then auto-connect if enabled
+        let mortality_periods = config.network_mortality_periods();
+        {
             let handle = chain_handle.clone();
-            let net = network; // Copy
-            let use_light = connection_mode.uses_light_client();
+            let net = network.clone();
+            let auto_connect = config.auto_connect || session.is_some();
+            let mortality_periods = mortality_periods.clone();
             cx.spawn(move |_, _cx: &mut gpui::AsyncApp| async move {
-                if let Err(e) = handle.connect(net, use_light).await {
+                if let Err(e) = handle.set_mortality_periods(mortality_periods).await {
+                    tracing::error!("Failed to apply mortality settings: {}", e);
+                }
+                if session.is_some()
+                    && let Err(e) = handle.set_session(session).await
+                {
+                    tracing::error!("Failed to apply session mode: {}", e);
+                }
+                if auto_connect && let Err(e) = handle.connect(net, connection_mode).await {
                     tracing::error!("Failed to auto-connect: {}", e);
                 }
             })
@@ -575,7 +994,21 @@ impl StkoptApp {
             connection_status: ConnectionStatus::Disconnected,
             connection_mode,
             network,
+            custom_network,
             staking_info: None,
+            balance_breakdown: None,
+            nominator_exposure: None,
+            account_health: None,
+            past_slashes: None,
+            bags_position: None,
+            pool_details: None,
+            pool_admin: Vec::new(),
+            unbonding_schedule: Vec::new(),
+            portfolio: Vec::new(),
+            portfolio_loading: false,
+            portfolio_error: None,
+            staking_params: None,
+            slash_history: None,
             account_loading: false,
             validators: Vec::new(),
             selected_validators: Vec::new(),
@@ -585,6 +1018,9 @@ impl StkoptApp {
             validators_loading: false,
             validator_filter_cache: crate::validators::ValidatorFilterCache::new(),
             staking_history: Vec::new(),
+            exact_history: Vec::new(),
+            payout_checks: Vec::new(),
+            payouts_checking: false,
             history_loading: false,
             pools: Vec::new(),
             pools_loading: false,
@@ -603,12 +1039,14 @@ impl StkoptApp {
             settings_connection_mode: config.connection_mode,
             settings_auto_connect: config.auto_connect,
             settings_show_testnets: config.show_testnets,
+            settings_mortality_periods: mortality_periods,
             show_help: false,
             optimization_result: None,
             optimization_status: None,
             optimization_strategy: crate::optimization::SelectionStrategy::default(),
             optimization_max_commission: 0.15,
             optimization_target_count: 16,
+            optimization_exclude_reported: false,
             chain_handle: Some(chain_handle),
             connection_error: None,
             pending_updates,
@@ -631,16 +1069,34 @@ impl StkoptApp {
             qr_modal_tab: QrModalTab::default(),
             tx_status_message: None,
             tx_status: QrTxStatus::NotReady,
+            qr_export_dir: default_qr_export_dir(),
+            qr_export_format: stkopt_chain::QrExportFormat::default(),
+            qr_export_message: None,
+            expiry_countdown_active: false,
+            tx_journal: Vec::new(),
+            tx_journal_status: None,
+            tx_journal_search: String::new(),
+            tx_journal_message: None,
+            journal_export_dir: default_qr_export_dir(),
+            journal_export_format: stkopt_core::JournalExportFormat::default(),
+            account_scan_active: false,
+            account_scan_message: None,
             show_pool_modal: false,
             pool_operation: PoolOperation::default(),
             selected_pool_id: None,
             pool_amount_input: String::new(),
+            pool_claim_permission: ClaimPermission::Permissioned,
+            pool_member_call: PoolMemberCall::default(),
+            pool_member_address: String::new(),
+            pool_admin_input: String::new(),
+            pool_admin_state: PoolState::Open,
             pool_action_message: None,
             pool_action_generating: false,
             qr_reader: None,
             camera_preview: None,
             scanned_signature: None,
             signed_extrinsic: None,
+            signed_tx_hash: None,
             tx_submitting: false,
             rewards_destination: stkopt_chain::RewardDestination::Staked,
             show_blocked: true,
@@ -650,12 +1106,12 @@ impl StkoptApp {
 
         // Load address book from disk
         if let Ok(book) = crate::persistence::load_address_book() {
-            let net_config = crate::persistence::NetworkConfig::from(instance.network);
+            let net_config = crate::persistence::NetworkConfig::from(&instance.network);
             for entry in book.for_network(net_config) {
                 instance.address_book.push(SavedAccount {
                     address: entry.address.clone(),
                     label: Some(entry.label.clone()),
-                    network: instance.network,
+                    network: instance.network.clone(),
                 });
             }
         }
@@ -667,7 +1123,7 @@ impl StkoptApp {
     /// Load cached data from the database.
     pub fn load_cache(&self, cx: &mut Context<Self>) {
         let db = self.db.clone();
-        let network = self.network;
+        let network = self.network.clone();
         let pending_updates = self.pending_updates.clone();
 
         let mut async_cx = cx.to_async();
@@ -678,7 +1134,7 @@ impl StkoptApp {
                 // Use the most recent cached era for freshness checks; default to 0 only when
                 // no metadata has ever been persisted.
                 let current_era = db
-                    .get_chain_metadata(network)
+                    .get_chain_metadata(network.clone())
                     .await
                     .ok()
                     .flatten()
@@ -855,15 +1311,26 @@ impl StkoptApp {
                 // Update staking info from account data
                 self.staking_info = Some(StakingInfo {
                     total_balance: account_data.free_balance + account_data.reserved_balance,
-                    transferable: account_data
-                        .free_balance
-                        .saturating_sub(account_data.frozen_balance),
+                    transferable: account_data.balance_breakdown.as_ref().map_or(
+                        account_data
+                            .free_balance
+                            .saturating_sub(account_data.frozen_balance),
+                        |breakdown| breakdown.transferable(),
+                    ),
                     bonded: account_data.staked_balance.unwrap_or(0),
                     unbonding: account_data.unbonding_balance,
                     rewards_pending: account_data.pool_pending_rewards,
                     is_nominating: account_data.is_nominating,
                     nomination_count: account_data.nominations.len(),
                 });
+                self.balance_breakdown = account_data.balance_breakdown.clone();
+                self.nominator_exposure = account_data.exposure.clone();
+                self.account_health = account_data.health.clone().zip(account_data.active_era);
+                self.past_slashes = account_data.past_slashes.clone();
+                self.bags_position = account_data.bags_position.clone();
+                self.pool_details = account_data.pool_details.clone();
+                self.pool_admin = account_data.pool_admin.clone();
+                self.unbonding_schedule = account_data.unbonding.clone();
                 tracing::info!(
                     "Account data loaded: balance={}, unbonding={}, pool_pending_rewards={}",
                     account_data.free_balance,
@@ -880,6 +1347,21 @@ impl StkoptApp {
                 self.history_loading = false;
                 self.history_progress = 1.0;
             }
+            ChainUpdate::StakingParamsLoaded(params) => {
+                self.staking_params = Some(params);
+            }
+            ChainUpdate::SlashHistoryLoaded(mut history) => {
+                // Offence reports are loaded separately and kept across reloads
+                if history.offences.is_empty()
+                    && let Some(previous) = self.slash_history.take()
+                {
+                    history.offences = previous.offences;
+                }
+                self.slash_history = Some(history);
+            }
+            ChainUpdate::OffenceReportsLoaded(reports) => {
+                self.slash_history.get_or_insert_default().offences = reports;
+            }
             ChainUpdate::QrPayloadGenerated(payload) => {
                 // Store the generated QR payload for display in QR modal
                 tracing::info!("QR payload generated: {}", payload.description);
@@ -894,10 +1376,10 @@ impl StkoptApp {
                 // Handle transaction submission result
                 use crate::chain::TxSubmissionResult;
                 match result {
-                    TxSubmissionResult::InBlock { block_hash } => {
+                    TxSubmissionResult::InBlock { block_hash, .. } => {
                         tracing::info!("Transaction in block: 0x{}", hex::encode(block_hash));
                     }
-                    TxSubmissionResult::Finalized { block_hash } => {
+                    TxSubmissionResult::Finalized { block_hash, .. } => {
                         tracing::info!(
                             "Transaction finalized in block: 0x{}",
                             hex::encode(block_hash)
@@ -933,6 +1415,17 @@ impl StkoptApp {
             auto_connect: self.settings_auto_connect,
             show_testnets: self.settings_show_testnets,
             accounts: Vec::new(), // Legacy TUI format, not used in GPUI
+            mortality_periods: self
+                .settings_mortality_periods
+                .iter()
+                .map(|(network, period)| {
+                    (crate::persistence::NetworkConfig::from(network), *period)
+                })
+                .collect(),
+            // Edited by hand in config.json; keep what is on disk
+            custom_network: crate::persistence::load_config()
+                .ok()
+                .and_then(|config| config.custom_network),
         };
 
         if let Err(e) = crate::persistence::save_config(&config) {
@@ -940,6 +1433,36 @@ impl StkoptApp {
         }
     }
 
+    /// Mortality period (blocks) used for new transactions on the active network.
+    pub fn mortality_period(&self) -> u64 {
+        self.settings_mortality_periods
+            .get(&self.network)
+            .copied()
+            .map(stkopt_core::normalize_mortality_period)
+            .unwrap_or(stkopt_core::DEFAULT_MORTALITY_PERIOD)
+    }
+
+    /// Change the mortality period for the active network and apply it to the chain worker.
+    pub fn set_mortality_period(&mut self, period: u64, cx: &mut Context<Self>) {
+        self.settings_mortality_periods.insert(
+            self.network.clone(),
+            stkopt_core::normalize_mortality_period(period),
+        );
+        self.save_config();
+
+        if let Some(ref handle) = self.chain_handle {
+            let handle = handle.clone();
+            let periods = self.settings_mortality_periods.clone();
+            cx.spawn(move |_, _cx: &mut gpui::AsyncApp| async move {
+                if let Err(e) = handle.set_mortality_periods(periods).await {
+                    tracing::error!("Failed to apply mortality settings: {}", e);
+                }
+            })
+            .detach();
+        }
+        cx.notify();
+    }
+
     /// Change the active connection mode and reconnect if the app was connected.
     pub fn set_connection_mode(&mut self, mode: ConnectionMode, cx: &mut Context<Self>) {
         if self.connection_mode == mode && self.settings_connection_mode == mode.to_config() {
@@ -959,8 +1482,7 @@ impl StkoptApp {
         if should_reconnect {
             if let Some(ref handle) = self.chain_handle {
                 let handle = handle.clone();
-                let network = self.network;
-                let use_light_client = mode.uses_light_client();
+                let network = self.network.clone();
 
                 self.connection_status = ConnectionStatus::Connecting;
                 self.operations_loading = true;
@@ -974,7 +1496,22 @@ impl StkoptApp {
                 self.pools.clear();
                 self.pool_filter_cache.invalidate();
                 self.staking_info = None;
+                self.balance_breakdown = None;
+                self.nominator_exposure = None;
+                self.account_health = None;
+                self.past_slashes = None;
+                self.bags_position = None;
+                self.pool_details = None;
+                self.pool_admin.clear();
+                self.unbonding_schedule.clear();
+                self.portfolio.clear();
+                self.portfolio_error = None;
+                self.staking_params = None;
+                self.slash_history = None;
+                self.optimization_exclude_reported = false;
                 self.staking_history.clear();
+                self.exact_history.clear();
+                self.payout_checks.clear();
                 self.optimization_result = None;
                 self.optimization_status = None;
                 self.connection_error = None;
@@ -985,7 +1522,7 @@ impl StkoptApp {
                 cx.spawn(
                     move |_: gpui::WeakEntity<StkoptApp>, _: &mut gpui::AsyncApp| async move {
                         let _ = handle.disconnect().await;
-                        let _ = handle.connect(network, use_light_client).await;
+                        let _ = handle.connect(network, mode).await;
                     },
                 )
                 .detach();
@@ -1010,7 +1547,7 @@ impl StkoptApp {
     /// history, selections, and pending transactions so stale data from the
     /// previous network is not displayed.
     pub fn switch_network(&mut self, network: Network, cx: &mut Context<Self>) {
-        let new_config = crate::persistence::NetworkConfig::from(network);
+        let new_config = crate::persistence::NetworkConfig::from(network.clone());
         if self.network == network && self.settings_network == new_config {
             return;
         }
@@ -1040,7 +1577,22 @@ impl StkoptApp {
         self.pools.clear();
         self.pool_filter_cache.invalidate();
         self.staking_info = None;
+        self.balance_breakdown = None;
+        self.nominator_exposure = None;
+        self.account_health = None;
+        self.past_slashes = None;
+        self.bags_position = None;
+        self.pool_details = None;
+        self.pool_admin.clear();
+        self.unbonding_schedule.clear();
+        self.portfolio.clear();
+        self.portfolio_error = None;
+        self.staking_params = None;
+        self.slash_history = None;
+        self.optimization_exclude_reported = false;
         self.staking_history.clear();
+        self.exact_history.clear();
+        self.payout_checks.clear();
         self.optimization_result = None;
         self.optimization_status = None;
         self.connection_error = None;
@@ -1101,20 +1653,66 @@ impl StkoptApp {
 
     /// Add an account to the address book if not already present.
     pub fn add_to_address_book(&mut self, address: String) {
+        self.add_labeled_to_address_book(address, None);
+    }
+
+    /// Add an account with an optional label. Returns false if already present.
+    pub fn add_labeled_to_address_book(&mut self, address: String, label: Option<String>) -> bool {
         // Check if already exists
         if self
             .address_book
             .iter()
             .any(|a| a.address == address && a.network == self.network)
         {
-            return;
+            return false;
         }
         self.address_book.push(SavedAccount {
             address,
-            label: None,
-            network: self.network,
+            label,
+            network: self.network.clone(),
         });
         self.persist_address_book();
+        true
+    }
+
+    /// Start the camera to import an account from a Vault export QR.
+    pub fn start_account_scan(&mut self, cx: &mut Context<Self>) {
+        self.account_scan_active = true;
+        self.account_scan_message = None;
+        self.start_camera(cx);
+        if self.qr_reader.is_none() {
+            self.account_scan_active = false;
+            self.account_scan_message = self.connection_error.clone();
+        }
+    }
+
+    /// Stop a running Vault account scan.
+    pub fn stop_account_scan(&mut self, cx: &mut Context<Self>) {
+        self.account_scan_active = false;
+        self.stop_camera_with_reason("account scan cancelled", cx);
+    }
+
+    /// Add the account from a scanned Vault export QR to the address book.
+    pub fn import_vault_account(&mut self, data: &[u8]) {
+        self.account_scan_message = Some(
+            match crate::account::parse_vault_account(data, self.network.clone()) {
+                Ok(account) => {
+                    let label = account.default_label();
+                    if self
+                        .add_labeled_to_address_book(account.address.clone(), Some(label.clone()))
+                    {
+                        tracing::info!("Imported Vault account {} ({})", label, account.address);
+                        format!("Added {} from Vault", label)
+                    } else {
+                        "Account is already in the address book".to_string()
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!("Vault account import failed: {}", e);
+                    e
+                }
+            },
+        );
     }
 
     /// Remove an account from the address book.
@@ -1126,11 +1724,22 @@ impl StkoptApp {
         self.address_book
             .retain(|a| a.address != address || a.network != self.network);
         self.persist_address_book();
+        self.portfolio.retain(|a| a.address != address);
 
         if self.watched_account.as_ref().is_some_and(|a| a == address) {
             self.watched_account = None;
             self.staking_info = None;
+            self.balance_breakdown = None;
+            self.nominator_exposure = None;
+            self.account_health = None;
+            self.past_slashes = None;
+            self.bags_position = None;
+            self.pool_details = None;
+            self.pool_admin.clear();
+            self.unbonding_schedule.clear();
             self.staking_history.clear();
+            self.exact_history.clear();
+            self.payout_checks.clear();
             self.account_loading = false;
             self.history_loading = false;
         }
@@ -1138,7 +1747,7 @@ impl StkoptApp {
 
     /// Persist the address book to disk.
     fn persist_address_book(&self) {
-        let net_config = crate::persistence::NetworkConfig::from(self.network);
+        let net_config = crate::persistence::NetworkConfig::from(&self.network);
         let mut book = crate::persistence::load_address_book().unwrap_or_default();
 
         // Remove existing entries for this network and re-add from current state
@@ -1173,7 +1782,7 @@ impl StkoptApp {
     }
 
     /// Get the token symbol for the current network.
-    pub fn token_symbol(&self) -> &'static str {
+    pub fn token_symbol(&self) -> &str {
         self.network.token_symbol()
     }
 
@@ -1192,6 +1801,11 @@ impl StkoptApp {
         self.data_download_complete() && self.watched_account.is_some()
     }
 
+    /// Whether the loaded account may create a pool, which pool members cannot.
+    pub fn can_create_pool(&self) -> bool {
+        self.staking_info.is_some() && self.pool_details.is_none()
+    }
+
     /// Get cached filtered validators, recomputing only when inputs changed.
     pub fn filtered_validators_cached(&mut self) -> Arc<Vec<(usize, crate::app::ValidatorInfo)>> {
         self.validator_filter_cache.get(
@@ -1264,10 +1878,23 @@ impl StkoptApp {
         self.account_error = None;
         self.connection_error = None;
         self.staking_info = None;
+        self.balance_breakdown = None;
+        self.nominator_exposure = None;
+        self.account_health = None;
+        self.past_slashes = None;
+        self.bags_position = None;
+        self.pool_details = None;
+        self.pool_admin.clear();
+        self.unbonding_schedule.clear();
         self.staking_history.clear();
+        self.exact_history.clear();
+        self.payout_checks.clear();
         self.account_loading = false;
         self.history_loading = false;
+        self.tx_journal.clear();
+        self.tx_journal_message = None;
         self.close_qr_modal(cx);
+        self.load_transaction_journal(cx);
     }
 
     /// Fetch account data for the currently watched account.
@@ -1317,6 +1944,56 @@ impl StkoptApp {
         .detach();
     }
 
+    /// Fetch the status of every address book entry on the current network.
+    pub fn fetch_portfolio(&mut self, cx: &mut Context<Self>) {
+        let Some(ref handle) = self.chain_handle else {
+            return;
+        };
+        if self.connection_status != ConnectionStatus::Connected || self.portfolio_loading {
+            return;
+        }
+        let accounts: Vec<(String, Option<String>)> = self
+            .address_book
+            .iter()
+            .filter(|a| a.network == self.network)
+            .map(|a| (a.address.clone(), a.label.clone()))
+            .collect();
+        if accounts.is_empty() {
+            self.portfolio.clear();
+            return;
+        }
+
+        self.portfolio_loading = true;
+        self.portfolio_error = None;
+        cx.notify();
+
+        let handle = handle.clone();
+        let entity = self.entity.clone();
+        let mut async_cx = cx.to_async();
+        cx.spawn(
+            move |_this: gpui::WeakEntity<StkoptApp>, _cx: &mut gpui::AsyncApp| async move {
+                let result = handle.fetch_portfolio(accounts).await;
+                let _ = entity.update(&mut async_cx, |this, cx: &mut Context<StkoptApp>| {
+                    this.portfolio_loading = false;
+                    match result {
+                        Ok(portfolio) => this.portfolio = portfolio,
+                        Err(e) => {
+                            tracing::error!("Failed to fetch portfolio: {}", e);
+                            this.portfolio_error = Some(e);
+                        }
+                    }
+                    cx.notify();
+                });
+            },
+        )
+        .detach();
+    }
+
+    /// Totals across the loaded portfolio.
+    pub fn portfolio_summary(&self) -> PortfolioSummary {
+        summarize_portfolio(&self.portfolio, &self.validators, &self.pools)
+    }
+
     /// Open the staking modal with a specific operation.
     pub fn open_staking_modal(&mut self, operation: StakingOperation, cx: &mut Context<Self>) {
         if !self.commands_available() {
@@ -1330,6 +2007,102 @@ impl StkoptApp {
         cx.notify();
     }
 
+    /// Problem with the staking modal amount, checked against the staking limits.
+    pub fn staking_amount_issue(&self) -> Option<BondIssue> {
+        let tx_type = self.staking_operation.transaction_type()?;
+        if !self.staking_operation.requires_amount() {
+            return None;
+        }
+        let amount = parse_token_amount(&self.staking_amount_input, self.token_decimals()).ok()?;
+        let bonded = self.staking_info.as_ref().map_or(0, |info| info.bonded);
+        let nominating = self
+            .staking_info
+            .as_ref()
+            .is_some_and(|info| info.is_nominating);
+        self.staking_params
+            .as_ref()?
+            .check_bond(tx_type, amount, bonded, nominating)
+    }
+
+    /// Staking limits for the watched account's direct stake.
+    fn bond_limits(&self) -> Option<crate::transactions::BondLimits<'_>> {
+        let params = self.staking_params.as_ref()?;
+        Some(crate::transactions::BondLimits {
+            params,
+            bonded: self.staking_info.as_ref().map_or(0, |info| info.bonded),
+            nominating: self
+                .staking_info
+                .as_ref()
+                .is_some_and(|info| info.is_nominating),
+            decimals: self.token_decimals(),
+            symbol: self.token_symbol(),
+        })
+    }
+
+    /// Health check of the watched account, once its live data is loaded.
+    pub fn health_report(&self) -> Option<HealthReport> {
+        let (health, era) = self.account_health.as_ref()?;
+        Some(check_health(
+            health,
+            &self.validators,
+            &self.pools,
+            self.staking_params.as_ref(),
+            *era,
+        ))
+    }
+
+    /// Slash risk of the watched account's nominations, once its live data
+    /// and the network's slash history are loaded.
+    pub fn slash_risk(&self) -> Option<SlashRiskReport> {
+        let history = self.slash_history.as_ref()?;
+        let (health, _) = self.account_health.as_ref()?;
+        let targets: Vec<subxt::utils::AccountId32> = health
+            .targets
+            .iter()
+            .filter_map(|t| t.address.parse().ok())
+            .collect();
+        let prefix = self.network.ss58_format();
+        let stakes = slash_risk_targets(
+            &targets,
+            self.nominator_exposure.as_ref(),
+            health.bonded,
+            prefix,
+        )
+        .ok()?;
+        let account = self.watched_account.as_ref()?.parse().ok()?;
+        Some(assess_slash_risk(
+            &encode_ss58(&account, prefix).ok()?,
+            &stakes,
+            history,
+            self.past_slashes.clone()?,
+        ))
+    }
+
+    /// Validators the optimizer must skip for their slash history.
+    pub fn slashed_validators(&self) -> HashSet<String> {
+        self.slash_history
+            .as_ref()
+            .map(|history| history.slashed_validators(self.optimization_exclude_reported))
+            .unwrap_or_default()
+    }
+
+    /// Turn excluding reported validators on or off, loading the offence
+    /// reports when turned on.
+    pub fn set_exclude_reported(&mut self, enabled: bool, cx: &mut Context<Self>) {
+        self.optimization_exclude_reported = enabled;
+        if enabled && let Some(handle) = self.chain_handle.clone() {
+            cx.spawn(
+                move |_: gpui::WeakEntity<StkoptApp>, _: &mut gpui::AsyncApp| async move {
+                    if let Err(e) = handle.fetch_offence_reports().await {
+                        tracing::warn!("{}", e);
+                    }
+                },
+            )
+            .detach();
+        }
+        cx.notify();
+    }
+
     /// Generate QR payload for the current staking operation.
     pub fn generate_staking_qr(&mut self, cx: &mut Context<Self>) {
         if !self.commands_available() {
@@ -1379,6 +2152,21 @@ impl StkoptApp {
             0
         };
 
+        if let Some(tx_type) = self.staking_operation.transaction_type()
+            && self.staking_operation.requires_amount()
+            && let Err(e) = crate::transactions::validate_tx_params(
+                &tx_type,
+                Some(amount),
+                None,
+                self.bond_limits().as_ref(),
+            )
+        {
+            self.staking_action_generating = false;
+            self.staking_action_message = Some(e);
+            cx.notify();
+            return;
+        }
+
         // For Nominate, redirect to Optimization view
         if self.staking_operation == StakingOperation::Nominate {
             self.staking_action_generating = false;
@@ -1395,6 +2183,18 @@ impl StkoptApp {
             return;
         }
 
+        let lighter = self
+            .bags_position
+            .as_ref()
+            .and_then(|p| p.lighter_ahead.as_deref())
+            .and_then(|a| a.parse::<subxt::utils::AccountId32>().ok());
+        if self.staking_operation == StakingOperation::PutInFrontOf && lighter.is_none() {
+            self.staking_action_generating = false;
+            self.staking_action_message = Some("No lighter voter ahead in your bag".to_string());
+            cx.notify();
+            return;
+        }
+
         let handle = chain_handle.clone();
         let operation = self.staking_operation;
         let rewards_destination = self.rewards_destination.clone();
@@ -1416,6 +2216,14 @@ impl StkoptApp {
                         handle.create_withdraw_unbonded_payload(signer).await
                     }
                     StakingOperation::Chill => handle.create_chill_payload(signer).await,
+                    StakingOperation::Rebag => handle.create_rebag_payload(signer).await,
+                    StakingOperation::PutInFrontOf => match lighter {
+                        Some(lighter) => {
+                            handle.create_put_in_front_of_payload(signer, lighter).await
+                        }
+                        // Checked above, before the spawn
+                        None => unreachable!(),
+                    },
                     StakingOperation::SetPayee => {
                         handle
                             .create_set_payee_payload(signer, rewards_destination)
@@ -1508,6 +2316,23 @@ impl StkoptApp {
         self.generate_pool_qr(cx);
     }
 
+    /// Generate a withdraw-unbonded QR for the chunks of `source` in one step.
+    pub fn withdraw_unbonded(&mut self, source: UnbondingSource, cx: &mut Context<Self>) {
+        match source {
+            UnbondingSource::Direct => {
+                self.staking_operation = StakingOperation::WithdrawUnbonded;
+                self.staking_amount_input.clear();
+                self.generate_staking_qr(cx);
+            }
+            UnbondingSource::Pool(_) => {
+                self.pool_operation = PoolOperation::Withdraw;
+                self.selected_pool_id = None;
+                self.pool_amount_input.clear();
+                self.generate_pool_qr(cx);
+            }
+        }
+    }
+
     /// Generate QR payload for nominating validators.
     pub fn generate_nominate_qr(&mut self, targets: Vec<String>, cx: &mut Context<Self>) {
         if !self.commands_available() {
@@ -1559,6 +2384,17 @@ impl StkoptApp {
             return;
         }
 
+        if let Err(e) = crate::transactions::validate_tx_params(
+            &TransactionType::Nominate,
+            None,
+            Some(&targets),
+            self.bond_limits().as_ref(),
+        ) {
+            self.connection_error = Some(e);
+            cx.notify();
+            return;
+        }
+
         let handle = chain_handle.clone();
         let mut async_cx = cx.to_async();
 
@@ -1612,6 +2448,19 @@ impl StkoptApp {
         self.pool_operation = operation;
         self.selected_pool_id = pool_id;
         self.pool_amount_input.clear();
+        self.pool_claim_permission = self
+            .pool_details
+            .as_ref()
+            .map_or(ClaimPermission::Permissioned, |d| d.claim_permission);
+        self.pool_member_call = PoolMemberCall::default();
+        self.pool_member_address.clear();
+        self.pool_admin_input.clear();
+        self.pool_admin_state = self
+            .pool_admin
+            .iter()
+            .find(|p| Some(p.pool_id) == pool_id)
+            .map(|p| p.state)
+            .unwrap_or(PoolState::Open);
         self.pool_action_message = None;
         self.pool_action_generating = false;
         self.show_pool_modal = true;
@@ -1667,7 +2516,54 @@ impl StkoptApp {
             0
         };
 
+        // Build the admin call up front so input errors stay in the modal
+        let admin_call = if let PoolOperation::Admin(action) = self.pool_operation {
+            let targets = self
+                .selected_validators
+                .iter()
+                .filter_map(|&idx| self.validators.get(idx))
+                .filter_map(|v| v.address.parse::<subxt::utils::AccountId32>().ok())
+                .collect();
+            match build_pool_admin_call(
+                action,
+                &self.pool_admin_input,
+                amount,
+                self.pool_admin_state,
+                targets,
+            ) {
+                Ok(call) => Some(call),
+                Err(e) => {
+                    self.pool_action_generating = false;
+                    self.pool_action_message = Some(e);
+                    cx.notify();
+                    return;
+                }
+            }
+        } else {
+            None
+        };
+
+        let member = if self.pool_operation == PoolOperation::MemberRewards {
+            match self
+                .pool_member_address
+                .trim()
+                .parse::<subxt::utils::AccountId32>()
+            {
+                Ok(member) => Some(member),
+                Err(_) => {
+                    self.pool_action_generating = false;
+                    self.pool_action_message = Some("Invalid member address".to_string());
+                    cx.notify();
+                    return;
+                }
+            }
+        } else {
+            None
+        };
+
         let pool_id = self.selected_pool_id;
+        let permission = self.pool_claim_permission;
+        let member_call = self.pool_member_call;
         let handle = chain_handle.clone();
         let operation = self.pool_operation;
         let mut async_cx = cx.to_async();
@@ -1693,6 +2589,29 @@ impl StkoptApp {
                         handle.create_pool_unbond_payload(signer, amount).await
                     }
                     PoolOperation::Withdraw => handle.create_pool_withdraw_payload(signer).await,
+                    PoolOperation::SetClaimPermission => {
+                        handle
+                            .create_pool_set_claim_permission_payload(signer, permission)
+                            .await
+                    }
+                    PoolOperation::Admin(_) => match (pool_id, admin_call) {
+                        // A new pool has no id until the pallet assigns one
+                        (_, Some(call @ crate::chain::PoolAdminCall::Create { .. })) => {
+                            handle.create_pool_admin_payload(signer, 0, call).await
+                        }
+                        (Some(id), Some(call)) => {
+                            handle.create_pool_admin_payload(signer, id, call).await
+                        }
+                        _ => Err("No pool selected".to_string()),
+                    },
+                    PoolOperation::MemberRewards => match member {
+                        Some(member) => {
+                            handle
+                                .create_pool_member_call_payload(signer, member, member_call)
+                                .await
+                        }
+                        None => Err("No member entered".to_string()),
+                    },
                 };
 
                 match result {
@@ -1754,7 +2673,10 @@ impl StkoptApp {
 
         cx.spawn(
             move |this: gpui::WeakEntity<Self>, _cx: &mut gpui::AsyncApp| async move {
-                let result = chain_handle.fetch_history(address, lookback_days).await;
+                let result = chain_handle
+                    .fetch_history(address.clone(), lookback_days)
+                    .await;
+                let loaded = result.is_ok();
                 if let Err(e) = this.update(&mut async_cx, |this, cx: &mut Context<Self>| {
                     this.history_loading = false;
                     match result {
@@ -1773,29 +2695,269 @@ impl StkoptApp {
                 }) {
                     tracing::error!("Failed to update UI with history: {:?}", e);
                 }
+                if !loaded {
+                    return;
+                }
+
+                match chain_handle
+                    .fetch_exact_history(address, lookback_days)
+                    .await
+                {
+                    Ok(exact) => {
+                        let _ = this.update(&mut async_cx, |this, cx: &mut Context<Self>| {
+                            tracing::info!("Exact rewards loaded for {} eras", exact.len());
+                            this.exact_history = exact;
+                            cx.notify();
+                        });
+                    }
+                    Err(e) => tracing::warn!("Failed to load exact rewards: {}", e),
+                }
+            },
+        )
+        .detach();
+    }
+
+    /// Reconcile the expected rewards of the loaded history against the
+    /// account's payout events.
+    pub fn check_payouts(&mut self, cx: &mut Context<Self>) {
+        let Some(ref address) = self.watched_account else {
+            return;
+        };
+        let Some(ref chain_handle) = self.chain_handle else {
+            tracing::warn!("Not connected to chain");
+            return;
+        };
+        let (Some(first), Some(last)) = (self.staking_history.first(), self.staking_history.last())
+        else {
+            return;
+        };
+
+        self.payouts_checking = true;
+        cx.notify();
+
+        let address = address.clone();
+        let chain_handle = chain_handle.clone();
+        let (start_era, end_era) = (first.era, last.era);
+        let mut async_cx = cx.to_async();
+
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, _cx: &mut gpui::AsyncApp| async move {
+                let result = chain_handle
+                    .check_payouts(address, start_era, end_era)
+                    .await;
+                if let Err(e) = this.update(&mut async_cx, |this, cx: &mut Context<Self>| {
+                    this.payouts_checking = false;
+                    match result {
+                        Ok(checks) => this.payout_checks = checks,
+                        Err(e) => {
+                            tracing::error!("Failed to check payouts: {}", e);
+                            this.connection_error = Some(format!("Failed to check payouts: {}", e));
+                        }
+                    }
+                    cx.notify();
+                }) {
+                    tracing::error!("Failed to update UI with payout checks: {:?}", e);
+                }
+            },
+        )
+        .detach();
+    }
+
+    /// Load the transaction journal for the watched account.
+    pub fn load_transaction_journal(&mut self, cx: &mut Context<Self>) {
+        let Some(ref address) = self.watched_account else {
+            return;
+        };
+        let signer = address
+            .parse::<subxt::utils::AccountId32>()
+            .map(|account| journal_signer(self.network.clone(), &account))
+            .unwrap_or_else(|_| address.clone());
+        let db = self.db.clone();
+        let network = self.network.clone();
+        let mut async_cx = cx.to_async();
+
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, _cx: &mut gpui::AsyncApp| async move {
+                let result = db
+                    .get_transactions(network, stkopt_core::TransactionFilter::for_signer(&signer))
+                    .await;
+                if let Err(e) = this.update(&mut async_cx, |this, cx: &mut Context<Self>| {
+                    match result {
+                        Ok(records) => this.tx_journal = records,
+                        Err(e) => tracing::warn!("Failed to load transaction journal: {:#}", e),
+                    }
+                    cx.notify();
+                }) {
+                    tracing::error!("Failed to update UI with transaction journal: {:?}", e);
+                }
             },
         )
         .detach();
     }
 
+    /// Journaled transactions matching the current status filter and search.
+    pub fn filtered_transaction_journal(&self) -> Vec<&stkopt_core::TransactionRecord> {
+        let filter = stkopt_core::TransactionFilter {
+            status: self.tx_journal_status,
+            search: Some(self.tx_journal_search.clone()),
+            ..Default::default()
+        };
+        self.tx_journal
+            .iter()
+            .filter(|record| filter.matches(record))
+            .collect()
+    }
+
+    /// Cycle the transaction history status filter (all -> each status -> all).
+    pub fn cycle_journal_status_filter(&mut self) {
+        let statuses = stkopt_core::TxJournalStatus::all();
+        self.tx_journal_status = match self.tx_journal_status {
+            None => statuses.first().copied(),
+            Some(current) => statuses
+                .iter()
+                .position(|status| *status == current)
+                .and_then(|index| statuses.get(index + 1))
+                .copied(),
+        };
+    }
+
+    /// Export the filtered transaction history to a file.
+    pub fn export_transaction_journal(&mut self, cx: &mut Context<Self>) {
+        let records: Vec<stkopt_core::TransactionRecord> = self
+            .filtered_transaction_journal()
+            .into_iter()
+            .cloned()
+            .collect();
+        if records.is_empty() {
+            self.tx_journal_message = Some("Nothing to export".to_string());
+            cx.notify();
+            return;
+        }
+
+        let format = self.journal_export_format;
+        let path = self
+            .journal_export_dir
+            .join(stkopt_core::journal::default_journal_file_name(
+                self.watched_account.as_deref(),
+                format,
+            ));
+        self.tx_journal_message = Some(
+            match stkopt_core::export_transactions(&records, format)
+                .map_err(|e| e.to_string())
+                .and_then(|contents| std::fs::write(&path, contents).map_err(|e| e.to_string()))
+            {
+                Ok(()) => {
+                    tracing::info!(
+                        "Exported {} transaction(s) to {}",
+                        records.len(),
+                        path.display()
+                    );
+                    format!("Exported {} to {}", records.len(), path.display())
+                }
+                Err(e) => {
+                    tracing::warn!("Transaction history export failed: {}", e);
+                    format!("Export failed: {}", e)
+                }
+            },
+        );
+        cx.notify();
+    }
+
     /// Clear state derived from a previously scanned signature.
     pub fn clear_qr_signature_state(&mut self) {
         self.scanned_signature = None;
         self.signed_extrinsic = None;
+        self.signed_tx_hash = None;
         self.tx_status_message = None;
         self.tx_status = QrTxStatus::NotReady;
         self.tx_submitting = false;
         self.camera_preview = None;
     }
 
+    /// Rebuild the pending payload with a fresh nonce, block hash and era.
+    ///
+    /// The previous signature (if any) is discarded; the new QR must be signed again.
+    pub fn rebuild_pending_payload(&mut self, cx: &mut Context<Self>) {
+        let Some(ref chain_handle) = self.chain_handle else {
+            self.tx_status_message = Some("Not connected".to_string());
+            cx.notify();
+            return;
+        };
+        let Some(ref payload) = self.pending_tx_payload else {
+            return;
+        };
+
+        let handle = chain_handle.clone();
+        let signer = payload.signer.clone();
+        let unsigned = payload.unsigned_payload.clone();
+        self.tx_status_message = Some("Rebuilding transaction...".to_string());
+        cx.notify();
+
+        let mut async_cx = cx.to_async();
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, _cx: &mut gpui::AsyncApp| async move {
+                let result = handle.refresh_payload(signer, unsigned).await;
+                if let Err(e) = this.update(&mut async_cx, |this, cx: &mut Context<Self>| {
+                    match result {
+                        Ok(payload) => {
+                            this.stop_camera_with_reason("Payload rebuilt", cx);
+                            this.clear_qr_signature_state();
+                            this.pending_tx_payload = Some(payload);
+                            this.qr_export_message = None;
+                            this.qr_modal_tab = QrModalTab::QrCode;
+                        }
+                        Err(e) => {
+                            this.tx_status_message = Some(e);
+                        }
+                    }
+                    cx.notify();
+                }) {
+                    tracing::error!("Failed to update UI after payload rebuild: {:?}", e);
+                }
+            },
+        )
+        .detach();
+    }
+
     /// Close the QR modal and clear transaction-scanning state.
     pub fn close_qr_modal(&mut self, cx: &mut Context<Self>) {
         self.show_qr_modal = false;
         self.pending_tx_payload = None;
+        self.qr_export_message = None;
         self.clear_qr_signature_state();
         self.stop_camera_with_reason("QR modal closed", cx);
     }
 
+    /// Export the pending signing QR to an image file on the background executor.
+    pub fn export_qr(&mut self, cx: &mut Context<Self>) {
+        let Some(ref payload) = self.pending_tx_payload else {
+            return;
+        };
+        let data = payload.qr_data.clone();
+        let format = self.qr_export_format;
+        let dir = self.qr_export_dir.clone();
+        self.qr_export_message = Some("Exporting QR...".to_string());
+        cx.notify();
+
+        let mut async_cx = cx.to_async();
+        let executor = async_cx.background_executor().clone();
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, _cx: &mut gpui::AsyncApp| async move {
+                let message = executor
+                    .spawn(async move { qr_export_message(&data, format, &dir) })
+                    .await;
+                let _ = this.update(&mut async_cx, |this, cx: &mut Context<Self>| {
+                    // The modal may have been closed while the file was written.
+                    if this.show_qr_modal {
+                        this.qr_export_message = Some(message);
+                        cx.notify();
+                    }
+                });
+            },
+        )
+        .detach();
+    }
+
     /// Switch QR modal tabs and keep camera lifecycle aligned with the scan tab.
     pub fn set_qr_modal_tab(&mut self, tab: QrModalTab, cx: &mut Context<Self>) {
         self.qr_modal_tab = tab;
@@ -1871,6 +3033,52 @@ impl StkoptApp {
         cx.notify();
     }
 
+    /// Re-render once a second while a mortal payload's countdown is on screen.
+    pub fn ensure_expiry_countdown(&mut self, cx: &mut Context<Self>) {
+        if self.expiry_countdown_active
+            || !self.show_qr_modal
+            || self
+                .pending_tx_payload
+                .as_ref()
+                .and_then(|payload| payload.remaining_secs())
+                .is_none()
+        {
+            return;
+        }
+        self.expiry_countdown_active = true;
+
+        let mut async_cx = cx.to_async();
+        let executor = async_cx.background_executor().clone();
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, _cx: &mut gpui::AsyncApp| async move {
+                loop {
+                    executor.timer(Duration::from_secs(1)).await;
+
+                    let keep_ticking =
+                        match this.update(&mut async_cx, |this, cx: &mut Context<Self>| {
+                            let ticking = this.show_qr_modal
+                                && this.pending_tx_payload.as_ref().is_some_and(|payload| {
+                                    payload.remaining_secs().is_some_and(|secs| secs > 0)
+                                });
+                            if !ticking {
+                                this.expiry_countdown_active = false;
+                            }
+                            cx.notify();
+                            ticking
+                        }) {
+                            Ok(keep_ticking) => keep_ticking,
+                            Err(_) => false,
+                        };
+
+                    if !keep_ticking {
+                        break;
+                    }
+                }
+            },
+        )
+        .detach();
+    }
+
     /// Wake GPUI periodically while the camera reader is active.
     fn schedule_camera_poll(&self, cx: &mut Context<Self>) {
         let mut async_cx = cx.to_async();
@@ -1931,6 +3139,7 @@ impl StkoptApp {
 
         let encoded_len = signed.encoded.len();
         self.signed_extrinsic = Some(signed.encoded);
+        self.signed_tx_hash = Some(signed.hash);
         self.tx_status = QrTxStatus::Ready;
         self.tx_status_message = Some(format!(
             "Signature decoded. Signed transaction ready ({} bytes).",
@@ -1958,6 +3167,19 @@ impl StkoptApp {
             return;
         };
 
+        if self
+            .pending_tx_payload
+            .as_ref()
+            .is_some_and(|payload| payload.is_expired())
+        {
+            self.tx_status = QrTxStatus::Expired;
+            self.tx_status_message = Some(
+                "Transaction expired before submission. Rebuild it and sign again.".to_string(),
+            );
+            cx.notify();
+            return;
+        }
+
         let Some(extrinsic) = self.signed_extrinsic.clone() else {
             self.tx_status = QrTxStatus::NotReady;
             self.tx_status_message = Some("Scan the signed QR code first".to_string());
@@ -1971,44 +3193,49 @@ impl StkoptApp {
         cx.notify();
 
         let handle = chain_handle.clone();
+        let db = self.db.clone();
+        let network = self.network.clone();
+        let journal = self
+            .pending_tx_payload
+            .as_ref()
+            .zip(self.signed_tx_hash.as_ref())
+            .map(|(payload, hash)| journal_entry(network.clone(), payload, hash));
         let mut async_cx = cx.to_async();
         cx.spawn(
             move |this: gpui::WeakEntity<Self>, _cx: &mut gpui::AsyncApp| async move {
+                let journal_id = match journal {
+                    Some(entry) => match db.record_transaction(network, entry).await {
+                        Ok(id) => Some(id),
+                        Err(e) => {
+                            tracing::warn!("Failed to journal transaction: {:#}", e);
+                            None
+                        }
+                    },
+                    None => None,
+                };
                 let result = handle.submit_signed_extrinsic(extrinsic).await;
+                if let Some(id) = journal_id {
+                    let (status, block_hash, dispatch_result) = tx_journal_update(&result);
+                    if let Err(e) = db
+                        .set_transaction_status(id, status, block_hash, dispatch_result)
+                        .await
+                    {
+                        tracing::warn!("Failed to update journaled transaction: {:#}", e);
+                    }
+                }
                 if let Err(e) = this.update(&mut async_cx, |this, cx: &mut Context<Self>| {
                     this.tx_submitting = false;
-                    let (status, message) = match result {
-                        Ok(crate::chain::TxSubmissionResult::InBlock { block_hash }) => {
-                            this.signed_extrinsic = None;
-                            (
-                                QrTxStatus::Submitted,
-                                format!(
-                                    "Transaction included in block 0x{}",
-                                    hex::encode(block_hash)
-                                ),
-                            )
-                        }
-                        Ok(crate::chain::TxSubmissionResult::Finalized { block_hash }) => {
-                            this.signed_extrinsic = None;
-                            (
-                                QrTxStatus::Submitted,
-                                format!(
-                                    "Transaction finalized in block 0x{}",
-                                    hex::encode(block_hash)
-                                ),
-                            )
-                        }
-                        Ok(crate::chain::TxSubmissionResult::Dropped(reason)) => (
-                            QrTxStatus::Failed,
-                            format!("Transaction dropped: {}", reason),
-                        ),
-                        Err(e) => (
-                            QrTxStatus::Failed,
-                            format!("Transaction submission failed: {}", e),
-                        ),
-                    };
+                    if matches!(
+                        result,
+                        Ok(crate::chain::TxSubmissionResult::InBlock { .. }
+                            | crate::chain::TxSubmissionResult::Finalized { .. })
+                    ) {
+                        this.signed_extrinsic = None;
+                    }
+                    let (status, message) = tx_submission_status(&result);
                     this.tx_status = status;
                     this.tx_status_message = Some(message);
+                    this.load_transaction_journal(cx);
                     cx.notify();
                 }) {
                     tracing::error!("Failed to update UI after transaction submit: {:?}", e);
@@ -2033,6 +3260,12 @@ impl StkoptApp {
             match result {
                 crate::qr_reader::QrScanResult::Success(data, preview) => {
                     tracing::info!("QR code scanned: {} bytes", data.len());
+                    if self.account_scan_active {
+                        self.account_scan_active = false;
+                        self.stop_camera_with_reason("Vault account scanned", cx);
+                        self.import_vault_account(&data);
+                        return;
+                    }
                     self.camera_preview = Some(preview);
                     self.scanned_signature = Some(data.clone());
                     // Stop scanning after successful decode
@@ -2065,6 +3298,10 @@ impl StkoptApp {
                 }
                 crate::qr_reader::QrScanResult::Error(e) => {
                     tracing::error!("Camera error: {}", e);
+                    if self.account_scan_active {
+                        self.account_scan_active = false;
+                        self.account_scan_message = Some(e.clone());
+                    }
                     self.connection_error = Some(e);
                     if let Some(mut reader) = self.qr_reader.take() {
                         reader.stop();
@@ -2100,6 +3337,47 @@ impl StkoptApp {
             });
 
         // App title and network selector
+        let mut network_pills = div()
+            .mt_1()
+            .flex()
+            .gap_1()
+            .child(network_pill(
+                "DOT",
+                Network::Polkadot,
+                self.network.clone(),
+                &theme,
+                entity.clone(),
+            ))
+            .child(network_pill(
+                "KSM",
+                Network::Kusama,
+                self.network.clone(),
+                &theme,
+                entity.clone(),
+            ))
+            .child(network_pill(
+                "WND",
+                Network::Westend,
+                self.network.clone(),
+                &theme,
+                entity.clone(),
+            ))
+            .child(network_pill(
+                "PAS",
+                Network::Paseo,
+                self.network.clone(),
+                &theme,
+                entity.clone(),
+            ));
+        if let Some(custom) = &self.custom_network {
+            network_pills = network_pills.child(network_pill(
+                custom.token_symbol().to_string(),
+                custom.clone(),
+                self.network.clone(),
+                &theme,
+                entity.clone(),
+            ));
+        }
         nav = nav.child(
             div()
                 .px_3()
@@ -2115,40 +3393,7 @@ impl StkoptApp {
                         .child(Text::new("⚡").size(TextSize::Xl))
                         .child(Heading::h3("Staking Optimizer").into_any_element()),
                 )
-                .child(
-                    div()
-                        .mt_1()
-                        .flex()
-                        .gap_1()
-                        .child(network_pill(
-                            "DOT",
-                            Network::Polkadot,
-                            self.network,
-                            &theme,
-                            entity.clone(),
-                        ))
-                        .child(network_pill(
-                            "KSM",
-                            Network::Kusama,
-                            self.network,
-                            &theme,
-                            entity.clone(),
-                        ))
-                        .child(network_pill(
-                            "WND",
-                            Network::Westend,
-                            self.network,
-                            &theme,
-                            entity.clone(),
-                        ))
-                        .child(network_pill(
-                            "PAS",
-                            Network::Paseo,
-                            self.network,
-                            &theme,
-                            entity.clone(),
-                        )),
-                ),
+                .child(network_pills),
         );
 
         // Navigation items
@@ -2187,6 +3432,9 @@ impl StkoptApp {
                 .on_mouse_down(MouseButton::Left, move |_event, _window, cx| {
                     entity_clone.update(cx, |this, cx| {
                         this.current_section = section;
+                        if section == Section::History {
+                            this.load_transaction_journal(cx);
+                        }
                         cx.notify();
                     });
                 });
@@ -2233,6 +3481,13 @@ impl StkoptApp {
                 &theme,
                 entity.clone(),
             ))
+            .child(mode_pill(
+                "Hybrid",
+                ConnectionMode::Hybrid,
+                self.connection_mode,
+                &theme,
+                entity.clone(),
+            ))
     }
 
     fn render_connection_status(&self, cx: &Context<Self>) -> impl IntoElement {
@@ -2542,7 +3797,7 @@ impl Render for StkoptApp {
 
 /// Network selector pill button.
 fn network_pill(
-    label: &'static str,
+    label: impl Into<SharedString>,
     network: Network,
     current: Network,
     theme: &gpui_ui_kit::theme::Theme,
@@ -2561,7 +3816,7 @@ fn network_pill(
     };
 
     div()
-        .id(SharedString::from(format!("network-{:?}", network)))
+        .id(SharedString::from(format!("network-{}", network)))
         .px_1()
         .py_1()
         .rounded_md()
@@ -2579,7 +3834,7 @@ fn network_pill(
         )
         .on_click(move |_event, _window, cx| {
             entity.update(cx, |this, cx| {
-                this.switch_network(network, cx);
+                this.switch_network(network.clone(), cx);
             });
         })
 }
@@ -2634,9 +3889,10 @@ fn mode_pill(
 mod tests {
     use super::{
         ConnectionMode, ConnectionModeExt, ConnectionStatus, Network, NetworkExt, PoolOperation,
-        PoolState, QrModalTab, Section, StakingOperation, clamp_log_pane_height,
-        generate_mock_pools, optimization_available, parse_token_amount, progress_steps_complete,
-        rendered_step_progress,
+        PoolState, QrModalTab, QrTxStatus, Section, StakingOperation, clamp_log_pane_height,
+        default_qr_export_dir, generate_mock_pools, journal_entry, journal_signer,
+        optimization_available, parse_token_amount, progress_steps_complete, qr_export_message,
+        rendered_step_progress, tx_journal_update, tx_submission_status,
     };
 
     // clamp_log_pane_height tests
@@ -2715,6 +3971,158 @@ mod tests {
     }
 
     // parse_token_amount tests
+    #[test]
+    fn test_default_qr_export_dir_is_not_empty() {
+        assert!(!default_qr_export_dir().as_os_str().is_empty());
+    }
+
+    #[test]
+    fn test_qr_export_message_writes_file() {
+        let dir =
+            std::env::temp_dir().join(format!("stkopt-gpui-qr-export-{}", std::process::id()));
+        let message = qr_export_message(&[0x53; 64], stkopt_chain::QrExportFormat::Png, &dir);
+        let path = message
+            .strip_prefix("Exported to ")
+            .map(std::path::PathBuf::from)
+            .expect("export should succeed");
+        assert_eq!(path.extension().and_then(|e| e.to_str()), Some("png"));
+        let bytes = std::fs::read(&path).unwrap();
+        assert!(bytes.starts_with(b"\x89PNG"));
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_qr_export_message_reports_failure() {
+        let file =
+            std::env::temp_dir().join(format!("stkopt-gpui-qr-export-file-{}", std::process::id()));
+        std::fs::write(&file, b"not a directory").unwrap();
+        let message = qr_export_message(&[0x53; 64], stkopt_chain::QrExportFormat::Svg, &file);
+        assert!(message.starts_with("Export failed: "), "{message}");
+        let _ = std::fs::remove_file(&file);
+    }
+
+    fn failed_outcome() -> stkopt_chain::TxOutcome {
+        stkopt_chain::TxOutcome {
+            events: vec![],
+            failure: Some(stkopt_chain::DispatchFailure {
+                pallet: Some("Staking".to_string()),
+                error: "InsufficientBond".to_string(),
+                docs: String::new(),
+                batch_index: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_tx_submission_status_reports_dispatch_failure() {
+        let result = Ok(crate::chain::TxSubmissionResult::InBlock {
+            block_hash: [0xaa; 32],
+            outcome: Some(failed_outcome()),
+        });
+        let (status, message) = tx_submission_status(&result);
+        assert_eq!(status, QrTxStatus::Failed);
+        assert!(message.ends_with("Failed: Staking.InsufficientBond"));
+
+        let (journal_status, block_hash, dispatch_result) = tx_journal_update(&result);
+        assert_eq!(journal_status, stkopt_core::TxJournalStatus::Failed);
+        assert_eq!(block_hash, Some(format!("0x{}", "aa".repeat(32))));
+        assert_eq!(
+            dispatch_result.as_deref(),
+            Some("Failed: Staking.InsufficientBond")
+        );
+    }
+
+    #[test]
+    fn test_tx_submission_status_reports_expired_payload() {
+        let result: Result<crate::chain::TxSubmissionResult, String> =
+            Err("Invalid Transaction (1010): Transaction is outdated".to_string());
+        let (status, message) = tx_submission_status(&result);
+        assert_eq!(status, QrTxStatus::Expired);
+        assert!(message.contains("Rebuild"));
+        assert_eq!(
+            tx_journal_update(&result).0,
+            stkopt_core::TxJournalStatus::Failed
+        );
+    }
+
+    #[test]
+    fn test_tx_submission_status_success_and_errors() {
+        let ok = Ok(crate::chain::TxSubmissionResult::Finalized {
+            block_hash: [0; 32],
+            outcome: Some(stkopt_chain::TxOutcome::default()),
+        });
+        let (status, message) = tx_submission_status(&ok);
+        assert_eq!(status, QrTxStatus::Submitted);
+        assert!(message.starts_with("Transaction finalized in block 0x"));
+        assert!(message.ends_with("Succeeded"));
+        assert_eq!(
+            tx_journal_update(&ok).0,
+            stkopt_core::TxJournalStatus::Finalized
+        );
+
+        let unknown = Ok(crate::chain::TxSubmissionResult::InBlock {
+            block_hash: [0; 32],
+            outcome: None,
+        });
+        assert_eq!(tx_submission_status(&unknown).0, QrTxStatus::Submitted);
+        assert_eq!(
+            tx_journal_update(&unknown),
+            (
+                stkopt_core::TxJournalStatus::InBlock,
+                Some(format!("0x{}", "00".repeat(32))),
+                None
+            )
+        );
+
+        let err: Result<crate::chain::TxSubmissionResult, String> = Err("timeout".to_string());
+        assert_eq!(
+            tx_submission_status(&err),
+            (
+                QrTxStatus::Failed,
+                "Transaction submission failed: timeout".to_string()
+            )
+        );
+        assert_eq!(
+            tx_journal_update(&err).0,
+            stkopt_core::TxJournalStatus::Failed
+        );
+    }
+
+    #[test]
+    fn test_journal_entry_from_payload() {
+        let payload = crate::chain::TransactionPayload {
+            qr_data: vec![],
+            unsigned_payload: stkopt_chain::UnsignedPayload {
+                call_data: vec![0x07, 0x03],
+                description: "Bond 10 DOT".to_string(),
+                metadata_hash: [0u8; 32],
+                genesis_hash: [0u8; 32],
+                block_hash: [0u8; 32],
+                spec_version: 0,
+                tx_version: 0,
+                nonce: 4,
+                era: stkopt_chain::Era::Immortal,
+                birth_block: None,
+                include_metadata_hash: false,
+                use_asset_payment: false,
+                extension_ids: vec![],
+            },
+            signer: subxt::utils::AccountId32::from([0u8; 32]),
+            description: "Bond 10 DOT".to_string(),
+            built_at: std::time::Instant::now(),
+        };
+        let entry = journal_entry(Network::Kusama, &payload, &[0xcd; 32]);
+        assert_eq!(entry.description, "Bond 10 DOT");
+        assert_eq!(entry.call_data, "0x0703");
+        assert_eq!(entry.nonce, 4);
+        assert_eq!(entry.mortality, stkopt_core::TxMortality::Immortal);
+        assert_eq!(entry.tx_hash, format!("0x{}", "cd".repeat(32)));
+        assert_eq!(
+            entry.signer,
+            journal_signer(Network::Kusama, &payload.signer)
+        );
+    }
+
     #[test]
     fn test_parse_token_amount_whole_number() {
         assert_eq!(parse_token_amount("1", 10).unwrap(), 10_000_000_000);
@@ -2811,6 +4219,15 @@ mod tests {
             pool_pending_rewards: 0,
             pool_unbonding_eras: vec![],
             pool_last_recorded_reward_counter: 0,
+            balance_breakdown: None,
+            exposure: None,
+            health: None,
+            past_slashes: None,
+            bags_position: None,
+            pool_details: None,
+            pool_admin: Vec::new(),
+            active_era: None,
+            unbonding: Vec::new(),
         };
         let transferable = account_data
             .free_balance
@@ -2898,6 +4315,10 @@ mod tests {
         assert_eq!(PoolOperation::ClaimPayout.label(), "Claim Payout");
         assert_eq!(PoolOperation::Unbond.label(), "Unbond");
         assert_eq!(PoolOperation::Withdraw.label(), "Withdraw");
+        assert_eq!(
+            PoolOperation::SetClaimPermission.label(),
+            "Claim Permission"
+        );
     }
 
     #[test]
@@ -2907,6 +4328,103 @@ mod tests {
         assert!(PoolOperation::Unbond.requires_amount());
         assert!(!PoolOperation::ClaimPayout.requires_amount());
         assert!(!PoolOperation::Withdraw.requires_amount());
+        assert!(!PoolOperation::SetClaimPermission.requires_amount());
+        assert!(
+            !PoolOperation::Admin(stkopt_core::PoolAdminAction::SetCommission).requires_amount()
+        );
+        assert!(PoolOperation::Admin(stkopt_core::PoolAdminAction::Create).requires_amount());
+        assert!(!PoolOperation::MemberRewards.requires_amount());
+    }
+
+    #[test]
+    fn test_build_pool_admin_call() {
+        use super::build_pool_admin_call;
+        use crate::chain::PoolAdminCall;
+        use stkopt_chain::PoolRoleUpdate;
+        use stkopt_core::PoolAdminAction;
+
+        assert_eq!(
+            build_pool_admin_call(
+                PoolAdminAction::SetCommission,
+                "5",
+                0,
+                PoolState::Open,
+                vec![]
+            ),
+            Ok(PoolAdminCall::SetCommission(Some(50_000_000)))
+        );
+        assert_eq!(
+            build_pool_admin_call(
+                PoolAdminAction::SetCommission,
+                "",
+                0,
+                PoolState::Open,
+                vec![]
+            ),
+            Ok(PoolAdminCall::SetCommission(None))
+        );
+        assert_eq!(
+            build_pool_admin_call(PoolAdminAction::SetState, "", 0, PoolState::Blocked, vec![]),
+            Ok(PoolAdminCall::SetState(PoolState::Blocked))
+        );
+        assert!(
+            build_pool_admin_call(PoolAdminAction::Nominate, "", 0, PoolState::Open, vec![])
+                .is_err()
+        );
+        assert!(
+            build_pool_admin_call(
+                PoolAdminAction::SetCommissionChangeRate,
+                "1",
+                0,
+                PoolState::Open,
+                vec![]
+            )
+            .is_err()
+        );
+        assert_eq!(
+            build_pool_admin_call(
+                PoolAdminAction::UpdateRoles,
+                "- none -",
+                0,
+                PoolState::Open,
+                vec![]
+            ),
+            Ok(PoolAdminCall::UpdateRoles {
+                root: PoolRoleUpdate::Keep,
+                nominator: PoolRoleUpdate::Remove,
+                bouncer: PoolRoleUpdate::Keep,
+            })
+        );
+
+        let root = subxt::utils::AccountId32::from([2u8; 32]);
+        assert_eq!(
+            build_pool_admin_call(
+                PoolAdminAction::Create,
+                &format!("{} - -", root),
+                500,
+                PoolState::Open,
+                vec![]
+            ),
+            Ok(PoolAdminCall::Create {
+                amount: 500,
+                root: Some(root),
+                nominator: None,
+                bouncer: None,
+            })
+        );
+        assert_eq!(
+            build_pool_admin_call(PoolAdminAction::Create, "", 500, PoolState::Open, vec![]),
+            Ok(PoolAdminCall::Create {
+                amount: 500,
+                root: None,
+                nominator: None,
+                bouncer: None,
+            })
+        );
+        assert!(
+            build_pool_admin_call(PoolAdminAction::Create, "-", 500, PoolState::Open, vec![])
+                .is_err()
+        );
     }
 
     // StakingOperation enum tests
@@ -2921,6 +4439,8 @@ mod tests {
         assert_eq!(StakingOperation::Chill.label(), "Stop Nominating");
         assert_eq!(StakingOperation::ClaimRewards.label(), "Claim Rewards");
         assert_eq!(StakingOperation::SetPayee.label(), "Set Payee");
+        assert_eq!(StakingOperation::Rebag.label(), "Rebag");
+        assert_eq!(StakingOperation::PutInFrontOf.label(), "Put In Front");
     }
 
     #[test]
@@ -2934,6 +4454,8 @@ mod tests {
         assert!(!StakingOperation::Chill.requires_amount());
         assert!(!StakingOperation::ClaimRewards.requires_amount());
         assert!(!StakingOperation::SetPayee.requires_amount());
+        assert!(!StakingOperation::Rebag.requires_amount());
+        assert!(!StakingOperation::PutInFrontOf.requires_amount());
     }
 
     // QrModalTab enum tests
@@ -2976,18 +4498,24 @@ mod tests {
             ConnectionMode::LightClient.to_config(),
             crate::persistence::ConnectionModeConfig::LightClient
         );
+        assert_eq!(
+            ConnectionMode::Hybrid.to_config(),
+            crate::persistence::ConnectionModeConfig::Hybrid
+        );
     }
 
     #[test]
     fn test_connection_mode_uses_light_client() {
         assert!(!ConnectionMode::Rpc.uses_light_client());
         assert!(ConnectionMode::LightClient.uses_light_client());
+        assert!(ConnectionMode::Hybrid.uses_light_client());
     }
 
     #[test]
     fn test_connection_mode_label() {
         assert_eq!(ConnectionMode::Rpc.label(), "RPC");
         assert_eq!(ConnectionMode::LightClient.label(), "Light Client");
+        assert_eq!(ConnectionMode::Hybrid.label(), "Hybrid");
     }
 
     #[test]
@@ -5,12 +5,20 @@
 
 use std::collections::HashMap;
 use stkopt_chain::{
-    ChainClient, ConnectionConfig, ConnectionMode as ChainConnectionMode, PeopleChainClient,
-    RewardDestination, RpcEndpoints, UnsignedPayload, basic_display_validators, encode_for_qr,
-    eras_for_lookback_days, fetch_and_enrich_pools, fetch_and_enrich_validators,
-    staking_history_point, validator_apy_map,
+    BalanceBreakdown, ChainClient, ChainSubscription, ConnectionConfig,
+    ConnectionMode as ChainConnectionMode, DEFAULT_PAYOUT_SCAN_BLOCKS, HistoryEraSource,
+    IndexedValidatorApySource, IndexerClient, NominatorExposure, PeopleChainClient, PoolRoleUpdate,
+    RewardDestination, RpcEndpoints, SessionMode, SubscriptionUpdate, UnbondingEntry,
+    UnsignedPayload, basic_display_validators, encode_for_qr, eras_for_lookback_days,
+    exact_history_point, fetch_and_enrich_pools, fetch_and_enrich_validators,
+    staking_history_point, unbonding_schedule, validator_apy_map,
+};
+use stkopt_core::{
+    BagsListPosition, CachePolicy, ClaimPermission, CommissionChangeRate, ConnectionStatus,
+    EndpointHealth, EraIndex, HealthAccount, HistoryService, Network, NominatorSlash,
+    OffenceReport, PayoutCheck, PayoutScan, PoolAdmin, PoolMemberCall, PoolMemberDetails,
+    PoolState, PortfolioAccount, SlashHistory, StakingParams, reconcile_payouts,
 };
-use stkopt_core::{CachePolicy, ConnectionStatus, HistoryService, Network};
 use subxt::utils::AccountId32;
 use tokio::sync::{mpsc, oneshot};
 
@@ -62,7 +70,7 @@ pub enum ChainCommand {
     /// Connect to a network.
     Connect {
         network: Network,
-        use_light_client: bool,
+        mode: ChainConnectionMode,
     },
     /// Disconnect from the network.
     Disconnect,
@@ -75,16 +83,36 @@ pub enum ChainCommand {
     FetchValidators {
         reply: oneshot::Sender<Result<Vec<ValidatorInfo>, String>>,
     },
+    /// Fetch offence reports from the relay chain.
+    FetchOffenceReports,
     /// Fetch pools list.
     FetchPools {
         reply: oneshot::Sender<Result<Vec<PoolInfo>, String>>,
     },
+    /// Fetch the status of saved accounts, given as (address, label).
+    FetchPortfolio {
+        accounts: Vec<(String, Option<String>)>,
+        reply: oneshot::Sender<Result<Vec<PortfolioAccount>, String>>,
+    },
     /// Fetch staking history for an account.
     FetchHistory {
         address: String,
         lookback_days: u32,
         reply: oneshot::Sender<Result<Vec<HistoryPoint>, String>>,
     },
+    /// Fetch exact per-era rewards for an account.
+    FetchExactHistory {
+        address: String,
+        lookback_days: u32,
+        reply: oneshot::Sender<Result<Vec<HistoryPoint>, String>>,
+    },
+    /// Reconcile expected rewards for an era range against payout events.
+    CheckPayouts {
+        address: String,
+        start_era: EraIndex,
+        end_era: EraIndex,
+        reply: oneshot::Sender<Result<Vec<PayoutCheck>, String>>,
+    },
     // === Transaction Payload Generation ===
     /// Create bond transaction payload.
     CreateBondPayload {
@@ -126,6 +154,27 @@ pub enum ChainCommand {
         signer: AccountId32,
         reply: oneshot::Sender<Result<TransactionPayload, String>>,
     },
+    /// Create voter list rebag transaction payload.
+    CreateRebagPayload {
+        signer: AccountId32,
+        reply: oneshot::Sender<Result<TransactionPayload, String>>,
+    },
+    /// Create voter list put_in_front_of transaction payload.
+    CreatePutInFrontOfPayload {
+        signer: AccountId32,
+        lighter: AccountId32,
+        reply: oneshot::Sender<Result<TransactionPayload, String>>,
+    },
+    /// Rebuild a payload with a fresh nonce, block hash and era.
+    RefreshPayload {
+        payload: UnsignedPayload,
+        signer: AccountId32,
+        reply: oneshot::Sender<Result<TransactionPayload, String>>,
+    },
+    /// Set per-network mortality periods for new payloads.
+    SetMortalityPeriods { periods: HashMap<Network, u64> },
+    /// Record or replay chain sessions on the next connect.
+    SetSession { session: Option<SessionMode> },
     /// Create nominate transaction payload.
     CreateNominatePayload {
         signer: AccountId32,
@@ -162,6 +211,26 @@ pub enum ChainCommand {
         signer: AccountId32,
         reply: oneshot::Sender<Result<TransactionPayload, String>>,
     },
+    /// Create pool set_claim_permission transaction payload.
+    CreatePoolSetClaimPermissionPayload {
+        signer: AccountId32,
+        permission: ClaimPermission,
+        reply: oneshot::Sender<Result<TransactionPayload, String>>,
+    },
+    /// Create a bond_extra_other or claim_payout_other transaction payload.
+    CreatePoolMemberCallPayload {
+        signer: AccountId32,
+        member: AccountId32,
+        call: PoolMemberCall,
+        reply: oneshot::Sender<Result<TransactionPayload, String>>,
+    },
+    /// Create a pool administration transaction payload.
+    CreatePoolAdminPayload {
+        signer: AccountId32,
+        pool_id: u32,
+        call: PoolAdminCall,
+        reply: oneshot::Sender<Result<TransactionPayload, String>>,
+    },
     // === Transaction Submission ===
     /// Submit a signed extrinsic to the network.
     SubmitSignedExtrinsic {
@@ -170,6 +239,31 @@ pub enum ChainCommand {
     },
 }
 
+/// Pool administration call with its arguments, signed by a pool role.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PoolAdminCall {
+    Nominate(Vec<AccountId32>),
+    SetState(PoolState),
+    SetMetadata(String),
+    /// Commission in parts per billion, paid to the signer; `None` removes it.
+    SetCommission(Option<u32>),
+    SetCommissionMax(u32),
+    SetCommissionChangeRate(CommissionChangeRate),
+    ClaimCommission,
+    UpdateRoles {
+        root: PoolRoleUpdate,
+        nominator: PoolRoleUpdate,
+        bouncer: PoolRoleUpdate,
+    },
+    /// Create a pool bonding `amount`; a `None` role goes to the signer.
+    Create {
+        amount: u128,
+        root: Option<AccountId32>,
+        nominator: Option<AccountId32>,
+        bouncer: Option<AccountId32>,
+    },
+}
+
 /// Account data fetched from chain.
 #[derive(Debug, Clone)]
 pub struct AccountData {
@@ -188,6 +282,24 @@ pub struct AccountData {
     pub pool_unbonding_eras: Vec<(u32, u128)>,
     /// Last recorded reward counter for pool membership.
     pub pool_last_recorded_reward_counter: u128,
+    /// Locks, freezes and holds, when fetched live.
+    pub balance_breakdown: Option<BalanceBreakdown>,
+    /// Stake allocation in the active era, when fetched live.
+    pub exposure: Option<NominatorExposure>,
+    /// Health check inputs, when fetched live.
+    pub health: Option<HealthAccount>,
+    /// Slashes applied to the account, when fetched live.
+    pub past_slashes: Option<Vec<NominatorSlash>>,
+    /// Position in the voter list, when fetched live and bonded.
+    pub bags_position: Option<BagsListPosition>,
+    /// Pool points in balance terms and claim permission, when fetched live.
+    pub pool_details: Option<PoolMemberDetails>,
+    /// Pools the account holds a role in, when fetched live.
+    pub pool_admin: Vec<PoolAdmin>,
+    /// Active era at fetch time, when fetched live.
+    pub active_era: Option<EraIndex>,
+    /// Direct and pool unbonding chunks with projected unlock dates, when fetched live.
+    pub unbonding: Vec<UnbondingEntry>,
 }
 
 fn account_data_from_cache(status: &CachedAccountStatus) -> AccountData {
@@ -215,6 +327,15 @@ fn account_data_from_cache(status: &CachedAccountStatus) -> AccountData {
         pool_pending_rewards: 0,
         pool_unbonding_eras,
         pool_last_recorded_reward_counter: status.pool_last_recorded_reward_counter,
+        balance_breakdown: None,
+        exposure: None,
+        health: None,
+        past_slashes: None,
+        bags_position: None,
+        pool_details: None,
+        pool_admin: Vec::new(),
+        active_era: None,
+        unbonding: Vec::new(),
     }
 }
 
@@ -229,6 +350,21 @@ pub struct TransactionPayload {
     pub signer: AccountId32,
     /// Human-readable description.
     pub description: String,
+    /// When the payload was built (for the mortality countdown).
+    pub built_at: std::time::Instant,
+}
+
+impl TransactionPayload {
+    /// Estimated seconds until the payload expires; `None` when immortal.
+    pub fn remaining_secs(&self) -> Option<u64> {
+        let window = self.unsigned_payload.mortality_window()?;
+        Some(window.remaining_secs(self.built_at.elapsed().as_secs()))
+    }
+
+    /// Whether the payload's mortality window has passed.
+    pub fn is_expired(&self) -> bool {
+        self.remaining_secs() == Some(0)
+    }
 }
 
 /// Enrich validators with stake, points, identity, and APY data.
@@ -240,6 +376,7 @@ pub struct TransactionPayload {
 /// - APY calculated from era rewards
 async fn enrich_validators(
     client: &ChainClient,
+    indexer: Option<&IndexerClient>,
     validators: &[stkopt_chain::ValidatorInfo],
     people_client: Option<&PeopleChainClient>,
     db: Option<&DbService>,
@@ -275,7 +412,7 @@ async fn enrich_validators(
     let identity_map: HashMap<String, String> = if let Some(db) = db {
         match db
             .get_validator_identities_within_age(
-                network,
+                network.clone(),
                 stkopt_core::DEFAULT_IDENTITY_MAX_AGE_SECS,
             )
             .await
@@ -296,7 +433,10 @@ async fn enrich_validators(
     };
 
     let outcome = match fetch_and_enrich_validators(
-        client,
+        &IndexedValidatorApySource {
+            chain: client,
+            indexer,
+        },
         validators,
         people_client,
         identity_map,
@@ -349,6 +489,7 @@ fn make_transaction_payload(
         unsigned_payload: payload,
         signer,
         description,
+        built_at: std::time::Instant::now(),
     })
 }
 
@@ -364,9 +505,15 @@ fn slashing_spans_for_withdraw(spans: Result<u32, stkopt_chain::ChainError>) ->
 #[derive(Debug, Clone)]
 pub enum TxSubmissionResult {
     /// Transaction was included in a block.
-    InBlock { block_hash: [u8; 32] },
+    InBlock {
+        block_hash: [u8; 32],
+        outcome: Option<stkopt_chain::TxOutcome>,
+    },
     /// Transaction was finalized.
-    Finalized { block_hash: [u8; 32] },
+    Finalized {
+        block_hash: [u8; 32],
+        outcome: Option<stkopt_chain::TxOutcome>,
+    },
     /// Transaction was dropped.
     Dropped(String),
 }
@@ -386,6 +533,12 @@ pub enum ChainUpdate {
     AccountLoaded(AccountData),
     /// History loaded.
     HistoryLoaded(Vec<HistoryPoint>),
+    /// Staking limits loaded.
+    StakingParamsLoaded(StakingParams),
+    /// Validator slashes and pending slashes loaded.
+    SlashHistoryLoaded(SlashHistory),
+    /// Offence reports loaded.
+    OffenceReportsLoaded(Vec<OffenceReport>),
     /// QR payload generated for signing.
     QrPayloadGenerated(TransactionPayload),
     /// Transaction submission status update.
@@ -411,12 +564,9 @@ pub struct ChainHandle {
 
 impl ChainHandle {
     /// Request connection to a network.
-    pub async fn connect(&self, network: Network, use_light_client: bool) -> Result<(), String> {
+    pub async fn connect(&self, network: Network, mode: ChainConnectionMode) -> Result<(), String> {
         self.command_tx
-            .send(ChainCommand::Connect {
-                network,
-                use_light_client,
-            })
+            .send(ChainCommand::Connect { network, mode })
             .await
             .map_err(|e| format!("Failed to send connect command: {}", e))
     }
@@ -429,6 +579,14 @@ impl ChainHandle {
             .map_err(|e| format!("Failed to send disconnect command: {}", e))
     }
 
+    /// Request the offence reports, sent back as an update.
+    pub async fn fetch_offence_reports(&self) -> Result<(), String> {
+        self.command_tx
+            .send(ChainCommand::FetchOffenceReports)
+            .await
+            .map_err(|e| format!("Failed to send fetch offence reports command: {}", e))
+    }
+
     /// Fetch account data.
     pub async fn fetch_account(&self, address: String) -> Result<AccountData, String> {
         let (reply_tx, reply_rx) = oneshot::channel();
@@ -462,6 +620,22 @@ impl ChainHandle {
         reply_rx.await.map_err(|_| "Channel closed".to_string())?
     }
 
+    /// Fetch the status of saved accounts, given as (address, label).
+    pub async fn fetch_portfolio(
+        &self,
+        accounts: Vec<(String, Option<String>)>,
+    ) -> Result<Vec<PortfolioAccount>, String> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.command_tx
+            .send(ChainCommand::FetchPortfolio {
+                accounts,
+                reply: reply_tx,
+            })
+            .await
+            .map_err(|e| format!("Failed to send fetch portfolio command: {}", e))?;
+        reply_rx.await.map_err(|_| "Channel closed".to_string())?
+    }
+
     /// Fetch staking history for an account.
     ///
     /// `lookback_days` is converted to eras using the chain's current era duration
@@ -483,6 +657,46 @@ impl ChainHandle {
         reply_rx.await.map_err(|_| "Channel closed".to_string())?
     }
 
+    /// Fetch exact per-era rewards for an account over the same window as
+    /// [`Self::fetch_history`].
+    pub async fn fetch_exact_history(
+        &self,
+        address: String,
+        lookback_days: u32,
+    ) -> Result<Vec<HistoryPoint>, String> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.command_tx
+            .send(ChainCommand::FetchExactHistory {
+                address,
+                lookback_days,
+                reply: reply_tx,
+            })
+            .await
+            .map_err(|e| format!("Failed to send fetch exact history command: {}", e))?;
+        reply_rx.await.map_err(|_| "Channel closed".to_string())?
+    }
+
+    /// Match the expected rewards of `start_era..=end_era` against the
+    /// account's `Staking.Rewarded` events.
+    pub async fn check_payouts(
+        &self,
+        address: String,
+        start_era: EraIndex,
+        end_era: EraIndex,
+    ) -> Result<Vec<PayoutCheck>, String> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.command_tx
+            .send(ChainCommand::CheckPayouts {
+                address,
+                start_era,
+                end_era,
+                reply: reply_tx,
+            })
+            .await
+            .map_err(|e| format!("Failed to send check payouts command: {}", e))?;
+        reply_rx.await.map_err(|_| "Channel closed".to_string())?
+    }
+
     // === Transaction Payload Generation ===
 
     /// Create a bond transaction payload.
@@ -607,6 +821,77 @@ impl ChainHandle {
         reply_rx.await.map_err(|_| "Channel closed".to_string())?
     }
 
+    /// Create a voter list rebag transaction payload for the signer.
+    pub async fn create_rebag_payload(
+        &self,
+        signer: AccountId32,
+    ) -> Result<TransactionPayload, String> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.command_tx
+            .send(ChainCommand::CreateRebagPayload {
+                signer,
+                reply: reply_tx,
+            })
+            .await
+            .map_err(|e| format!("Failed to send command: {}", e))?;
+        reply_rx.await.map_err(|_| "Channel closed".to_string())?
+    }
+
+    /// Create a voter list put_in_front_of transaction payload.
+    pub async fn create_put_in_front_of_payload(
+        &self,
+        signer: AccountId32,
+        lighter: AccountId32,
+    ) -> Result<TransactionPayload, String> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.command_tx
+            .send(ChainCommand::CreatePutInFrontOfPayload {
+                signer,
+                lighter,
+                reply: reply_tx,
+            })
+            .await
+            .map_err(|e| format!("Failed to send command: {}", e))?;
+        reply_rx.await.map_err(|_| "Channel closed".to_string())?
+    }
+
+    /// Rebuild an expired payload with a fresh nonce, block hash and era.
+    pub async fn refresh_payload(
+        &self,
+        signer: AccountId32,
+        payload: UnsignedPayload,
+    ) -> Result<TransactionPayload, String> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.command_tx
+            .send(ChainCommand::RefreshPayload {
+                payload,
+                signer,
+                reply: reply_tx,
+            })
+            .await
+            .map_err(|e| format!("Failed to send command: {}", e))?;
+        reply_rx.await.map_err(|_| "Channel closed".to_string())?
+    }
+
+    /// Set per-network mortality periods used for new payloads.
+    pub async fn set_mortality_periods(
+        &self,
+        periods: HashMap<Network, u64>,
+    ) -> Result<(), String> {
+        self.command_tx
+            .send(ChainCommand::SetMortalityPeriods { periods })
+            .await
+            .map_err(|e| format!("Failed to send command: {}", e))
+    }
+
+    /// Record to, or replay from, a session file on the next connect.
+    pub async fn set_session(&self, session: Option<SessionMode>) -> Result<(), String> {
+        self.command_tx
+            .send(ChainCommand::SetSession { session })
+            .await
+            .map_err(|e| format!("Failed to send command: {}", e))
+    }
+
     /// Create a nominate transaction payload.
     pub async fn create_nominate_payload(
         &self,
@@ -715,6 +1000,65 @@ impl ChainHandle {
         reply_rx.await.map_err(|_| "Channel closed".to_string())?
     }
 
+    /// Create a payload compounding or paying out another pool member's
+    /// rewards, checked against their claim permission.
+    pub async fn create_pool_member_call_payload(
+        &self,
+        signer: AccountId32,
+        member: AccountId32,
+        call: PoolMemberCall,
+    ) -> Result<TransactionPayload, String> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.command_tx
+            .send(ChainCommand::CreatePoolMemberCallPayload {
+                signer,
+                member,
+                call,
+                reply: reply_tx,
+            })
+            .await
+            .map_err(|e| format!("Failed to send command: {}", e))?;
+        reply_rx.await.map_err(|_| "Channel closed".to_string())?
+    }
+
+    /// Create a pool set_claim_permission transaction payload.
+    pub async fn create_pool_set_claim_permission_payload(
+        &self,
+        signer: AccountId32,
+        permission: ClaimPermission,
+    ) -> Result<TransactionPayload, String> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.command_tx
+            .send(ChainCommand::CreatePoolSetClaimPermissionPayload {
+                signer,
+                permission,
+                reply: reply_tx,
+            })
+            .await
+            .map_err(|e| format!("Failed to send command: {}", e))?;
+        reply_rx.await.map_err(|_| "Channel closed".to_string())?
+    }
+
+    /// Create a pool administration transaction payload.
+    pub async fn create_pool_admin_payload(
+        &self,
+        signer: AccountId32,
+        pool_id: u32,
+        call: PoolAdminCall,
+    ) -> Result<TransactionPayload, String> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.command_tx
+            .send(ChainCommand::CreatePoolAdminPayload {
+                signer,
+                pool_id,
+                call,
+                reply: reply_tx,
+            })
+            .await
+            .map_err(|e| format!("Failed to send command: {}", e))?;
+        reply_rx.await.map_err(|_| "Channel closed".to_string())?
+    }
+
     // === Transaction Submission ===
 
     /// Submit a signed extrinsic to the network.
@@ -738,21 +1082,41 @@ impl ChainHandle {
 struct ChainWorker {
     client: Option<ChainClient>,
     people_client: Option<PeopleChainClient>,
+    /// Staking indexer for history the light client cannot serve.
+    indexer: Option<IndexerClient>,
     update_tx: mpsc::Sender<ChainUpdate>,
     db: Option<DbService>,
     network: Option<Network>,
-    use_light_client: bool,
+    mode: ChainConnectionMode,
+    mortality_periods: HashMap<Network, u64>,
+    /// Session recording or replay applied to connections.
+    session: Option<SessionMode>,
+    /// Era and account watcher for the current connection.
+    subscription: Option<ChainSubscription>,
+    subscription_tx: mpsc::Sender<SubscriptionUpdate>,
+    /// Address of the account last fetched, watched for changes.
+    watched_address: Option<String>,
 }
 
 impl ChainWorker {
-    fn new(update_tx: mpsc::Sender<ChainUpdate>, db: Option<DbService>) -> Self {
+    fn new(
+        update_tx: mpsc::Sender<ChainUpdate>,
+        subscription_tx: mpsc::Sender<SubscriptionUpdate>,
+        db: Option<DbService>,
+    ) -> Self {
         Self {
             client: None,
             people_client: None,
+            indexer: None,
             update_tx,
             db,
             network: None,
-            use_light_client: false,
+            mode: ChainConnectionMode::Rpc,
+            mortality_periods: HashMap::new(),
+            session: None,
+            subscription: None,
+            subscription_tx,
+            watched_address: None,
         }
     }
 
@@ -763,6 +1127,68 @@ impl ChainWorker {
             .await;
     }
 
+    /// Fetch the staking limits and send them to the UI.
+    ///
+    /// Falls back to the last cached limits if they cannot be read now.
+    async fn fetch_staking_params(&self, network: Network) -> Option<StakingParams> {
+        let client = self.client.as_ref()?;
+        let params = match client.get_staking_params().await {
+            Ok(params) => Some(params),
+            Err(e) => {
+                tracing::warn!("Failed to get staking parameters: {}", e);
+                match self.db {
+                    Some(ref db) => db
+                        .get_chain_metadata(network)
+                        .await
+                        .ok()
+                        .flatten()
+                        .and_then(|meta| meta.staking_params),
+                    None => None,
+                }
+            }
+        };
+        if let Some(ref params) = params {
+            let _ = self
+                .update_tx
+                .send(ChainUpdate::StakingParamsLoaded(params.clone()))
+                .await;
+        }
+        params
+    }
+
+    /// Fetch the network's slash history and send it to the UI.
+    async fn fetch_slash_history(&self) {
+        let Some(client) = self.client.as_ref() else {
+            return;
+        };
+        match client.get_slash_history().await {
+            Ok(history) => {
+                let _ = self
+                    .update_tx
+                    .send(ChainUpdate::SlashHistoryLoaded(history))
+                    .await;
+            }
+            Err(e) => tracing::warn!("Failed to get slash history: {}", e),
+        }
+    }
+
+    /// Fetch the relay chain's offence reports and send them to the UI.
+    async fn fetch_offence_reports(&self) {
+        let Some(client) = self.client.as_ref() else {
+            return;
+        };
+        match client.get_offence_reports().await {
+            Ok(reports) => {
+                tracing::info!("Loaded {} offence reports", reports.len());
+                let _ = self
+                    .update_tx
+                    .send(ChainUpdate::OffenceReportsLoaded(reports))
+                    .await;
+            }
+            Err(e) => tracing::warn!("Failed to get offence reports: {}", e),
+        }
+    }
+
     async fn load_cached_startup_data(&self, network: Network, current_era: u32) -> (bool, bool) {
         let mut validators_fresh = false;
         let mut pools_fresh = false;
@@ -818,9 +1244,10 @@ impl ChainWorker {
         (validators_fresh, pools_fresh)
     }
 
-    async fn handle_connect(&mut self, network: Network, use_light_client: bool) {
-        self.network = Some(network);
-        self.use_light_client = use_light_client;
+    async fn handle_connect(&mut self, network: Network, mode: ChainConnectionMode) {
+        self.save_light_client_peers().await;
+        self.network = Some(network.clone());
+        self.mode = mode;
 
         // Send connecting status
         let _ = self
@@ -829,12 +1256,12 @@ impl ChainWorker {
             .await;
 
         let config = ConnectionConfig {
-            mode: if use_light_client {
-                ChainConnectionMode::LightClient
-            } else {
-                ChainConnectionMode::Rpc
-            },
+            mode,
             rpc_endpoints: RpcEndpoints::default(),
+            mortality_periods: self.mortality_periods.clone(),
+            session: self.session.clone(),
+            endpoint_health: self.load_endpoint_health(network.clone()).await,
+            light_client_dir: stkopt_core::config::get_light_client_dir().ok(),
         };
 
         // Create status channel
@@ -854,115 +1281,177 @@ impl ChainWorker {
             }
         });
 
-        match ChainClient::connect(network, &config, status_tx).await {
+        match ChainClient::connect(network.clone(), &config, status_tx).await {
             Ok(client) => {
                 tracing::info!("Connected to {} via {:?}", network, config.mode);
-                self.client = Some(client);
-                self.set_progress(LoadingStep::Operations, 0.35).await;
-
-                // Fetch and persist chain metadata
-                if let Some(ref client) = self.client {
-                    let info = match client.get_chain_info().await {
-                        Ok(info) => info,
-                        Err(e) => {
-                            tracing::warn!("Failed to get chain info: {}", e);
-                            return;
-                        }
-                    };
-                    let genesis_hash = hex::encode(client.genesis_hash());
-
-                    // Fetch dynamic data
-                    let era_duration_ms = client
-                        .get_era_duration_ms()
-                        .await
-                        .unwrap_or(24 * 60 * 60 * 1000);
-                    let current_era = client
-                        .get_active_era()
-                        .await
-                        .ok()
-                        .flatten()
-                        .map(|e| e.index)
-                        .unwrap_or(0);
+                self.on_connected(network, client).await;
+            }
+            Err(e) => self.report_connect_error(e).await,
+        }
+    }
 
-                    // Token properties based on network
-                    let token_symbol = network.token_symbol().to_string();
-                    let token_decimals = network.token_decimals();
-                    let ss58_prefix = network.ss58_format();
+    /// Set up a fresh connection and load the startup data it needs.
+    async fn on_connected(&mut self, network: Network, client: ChainClient) {
+        self.save_endpoint_health(&client).await;
+        let is_light_client = client.is_light_client();
+        let watched = self
+            .watched_address
+            .as_deref()
+            .and_then(|address| address.parse().ok());
+        self.subscription = Some(ChainSubscription::spawn(
+            client.clone(),
+            watched,
+            self.subscription_tx.clone(),
+        ));
+        self.client = Some(client);
+        self.indexer = if is_light_client {
+            IndexerClient::new(network.clone())
+                .inspect_err(|e| tracing::warn!("Staking indexer unavailable: {}", e))
+                .ok()
+        } else {
+            None
+        };
+        self.set_progress(LoadingStep::Operations, 0.35).await;
 
-                    if let Some(ref db) = self.db {
-                        let cached_meta = CachedChainMetadata {
-                            genesis_hash,
-                            spec_version: info.spec_version,
-                            tx_version: info.tx_version,
-                            ss58_prefix,
-                            token_symbol,
-                            token_decimals,
-                            era_duration_ms,
-                            current_era,
-                        };
-                        if let Err(e) = db.set_chain_metadata(network, cached_meta).await {
-                            tracing::warn!("Failed to cache chain metadata: {}", e);
-                        }
-                    }
+        // Fetch and persist chain metadata
+        if let Some(ref client) = self.client {
+            let info = match client.get_chain_info().await {
+                Ok(info) => info,
+                Err(e) => {
+                    tracing::warn!("Failed to get chain info: {}", e);
+                    return;
                 }
-                self.set_progress(LoadingStep::Operations, 1.0).await;
+            };
+            let genesis_hash = hex::encode(client.genesis_hash());
 
-                let current_era = if let Some(ref db) = self.db {
-                    db.get_chain_metadata(network)
-                        .await
-                        .ok()
-                        .flatten()
-                        .map(|meta| meta.current_era)
-                        .unwrap_or(0)
-                } else {
-                    0
+            // Fetch dynamic data
+            let era_duration_ms = client
+                .get_era_duration_ms()
+                .await
+                .unwrap_or(24 * 60 * 60 * 1000);
+            let current_era = client
+                .get_active_era()
+                .await
+                .ok()
+                .flatten()
+                .map(|e| e.index)
+                .unwrap_or(0);
+
+            // Token properties based on network
+            let token_symbol = network.token_symbol().to_string();
+            let token_decimals = network.token_decimals();
+            let ss58_prefix = network.ss58_format();
+            let staking_params = self.fetch_staking_params(network.clone()).await;
+
+            if let Some(ref db) = self.db {
+                let cached_meta = CachedChainMetadata {
+                    genesis_hash,
+                    spec_version: info.spec_version,
+                    tx_version: info.tx_version,
+                    ss58_prefix,
+                    token_symbol,
+                    token_decimals,
+                    era_duration_ms,
+                    current_era,
+                    staking_params,
                 };
-                let (validators_cached, pools_cached) =
-                    self.load_cached_startup_data(network, current_era).await;
-
-                // Auto-fetch only the startup data that was not already cached.
-                if !validators_cached {
-                    self.set_progress(LoadingStep::Validators, 0.1).await;
-                    if let Some(ref client) = self.client {
-                        self.people_client = connect_ready_people_client(client, network).await;
-                    }
-                    self.fetch_validators_internal(network).await;
+                if let Err(e) = db.set_chain_metadata(network.clone(), cached_meta).await {
+                    tracing::warn!("Failed to cache chain metadata: {}", e);
                 }
-                if !pools_cached {
-                    self.set_progress(LoadingStep::Pools, 0.1).await;
-                    // Delay before pool fetch: light client needs time for storage iteration
-                    tokio::time::sleep(std::time::Duration::from_secs(2)).await;
-                    self.fetch_pools_internal(network).await;
-                }
-                let _ = self
-                    .update_tx
-                    .send(ChainUpdate::ConnectionStatus(ConnectionStatus::Connected))
-                    .await;
-            }
-            Err(e) => {
-                tracing::error!("Failed to connect: {}", e);
-                let _ = self.update_tx.send(ChainUpdate::Error(e.to_string())).await;
-                let _ = self
-                    .update_tx
-                    .send(ChainUpdate::ConnectionStatus(ConnectionStatus::Error(
-                        e.to_string(),
-                    )))
-                    .await;
             }
         }
-    }
+        self.set_progress(LoadingStep::Operations, 1.0).await;
 
-    async fn handle_disconnect(&mut self) {
-        self.client = None;
-        self.people_client = None;
-        let _ = self
-            .update_tx
-            .send(ChainUpdate::ConnectionStatus(
+        let current_era = if let Some(ref db) = self.db {
+            db.get_chain_metadata(network.clone())
+                .await
+                .ok()
+                .flatten()
+                .map(|meta| meta.current_era)
+                .unwrap_or(0)
+        } else {
+            0
+        };
+        let (validators_cached, pools_cached) = self
+            .load_cached_startup_data(network.clone(), current_era)
+            .await;
+
+        // Auto-fetch only the startup data that was not already cached.
+        if !validators_cached {
+            self.set_progress(LoadingStep::Validators, 0.1).await;
+            if let Some(ref client) = self.client {
+                self.people_client = connect_ready_people_client(client, network.clone()).await;
+            }
+            self.fetch_validators_internal(network.clone()).await;
+        }
+        if !pools_cached {
+            self.set_progress(LoadingStep::Pools, 0.1).await;
+            // Delay before pool fetch: light client needs time for storage iteration
+            tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+            self.fetch_pools_internal(network).await;
+        }
+        // Slash history feeds the optimizer and the account's slash risk
+        self.fetch_slash_history().await;
+        let _ = self
+            .update_tx
+            .send(ChainUpdate::ConnectionStatus(ConnectionStatus::Connected))
+            .await;
+    }
+
+    async fn report_connect_error(&self, e: stkopt_chain::ChainError) {
+        tracing::error!("Failed to connect: {}", e);
+        let _ = self.update_tx.send(ChainUpdate::Error(e.to_string())).await;
+        let _ = self
+            .update_tx
+            .send(ChainUpdate::ConnectionStatus(ConnectionStatus::Error(
+                e.to_string(),
+            )))
+            .await;
+    }
+
+    async fn handle_disconnect(&mut self) {
+        self.save_light_client_peers().await;
+        self.subscription = None;
+        self.client = None;
+        self.people_client = None;
+        self.indexer = None;
+        let _ = self
+            .update_tx
+            .send(ChainUpdate::ConnectionStatus(
                 ConnectionStatus::Disconnected,
             ))
             .await;
     }
 
+    async fn load_endpoint_health(&self, network: Network) -> Vec<EndpointHealth> {
+        let Some(ref db) = self.db else {
+            return Vec::new();
+        };
+        db.get_endpoint_health(network).await.unwrap_or_else(|e| {
+            tracing::warn!("Failed to load endpoint health: {}", e);
+            Vec::new()
+        })
+    }
+
+    /// Save the light client's known peers to dial them first next start.
+    async fn save_light_client_peers(&self) {
+        if let Some(ref client) = self.client
+            && let Err(e) = client.save_light_client_peers().await
+        {
+            tracing::warn!("Failed to save light-client peers: {}", e);
+        }
+    }
+
+    async fn save_endpoint_health(&self, client: &ChainClient) {
+        if let Some(ref db) = self.db
+            && let Err(e) = db
+                .set_endpoint_health(client.network(), client.endpoint_health())
+                .await
+        {
+            tracing::warn!("Failed to save endpoint health: {}", e);
+        }
+    }
+
     /// Check if an error string indicates a lost connection.
     fn is_connection_error(err: &str) -> bool {
         err.contains("ConnectionShutdown")
@@ -974,13 +1463,23 @@ impl ChainWorker {
 
     /// Attempt to reconnect using stored connection params.
     async fn try_reconnect(&mut self) -> bool {
-        let Some(network) = self.network else {
+        let Some(network) = self.network.clone() else {
             return false;
         };
         tracing::warn!("Connection lost, attempting reconnection to {}...", network);
-        self.client = None;
         self.people_client = None;
-        self.handle_connect(network, self.use_light_client).await;
+        let Some(client) = self.client.take() else {
+            self.handle_connect(network, self.mode).await;
+            return self.client.is_some();
+        };
+        // Reconnecting demotes the endpoint that dropped us, so it fails over
+        match client.reconnect().await {
+            Ok(client) => self.on_connected(network, client).await,
+            Err(e) => {
+                self.save_endpoint_health(&client).await;
+                self.report_connect_error(e).await;
+            }
+        }
         self.client.is_some()
     }
 
@@ -1008,16 +1507,18 @@ impl ChainWorker {
                     );
 
                     if self.people_client.is_none() {
-                        self.people_client = connect_ready_people_client(client, network).await;
+                        self.people_client =
+                            connect_ready_people_client(client, network.clone()).await;
                     }
 
                     // Enrich validators with full data (stake, identity, APY)
                     let enriched = enrich_validators(
                         client,
+                        self.indexer.as_ref(),
                         &validators,
                         self.people_client.as_ref(),
                         self.db.as_ref(),
-                        network,
+                        network.clone(),
                     )
                     .await;
                     self.set_progress(LoadingStep::Validators, 0.85).await;
@@ -1025,11 +1526,12 @@ impl ChainWorker {
                     // Persist to DB
                     if let Some(ref db) = self.db {
                         // Get current era from metadata or default to 0
-                        let era = if let Ok(Some(meta)) = db.get_chain_metadata(network).await {
-                            meta.current_era
-                        } else {
-                            0
-                        };
+                        let era =
+                            if let Ok(Some(meta)) = db.get_chain_metadata(network.clone()).await {
+                                meta.current_era
+                            } else {
+                                0
+                            };
 
                         if let Err(e) = db
                             .set_cached_validators_checked(
@@ -1084,19 +1586,42 @@ impl ChainWorker {
         let pool = client.get_pool_membership(&account_id).await;
 
         let bal = balance.map_err(|e| format!("Failed to fetch balance: {}", e))?;
+        let balance_breakdown = client
+            .get_balance_breakdown(&account_id)
+            .await
+            .inspect_err(|e| tracing::warn!("Failed to fetch balance breakdown: {}", e))
+            .ok();
 
         let staking_ledger = staking.ok().flatten();
         let staked = staking_ledger.as_ref().map(|s| s.active);
-        let unbonding = staking_ledger
+        let unbonding_balance = staking_ledger
             .as_ref()
             .map(|s| s.unlocking.iter().map(|c| c.value).sum())
             .unwrap_or(0);
+        let nominations = nominations.ok().flatten();
         let noms: Vec<String> = nominations
-            .ok()
-            .flatten()
+            .as_ref()
             .map(|n| n.targets.iter().map(|t| t.to_string()).collect())
             .unwrap_or_default();
 
+        let era_info = client
+            .get_active_era()
+            .await
+            .inspect_err(|e| tracing::warn!("Failed to get active era: {}", e))
+            .ok()
+            .flatten();
+        let active_era = era_info.as_ref().map(|era| era.index);
+
+        // Which nominated validators carry the stake this era
+        let exposure = match (&nominations, active_era) {
+            (Some(n), Some(era)) if !n.targets.is_empty() => client
+                .get_nominator_exposure(&account_id, &n.targets, era)
+                .await
+                .inspect_err(|e| tracing::warn!("Failed to fetch nominator exposure: {}", e))
+                .ok(),
+            _ => None,
+        };
+
         // Get pool membership and calculate pending rewards
         let pool_membership = pool.ok().flatten();
         let pool_id = pool_membership.as_ref().map(|p| p.pool_id);
@@ -1122,12 +1647,70 @@ impl ChainWorker {
             0
         };
 
+        let health = client
+            .get_health_account(
+                staking_ledger.as_ref(),
+                nominations.as_ref(),
+                pool_membership.as_ref(),
+                exposure.as_ref(),
+            )
+            .await
+            .inspect_err(|e| tracing::warn!("Failed to fetch health check data: {}", e))
+            .ok();
+
+        let past_slashes = client
+            .get_nominator_slashes(&account_id)
+            .await
+            .inspect_err(|e| tracing::warn!("Failed to fetch nominator slashes: {}", e))
+            .ok();
+
+        let bags_position = match &staking_ledger {
+            Some(ledger) => client
+                .get_bags_list_position(&account_id, ledger.active)
+                .await
+                .inspect_err(|e| tracing::warn!("Failed to fetch voter list position: {}", e))
+                .ok()
+                .flatten(),
+            None => None,
+        };
+
+        let pool_details = match &pool_membership {
+            Some(membership) => client
+                .get_pool_member_details(&account_id, membership)
+                .await
+                .inspect_err(|e| tracing::warn!("Failed to fetch pool member details: {}", e))
+                .ok(),
+            None => None,
+        };
+
+        let pool_admin = client
+            .get_pool_admin_roles(&account_id)
+            .await
+            .inspect_err(|e| tracing::warn!("Failed to fetch pool roles: {}", e))
+            .unwrap_or_default();
+
+        let unbonding = match &era_info {
+            Some(era) => unbonding_schedule(
+                staking_ledger
+                    .as_ref()
+                    .map_or(&[][..], |l| &l.unlocking[..]),
+                pool_id,
+                pool_membership
+                    .as_ref()
+                    .map_or(&[][..], |m| &m.unbonding_eras[..]),
+                era.index,
+                era.start_timestamp_ms,
+                era.duration_ms,
+            ),
+            None => Vec::new(),
+        };
+
         let account_data = AccountData {
             free_balance: bal.free,
             reserved_balance: bal.reserved,
             frozen_balance: bal.frozen,
             staked_balance: staked,
-            unbonding_balance: unbonding,
+            unbonding_balance,
             is_nominating: !noms.is_empty(),
             nominations: noms.clone(),
             pool_id,
@@ -1140,6 +1723,15 @@ impl ChainWorker {
                 .as_ref()
                 .map(|membership| membership.last_recorded_reward_counter)
                 .unwrap_or_default(),
+            balance_breakdown,
+            exposure,
+            health,
+            past_slashes,
+            bags_position,
+            pool_details,
+            pool_admin,
+            active_era,
+            unbonding,
         };
 
         // Persist to DB
@@ -1192,16 +1784,21 @@ impl ChainWorker {
         address: String,
         reply: oneshot::Sender<Result<AccountData, String>>,
     ) {
+        if let (Some(subscription), Ok(account)) = (&self.subscription, address.parse()) {
+            subscription.watch_account(Some(account));
+        }
+        self.watched_address = Some(address.clone());
+
         if let Some(ref db) = self.db {
             match db
-                .get_recent_cached_account_status(network, address.clone())
+                .get_recent_cached_account_status(network.clone(), address.clone())
                 .await
             {
                 Ok(Some(status)) => {
                     tracing::info!("Using recent cached account status for {}", address);
                     let _ = reply.send(Ok(account_data_from_cache(&status)));
 
-                    let mut result = self.fetch_account_once(network, &address).await;
+                    let mut result = self.fetch_account_once(network.clone(), &address).await;
                     if let Err(ref e) = result
                         && Self::is_connection_error(e)
                         && self.try_reconnect().await
@@ -1226,7 +1823,7 @@ impl ChainWorker {
             }
         }
 
-        let mut result = self.fetch_account_once(network, &address).await;
+        let mut result = self.fetch_account_once(network.clone(), &address).await;
         if let Err(ref e) = result
             && Self::is_connection_error(e)
             && self.try_reconnect().await
@@ -1259,20 +1856,21 @@ impl ChainWorker {
                 let validators = fetch.validators;
                 tracing::info!("Fetched {} raw validators, enriching...", validators.len());
                 if self.people_client.is_none() {
-                    self.people_client = connect_ready_people_client(client, network).await;
+                    self.people_client = connect_ready_people_client(client, network.clone()).await;
                 }
                 let enriched = enrich_validators(
                     client,
+                    self.indexer.as_ref(),
                     &validators,
                     self.people_client.as_ref(),
                     self.db.as_ref(),
-                    network,
+                    network.clone(),
                 )
                 .await;
 
                 // Persist to DB
                 if let Some(ref db) = self.db {
-                    let era = if let Ok(Some(meta)) = db.get_chain_metadata(network).await {
+                    let era = if let Ok(Some(meta)) = db.get_chain_metadata(network.clone()).await {
                         meta.current_era
                     } else {
                         0
@@ -1300,13 +1898,16 @@ impl ChainWorker {
     async fn validator_apy_map_for_pools(&mut self, network: Network) -> HashMap<String, f64> {
         if let Some(ref db) = self.db {
             let current_era = db
-                .get_chain_metadata(network)
+                .get_chain_metadata(network.clone())
                 .await
                 .ok()
                 .flatten()
                 .map(|meta| meta.current_era)
                 .unwrap_or(0);
-            match db.get_fresh_cached_validators(network, current_era).await {
+            match db
+                .get_fresh_cached_validators(network.clone(), current_era)
+                .await
+            {
                 Ok(validators) => {
                     let cached_map = validator_apy_map(&validators);
                     if !cached_map.is_empty() {
@@ -1342,7 +1943,7 @@ impl ChainWorker {
         network: Network,
         reply: oneshot::Sender<Result<Vec<ValidatorInfo>, String>>,
     ) {
-        let mut result = self.fetch_validators_once(network).await;
+        let mut result = self.fetch_validators_once(network.clone()).await;
         if let Err(ref e) = result
             && Self::is_connection_error(e)
             && self.try_reconnect().await
@@ -1365,7 +1966,7 @@ impl ChainWorker {
         };
 
         self.set_progress(LoadingStep::Pools, 0.45).await;
-        let validator_apy_map = self.validator_apy_map_for_pools(network).await;
+        let validator_apy_map = self.validator_apy_map_for_pools(network.clone()).await;
         self.set_progress(LoadingStep::Pools, 0.65).await;
         tracing::info!(
             "Built validator APY map with {} entries for pool APY calculation",
@@ -1398,7 +1999,7 @@ impl ChainWorker {
 
         if let Some(ref db) = self.db {
             let era = db
-                .get_chain_metadata(network)
+                .get_chain_metadata(network.clone())
                 .await
                 .ok()
                 .flatten()
@@ -1437,7 +2038,7 @@ impl ChainWorker {
         network: Network,
         reply: oneshot::Sender<Result<Vec<PoolInfo>, String>>,
     ) {
-        let mut result = self.fetch_pools_once(network).await;
+        let mut result = self.fetch_pools_once(network.clone()).await;
         if let Err(ref e) = result
             && Self::is_connection_error(e)
             && self.try_reconnect().await
@@ -1447,6 +2048,107 @@ impl ChainWorker {
         let _ = reply.send(result);
     }
 
+    async fn handle_fetch_portfolio(
+        &mut self,
+        network: Network,
+        accounts: Vec<(String, Option<String>)>,
+        reply: oneshot::Sender<Result<Vec<PortfolioAccount>, String>>,
+    ) {
+        let mut result = self.fetch_portfolio_once(network.clone(), &accounts).await;
+        if let Err(ref e) = result
+            && Self::is_connection_error(e)
+            && self.try_reconnect().await
+        {
+            result = self.fetch_portfolio_once(network, &accounts).await;
+        }
+        let _ = reply.send(result);
+    }
+
+    async fn fetch_portfolio_once(
+        &self,
+        network: Network,
+        accounts: &[(String, Option<String>)],
+    ) -> Result<Vec<PortfolioAccount>, String> {
+        let Some(client) = self.client.as_ref() else {
+            return Err("Not connected".to_string());
+        };
+        let addresses = accounts
+            .iter()
+            .map(|(address, _)| {
+                address
+                    .parse::<AccountId32>()
+                    .map_err(|e| format!("Invalid address {}: {}", address, e))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let statuses = client
+            .get_accounts_status(&addresses)
+            .await
+            .map_err(|e| e.to_string())?;
+        statuses
+            .iter()
+            .zip(accounts)
+            .map(|(status, (_, label))| {
+                status
+                    .to_portfolio_account(label.clone(), network.ss58_format())
+                    .map_err(|e| e.to_string())
+            })
+            .collect()
+    }
+
+    /// Refresh data after the chain subscription saw a change.
+    async fn handle_subscription_update(&mut self, network: Network, update: SubscriptionUpdate) {
+        match update {
+            SubscriptionUpdate::EraChanged { previous, era } => {
+                tracing::info!("Era changed from {} to {}", previous, era.index);
+                // Fresh data is cached under the new era; limits like
+                // MinimumActiveStake move with each election
+                let staking_params = self.fetch_staking_params(network.clone()).await;
+                self.fetch_slash_history().await;
+                if let Some(ref db) = self.db
+                    && let Ok(Some(mut meta)) = db.get_chain_metadata(network.clone()).await
+                {
+                    meta.current_era = era.index;
+                    if staking_params.is_some() {
+                        meta.staking_params = staking_params;
+                    }
+                    if let Err(e) = db.set_chain_metadata(network.clone(), meta).await {
+                        tracing::warn!("Failed to update cached era: {}", e);
+                    }
+                }
+                match self.fetch_validators_once(network.clone()).await {
+                    Ok(validators) => {
+                        let _ = self
+                            .update_tx
+                            .send(ChainUpdate::ValidatorsLoaded(validators))
+                            .await;
+                    }
+                    Err(e) => tracing::warn!("Failed to refresh validators: {}", e),
+                }
+                match self.fetch_pools_once(network).await {
+                    Ok(pools) => {
+                        let _ = self.update_tx.send(ChainUpdate::PoolsLoaded(pools)).await;
+                    }
+                    Err(e) => tracing::warn!("Failed to refresh pools: {}", e),
+                }
+            }
+            SubscriptionUpdate::AccountChanged { account, .. } => {
+                let Some(address) = self.watched_address.clone() else {
+                    return;
+                };
+                tracing::info!("Account {} changed on chain", account);
+                match self.fetch_account_once(network, &address).await {
+                    Ok(account_data) => {
+                        let _ = self
+                            .update_tx
+                            .send(ChainUpdate::AccountLoaded(account_data))
+                            .await;
+                    }
+                    Err(e) => tracing::warn!("Failed to refresh account: {}", e),
+                }
+            }
+        }
+    }
+
     // === Transaction Payload Handlers ===
 
     async fn handle_create_bond_payload(
@@ -1575,6 +2277,68 @@ impl ChainWorker {
         let _ = reply.send(result);
     }
 
+    async fn handle_create_rebag_payload(
+        &self,
+        signer: AccountId32,
+        reply: oneshot::Sender<Result<TransactionPayload, String>>,
+    ) {
+        let result = if let Some(ref client) = self.client {
+            client
+                .create_rebag_payload(&signer, &signer, true)
+                .await
+                .map_err(|e| format!("Failed to create rebag payload: {}", e))
+                .and_then(|p| make_transaction_payload(p, signer))
+        } else {
+            Err("Not connected".to_string())
+        };
+        let _ = reply.send(result);
+    }
+
+    async fn handle_create_put_in_front_of_payload(
+        &self,
+        signer: AccountId32,
+        lighter: AccountId32,
+        reply: oneshot::Sender<Result<TransactionPayload, String>>,
+    ) {
+        let result = if let Some(ref client) = self.client {
+            client
+                .create_put_in_front_of_payload(&signer, &lighter, true)
+                .await
+                .map_err(|e| format!("Failed to create put_in_front_of payload: {}", e))
+                .and_then(|p| make_transaction_payload(p, signer))
+        } else {
+            Err("Not connected".to_string())
+        };
+        let _ = reply.send(result);
+    }
+
+    async fn handle_refresh_payload(
+        &self,
+        payload: UnsignedPayload,
+        signer: AccountId32,
+        reply: oneshot::Sender<Result<TransactionPayload, String>>,
+    ) {
+        let result = if let Some(ref client) = self.client {
+            client
+                .refresh_payload(&signer, &payload)
+                .await
+                .map_err(|e| format!("Failed to rebuild payload: {}", e))
+                .and_then(|p| make_transaction_payload(p, signer))
+        } else {
+            Err("Not connected".to_string())
+        };
+        let _ = reply.send(result);
+    }
+
+    fn handle_set_mortality_periods(&mut self, periods: HashMap<Network, u64>) {
+        if let (Some(client), Some(network)) = (self.client.as_mut(), self.network.clone())
+            && let Some(&period) = periods.get(&network)
+        {
+            client.set_mortality_period(period);
+        }
+        self.mortality_periods = periods;
+    }
+
     async fn handle_create_nominate_payload(
         &self,
         signer: AccountId32,
@@ -1688,6 +2452,137 @@ impl ChainWorker {
         let _ = reply.send(result);
     }
 
+    async fn handle_create_pool_set_claim_permission_payload(
+        &self,
+        signer: AccountId32,
+        permission: ClaimPermission,
+        reply: oneshot::Sender<Result<TransactionPayload, String>>,
+    ) {
+        let result = if let Some(ref client) = self.client {
+            client
+                .create_pool_set_claim_permission_payload(&signer, permission, true)
+                .await
+                .map_err(|e| format!("Failed to create set_claim_permission payload: {}", e))
+                .and_then(|p| make_transaction_payload(p, signer))
+        } else {
+            Err("Not connected".to_string())
+        };
+        let _ = reply.send(result);
+    }
+
+    async fn handle_create_pool_member_call_payload(
+        &self,
+        signer: AccountId32,
+        member: AccountId32,
+        call: PoolMemberCall,
+        reply: oneshot::Sender<Result<TransactionPayload, String>>,
+    ) {
+        let result = if let Some(ref client) = self.client {
+            client
+                .create_pool_member_call_payload(&signer, &member, call, true)
+                .await
+                .map_err(|e| format!("Failed to create member rewards payload: {}", e))
+                .and_then(|p| make_transaction_payload(p, signer))
+        } else {
+            Err("Not connected".to_string())
+        };
+        let _ = reply.send(result);
+    }
+
+    async fn handle_create_pool_admin_payload(
+        &self,
+        signer: AccountId32,
+        pool_id: u32,
+        call: PoolAdminCall,
+        reply: oneshot::Sender<Result<TransactionPayload, String>>,
+    ) {
+        let result = if let Some(ref client) = self.client {
+            let payload = match &call {
+                PoolAdminCall::Nominate(targets) => {
+                    client
+                        .create_pool_nominate_payload(&signer, pool_id, targets, true)
+                        .await
+                }
+                PoolAdminCall::SetState(state) => {
+                    client
+                        .create_pool_set_state_payload(&signer, pool_id, *state, true)
+                        .await
+                }
+                PoolAdminCall::SetMetadata(name) => {
+                    client
+                        .create_pool_set_metadata_payload(&signer, pool_id, name.as_bytes(), true)
+                        .await
+                }
+                PoolAdminCall::SetCommission(rate) => {
+                    // Commission is paid to the root setting it
+                    client
+                        .create_pool_set_commission_payload(
+                            &signer,
+                            pool_id,
+                            rate.map(|perbill| (perbill, &signer)),
+                            true,
+                        )
+                        .await
+                }
+                PoolAdminCall::SetCommissionMax(max) => {
+                    client
+                        .create_pool_set_commission_max_payload(&signer, pool_id, *max, true)
+                        .await
+                }
+                PoolAdminCall::SetCommissionChangeRate(change_rate) => {
+                    client
+                        .create_pool_set_commission_change_rate_payload(
+                            &signer,
+                            pool_id,
+                            *change_rate,
+                            true,
+                        )
+                        .await
+                }
+                PoolAdminCall::ClaimCommission => {
+                    client
+                        .create_pool_claim_commission_payload(&signer, pool_id, true)
+                        .await
+                }
+                PoolAdminCall::UpdateRoles {
+                    root,
+                    nominator,
+                    bouncer,
+                } => {
+                    client
+                        .create_pool_update_roles_payload(
+                            &signer, pool_id, root, nominator, bouncer, true,
+                        )
+                        .await
+                }
+                PoolAdminCall::Create {
+                    amount,
+                    root,
+                    nominator,
+                    bouncer,
+                } => {
+                    // The pallet assigns the new pool's id, so `pool_id` is unused
+                    client
+                        .create_pool_create_payload(
+                            &signer,
+                            *amount,
+                            root.as_ref().unwrap_or(&signer),
+                            nominator.as_ref().unwrap_or(&signer),
+                            bouncer.as_ref().unwrap_or(&signer),
+                            true,
+                        )
+                        .await
+                }
+            };
+            payload
+                .map_err(|e| format!("Failed to create pool admin payload: {}", e))
+                .and_then(|p| make_transaction_payload(p, signer))
+        } else {
+            Err("Not connected".to_string())
+        };
+        let _ = reply.send(result);
+    }
+
     // === Transaction Submission Handler ===
 
     async fn handle_submit_signed_extrinsic(
@@ -1704,10 +2599,12 @@ impl ChainWorker {
                             if in_block.finalized {
                                 Ok(TxSubmissionResult::Finalized {
                                     block_hash: in_block.block_hash,
+                                    outcome: in_block.outcome,
                                 })
                             } else {
                                 Ok(TxSubmissionResult::InBlock {
                                     block_hash: in_block.block_hash,
+                                    outcome: in_block.outcome,
                                 })
                             }
                         }
@@ -1846,7 +2743,7 @@ impl ChainWorker {
         let mut fallback_cached_history = Vec::new();
         if let Some(ref db) = self.db {
             match db
-                .get_latest_history_cache(network, address.clone(), Some(fallback_eras))
+                .get_latest_history_cache(network.clone(), address.clone(), Some(fallback_eras))
                 .await
             {
                 Ok(history) if !history.is_empty() => {
@@ -1883,7 +2780,7 @@ impl ChainWorker {
         let cached_history = if let Some(ref db) = self.db {
             match db
                 .get_history_cache_range(
-                    network,
+                    network.clone(),
                     address.clone(),
                     start_era,
                     end_era,
@@ -1919,7 +2816,7 @@ impl ChainWorker {
         };
 
         let eras_to_fetch: Vec<u32> = if let Some(ref db) = self.db {
-            db.get_missing_history_eras(network, address.clone(), start_era, end_era)
+            db.get_missing_history_eras(network.clone(), address.clone(), start_era, end_era)
                 .await
                 .unwrap_or_else(|_| (start_era..current_era).collect())
         } else {
@@ -1934,6 +2831,14 @@ impl ChainWorker {
 
         tracing::info!("Fetching {} missing eras from chain", eras_to_fetch.len());
 
+        // Light clients lack old era state; use spot-checked indexer data
+        let source =
+            HistoryEraSource::load(client, self.indexer.as_ref(), start_era, end_era).await;
+        if source.indexed_eras() > 0 {
+            tracing::info!("Using indexer data for {} eras", source.indexed_eras());
+        }
+        let source = &source;
+
         let concurrency = 8;
         let mut fetched = stream::iter(eras_to_fetch)
             .map(|era| async move {
@@ -1943,8 +2848,8 @@ impl ChainWorker {
                     current_era_start_ms,
                     era_duration_ms,
                     user_bonded,
-                    |era| client.get_era_validator_reward(era),
-                    |era| client.get_era_total_stake_direct(era),
+                    |era| source.validator_reward(era),
+                    |era| source.total_stake(era),
                 )
                 .await;
                 (era, point)
@@ -1985,6 +2890,261 @@ impl ChainWorker {
         tracing::info!("Staking history loaded: {} total points", all_points.len());
         let _ = reply.send(Ok(all_points));
     }
+
+    /// Compute exact per-era rewards from the paged exposure of the account's
+    /// current nominations, reusing cached eras.
+    async fn handle_fetch_exact_history(
+        &mut self,
+        network: Network,
+        address: String,
+        lookback_days: u32,
+        reply: oneshot::Sender<Result<Vec<HistoryPoint>, String>>,
+    ) {
+        let Some(ref client) = self.client else {
+            let _ = reply.send(Err("Not connected".to_string()));
+            return;
+        };
+        let account: AccountId32 = match address.parse() {
+            Ok(a) => a,
+            Err(e) => {
+                let _ = reply.send(Err(format!("Invalid address: {}", e)));
+                return;
+            }
+        };
+
+        let current_era_info = match client.get_active_era().await {
+            Ok(Some(era)) => era,
+            Ok(None) => {
+                let _ = reply.send(Ok(Vec::new()));
+                return;
+            }
+            Err(e) => {
+                let _ = reply.send(Err(format!("Failed to get active era: {}", e)));
+                return;
+            }
+        };
+        let current_era = current_era_info.index;
+        let current_era_start_ms = current_era_info.start_timestamp_ms;
+        let era_duration_ms = current_era_info.duration_ms;
+        let num_eras = eras_for_lookback_days(lookback_days, era_duration_ms);
+        let (start_era, end_era) = HistoryService::era_window(current_era, num_eras);
+
+        let (mut points, missing) = match self.db {
+            Some(ref db) => (
+                db.get_exact_history_range(
+                    network.clone(),
+                    address.clone(),
+                    start_era,
+                    end_era,
+                    current_era,
+                    current_era_start_ms,
+                    era_duration_ms,
+                )
+                .await
+                .unwrap_or_default(),
+                db.get_missing_exact_history_eras(
+                    network.clone(),
+                    address.clone(),
+                    start_era,
+                    end_era,
+                )
+                .await
+                .unwrap_or_else(|_| (start_era..=end_era).collect()),
+            ),
+            None => (Vec::new(), (start_era..=end_era).collect()),
+        };
+
+        let targets = match client.get_nominations(&account).await {
+            Ok(Some(nominations)) => nominations.targets,
+            Ok(None) => Vec::new(),
+            Err(e) => {
+                tracing::warn!("Failed to get nominations for exact rewards: {}", e);
+                Vec::new()
+            }
+        };
+        if missing.is_empty() || targets.is_empty() {
+            let _ = reply.send(Ok(points));
+            return;
+        }
+
+        let user_bonded = match client.get_staking_ledger(&account).await {
+            Ok(Some(ledger)) => ledger.active,
+            _ => 0,
+        };
+
+        let account = &account;
+        let targets = &targets;
+        let mut new_points: Vec<HistoryPoint> = stream::iter(missing)
+            .map(|era| async move {
+                match client.get_exact_era_reward(account, targets, era).await {
+                    Ok(reward) => reward.map(|reward| {
+                        exact_history_point(
+                            era,
+                            current_era,
+                            current_era_start_ms,
+                            era_duration_ms,
+                            reward,
+                            user_bonded,
+                        )
+                    }),
+                    Err(e) => {
+                        tracing::debug!("No exact reward for era {}: {}", era, e);
+                        None
+                    }
+                }
+            })
+            .buffer_unordered(8)
+            .filter_map(|point| async move { point })
+            .collect()
+            .await;
+        new_points.sort_by_key(|p| p.era);
+
+        if let Some(ref db) = self.db
+            && !new_points.is_empty()
+        {
+            if let Err(e) = db
+                .insert_exact_history_batch(network, address.clone(), new_points.clone())
+                .await
+            {
+                tracing::warn!("Failed to cache exact history: {}", e);
+            } else {
+                tracing::info!("Cached {} exact history points", new_points.len());
+            }
+        }
+
+        points.extend(new_points);
+        points.sort_by_key(|p| p.era);
+        let _ = reply.send(Ok(points));
+    }
+
+    /// Reconcile expected rewards against payout events.
+    ///
+    /// New events come from the network's staking indexer in either
+    /// connection mode. Without one, or if it fails, they come from a scan of
+    /// the blocks since the last scan, at most [`DEFAULT_PAYOUT_SCAN_BLOCKS`]
+    /// back.
+    async fn handle_check_payouts(
+        &mut self,
+        network: Network,
+        address: String,
+        (start_era, end_era): (EraIndex, EraIndex),
+        reply: oneshot::Sender<Result<Vec<PayoutCheck>, String>>,
+    ) {
+        let Some(ref client) = self.client else {
+            let _ = reply.send(Err("Not connected".to_string()));
+            return;
+        };
+        let account: AccountId32 = match address.parse() {
+            Ok(a) => a,
+            Err(e) => {
+                let _ = reply.send(Err(format!("Invalid address: {}", e)));
+                return;
+            }
+        };
+
+        // The RPC mode has no history indexer, but payouts are still cheaper
+        // to fetch from one than to scan for.
+        let payout_indexer = match self.indexer {
+            Some(_) => None,
+            None => IndexerClient::new(network.clone()).ok(),
+        };
+        let indexed = match self.indexer.as_ref().or(payout_indexer.as_ref()) {
+            Some(indexer) => match indexer
+                .fetch_payout_events(&address, start_era, end_era)
+                .await
+            {
+                Ok(events) => Some(events),
+                Err(e) => {
+                    tracing::warn!("Indexer payouts unavailable, scanning blocks: {}", e);
+                    None
+                }
+            },
+            None => None,
+        };
+        let (new_events, scan) = match indexed {
+            Some(events) => (events, None),
+            None => {
+                let latest = match client.get_latest_block().await {
+                    Ok((number, _)) => number as u64,
+                    Err(e) => {
+                        let _ = reply.send(Err(format!("Failed to get latest block: {}", e)));
+                        return;
+                    }
+                };
+                let cursor = match self.db {
+                    Some(ref db) => db
+                        .get_payout_scan_cursor(network.clone(), address.clone())
+                        .await
+                        .unwrap_or_default(),
+                    None => None,
+                };
+                let (from, scan) = PayoutScan::next(cursor, latest, DEFAULT_PAYOUT_SCAN_BLOCKS);
+                match client
+                    .scan_payout_events(std::slice::from_ref(&account), from, scan.last_block)
+                    .await
+                {
+                    Ok(events) => (events.into_iter().map(|e| e.event).collect(), Some(scan)),
+                    Err(e) => {
+                        let _ = reply.send(Err(format!("Failed to scan payouts: {}", e)));
+                        return;
+                    }
+                }
+            }
+        };
+        // Eras active from the first scanned block on are paid out within the
+        // scanned blocks; the indexer has every era.
+        let covered_from = match scan {
+            Some(scan) => match client.get_active_era_at(scan.first_block).await {
+                Ok(era) => era.unwrap_or(EraIndex::MAX),
+                Err(e) => {
+                    tracing::debug!("No active era at block {}: {}", scan.first_block, e);
+                    EraIndex::MAX
+                }
+            },
+            None => 0,
+        };
+
+        let events = match self.db {
+            Some(ref db) => {
+                if let Err(e) = db
+                    .insert_payout_events(network.clone(), address.clone(), new_events.clone())
+                    .await
+                {
+                    tracing::warn!("Failed to cache payout events: {}", e);
+                }
+                if let Some(scan) = scan
+                    && let Err(e) = db
+                        .set_payout_scan_cursor(network.clone(), address.clone(), scan)
+                        .await
+                {
+                    tracing::warn!("Failed to save payout scan cursor: {}", e);
+                }
+                db.get_payout_events(network, address.clone(), start_era, end_era)
+                    .await
+                    .unwrap_or(new_events)
+            }
+            None => new_events,
+        };
+
+        let targets = match client.get_nominations(&account).await {
+            Ok(nominations) => nominations.map(|n| n.targets).unwrap_or_default(),
+            Err(e) => {
+                let _ = reply.send(Err(format!("Failed to get nominations: {}", e)));
+                return;
+            }
+        };
+        let expected = client
+            .get_expected_payouts_in(&account, &targets, start_era..=end_era, &events)
+            .await;
+
+        let checks = reconcile_payouts(&expected, &events, covered_from);
+        tracing::info!(
+            "Payout check: {} of {} flagged",
+            checks.iter().filter(|c| c.is_flagged()).count(),
+            checks.len()
+        );
+        let _ = reply.send(Ok(checks));
+    }
 }
 
 /// Spawn the chain worker and return a handle.
@@ -1994,33 +3154,55 @@ pub fn spawn_chain_worker(
 ) -> (ChainHandle, mpsc::Receiver<ChainUpdate>) {
     let (command_tx, mut command_rx) = mpsc::channel::<ChainCommand>(32);
     let (update_tx, update_rx) = mpsc::channel::<ChainUpdate>(32);
+    let (subscription_tx, mut subscription_rx) = mpsc::channel::<SubscriptionUpdate>(16);
 
     handle.spawn(async move {
-        let mut worker = ChainWorker::new(update_tx, db);
+        let mut worker = ChainWorker::new(update_tx, subscription_tx, db);
         let mut current_network = Network::Polkadot; // Track current network for DB operations
 
-        while let Some(command) = command_rx.recv().await {
+        loop {
+            let command = tokio::select! {
+                command = command_rx.recv() => match command {
+                    Some(command) => command,
+                    None => break,
+                },
+                Some(update) = subscription_rx.recv() => {
+                    worker
+                        .handle_subscription_update(current_network.clone(), update)
+                        .await;
+                    continue;
+                }
+            };
             match command {
-                ChainCommand::Connect {
-                    network,
-                    use_light_client,
-                } => {
-                    current_network = network;
-                    worker.handle_connect(network, use_light_client).await;
+                ChainCommand::Connect { network, mode } => {
+                    current_network = network.clone();
+                    worker.handle_connect(network, mode).await;
                 }
                 ChainCommand::Disconnect => {
                     worker.handle_disconnect().await;
                 }
                 ChainCommand::FetchAccount { address, reply } => {
                     worker
-                        .handle_fetch_account(current_network, address, reply)
+                        .handle_fetch_account(current_network.clone(), address, reply)
                         .await;
                 }
                 ChainCommand::FetchValidators { reply } => {
-                    worker.handle_fetch_validators(current_network, reply).await;
+                    worker
+                        .handle_fetch_validators(current_network.clone(), reply)
+                        .await;
+                }
+                ChainCommand::FetchOffenceReports => {
+                    worker.fetch_offence_reports().await;
                 }
                 ChainCommand::FetchPools { reply } => {
-                    worker.handle_fetch_pools(current_network, reply).await;
+                    worker
+                        .handle_fetch_pools(current_network.clone(), reply)
+                        .await;
+                }
+                ChainCommand::FetchPortfolio { accounts, reply } => {
+                    worker
+                        .handle_fetch_portfolio(current_network.clone(), accounts, reply)
+                        .await;
                 }
                 ChainCommand::FetchHistory {
                     address,
@@ -2028,7 +3210,41 @@ pub fn spawn_chain_worker(
                     reply,
                 } => {
                     worker
-                        .handle_fetch_history(current_network, address, lookback_days, reply)
+                        .handle_fetch_history(
+                            current_network.clone(),
+                            address,
+                            lookback_days,
+                            reply,
+                        )
+                        .await;
+                }
+                ChainCommand::FetchExactHistory {
+                    address,
+                    lookback_days,
+                    reply,
+                } => {
+                    worker
+                        .handle_fetch_exact_history(
+                            current_network.clone(),
+                            address,
+                            lookback_days,
+                            reply,
+                        )
+                        .await;
+                }
+                ChainCommand::CheckPayouts {
+                    address,
+                    start_era,
+                    end_era,
+                    reply,
+                } => {
+                    worker
+                        .handle_check_payouts(
+                            current_network.clone(),
+                            address,
+                            (start_era, end_era),
+                            reply,
+                        )
                         .await;
                 }
                 // === Transaction Payload Generation ===
@@ -2085,6 +3301,31 @@ pub fn spawn_chain_worker(
                 ChainCommand::CreateChillPayload { signer, reply } => {
                     worker.handle_create_chill_payload(signer, reply).await;
                 }
+                ChainCommand::CreateRebagPayload { signer, reply } => {
+                    worker.handle_create_rebag_payload(signer, reply).await;
+                }
+                ChainCommand::CreatePutInFrontOfPayload {
+                    signer,
+                    lighter,
+                    reply,
+                } => {
+                    worker
+                        .handle_create_put_in_front_of_payload(signer, lighter, reply)
+                        .await;
+                }
+                ChainCommand::RefreshPayload {
+                    payload,
+                    signer,
+                    reply,
+                } => {
+                    worker.handle_refresh_payload(payload, signer, reply).await;
+                }
+                ChainCommand::SetSession { session } => {
+                    worker.session = session;
+                }
+                ChainCommand::SetMortalityPeriods { periods } => {
+                    worker.handle_set_mortality_periods(periods);
+                }
                 ChainCommand::CreateNominatePayload {
                     signer,
                     targets,
@@ -2131,6 +3372,35 @@ pub fn spawn_chain_worker(
                         .handle_create_pool_withdraw_payload(signer, reply)
                         .await;
                 }
+                ChainCommand::CreatePoolSetClaimPermissionPayload {
+                    signer,
+                    permission,
+                    reply,
+                } => {
+                    worker
+                        .handle_create_pool_set_claim_permission_payload(signer, permission, reply)
+                        .await;
+                }
+                ChainCommand::CreatePoolMemberCallPayload {
+                    signer,
+                    member,
+                    call,
+                    reply,
+                } => {
+                    worker
+                        .handle_create_pool_member_call_payload(signer, member, call, reply)
+                        .await;
+                }
+                ChainCommand::CreatePoolAdminPayload {
+                    signer,
+                    pool_id,
+                    call,
+                    reply,
+                } => {
+                    worker
+                        .handle_create_pool_admin_payload(signer, pool_id, call, reply)
+                        .await;
+                }
                 // === Transaction Submission ===
                 ChainCommand::SubmitSignedExtrinsic { extrinsic, reply } => {
                     worker
@@ -2139,6 +3409,9 @@ pub fn spawn_chain_worker(
                 }
             }
         }
+
+        // Every handle was dropped: the app is shutting down
+        worker.save_light_client_peers().await;
     });
 
     (ChainHandle { command_tx }, update_rx)
@@ -2162,6 +3435,15 @@ mod tests {
             pool_pending_rewards: 0,
             pool_unbonding_eras: vec![],
             pool_last_recorded_reward_counter: 0,
+            balance_breakdown: None,
+            exposure: None,
+            health: None,
+            past_slashes: None,
+            bags_position: None,
+            pool_details: None,
+            pool_admin: Vec::new(),
+            active_era: None,
+            unbonding: Vec::new(),
         };
         assert_eq!(data.free_balance, 1000);
         assert_eq!(data.frozen_balance, 50);
@@ -2252,6 +3534,7 @@ mod tests {
             tx_version: 1,
             nonce: 0,
             era: stkopt_chain::Era::Immortal,
+            birth_block: None,
             include_metadata_hash: false,
             use_asset_payment: false,
             extension_ids: vec![],
@@ -2261,6 +3544,37 @@ mod tests {
         assert_eq!(result.signer, signer);
         assert_eq!(result.description, "Bond 10 DOT");
         assert!(!result.qr_data.is_empty());
+        assert_eq!(result.remaining_secs(), None);
+        assert!(!result.is_expired());
+    }
+
+    #[test]
+    fn test_transaction_payload_expiry() {
+        let payload = UnsignedPayload {
+            call_data: vec![0x01, 0x02],
+            description: "Chill".to_string(),
+            metadata_hash: [0u8; 32],
+            genesis_hash: [0u8; 32],
+            block_hash: [0u8; 32],
+            spec_version: 1,
+            tx_version: 1,
+            nonce: 0,
+            era: stkopt_chain::Era::Mortal {
+                period: 4,
+                phase: 0,
+            },
+            birth_block: Some(1_000),
+            include_metadata_hash: false,
+            use_asset_payment: false,
+            extension_ids: vec![],
+        };
+        let mut result = make_transaction_payload(payload, account(1)).unwrap();
+        assert!(result.remaining_secs().is_some_and(|secs| secs > 0));
+        assert!(!result.is_expired());
+
+        result.built_at -= std::time::Duration::from_secs(60);
+        assert_eq!(result.remaining_secs(), Some(0));
+        assert!(result.is_expired());
     }
 
     // === slashing_spans_for_withdraw ===
@@ -2293,6 +3607,7 @@ mod tests {
             token_decimals: Network::Paseo.token_decimals(),
             era_duration_ms: 0,
             current_era: 0,
+            staking_params: None,
         };
         assert_eq!(meta.ss58_prefix, 0);
         assert_eq!(meta.token_symbol, "PAS");
@@ -2323,7 +3638,7 @@ mod tests {
             let network = Network::Polkadot;
             service
                 .set_chain_metadata(
-                    network,
+                    network.clone(),
                     CachedChainMetadata {
                         genesis_hash: "0x00".to_string(),
                         spec_version: 1,
@@ -2333,6 +3648,7 @@ mod tests {
                         token_decimals: network.token_decimals(),
                         era_duration_ms: 24 * 60 * 60 * 1000,
                         current_era: 1500,
+                        staking_params: None,
                     },
                 )
                 .await
@@ -2340,7 +3656,7 @@ mod tests {
 
             service
                 .set_cached_validators_checked(
-                    network,
+                    network.clone(),
                     1500,
                     vec![ValidatorInfo {
                         address: account(1).to_string(),
@@ -3,11 +3,14 @@ use crate::persistence::get_data_dir;
 use anyhow::{Context, Result};
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
-use stkopt_core::Network;
 use stkopt_core::db::{
     AccountStatusService, CachePolicy, CachedAccountStatus, CachedChainMetadata, HistoryService,
     StakingDb, StartupDataCache, StartupDataService,
 };
+use stkopt_core::{
+    EndpointHealth, Network, NewTransaction, PayoutEvent, PayoutScan, TransactionFilter,
+    TransactionRecord, TxJournalStatus,
+};
 
 /// Service for asynchronous database access.
 #[derive(Clone)]
@@ -111,6 +114,23 @@ impl DbService {
             .await?
     }
 
+    /// Insert exact per-era reward points.
+    pub async fn insert_exact_history_batch(
+        &self,
+        network: Network,
+        address: String,
+        points: Vec<HistoryPoint>,
+    ) -> Result<()> {
+        let db = self.db.clone();
+        self.handle
+            .spawn_blocking(move || {
+                let mut db = db.lock().map_err(|_| anyhow::anyhow!("Db lock poisoned"))?;
+                db.insert_exact_history_batch(network, &address, &points)
+                    .context("Failed to insert exact history")
+            })
+            .await?
+    }
+
     /// Get cached validators.
     pub async fn get_cached_validators(&self, network: Network) -> Result<Vec<ValidatorInfo>> {
         let db = self.db.clone();
@@ -365,6 +385,34 @@ impl DbService {
             .await?
     }
 
+    /// Get persisted endpoint health stats.
+    pub async fn get_endpoint_health(&self, network: Network) -> Result<Vec<EndpointHealth>> {
+        let db = self.db.clone();
+        self.handle
+            .spawn_blocking(move || {
+                let db = db.lock().map_err(|_| anyhow::anyhow!("Db lock poisoned"))?;
+                db.get_endpoint_health(network)
+                    .context("Failed to get endpoint health")
+            })
+            .await?
+    }
+
+    /// Persist endpoint health stats.
+    pub async fn set_endpoint_health(
+        &self,
+        network: Network,
+        health: Vec<EndpointHealth>,
+    ) -> Result<()> {
+        let db = self.db.clone();
+        self.handle
+            .spawn_blocking(move || {
+                let mut db = db.lock().map_err(|_| anyhow::anyhow!("Db lock poisoned"))?;
+                db.set_endpoint_health(network, &health)
+                    .context("Failed to set endpoint health")
+            })
+            .await?
+    }
+
     /// Get cached account status.
     pub async fn get_cached_account_status(
         &self,
@@ -454,6 +502,123 @@ impl DbService {
             .await?
     }
 
+    /// Load cached exact per-era rewards for a range.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn get_exact_history_range(
+        &self,
+        network: Network,
+        address: String,
+        start_era: u32,
+        end_era: u32,
+        current_era: u32,
+        current_era_start_ms: u64,
+        era_duration_ms: u64,
+    ) -> Result<Vec<HistoryPoint>> {
+        let db = self.db.clone();
+        self.handle
+            .spawn_blocking(move || {
+                let db = db.lock().map_err(|_| anyhow::anyhow!("Db lock poisoned"))?;
+                HistoryService::load_exact_range(
+                    &db,
+                    network,
+                    &address,
+                    start_era,
+                    end_era,
+                    current_era,
+                    current_era_start_ms,
+                    era_duration_ms,
+                )
+                .context("Failed to load exact history range")
+            })
+            .await?
+    }
+
+    /// Get eras without a cached exact reward.
+    pub async fn get_missing_exact_history_eras(
+        &self,
+        network: Network,
+        address: String,
+        start_era: u32,
+        end_era: u32,
+    ) -> Result<Vec<u32>> {
+        let db = self.db.clone();
+        self.handle
+            .spawn_blocking(move || {
+                let db = db.lock().map_err(|_| anyhow::anyhow!("Db lock poisoned"))?;
+                HistoryService::missing_exact_eras(&db, network, &address, start_era, end_era)
+                    .context("Failed to get missing exact history eras")
+            })
+            .await?
+    }
+
+    /// Store payout events of an address.
+    pub async fn insert_payout_events(
+        &self,
+        network: Network,
+        address: String,
+        events: Vec<PayoutEvent>,
+    ) -> Result<()> {
+        let db = self.db.clone();
+        self.handle
+            .spawn_blocking(move || {
+                let mut db = db.lock().map_err(|_| anyhow::anyhow!("Db lock poisoned"))?;
+                db.insert_payout_events(network, &address, &events)
+                    .context("Failed to insert payout events")
+            })
+            .await?
+    }
+
+    /// Get stored staking payout events of an address for an era range.
+    pub async fn get_payout_events(
+        &self,
+        network: Network,
+        address: String,
+        start_era: u32,
+        end_era: u32,
+    ) -> Result<Vec<PayoutEvent>> {
+        let db = self.db.clone();
+        self.handle
+            .spawn_blocking(move || {
+                let db = db.lock().map_err(|_| anyhow::anyhow!("Db lock poisoned"))?;
+                db.get_payout_events(network, &address, Some((start_era, end_era)))
+                    .context("Failed to get payout events")
+            })
+            .await?
+    }
+
+    /// Get the block range scanned for payout events of an address.
+    pub async fn get_payout_scan_cursor(
+        &self,
+        network: Network,
+        address: String,
+    ) -> Result<Option<PayoutScan>> {
+        let db = self.db.clone();
+        self.handle
+            .spawn_blocking(move || {
+                let db = db.lock().map_err(|_| anyhow::anyhow!("Db lock poisoned"))?;
+                db.get_payout_scan_cursor(network, &address)
+                    .context("Failed to get payout scan cursor")
+            })
+            .await?
+    }
+
+    /// Record the block range scanned for payout events of an address.
+    pub async fn set_payout_scan_cursor(
+        &self,
+        network: Network,
+        address: String,
+        scan: PayoutScan,
+    ) -> Result<()> {
+        let db = self.db.clone();
+        self.handle
+            .spawn_blocking(move || {
+                let db = db.lock().map_err(|_| anyhow::anyhow!("Db lock poisoned"))?;
+                db.set_payout_scan_cursor(network, &address, scan)
+                    .context("Failed to set payout scan cursor")
+            })
+            .await?
+    }
+
     /// Load latest cached history fallback under the shared history policy.
     pub async fn get_latest_history_cache(
         &self,
@@ -487,6 +652,57 @@ impl DbService {
             })
             .await?
     }
+
+    /// Record a submitted transaction in the journal and return its id.
+    pub async fn record_transaction(&self, network: Network, tx: NewTransaction) -> Result<i64> {
+        let db = self.db.clone();
+        self.handle
+            .spawn_blocking(move || {
+                let db = db.lock().map_err(|_| anyhow::anyhow!("Db lock poisoned"))?;
+                db.record_transaction(network, &tx)
+                    .context("Failed to record transaction")
+            })
+            .await?
+    }
+
+    /// Record a status change of a journaled transaction.
+    pub async fn set_transaction_status(
+        &self,
+        id: i64,
+        status: TxJournalStatus,
+        block_hash: Option<String>,
+        dispatch_result: Option<String>,
+    ) -> Result<bool> {
+        let db = self.db.clone();
+        self.handle
+            .spawn_blocking(move || {
+                let db = db.lock().map_err(|_| anyhow::anyhow!("Db lock poisoned"))?;
+                db.set_transaction_status(
+                    id,
+                    status,
+                    block_hash.as_deref(),
+                    dispatch_result.as_deref(),
+                )
+                .context("Failed to update transaction status")
+            })
+            .await?
+    }
+
+    /// List journaled transactions, newest first.
+    pub async fn get_transactions(
+        &self,
+        network: Network,
+        filter: TransactionFilter,
+    ) -> Result<Vec<TransactionRecord>> {
+        let db = self.db.clone();
+        self.handle
+            .spawn_blocking(move || {
+                let db = db.lock().map_err(|_| anyhow::anyhow!("Db lock poisoned"))?;
+                db.get_transactions(network, &filter)
+                    .context("Failed to get transactions")
+            })
+            .await?
+    }
 }
 
 #[cfg(test)]
@@ -544,4 +760,46 @@ mod tests {
             assert_eq!(missing, vec![1501, 1503]);
         });
     }
+
+    #[test]
+    fn test_transaction_journal_round_trip() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let service = DbService::new_memory(runtime.handle().clone()).unwrap();
+
+        runtime.block_on(async {
+            let id = service
+                .record_transaction(
+                    Network::Polkadot,
+                    NewTransaction {
+                        signer: "addr1".to_string(),
+                        description: "Bond 1 DOT".to_string(),
+                        call_data: "0x0700".to_string(),
+                        nonce: 0,
+                        mortality: stkopt_core::TxMortality::Immortal,
+                        tx_hash: "0x01".to_string(),
+                    },
+                )
+                .await
+                .unwrap();
+            assert!(
+                service
+                    .set_transaction_status(
+                        id,
+                        TxJournalStatus::InBlock,
+                        Some("0xbeef".to_string()),
+                        None,
+                    )
+                    .await
+                    .unwrap()
+            );
+
+            let records = service
+                .get_transactions(Network::Polkadot, TransactionFilter::for_signer("addr1"))
+                .await
+                .unwrap();
+            assert_eq!(records.len(), 1);
+            assert_eq!(records[0].status, TxJournalStatus::InBlock);
+            assert_eq!(records[0].block_hash.as_deref(), Some("0xbeef"));
+        });
+    }
 }
@@ -24,13 +24,53 @@ pub mod validators;
 pub mod views;
 
 use app::StkoptApp;
+use clap::Parser;
 use gpui::prelude::*;
 use gpui_ui_kit::{MiniApp, MiniAppConfig};
 
 use tracing_subscriber::EnvFilter;
 use tracing_subscriber::prelude::*;
 
+/// Command-line options for the desktop app.
+#[derive(Parser, Debug)]
+#[command(name = "stkopt-desktop", version, about)]
+struct Args {
+    /// Directory for exported signing QR images (default: Downloads).
+    #[arg(long = "qr-export-dir")]
+    qr_export_dir: Option<std::path::PathBuf>,
+
+    /// Format for exported signing QR images: auto, png, svg or gif.
+    #[arg(long = "qr-export-format", default_value = "auto")]
+    qr_export_format: stkopt_chain::QrExportFormat,
+
+    /// Directory for exported transaction history (default: Downloads).
+    #[arg(long = "journal-export-dir")]
+    journal_export_dir: Option<std::path::PathBuf>,
+
+    /// Format for exported transaction history: csv or json.
+    #[arg(long = "journal-export-format", default_value = "csv")]
+    journal_export_format: stkopt_core::JournalExportFormat,
+
+    /// Record every chain query made this session to FILE (connects via RPC).
+    #[arg(long, value_name = "FILE", conflicts_with = "replay")]
+    record: Option<std::path::PathBuf>,
+
+    /// Replay a session recorded with --record, without network access.
+    #[arg(long, value_name = "FILE")]
+    replay: Option<std::path::PathBuf>,
+}
+
+impl Args {
+    fn session(&self) -> Option<stkopt_chain::SessionMode> {
+        self.record
+            .clone()
+            .map(stkopt_chain::SessionMode::Record)
+            .or_else(|| self.replay.clone().map(stkopt_chain::SessionMode::Replay))
+    }
+}
+
 fn main() {
+    let args = Args::parse();
     let config = crate::persistence::load_config().unwrap_or_default();
     let initial_theme = crate::theme::theme_variant_for_config(config.theme);
 
@@ -61,7 +101,24 @@ fn main() {
         move |cx| {
             // Initialize gpui_tokio bridge with the runtime handle
             gpui_tokio::init_from_handle(cx, handle.clone());
-            cx.new(|cx| StkoptApp::new(cx, app_logger.clone())) // LogBuffer clone is cheap (inner Arc)
+            let qr_export_dir = args.qr_export_dir.clone();
+            let qr_export_format = args.qr_export_format;
+            let journal_export_dir = args.journal_export_dir.clone();
+            let journal_export_format = args.journal_export_format;
+            let session = args.session();
+            cx.new(|cx| {
+                // LogBuffer clone is cheap (inner Arc)
+                let mut app = StkoptApp::with_session(cx, app_logger.clone(), session);
+                if let Some(dir) = qr_export_dir {
+                    app.qr_export_dir = dir;
+                }
+                app.qr_export_format = qr_export_format;
+                if let Some(dir) = journal_export_dir {
+                    app.journal_export_dir = dir;
+                }
+                app.journal_export_format = journal_export_format;
+                app
+            })
         },
     );
 
@@ -94,4 +151,73 @@ mod log_filter_tests {
     fn light_client_chatter_filter_directives_are_valid() {
         let _ = suppress_light_client_chatter(EnvFilter::new("debug"));
     }
+
+    #[test]
+    fn qr_export_args_parse() {
+        let args = Args::try_parse_from(["stkopt-desktop"]).unwrap();
+        assert!(args.qr_export_dir.is_none());
+        assert_eq!(args.qr_export_format, stkopt_chain::QrExportFormat::Auto);
+
+        let args = Args::try_parse_from([
+            "stkopt-desktop",
+            "--qr-export-dir",
+            "/tmp/qr",
+            "--qr-export-format",
+            "svg",
+        ])
+        .unwrap();
+        assert_eq!(
+            args.qr_export_dir,
+            Some(std::path::PathBuf::from("/tmp/qr"))
+        );
+        assert_eq!(args.qr_export_format, stkopt_chain::QrExportFormat::Svg);
+    }
+
+    #[test]
+    fn session_args_parse() {
+        let args = Args::try_parse_from(["stkopt-desktop"]).unwrap();
+        assert!(args.session().is_none());
+
+        let args = Args::try_parse_from(["stkopt-desktop", "--replay", "/tmp/s.json"]).unwrap();
+        assert_eq!(
+            args.session(),
+            Some(stkopt_chain::SessionMode::Replay("/tmp/s.json".into()))
+        );
+
+        let both = Args::try_parse_from([
+            "stkopt-desktop",
+            "--record",
+            "/tmp/a.json",
+            "--replay",
+            "/tmp/b.json",
+        ]);
+        assert!(both.is_err());
+    }
+
+    #[test]
+    fn journal_export_args_parse() {
+        let args = Args::try_parse_from(["stkopt-desktop"]).unwrap();
+        assert!(args.journal_export_dir.is_none());
+        assert_eq!(
+            args.journal_export_format,
+            stkopt_core::JournalExportFormat::Csv
+        );
+
+        let args = Args::try_parse_from([
+            "stkopt-desktop",
+            "--journal-export-dir",
+            "/tmp/journal",
+            "--journal-export-format",
+            "json",
+        ])
+        .unwrap();
+        assert_eq!(
+            args.journal_export_dir,
+            Some(std::path::PathBuf::from("/tmp/journal"))
+        );
+        assert_eq!(
+            args.journal_export_format,
+            stkopt_core::JournalExportFormat::Json
+        );
+    }
 }
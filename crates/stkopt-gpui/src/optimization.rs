@@ -1,7 +1,7 @@
 //! Optimization view adapter for validator selection.
 
 use crate::app::ValidatorInfo;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use stkopt_core::{
     MAX_NOMINATIONS, OptimizationCriteria as CoreOptimizationCriteria, OptimizationDataSource,
     SelectionStrategy as CoreSelectionStrategy, optimize_display_validators,
@@ -18,6 +18,8 @@ pub struct OptimizationCriteria {
     pub target_count: usize,
     /// Selection strategy.
     pub strategy: SelectionStrategy,
+    /// Addresses of validators with slash history, never selected.
+    pub slashed: HashSet<String>,
 }
 
 impl Default for OptimizationCriteria {
@@ -27,6 +29,7 @@ impl Default for OptimizationCriteria {
             exclude_blocked: true,
             target_count: MAX_NOMINATIONS,
             strategy: SelectionStrategy::TopApy,
+            slashed: HashSet::new(),
         }
     }
 }
@@ -115,6 +118,7 @@ pub fn optimize_selection(
         exclude_blocked: criteria.exclude_blocked,
         target_count: criteria.target_count,
         strategy: criteria.strategy.core_strategy(),
+        slashed: criteria.slashed.clone(),
     };
 
     let optimized = optimize_display_validators(validators, &core_criteria);
@@ -237,6 +241,7 @@ mod tests {
             exclude_blocked: true,
             target_count: 2,
             strategy: SelectionStrategy::TopApy,
+            slashed: HashSet::new(),
         };
 
         let result = optimize_selection(&validators, &criteria);
@@ -252,12 +257,27 @@ mod tests {
             exclude_blocked: true,
             target_count: 10,
             strategy: SelectionStrategy::TopApy,
+            slashed: HashSet::new(),
         };
 
         let result = optimize_selection(&validators, &criteria);
         assert!(!result.selected_indices.contains(&3));
     }
 
+    #[test]
+    fn test_optimize_excludes_slashed() {
+        let validators = sample_validators();
+        let criteria = OptimizationCriteria {
+            target_count: 2,
+            slashed: HashSet::from([validators[0].address.clone()]),
+            ..Default::default()
+        };
+
+        let result = optimize_selection(&validators, &criteria);
+        assert!(!result.selected_indices.is_empty());
+        assert!(!result.selected_indices.contains(&0));
+    }
+
     #[test]
     fn test_optimize_excludes_high_commission() {
         let validators = sample_validators();
@@ -266,6 +286,7 @@ mod tests {
             exclude_blocked: false,
             target_count: 10,
             strategy: SelectionStrategy::TopApy,
+            slashed: HashSet::new(),
         };
 
         let result = optimize_selection(&validators, &criteria);
@@ -290,6 +311,7 @@ mod tests {
             exclude_blocked: true,
             target_count: 3,
             strategy: SelectionStrategy::TopApy,
+            slashed: HashSet::new(),
         };
 
         let result = optimize_selection(&validators, &criteria);
@@ -313,6 +335,7 @@ mod tests {
             exclude_blocked: true,
             target_count: 2,
             strategy: SelectionStrategy::TopApy,
+            slashed: HashSet::new(),
         };
 
         let result = optimize_selection(&validators, &criteria);
@@ -382,6 +405,7 @@ mod tests {
             exclude_blocked: true,
             target_count: 2,
             strategy: SelectionStrategy::DiversifyByStake,
+            slashed: HashSet::new(),
         };
 
         let result = optimize_selection(&validators, &criteria);
@@ -44,7 +44,7 @@ mod tests {
             Network::Westend,
             Network::Paseo,
         ] {
-            let config = NetworkConfig::from(network);
+            let config = NetworkConfig::from(&network);
             assert_eq!(config.to_network(), Some(network));
         }
     }
@@ -53,6 +53,7 @@ mod tests {
     fn test_connection_mode_labels() {
         assert_eq!(ConnectionModeConfig::LightClient.label(), "Light Client");
         assert_eq!(ConnectionModeConfig::Rpc.label(), "RPC");
+        assert_eq!(ConnectionModeConfig::Hybrid.label(), "Hybrid");
     }
 
     #[test]
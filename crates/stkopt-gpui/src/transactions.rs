@@ -8,11 +8,29 @@
 // Re-export core transaction types
 pub use stkopt_core::{RewardDestination, TransactionStatus, TransactionType};
 
+use stkopt_core::StakingParams;
+
+/// Chain limits an amount is checked against by [`validate_tx_params`].
+pub struct BondLimits<'a> {
+    pub params: &'a StakingParams,
+    /// Current active bond: direct stake, or pool stake for pool operations.
+    pub bonded: u128,
+    /// Whether the account nominates; the nominator limits apply only then.
+    pub nominating: bool,
+    pub decimals: u8,
+    pub symbol: &'a str,
+}
+
 /// Validate transaction parameters.
+///
+/// With `limits`, amounts the chain would reject (below `MinNominatorBond`
+/// or `MinJoinBond`) are errors too, as is nominating from too small a bond
+/// or as a new nominator over the nominator cap.
 pub fn validate_tx_params(
     tx_type: &TransactionType,
     amount: Option<u128>,
     validators: Option<&[String]>,
+    limits: Option<&BondLimits>,
 ) -> Result<(), String> {
     match tx_type {
         TransactionType::Nominate => {
@@ -50,6 +68,17 @@ pub fn validate_tx_params(
             // These operations don't require amount or validators
         }
     }
+    if let Some(limits) = limits
+        && let Some(issue) = limits.params.check_bond(
+            *tx_type,
+            amount.unwrap_or(0),
+            limits.bonded,
+            limits.nominating,
+        )
+        && issue.is_error()
+    {
+        return Err(issue.message(limits.decimals, limits.symbol));
+    }
     Ok(())
 }
 
@@ -148,57 +177,171 @@ mod tests {
     #[test]
     fn test_validate_tx_params_nominate() {
         let validators = vec!["1abc".to_string()];
-        assert!(validate_tx_params(&TransactionType::Nominate, None, Some(&validators)).is_ok());
+        assert!(
+            validate_tx_params(&TransactionType::Nominate, None, Some(&validators), None).is_ok()
+        );
     }
 
     #[test]
     fn test_validate_tx_params_nominate_empty() {
         let validators: Vec<String> = vec![];
-        let result = validate_tx_params(&TransactionType::Nominate, None, Some(&validators));
+        let result = validate_tx_params(&TransactionType::Nominate, None, Some(&validators), None);
         assert!(result.is_err());
     }
 
     #[test]
     fn test_validate_tx_params_nominate_too_many() {
         let validators: Vec<String> = (0..20).map(|i| format!("1{}", i)).collect();
-        let result = validate_tx_params(&TransactionType::Nominate, None, Some(&validators));
+        let result = validate_tx_params(&TransactionType::Nominate, None, Some(&validators), None);
         assert!(result.is_err());
         assert!(result.unwrap_err().contains("Maximum 16"));
     }
 
     #[test]
     fn test_validate_tx_params_bond() {
-        assert!(validate_tx_params(&TransactionType::Bond, Some(1000), None).is_ok());
+        assert!(validate_tx_params(&TransactionType::Bond, Some(1000), None, None).is_ok());
     }
 
     #[test]
     fn test_validate_tx_params_bond_zero() {
-        let result = validate_tx_params(&TransactionType::Bond, Some(0), None);
+        let result = validate_tx_params(&TransactionType::Bond, Some(0), None, None);
         assert!(result.is_err());
     }
 
     #[test]
     fn test_validate_tx_params_bond_missing() {
-        let result = validate_tx_params(&TransactionType::Bond, None, None);
+        let result = validate_tx_params(&TransactionType::Bond, None, None, None);
         assert!(result.is_err());
     }
 
     #[test]
     fn test_validate_tx_params_chill() {
         // Chill doesn't require any params
-        assert!(validate_tx_params(&TransactionType::Chill, None, None).is_ok());
+        assert!(validate_tx_params(&TransactionType::Chill, None, None, None).is_ok());
     }
 
     #[test]
     fn test_validate_tx_params_withdraw_unbonded() {
         // WithdrawUnbonded doesn't require any params
-        assert!(validate_tx_params(&TransactionType::WithdrawUnbonded, None, None).is_ok());
+        assert!(validate_tx_params(&TransactionType::WithdrawUnbonded, None, None, None).is_ok());
     }
 
     #[test]
     fn test_validate_tx_params_pool_join() {
-        assert!(validate_tx_params(&TransactionType::PoolJoin, Some(1000), None).is_ok());
-        assert!(validate_tx_params(&TransactionType::PoolJoin, Some(0), None).is_err());
-        assert!(validate_tx_params(&TransactionType::PoolJoin, None, None).is_err());
+        assert!(validate_tx_params(&TransactionType::PoolJoin, Some(1000), None, None).is_ok());
+        assert!(validate_tx_params(&TransactionType::PoolJoin, Some(0), None, None).is_err());
+        assert!(validate_tx_params(&TransactionType::PoolJoin, None, None, None).is_err());
+    }
+
+    fn limits(params: &StakingParams, bonded: u128) -> BondLimits<'_> {
+        BondLimits {
+            params,
+            bonded,
+            nominating: true,
+            decimals: 10,
+            symbol: "DOT",
+        }
+    }
+
+    #[test]
+    fn test_validate_tx_params_bond_limits() {
+        let params = StakingParams {
+            min_nominator_bond: 2_500_000_000_000,
+            min_active_stake: 5_000_000_000_000,
+            ..Default::default()
+        };
+        let result = validate_tx_params(
+            &TransactionType::Bond,
+            Some(10_000_000_000),
+            None,
+            Some(&limits(&params, 0)),
+        );
+        assert!(result.unwrap_err().contains("minimum nominator bond"));
+        // Below MinimumActiveStake is only a warning
+        assert!(
+            validate_tx_params(
+                &TransactionType::Bond,
+                Some(3_000_000_000_000),
+                None,
+                Some(&limits(&params, 0)),
+            )
+            .is_ok()
+        );
+        // Bond extra counts the existing bond
+        assert!(
+            validate_tx_params(
+                &TransactionType::BondExtra,
+                Some(10_000_000_000),
+                None,
+                Some(&limits(&params, 2_500_000_000_000)),
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_validate_tx_params_nominate_limits() {
+        let params = StakingParams {
+            min_nominator_bond: 2_500_000_000_000,
+            max_nominators: Some(10),
+            nominator_count: 10,
+            ..Default::default()
+        };
+        let validators = vec!["validator1".to_string()];
+        let mut limits = limits(&params, 3_000_000_000_000);
+        // Existing nominators may change their targets at the cap
+        assert!(
+            validate_tx_params(
+                &TransactionType::Nominate,
+                None,
+                Some(&validators),
+                Some(&limits),
+            )
+            .is_ok()
+        );
+        limits.nominating = false;
+        let result = validate_tx_params(
+            &TransactionType::Nominate,
+            None,
+            Some(&validators),
+            Some(&limits),
+        );
+        assert!(result.unwrap_err().contains("nominator limit"));
+        limits.nominating = true;
+        limits.bonded = 10_000_000_000;
+        let result = validate_tx_params(
+            &TransactionType::Nominate,
+            None,
+            Some(&validators),
+            Some(&limits),
+        );
+        assert!(result.unwrap_err().contains("minimum nominator bond"));
+    }
+
+    #[test]
+    fn test_validate_tx_params_unbond_remainder() {
+        let params = StakingParams {
+            min_nominator_bond: 2_500_000_000_000,
+            ..Default::default()
+        };
+        let limits = limits(&params, 3_000_000_000_000);
+        assert!(
+            validate_tx_params(
+                &TransactionType::Unbond,
+                Some(1_000_000_000_000),
+                None,
+                Some(&limits)
+            )
+            .is_err()
+        );
+        assert!(
+            validate_tx_params(
+                &TransactionType::Unbond,
+                Some(3_000_000_000_000),
+                None,
+                Some(&limits)
+            )
+            .is_ok()
+        );
     }
 }
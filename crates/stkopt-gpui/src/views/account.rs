@@ -7,9 +7,12 @@ use gpui_ui_kit::*;
 use tokio::sync::mpsc;
 
 use crate::account::{ValidationResult, validate_address};
-use crate::app::{NetworkExt, StkoptApp};
+use crate::app::{NetworkExt, PoolOperation, StakingOperation, StkoptApp};
 use crate::chain::ChainUpdate;
 use crate::gpui_tokio::Tokio;
+use crate::views::QrModal;
+use stkopt_chain::UnbondingSource;
+use stkopt_core::{PoolAdminAction, Severity, format_perbill};
 
 pub struct AccountSection;
 
@@ -22,6 +25,15 @@ impl AccountSection {
             .child(Heading::h1("Account"))
             .child(Self::render_account_input(app, cx))
             .child(Self::render_account_details(app, cx))
+            .child(Self::render_balance_breakdown(app, cx))
+            .child(Self::render_nominator_exposure(app, cx))
+            .child(Self::render_unbonding_schedule(app, cx))
+            .child(Self::render_pool_membership(app, cx))
+            .child(Self::render_pool_admin(app, cx))
+            .child(Self::render_health_check(app, cx))
+            .child(Self::render_slash_risk(app, cx))
+            .child(Self::render_bags_position(app, cx))
+            .child(Self::render_portfolio(app, cx))
             .child(Self::render_address_book(app, cx))
     }
 
@@ -73,6 +85,19 @@ impl AccountSection {
                                 }
                             }),
                     )
+                    .child(
+                        Button::new("btn-scan-vault", "Scan from Vault")
+                            .variant(ButtonVariant::Secondary)
+                            .disabled(app.account_scan_active)
+                            .on_click({
+                                let entity = entity.clone();
+                                move |_window, cx| {
+                                    entity.update(cx, |this, cx| {
+                                        this.start_account_scan(cx);
+                                    });
+                                }
+                            }),
+                    )
                     .child(
                         Button::new("btn-watch", "Watch")
                             .variant(ButtonVariant::Primary)
@@ -108,18 +133,80 @@ impl AccountSection {
                     ),
             );
 
-        // Show error message if present
+        // Show error message if present, else warn about another network's prefix
         if let Some(ref error) = app.account_error {
             content = content.child(
                 Text::new(error.clone())
                     .size(TextSize::Xs)
                     .color(theme.error),
             );
+        } else if let Some(warning) =
+            crate::account::ss58_prefix_warning(app.account_input.trim(), app.network.clone())
+        {
+            content = content.child(Text::new(warning).size(TextSize::Xs).color(theme.warning));
+        }
+
+        if app.account_scan_active {
+            content = content.child(Self::render_account_scan(app, cx));
+        } else if let Some(ref message) = app.account_scan_message {
+            content = content.child(
+                Text::new(message.clone())
+                    .size(TextSize::Xs)
+                    .color(theme.text_secondary),
+            );
         }
 
         Card::new().content(content)
     }
 
+    fn render_account_scan(app: &StkoptApp, cx: &Context<StkoptApp>) -> impl IntoElement {
+        let theme = cx.theme();
+        let entity = app.entity.clone();
+
+        let preview = match app.camera_preview {
+            Some(ref preview) => QrModal::render_camera_preview(preview, &theme),
+            None => div()
+                .w(px(320.0))
+                .h(px(240.0))
+                .bg(theme.muted)
+                .rounded_lg()
+                .flex()
+                .items_center()
+                .justify_center()
+                .child(
+                    Text::new("Starting camera...")
+                        .size(TextSize::Sm)
+                        .color(theme.text_secondary),
+                ),
+        };
+
+        div()
+            .flex()
+            .flex_col()
+            .gap_2()
+            .child(
+                Text::new(format!(
+                    "Show the account's export QR in Polkadot Vault ({})",
+                    app.network.label()
+                ))
+                .size(TextSize::Xs)
+                .color(theme.text_secondary),
+            )
+            .child(preview)
+            .child(
+                div().child(
+                    Button::new("btn-cancel-vault-scan", "Cancel")
+                        .variant(ButtonVariant::Secondary)
+                        .size(ButtonSize::Xs)
+                        .on_click(move |_window, cx| {
+                            entity.update(cx, |this, cx| {
+                                this.stop_account_scan(cx);
+                            });
+                        }),
+                ),
+            )
+    }
+
     fn render_account_details(app: &StkoptApp, cx: &Context<StkoptApp>) -> AnyElement {
         let theme = cx.theme();
 
@@ -163,6 +250,829 @@ impl AccountSection {
         }
     }
 
+    fn render_balance_breakdown(app: &StkoptApp, cx: &Context<StkoptApp>) -> AnyElement {
+        let theme = cx.theme();
+        let Some(ref breakdown) = app.balance_breakdown else {
+            return div().into_any_element();
+        };
+        let symbol = app.token_symbol();
+        let decimals = app.token_decimals();
+        let amount = |value| format_balance(value, symbol, decimals);
+
+        let mut rows = div()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .child(detail_row("Free", amount(breakdown.balance.free), &theme))
+            .child(detail_row("Held", amount(breakdown.held()), &theme))
+            .child(detail_row(
+                "Frozen",
+                amount(breakdown.balance.frozen),
+                &theme,
+            ))
+            .child(detail_row(
+                "Transferable",
+                amount(breakdown.transferable()),
+                &theme,
+            ))
+            .child(detail_row("Bondable", amount(breakdown.bondable()), &theme));
+
+        let restrictions = breakdown.restrictions();
+        if !restrictions.is_empty() || !breakdown.vesting.is_empty() {
+            rows = rows.child(
+                div().pt_2().child(
+                    Text::new(
+                        "Locks, freezes and holds overlap; the largest sets the frozen amount",
+                    )
+                    .size(TextSize::Xs)
+                    .color(theme.text_secondary),
+                ),
+            );
+        }
+        for (kind, restriction) in restrictions {
+            rows = rows.child(detail_row(
+                kind,
+                format!("{} ({})", amount(restriction.amount), restriction.reason),
+                &theme,
+            ));
+        }
+        for schedule in &breakdown.vesting {
+            rows = rows.child(detail_row(
+                "Vesting",
+                format!(
+                    "{} from block {}, {} per block",
+                    amount(schedule.locked),
+                    schedule.starting_block,
+                    amount(schedule.per_block)
+                ),
+                &theme,
+            ));
+        }
+
+        Card::new()
+            .content(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_3()
+                    .child(Heading::h3("Balance Breakdown"))
+                    .child(rows),
+            )
+            .into_any_element()
+    }
+
+    fn render_nominator_exposure(app: &StkoptApp, cx: &Context<StkoptApp>) -> AnyElement {
+        let theme = cx.theme();
+        let Some(ref exposure) = app.nominator_exposure else {
+            return div().into_any_element();
+        };
+        let symbol = app.token_symbol();
+        let decimals = app.token_decimals();
+
+        let status = if exposure.is_active() {
+            format!(
+                "Active in era {}: {} exposed",
+                exposure.era,
+                format_balance(exposure.active_stake(), symbol, decimals)
+            )
+        } else {
+            format!("Inactive in era {}", exposure.era)
+        };
+        let mut rows = div().flex().flex_col().gap_1().child(
+            Text::new(status)
+                .size(TextSize::Sm)
+                .weight(TextWeight::Medium)
+                .color(if exposure.is_active() {
+                    theme.success
+                } else {
+                    theme.warning
+                }),
+        );
+
+        for backing in &exposure.backing {
+            let address = stkopt_chain::encode_ss58(&backing.validator, app.network.ss58_format())
+                .unwrap_or_else(|_| backing.validator.to_string());
+            let name = app
+                .validators
+                .iter()
+                .find(|v| v.address == address)
+                .and_then(|v| v.name.clone())
+                .unwrap_or_else(|| {
+                    format!("{}...{}", &address[..6], &address[address.len() - 6..])
+                });
+            rows = rows.child(
+                div()
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .py_1()
+                    .border_b_1()
+                    .border_color(theme.border)
+                    .child(
+                        Text::new(name)
+                            .size(TextSize::Xs)
+                            .color(theme.text_secondary),
+                    )
+                    .child(
+                        Text::new(format!(
+                            "{} (page {}/{})",
+                            format_balance(backing.value, symbol, decimals),
+                            backing.page + 1,
+                            backing.page_count
+                        ))
+                        .size(TextSize::Xs)
+                        .weight(TextWeight::Medium),
+                    ),
+            );
+        }
+        if !exposure.inactive.is_empty() {
+            rows = rows.child(detail_row(
+                "Not elected",
+                format!("{} validator(s)", exposure.inactive.len()),
+                &theme,
+            ));
+        }
+        for warning in exposure.warnings(true) {
+            rows = rows.child(Text::new(warning).size(TextSize::Xs).color(theme.warning));
+        }
+
+        Card::new()
+            .content(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_3()
+                    .child(Heading::h3("Active Nominations"))
+                    .child(rows),
+            )
+            .into_any_element()
+    }
+
+    fn render_unbonding_schedule(app: &StkoptApp, cx: &Context<StkoptApp>) -> AnyElement {
+        let theme = cx.theme();
+        if app.unbonding_schedule.is_empty() {
+            return div().into_any_element();
+        }
+        let entity = app.entity.clone();
+        let symbol = app.token_symbol();
+        let decimals = app.token_decimals();
+
+        let mut rows = div().flex().flex_col().gap_1();
+        for (i, entry) in app.unbonding_schedule.iter().enumerate() {
+            let source = match entry.source {
+                UnbondingSource::Direct => "Staked".to_string(),
+                UnbondingSource::Pool(id) => format!("Pool #{}", id),
+            };
+            let when = if entry.is_withdrawable() {
+                Text::new("Withdrawable now")
+                    .size(TextSize::Xs)
+                    .weight(TextWeight::Medium)
+                    .color(theme.success)
+            } else {
+                Text::new(format!(
+                    "{} · era {} (in {} eras)",
+                    entry.display_date(),
+                    entry.era,
+                    entry.remaining_eras
+                ))
+                .size(TextSize::Xs)
+                .color(theme.text_secondary)
+            };
+            let mut row = div()
+                .flex()
+                .items_center()
+                .gap_3()
+                .py_1()
+                .border_b_1()
+                .border_color(theme.border)
+                .child(
+                    div().w(px(80.0)).child(
+                        Text::new(source)
+                            .size(TextSize::Xs)
+                            .color(theme.text_secondary),
+                    ),
+                )
+                .child(
+                    div().w(px(140.0)).child(
+                        Text::new(format_balance(entry.value, symbol, decimals))
+                            .size(TextSize::Xs)
+                            .weight(TextWeight::Medium),
+                    ),
+                )
+                .child(div().flex_1().child(when));
+            if entry.is_withdrawable() {
+                let entity = entity.clone();
+                let source = entry.source;
+                row = row.child(
+                    Button::new(SharedString::from(format!("withdraw-{}", i)), "Withdraw")
+                        .variant(ButtonVariant::Primary)
+                        .size(ButtonSize::Xs)
+                        .disabled(!app.commands_available())
+                        .on_click(move |_window, cx| {
+                            entity.update(cx, |this, cx| {
+                                this.withdraw_unbonded(source, cx);
+                            });
+                        }),
+                );
+            }
+            rows = rows.child(row);
+        }
+
+        Card::new()
+            .content(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_3()
+                    .child(Heading::h3("Unbonding Schedule"))
+                    .child(rows),
+            )
+            .into_any_element()
+    }
+
+    fn render_pool_membership(app: &StkoptApp, cx: &Context<StkoptApp>) -> AnyElement {
+        let theme = cx.theme();
+        let Some(details) = &app.pool_details else {
+            return div().into_any_element();
+        };
+        let entity = app.entity.clone();
+        let symbol = app.token_symbol();
+        let decimals = app.token_decimals();
+        let pool_name = app
+            .pools
+            .iter()
+            .find(|p| p.id == details.pool_id)
+            .map_or_else(
+                || format!("Pool #{}", details.pool_id),
+                |p| format!("#{} {}", p.id, p.name),
+            );
+
+        let mut rows = div()
+            .flex()
+            .flex_col()
+            .gap_2()
+            .child(detail_row("Pool", pool_name, &theme))
+            .child(detail_row(
+                "Active stake",
+                format_balance(details.balance, symbol, decimals),
+                &theme,
+            ))
+            .child(detail_row(
+                "Pending rewards",
+                format_balance(details.pending_rewards, symbol, decimals),
+                &theme,
+            ));
+        for unbonding in &details.unbonding {
+            rows = rows.child(detail_row(
+                "Unbonding",
+                format!(
+                    "{} · era {}",
+                    format_balance(unbonding.balance, symbol, decimals),
+                    unbonding.era
+                ),
+                &theme,
+            ));
+        }
+        rows = rows.child(
+            div()
+                .flex()
+                .items_center()
+                .gap_2()
+                .child(div().flex_1().child(detail_row(
+                    "Claim permission",
+                    details.claim_permission.label().to_string(),
+                    &theme,
+                )))
+                .child(
+                    Button::new("btn-claim-permission", "Change")
+                        .variant(ButtonVariant::Secondary)
+                        .size(ButtonSize::Xs)
+                        .disabled(!app.commands_available())
+                        .on_click(move |_window, cx| {
+                            entity.update(cx, |this, cx| {
+                                this.open_pool_modal(PoolOperation::SetClaimPermission, None, cx);
+                            });
+                        }),
+                ),
+        );
+
+        Card::new()
+            .content(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_3()
+                    .child(Heading::h3("Pool Membership"))
+                    .child(rows),
+            )
+            .into_any_element()
+    }
+
+    fn render_pool_admin(app: &StkoptApp, cx: &Context<StkoptApp>) -> AnyElement {
+        let theme = cx.theme();
+        let can_create = app.can_create_pool();
+        if app.pool_admin.is_empty() && !can_create {
+            return div().into_any_element();
+        }
+        let symbol = app.token_symbol();
+        let decimals = app.token_decimals();
+
+        let mut rows = div().flex().flex_col().gap_3();
+        for pool in &app.pool_admin {
+            let pool_id = pool.pool_id;
+            let roles = pool
+                .roles
+                .iter()
+                .map(|r| r.label())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let commission = match (pool.commission.current, pool.commission.max) {
+                (Some(current), Some(max)) => {
+                    format!("{} (max {})", format_perbill(current), format_perbill(max))
+                }
+                (Some(current), None) => format_perbill(current),
+                (None, Some(max)) => format!("none (max {})", format_perbill(max)),
+                (None, None) => "none".to_string(),
+            };
+
+            let mut actions = div().flex().flex_wrap().gap_1();
+            for action in pool.actions() {
+                let entity = app.entity.clone();
+                actions = actions.child(
+                    Button::new(
+                        SharedString::from(format!("btn-pool-admin-{}-{:?}", pool_id, action)),
+                        action.label(),
+                    )
+                    .variant(ButtonVariant::Secondary)
+                    .size(ButtonSize::Xs)
+                    .disabled(!app.commands_available())
+                    .on_click(move |_window, cx| {
+                        entity.update(cx, |this, cx| {
+                            this.open_pool_modal(PoolOperation::Admin(action), Some(pool_id), cx);
+                        });
+                    }),
+                );
+            }
+
+            rows = rows.child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_2()
+                    .pb_2()
+                    .border_b_1()
+                    .border_color(theme.border)
+                    .child(detail_row(
+                        "Pool",
+                        format!(
+                            "#{} {}",
+                            pool_id,
+                            pool.name.as_deref().unwrap_or("Unnamed pool")
+                        ),
+                        &theme,
+                    ))
+                    .child(detail_row("State", format!("{:?}", pool.state), &theme))
+                    .child(detail_row("Your roles", roles, &theme))
+                    .child(detail_row("Commission", commission, &theme))
+                    .child(detail_row(
+                        "Pending commission",
+                        format_balance(pool.commission.pending, symbol, decimals),
+                        &theme,
+                    ))
+                    .child(actions),
+            );
+        }
+
+        if can_create {
+            let entity = app.entity.clone();
+            let action = PoolAdminAction::Create;
+            rows = rows.child(
+                div().flex().child(
+                    Button::new("btn-pool-admin-create", action.label())
+                        .variant(ButtonVariant::Secondary)
+                        .size(ButtonSize::Xs)
+                        .disabled(!app.commands_available())
+                        .on_click(move |_window, cx| {
+                            entity.update(cx, |this, cx| {
+                                this.open_pool_modal(PoolOperation::Admin(action), None, cx);
+                            });
+                        }),
+                ),
+            );
+        }
+
+        Card::new()
+            .content(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_3()
+                    .child(Heading::h3("Pool Administration"))
+                    .child(rows),
+            )
+            .into_any_element()
+    }
+
+    fn render_health_check(app: &StkoptApp, cx: &Context<StkoptApp>) -> AnyElement {
+        let theme = cx.theme();
+        let Some(report) = app.health_report() else {
+            return div().into_any_element();
+        };
+        let symbol = app.token_symbol();
+        let decimals = app.token_decimals();
+
+        let mut rows = div().flex().flex_col().gap_2();
+        if report.is_healthy() {
+            rows = rows.child(
+                Text::new("No problems found")
+                    .size(TextSize::Sm)
+                    .color(theme.success),
+            );
+        }
+        for issue in &report.issues {
+            let color = match issue.severity() {
+                Severity::Critical => theme.error,
+                Severity::Warning => theme.warning,
+                Severity::Info => theme.text_secondary,
+            };
+            rows = rows.child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_1()
+                    .py_1()
+                    .border_b_1()
+                    .border_color(theme.border)
+                    .child(
+                        div()
+                            .flex()
+                            .gap_2()
+                            .child(
+                                Text::new(issue.severity().label())
+                                    .size(TextSize::Xs)
+                                    .weight(TextWeight::Semibold)
+                                    .color(color),
+                            )
+                            .child(Text::new(issue.message(decimals, symbol)).size(TextSize::Xs)),
+                    )
+                    .child(
+                        Text::new(issue.fix())
+                            .size(TextSize::Xs)
+                            .color(theme.text_secondary),
+                    ),
+            );
+        }
+
+        Card::new()
+            .content(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_3()
+                    .child(Heading::h3("Health Check"))
+                    .child(rows),
+            )
+            .into_any_element()
+    }
+
+    fn render_slash_risk(app: &StkoptApp, cx: &Context<StkoptApp>) -> AnyElement {
+        let theme = cx.theme();
+        let Some(risk) = app.slash_risk() else {
+            return div().into_any_element();
+        };
+        let symbol = app.token_symbol();
+        let decimals = app.token_decimals();
+        let amount = |value| format_balance(value, symbol, decimals);
+
+        let mut rows = div()
+            .flex()
+            .flex_col()
+            .gap_2()
+            .child(detail_row(
+                "Pending slashes",
+                amount(risk.total_pending()),
+                &theme,
+            ))
+            .child(detail_row(
+                "Already slashed",
+                format!(
+                    "{} in {} era(s)",
+                    amount(risk.total_past()),
+                    risk.past_slashes.len()
+                ),
+                &theme,
+            ))
+            .child(detail_row(
+                "Estimated loss",
+                amount(risk.total_estimated_loss()),
+                &theme,
+            ));
+        if risk.flagged().next().is_none() {
+            rows = rows.child(
+                Text::new("No slashes or offences against nominated validators")
+                    .size(TextSize::Sm)
+                    .color(theme.success),
+            );
+        }
+        for target in risk.flagged() {
+            let name = app
+                .validators
+                .iter()
+                .find(|v| v.address == target.validator)
+                .and_then(|v| v.name.clone())
+                .unwrap_or_else(|| {
+                    let address = &target.validator;
+                    format!("{}...{}", &address[..6], &address[address.len() - 6..])
+                });
+            let mut details = Vec::new();
+            if target.pending > 0 {
+                details.push(format!("{} pending", amount(target.pending)));
+            }
+            if let Some(worst) = target.slashes.iter().max_by_key(|s| s.fraction) {
+                details.push(format!(
+                    "{} slash(es), worst {:.2}% in era {}",
+                    target.slashes.len(),
+                    worst.fraction as f64 / 10_000_000.0,
+                    worst.era
+                ));
+            }
+            if target.offences > 0 {
+                details.push(format!("{} offence report(s)", target.offences));
+            }
+            rows = rows.child(
+                div()
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .py_1()
+                    .border_b_1()
+                    .border_color(theme.border)
+                    .child(
+                        Text::new(name)
+                            .size(TextSize::Xs)
+                            .color(if target.pending > 0 {
+                                theme.error
+                            } else {
+                                theme.warning
+                            }),
+                    )
+                    .child(Text::new(details.join(", ")).size(TextSize::Xs)),
+            );
+        }
+
+        Card::new()
+            .content(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_3()
+                    .child(Heading::h3("Slash Risk"))
+                    .child(rows),
+            )
+            .into_any_element()
+    }
+
+    fn render_bags_position(app: &StkoptApp, cx: &Context<StkoptApp>) -> AnyElement {
+        let theme = cx.theme();
+        let Some(position) = &app.bags_position else {
+            return div().into_any_element();
+        };
+        let entity = app.entity.clone();
+        let commands_available = app.commands_available();
+        let bag = |upper: u64| {
+            if upper == u64::MAX {
+                "Top bag".to_string()
+            } else {
+                format!("≤ {}", upper)
+            }
+        };
+
+        let mut rows = div()
+            .flex()
+            .flex_col()
+            .gap_2()
+            .child(detail_row("Bag", bag(position.bag_upper), &theme))
+            .child(detail_row(
+                "Expected bag",
+                bag(position.expected_bag),
+                &theme,
+            ))
+            .child(detail_row(
+                "Position in bag",
+                position.rank.map_or_else(
+                    || format!("Beyond {}", stkopt_chain::MAX_BAG_WALK),
+                    |rank| rank.to_string(),
+                ),
+                &theme,
+            ));
+        if !position.needs_rebag() && !position.can_move_up() {
+            rows = rows.child(
+                Text::new("In the bag matching your stake")
+                    .size(TextSize::Sm)
+                    .color(theme.success),
+            );
+        }
+
+        let mut actions = div().flex().gap_2();
+        if position.needs_rebag() {
+            actions = actions.child(
+                Button::new("btn-rebag", "Rebag")
+                    .variant(ButtonVariant::Primary)
+                    .size(ButtonSize::Xs)
+                    .disabled(!commands_available)
+                    .on_click({
+                        let entity = entity.clone();
+                        move |_window, cx| {
+                            entity.update(cx, |this, cx| {
+                                this.open_staking_modal(StakingOperation::Rebag, cx);
+                            });
+                        }
+                    }),
+            );
+        }
+        if position.can_move_up() {
+            actions = actions.child(
+                Button::new("btn-put-in-front", "Move Ahead of Lighter Voter")
+                    .variant(ButtonVariant::Secondary)
+                    .size(ButtonSize::Xs)
+                    .disabled(!commands_available)
+                    .on_click(move |_window, cx| {
+                        entity.update(cx, |this, cx| {
+                            this.open_staking_modal(StakingOperation::PutInFrontOf, cx);
+                        });
+                    }),
+            );
+        }
+
+        Card::new()
+            .content(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_3()
+                    .child(Heading::h3("Voter List"))
+                    .child(rows)
+                    .child(actions),
+            )
+            .into_any_element()
+    }
+
+    fn render_portfolio(app: &StkoptApp, cx: &Context<StkoptApp>) -> AnyElement {
+        let theme = cx.theme();
+        if !app.address_book.iter().any(|a| a.network == app.network) {
+            return div().into_any_element();
+        }
+        let entity = app.entity.clone();
+        let symbol = app.token_symbol();
+        let decimals = app.token_decimals();
+        let format_apy = |apy: Option<f64>| {
+            apy.map(|apy| format!("{:.2}%", apy * 100.0))
+                .unwrap_or_else(|| "-".to_string())
+        };
+
+        let header = div()
+            .flex()
+            .items_center()
+            .justify_between()
+            .child(Heading::h3("Portfolio"))
+            .child({
+                let entity = entity.clone();
+                let label = if app.portfolio.is_empty() {
+                    "Load"
+                } else {
+                    "Refresh"
+                };
+                Button::new("btn-load-portfolio", label)
+                    .variant(ButtonVariant::Secondary)
+                    .size(ButtonSize::Xs)
+                    .disabled(app.portfolio_loading || !app.data_download_complete())
+                    .on_click(move |_window, cx| {
+                        entity.update(cx, |this, cx| {
+                            this.fetch_portfolio(cx);
+                        });
+                    })
+            });
+
+        let mut content = div().flex().flex_col().gap_3().child(header);
+
+        if app.portfolio_loading {
+            content = content.child(
+                Text::new("Loading saved accounts...")
+                    .size(TextSize::Xs)
+                    .color(theme.text_secondary),
+            );
+        } else if let Some(ref error) = app.portfolio_error {
+            content = content.child(
+                Text::new(format!("Failed to load portfolio: {}", error))
+                    .size(TextSize::Xs)
+                    .color(theme.error),
+            );
+        }
+
+        if app.portfolio.is_empty() {
+            return Card::new().content(content).into_any_element();
+        }
+
+        let summary = app.portfolio_summary();
+        let stat = |label: &'static str, value: String| {
+            div()
+                .flex()
+                .flex_col()
+                .child(
+                    Text::new(label)
+                        .size(TextSize::Xs)
+                        .color(theme.text_secondary),
+                )
+                .child(
+                    Text::new(value)
+                        .size(TextSize::Sm)
+                        .weight(TextWeight::Semibold),
+                )
+        };
+        content = content.child(
+            div()
+                .flex()
+                .gap_6()
+                .child(stat(
+                    "Total bonded",
+                    format_balance(summary.total_bonded, symbol, decimals),
+                ))
+                .child(stat(
+                    "Unbonding",
+                    format_balance(summary.total_unbonding, symbol, decimals),
+                ))
+                .child(stat(
+                    "Pending rewards",
+                    format_balance(summary.total_pending_rewards, symbol, decimals),
+                ))
+                .child(stat("Weighted APY", format_apy(summary.weighted_apy))),
+        );
+
+        let mut rows = div().flex().flex_col();
+        for (i, account) in app.portfolio.iter().enumerate() {
+            let name = match account.label {
+                Some(ref label) => format!("{} ({})", label, truncate_address(&account.address)),
+                None => truncate_address(&account.address),
+            };
+            let pool = account
+                .pool
+                .as_ref()
+                .map(|p| format!("Pool #{}", p.pool_id))
+                .unwrap_or_default();
+            let address = account.address.clone();
+            let entity = entity.clone();
+            rows = rows.child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_3()
+                    .py_1()
+                    .border_b_1()
+                    .border_color(theme.border)
+                    .child(div().flex_1().child(Text::new(name).size(TextSize::Xs)))
+                    .child(
+                        div().w(px(140.0)).child(
+                            Text::new(format_balance(account.staked(), symbol, decimals))
+                                .size(TextSize::Xs)
+                                .weight(TextWeight::Medium),
+                        ),
+                    )
+                    .child(
+                        div().w(px(80.0)).child(
+                            Text::new(pool)
+                                .size(TextSize::Xs)
+                                .color(theme.text_secondary),
+                        ),
+                    )
+                    .child(
+                        div().w(px(60.0)).child(
+                            Text::new(format_apy(account.apy(&app.validators, &app.pools)))
+                                .size(TextSize::Xs)
+                                .color(theme.success),
+                        ),
+                    )
+                    .child(
+                        Button::new(SharedString::from(format!("portfolio-open-{}", i)), "Open")
+                            .variant(ButtonVariant::Secondary)
+                            .size(ButtonSize::Xs)
+                            .disabled(!app.data_download_complete())
+                            .on_click(move |_window, cx| {
+                                let address = address.clone();
+                                entity.update(cx, |this, cx| {
+                                    this.set_watched_account(address, cx);
+                                    this.save_config();
+                                    this.fetch_watched_account(cx);
+                                    this.current_section = crate::app::Section::Dashboard;
+                                    cx.notify();
+                                });
+                            }),
+                    ),
+            );
+        }
+
+        Card::new().content(content.child(rows)).into_any_element()
+    }
+
     fn render_address_book(app: &StkoptApp, cx: &Context<StkoptApp>) -> AnyElement {
         let theme = cx.theme();
         let entity = app.entity.clone();
@@ -221,6 +1131,10 @@ impl AccountSection {
                 theme.surface
             };
             let address = entry.address.clone();
+            let display = match entry.label {
+                Some(ref label) => format!("{} ({})", label, truncate_address(&address)),
+                None => truncate_address(&address),
+            };
             let is_active = app.watched_account.as_ref() == Some(&address);
 
             list = list.child(
@@ -238,15 +1152,11 @@ impl AccountSection {
                             .flex()
                             .items_center()
                             .gap_1()
-                            .child(
-                                Text::new(truncate_address(&address))
-                                    .size(TextSize::Xs)
-                                    .weight(if is_active {
-                                        TextWeight::Semibold
-                                    } else {
-                                        TextWeight::Normal
-                                    }),
-                            )
+                            .child(Text::new(display).size(TextSize::Xs).weight(if is_active {
+                                TextWeight::Semibold
+                            } else {
+                                TextWeight::Normal
+                            }))
                             .when(is_active, |el| {
                                 el.child(
                                     Badge::new("watching")
@@ -257,7 +1167,7 @@ impl AccountSection {
                     )
                     .child(
                         div().w(px(100.0)).child(
-                            Text::new(entry.network.token_symbol())
+                            Text::new(entry.network.token_symbol().to_string())
                                 .size(TextSize::Xs)
                                 .color(theme.text_secondary),
                         ),
@@ -340,6 +1250,14 @@ fn detail_row(
         )
 }
 
+fn format_balance(amount: u128, symbol: &str, decimals: u8) -> String {
+    let divisor = 10u128.pow(decimals as u32);
+    let whole = amount / divisor;
+    let frac_divisor = 10u128.pow(decimals.saturating_sub(4) as u32);
+    let frac = (amount % divisor) / frac_divisor;
+    format!("{}.{:04} {}", whole, frac, symbol)
+}
+
 fn truncate_address(address: &str) -> String {
     if address.len() > 16 {
         format!("{}...{}", &address[..8], &address[address.len() - 8..])
@@ -54,6 +54,7 @@ impl DashboardSection {
                     .child(stat_card("Unbonding", unbonding, "⏳", &theme))
                     .child(stat_card("Pending Rewards", rewards, "🎁", &theme)),
             )
+            .child(Self::render_network_info(app, cx))
             .child(
                 div()
                     .flex()
@@ -160,6 +161,67 @@ impl DashboardSection {
             )
     }
 
+    /// Staking limits of the connected network.
+    fn render_network_info(app: &StkoptApp, cx: &Context<StkoptApp>) -> AnyElement {
+        let theme = cx.theme();
+        let Some(ref params) = app.staking_params else {
+            return div().into_any_element();
+        };
+        let symbol = app.token_symbol();
+        let decimals = app.token_decimals();
+        let amount = |value| format_balance(value, symbol, decimals);
+        let nominators = match params.max_nominators {
+            Some(max) => format!("{} / {}", params.nominator_count, max),
+            None => params.nominator_count.to_string(),
+        };
+
+        let mut rows = div()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .child(Heading::h2("Staking Limits"))
+            .child(info_row(
+                "Min nominator bond",
+                amount(params.min_nominator_bond),
+                &theme,
+            ))
+            .child(info_row(
+                "Min active stake",
+                amount(params.min_active_stake),
+                &theme,
+            ))
+            .child(info_row("Nominators", nominators, &theme))
+            .child(info_row(
+                "Exposure page size",
+                params.max_exposure_page_size.to_string(),
+                &theme,
+            ))
+            .child(info_row(
+                "Pool min join bond",
+                amount(params.min_join_bond),
+                &theme,
+            ))
+            .child(info_row(
+                "Pool min create bond",
+                amount(params.min_create_bond),
+                &theme,
+            ))
+            .child(info_row(
+                "Bonding duration",
+                format!("{} eras", params.bonding_duration),
+                &theme,
+            ));
+        if params.nominators_full() {
+            rows = rows.child(
+                Text::new("Nominator limit reached: new nominators are rejected")
+                    .size(TextSize::Xs)
+                    .color(theme.warning),
+            );
+        }
+
+        Card::new().content(rows).into_any_element()
+    }
+
     fn render_feedback(app: &StkoptApp, entity: Entity<StkoptApp>) -> AnyElement {
         if let Some(error) = app.connection_error.clone() {
             return Alert::new("dashboard-error", error)
@@ -206,6 +268,30 @@ fn stat_card(
     )
 }
 
+fn info_row(
+    label: &'static str,
+    value: String,
+    theme: &gpui_ui_kit::theme::Theme,
+) -> impl IntoElement {
+    div()
+        .flex()
+        .items_center()
+        .justify_between()
+        .py_1()
+        .border_b_1()
+        .border_color(theme.border)
+        .child(
+            Text::new(label)
+                .size(TextSize::Xs)
+                .color(theme.text_secondary),
+        )
+        .child(
+            Text::new(value)
+                .size(TextSize::Xs)
+                .weight(TextWeight::Medium),
+        )
+}
+
 fn format_balance(amount: u128, symbol: &str, decimals: u8) -> String {
     let divisor = 10u128.pow(decimals as u32);
     let whole = amount / divisor;
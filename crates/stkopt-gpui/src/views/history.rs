@@ -9,6 +9,8 @@ use gpui_ui_kit::theme::ThemeExt;
 use gpui_ui_kit::*;
 
 use crate::app::{HistoryPoint, StkoptApp};
+use crate::views::TransactionsCard;
+use stkopt_core::{PayoutStatus, compare_rewards, era_payout_status};
 
 pub struct HistorySection;
 
@@ -38,6 +40,52 @@ impl HistorySection {
         } else {
             (format!("-- {}", symbol), "--%".to_string(), "0".to_string())
         };
+        let comparisons = compare_rewards(&app.staking_history, &app.exact_history);
+        let exact_difference = (!comparisons.is_empty()).then(|| {
+            let diff: i128 = comparisons.iter().map(|c| c.difference()).sum();
+            format!(
+                "{} over {} eras",
+                format_reward_difference(diff, symbol, decimals),
+                comparisons.len()
+            )
+        });
+
+        let flagged_payouts = (!app.payout_checks.is_empty()).then(|| {
+            let flagged: Vec<_> = app
+                .payout_checks
+                .iter()
+                .filter(|c| c.is_flagged())
+                .collect();
+            let shortfall: u128 = flagged.iter().map(|c| c.shortfall()).sum();
+            format!(
+                "{} of {} flagged, {} short",
+                flagged.len(),
+                app.payout_checks.len(),
+                format_balance(shortfall, symbol, decimals)
+            )
+        });
+
+        let check_payouts_button = Button::new(
+            "btn-check-payouts",
+            if app.payouts_checking {
+                "Checking..."
+            } else {
+                "Check payouts"
+            },
+        )
+        .variant(ButtonVariant::Secondary)
+        .size(ButtonSize::Xs)
+        .disabled(
+            !has_account || is_loading || app.payouts_checking || app.staking_history.is_empty(),
+        )
+        .on_click({
+            let entity = entity.clone();
+            move |_window, cx| {
+                entity.update(cx, |this, cx| {
+                    this.check_payouts(cx);
+                });
+            }
+        });
 
         let refresh_button = Button::new(
             "btn-refresh-history",
@@ -65,7 +113,13 @@ impl HistorySection {
                     .items_center()
                     .justify_between()
                     .child(Heading::h1("Staking History"))
-                    .child(refresh_button),
+                    .child(
+                        div()
+                            .flex()
+                            .gap_2()
+                            .child(check_payouts_button)
+                            .child(refresh_button),
+                    ),
             )
             .child(
                 div()
@@ -73,7 +127,13 @@ impl HistorySection {
                     .gap_3()
                     .child(stat_card("Total Rewards", total_rewards, &theme))
                     .child(stat_card_success("Average APY", avg_apy, &theme))
-                    .child(stat_card("Eras Tracked", eras_count, &theme)),
+                    .child(stat_card("Eras Tracked", eras_count, &theme))
+                    .when_some(exact_difference, |row, diff| {
+                        row.child(stat_card("Exact vs Estimate", diff, &theme))
+                    })
+                    .when_some(flagged_payouts, |row, flagged| {
+                        row.child(stat_card("Payouts", flagged, &theme))
+                    }),
             )
             .child(if is_loading {
                 // Show loading indicator
@@ -99,6 +159,7 @@ impl HistorySection {
             } else {
                 Self::render_history_table(app, &theme)
             })
+            .child(TransactionsCard::render(app, cx))
     }
 
     fn render_apy_chart(app: &StkoptApp, theme: &gpui_ui_kit::theme::Theme) -> AnyElement {
@@ -109,11 +170,18 @@ impl HistorySection {
         let chart_points: Vec<ApyChartPoint> = app
             .staking_history
             .iter()
-            .map(ApyChartPoint::from)
+            .map(|point| {
+                let exact = app.exact_history.iter().find(|x| x.era == point.era);
+                ApyChartPoint {
+                    exact_apy_percent: exact.map(|x| ApyChartPoint::from(x).apy_percent),
+                    ..ApyChartPoint::from(point)
+                }
+            })
             .collect();
+        let has_exact = chart_points.iter().any(|p| p.exact_apy_percent.is_some());
         let max_apy = chart_points
             .iter()
-            .map(|point| point.apy_percent)
+            .flat_map(|point| std::iter::once(point.apy_percent).chain(point.exact_apy_percent))
             .fold(0.0_f64, f64::max);
         let y_max = nice_axis_max(max_apy);
         let y_ticks = y_axis_ticks(y_max);
@@ -133,7 +201,30 @@ impl HistorySection {
                     .flex()
                     .flex_col()
                     .gap_2()
-                    .child(Heading::h3("APY Trend"))
+                    .child(
+                        div()
+                            .flex()
+                            .items_center()
+                            .justify_between()
+                            .child(Heading::h3("APY Trend"))
+                            .when(has_exact, |row| {
+                                row.child(
+                                    div()
+                                        .flex()
+                                        .gap_3()
+                                        .child(
+                                            Text::new("● Estimated")
+                                                .size(TextSize::Xs)
+                                                .color(theme.success),
+                                        )
+                                        .child(
+                                            Text::new("● Exact")
+                                                .size(TextSize::Xs)
+                                                .color(theme.accent),
+                                        ),
+                                )
+                            }),
+                    )
                     .child(
                         div()
                             .w(px(chart_width))
@@ -224,7 +315,21 @@ impl HistorySection {
                 )
                 .child(
                     div().w(px(120.0)).child(
-                        Text::new("Rewards")
+                        Text::new("Estimated")
+                            .size(TextSize::Xs)
+                            .weight(TextWeight::Semibold),
+                    ),
+                )
+                .child(
+                    div().w(px(120.0)).child(
+                        Text::new("Exact")
+                            .size(TextSize::Xs)
+                            .weight(TextWeight::Semibold),
+                    ),
+                )
+                .child(
+                    div().w(px(100.0)).child(
+                        Text::new("Δ")
                             .size(TextSize::Xs)
                             .weight(TextWeight::Semibold),
                     ),
@@ -235,6 +340,13 @@ impl HistorySection {
                             .size(TextSize::Xs)
                             .weight(TextWeight::Semibold),
                     ),
+                )
+                .child(
+                    div().w(px(80.0)).child(
+                        Text::new("Payout")
+                            .size(TextSize::Xs)
+                            .weight(TextWeight::Semibold),
+                    ),
                 ),
         );
 
@@ -244,6 +356,32 @@ impl HistorySection {
         for (i, point) in app.staking_history.iter().rev().take(30).enumerate() {
             let staked_str = format_balance(point.bonded, symbol, decimals);
             let rewards_str = format_balance(point.reward, symbol, decimals);
+            let exact = app.exact_history.iter().find(|x| x.era == point.era);
+            let exact_str = exact
+                .map(|x| format_balance(x.reward, symbol, decimals))
+                .unwrap_or_else(|| "-".to_string());
+            let (diff_str, diff_color) = match exact {
+                Some(x) => {
+                    let diff = x.reward as i128 - point.reward as i128;
+                    (
+                        format_reward_difference(diff, symbol, decimals),
+                        if diff < 0 {
+                            theme.warning
+                        } else {
+                            theme.success
+                        },
+                    )
+                }
+                None => ("-".to_string(), theme.text_secondary),
+            };
+            let (payout_str, payout_color) = match era_payout_status(&app.payout_checks, point.era)
+            {
+                Some(PayoutStatus::Paid) => ("Paid", theme.success),
+                Some(PayoutStatus::Short) => ("Short", theme.warning),
+                Some(PayoutStatus::Unclaimed) => ("Unclaimed", theme.warning),
+                Some(PayoutStatus::Missing) => ("Missing", theme.error),
+                None => ("-", theme.text_secondary),
+            };
             let apy_str = format!("{:.2}%", point.apy * 100.0);
             let date_str = point.date.clone().unwrap_or_else(|| "-".to_string());
             let row_bg = if i % 2 == 0 {
@@ -292,10 +430,25 @@ impl HistorySection {
                                 .color(theme.success),
                         ),
                     )
+                    .child(
+                        div()
+                            .w(px(120.0))
+                            .child(Text::new(exact_str).size(TextSize::Xs)),
+                    )
+                    .child(
+                        div()
+                            .w(px(100.0))
+                            .child(Text::new(diff_str).size(TextSize::Xs).color(diff_color)),
+                    )
                     .child(
                         div()
                             .w(px(80.0))
                             .child(Text::new(apy_str).size(TextSize::Xs).color(apy_color)),
+                    )
+                    .child(
+                        div()
+                            .w(px(80.0))
+                            .child(Text::new(payout_str).size(TextSize::Xs).color(payout_color)),
                     ),
             );
         }
@@ -322,6 +475,8 @@ impl HistorySection {
 struct ApyChartPoint {
     label: String,
     apy_percent: f64,
+    /// APY of the exact reward for the same era, when known.
+    exact_apy_percent: Option<f64>,
 }
 
 impl From<&HistoryPoint> for ApyChartPoint {
@@ -335,6 +490,7 @@ impl From<&HistoryPoint> for ApyChartPoint {
         Self {
             label: format_history_axis_label(point),
             apy_percent,
+            exact_apy_percent: None,
         }
     }
 }
@@ -396,6 +552,7 @@ fn render_apy_plot(
 ) -> AnyElement {
     let line_color = theme.success;
     let marker_color = theme.success;
+    let exact_color = theme.accent;
     let grid_color = with_alpha(theme.border, 0.55);
     let axis_color = with_alpha(theme.text_muted, 0.85);
     let background = theme.background;
@@ -421,6 +578,7 @@ fn render_apy_plot(
                         window,
                     );
                     paint_apy_line(bounds, &points, y_max, line_color, marker_color, window);
+                    paint_exact_apy(bounds, &points, y_max, exact_color, window);
                 },
             )
             .size_full(),
@@ -511,6 +669,56 @@ fn paint_apy_line(
     }
 }
 
+/// Draw the exact-reward APY through the eras that have one.
+fn paint_exact_apy(
+    bounds: Bounds<Pixels>,
+    points: &[ApyChartPoint],
+    y_max: f64,
+    color: Rgba,
+    window: &mut Window,
+) {
+    let exact: Vec<Point<Pixels>> = points
+        .iter()
+        .enumerate()
+        .filter_map(|(index, p)| {
+            let apy = p.exact_apy_percent?;
+            Some(chart_point_position(
+                index,
+                apy,
+                points.len(),
+                y_max,
+                bounds,
+            ))
+        })
+        .collect();
+
+    if exact.len() >= 2 {
+        let mut builder = PathBuilder::stroke(px(1.5));
+        builder.move_to(exact[0]);
+        for point in &exact[1..] {
+            builder.line_to(*point);
+        }
+        if let Ok(path) = builder.build() {
+            window.paint_path(path, color);
+        }
+    }
+
+    for center in exact {
+        let radius = px(2.5);
+        window.paint_quad(quad(
+            Bounds {
+                origin: point(center.x - radius, center.y - radius),
+                size: size(radius * 2.0, radius * 2.0),
+            },
+            radius,
+            color,
+            px(0.0),
+            transparent_black(),
+            Default::default(),
+        ));
+    }
+}
+
 fn paint_stroke(
     window: &mut Window,
     from: Point<Pixels>,
@@ -699,10 +907,29 @@ fn format_balance(amount: u128, symbol: &str, decimals: u8) -> String {
     format!("{}.{:04} {}", whole, frac, symbol)
 }
 
+/// Format a signed reward difference like [`format_balance`].
+fn format_reward_difference(diff: i128, symbol: &str, decimals: u8) -> String {
+    let sign = if diff < 0 { "-" } else { "+" };
+    format!(
+        "{}{}",
+        sign,
+        format_balance(diff.unsigned_abs(), symbol, decimals)
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn reward_difference_is_signed() {
+        assert_eq!(
+            format_reward_difference(-5_000_000_000, "DOT", 10),
+            "-0.5000 DOT"
+        );
+        assert_eq!(format_reward_difference(0, "DOT", 10), "+0.0000 DOT");
+    }
+
     #[test]
     fn date_labels_use_mm_dd() {
         assert_eq!(format_mm_dd_date("20260531").as_deref(), Some("05-31"));
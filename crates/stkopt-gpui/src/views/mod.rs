@@ -11,6 +11,7 @@ mod pools;
 mod qr_modal;
 mod settings;
 mod staking_modal;
+mod transactions;
 mod validators;
 
 pub use account::AccountSection;
@@ -24,4 +25,5 @@ pub use pools::PoolsSection;
 pub use qr_modal::QrModal;
 pub use settings::SettingsSection;
 pub use staking_modal::StakingModal;
+pub use transactions::TransactionsCard;
 pub use validators::ValidatorsSection;
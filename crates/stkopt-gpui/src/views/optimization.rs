@@ -155,6 +155,30 @@ impl OptimizationSection {
                                                     }
                                                 }),
                                         ),
+                                )
+                                .child(
+                                    div()
+                                        .flex()
+                                        .flex_col()
+                                        .gap_1()
+                                        .child(
+                                            Text::new("Skip Reported Validators")
+                                                .size(TextSize::Xs)
+                                                .color(theme.text_secondary),
+                                        )
+                                        .child(
+                                            Toggle::new("optimization-exclude-reported")
+                                                .checked(app.optimization_exclude_reported)
+                                                .size(ToggleSize::Md)
+                                                .on_change({
+                                                    let entity = entity.clone();
+                                                    move |enabled, _window, cx| {
+                                                        entity.update(cx, |this, cx| {
+                                                            this.set_exclude_reported(enabled, cx);
+                                                        });
+                                                    }
+                                                }),
+                                        ),
                                 ),
                         ),
                 ),
@@ -176,6 +200,7 @@ impl OptimizationSection {
                                         exclude_blocked: true,
                                         target_count: this.optimization_target_count,
                                         strategy: this.optimization_strategy,
+                                        slashed: this.slashed_validators(),
                                     };
                                     let result = optimize_selection(&this.validators, &criteria);
                                     this.selected_validators =
@@ -7,7 +7,8 @@ use gpui::*;
 use gpui_ui_kit::theme::ThemeExt;
 use gpui_ui_kit::*;
 
-use crate::app::{PoolOperation, StkoptApp, parse_token_amount};
+use crate::app::{PoolOperation, PoolState, StkoptApp, parse_token_amount};
+use stkopt_core::{ClaimPermission, PoolAdminAction, PoolMemberCall, format_perbill};
 
 /// Pool modal component.
 pub struct PoolModal;
@@ -168,6 +169,53 @@ impl PoolModal {
             );
         }
 
+        // Claim permission picker
+        if operation == PoolOperation::SetClaimPermission {
+            let mut options = div().flex().flex_col().gap_1().child(
+                Text::new("Claim Permission")
+                    .size(TextSize::Xs)
+                    .color(theme.text_secondary),
+            );
+            for permission in ClaimPermission::ALL {
+                let selected = app.pool_claim_permission == permission;
+                let entity = entity.clone();
+                options = options.child(
+                    div()
+                        .id(SharedString::from(format!(
+                            "claim-permission-{}",
+                            permission.variant_name()
+                        )))
+                        .p_2()
+                        .rounded_md()
+                        .border_1()
+                        .border_color(if selected { theme.accent } else { theme.border })
+                        .cursor_pointer()
+                        .on_mouse_down(MouseButton::Left, move |_event, _window, cx| {
+                            entity.update(cx, |this, cx| {
+                                this.pool_claim_permission = permission;
+                                cx.notify();
+                            });
+                        })
+                        .child(Text::new(permission.label()).size(TextSize::Xs).color(
+                            if selected {
+                                theme.accent
+                            } else {
+                                theme.text_primary
+                            },
+                        )),
+                );
+            }
+            body = body.child(options);
+        }
+
+        if let PoolOperation::Admin(action) = operation {
+            body = body.child(Self::render_admin_input(action, app, cx));
+        }
+
+        if operation == PoolOperation::MemberRewards {
+            body = body.child(Self::render_member_input(app, cx));
+        }
+
         // Amount input for operations that require it
         if operation.requires_amount() {
             let error_color = theme.error;
@@ -203,7 +251,7 @@ impl PoolModal {
                                         }
                                     }),
                             )
-                            .child(Text::new(symbol).size(TextSize::Xs)),
+                            .child(Text::new(symbol.to_string()).size(TextSize::Xs)),
                     )
                     .when_some(amount_error, |div, error| {
                         div.child(Text::new(error).size(TextSize::Xs).color(error_color))
@@ -242,6 +290,186 @@ impl PoolModal {
         body
     }
 
+    fn render_admin_input(
+        action: PoolAdminAction,
+        app: &StkoptApp,
+        cx: &Context<StkoptApp>,
+    ) -> impl IntoElement {
+        let theme = cx.theme();
+        let entity = app.entity.clone();
+        let pool = app
+            .pool_admin
+            .iter()
+            .find(|p| Some(p.pool_id) == app.selected_pool_id);
+
+        let mut section = div().flex().flex_col().gap_1();
+
+        if let Some(pool) = pool {
+            let commission = pool
+                .commission
+                .current
+                .map(format_perbill)
+                .unwrap_or_else(|| "none".to_string());
+            section = section.child(
+                Text::new(format!(
+                    "{} · {:?} · commission {}",
+                    pool.name.as_deref().unwrap_or("Unnamed pool"),
+                    pool.state,
+                    commission
+                ))
+                .size(TextSize::Xs)
+                .color(theme.text_secondary),
+            );
+        }
+
+        match action {
+            PoolAdminAction::Nominate => {
+                section = section.child(
+                    Text::new(format!(
+                        "{} validators selected in the Optimization tab",
+                        app.selected_validators.len()
+                    ))
+                    .size(TextSize::Xs),
+                );
+            }
+            PoolAdminAction::ClaimCommission => {
+                let symbol = app.token_symbol();
+                let decimals = app.token_decimals();
+                let pending = pool.map(|p| p.commission.pending).unwrap_or(0);
+                section = section.child(
+                    Text::new(format!(
+                        "Pending commission: {}",
+                        format_balance(pending, symbol, decimals)
+                    ))
+                    .size(TextSize::Xs),
+                );
+            }
+            PoolAdminAction::SetState => {
+                for state in [PoolState::Open, PoolState::Blocked, PoolState::Destroying] {
+                    let selected = app.pool_admin_state == state;
+                    let entity = entity.clone();
+                    section = section.child(
+                        div()
+                            .id(SharedString::from(format!("pool-state-{:?}", state)))
+                            .p_2()
+                            .rounded_md()
+                            .border_1()
+                            .border_color(if selected { theme.accent } else { theme.border })
+                            .cursor_pointer()
+                            .on_mouse_down(MouseButton::Left, move |_event, _window, cx| {
+                                entity.update(cx, |this, cx| {
+                                    this.pool_admin_state = state;
+                                    cx.notify();
+                                });
+                            })
+                            .child(Text::new(format!("{:?}", state)).size(TextSize::Xs).color(
+                                if selected {
+                                    theme.accent
+                                } else {
+                                    theme.text_primary
+                                },
+                            )),
+                    );
+                }
+            }
+            _ => {
+                let (label, placeholder) = match action {
+                    PoolAdminAction::SetMetadata => ("Pool name", "My pool"),
+                    PoolAdminAction::SetCommission => ("Commission % (empty removes it)", "5"),
+                    PoolAdminAction::SetCommissionMax => ("Max commission %", "10"),
+                    PoolAdminAction::SetCommissionChangeRate => {
+                        ("Max increase % and min delay blocks", "1 14400")
+                    }
+                    PoolAdminAction::Create => (
+                        "Root, nominator and bouncer (empty or - uses your account)",
+                        "- - -",
+                    ),
+                    _ => (
+                        "Root, nominator and bouncer (- keeps, none removes)",
+                        "- - -",
+                    ),
+                };
+                section = section
+                    .child(
+                        Text::new(label)
+                            .size(TextSize::Xs)
+                            .color(theme.text_secondary),
+                    )
+                    .child(
+                        Input::new("pool-admin-input")
+                            .placeholder(placeholder)
+                            .size(InputSize::Md)
+                            .value(app.pool_admin_input.clone())
+                            .on_text_change(move |value: String, _window, cx| {
+                                entity.update(cx, |this, cx| {
+                                    this.pool_admin_input = value;
+                                    this.pool_action_message = None;
+                                    cx.notify();
+                                });
+                            }),
+                    );
+            }
+        }
+
+        section
+    }
+
+    fn render_member_input(app: &StkoptApp, cx: &Context<StkoptApp>) -> impl IntoElement {
+        let theme = cx.theme();
+        let entity = app.entity.clone();
+        let mut section = div().flex().flex_col().gap_1();
+
+        for call in PoolMemberCall::ALL {
+            let selected = app.pool_member_call == call;
+            let entity = entity.clone();
+            section = section.child(
+                div()
+                    .id(SharedString::from(format!("pool-member-call-{:?}", call)))
+                    .p_2()
+                    .rounded_md()
+                    .border_1()
+                    .border_color(if selected { theme.accent } else { theme.border })
+                    .cursor_pointer()
+                    .on_mouse_down(MouseButton::Left, move |_event, _window, cx| {
+                        entity.update(cx, |this, cx| {
+                            this.pool_member_call = call;
+                            this.pool_action_message = None;
+                            cx.notify();
+                        });
+                    })
+                    .child(
+                        Text::new(call.label())
+                            .size(TextSize::Xs)
+                            .color(if selected {
+                                theme.accent
+                            } else {
+                                theme.text_primary
+                            }),
+                    ),
+            );
+        }
+
+        section
+            .child(
+                Text::new("Member address")
+                    .size(TextSize::Xs)
+                    .color(theme.text_secondary),
+            )
+            .child(
+                Input::new("pool-member-input")
+                    .placeholder("Pool member address")
+                    .size(InputSize::Md)
+                    .value(app.pool_member_address.clone())
+                    .on_text_change(move |value: String, _window, cx| {
+                        entity.update(cx, |this, cx| {
+                            this.pool_member_address = value;
+                            this.pool_action_message = None;
+                            cx.notify();
+                        });
+                    }),
+            )
+    }
+
     fn render_footer(app: &mut StkoptApp, cx: &mut Context<StkoptApp>) -> impl IntoElement {
         let theme = cx.theme();
         let entity = app.entity.clone();
@@ -304,6 +532,9 @@ impl PoolModal {
             PoolOperation::ClaimPayout => "🎁",
             PoolOperation::Unbond => "🔓",
             PoolOperation::Withdraw => "💸",
+            PoolOperation::SetClaimPermission => "🔑",
+            PoolOperation::Admin(_) => "🛠",
+            PoolOperation::MemberRewards => "🤝",
         }
     }
 
@@ -318,6 +549,39 @@ impl PoolModal {
                 "Start unbonding tokens from the pool. They will be available to withdraw after the unbonding period."
             }
             PoolOperation::Withdraw => "Withdraw tokens that have completed the unbonding period.",
+            PoolOperation::SetClaimPermission => {
+                "Choose who may claim or compound your pool rewards. Permissionless compounding lets anyone restake them for you."
+            }
+            PoolOperation::MemberRewards => {
+                "Compound or pay out another member's pending rewards. Their claim permission must allow it, which is checked before the QR is built."
+            }
+            PoolOperation::Admin(action) => match action {
+                PoolAdminAction::Nominate => {
+                    "Nominate the validators selected in the Optimization tab on behalf of the pool."
+                }
+                PoolAdminAction::SetState => {
+                    "Open the pool to new members, block joins, or start destroying it."
+                }
+                PoolAdminAction::SetMetadata => "Set the pool name shown to members.",
+                PoolAdminAction::SetCommission => {
+                    "Set the commission taken from pool rewards, paid to your account."
+                }
+                PoolAdminAction::SetCommissionMax => {
+                    "Cap the commission. The cap can only be lowered afterwards."
+                }
+                PoolAdminAction::SetCommissionChangeRate => {
+                    "Limit how much and how often the commission may increase."
+                }
+                PoolAdminAction::ClaimCommission => {
+                    "Claim commission accrued by the pool to the commission payee."
+                }
+                PoolAdminAction::UpdateRoles => {
+                    "Change the root, nominator and bouncer accounts of the pool."
+                }
+                PoolAdminAction::Create => {
+                    "Create a new pool bonded from your account, which becomes its depositor. The bond must cover the minimum to create a pool."
+                }
+            },
         }
     }
 }
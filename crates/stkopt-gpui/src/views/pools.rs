@@ -47,6 +47,24 @@ impl PoolsSection {
                                         }
                                     }),
                             )
+                            .child(
+                                Button::new("btn-pool-member-rewards", "Member Rewards")
+                                    .variant(ButtonVariant::Secondary)
+                                    .size(ButtonSize::Xs)
+                                    .disabled(!app.commands_available())
+                                    .on_click({
+                                        let entity = entity.clone();
+                                        move |_window, cx| {
+                                            entity.update(cx, |this, cx| {
+                                                this.open_pool_modal(
+                                                    PoolOperation::MemberRewards,
+                                                    None,
+                                                    cx,
+                                                );
+                                            });
+                                        }
+                                    }),
+                            )
                             .child(
                                 Button::new("btn-refresh-pools", "Refresh")
                                     .variant(ButtonVariant::Secondary)
@@ -9,12 +9,14 @@ use gpui_ui_kit::*;
 use qrcode::{EcLevel, QrCode, Version};
 
 use crate::app::{QrModalTab, QrTxStatus, StkoptApp};
+use stkopt_core::mortality::EXPIRY_WARNING_SECS;
 
 /// QR modal component.
 pub struct QrModal;
 
 impl QrModal {
     pub fn render(app: &mut StkoptApp, cx: &mut Context<StkoptApp>) -> impl IntoElement {
+        app.ensure_expiry_countdown(cx);
         let theme = cx.theme();
         let entity = app.entity.clone();
 
@@ -162,6 +164,7 @@ impl QrModal {
 
     fn render_qr_tab(app: &StkoptApp, cx: &Context<StkoptApp>) -> Div {
         let theme = cx.theme();
+        let entity = app.entity.clone();
 
         if let Some(ref payload) = app.pending_tx_payload {
             let qr_data = &payload.qr_data;
@@ -169,7 +172,22 @@ impl QrModal {
             // Try to generate QR code with different versions
             let qr_result = Self::generate_qr_code(qr_data, &theme);
 
-            match qr_result {
+            let export_button = Button::new(
+                "btn-export-qr",
+                format!("Export QR ({})", app.qr_export_format),
+            )
+            .variant(ButtonVariant::Secondary)
+            .theme(crate::theme::button_theme_for_ui_theme(&theme))
+            .on_click({
+                let entity = entity.clone();
+                move |_window, cx| {
+                    entity.update(cx, |this, cx| {
+                        this.export_qr(cx);
+                    });
+                }
+            });
+
+            let mut content = match qr_result {
                 Ok(qr_element) => div()
                     .flex()
                     .flex_col()
@@ -213,7 +231,17 @@ impl QrModal {
                             .size(TextSize::Xs)
                             .color(theme.text_secondary),
                     ),
+            };
+
+            content = content.child(export_button);
+            if let Some(ref message) = app.qr_export_message {
+                content = content.child(
+                    Text::new(message.clone())
+                        .size(TextSize::Xs)
+                        .color(theme.text_secondary),
+                );
             }
+            content
         } else {
             div()
                 .flex()
@@ -371,7 +399,7 @@ impl QrModal {
         content
     }
 
-    fn render_camera_preview(
+    pub(crate) fn render_camera_preview(
         preview: &crate::qr_reader::CameraPreview,
         theme: &gpui_ui_kit::theme::Theme,
     ) -> Div {
@@ -417,7 +445,9 @@ impl QrModal {
                 QrTxStatus::Ready | QrTxStatus::Submitting | QrTxStatus::Submitted => {
                     theme.success_token().subtle
                 }
-                QrTxStatus::NotReady | QrTxStatus::Failed => theme.warning_token().subtle,
+                QrTxStatus::NotReady | QrTxStatus::Failed | QrTxStatus::Expired => {
+                    theme.warning_token().subtle
+                }
             };
 
             content = content.child(
@@ -447,6 +477,7 @@ impl QrModal {
                 .theme(crate::theme::button_theme_for_ui_theme(&theme))
                 .disabled(
                     app.signed_extrinsic.is_none()
+                        || app.tx_status == QrTxStatus::Expired
                         || app.tx_status == QrTxStatus::Submitting
                         || app.tx_status == QrTxStatus::Submitted,
                 )
@@ -466,31 +497,69 @@ impl QrModal {
     fn render_footer(app: &mut StkoptApp, cx: &mut Context<StkoptApp>) -> impl IntoElement {
         let theme = cx.theme();
         let entity = app.entity.clone();
+        let remaining = app
+            .pending_tx_payload
+            .as_ref()
+            .and_then(|payload| payload.remaining_secs());
+        let expired = remaining == Some(0) || app.tx_status == QrTxStatus::Expired;
 
-        div()
-            .flex()
-            .items_center()
-            .justify_between()
-            .p_3()
-            .border_t_1()
-            .border_color(theme.border)
-            .child(
-                Text::new("Use Polkadot Vault for secure signing")
-                    .size(TextSize::Xs)
-                    .color(theme.text_secondary),
-            )
-            .child(
-                Button::new("btn-close-qr", "Close")
-                    .variant(ButtonVariant::Secondary)
+        let status = match remaining {
+            Some(0) => Text::new("Expired - rebuild and sign again")
+                .size(TextSize::Xs)
+                .color(theme.error),
+            Some(secs) => Text::new(format!(
+                "Expires in {}",
+                stkopt_core::format_countdown(secs)
+            ))
+            .size(TextSize::Xs)
+            .color(if secs < EXPIRY_WARNING_SECS {
+                theme.warning
+            } else {
+                theme.text_secondary
+            }),
+            None => Text::new("Use Polkadot Vault for secure signing")
+                .size(TextSize::Xs)
+                .color(theme.text_secondary),
+        };
+
+        let mut buttons = div().flex().items_center().gap_2();
+        if expired {
+            buttons = buttons.child(
+                Button::new("btn-rebuild-tx", "Rebuild")
+                    .variant(ButtonVariant::Primary)
+                    .theme(crate::theme::button_theme_for_ui_theme(&theme))
                     .on_click({
                         let entity = entity.clone();
                         move |_window, cx| {
                             entity.update(cx, |this, cx| {
-                                this.close_qr_modal(cx);
+                                this.rebuild_pending_payload(cx);
                             });
                         }
                     }),
-            )
+            );
+        }
+        buttons = buttons.child(
+            Button::new("btn-close-qr", "Close")
+                .variant(ButtonVariant::Secondary)
+                .on_click({
+                    let entity = entity.clone();
+                    move |_window, cx| {
+                        entity.update(cx, |this, cx| {
+                            this.close_qr_modal(cx);
+                        });
+                    }
+                }),
+        );
+
+        div()
+            .flex()
+            .items_center()
+            .justify_between()
+            .p_3()
+            .border_t_1()
+            .border_color(theme.border)
+            .child(status)
+            .child(buttons)
     }
 }
 
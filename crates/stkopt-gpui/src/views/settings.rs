@@ -137,6 +137,12 @@ impl SettingsSection {
                         "How to connect to the blockchain",
                         Self::render_connection_mode_selector(app, cx),
                         &theme,
+                    ))
+                    .child(Self::render_setting_row(
+                        "Transaction Mortality",
+                        "Blocks a signed transaction stays valid on the current network",
+                        Self::render_mortality_selector(app, cx),
+                        &theme,
                     )),
             )
     }
@@ -265,6 +271,9 @@ impl SettingsSection {
         if app.settings_show_testnets || current == NetworkConfig::Westend {
             options.push(ButtonSetOption::new("westend", "Westend"));
         }
+        if let Some(custom) = &app.custom_network {
+            options.push(ButtonSetOption::new("custom", custom.to_string()));
+        }
 
         ButtonSet::new("network-selector")
             .options(options)
@@ -293,6 +302,7 @@ impl SettingsSection {
             .options(vec![
                 ButtonSetOption::new("light-client", "Light Client"),
                 ButtonSetOption::new("rpc", "RPC"),
+                ButtonSetOption::new("hybrid", "Hybrid"),
             ])
             .selected(connection_mode_config_value(current))
             .size(ButtonSetSize::Sm)
@@ -305,6 +315,52 @@ impl SettingsSection {
                 }
             })
     }
+
+    fn render_mortality_selector(app: &StkoptApp, _cx: &Context<StkoptApp>) -> impl IntoElement {
+        let entity = app.entity.clone();
+
+        ButtonSet::new("mortality-selector")
+            .options(
+                MORTALITY_OPTIONS
+                    .iter()
+                    .map(|(value, label, _)| ButtonSetOption::new(*value, *label))
+                    .collect(),
+            )
+            .selected(mortality_period_value(app.mortality_period()))
+            .size(ButtonSetSize::Sm)
+            .on_change(move |value, _window, cx| {
+                let value = value.to_string();
+                if let Some(period) = mortality_period_from_value(&value) {
+                    entity.update(cx, |this, cx| {
+                        this.set_mortality_period(period, cx);
+                    });
+                }
+            })
+    }
+}
+
+/// Mortality period choices: (value, label, blocks).
+const MORTALITY_OPTIONS: [(&str, &str, u64); 5] = [
+    ("64", "~6 min", 64),
+    ("256", "~25 min", 256),
+    ("1024", "~1.7 h", 1024),
+    ("2048", "~3.4 h", 2048),
+    ("4096", "~6.8 h", 4096),
+];
+
+fn mortality_period_value(period: u64) -> &'static str {
+    MORTALITY_OPTIONS
+        .iter()
+        .find(|(_, _, blocks)| *blocks == period)
+        .map(|(value, _, _)| *value)
+        .unwrap_or("")
+}
+
+fn mortality_period_from_value(value: &str) -> Option<u64> {
+    MORTALITY_OPTIONS
+        .iter()
+        .find(|(v, _, _)| *v == value)
+        .map(|(_, _, blocks)| *blocks)
 }
 
 fn network_config_value(network: NetworkConfig) -> &'static str {
@@ -322,6 +378,7 @@ fn network_config_from_value(value: &str) -> Option<NetworkConfig> {
         "polkadot" => Some(NetworkConfig::Polkadot),
         "kusama" => Some(NetworkConfig::Kusama),
         "westend" => Some(NetworkConfig::Westend),
+        "custom" => Some(NetworkConfig::Custom),
         _ => None,
     }
 }
@@ -330,6 +387,7 @@ fn connection_mode_config_value(mode: ConnectionModeConfig) -> &'static str {
     match mode {
         ConnectionModeConfig::LightClient => "light-client",
         ConnectionModeConfig::Rpc => "rpc",
+        ConnectionModeConfig::Hybrid => "hybrid",
     }
 }
 
@@ -337,6 +395,7 @@ fn connection_mode_config_from_value(value: &str) -> Option<ConnectionModeConfig
     match value {
         "light-client" => Some(ConnectionModeConfig::LightClient),
         "rpc" => Some(ConnectionModeConfig::Rpc),
+        "hybrid" => Some(ConnectionModeConfig::Hybrid),
         _ => None,
     }
 }
@@ -344,11 +403,28 @@ fn connection_mode_config_from_value(value: &str) -> Option<ConnectionModeConfig
 #[cfg(test)]
 mod tests {
     use super::{
-        connection_mode_config_from_value, connection_mode_config_value, network_config_from_value,
+        connection_mode_config_from_value, connection_mode_config_value,
+        mortality_period_from_value, mortality_period_value, network_config_from_value,
         network_config_value,
     };
     use crate::persistence::{ConnectionModeConfig, NetworkConfig};
 
+    // ── mortality_period_value ──────────────────────────────────────────
+
+    #[test]
+    fn test_mortality_period_value_roundtrip() {
+        for period in [64, 256, 1024, 2048, 4096] {
+            let value = mortality_period_value(period);
+            assert_eq!(mortality_period_from_value(value), Some(period));
+        }
+    }
+
+    #[test]
+    fn test_mortality_period_value_unlisted() {
+        assert_eq!(mortality_period_value(128), "");
+        assert_eq!(mortality_period_from_value("bogus"), None);
+    }
+
     // ── network_config_value ────────────────────────────────────────────
 
     #[test]
@@ -409,7 +485,10 @@ mod tests {
 
     #[test]
     fn test_network_config_from_value_custom() {
-        assert_eq!(network_config_from_value("custom"), None);
+        assert_eq!(
+            network_config_from_value("custom"),
+            Some(NetworkConfig::Custom)
+        );
     }
 
     #[test]
@@ -458,6 +537,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_connection_mode_config_value_hybrid_roundtrip() {
+        assert_eq!(
+            connection_mode_config_from_value(connection_mode_config_value(
+                ConnectionModeConfig::Hybrid
+            )),
+            Some(ConnectionModeConfig::Hybrid)
+        );
+    }
+
     #[test]
     fn test_connection_mode_config_from_value_unknown() {
         assert_eq!(connection_mode_config_from_value("unknown"), None);
@@ -155,11 +155,25 @@ impl StakingModal {
                                         }
                                     }),
                             )
-                            .child(Text::new(symbol).size(TextSize::Xs)),
+                            .child(Text::new(symbol.to_string()).size(TextSize::Xs)),
                     ),
             );
         }
 
+        // Amount checked against the chain's staking limits
+        if let Some(issue) = app.staking_amount_issue() {
+            let color = if issue.is_error() {
+                theme.error
+            } else {
+                theme.warning
+            };
+            body = body.child(
+                Text::new(issue.message(decimals, symbol))
+                    .size(TextSize::Xs)
+                    .color(color),
+            );
+        }
+
         // SetPayee: reward destination picker
         if operation == StakingOperation::SetPayee {
             let current_dest = &app.rewards_destination;
@@ -386,6 +400,8 @@ impl StakingModal {
             StakingOperation::Chill => "❄️",
             StakingOperation::ClaimRewards => "🎁",
             StakingOperation::SetPayee => "⚙️",
+            StakingOperation::Rebag => "🧺",
+            StakingOperation::PutInFrontOf => "⏫",
         }
     }
 
@@ -408,6 +424,12 @@ impl StakingModal {
             StakingOperation::SetPayee => {
                 "Change where your staking rewards are sent. 'Staked' compounds rewards automatically."
             }
+            StakingOperation::Rebag => {
+                "Move into the voter list bag matching your current stake, so the election snapshot picks you up in order."
+            }
+            StakingOperation::PutInFrontOf => {
+                "Move ahead of a lighter voter in your voter list bag."
+            }
         }
     }
 }
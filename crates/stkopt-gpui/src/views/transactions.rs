@@ -0,0 +1,242 @@
+//! Transaction history card - journal of transactions submitted from this app.
+
+use gpui::prelude::*;
+use gpui::{AnyElement, Context, div, px};
+use gpui_ui_kit::theme::ThemeExt;
+use gpui_ui_kit::*;
+use stkopt_core::{TransactionRecord, TxJournalStatus};
+
+use crate::app::StkoptApp;
+
+pub struct TransactionsCard;
+
+impl TransactionsCard {
+    pub fn render(app: &mut StkoptApp, cx: &mut Context<StkoptApp>) -> AnyElement {
+        let theme = cx.theme();
+        let entity = app.entity.clone();
+        let has_account = app.watched_account.is_some();
+        let filter_label = match app.tx_journal_status {
+            Some(status) => format!("Status: {}", status),
+            None => "Status: All".to_string(),
+        };
+
+        let toolbar = div()
+            .flex()
+            .items_center()
+            .gap_2()
+            .child(
+                Input::new("tx-journal-search")
+                    .placeholder("Search operation, hash or result")
+                    .size(InputSize::Sm)
+                    .value(app.tx_journal_search.clone())
+                    .on_text_change({
+                        let entity = entity.clone();
+                        move |value: String, _window, cx| {
+                            entity.update(cx, |this, cx| {
+                                this.tx_journal_search = value;
+                                cx.notify();
+                            });
+                        }
+                    }),
+            )
+            .child(
+                Button::new("btn-tx-journal-filter", filter_label)
+                    .variant(ButtonVariant::Secondary)
+                    .size(ButtonSize::Xs)
+                    .on_click({
+                        let entity = entity.clone();
+                        move |_window, cx| {
+                            entity.update(cx, |this, cx| {
+                                this.cycle_journal_status_filter();
+                                cx.notify();
+                            });
+                        }
+                    }),
+            )
+            .child(
+                Button::new(
+                    "btn-tx-journal-export",
+                    format!("Export {}", app.journal_export_format),
+                )
+                .variant(ButtonVariant::Secondary)
+                .size(ButtonSize::Xs)
+                .disabled(!has_account)
+                .on_click({
+                    let entity = entity.clone();
+                    move |_window, cx| {
+                        entity.update(cx, |this, cx| {
+                            this.export_transaction_journal(cx);
+                        });
+                    }
+                }),
+            );
+
+        let records = app.filtered_transaction_journal();
+        let mut list = div().flex().flex_col();
+        if records.is_empty() {
+            let message = if app.tx_journal.is_empty() {
+                "No transactions submitted from this app yet"
+            } else {
+                "No transactions match the current filter"
+            };
+            list = list.child(
+                div().p_4().child(
+                    Text::new(message)
+                        .size(TextSize::Xs)
+                        .color(theme.text_secondary),
+                ),
+            );
+        } else {
+            list = list.child(
+                div()
+                    .flex()
+                    .items_center()
+                    .px_3()
+                    .py_2()
+                    .bg(theme.surface)
+                    .border_b_1()
+                    .border_color(theme.border)
+                    .child(header_cell("Submitted (UTC)", Some(150.0)))
+                    .child(header_cell("Operation", None))
+                    .child(header_cell("Nonce", Some(60.0)))
+                    .child(header_cell("Status", Some(90.0)))
+                    .child(header_cell("Tx Hash", Some(130.0))),
+            );
+            for (i, record) in records.iter().enumerate() {
+                let row_bg = if i % 2 == 0 {
+                    theme.background
+                } else {
+                    theme.surface
+                };
+                let status_color = match record.status {
+                    TxJournalStatus::Submitted | TxJournalStatus::InBlock => theme.warning,
+                    TxJournalStatus::Finalized => theme.success,
+                    TxJournalStatus::Failed => theme.error,
+                };
+                let mut operation = div()
+                    .flex_1()
+                    .flex()
+                    .flex_col()
+                    .child(Text::new(record.description.clone()).size(TextSize::Xs));
+                if let Some(result) = record.dispatch_result.as_deref() {
+                    operation = operation.child(
+                        Text::new(result.to_string())
+                            .size(TextSize::Xs)
+                            .color(theme.error),
+                    );
+                }
+                list = list.child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .px_3()
+                        .py_1()
+                        .bg(row_bg)
+                        .border_b_1()
+                        .border_color(theme.border)
+                        .child(
+                            div().w(px(150.0)).child(
+                                Text::new(record.submitted_at.clone())
+                                    .size(TextSize::Xs)
+                                    .color(theme.text_secondary),
+                            ),
+                        )
+                        .child(operation)
+                        .child(
+                            div()
+                                .w(px(60.0))
+                                .child(Text::new(record.nonce.to_string()).size(TextSize::Xs)),
+                        )
+                        .child(
+                            div().w(px(90.0)).child(
+                                Text::new(record.status.to_string())
+                                    .size(TextSize::Xs)
+                                    .color(status_color),
+                            ),
+                        )
+                        .child(
+                            div().w(px(130.0)).child(
+                                Text::new(short_hash(record))
+                                    .size(TextSize::Xs)
+                                    .color(theme.text_secondary),
+                            ),
+                        ),
+                );
+            }
+        }
+
+        let mut content = div()
+            .flex()
+            .flex_col()
+            .gap_3()
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .child(Heading::h3("Transaction History"))
+                    .child(toolbar),
+            )
+            .child(list);
+        if let Some(ref message) = app.tx_journal_message {
+            content = content.child(
+                Text::new(message.clone())
+                    .size(TextSize::Xs)
+                    .color(theme.text_secondary),
+            );
+        }
+
+        Card::new().content(content).into_any_element()
+    }
+}
+
+fn header_cell(label: &'static str, width: Option<f32>) -> gpui::Div {
+    let cell = match width {
+        Some(width) => div().w(px(width)),
+        None => div().flex_1(),
+    };
+    cell.child(
+        Text::new(label)
+            .size(TextSize::Xs)
+            .weight(TextWeight::Semibold),
+    )
+}
+
+/// Abbreviated tx hash for the table (`0x1234…abcd`).
+fn short_hash(record: &TransactionRecord) -> String {
+    let hash = record.tx_hash.as_str();
+    if hash.len() <= 14 {
+        return hash.to_string();
+    }
+    format!("{}…{}", &hash[..6], &hash[hash.len() - 4..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(tx_hash: &str) -> TransactionRecord {
+        TransactionRecord {
+            id: 1,
+            network: stkopt_core::Network::Polkadot,
+            signer: "addr".to_string(),
+            description: "Bond".to_string(),
+            call_data: "0x".to_string(),
+            nonce: 0,
+            mortality: stkopt_core::TxMortality::Immortal,
+            tx_hash: tx_hash.to_string(),
+            block_hash: None,
+            status: TxJournalStatus::Submitted,
+            dispatch_result: None,
+            submitted_at: "2026-01-01 00:00:00".to_string(),
+            completed_at: None,
+        }
+    }
+
+    #[test]
+    fn test_short_hash_abbreviates_long_hashes() {
+        let hash = format!("0x{}", "ab".repeat(32));
+        assert_eq!(short_hash(&record(&hash)), "0xabab…abab");
+        assert_eq!(short_hash(&record("0x1234")), "0x1234");
+    }
+}
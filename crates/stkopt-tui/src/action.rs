@@ -1,10 +1,15 @@
 //! Actions for state updates.
 
 use stkopt_chain::{
-    AccountBalance, ChainInfo, NominatorInfo, PoolMembership, RewardDestination, StakingLedger,
-    UnsignedPayload,
+    AccountBalance, BalanceBreakdown, ChainInfo, NominatorExposure, NominatorInfo, PoolMembership,
+    PoolRoleUpdate, RewardDestination, StakingLedger, TxOutcome, UnsignedPayload,
+};
+use stkopt_core::{
+    AddressBookEntry, BagsListPosition, ClaimPermission, CommissionChangeRate, ConnectionStatus,
+    EraInfo, HealthAccount, Network, NominatorSlash, OffenceReport, OptimizationResult,
+    PayoutCheck, PoolAdmin, PoolMemberCall, PoolMemberDetails, PoolState, PortfolioAccount,
+    SlashHistory, StakingParams, TransactionRecord, TransactionType,
 };
-use stkopt_core::{ConnectionStatus, EraInfo, Network, OptimizationResult};
 use subxt::utils::AccountId32;
 
 // Re-export display types from stkopt-core
@@ -19,9 +24,62 @@ pub enum StakingInputMode {
     Unbond,
     BondExtra,
     SetPayee,
+    SetClaimPermission,
     PoolJoin,
     PoolUnbond,
     PoolBondExtra,
+    /// Choosing a pool administration call.
+    PoolAdmin,
+    /// Entering the argument of the chosen pool administration call.
+    PoolAdminInput,
+    /// Entering another pool member to compound or pay out rewards for.
+    PoolMemberCall,
+}
+
+impl StakingInputMode {
+    /// Transaction the entered amount is for, if any.
+    pub fn transaction_type(self) -> Option<TransactionType> {
+        match self {
+            StakingInputMode::Bond => Some(TransactionType::Bond),
+            StakingInputMode::Unbond => Some(TransactionType::Unbond),
+            StakingInputMode::BondExtra => Some(TransactionType::BondExtra),
+            StakingInputMode::PoolJoin => Some(TransactionType::PoolJoin),
+            StakingInputMode::PoolUnbond => Some(TransactionType::PoolUnbond),
+            StakingInputMode::PoolBondExtra => Some(TransactionType::PoolBondExtra),
+            StakingInputMode::None
+            | StakingInputMode::SetPayee
+            | StakingInputMode::SetClaimPermission
+            | StakingInputMode::PoolAdmin
+            | StakingInputMode::PoolAdminInput
+            | StakingInputMode::PoolMemberCall => None,
+        }
+    }
+}
+
+/// Pool administration call with its arguments, signed by a pool role.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PoolAdminOp {
+    /// Nominate the validators selected in the Nominate view.
+    Nominate,
+    SetState(PoolState),
+    SetMetadata(String),
+    /// Commission in parts per billion, paid to the signer; `None` removes it.
+    SetCommission(Option<u32>),
+    SetCommissionMax(u32),
+    SetCommissionChangeRate(CommissionChangeRate),
+    ClaimCommission,
+    UpdateRoles {
+        root: PoolRoleUpdate,
+        nominator: PoolRoleUpdate,
+        bouncer: PoolRoleUpdate,
+    },
+    /// Create a pool bonding `amount`; a `None` role goes to the signer.
+    Create {
+        amount: u128,
+        root: Option<AccountId32>,
+        nominator: Option<AccountId32>,
+        bouncer: Option<AccountId32>,
+    },
 }
 
 /// Account status information for display.
@@ -29,9 +87,20 @@ pub enum StakingInputMode {
 pub struct AccountStatus {
     pub address: AccountId32,
     pub balance: AccountBalance,
+    /// Locks, freezes and holds; not cached, so `None` until fetched live.
+    pub balance_breakdown: Option<BalanceBreakdown>,
     pub staking_ledger: Option<StakingLedger>,
     pub nominations: Option<NominatorInfo>,
     pub pool_membership: Option<PoolMembership>,
+    /// Stake allocation in the active era; not cached, so `None` until fetched live.
+    pub exposure: Option<NominatorExposure>,
+    /// Health check inputs; not cached, so `None` until fetched live.
+    pub health: Option<HealthAccount>,
+    /// Slashes applied to the account; not cached, so `None` until fetched live.
+    pub past_slashes: Option<Vec<NominatorSlash>>,
+    /// Pool points in balance terms and claim permission; not cached, so
+    /// `None` until fetched live.
+    pub pool_details: Option<PoolMemberDetails>,
 }
 
 /// Transaction info for QR code display.
@@ -61,8 +130,18 @@ pub enum TxSubmissionStatus {
     ReadyToSubmit,
     /// Submitting to network.
     Submitting,
-    /// Finalized in a block.
-    Finalized { block_hash: [u8; 32] },
+    /// Included in a best block (not yet finalized), with its decoded dispatch result.
+    InBlock {
+        block_hash: [u8; 32],
+        outcome: Option<TxOutcome>,
+    },
+    /// Finalized in a block, with the dispatch result decoded from its events.
+    Finalized {
+        block_hash: [u8; 32],
+        outcome: Option<TxOutcome>,
+    },
+    /// The payload's mortality window passed; it must be rebuilt and re-signed.
+    Expired,
     /// Submission failed.
     Failed(String),
 }
@@ -96,6 +175,8 @@ pub struct PendingTransaction {
     pub tx_hash: [u8; 32],
     /// Current submission status.
     pub status: TxSubmissionStatus,
+    /// Transaction journal row, once recorded at submission.
+    pub journal_id: Option<i64>,
 }
 
 /// Actions that can update application state.
@@ -109,6 +190,16 @@ pub enum Action {
     SetActiveEra(EraInfo),
     /// Set era duration in milliseconds.
     SetEraDuration(u64),
+    /// Set the chain's staking limits.
+    SetStakingParams(StakingParams),
+    /// Set the network's validator slashes and pending slashes.
+    SetSlashHistory(SlashHistory),
+    /// Set the offence reports on the relay chain.
+    SetOffenceReports(Vec<OffenceReport>),
+    /// Set the watched account's position in the voter list.
+    SetBagsListPosition(Option<BagsListPosition>),
+    /// Set the pools in which the watched account holds a role.
+    SetPoolAdmin(Vec<PoolAdmin>),
 
     /// Set display validators (aggregated data).
     SetDisplayValidators(Vec<DisplayValidator>),
@@ -134,6 +225,8 @@ pub enum Action {
     ToggleValidatorSelection(usize),
     /// Clear nominations.
     ClearNominations,
+    /// Toggle excluding validators with offence reports from optimization.
+    ToggleExcludeReported,
     /// Generate QR code for nomination transaction.
     GenerateNominationQR,
 
@@ -150,6 +243,10 @@ pub enum Action {
     GenerateWithdrawUnbondedQR,
     /// Generate QR for chill.
     GenerateChillQR,
+    /// Generate QR for moving into the bag matching the current stake.
+    GenerateRebagQR,
+    /// Generate QR for moving ahead of a lighter voter in the same bag.
+    GeneratePutInFrontOfQR,
 
     // === Pool Operations ===
     /// Generate QR for joining a pool.
@@ -162,6 +259,15 @@ pub enum Action {
     GeneratePoolUnbondQR { amount: u128 },
     /// Generate QR for withdrawing unbonded from pool.
     GeneratePoolWithdrawQR,
+    /// Generate QR for setting who may claim or compound pool rewards.
+    GeneratePoolSetClaimPermissionQR { permission: ClaimPermission },
+    /// Generate QR for a pool administration call.
+    GeneratePoolAdminQR { pool_id: u32, op: PoolAdminOp },
+    /// Generate QR compounding or paying out another pool member's rewards.
+    GeneratePoolMemberCallQR {
+        member: AccountId32,
+        call: PoolMemberCall,
+    },
 
     // === UI State Updates ===
     /// Set the selected reward destination.
@@ -173,10 +279,36 @@ pub enum Action {
     SetQRData(Option<Vec<u8>>, Option<TransactionInfo>),
     /// Store the pending unsigned transaction for later signature.
     SetPendingUnsignedTx(Option<PendingUnsignedTx>),
+    /// Rebuild the pending payload with a fresh nonce, block hash and era.
+    RebuildPendingTx,
+    /// Rebuilding the pending payload failed (error message).
+    PendingTxRebuildFailed(String),
     /// Start scanning for signed transaction QR from Vault.
     StartSignatureScan,
     /// Stop scanning for signature.
     StopSignatureScan,
+    /// Start scanning a Polkadot Vault account export QR.
+    StartAccountScan,
+    /// Vault account export QR scanned (raw bytes).
+    AccountQrScanned(Vec<u8>),
+    /// Vault account import finished (saved entry or error message).
+    VaultAccountImported(Result<AddressBookEntry, String>),
+    /// Export the displayed signing QR to an image file.
+    ExportQr,
+    /// QR export finished (written path or error message).
+    QrExported(Result<String, String>),
+    /// Load the transaction journal for the watched account.
+    LoadTransactions,
+    /// Set journaled transactions for the watched account (newest first).
+    SetTransactions(Vec<TransactionRecord>),
+    /// Export the filtered transaction journal to a file.
+    ExportTransactions,
+    /// Journal export finished (written path or error message).
+    TransactionsExported(Result<String, String>),
+    /// Load the status of every saved account on the current network.
+    LoadPortfolio,
+    /// Set the portfolio (accounts in saved order, or an error message).
+    SetPortfolio(Result<Vec<PortfolioAccount>, String>),
     /// Signature scanned from Vault QR code (raw bytes).
     SignatureScanned(Vec<u8>),
     /// QR scan failed with error message.
@@ -196,6 +328,8 @@ pub enum Action {
     SetStakingHistory(Vec<StakingHistoryPoint>),
     /// Add a single staking history point (streaming).
     AddStakingHistoryPoint(StakingHistoryPoint),
+    /// Add a single exact per-era reward point (streaming).
+    AddExactHistoryPoint(StakingHistoryPoint),
     /// Set the total number of eras expected for history loading progress.
     SetHistoryTotalEras(u32),
     /// Start loading staking history.
@@ -204,6 +338,10 @@ pub enum Action {
     CancelLoadingHistory,
     /// Mark history loading as complete.
     HistoryLoadingComplete,
+    /// Reconcile expected rewards of the loaded eras against payout events.
+    CheckPayouts,
+    /// Payout reconciliation result, one check per era and validator.
+    SetPayoutChecks(Result<Vec<PayoutCheck>, String>),
     /// Switch network.
     SwitchNetwork(Network),
     /// Select an entry from the address book (by index).
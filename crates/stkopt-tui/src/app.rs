@@ -2,7 +2,8 @@
 
 use crate::action::{
     AccountStatus, Action, DisplayPool, DisplayValidator, PendingTransaction, PendingUnsignedTx,
-    QrScanStatus, StakingHistoryPoint, StakingInputMode, TransactionInfo, TxSubmissionStatus,
+    PoolAdminOp, QrScanStatus, StakingHistoryPoint, StakingInputMode, TransactionInfo,
+    TxSubmissionStatus,
 };
 use crate::log_buffer::LogBuffer;
 use crate::theme::{Palette, Theme};
@@ -10,8 +11,18 @@ use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::widgets::TableState;
 use std::collections::HashSet;
 use std::sync::Arc;
-use stkopt_chain::{ChainInfo, RewardDestination};
-use stkopt_core::{ConnectionStatus, Network, OptimizationResult};
+use stkopt_chain::{
+    ChainInfo, PoolRoleUpdate, RewardDestination, UnbondingEntry, encode_ss58, slash_risk_targets,
+    unbonding_schedule,
+};
+use stkopt_core::{
+    AddressBookEntry, BagsListPosition, BondIssue, ClaimPermission, CommissionChangeRate,
+    ConnectionStatus, HealthReport, Network, OptimizationResult, PayoutCheck, PoolAdmin,
+    PoolAdminAction, PoolMemberCall, PoolState, PortfolioAccount, PortfolioSummary, SlashHistory,
+    SlashRiskReport, StakingParams, TransactionFilter, TransactionRecord, TransactionType,
+    TxJournalStatus, VaultAccountQr, assess_slash_risk, check_health, parse_vault_account_qr,
+    perbill_from_percent, points_to_balance, summarize_portfolio,
+};
 use subxt::utils::AccountId32;
 
 /// Known address book entries (name, address).
@@ -186,6 +197,8 @@ pub enum View {
     Nominate,
     Validators,
     Pools,
+    Transactions,
+    Portfolio,
 }
 
 impl View {
@@ -197,6 +210,8 @@ impl View {
             View::Nominate,
             View::Validators,
             View::Pools,
+            View::Transactions,
+            View::Portfolio,
         ]
     }
 
@@ -208,6 +223,8 @@ impl View {
             View::Nominate => "Nominate",
             View::Validators => "Validators",
             View::Pools => "Pools",
+            View::Transactions => "Transactions",
+            View::Portfolio => "Portfolio",
         }
     }
 
@@ -219,6 +236,8 @@ impl View {
             View::Nominate => 3,
             View::Validators => 4,
             View::Pools => 5,
+            View::Transactions => 6,
+            View::Portfolio => 7,
         }
     }
 
@@ -230,6 +249,8 @@ impl View {
             3 => View::Nominate,
             4 => View::Validators,
             5 => View::Pools,
+            6 => View::Transactions,
+            7 => View::Portfolio,
             _ => View::AccountStatus,
         }
     }
@@ -252,6 +273,8 @@ pub struct CameraState {
     pub preview_size: (usize, usize),
     /// QR code bounding box in normalized coordinates (0.0-1.0).
     pub qr_bounds: Option<[(f32, f32); 4]>,
+    /// Whether the camera is scanning a Vault account export instead of a signature.
+    pub account_scan: bool,
 }
 
 /// QR code display and transaction state.
@@ -271,6 +294,10 @@ pub struct QrState {
     pub pending_unsigned: Option<PendingUnsignedTx>,
     /// Pending signed transaction (ready for/in-progress submission).
     pub pending_signed: Option<PendingTransaction>,
+    /// Result of the last QR export (shown in the modal footer).
+    pub export_status: Option<String>,
+    /// When the pending unsigned payload was built (for the expiry countdown).
+    pub pending_built_at: Option<std::time::Instant>,
 }
 
 /// Staking history state.
@@ -278,6 +305,14 @@ pub struct QrState {
 pub struct HistoryState {
     /// Staking history for the watched account.
     pub points: Vec<StakingHistoryPoint>,
+    /// Exact per-era rewards, shown next to the estimated `points`.
+    pub exact_points: Vec<StakingHistoryPoint>,
+    /// Expected payouts matched against `Staking.Rewarded` events.
+    pub payout_checks: Vec<PayoutCheck>,
+    /// Whether a payout check is running.
+    pub checking_payouts: bool,
+    /// Error of the last payout check.
+    pub payout_error: Option<String>,
     /// Whether staking history is currently loading.
     pub loading: bool,
     /// Number of days to load when requesting recent history.
@@ -298,6 +333,191 @@ impl HistoryState {
     }
 }
 
+/// Transaction journal state.
+#[derive(Debug, Default)]
+pub struct JournalState {
+    /// Journaled transactions for the watched account, newest first.
+    pub records: Vec<TransactionRecord>,
+    /// Only show transactions with this status.
+    pub status_filter: Option<TxJournalStatus>,
+    /// Table selection in the filtered list.
+    pub table_state: TableState,
+    /// Result of the last journal export.
+    pub export_status: Option<String>,
+}
+
+impl JournalState {
+    /// Cycle the status filter: all, then each status in turn.
+    pub fn cycle_status_filter(&mut self) {
+        let statuses = TxJournalStatus::all();
+        self.status_filter = match self.status_filter {
+            None => statuses.first().copied(),
+            Some(current) => statuses
+                .iter()
+                .position(|s| *s == current)
+                .and_then(|idx| statuses.get(idx + 1))
+                .copied(),
+        };
+        self.table_state.select(None);
+    }
+}
+
+/// Saved-accounts portfolio state.
+#[derive(Debug, Default)]
+pub struct PortfolioState {
+    /// Status of each saved account on the current network.
+    pub accounts: Vec<PortfolioAccount>,
+    /// Whether the portfolio is loading.
+    pub loading: bool,
+    /// Error from the last load, if it failed.
+    pub error: Option<String>,
+    /// Table selection.
+    pub table_state: TableState,
+}
+
+/// States a pool root or bouncer can set, in `set_state` order.
+pub const POOL_STATES: [PoolState; 3] =
+    [PoolState::Open, PoolState::Blocked, PoolState::Destroying];
+
+/// Administration state for pools in which the watched account holds a role.
+#[derive(Debug, Default)]
+pub struct PoolAdminState {
+    /// Pools the watched account holds a role in.
+    pub pools: Vec<PoolAdmin>,
+    /// Index into `pools`.
+    pub selected_pool: usize,
+    /// Index into the selected pool's available actions.
+    pub selected_action: usize,
+    /// Argument being entered for the chosen action.
+    pub input: String,
+    /// Index into `POOL_STATES` when setting the pool state.
+    pub state_index: usize,
+    /// Why the last entered argument was rejected.
+    pub error: Option<String>,
+    /// Whether the account may create a pool, which members of one cannot.
+    pub can_create: bool,
+}
+
+impl PoolAdminState {
+    pub fn pool(&self) -> Option<&PoolAdmin> {
+        self.pools.get(self.selected_pool)
+    }
+
+    /// Admin calls the account may make in the selected pool, then `Create`.
+    pub fn actions(&self) -> Vec<PoolAdminAction> {
+        let mut actions = self.pool().map(PoolAdmin::actions).unwrap_or_default();
+        if self.can_create {
+            actions.push(PoolAdminAction::Create);
+        }
+        actions
+    }
+
+    pub fn action(&self) -> Option<PoolAdminAction> {
+        self.actions().get(self.selected_action).copied()
+    }
+}
+
+/// Reward call on behalf of another pool member, as entered in the modal.
+#[derive(Debug, Default)]
+pub struct PoolMemberCallState {
+    /// Member address being entered.
+    pub input: String,
+    pub call: PoolMemberCall,
+    /// Why the entered address was rejected.
+    pub error: Option<String>,
+}
+
+/// Parse the argument entered for a pool administration call.
+///
+/// Commission rates are percentages. Roles take three space-separated
+/// entries for root, nominator and bouncer: an address, `-` to keep the
+/// role or `none` to remove it. Creating a pool takes the initial bond,
+/// optionally followed by the three roles where `-` means the signer.
+pub fn parse_pool_admin_input(
+    action: PoolAdminAction,
+    input: &str,
+    state: PoolState,
+    decimals: u8,
+) -> Result<PoolAdminOp, String> {
+    let input = input.trim();
+    let percent = |text: &str| {
+        text.parse::<f64>()
+            .ok()
+            .and_then(perbill_from_percent)
+            .ok_or_else(|| format!("'{}' is not a percentage between 0 and 100", text))
+    };
+    match action {
+        PoolAdminAction::Nominate => Ok(PoolAdminOp::Nominate),
+        PoolAdminAction::ClaimCommission => Ok(PoolAdminOp::ClaimCommission),
+        PoolAdminAction::SetState => Ok(PoolAdminOp::SetState(state)),
+        PoolAdminAction::SetMetadata if input.is_empty() => Err("Enter a pool name".to_string()),
+        PoolAdminAction::SetMetadata => Ok(PoolAdminOp::SetMetadata(input.to_string())),
+        PoolAdminAction::SetCommission if input.is_empty() => Ok(PoolAdminOp::SetCommission(None)),
+        PoolAdminAction::SetCommission => {
+            percent(input).map(|p| PoolAdminOp::SetCommission(Some(p)))
+        }
+        PoolAdminAction::SetCommissionMax => percent(input).map(PoolAdminOp::SetCommissionMax),
+        PoolAdminAction::SetCommissionChangeRate => {
+            let [rate, delay] = input.split_whitespace().collect::<Vec<_>>()[..] else {
+                return Err("Enter a percentage and a block delay".to_string());
+            };
+            let min_delay = delay
+                .parse::<u32>()
+                .map_err(|_| format!("'{}' is not a block count", delay))?;
+            Ok(PoolAdminOp::SetCommissionChangeRate(CommissionChangeRate {
+                max_increase: percent(rate)?,
+                min_delay,
+            }))
+        }
+        PoolAdminAction::UpdateRoles => {
+            let updates = input
+                .split_whitespace()
+                .map(|entry| match entry {
+                    "-" => Ok(PoolRoleUpdate::Keep),
+                    "none" => Ok(PoolRoleUpdate::Remove),
+                    address => <AccountId32 as std::str::FromStr>::from_str(address)
+                        .map(PoolRoleUpdate::Set)
+                        .map_err(|_| format!("Invalid address: {}", address)),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let [root, nominator, bouncer] = <[PoolRoleUpdate; 3]>::try_from(updates)
+                .map_err(|_| "Enter root, nominator and bouncer".to_string())?;
+            Ok(PoolAdminOp::UpdateRoles {
+                root,
+                nominator,
+                bouncer,
+            })
+        }
+        PoolAdminAction::Create => {
+            let mut entries = input.split_whitespace();
+            let amount = entries
+                .next()
+                .ok_or_else(|| "Enter the initial bond".to_string())
+                .and_then(|amount| stkopt_core::parse_token_amount(amount, decimals))?;
+            let roles = entries
+                .map(|entry| match entry {
+                    "-" => Ok(None),
+                    address => <AccountId32 as std::str::FromStr>::from_str(address)
+                        .map(Some)
+                        .map_err(|_| format!("Invalid address: {}", address)),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let [root, nominator, bouncer] = if roles.is_empty() {
+                Default::default()
+            } else {
+                <[Option<AccountId32>; 3]>::try_from(roles)
+                    .map_err(|_| "Enter root, nominator and bouncer, or none".to_string())?
+            };
+            Ok(PoolAdminOp::Create {
+                amount,
+                root,
+                nominator,
+                bouncer,
+            })
+        }
+    }
+}
+
 /// Loading progress and bandwidth state.
 #[derive(Debug, Default)]
 pub struct LoadingState {
@@ -328,6 +548,8 @@ pub struct App {
     pub palette: Palette,
     /// Current network.
     pub network: Network,
+    /// Network defined in config.json, selectable after the built-in ones.
+    pub custom_network: Option<Network>,
     /// Connection status.
     pub connection_status: ConnectionStatus,
     /// Chain info (name, spec version, validation).
@@ -352,8 +574,18 @@ pub struct App {
     pub current_era: Option<u32>,
     /// Era completion percentage.
     pub era_pct_complete: f64,
+    /// Current era start in Unix milliseconds.
+    pub era_start_ms: u64,
     /// Era duration in milliseconds.
     pub era_duration_ms: u64,
+    /// Staking limits of the connected chain.
+    pub staking_params: Option<StakingParams>,
+    /// Slashes and offences on the connected chain.
+    pub slash_history: Option<SlashHistory>,
+    /// Whether the optimizer also skips validators with offence reports.
+    pub exclude_reported: bool,
+    /// The watched account's position in the voter list.
+    pub bags_position: Option<BagsListPosition>,
 
     // === Validators State ===
     /// Display validators (aggregated data).
@@ -410,6 +642,10 @@ pub struct App {
     pub account_panel_focus: usize,
     /// Address book table state.
     pub address_book_state: TableState,
+    /// Saved address book entries for the current network.
+    pub saved_addresses: Vec<AddressBookEntry>,
+    /// Result of the last address book change (e.g. Vault import).
+    pub address_book_status: Option<String>,
     /// Whether to show the account input prompt popup.
     pub show_account_prompt: bool,
     /// Validation error message for account input.
@@ -422,6 +658,12 @@ pub struct App {
     pub camera: CameraState,
     /// Staking history state.
     pub history: HistoryState,
+    /// Transaction journal state.
+    pub journal: JournalState,
+    /// Saved-accounts portfolio state.
+    pub portfolio: PortfolioState,
+    /// Pool administration state.
+    pub pool_admin: PoolAdminState,
     /// Loading progress state.
     pub loading: LoadingState,
 
@@ -438,6 +680,23 @@ pub struct App {
     pub pool_input_amount: String,
     /// Selected pool for join operation.
     pub selected_pool_for_join: Option<usize>,
+    /// Selected pool claim permission.
+    pub claim_permission: ClaimPermission,
+    /// Another pool member's rewards to compound or pay out.
+    pub pool_member_call: PoolMemberCallState,
+}
+
+/// Insert `point` in era order, replacing any point for the same era.
+fn insert_history_point(points: &mut Vec<StakingHistoryPoint>, point: StakingHistoryPoint) {
+    if let Some(existing) = points.iter_mut().find(|p| p.era == point.era) {
+        *existing = point;
+    } else {
+        let pos = points
+            .iter()
+            .position(|p| p.era > point.era)
+            .unwrap_or(points.len());
+        points.insert(pos, point);
+    }
 }
 
 /// Direction for cycling selection.
@@ -484,6 +743,7 @@ impl App {
             theme,
             palette,
             network,
+            custom_network: None,
             connection_status: ConnectionStatus::Disconnected,
             chain_info: None,
             current_view: View::default(),
@@ -497,7 +757,12 @@ impl App {
             // Era state
             current_era: None,
             era_pct_complete: 0.0,
+            era_start_ms: 0,
             era_duration_ms: 0,
+            staking_params: None,
+            slash_history: None,
+            exclude_reported: false,
+            bags_position: None,
 
             // Validators state
             validators: Vec::new(),
@@ -530,6 +795,8 @@ impl App {
             account_input: String::new(),
             account_panel_focus: 0,
             address_book_state: TableState::default(),
+            saved_addresses: Vec::new(),
+            address_book_status: None,
             show_account_prompt: false,
             validation_error: None,
 
@@ -537,6 +804,9 @@ impl App {
             qr: QrState::default(),
             camera: CameraState::default(),
             history: HistoryState::new(),
+            journal: JournalState::default(),
+            portfolio: PortfolioState::default(),
+            pool_admin: PoolAdminState::default(),
             loading: LoadingState {
                 chain: true, // Start in loading state
                 start_time: Some(std::time::Instant::now()),
@@ -549,6 +819,8 @@ impl App {
             rewards_destination: RewardDestination::Staked,
             pool_input_amount: String::new(),
             selected_pool_for_join: None,
+            claim_permission: ClaimPermission::Permissioned,
+            pool_member_call: PoolMemberCallState::default(),
         }
     }
 
@@ -644,6 +916,30 @@ impl App {
         }
     }
 
+    /// Estimated seconds until the pending unsigned payload expires.
+    ///
+    /// `None` for immortal payloads or when nothing is pending.
+    pub fn pending_tx_remaining_secs(&self) -> Option<u64> {
+        let window = self
+            .qr
+            .pending_unsigned
+            .as_ref()?
+            .payload
+            .mortality_window()?;
+        let built_at = self.qr.pending_built_at?;
+        Some(window.remaining_secs(built_at.elapsed().as_secs()))
+    }
+
+    /// Whether the pending payload expired and must be rebuilt before submission.
+    pub fn pending_tx_expired(&self) -> bool {
+        self.pending_tx_remaining_secs() == Some(0)
+            || self
+                .qr
+                .pending_signed
+                .as_ref()
+                .is_some_and(|tx| matches!(tx.status, TxSubmissionStatus::Expired))
+    }
+
     /// Handle keyboard input when QR modal is showing.
     fn handle_qr_modal_key(&mut self, key: KeyEvent) -> Option<Action> {
         match key.code {
@@ -658,10 +954,19 @@ impl App {
                 self.camera.qr_bounds = None;
                 self.qr.pending_unsigned = None;
                 self.qr.pending_signed = None;
+                self.qr.export_status = None;
+                self.qr.pending_built_at = None;
                 if self.camera.scanning {
                     return Some(Action::StopSignatureScan);
                 }
             }
+            KeyCode::Char('r') if self.pending_tx_expired() => {
+                return Some(Action::RebuildPendingTx);
+            }
+            KeyCode::Char('e') if self.qr.data.is_some() => {
+                self.qr.export_status = Some("Exporting QR...".to_string());
+                return Some(Action::ExportQr);
+            }
             KeyCode::Tab | KeyCode::Right => {
                 let max_tab = if self.qr.pending_signed.is_some() {
                     4
@@ -695,7 +1000,11 @@ impl App {
             KeyCode::Char('s') | KeyCode::Enter
                 if self.qr.pending_signed.is_some() && self.qr.modal_tab == 3 =>
             {
-                if let Some(ref tx) = self.qr.pending_signed
+                if self.pending_tx_expired() {
+                    if let Some(ref mut tx) = self.qr.pending_signed {
+                        tx.status = TxSubmissionStatus::Expired;
+                    }
+                } else if let Some(ref tx) = self.qr.pending_signed
                     && matches!(tx.status, TxSubmissionStatus::ReadyToSubmit)
                 {
                     return Some(Action::SubmitTransaction);
@@ -728,6 +1037,18 @@ impl App {
             return self.handle_qr_modal_key(key);
         }
 
+        // Handle Vault account scan overlay
+        if self.camera.account_scan {
+            if matches!(key.code, KeyCode::Esc) {
+                self.camera.account_scan = false;
+                self.camera.status = None;
+                self.camera.preview = None;
+                self.camera.qr_bounds = None;
+                return Some(Action::StopSignatureScan);
+            }
+            return None;
+        }
+
         // Handle help overlay
         if self.showing_help {
             if matches!(key.code, KeyCode::Esc | KeyCode::Char('?') | KeyCode::Enter) {
@@ -749,6 +1070,14 @@ impl App {
             KeyCode::Char('4') => self.current_view = View::Nominate,
             KeyCode::Char('5') => self.current_view = View::Validators,
             KeyCode::Char('6') => self.current_view = View::Pools,
+            KeyCode::Char('7') => {
+                self.current_view = View::Transactions;
+                return self.load_transactions_action();
+            }
+            KeyCode::Char('8') => {
+                self.current_view = View::Portfolio;
+                return self.load_portfolio_action();
+            }
             KeyCode::Char('n') => return self.next_network(),
             KeyCode::Char('a')
                 if self.current_view == View::AccountStatus || self.show_account_prompt =>
@@ -757,6 +1086,17 @@ impl App {
                 self.account_input.clear();
                 self.show_account_prompt = false;
             }
+            KeyCode::Char('v')
+                if (self.current_view == View::AccountStatus || self.show_account_prompt)
+                    && !self.camera.scanning =>
+            {
+                self.show_account_prompt = false;
+                self.address_book_status = None;
+                self.camera.account_scan = true;
+                self.camera.status = Some(CameraScanStatus::Initializing);
+                self.camera.frames_captured = 0;
+                return Some(Action::StartAccountScan);
+            }
             // Account Staking Operations (Now in AccountChanges)
             KeyCode::Char('b')
                 if self.current_view == View::AccountChanges && self.watched_account.is_some() =>
@@ -795,6 +1135,23 @@ impl App {
             {
                 return Some(Action::GenerateChillQR);
             }
+            KeyCode::Char('g')
+                if self.current_view == View::AccountChanges
+                    && self.watched_account.is_some()
+                    && self.bags_position.is_some() =>
+            {
+                return Some(Action::GenerateRebagQR);
+            }
+            KeyCode::Char('f')
+                if self.current_view == View::AccountChanges
+                    && self.watched_account.is_some()
+                    && self
+                        .bags_position
+                        .as_ref()
+                        .is_some_and(BagsListPosition::can_move_up) =>
+            {
+                return Some(Action::GeneratePutInFrontOfQR);
+            }
             // Pool Operations
             KeyCode::Char('j')
                 if self.current_view == View::Pools && self.watched_account.is_some() =>
@@ -831,6 +1188,43 @@ impl App {
             {
                 return Some(Action::GeneratePoolWithdrawQR);
             }
+            KeyCode::Char('P')
+                if self.current_view == View::Pools
+                    && self
+                        .account_status
+                        .as_ref()
+                        .is_some_and(|s| s.pool_membership.is_some()) =>
+            {
+                self.claim_permission = self
+                    .account_status
+                    .as_ref()
+                    .and_then(|s| s.pool_details.as_ref())
+                    .map_or(ClaimPermission::Permissioned, |d| d.claim_permission);
+                self.input_mode = InputMode::Staking;
+                self.staking_input_mode = StakingInputMode::SetClaimPermission;
+            }
+            KeyCode::Char('M')
+                if self.current_view == View::Pools && self.watched_account.is_some() =>
+            {
+                self.pool_member_call = PoolMemberCallState::default();
+                self.input_mode = InputMode::Staking;
+                self.staking_input_mode = StakingInputMode::PoolMemberCall;
+            }
+            KeyCode::Char('A')
+                if self.current_view == View::Pools && self.watched_account.is_some() =>
+            {
+                self.pool_admin.can_create = self
+                    .account_status
+                    .as_ref()
+                    .is_some_and(|s| s.pool_membership.is_none());
+                if self.pool_admin.actions().is_empty() {
+                    return None;
+                }
+                self.pool_admin.selected_action = 0;
+                self.pool_admin.error = None;
+                self.input_mode = InputMode::Staking;
+                self.staking_input_mode = StakingInputMode::PoolAdmin;
+            }
             KeyCode::Char('c') if self.current_view == View::AccountStatus => {
                 return Some(Action::ClearAccount);
             }
@@ -849,6 +1243,9 @@ impl App {
             KeyCode::Char('g') if self.current_view == View::Nominate => {
                 return Some(Action::GenerateNominationQR);
             }
+            KeyCode::Char('x') if self.current_view == View::Nominate => {
+                return Some(Action::ToggleExcludeReported);
+            }
             // History view keys
             KeyCode::Char('l') if self.current_view == View::AccountHistory => {
                 if !self.history.loading && self.watched_account.is_some() {
@@ -860,11 +1257,44 @@ impl App {
                     return Some(Action::CancelLoadingHistory);
                 }
             }
+            KeyCode::Char('p') if self.current_view == View::AccountHistory => {
+                if !self.history.loading
+                    && !self.history.checking_payouts
+                    && !self.history.points.is_empty()
+                {
+                    return Some(Action::CheckPayouts);
+                }
+            }
+            // Transactions view keys
+            KeyCode::Char('f') if self.current_view == View::Transactions => {
+                self.journal.cycle_status_filter();
+            }
+            KeyCode::Char('r') if self.current_view == View::Transactions => {
+                return self.load_transactions_action();
+            }
+            KeyCode::Char('e') if self.current_view == View::Transactions => {
+                if !self.filtered_transactions().is_empty() {
+                    return Some(Action::ExportTransactions);
+                }
+                self.journal.export_status = Some("Nothing to export".to_string());
+            }
+            // Portfolio view keys
+            KeyCode::Char('r') if self.current_view == View::Portfolio => {
+                return self.load_portfolio_action();
+            }
+            KeyCode::Enter if self.current_view == View::Portfolio => {
+                return self.select_portfolio_account();
+            }
             KeyCode::Char('?') => {
                 self.showing_help = true;
             }
             // Search with /
-            KeyCode::Char('/') if matches!(self.current_view, View::Validators | View::Pools) => {
+            KeyCode::Char('/')
+                if matches!(
+                    self.current_view,
+                    View::Validators | View::Pools | View::Transactions
+                ) =>
+            {
                 self.input_mode = InputMode::Searching;
                 self.search_query.clear();
             }
@@ -951,11 +1381,13 @@ impl App {
                 self.search_query.pop();
                 self.validators_cache_dirty = true;
                 self.pools_cache_dirty = true;
+                self.journal.table_state.select(None);
             }
             KeyCode::Char(c) => {
                 self.search_query.push(c);
                 self.validators_cache_dirty = true;
                 self.pools_cache_dirty = true;
+                self.journal.table_state.select(None);
             }
             _ => {}
         }
@@ -1106,7 +1538,173 @@ impl App {
                 }
                 _ => None,
             },
+            StakingInputMode::SetClaimPermission => match key.code {
+                KeyCode::Esc => {
+                    self.input_mode = InputMode::Normal;
+                    self.staking_input_mode = StakingInputMode::None;
+                    None
+                }
+                KeyCode::Char('P') | KeyCode::Right | KeyCode::Left => {
+                    self.claim_permission = self.claim_permission.next();
+                    None
+                }
+                KeyCode::Enter => {
+                    self.input_mode = InputMode::Normal;
+                    self.staking_input_mode = StakingInputMode::None;
+                    Some(Action::GeneratePoolSetClaimPermissionQR {
+                        permission: self.claim_permission,
+                    })
+                }
+                _ => None,
+            },
+            StakingInputMode::PoolAdmin => self.handle_pool_admin_menu_key(key),
+            StakingInputMode::PoolAdminInput => self.handle_pool_admin_input_key(key),
+            StakingInputMode::PoolMemberCall => self.handle_pool_member_call_key(key),
+        }
+    }
+
+    /// Enter another pool member and choose to compound or pay out their rewards.
+    fn handle_pool_member_call_key(&mut self, key: KeyEvent) -> Option<Action> {
+        let state = &mut self.pool_member_call;
+        match key.code {
+            KeyCode::Esc => {
+                self.input_mode = InputMode::Normal;
+                self.staking_input_mode = StakingInputMode::None;
+            }
+            KeyCode::Left | KeyCode::Right | KeyCode::Tab => {
+                state.call = state.call.next();
+            }
+            KeyCode::Enter => {
+                let input = state.input.trim();
+                match <AccountId32 as std::str::FromStr>::from_str(input) {
+                    Ok(member) => {
+                        self.input_mode = InputMode::Normal;
+                        self.staking_input_mode = StakingInputMode::None;
+                        return Some(Action::GeneratePoolMemberCallQR {
+                            member,
+                            call: state.call,
+                        });
+                    }
+                    Err(_) => state.error = Some(format!("Invalid address: {}", input)),
+                }
+            }
+            KeyCode::Backspace => {
+                state.input.pop();
+                state.error = None;
+            }
+            KeyCode::Char(c) => {
+                state.input.push(c);
+                state.error = None;
+            }
+            _ => {}
+        }
+        None
+    }
+
+    /// Choose a pool and the admin call to make in it.
+    fn handle_pool_admin_menu_key(&mut self, key: KeyEvent) -> Option<Action> {
+        let admin = &mut self.pool_admin;
+        match key.code {
+            KeyCode::Esc => {
+                self.input_mode = InputMode::Normal;
+                self.staking_input_mode = StakingInputMode::None;
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                admin.selected_action = admin.selected_action.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                if admin.selected_action + 1 < admin.actions().len() {
+                    admin.selected_action += 1;
+                }
+            }
+            KeyCode::Left | KeyCode::Right if admin.pools.len() > 1 => {
+                let direction = if key.code == KeyCode::Left {
+                    Direction::Previous
+                } else {
+                    Direction::Next
+                };
+                admin.selected_pool =
+                    cycle_selection(Some(admin.selected_pool), admin.pools.len(), direction)
+                        .unwrap_or(0);
+                admin.selected_action = 0;
+            }
+            KeyCode::Enter => {
+                let action = admin.action()?;
+                match action {
+                    PoolAdminAction::Nominate | PoolAdminAction::ClaimCommission => {
+                        return self.confirm_pool_admin();
+                    }
+                    _ => {
+                        admin.input.clear();
+                        admin.error = None;
+                        admin.state_index = admin
+                            .pool()
+                            .and_then(|pool| POOL_STATES.iter().position(|s| *s == pool.state))
+                            .unwrap_or(0);
+                        self.staking_input_mode = StakingInputMode::PoolAdminInput;
+                    }
+                }
+            }
+            _ => {}
+        }
+        None
+    }
+
+    /// Enter the argument of the chosen pool admin call.
+    fn handle_pool_admin_input_key(&mut self, key: KeyEvent) -> Option<Action> {
+        let admin = &mut self.pool_admin;
+        let setting_state = admin.action() == Some(PoolAdminAction::SetState);
+        match key.code {
+            KeyCode::Esc => {
+                admin.error = None;
+                self.staking_input_mode = StakingInputMode::PoolAdmin;
+            }
+            KeyCode::Enter => return self.confirm_pool_admin(),
+            KeyCode::Left | KeyCode::Right if setting_state => {
+                let direction = if key.code == KeyCode::Left {
+                    Direction::Previous
+                } else {
+                    Direction::Next
+                };
+                admin.state_index =
+                    cycle_selection(Some(admin.state_index), POOL_STATES.len(), direction)
+                        .unwrap_or(0);
+            }
+            KeyCode::Backspace => {
+                admin.input.pop();
+            }
+            KeyCode::Char(c) if !setting_state => admin.input.push(c),
+            _ => {}
         }
+        None
+    }
+
+    /// Build the chosen pool admin call, keeping the modal open on bad input.
+    fn confirm_pool_admin(&mut self) -> Option<Action> {
+        let admin = &mut self.pool_admin;
+        let action = admin.action()?;
+        // A new pool has no id until the pallet assigns one
+        let pool_id = match action {
+            PoolAdminAction::Create => 0,
+            _ => admin.pool()?.pool_id,
+        };
+        let op = match parse_pool_admin_input(
+            action,
+            &admin.input,
+            POOL_STATES[admin.state_index % POOL_STATES.len()],
+            self.network.token_decimals(),
+        ) {
+            Ok(op) => op,
+            Err(e) => {
+                admin.error = Some(e);
+                return None;
+            }
+        };
+        admin.input.clear();
+        admin.error = None;
+        self.input_mode = InputMode::Normal;
+        self.staking_input_mode = StakingInputMode::None;
+        Some(Action::GeneratePoolAdminQR { pool_id, op })
     }
 
     /// Confirm staking operation and generate QR.
@@ -1120,6 +1718,14 @@ impl App {
 
         let amount = self.parse_amount(amount_str)?;
 
+        // Keep the modal open on amounts the chain would reject
+        if self
+            .staking_input_issue()
+            .is_some_and(|issue| issue.is_error())
+        {
+            return None;
+        }
+
         // Reset input mode
         self.input_mode = InputMode::Normal;
         let mode = self.staking_input_mode;
@@ -1151,6 +1757,120 @@ impl App {
         }
     }
 
+    /// Problem with the amount being entered, checked against the staking limits.
+    pub fn staking_input_issue(&self) -> Option<BondIssue> {
+        let tx_type = self.staking_input_mode.transaction_type()?;
+        let is_pool = matches!(
+            tx_type,
+            TransactionType::PoolJoin
+                | TransactionType::PoolUnbond
+                | TransactionType::PoolBondExtra
+        );
+        let amount_str = if is_pool {
+            &self.pool_input_amount
+        } else {
+            &self.staking_input_amount
+        };
+        let mut amount = self.parse_amount(amount_str)?;
+        let status = self.account_status.as_ref();
+        let bonded = if is_pool {
+            // Pool stake is held in points, and pool unbonds are in points;
+            // the limits are balances. Unchecked until the pool is loaded.
+            let details = status.and_then(|s| s.pool_details.as_ref());
+            if tx_type == TransactionType::PoolUnbond {
+                amount = details.map_or(0, |d| points_to_balance(amount, d.points, d.balance));
+            }
+            details.map_or(0, |d| d.balance)
+        } else {
+            status
+                .and_then(|s| s.staking_ledger.as_ref())
+                .map_or(0, |l| l.active)
+        };
+        let nominating = status
+            .and_then(|s| s.nominations.as_ref())
+            .is_some_and(|n| !n.targets.is_empty());
+        self.staking_params
+            .as_ref()?
+            .check_bond(tx_type, amount, bonded, nominating)
+    }
+
+    /// Problem with nominating from the watched account's current bond.
+    pub fn nomination_issue(&self) -> Option<BondIssue> {
+        let status = self.account_status.as_ref();
+        let bonded = status
+            .and_then(|s| s.staking_ledger.as_ref())
+            .map_or(0, |l| l.active);
+        let nominating = status
+            .and_then(|s| s.nominations.as_ref())
+            .is_some_and(|n| !n.targets.is_empty());
+        self.staking_params
+            .as_ref()?
+            .check_bond(TransactionType::Nominate, 0, bonded, nominating)
+    }
+
+    /// Projected unlock dates of the watched account's direct and pool chunks.
+    pub fn unbonding_schedule(&self) -> Vec<UnbondingEntry> {
+        let (Some(status), Some(current_era)) = (&self.account_status, self.current_era) else {
+            return Vec::new();
+        };
+        let direct = status
+            .staking_ledger
+            .as_ref()
+            .map_or(&[][..], |l| &l.unlocking[..]);
+        let pool = status.pool_membership.as_ref();
+        unbonding_schedule(
+            direct,
+            pool.map(|m| m.pool_id),
+            pool.map_or(&[][..], |m| &m.unbonding_eras[..]),
+            current_era,
+            self.era_start_ms,
+            self.era_duration_ms,
+        )
+    }
+
+    /// Health check of the watched account, once its live status is loaded.
+    pub fn health_report(&self) -> Option<HealthReport> {
+        let health = self.account_status.as_ref()?.health.as_ref()?;
+        Some(check_health(
+            health,
+            &self.validators,
+            &self.pools,
+            self.staking_params.as_ref(),
+            self.current_era?,
+        ))
+    }
+
+    /// Slash risk of the watched account's nominations, once its live status
+    /// and the network's slash history are loaded.
+    pub fn slash_risk(&self) -> Option<SlashRiskReport> {
+        let history = self.slash_history.as_ref()?;
+        let status = self.account_status.as_ref()?;
+        let nominations = status.nominations.as_ref()?;
+        let bonded = status.staking_ledger.as_ref().map_or(0, |l| l.active);
+        let prefix = self.network.ss58_format();
+        let targets = slash_risk_targets(
+            &nominations.targets,
+            status.exposure.as_ref(),
+            bonded,
+            prefix,
+        )
+        .ok()?;
+        Some(assess_slash_risk(
+            &encode_ss58(&status.address, prefix).ok()?,
+            &targets,
+            history,
+            status.past_slashes.clone()?,
+        ))
+    }
+
+    /// Validators the optimizer must skip for their slash history.
+    pub fn slashed_validators(&self) -> HashSet<String> {
+        self.slash_history
+            .as_ref()
+            .map(|history| history.slashed_validators(self.exclude_reported))
+            .unwrap_or_default()
+    }
+
     /// Parse amount string to u128 (planck) based on network decimals.
     fn parse_amount(&self, input: &str) -> Option<u128> {
         stkopt_core::parse_token_amount(input, self.network.token_decimals()).ok()
@@ -1218,16 +1938,46 @@ impl App {
         <AccountId32 as std::str::FromStr>::from_str(input).map_err(|e| e.to_string())
     }
 
+    /// Parse a scanned Vault account export and check it against the current network.
+    pub fn vault_account_from_qr(&self, data: &[u8]) -> Result<VaultAccountQr, String> {
+        let account = parse_vault_account_qr(data).map_err(|e| e.to_string())?;
+        account
+            .ensure_network(self.network.clone())
+            .map_err(|e| e.to_string())?;
+        <AccountId32 as std::str::FromStr>::from_str(&account.address)
+            .map_err(|e| format!("Invalid address in Vault QR: {}", e))?;
+        stkopt_chain::ensure_ss58_prefix(&account.address, self.network.ss58_format())
+            .map_err(|e| format!("Vault QR for {}: {}", self.network, e))?;
+        Ok(account)
+    }
+
+    /// Warning for a valid address encoded with another network's SS58 prefix.
+    pub fn account_input_warning(&self) -> Option<String> {
+        let input = self.account_input.trim();
+        self.validate_account_input().ok()?;
+        let prefix = stkopt_chain::decode_ss58_prefix(input).ok()?;
+        (prefix != self.network.ss58_format()).then(|| {
+            format!(
+                "Address uses SS58 prefix {}, not the {} prefix {} - check the network",
+                prefix,
+                self.network,
+                self.network.ss58_format()
+            )
+        })
+    }
+
     /// Switch to next view.
     fn next_view(&mut self) -> Option<Action> {
         let views = View::all();
         let current_idx = self.current_view.index();
         let next_idx = (current_idx + 1) % views.len();
         self.current_view = View::from_index(next_idx);
-        if self.current_view == View::AccountHistory {
-            return self.maybe_auto_load_history();
+        match self.current_view {
+            View::AccountHistory => self.maybe_auto_load_history(),
+            View::Transactions => self.load_transactions_action(),
+            View::Portfolio => self.load_portfolio_action(),
+            _ => None,
         }
-        None
     }
 
     /// Switch to previous view.
@@ -1240,10 +1990,38 @@ impl App {
             current_idx - 1
         };
         self.current_view = View::from_index(prev_idx);
-        if self.current_view == View::AccountHistory {
-            return self.maybe_auto_load_history();
+        match self.current_view {
+            View::AccountHistory => self.maybe_auto_load_history(),
+            View::Transactions => self.load_transactions_action(),
+            View::Portfolio => self.load_portfolio_action(),
+            _ => None,
         }
-        None
+    }
+
+    /// Reload the transaction journal when an account is watched.
+    fn load_transactions_action(&self) -> Option<Action> {
+        self.watched_account
+            .as_ref()
+            .map(|_| Action::LoadTransactions)
+    }
+
+    /// Reload the portfolio unless a load is already running.
+    fn load_portfolio_action(&self) -> Option<Action> {
+        (!self.portfolio.loading).then_some(Action::LoadPortfolio)
+    }
+
+    /// Watch the selected portfolio account and show its account view.
+    fn select_portfolio_account(&mut self) -> Option<Action> {
+        let idx = self.portfolio.table_state.selected()?;
+        let address = self.portfolio.accounts.get(idx)?.address.clone();
+        let account = <AccountId32 as std::str::FromStr>::from_str(&address).ok()?;
+        self.current_view = View::AccountStatus;
+        Some(Action::SetWatchedAccount(account, address))
+    }
+
+    /// Totals across the loaded portfolio.
+    pub fn portfolio_summary(&self) -> PortfolioSummary {
+        summarize_portfolio(&self.portfolio.accounts, &self.validators, &self.pools)
     }
 
     /// Auto-load staking history when switching to History view.
@@ -1259,6 +2037,8 @@ impl App {
                     || self.history.loaded_for.as_ref() != Some(&account_str));
             if should_load {
                 self.history.points.clear();
+                self.history.exact_points.clear();
+                self.history.payout_checks.clear();
                 self.history.loading = true;
                 self.history.loaded_for = Some(account_str);
                 return Some(Action::LoadStakingHistory);
@@ -1269,13 +2049,17 @@ impl App {
 
     /// Switch to next network.
     fn next_network(&mut self) -> Option<Action> {
-        let networks = Network::all();
+        let networks: Vec<Network> = Network::builtin()
+            .iter()
+            .cloned()
+            .chain(self.custom_network.clone())
+            .collect();
         let current_idx = networks
             .iter()
             .position(|n| *n == self.network)
             .unwrap_or(0);
         let next_idx = (current_idx + 1) % networks.len();
-        Some(Action::SwitchNetwork(networks[next_idx]))
+        Some(Action::SwitchNetwork(networks[next_idx].clone()))
     }
 
     /// Get tick count for animations.
@@ -1296,10 +2080,35 @@ impl App {
             Action::SetActiveEra(era_info) => {
                 self.current_era = Some(era_info.index);
                 self.era_pct_complete = era_info.pct_complete;
+                self.era_start_ms = era_info.start_timestamp_ms;
             }
             Action::SetEraDuration(duration) => {
                 self.era_duration_ms = duration;
             }
+            Action::SetStakingParams(params) => {
+                self.staking_params = Some(params);
+            }
+            Action::SetSlashHistory(mut history) => {
+                // Offence reports are loaded separately and kept across reloads
+                if history.offences.is_empty()
+                    && let Some(previous) = self.slash_history.take()
+                {
+                    history.offences = previous.offences;
+                }
+                self.slash_history = Some(history);
+            }
+            Action::SetOffenceReports(reports) => {
+                self.slash_history.get_or_insert_default().offences = reports;
+            }
+            Action::SetBagsListPosition(position) => {
+                self.bags_position = position;
+            }
+            Action::SetPoolAdmin(pools) => {
+                self.pool_admin = PoolAdminState {
+                    pools,
+                    ..Default::default()
+                };
+            }
 
             Action::SetDisplayValidators(validators) => {
                 self.validators = validators;
@@ -1336,7 +2145,10 @@ impl App {
             Action::SetWatchedAccount(account, _original) => {
                 self.watched_account = Some(account);
                 self.account_status = None; // Will be fetched
+                self.bags_position = None;
+                self.pool_admin = PoolAdminState::default();
                 self.account_panel_focus = 0; // Move focus back to account status
+                self.journal = JournalState::default();
             }
             Action::SetAccountStatus(status) => {
                 tracing::debug!("SetAccountStatus action received, updating app state");
@@ -1345,11 +2157,18 @@ impl App {
             Action::ClearAccount => {
                 self.watched_account = None;
                 self.account_status = None;
+                self.bags_position = None;
+                self.pool_admin = PoolAdminState::default();
                 self.account_input.clear();
+                self.journal = JournalState::default();
             }
             Action::RunOptimization | Action::RunOptimizationWithStrategy(_) => {
                 // Handled in main.rs
             }
+            Action::ToggleExcludeReported => {
+                // Offence reports are fetched in main.rs when turned on
+                self.exclude_reported = !self.exclude_reported;
+            }
             Action::SetOptimizationResult(result) => {
                 // Select the optimized validators
                 self.selected_validators.clear();
@@ -1409,6 +2228,7 @@ impl App {
                 self.qr.tx_info = tx_info;
                 self.qr.frame = 0; // Reset animation frame for new QR
                 self.qr.modal_tab = 0; // Reset to QR tab
+                self.qr.export_status = None;
                 self.qr.showing = self.qr.data.is_some();
                 if self.qr.showing {
                     self.nomination_status =
@@ -1416,14 +2236,107 @@ impl App {
                 }
             }
             Action::SetPendingUnsignedTx(pending) => {
+                self.qr.pending_built_at = pending.as_ref().map(|_| std::time::Instant::now());
                 self.qr.pending_unsigned = pending;
             }
+            Action::RebuildPendingTx => {
+                // Handled in main.rs - requests a fresh payload from the chain task
+                self.qr.pending_signed = None;
+                self.qr.export_status = Some("Rebuilding transaction...".to_string());
+            }
+            Action::PendingTxRebuildFailed(ref error) => {
+                self.qr.export_status = Some(format!("Rebuild failed: {}", error));
+            }
             Action::StartSignatureScan => {
                 self.camera.scanning = true;
             }
             Action::StopSignatureScan => {
                 self.camera.scanning = false;
             }
+            Action::StartAccountScan => {
+                self.camera.scanning = true;
+            }
+            Action::AccountQrScanned(_) => {
+                // Handled in main.rs - validates and saves to the address book
+                self.camera.scanning = false;
+            }
+            Action::VaultAccountImported(result) => {
+                self.camera.account_scan = false;
+                self.camera.preview = None;
+                self.camera.qr_bounds = None;
+                match result {
+                    Ok(entry) => {
+                        self.address_book_status =
+                            Some(format!("Added {} from Vault", entry.label));
+                        if !self
+                            .saved_addresses
+                            .iter()
+                            .any(|e| e.address == entry.address)
+                        {
+                            self.saved_addresses.push(entry);
+                        }
+                        self.account_panel_focus = 1;
+                        let my_account = usize::from(self.watched_account.is_some());
+                        self.address_book_state
+                            .select(Some(my_account + self.saved_addresses.len() - 1));
+                    }
+                    Err(e) => {
+                        self.camera.status = Some(CameraScanStatus::Error);
+                        self.address_book_status = Some(e);
+                    }
+                }
+            }
+            Action::ExportQr => {
+                // Handled in main.rs - writes the image file
+            }
+            Action::QrExported(result) => {
+                self.qr.export_status = Some(match result {
+                    Ok(path) => format!("Exported to {}", path),
+                    Err(e) => format!("Export failed: {}", e),
+                });
+            }
+            Action::LoadPortfolio => {
+                // Saved accounts are read from the config in main.rs
+                self.portfolio.loading = true;
+                self.portfolio.error = None;
+            }
+            Action::SetPortfolio(result) => {
+                self.portfolio.loading = false;
+                match result {
+                    Ok(accounts) => {
+                        self.portfolio.accounts = accounts;
+                        let len = self.portfolio.accounts.len();
+                        let sel = self.portfolio.table_state.selected();
+                        self.portfolio.table_state.select(match sel {
+                            Some(i) if i < len => Some(i),
+                            _ if len > 0 => Some(0),
+                            _ => None,
+                        });
+                    }
+                    Err(e) => self.portfolio.error = Some(e),
+                }
+            }
+            Action::LoadTransactions | Action::ExportTransactions => {
+                // Handled in main.rs - reads the journal from the database
+            }
+            Action::SetTransactions(records) => {
+                self.journal.records = records;
+                let len = self.filtered_transactions().len();
+                if self
+                    .journal
+                    .table_state
+                    .selected()
+                    .is_some_and(|i| i >= len)
+                {
+                    self.journal.table_state.select(None);
+                }
+            }
+            Action::TransactionsExported(result) => {
+                self.journal.export_status = Some(match result {
+                    Ok(path) => format!("Exported to {}", path),
+                    Err(e) => format!("Export failed: {}", e),
+                });
+            }
             Action::SignatureScanned(_) => {
                 // Handled in main.rs - processes the signature and creates pending_tx
             }
@@ -1431,6 +2344,9 @@ impl App {
                 tracing::error!("QR scan failed: {}", error);
                 self.camera.scanning = false;
                 self.camera.status = Some(CameraScanStatus::Error);
+                if self.camera.account_scan {
+                    self.address_book_status = Some(error.clone());
+                }
                 self.nomination_status = Some(error.clone());
             }
             Action::UpdateScanStatus(status) => {
@@ -1467,22 +2383,10 @@ impl App {
                 self.history.loading = false;
             }
             Action::AddStakingHistoryPoint(point) => {
-                // Check if this era already exists (avoid duplicates)
-                if let Some(existing_pos) =
-                    self.history.points.iter().position(|p| p.era == point.era)
-                {
-                    // Replace existing entry with new data
-                    self.history.points[existing_pos] = point;
-                } else {
-                    // Insert in era order (oldest first)
-                    let pos = self
-                        .history
-                        .points
-                        .iter()
-                        .position(|p| p.era > point.era)
-                        .unwrap_or(self.history.points.len());
-                    self.history.points.insert(pos, point);
-                }
+                insert_history_point(&mut self.history.points, point);
+            }
+            Action::AddExactHistoryPoint(point) => {
+                insert_history_point(&mut self.history.exact_points, point);
             }
             Action::SetHistoryTotalEras(total) => {
                 self.history.total_eras = total.max(1);
@@ -1491,6 +2395,8 @@ impl App {
                 // Manual load request (clear if needed and start loading)
                 if !self.history.loading {
                     self.history.points.clear();
+                    self.history.exact_points.clear();
+                    self.history.payout_checks.clear();
                     self.history.loading = true;
                     if let Some(account) = &self.watched_account {
                         self.history.loaded_for = Some(account.to_string());
@@ -1504,19 +2410,40 @@ impl App {
             Action::HistoryLoadingComplete => {
                 self.history.loading = false;
             }
+            Action::CheckPayouts => {
+                self.history.checking_payouts = true;
+                self.history.payout_error = None;
+                // Actual check is handled in main.rs
+            }
+            Action::SetPayoutChecks(result) => {
+                self.history.checking_payouts = false;
+                match result {
+                    Ok(checks) => self.history.payout_checks = checks,
+                    Err(e) => self.history.payout_error = Some(e),
+                }
+            }
             Action::SwitchNetwork(network) => {
                 self.network = network;
                 self.connection_status = ConnectionStatus::Disconnected;
                 self.current_era = None;
                 self.era_pct_complete = 0.0;
+                self.era_start_ms = 0;
+                self.staking_params = None;
+                self.slash_history = None;
+                self.exclude_reported = false;
                 self.validators.clear();
                 self.validators_cache_dirty = true;
                 self.selected_validators.clear();
                 self.optimization_result = None;
                 self.account_status = None;
+                self.bags_position = None;
+                self.pool_admin = PoolAdminState::default();
                 self.history.points.clear();
+                self.history.exact_points.clear();
+                self.history.payout_checks.clear();
                 self.pools.clear();
                 self.pools_cache_dirty = true;
+                self.portfolio = PortfolioState::default();
             }
             Action::SelectAddressBookEntry(_idx) => {
                 // Handled in main.rs where we have access to the address book entries
@@ -1537,11 +2464,16 @@ impl App {
             | Action::GenerateSetPayeeQR { .. }
             | Action::GenerateWithdrawUnbondedQR
             | Action::GenerateChillQR
+            | Action::GenerateRebagQR
+            | Action::GeneratePutInFrontOfQR
             | Action::GeneratePoolJoinQR { .. }
             | Action::GeneratePoolBondExtraQR { .. }
             | Action::GeneratePoolClaimQR
             | Action::GeneratePoolUnbondQR { .. }
-            | Action::GeneratePoolWithdrawQR => {
+            | Action::GeneratePoolWithdrawQR
+            | Action::GeneratePoolSetClaimPermissionQR { .. }
+            | Action::GeneratePoolAdminQR { .. }
+            | Action::GeneratePoolMemberCallQR { .. } => {
                 // Handled in main.rs
             }
         }
@@ -1550,11 +2482,29 @@ impl App {
     /// Get the number of entries in the address book.
     pub fn address_book_len(&self) -> usize {
         let my_account = if self.watched_account.is_some() { 1 } else { 0 };
-        my_account + KNOWN_ADDRESSES.len()
+        my_account + self.saved_addresses.len() + KNOWN_ADDRESSES.len()
     }
 
-    /// Move selection up in the current list.
-    pub fn select_previous(&mut self) {
+    /// Resolve an address book row to `(label, address)`.
+    ///
+    /// Rows are ordered: My Account (if watched), saved addresses, known addresses.
+    /// Returns `None` for the My Account row and out-of-range indices.
+    pub fn address_book_entry(&self, idx: usize) -> Option<(String, String)> {
+        let idx = if self.watched_account.is_some() {
+            idx.checked_sub(1)?
+        } else {
+            idx
+        };
+        if let Some(entry) = self.saved_addresses.get(idx) {
+            return Some((entry.label.clone(), entry.address.clone()));
+        }
+        KNOWN_ADDRESSES
+            .get(idx - self.saved_addresses.len())
+            .map(|(name, addr)| (name.to_string(), addr.to_string()))
+    }
+
+    /// Move selection up in the current list.
+    pub fn select_previous(&mut self) {
         match self.current_view {
             View::Validators => {
                 let sel = cycle_selection(
@@ -1588,10 +2538,45 @@ impl App {
                 );
                 self.address_book_state.select(sel);
             }
+            View::Transactions => {
+                let sel = cycle_selection(
+                    self.journal.table_state.selected(),
+                    self.filtered_transactions().len(),
+                    Direction::Previous,
+                );
+                self.journal.table_state.select(sel);
+            }
+            View::Portfolio => {
+                let sel = cycle_selection(
+                    self.portfolio.table_state.selected(),
+                    self.portfolio.accounts.len(),
+                    Direction::Previous,
+                );
+                self.portfolio.table_state.select(sel);
+            }
             _ => {}
         }
     }
 
+    /// Filter for the transaction journal view (status and search query).
+    pub fn transaction_filter(&self) -> TransactionFilter {
+        TransactionFilter {
+            status: self.journal.status_filter,
+            search: Some(self.search_query.clone()).filter(|q| !q.is_empty()),
+            ..Default::default()
+        }
+    }
+
+    /// Journaled transactions matching the current filter, newest first.
+    pub fn filtered_transactions(&self) -> Vec<&TransactionRecord> {
+        let filter = self.transaction_filter();
+        self.journal
+            .records
+            .iter()
+            .filter(|r| filter.matches(r))
+            .collect()
+    }
+
     /// Get filtered and sorted validators.
     pub fn filtered_validators(&self) -> Vec<&DisplayValidator> {
         let mut result: Vec<_> = self
@@ -1752,6 +2737,22 @@ impl App {
                 );
                 self.address_book_state.select(sel);
             }
+            View::Transactions => {
+                let sel = cycle_selection(
+                    self.journal.table_state.selected(),
+                    self.filtered_transactions().len(),
+                    Direction::Next,
+                );
+                self.journal.table_state.select(sel);
+            }
+            View::Portfolio => {
+                let sel = cycle_selection(
+                    self.portfolio.table_state.selected(),
+                    self.portfolio.accounts.len(),
+                    Direction::Next,
+                );
+                self.portfolio.table_state.select(sel);
+            }
             _ => {}
         }
     }
@@ -1763,6 +2764,7 @@ mod tests {
     use crate::log_buffer::{LogLevel, LogLine};
     use crossterm::event::{KeyEventKind, KeyEventState, KeyModifiers};
     use stkopt_chain::{AccountBalance, UnsignedPayload};
+    use stkopt_core::PayoutStatus;
     use stkopt_core::optimizer::ValidatorCandidate;
     use stkopt_core::types::PoolState;
 
@@ -1793,6 +2795,18 @@ mod tests {
         assert!(app.validate_account_input().is_ok());
     }
 
+    #[test]
+    fn test_account_input_warning_on_prefix_mismatch() {
+        let mut app = create_app();
+        app.account_input = "HNZata7iMYWmk5RvZRTiAsSDhV8366zq2YGb3tLH5Upf74F".to_string();
+        let warning = app.account_input_warning().unwrap();
+        assert!(warning.contains("prefix 2"), "{warning}");
+        app.account_input = "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5".to_string();
+        assert!(app.account_input_warning().is_none());
+        app.account_input = "ab".to_string();
+        assert!(app.account_input_warning().is_none());
+    }
+
     #[test]
     fn test_validate_account_input_invalid_chars_rejected() {
         let mut app = create_app();
@@ -1909,7 +2923,7 @@ mod tests {
     #[test]
     fn test_view_all() {
         let all = View::all();
-        assert_eq!(all.len(), 6);
+        assert_eq!(all.len(), 8);
         assert!(all.contains(&View::AccountStatus));
     }
 
@@ -1931,6 +2945,13 @@ mod tests {
         assert_eq!(View::from_index(5), View::Pools);
     }
 
+    #[test]
+    fn test_view_transactions_index_round_trip() {
+        assert_eq!(View::Transactions.index(), 6);
+        assert_eq!(View::from_index(6), View::Transactions);
+        assert_eq!(View::Transactions.label(), "Transactions");
+    }
+
     #[test]
     fn test_view_from_index_out_of_bounds() {
         assert_eq!(View::from_index(99), View::AccountStatus);
@@ -2199,6 +3220,119 @@ mod tests {
         assert_eq!(app.address_book_len(), KNOWN_ADDRESSES.len() + 1);
     }
 
+    fn vault_entry(label: &str, address: &str) -> AddressBookEntry {
+        AddressBookEntry {
+            address: address.to_string(),
+            label: label.to_string(),
+            network: stkopt_core::NetworkConfig::Polkadot,
+            notes: None,
+            created_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_address_book_len_with_saved_addresses() {
+        let mut app = create_app();
+        app.saved_addresses.push(vault_entry("Vault", "addr"));
+        assert_eq!(app.address_book_len(), KNOWN_ADDRESSES.len() + 1);
+    }
+
+    #[test]
+    fn test_address_book_entry_order() {
+        let mut app = create_app();
+        app.watched_account = Some(AccountId32::from([0u8; 32]));
+        app.saved_addresses.push(vault_entry("Vault", "addr"));
+        assert_eq!(app.address_book_entry(0), None);
+        assert_eq!(
+            app.address_book_entry(1),
+            Some(("Vault".to_string(), "addr".to_string()))
+        );
+        let (name, addr) = KNOWN_ADDRESSES[0];
+        assert_eq!(
+            app.address_book_entry(2),
+            Some((name.to_string(), addr.to_string()))
+        );
+        assert_eq!(app.address_book_entry(app.address_book_len()), None);
+    }
+
+    // === Vault account import ===
+
+    const ALICE: &str = "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5";
+
+    #[test]
+    fn test_vault_account_from_qr() {
+        let app = create_app();
+        let qr = format!(
+            "substrate:{}:{}",
+            ALICE,
+            Network::Polkadot.relay_genesis_hash()
+        );
+        let account = app.vault_account_from_qr(qr.as_bytes()).unwrap();
+        assert_eq!(account.address, ALICE);
+    }
+
+    #[test]
+    fn test_vault_account_from_qr_wrong_network() {
+        let app = create_app();
+        let qr = format!(
+            "substrate:{}:{}",
+            ALICE,
+            Network::Kusama.relay_genesis_hash()
+        );
+        let err = app.vault_account_from_qr(qr.as_bytes()).unwrap_err();
+        assert!(err.contains("Kusama"));
+    }
+
+    #[test]
+    fn test_vault_account_from_qr_wrong_prefix() {
+        let app = create_app();
+        let qr = format!(
+            "substrate:HNZata7iMYWmk5RvZRTiAsSDhV8366zq2YGb3tLH5Upf74F:{}",
+            Network::Polkadot.relay_genesis_hash()
+        );
+        let err = app.vault_account_from_qr(qr.as_bytes()).unwrap_err();
+        assert!(err.contains("prefix 2"), "{err}");
+    }
+
+    #[test]
+    fn test_vault_account_from_qr_bad_checksum() {
+        let app = create_app();
+        let qr = format!(
+            "substrate:5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQZ:{}",
+            Network::Polkadot.relay_genesis_hash()
+        );
+        assert!(app.vault_account_from_qr(qr.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_handle_action_vault_account_imported() {
+        let mut app = create_app();
+        app.camera.account_scan = true;
+        app.handle_action(Action::VaultAccountImported(Ok(vault_entry(
+            "Vault", ALICE,
+        ))));
+        assert!(!app.camera.account_scan);
+        assert_eq!(app.saved_addresses.len(), 1);
+        assert_eq!(app.account_panel_focus, 1);
+        assert_eq!(app.address_book_state.selected(), Some(0));
+        assert_eq!(
+            app.address_book_status,
+            Some("Added Vault from Vault".to_string())
+        );
+    }
+
+    #[test]
+    fn test_handle_action_vault_account_import_failed() {
+        let mut app = create_app();
+        app.camera.account_scan = true;
+        app.handle_action(Action::VaultAccountImported(Err(
+            "wrong network".to_string()
+        )));
+        assert!(!app.camera.account_scan);
+        assert!(app.saved_addresses.is_empty());
+        assert_eq!(app.address_book_status, Some("wrong network".to_string()));
+    }
+
     // === App::filtered_validators ===
 
     #[test]
@@ -2545,13 +3679,13 @@ mod tests {
     fn test_prev_view() {
         let mut app = create_app();
         app.prev_view();
-        assert_eq!(app.current_view, View::Pools);
+        assert_eq!(app.current_view, View::Portfolio);
     }
 
     #[test]
     fn test_next_view_wraps() {
         let mut app = create_app();
-        for _ in 0..6 {
+        for _ in 0..View::all().len() {
             app.next_view();
         }
         assert_eq!(app.current_view, View::AccountStatus);
@@ -2560,7 +3694,7 @@ mod tests {
     #[test]
     fn test_prev_view_wraps() {
         let mut app = create_app();
-        for _ in 0..6 {
+        for _ in 0..View::all().len() {
             app.prev_view();
         }
         assert_eq!(app.current_view, View::AccountStatus);
@@ -2774,9 +3908,14 @@ mod tests {
                 reserved: 0,
                 frozen: 0,
             },
+            balance_breakdown: None,
             staking_ledger: None,
             nominations: None,
             pool_membership: None,
+            exposure: None,
+            health: None,
+            past_slashes: None,
+            pool_details: None,
         });
         app.handle_action(Action::ClearAccount);
         assert!(app.watched_account.is_none());
@@ -2927,6 +4066,7 @@ mod tests {
                 tx_version: 0,
                 nonce: 0,
                 era: stkopt_chain::Era::Immortal,
+                birth_block: None,
                 include_metadata_hash: false,
                 use_asset_payment: false,
                 extension_ids: vec![],
@@ -2989,6 +4129,7 @@ mod tests {
             signed_extrinsic: vec![],
             tx_hash: [0u8; 32],
             status: TxSubmissionStatus::ReadyToSubmit,
+            journal_id: None,
         });
         app.handle_action(Action::SetTxStatus(TxSubmissionStatus::Submitting));
         assert!(
@@ -3006,6 +4147,7 @@ mod tests {
             signed_extrinsic: vec![],
             tx_hash: [0u8; 32],
             status: TxSubmissionStatus::ReadyToSubmit,
+            journal_id: None,
         });
         app.qr.pending_unsigned = Some(PendingUnsignedTx {
             payload: UnsignedPayload {
@@ -3018,6 +4160,7 @@ mod tests {
                 tx_version: 0,
                 nonce: 0,
                 era: stkopt_chain::Era::Immortal,
+                birth_block: None,
                 include_metadata_hash: false,
                 use_asset_payment: false,
                 extension_ids: vec![],
@@ -3073,6 +4216,50 @@ mod tests {
         assert_eq!(app.history.points[1].era, 10);
     }
 
+    #[test]
+    fn test_handle_action_add_exact_history_point_kept_apart() {
+        let mut app = create_app();
+        app.handle_action(Action::AddStakingHistoryPoint(
+            StakingHistoryPoint::new_without_date(5, 100, 1000, 0.15),
+        ));
+        app.handle_action(Action::AddExactHistoryPoint(
+            StakingHistoryPoint::new_without_date(5, 90, 1000, 0.14),
+        ));
+        assert_eq!(app.history.points[0].reward, 100);
+        assert_eq!(app.history.exact_points.len(), 1);
+        assert_eq!(app.history.exact_points[0].reward, 90);
+    }
+
+    #[test]
+    fn test_check_payouts_key_and_result() {
+        let mut app = create_app();
+        app.current_view = View::AccountHistory;
+        assert!(app.handle_normal_key(key_char('p')).is_none());
+
+        app.history.points = vec![StakingHistoryPoint::new_without_date(5, 100, 1000, 0.15)];
+        let action = app.handle_normal_key(key_char('p'));
+        assert!(matches!(action, Some(Action::CheckPayouts)));
+        app.handle_action(Action::CheckPayouts);
+        assert!(app.history.checking_payouts);
+        assert!(app.handle_normal_key(key_char('p')).is_none());
+
+        let check = PayoutCheck {
+            era: 5,
+            validator: "val".to_string(),
+            expected: 100,
+            received: 0,
+            status: PayoutStatus::Missing,
+        };
+        app.handle_action(Action::SetPayoutChecks(Ok(vec![check])));
+        assert!(!app.history.checking_payouts);
+        assert_eq!(app.history.payout_checks.len(), 1);
+
+        app.handle_action(Action::SetPayoutChecks(Err("offline".to_string())));
+        assert_eq!(app.history.payout_error.as_deref(), Some("offline"));
+        app.handle_action(Action::LoadStakingHistory);
+        assert!(app.history.payout_checks.is_empty());
+    }
+
     #[test]
     fn test_handle_action_load_staking_history() {
         let mut app = create_app();
@@ -3124,9 +4311,14 @@ mod tests {
                 reserved: 0,
                 frozen: 0,
             },
+            balance_breakdown: None,
             staking_ledger: None,
             nominations: None,
             pool_membership: None,
+            exposure: None,
+            health: None,
+            past_slashes: None,
+            pool_details: None,
         });
         app.history
             .points
@@ -3503,6 +4695,560 @@ mod tests {
         assert!(action.is_none());
     }
 
+    /// Status of an account nominating one validator with `active` bonded.
+    fn nominating_status(active: u128) -> AccountStatus {
+        AccountStatus {
+            address: AccountId32::from([1u8; 32]),
+            balance: stkopt_chain::AccountBalance {
+                free: 0,
+                reserved: 0,
+                frozen: 0,
+            },
+            balance_breakdown: None,
+            staking_ledger: Some(stkopt_chain::StakingLedger {
+                stash: AccountId32::from([1u8; 32]),
+                total: active,
+                active,
+                unlocking: Vec::new(),
+            }),
+            nominations: Some(stkopt_chain::NominatorInfo {
+                targets: vec![AccountId32::from([2u8; 32])],
+                submitted_in: 1,
+            }),
+            pool_membership: None,
+            exposure: None,
+            health: None,
+            past_slashes: None,
+            pool_details: None,
+        }
+    }
+
+    #[test]
+    fn test_confirm_staking_operation_rejects_bond_below_minimum() {
+        let mut app = create_app();
+        app.handle_action(Action::SetStakingParams(StakingParams {
+            min_nominator_bond: 250_000_000_000,
+            ..Default::default()
+        }));
+        app.account_status = Some(nominating_status(0));
+        app.input_mode = InputMode::Staking;
+        app.staking_input_mode = StakingInputMode::BondExtra;
+        app.staking_input_amount = "1".to_string();
+        assert!(matches!(
+            app.staking_input_issue(),
+            Some(BondIssue::BelowMinNominatorBond { .. })
+        ));
+        assert!(app.confirm_staking_operation().is_none());
+        // The modal stays open for the amount to be corrected
+        assert_eq!(app.staking_input_mode, StakingInputMode::BondExtra);
+        assert_eq!(app.staking_input_amount, "1");
+
+        app.staking_input_amount = "25".to_string();
+        assert!(app.staking_input_issue().is_none());
+        assert!(app.confirm_staking_operation().is_some());
+    }
+
+    #[test]
+    fn test_staking_input_issue_warns_bond_before_nominating() {
+        let mut app = create_app();
+        app.staking_params = Some(StakingParams {
+            min_nominator_bond: 250_000_000_000,
+            ..Default::default()
+        });
+        app.staking_input_mode = StakingInputMode::Bond;
+        app.staking_input_amount = "1".to_string();
+        assert!(matches!(
+            app.staking_input_issue(),
+            Some(BondIssue::TooSmallToNominate { .. })
+        ));
+        assert!(app.confirm_staking_operation().is_some());
+    }
+
+    #[test]
+    fn test_nomination_issue() {
+        let mut app = create_app();
+        app.staking_params = Some(StakingParams {
+            min_nominator_bond: 250_000_000_000,
+            max_nominators: Some(10),
+            nominator_count: 10,
+            ..Default::default()
+        });
+        // Existing nominators are not blocked by the cap
+        app.account_status = Some(nominating_status(300_000_000_000));
+        assert!(app.nomination_issue().is_none());
+        app.account_status = Some(AccountStatus {
+            nominations: None,
+            ..nominating_status(300_000_000_000)
+        });
+        assert_eq!(
+            app.nomination_issue(),
+            Some(BondIssue::NominatorsFull { max: 10 })
+        );
+        app.account_status = Some(nominating_status(100_000_000_000));
+        assert!(matches!(
+            app.nomination_issue(),
+            Some(BondIssue::BelowMinNominatorBond { .. })
+        ));
+    }
+
+    #[test]
+    fn test_confirm_staking_operation_allows_bond_below_active_stake() {
+        let mut app = create_app();
+        app.staking_params = Some(StakingParams {
+            min_active_stake: 250_000_000_000,
+            ..Default::default()
+        });
+        app.account_status = Some(nominating_status(0));
+        app.staking_input_mode = StakingInputMode::BondExtra;
+        app.staking_input_amount = "1".to_string();
+        assert!(app.staking_input_issue().is_some_and(|i| !i.is_error()));
+        assert!(app.confirm_staking_operation().is_some());
+    }
+
+    #[test]
+    fn test_staking_input_issue_converts_pool_points() {
+        let mut app = create_app();
+        app.staking_params = Some(StakingParams {
+            min_join_bond: 100_000_000_000,
+            ..Default::default()
+        });
+        // 40 points worth 20 tokens: unbonding 30 points leaves 5 tokens
+        app.account_status = Some(AccountStatus {
+            pool_details: Some(stkopt_core::PoolMemberDetails {
+                pool_id: 1,
+                points: 400_000_000_000,
+                balance: 200_000_000_000,
+                unbonding: Vec::new(),
+                claim_permission: ClaimPermission::PermissionlessAll,
+                pending_rewards: 0,
+            }),
+            ..nominating_status(0)
+        });
+        app.staking_input_mode = StakingInputMode::PoolUnbond;
+        app.pool_input_amount = "30".to_string();
+        assert_eq!(
+            app.staking_input_issue(),
+            Some(BondIssue::RemainderBelowMinimum {
+                remaining: 50_000_000_000,
+                minimum: 100_000_000_000,
+            })
+        );
+        app.pool_input_amount = "10".to_string();
+        assert!(app.staking_input_issue().is_none());
+    }
+
+    #[test]
+    fn test_unbonding_schedule_merges_direct_and_pool_chunks() {
+        let mut app = create_app();
+        app.account_status = Some(AccountStatus {
+            address: AccountId32::from([1u8; 32]),
+            balance: stkopt_chain::AccountBalance {
+                free: 0,
+                reserved: 0,
+                frozen: 0,
+            },
+            balance_breakdown: None,
+            staking_ledger: Some(stkopt_chain::StakingLedger {
+                stash: AccountId32::from([1u8; 32]),
+                total: 30,
+                active: 0,
+                unlocking: vec![stkopt_chain::UnlockChunk {
+                    value: 30,
+                    era: 105,
+                }],
+            }),
+            nominations: None,
+            pool_membership: Some(stkopt_chain::PoolMembership {
+                pool_id: 4,
+                points: 0,
+                unbonding_eras: vec![(99, 10)],
+                last_recorded_reward_counter: 0,
+            }),
+            exposure: None,
+            health: None,
+            past_slashes: None,
+            pool_details: None,
+        });
+        assert!(app.unbonding_schedule().is_empty());
+
+        app.current_era = Some(100);
+        app.era_start_ms = 1_000;
+        app.era_duration_ms = 10;
+        let schedule = app.unbonding_schedule();
+        assert_eq!(schedule.len(), 2);
+        assert_eq!(schedule[0].source, stkopt_chain::UnbondingSource::Pool(4));
+        assert!(schedule[0].is_withdrawable());
+        assert_eq!(schedule[1].remaining_eras, 5);
+        assert_eq!(schedule[1].unlock_ms, 1_050);
+    }
+
+    #[test]
+    fn test_health_report_uses_staking_params() {
+        let mut app = create_app();
+        assert!(app.health_report().is_none());
+        app.account_status = Some(AccountStatus {
+            address: AccountId32::from([1u8; 32]),
+            balance: stkopt_chain::AccountBalance {
+                free: 0,
+                reserved: 0,
+                frozen: 0,
+            },
+            balance_breakdown: None,
+            staking_ledger: None,
+            nominations: None,
+            pool_membership: None,
+            exposure: None,
+            health: Some(stkopt_core::HealthAccount {
+                bonded: 100,
+                ..Default::default()
+            }),
+            past_slashes: None,
+            pool_details: None,
+        });
+        app.current_era = Some(100);
+        let report = app.health_report().unwrap();
+        assert_eq!(report.issues, vec![stkopt_core::HealthIssue::NotNominating]);
+
+        app.staking_params = Some(StakingParams {
+            min_active_stake: 200,
+            ..Default::default()
+        });
+        assert_eq!(app.health_report().unwrap().issues.len(), 2);
+    }
+
+    #[test]
+    fn test_slash_risk_and_optimizer_exclusion() {
+        let mut app = create_app();
+        let validator = AccountId32::from([2u8; 32]);
+        let prefix = app.network.ss58_format();
+        let validator_ss58 = encode_ss58(&validator, prefix).unwrap();
+        app.account_status = Some(AccountStatus {
+            address: AccountId32::from([1u8; 32]),
+            balance: stkopt_chain::AccountBalance {
+                free: 0,
+                reserved: 0,
+                frozen: 0,
+            },
+            balance_breakdown: None,
+            staking_ledger: Some(stkopt_chain::StakingLedger {
+                stash: AccountId32::from([1u8; 32]),
+                total: 1_000,
+                active: 1_000,
+                unlocking: Vec::new(),
+            }),
+            nominations: Some(stkopt_chain::NominatorInfo {
+                targets: vec![validator],
+                submitted_in: 1,
+            }),
+            pool_membership: None,
+            exposure: None,
+            health: None,
+            past_slashes: Some(Vec::new()),
+            pool_details: None,
+        });
+        assert!(app.slash_risk().is_none());
+        assert!(app.slashed_validators().is_empty());
+
+        app.handle_action(Action::SetSlashHistory(stkopt_core::SlashHistory {
+            validators: vec![stkopt_core::ValidatorSlash {
+                era: 5,
+                validator: validator_ss58.clone(),
+                fraction: 100_000_000,
+                amount: 10,
+            }],
+            ..Default::default()
+        }));
+        let report = app.slash_risk().unwrap();
+        assert_eq!(report.targets[0].validator, validator_ss58);
+        assert_eq!(report.total_estimated_loss(), 100);
+        assert!(app.slashed_validators().contains(&validator_ss58));
+    }
+
+    #[test]
+    fn test_exclude_reported_is_opt_in() {
+        let mut app = create_app();
+        app.current_view = View::Nominate;
+        app.handle_action(Action::SetOffenceReports(vec![
+            stkopt_core::OffenceReport {
+                offender: "reported".to_string(),
+                reporters: 1,
+            },
+        ]));
+        // A slash history reload keeps the loaded reports
+        app.handle_action(Action::SetSlashHistory(stkopt_core::SlashHistory::default()));
+        assert!(app.slashed_validators().is_empty());
+
+        let action = app.handle_key(KeyEvent::from(KeyCode::Char('x')));
+        assert!(matches!(action, Some(Action::ToggleExcludeReported)));
+        app.handle_action(Action::ToggleExcludeReported);
+        assert!(app.slashed_validators().contains("reported"));
+    }
+
+    #[test]
+    fn test_set_claim_permission_cycles_and_generates_qr() {
+        let mut app = create_app();
+        app.current_view = View::Pools;
+        app.watched_account = Some(AccountId32::from([1u8; 32]));
+        app.handle_key(key_code(KeyCode::Char('P')));
+        assert_eq!(app.input_mode, InputMode::Normal);
+
+        app.account_status = Some(AccountStatus {
+            address: AccountId32::from([1u8; 32]),
+            balance: stkopt_chain::AccountBalance {
+                free: 0,
+                reserved: 0,
+                frozen: 0,
+            },
+            balance_breakdown: None,
+            staking_ledger: None,
+            nominations: None,
+            pool_membership: Some(stkopt_chain::PoolMembership {
+                pool_id: 7,
+                points: 100,
+                unbonding_eras: Vec::new(),
+                last_recorded_reward_counter: 0,
+            }),
+            exposure: None,
+            health: None,
+            past_slashes: None,
+            pool_details: Some(stkopt_core::PoolMemberDetails {
+                pool_id: 7,
+                points: 100,
+                balance: 90,
+                unbonding: Vec::new(),
+                claim_permission: ClaimPermission::PermissionlessCompound,
+                pending_rewards: 0,
+            }),
+        });
+        app.handle_key(key_code(KeyCode::Char('P')));
+        assert_eq!(app.staking_input_mode, StakingInputMode::SetClaimPermission);
+        assert_eq!(
+            app.claim_permission,
+            ClaimPermission::PermissionlessCompound
+        );
+
+        app.handle_key(key_code(KeyCode::Right));
+        assert!(matches!(
+            app.handle_key(key_code(KeyCode::Enter)),
+            Some(Action::GeneratePoolSetClaimPermissionQR {
+                permission: ClaimPermission::PermissionlessWithdraw
+            })
+        ));
+        assert_eq!(app.input_mode, InputMode::Normal);
+    }
+
+    #[test]
+    fn test_pool_admin_requires_role_and_generates_qr() {
+        let mut app = create_app();
+        app.current_view = View::Pools;
+        app.watched_account = Some(AccountId32::from([1u8; 32]));
+        app.handle_key(key_code(KeyCode::Char('A')));
+        assert_eq!(app.input_mode, InputMode::Normal);
+
+        app.handle_action(Action::SetPoolAdmin(vec![PoolAdmin {
+            pool_id: 7,
+            name: Some("Test Pool".to_string()),
+            state: PoolState::Open,
+            roles: vec![stkopt_core::PoolRole::Bouncer],
+            commission: stkopt_core::PoolCommission::default(),
+        }]));
+        app.handle_key(key_code(KeyCode::Char('A')));
+        assert_eq!(app.staking_input_mode, StakingInputMode::PoolAdmin);
+        assert_eq!(
+            app.pool_admin.actions(),
+            vec![PoolAdminAction::SetState, PoolAdminAction::SetMetadata]
+        );
+
+        app.handle_key(key_code(KeyCode::Enter));
+        assert_eq!(app.staking_input_mode, StakingInputMode::PoolAdminInput);
+        app.handle_key(key_code(KeyCode::Right));
+        assert!(matches!(
+            app.handle_key(key_code(KeyCode::Enter)),
+            Some(Action::GeneratePoolAdminQR {
+                pool_id: 7,
+                op: PoolAdminOp::SetState(PoolState::Blocked)
+            })
+        ));
+        assert_eq!(app.input_mode, InputMode::Normal);
+
+        app.handle_action(Action::ClearAccount);
+        assert!(app.pool_admin.pools.is_empty());
+    }
+
+    #[test]
+    fn test_pool_admin_create_generates_qr() {
+        let mut app = create_app();
+        app.current_view = View::Pools;
+        app.watched_account = Some(AccountId32::from([1u8; 32]));
+        app.account_status = Some(nominating_status(0));
+        app.handle_key(key_code(KeyCode::Char('A')));
+        assert_eq!(app.staking_input_mode, StakingInputMode::PoolAdmin);
+        assert_eq!(app.pool_admin.actions(), vec![PoolAdminAction::Create]);
+
+        app.handle_key(key_code(KeyCode::Enter));
+        assert_eq!(app.staking_input_mode, StakingInputMode::PoolAdminInput);
+        for c in "20".chars() {
+            app.handle_key(key_code(KeyCode::Char(c)));
+        }
+        let action = app.handle_key(key_code(KeyCode::Enter));
+        let Some(Action::GeneratePoolAdminQR { pool_id: 0, op }) = action else {
+            panic!("expected a pool admin QR, got {:?}", action);
+        };
+        assert_eq!(
+            op,
+            PoolAdminOp::Create {
+                amount: 20 * 10u128.pow(app.network.token_decimals() as u32),
+                root: None,
+                nominator: None,
+                bouncer: None,
+            }
+        );
+
+        // Pool members cannot create another pool
+        app.account_status = Some(AccountStatus {
+            pool_membership: Some(stkopt_chain::PoolMembership {
+                pool_id: 7,
+                points: 1,
+                last_recorded_reward_counter: 0,
+                unbonding_eras: Vec::new(),
+            }),
+            ..nominating_status(0)
+        });
+        app.handle_key(key_code(KeyCode::Char('A')));
+        assert_eq!(app.input_mode, InputMode::Normal);
+    }
+
+    #[test]
+    fn test_pool_member_call_generates_qr() {
+        let mut app = create_app();
+        app.current_view = View::Pools;
+        app.watched_account = Some(AccountId32::from([1u8; 32]));
+        app.handle_key(key_code(KeyCode::Char('M')));
+        assert_eq!(app.staking_input_mode, StakingInputMode::PoolMemberCall);
+
+        for c in "nope".chars() {
+            app.handle_key(key_code(KeyCode::Char(c)));
+        }
+        assert!(app.handle_key(key_code(KeyCode::Enter)).is_none());
+        assert!(app.pool_member_call.error.is_some());
+
+        let member = AccountId32::from([3u8; 32]);
+        app.pool_member_call.input = member.to_string();
+        app.handle_key(key_code(KeyCode::Right));
+        let action = app.handle_key(key_code(KeyCode::Enter));
+        let Some(Action::GeneratePoolMemberCallQR {
+            member: entered,
+            call,
+        }) = action
+        else {
+            panic!("expected a pool member call QR, got {:?}", action);
+        };
+        assert_eq!(entered, member);
+        assert_eq!(call, PoolMemberCall::PayOut);
+        assert_eq!(app.input_mode, InputMode::Normal);
+    }
+
+    #[test]
+    fn test_parse_pool_admin_input() {
+        assert_eq!(
+            parse_pool_admin_input(PoolAdminAction::SetCommission, "5", PoolState::Open, 10),
+            Ok(PoolAdminOp::SetCommission(Some(50_000_000)))
+        );
+        assert_eq!(
+            parse_pool_admin_input(PoolAdminAction::SetCommission, "", PoolState::Open, 10),
+            Ok(PoolAdminOp::SetCommission(None))
+        );
+        assert!(
+            parse_pool_admin_input(
+                PoolAdminAction::SetCommissionMax,
+                "150",
+                PoolState::Open,
+                10
+            )
+            .is_err()
+        );
+        assert_eq!(
+            parse_pool_admin_input(
+                PoolAdminAction::SetCommissionChangeRate,
+                "1 14400",
+                PoolState::Open,
+                10
+            ),
+            Ok(PoolAdminOp::SetCommissionChangeRate(CommissionChangeRate {
+                max_increase: 10_000_000,
+                min_delay: 14_400,
+            }))
+        );
+        let root = AccountId32::from([2u8; 32]);
+        assert_eq!(
+            parse_pool_admin_input(
+                PoolAdminAction::UpdateRoles,
+                &format!("{} - none", root),
+                PoolState::Open,
+                10
+            ),
+            Ok(PoolAdminOp::UpdateRoles {
+                root: PoolRoleUpdate::Set(root),
+                nominator: PoolRoleUpdate::Keep,
+                bouncer: PoolRoleUpdate::Remove,
+            })
+        );
+        assert!(
+            parse_pool_admin_input(PoolAdminAction::UpdateRoles, "- -", PoolState::Open, 10)
+                .is_err()
+        );
+        assert_eq!(
+            parse_pool_admin_input(
+                PoolAdminAction::Create,
+                &format!("1.5 {} - -", root),
+                PoolState::Open,
+                10
+            ),
+            Ok(PoolAdminOp::Create {
+                amount: 15_000_000_000,
+                root: Some(root),
+                nominator: None,
+                bouncer: None,
+            })
+        );
+        assert!(
+            parse_pool_admin_input(PoolAdminAction::Create, "1 -", PoolState::Open, 10).is_err()
+        );
+    }
+
+    #[test]
+    fn test_bags_list_keys_follow_position() {
+        let mut app = create_app();
+        app.current_view = View::AccountChanges;
+        app.watched_account = Some(AccountId32::from([1u8; 32]));
+        assert!(app.handle_key(key_code(KeyCode::Char('g'))).is_none());
+
+        let mut position = BagsListPosition {
+            score: 10,
+            current_score: 30,
+            bag_upper: 20,
+            expected_bag: 40,
+            rank: Some(2),
+            lighter_ahead: None,
+        };
+        app.handle_action(Action::SetBagsListPosition(Some(position.clone())));
+        assert!(matches!(
+            app.handle_key(key_code(KeyCode::Char('g'))),
+            Some(Action::GenerateRebagQR)
+        ));
+        assert!(app.handle_key(key_code(KeyCode::Char('f'))).is_none());
+
+        position.lighter_ahead = Some("lighter".to_string());
+        app.handle_action(Action::SetBagsListPosition(Some(position)));
+        assert!(matches!(
+            app.handle_key(key_code(KeyCode::Char('f'))),
+            Some(Action::GeneratePutInFrontOfQR)
+        ));
+
+        app.handle_action(Action::ClearAccount);
+        assert!(app.bags_position.is_none());
+    }
+
     // === maybe_auto_load_history ===
 
     #[test]
@@ -3612,8 +5358,8 @@ mod tests {
     fn test_handle_normal_key_prev_view() {
         let mut app = create_app();
         let action = app.handle_normal_key(key_code(KeyCode::BackTab));
-        assert!(action.is_none());
-        assert_eq!(app.current_view, View::Pools);
+        assert!(matches!(action, Some(Action::LoadPortfolio)));
+        assert_eq!(app.current_view, View::Portfolio);
     }
 
     #[test]
@@ -4087,6 +5833,86 @@ mod tests {
         assert_eq!(app.qr.modal_tab, 2);
     }
 
+    #[test]
+    fn test_handle_normal_key_v_starts_account_scan() {
+        let mut app = create_app();
+        app.current_view = View::AccountStatus;
+        let action = app.handle_normal_key(key_code(KeyCode::Char('v')));
+        assert!(matches!(action, Some(Action::StartAccountScan)));
+        assert!(app.camera.account_scan);
+        assert_eq!(app.camera.status, Some(CameraScanStatus::Initializing));
+    }
+
+    #[test]
+    fn test_handle_normal_key_v_ignored_outside_account_view() {
+        let mut app = create_app();
+        app.current_view = View::Validators;
+        let action = app.handle_normal_key(key_code(KeyCode::Char('v')));
+        assert!(action.is_none());
+        assert!(!app.camera.account_scan);
+    }
+
+    #[test]
+    fn test_handle_normal_key_esc_cancels_account_scan() {
+        let mut app = create_app();
+        app.camera.account_scan = true;
+        app.camera.scanning = true;
+        let action = app.handle_normal_key(key_code(KeyCode::Esc));
+        assert!(matches!(action, Some(Action::StopSignatureScan)));
+        assert!(!app.camera.account_scan);
+        // Other keys are swallowed while the scanner is open
+        app.camera.account_scan = true;
+        assert!(
+            app.handle_normal_key(key_code(KeyCode::Char('c')))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_handle_qr_modal_key_e_exports() {
+        let mut app = create_app();
+        app.qr.showing = true;
+        app.qr.data = Some(vec![1, 2, 3]);
+        let action = app.handle_qr_modal_key(key_code(KeyCode::Char('e')));
+        assert!(matches!(action, Some(Action::ExportQr)));
+        assert!(app.qr.export_status.is_some());
+    }
+
+    #[test]
+    fn test_handle_qr_modal_key_e_without_data() {
+        let mut app = create_app();
+        app.qr.showing = true;
+        app.qr.data = None;
+        let action = app.handle_qr_modal_key(key_code(KeyCode::Char('e')));
+        assert!(action.is_none());
+        assert!(app.qr.export_status.is_none());
+    }
+
+    #[test]
+    fn test_handle_action_qr_exported() {
+        let mut app = create_app();
+        app.handle_action(Action::QrExported(Ok("/tmp/tx.gif".to_string())));
+        assert_eq!(
+            app.qr.export_status,
+            Some("Exported to /tmp/tx.gif".to_string())
+        );
+        app.handle_action(Action::QrExported(Err("disk full".to_string())));
+        assert_eq!(
+            app.qr.export_status,
+            Some("Export failed: disk full".to_string())
+        );
+    }
+
+    #[test]
+    fn test_handle_qr_modal_key_esc_clears_export_status() {
+        let mut app = create_app();
+        app.qr.showing = true;
+        app.qr.data = Some(vec![1]);
+        app.qr.export_status = Some("Exported to x.png".to_string());
+        app.handle_qr_modal_key(key_code(KeyCode::Esc));
+        assert!(app.qr.export_status.is_none());
+    }
+
     #[test]
     fn test_handle_qr_modal_key_s_start_scan() {
         let mut app = create_app();
@@ -4102,6 +5928,7 @@ mod tests {
                 spec_version: 0,
                 tx_version: 0,
                 era: stkopt_chain::Era::Immortal,
+                birth_block: None,
                 include_metadata_hash: false,
                 use_asset_payment: false,
                 extension_ids: vec![],
@@ -4126,12 +5953,89 @@ mod tests {
             signed_extrinsic: vec![],
             tx_hash: [0u8; 32],
             status: TxSubmissionStatus::ReadyToSubmit,
+            journal_id: None,
         });
         app.qr.modal_tab = 3;
         let action = app.handle_qr_modal_key(key_code(KeyCode::Enter));
         assert!(matches!(action, Some(Action::SubmitTransaction)));
     }
 
+    fn mortal_pending_tx(period: u64) -> PendingUnsignedTx {
+        PendingUnsignedTx {
+            payload: UnsignedPayload {
+                call_data: vec![],
+                description: "test".to_string(),
+                metadata_hash: [0u8; 32],
+                genesis_hash: [0u8; 32],
+                block_hash: [0u8; 32],
+                nonce: 0,
+                spec_version: 0,
+                tx_version: 0,
+                era: stkopt_chain::Era::Mortal {
+                    period,
+                    phase: 100 % period,
+                },
+                birth_block: Some(100),
+                include_metadata_hash: false,
+                use_asset_payment: false,
+                extension_ids: vec![],
+            },
+            signer: AccountId32::from([0u8; 32]),
+        }
+    }
+
+    #[test]
+    fn test_pending_tx_countdown() {
+        let mut app = create_app();
+        assert_eq!(app.pending_tx_remaining_secs(), None);
+        app.handle_action(Action::SetPendingUnsignedTx(Some(mortal_pending_tx(64))));
+        assert!(app.qr.pending_built_at.is_some());
+        let remaining = app.pending_tx_remaining_secs().unwrap();
+        assert!(remaining > 380 && remaining <= 384);
+        assert!(!app.pending_tx_expired());
+
+        app.handle_action(Action::SetPendingUnsignedTx(None));
+        assert!(app.qr.pending_built_at.is_none());
+    }
+
+    #[test]
+    fn test_expired_pending_tx_blocks_submit_and_offers_rebuild() {
+        let mut app = create_app();
+        app.qr.showing = true;
+        app.qr.pending_unsigned = Some(mortal_pending_tx(4));
+        app.qr.pending_built_at =
+            Some(std::time::Instant::now() - std::time::Duration::from_secs(60));
+        app.qr.pending_signed = Some(PendingTransaction {
+            signed_extrinsic: vec![],
+            tx_hash: [0u8; 32],
+            status: TxSubmissionStatus::ReadyToSubmit,
+            journal_id: None,
+        });
+        app.qr.modal_tab = 3;
+        assert!(app.pending_tx_expired());
+
+        let action = app.handle_qr_modal_key(key_code(KeyCode::Enter));
+        assert!(action.is_none());
+        assert!(matches!(
+            app.qr.pending_signed.as_ref().unwrap().status,
+            TxSubmissionStatus::Expired
+        ));
+
+        let action = app.handle_qr_modal_key(key_char('r'));
+        assert!(matches!(action, Some(Action::RebuildPendingTx)));
+        app.handle_action(Action::RebuildPendingTx);
+        assert!(app.qr.pending_signed.is_none());
+        assert!(app.qr.export_status.is_some());
+    }
+
+    #[test]
+    fn test_rebuild_key_ignored_while_valid() {
+        let mut app = create_app();
+        app.qr.showing = true;
+        app.handle_action(Action::SetPendingUnsignedTx(Some(mortal_pending_tx(64))));
+        assert!(app.handle_qr_modal_key(key_char('r')).is_none());
+    }
+
     // === handle_qr_tab_change ===
 
     #[test]
@@ -4149,6 +6053,7 @@ mod tests {
                 spec_version: 0,
                 tx_version: 0,
                 era: stkopt_chain::Era::Immortal,
+                birth_block: None,
                 include_metadata_hash: false,
                 use_asset_payment: false,
                 extension_ids: vec![],
@@ -4182,4 +6087,197 @@ mod tests {
         let action = app.handle_qr_tab_change();
         assert!(action.is_none());
     }
+
+    // === Transaction journal ===
+
+    fn make_journal_record(
+        id: i64,
+        description: &str,
+        status: TxJournalStatus,
+    ) -> TransactionRecord {
+        TransactionRecord {
+            id,
+            network: Network::Polkadot,
+            signer: "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5".to_string(),
+            description: description.to_string(),
+            call_data: "0x0700".to_string(),
+            nonce: id as u64,
+            mortality: stkopt_core::TxMortality::Immortal,
+            tx_hash: format!("0x{:064x}", id),
+            block_hash: None,
+            status,
+            dispatch_result: None,
+            submitted_at: "2025-01-01 00:00:00".to_string(),
+            completed_at: None,
+        }
+    }
+
+    fn app_with_journal() -> App {
+        let mut app = create_app();
+        app.watched_account = Some(AccountId32::from([1u8; 32]));
+        app.handle_action(Action::SetTransactions(vec![
+            make_journal_record(3, "Nominate 16 validators", TxJournalStatus::Submitted),
+            make_journal_record(2, "Unbond 5 DOT", TxJournalStatus::Failed),
+            make_journal_record(1, "Bond 10 DOT", TxJournalStatus::Finalized),
+        ]));
+        app.current_view = View::Transactions;
+        app
+    }
+
+    #[test]
+    fn test_key_7_opens_transactions_and_loads() {
+        let mut app = create_app();
+        assert!(app.handle_key(key_code(KeyCode::Char('7'))).is_none());
+        assert_eq!(app.current_view, View::Transactions);
+
+        app.watched_account = Some(AccountId32::from([1u8; 32]));
+        app.current_view = View::Pools;
+        let action = app.handle_key(key_code(KeyCode::Tab));
+        assert_eq!(app.current_view, View::Transactions);
+        assert!(matches!(action, Some(Action::LoadTransactions)));
+        assert!(matches!(
+            app.handle_key(key_code(KeyCode::Char('r'))),
+            Some(Action::LoadTransactions)
+        ));
+    }
+
+    fn portfolio_account(address: &AccountId32, bonded: u128) -> PortfolioAccount {
+        PortfolioAccount {
+            address: address.to_string(),
+            bonded,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_key_8_opens_portfolio_and_loads() {
+        let mut app = create_app();
+        let action = app.handle_key(key_code(KeyCode::Char('8')));
+        assert_eq!(app.current_view, View::Portfolio);
+        assert!(matches!(action, Some(Action::LoadPortfolio)));
+
+        app.handle_action(Action::LoadPortfolio);
+        assert!(app.portfolio.loading);
+        assert!(app.handle_key(key_code(KeyCode::Char('r'))).is_none());
+    }
+
+    #[test]
+    fn test_portfolio_enter_opens_account() {
+        let mut app = create_app();
+        let first = AccountId32::from([1u8; 32]);
+        let second = AccountId32::from([2u8; 32]);
+        app.current_view = View::Portfolio;
+        app.handle_action(Action::LoadPortfolio);
+        app.handle_action(Action::SetPortfolio(Ok(vec![
+            portfolio_account(&first, 100),
+            portfolio_account(&second, 300),
+        ])));
+        assert!(!app.portfolio.loading);
+        assert_eq!(app.portfolio.table_state.selected(), Some(0));
+        assert_eq!(app.portfolio_summary().total_bonded, 400);
+
+        app.handle_key(key_code(KeyCode::Down));
+        let action = app.handle_key(key_code(KeyCode::Enter));
+        assert_eq!(app.current_view, View::AccountStatus);
+        match action {
+            Some(Action::SetWatchedAccount(account, address)) => {
+                assert_eq!(account, second);
+                assert_eq!(address, second.to_string());
+            }
+            other => panic!("unexpected action: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_portfolio_load_error() {
+        let mut app = create_app();
+        app.handle_action(Action::LoadPortfolio);
+        app.handle_action(Action::SetPortfolio(Err("offline".to_string())));
+        assert!(!app.portfolio.loading);
+        assert_eq!(app.portfolio.error.as_deref(), Some("offline"));
+    }
+
+    #[test]
+    fn test_journal_status_filter_cycles() {
+        let mut app = app_with_journal();
+        assert_eq!(app.filtered_transactions().len(), 3);
+
+        app.handle_key(key_code(KeyCode::Char('f')));
+        assert_eq!(app.journal.status_filter, Some(TxJournalStatus::Submitted));
+        assert_eq!(app.filtered_transactions().len(), 1);
+
+        app.handle_key(key_code(KeyCode::Char('f')));
+        assert_eq!(app.journal.status_filter, Some(TxJournalStatus::InBlock));
+        assert!(app.filtered_transactions().is_empty());
+
+        app.handle_key(key_code(KeyCode::Char('f')));
+        app.handle_key(key_code(KeyCode::Char('f')));
+        assert_eq!(app.journal.status_filter, Some(TxJournalStatus::Failed));
+        assert_eq!(app.filtered_transactions()[0].id, 2);
+
+        app.handle_key(key_code(KeyCode::Char('f')));
+        assert_eq!(app.journal.status_filter, None);
+        assert_eq!(app.filtered_transactions().len(), 3);
+    }
+
+    #[test]
+    fn test_journal_search_filters_transactions() {
+        let mut app = app_with_journal();
+        app.handle_key(key_code(KeyCode::Char('/')));
+        assert_eq!(app.input_mode, InputMode::Searching);
+        for c in "bond".chars() {
+            app.handle_key(key_code(KeyCode::Char(c)));
+        }
+        let ids: Vec<_> = app.filtered_transactions().iter().map(|r| r.id).collect();
+        assert_eq!(ids, vec![2, 1]);
+    }
+
+    #[test]
+    fn test_journal_navigation_and_export() {
+        let mut app = app_with_journal();
+        app.handle_key(key_code(KeyCode::Down));
+        assert_eq!(app.journal.table_state.selected(), Some(0));
+        app.handle_key(key_code(KeyCode::Up));
+        assert_eq!(app.journal.table_state.selected(), Some(2));
+
+        assert!(matches!(
+            app.handle_key(key_code(KeyCode::Char('e'))),
+            Some(Action::ExportTransactions)
+        ));
+        app.handle_action(Action::TransactionsExported(Ok("/tmp/tx.csv".to_string())));
+        assert_eq!(
+            app.journal.export_status.as_deref(),
+            Some("Exported to /tmp/tx.csv")
+        );
+    }
+
+    #[test]
+    fn test_journal_export_with_nothing_to_export() {
+        let mut app = create_app();
+        app.current_view = View::Transactions;
+        assert!(app.handle_key(key_code(KeyCode::Char('e'))).is_none());
+        assert_eq!(
+            app.journal.export_status.as_deref(),
+            Some("Nothing to export")
+        );
+    }
+
+    #[test]
+    fn test_set_transactions_clears_stale_selection() {
+        let mut app = app_with_journal();
+        app.journal.table_state.select(Some(2));
+        app.handle_action(Action::SetTransactions(vec![make_journal_record(
+            1,
+            "Bond",
+            TxJournalStatus::Finalized,
+        )]));
+        assert_eq!(app.journal.table_state.selected(), None);
+    }
+
+    #[test]
+    fn test_clear_account_resets_journal() {
+        let mut app = app_with_journal();
+        app.handle_action(Action::ClearAccount);
+        assert!(app.journal.records.is_empty());
+    }
 }
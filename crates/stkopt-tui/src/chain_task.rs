@@ -1,7 +1,7 @@
 //! Chain background task for blockchain operations.
 
 use crate::action::{
-    AccountStatus, Action, DisplayValidator, PendingUnsignedTx, StakingHistoryPoint,
+    AccountStatus, Action, DisplayValidator, PendingUnsignedTx, PoolAdminOp, StakingHistoryPoint,
     TransactionInfo,
 };
 use crate::db;
@@ -9,15 +9,19 @@ use color_eyre::Result;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use stkopt_chain::{
-    AccountBalance, ChainClient, ConnectionConfig, DisplayValidatorEnrichment, NominatorInfo,
-    PoolEnrichmentOutcome, PoolMembership, RewardDestination, StakingLedger, UnlockChunk,
-    ValidatorEnrichmentOutcome, basic_display_pools, basic_display_validators,
-    eras_for_lookback_days, fetch_and_enrich_pools, fetch_and_enrich_validators, pool_metadata_map,
-    staking_history_point, validator_apy_map,
+    AccountBalance, ChainClient, ChainSubscription, ConnectionConfig, DEFAULT_PAYOUT_SCAN_BLOCKS,
+    DisplayValidatorEnrichment, HistoryEraSource, IndexedValidatorApySource, IndexerClient,
+    NominatorExposure, NominatorInfo, PoolEnrichmentOutcome, PoolMembership, RewardDestination,
+    StakingLedger, SubscriptionUpdate, UnlockChunk, ValidatorEnrichmentOutcome,
+    basic_display_pools, basic_display_validators, encode_ss58, eras_for_lookback_days,
+    exact_history_point, fetch_and_enrich_pools, fetch_and_enrich_validators, pool_metadata_map,
+    slash_risk_targets, staking_history_point, validator_apy_map,
 };
 use stkopt_core::{
-    AccountStatusService, CachePolicy, CachedAccountStatus, CachedChainMetadata, ConnectionStatus,
-    HistoryService, Network, StartupDataService,
+    AccountStatusService, CachePolicy, CachedAccountStatus, CachedChainMetadata, ClaimPermission,
+    ConnectionStatus, EndpointHealth, EraInfo, HistoryService, Network, PayoutCheck, PayoutScan,
+    PoolMemberCall, Severity, StakingParams, StartupDataService, assess_slash_risk, check_health,
+    format_token_balance, reconcile_payouts,
 };
 use subxt::utils::AccountId32;
 use tokio::sync::mpsc;
@@ -26,6 +30,7 @@ use futures::stream::{self, StreamExt};
 use std::future::Future;
 
 const PEOPLE_READY_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(60);
+const SUBSCRIPTION_CHANNEL_CAPACITY: usize = 16;
 
 pub(crate) fn cached_validators_have_chain_data(validators: &[DisplayValidator]) -> bool {
     validators.iter().any(|validator| validator.total_stake > 0)
@@ -65,6 +70,13 @@ pub enum StakingOp {
     Chill {
         signer: AccountId32,
     },
+    Rebag {
+        signer: AccountId32,
+    },
+    PutInFrontOf {
+        signer: AccountId32,
+        lighter: AccountId32,
+    },
 
     // Pool operations
     PoolJoin {
@@ -86,6 +98,28 @@ pub enum StakingOp {
     PoolWithdraw {
         signer: AccountId32,
     },
+    PoolSetClaimPermission {
+        signer: AccountId32,
+        permission: ClaimPermission,
+    },
+    /// Compound or pay out another member's rewards, as their claim permission allows.
+    PoolMemberCall {
+        signer: AccountId32,
+        member: AccountId32,
+        call: PoolMemberCall,
+    },
+
+    // Pool administration
+    PoolNominate {
+        signer: AccountId32,
+        pool_id: u32,
+        targets: Vec<AccountId32>,
+    },
+    PoolAdmin {
+        signer: AccountId32,
+        pool_id: u32,
+        op: PoolAdminOp,
+    },
 }
 
 /// Unified request type for all chain operations.
@@ -93,6 +127,10 @@ pub enum StakingOp {
 pub enum ChainRequest {
     /// Fetch account data.
     FetchAccount(AccountId32),
+    /// Fetch the status of saved accounts (address, label).
+    FetchPortfolio(Vec<(AccountId32, Option<String>)>),
+    /// Fetch offence reports from the relay chain.
+    FetchOffenceReports,
     /// Generate QR code for nomination.
     GenerateNominationQR {
         signer: AccountId32,
@@ -104,8 +142,19 @@ pub enum ChainRequest {
         lookback_days: u32,
         cancel_rx: tokio::sync::watch::Receiver<bool>,
     },
+    /// Reconcile expected rewards for an era range against payout events.
+    CheckPayouts {
+        account: AccountId32,
+        start_era: u32,
+        end_era: u32,
+    },
     /// Execute a staking operation (generates QR).
     ExecuteStakingOp(StakingOp),
+    /// Rebuild an expired payload with a fresh nonce, block hash and era.
+    RefreshPayload {
+        pending: PendingUnsignedTx,
+        targets: Vec<String>,
+    },
     /// Submit a signed transaction.
     SubmitTransaction(Vec<u8>),
     /// Reconnect to a different network.
@@ -161,12 +210,116 @@ async fn send_staking_qr(
         .await;
 }
 
+/// Build the payload of a pool administration call other than `nominate`.
+async fn create_pool_admin_payload(
+    client: &ChainClient,
+    signer: &AccountId32,
+    pool_id: u32,
+    op: &PoolAdminOp,
+    use_mortal_era: bool,
+) -> Result<stkopt_chain::UnsignedPayload, stkopt_chain::ChainError> {
+    match op {
+        PoolAdminOp::Nominate => Err(stkopt_chain::ChainError::InvalidData(
+            "Pool nominations need validator targets".to_string(),
+        )),
+        PoolAdminOp::SetState(state) => {
+            client
+                .create_pool_set_state_payload(signer, pool_id, *state, use_mortal_era)
+                .await
+        }
+        PoolAdminOp::SetMetadata(name) => {
+            client
+                .create_pool_set_metadata_payload(signer, pool_id, name.as_bytes(), use_mortal_era)
+                .await
+        }
+        PoolAdminOp::SetCommission(rate) => {
+            // Commission is paid to the root setting it
+            client
+                .create_pool_set_commission_payload(
+                    signer,
+                    pool_id,
+                    rate.map(|perbill| (perbill, signer)),
+                    use_mortal_era,
+                )
+                .await
+        }
+        PoolAdminOp::SetCommissionMax(max) => {
+            client
+                .create_pool_set_commission_max_payload(signer, pool_id, *max, use_mortal_era)
+                .await
+        }
+        PoolAdminOp::SetCommissionChangeRate(change_rate) => {
+            client
+                .create_pool_set_commission_change_rate_payload(
+                    signer,
+                    pool_id,
+                    *change_rate,
+                    use_mortal_era,
+                )
+                .await
+        }
+        PoolAdminOp::ClaimCommission => {
+            client
+                .create_pool_claim_commission_payload(signer, pool_id, use_mortal_era)
+                .await
+        }
+        PoolAdminOp::UpdateRoles {
+            root,
+            nominator,
+            bouncer,
+        } => {
+            client
+                .create_pool_update_roles_payload(
+                    signer,
+                    pool_id,
+                    root,
+                    nominator,
+                    bouncer,
+                    use_mortal_era,
+                )
+                .await
+        }
+        PoolAdminOp::Create {
+            amount,
+            root,
+            nominator,
+            bouncer,
+        } => {
+            // The pallet assigns the new pool's id, so `pool_id` is unused
+            client
+                .create_pool_create_payload(
+                    signer,
+                    *amount,
+                    root.as_ref().unwrap_or(signer),
+                    nominator.as_ref().unwrap_or(signer),
+                    bouncer.as_ref().unwrap_or(signer),
+                    use_mortal_era,
+                )
+                .await
+        }
+    }
+}
+
 /// Clear QR data and pending transaction info from the UI.
 async fn clear_staking_qr(action_tx: &mpsc::Sender<Action>) {
     let _ = action_tx.send(Action::SetPendingUnsignedTx(None)).await;
     let _ = action_tx.send(Action::SetQRData(None, None)).await;
 }
 
+/// Staking indexer for history the light client cannot serve; `None` in RPC mode.
+fn history_indexer(client: &ChainClient, network: Network) -> Option<IndexerClient> {
+    if !client.is_light_client() {
+        return None;
+    }
+    match IndexerClient::new(network) {
+        Ok(indexer) => Some(indexer),
+        Err(e) => {
+            tracing::warn!("Staking indexer unavailable: {}", e);
+            None
+        }
+    }
+}
+
 /// Helper to attempt reconnection.
 async fn try_reconnect(client: &ChainClient, max_attempts: u32) -> Option<ChainClient> {
     for attempt in 1..=max_attempts {
@@ -174,6 +327,7 @@ async fn try_reconnect(client: &ChainClient, max_attempts: u32) -> Option<ChainC
         match client.reconnect().await {
             Ok(new_client) => {
                 tracing::info!("Reconnected successfully!");
+                save_endpoint_health(&new_client);
                 return Some(new_client);
             }
             Err(e) => {
@@ -185,6 +339,32 @@ async fn try_reconnect(client: &ChainClient, max_attempts: u32) -> Option<ChainC
     None
 }
 
+/// Endpoint health persisted for `network`, used to rank its RPC endpoints.
+fn load_endpoint_health(network: Network) -> Vec<EndpointHealth> {
+    db::HistoryDb::open(&prepare_db_path())
+        .and_then(|db| db.get_endpoint_health(network))
+        .unwrap_or_else(|e| {
+            tracing::warn!("Failed to load endpoint health: {}", e);
+            Vec::new()
+        })
+}
+
+/// Persist the endpoint health gathered while connecting.
+fn save_endpoint_health(client: &ChainClient) {
+    let result = db::HistoryDb::open(&prepare_db_path())
+        .and_then(|mut db| db.set_endpoint_health(client.network(), &client.endpoint_health()));
+    if let Err(e) = result {
+        tracing::warn!("Failed to save endpoint health: {}", e);
+    }
+}
+
+/// Save the light client's known peers to dial them first next start.
+async fn save_light_client_peers(client: &ChainClient) {
+    if let Err(e) = client.save_light_client_peers().await {
+        tracing::warn!("Failed to save light-client peers: {}", e);
+    }
+}
+
 async fn connect_ready_people_client(
     client: &ChainClient,
     network: Network,
@@ -242,6 +422,7 @@ fn account_status_from_cache(address: AccountId32, status: &CachedAccountStatus)
             reserved: status.reserved_balance,
             frozen: status.frozen_balance,
         },
+        balance_breakdown: None,
         staking_ledger: (status.staked_amount > 0 || !unlocking.is_empty()).then_some(
             StakingLedger {
                 stash: address.clone(),
@@ -260,6 +441,10 @@ fn account_status_from_cache(address: AccountId32, status: &CachedAccountStatus)
             unbonding_eras,
             last_recorded_reward_counter: status.pool_last_recorded_reward_counter,
         }),
+        exposure: None,
+        health: None,
+        past_slashes: None,
+        pool_details: None,
     }
 }
 
@@ -386,10 +571,388 @@ where
     Some(point)
 }
 
+/// Load exact per-era rewards for `account` over `start_era..=end_era`.
+///
+/// Cached rows are sent first. Missing eras are computed from the paged
+/// exposure of the account's current nominations and cached; eras whose
+/// exposure the chain no longer keeps are skipped.
+#[allow(clippy::too_many_arguments)]
+async fn load_exact_history(
+    client: &ChainClient,
+    db: Option<&mut db::HistoryDb>,
+    network: Network,
+    account: &AccountId32,
+    (start_era, end_era): (u32, u32),
+    (current_era, current_era_start_ms, era_duration_ms): (u32, u64, u64),
+    user_bonded: u128,
+    cancel_rx: &tokio::sync::watch::Receiver<bool>,
+    action_tx: &mpsc::Sender<Action>,
+) {
+    let address = account.to_string();
+    // The database is not `Sync`, so read it before awaiting anything
+    let (cached, missing) = match &db {
+        Some(db) => (
+            HistoryService::load_exact_range(
+                db,
+                network.clone(),
+                &address,
+                start_era,
+                end_era,
+                current_era,
+                current_era_start_ms,
+                era_duration_ms,
+            )
+            .unwrap_or_default(),
+            HistoryService::missing_exact_eras(db, network.clone(), &address, start_era, end_era)
+                .unwrap_or_else(|_| (start_era..=end_era).collect()),
+        ),
+        None => (Vec::new(), (start_era..=end_era).collect()),
+    };
+    for point in cached {
+        let _ = action_tx.send(Action::AddExactHistoryPoint(point)).await;
+    }
+
+    let targets = match client.get_nominations(account).await {
+        Ok(Some(nominations)) => nominations.targets,
+        Ok(None) => return,
+        Err(e) => {
+            tracing::warn!("Failed to get nominations for exact rewards: {}", e);
+            return;
+        }
+    };
+    if missing.is_empty() || targets.is_empty() {
+        return;
+    }
+
+    let targets = &targets;
+    let fetched = stream::iter(missing)
+        .map(|era| {
+            let cancel_rx = cancel_rx.clone();
+            async move {
+                if *cancel_rx.borrow() {
+                    return None;
+                }
+                match client.get_exact_era_reward(account, targets, era).await {
+                    Ok(reward) => reward.map(|reward| {
+                        exact_history_point(
+                            era,
+                            current_era,
+                            current_era_start_ms,
+                            era_duration_ms,
+                            reward,
+                            user_bonded,
+                        )
+                    }),
+                    Err(e) => {
+                        tracing::debug!("No exact reward for era {}: {}", era, e);
+                        None
+                    }
+                }
+            }
+        })
+        .buffer_unordered(8)
+        .collect::<Vec<_>>()
+        .await;
+
+    if *cancel_rx.borrow() {
+        return;
+    }
+
+    let mut points: Vec<StakingHistoryPoint> = fetched.into_iter().flatten().collect();
+    points.sort_by_key(|point| point.era);
+    for point in &points {
+        let _ = action_tx
+            .send(Action::AddExactHistoryPoint(point.clone()))
+            .await;
+    }
+
+    if let Some(db) = db
+        && !points.is_empty()
+    {
+        if let Err(e) = db.insert_exact_history_batch(network, &address, &points) {
+            tracing::warn!("Failed to cache exact history: {}", e);
+        } else {
+            tracing::info!("Cached {} exact history points", points.len());
+        }
+    }
+}
+
+/// Match the expected per-validator rewards of `account` over
+/// `start_era..=end_era` against its payout events.
+///
+/// New events come from the network's staking indexer in either connection
+/// mode. Without one, or if it fails, they come from a scan of the blocks
+/// since the last scan, at most [`DEFAULT_PAYOUT_SCAN_BLOCKS`] back. Events
+/// are cached so coverage grows with each check; claimed payouts are only
+/// reported missing for eras paid out within the scanned blocks.
+async fn check_payouts(
+    client: &ChainClient,
+    indexer: Option<&IndexerClient>,
+    network: Network,
+    account: &AccountId32,
+    (start_era, end_era): (u32, u32),
+) -> Result<Vec<PayoutCheck>, stkopt_chain::ChainError> {
+    let address = account.to_string();
+    let mut db = match db::HistoryDb::open(&prepare_db_path()) {
+        Ok(db) => Some(db),
+        Err(e) => {
+            tracing::warn!("Failed to open history database: {}", e);
+            None
+        }
+    };
+
+    // The RPC mode has no history indexer, but payouts are still cheaper to
+    // fetch from one than to scan for.
+    let payout_indexer = match indexer {
+        Some(_) => None,
+        None => IndexerClient::new(network.clone()).ok(),
+    };
+    let indexed = match indexer.or(payout_indexer.as_ref()) {
+        Some(indexer) => {
+            let address = encode_ss58(account, network.ss58_format())?;
+            match indexer
+                .fetch_payout_events(&address, start_era, end_era)
+                .await
+            {
+                Ok(events) => Some(events),
+                Err(e) => {
+                    tracing::warn!("Indexer payouts unavailable, scanning blocks: {}", e);
+                    None
+                }
+            }
+        }
+        None => None,
+    };
+    let (new_events, scan) = match indexed {
+        Some(events) => (events, None),
+        None => {
+            let (latest, _) = client.get_latest_block().await?;
+            let cursor = db
+                .as_ref()
+                .and_then(|db| db.get_payout_scan_cursor(network.clone(), &address).ok())
+                .flatten();
+            let (from, scan) = PayoutScan::next(cursor, latest as u64, DEFAULT_PAYOUT_SCAN_BLOCKS);
+            let events = client
+                .scan_payout_events(std::slice::from_ref(account), from, scan.last_block)
+                .await?;
+            (events.into_iter().map(|e| e.event).collect(), Some(scan))
+        }
+    };
+    // Eras active from the first scanned block on are paid out within the
+    // scanned blocks; the indexer has every era.
+    let covered_from = match scan {
+        Some(scan) => match client.get_active_era_at(scan.first_block).await {
+            Ok(era) => era.unwrap_or(u32::MAX),
+            Err(e) => {
+                tracing::debug!("No active era at block {}: {}", scan.first_block, e);
+                u32::MAX
+            }
+        },
+        None => 0,
+    };
+
+    let events = match db.as_mut() {
+        Some(db) => {
+            if let Err(e) = db.insert_payout_events(network.clone(), &address, &new_events) {
+                tracing::warn!("Failed to cache payout events: {}", e);
+            }
+            if let Some(scan) = scan
+                && let Err(e) = db.set_payout_scan_cursor(network.clone(), &address, scan)
+            {
+                tracing::warn!("Failed to save payout scan cursor: {}", e);
+            }
+            db.get_payout_events(network, &address, Some((start_era, end_era)))
+                .unwrap_or(new_events)
+        }
+        None => new_events,
+    };
+
+    let targets = client
+        .get_nominations(account)
+        .await?
+        .map(|nominations| nominations.targets)
+        .unwrap_or_default();
+    let expected = client
+        .get_expected_payouts_in(account, &targets, start_era..=end_era, &events)
+        .await;
+
+    Ok(reconcile_payouts(&expected, &events, covered_from))
+}
+
+/// Reload validators and pools after the active era changed.
+///
+/// Uses cached identities only: the People chain is not reconnected for a
+/// refresh that only moves stake and APY figures.
+async fn refresh_era_data(
+    client: &ChainClient,
+    network: Network,
+    indexer: Option<&IndexerClient>,
+    mut db: Option<&mut db::HistoryDb>,
+    era: &EraInfo,
+    era_duration_ms: u64,
+    action_tx: &mpsc::Sender<Action>,
+) {
+    let fetch = if client.is_light_client() {
+        client.get_validators_light_client_with_completeness().await
+    } else {
+        client
+            .get_validators()
+            .await
+            .map(|validators| stkopt_chain::ValidatorFetch {
+                validators,
+                complete: true,
+            })
+    };
+    let fetch = match fetch {
+        Ok(fetch) => fetch,
+        Err(e) => {
+            tracing::warn!("Failed to refresh validators for era {}: {}", era.index, e);
+            return;
+        }
+    };
+
+    let identity_map = db
+        .as_deref()
+        .and_then(|db| {
+            db.get_validator_identities_within_age(
+                network.clone(),
+                stkopt_core::DEFAULT_IDENTITY_MAX_AGE_SECS,
+            )
+            .ok()
+        })
+        .unwrap_or_default();
+    let query_era = era.index.saturating_sub(1);
+    let display_validators = match fetch_and_enrich_validators(
+        &IndexedValidatorApySource {
+            chain: client,
+            indexer,
+        },
+        &fetch.validators,
+        None,
+        identity_map,
+        query_era,
+        era_duration_ms,
+    )
+    .await
+    {
+        Ok(outcome) => outcome.enrichment.validators,
+        Err(e) => {
+            tracing::warn!("Failed to enrich validators for era {}: {}", era.index, e);
+            return;
+        }
+    };
+    let apy_map = validator_apy_map(&display_validators);
+    if let Some(db) = db.as_deref_mut()
+        && let Err(e) = db.set_cached_validators_checked(
+            network.clone(),
+            era.index,
+            &display_validators,
+            fetch.complete,
+        )
+    {
+        tracing::warn!("Failed to cache validators: {}", e);
+    }
+    let _ = action_tx
+        .send(Action::SetDisplayValidators(display_validators))
+        .await;
+
+    let pools = match client.get_nomination_pools().await {
+        Ok(pools) => pools,
+        Err(e) => {
+            tracing::warn!("Failed to refresh pools for era {}: {}", era.index, e);
+            return;
+        }
+    };
+    let max_pools_to_query = 30.min(pools.len());
+    match fetch_and_enrich_pools(client, &pools, &apy_map, max_pools_to_query, None).await {
+        Ok(outcome) => {
+            if let Some(db) = db.as_deref_mut()
+                && let Err(e) =
+                    db.set_cached_pools_at_era(network.clone(), era.index, &outcome.pools)
+            {
+                tracing::warn!("Failed to cache pools: {}", e);
+            }
+            let _ = action_tx.send(Action::SetDisplayPools(outcome.pools)).await;
+        }
+        Err(e) => tracing::warn!("Failed to enrich pools for era {}: {}", era.index, e),
+    }
+
+    // Deferred slashes are applied and new ones reported between eras
+    fetch_slash_history(client, action_tx).await;
+
+    // Limits like MinimumActiveStake move with each election
+    if let Some(params) = fetch_staking_params(client, action_tx).await
+        && let Some(db) = db.as_deref_mut()
+        && let Ok(Some(mut metadata)) = db.get_chain_metadata(network.clone())
+    {
+        metadata.staking_params = Some(params);
+        metadata.current_era = era.index;
+        if let Err(e) = db.set_chain_metadata(network, &metadata) {
+            tracing::warn!("Failed to cache staking parameters: {}", e);
+        }
+    }
+    tracing::info!("Refreshed validators and pools for era {}", era.index);
+}
+
+/// Find which of `targets` carry the account's stake in the active era.
+async fn fetch_nominator_exposure(
+    client: &ChainClient,
+    account: &AccountId32,
+    targets: &[AccountId32],
+) -> Option<NominatorExposure> {
+    let era = match client.get_active_era().await {
+        Ok(Some(era)) => era,
+        Ok(None) => return None,
+        Err(e) => {
+            tracing::warn!("Failed to get active era for exposure: {}", e);
+            return None;
+        }
+    };
+    match client
+        .get_nominator_exposure(account, targets, era.index)
+        .await
+    {
+        Ok(exposure) => Some(exposure),
+        Err(e) => {
+            tracing::warn!("Failed to get nominator exposure: {}", e);
+            None
+        }
+    }
+}
+
+/// Fetch the network's slash history and send it to the UI.
+async fn fetch_slash_history(client: &ChainClient, action_tx: &mpsc::Sender<Action>) {
+    match client.get_slash_history().await {
+        Ok(history) => {
+            let _ = action_tx.send(Action::SetSlashHistory(history)).await;
+        }
+        Err(e) => tracing::warn!("Failed to get slash history: {}", e),
+    }
+}
+
+/// Fetch the staking limits and send them to the UI.
+async fn fetch_staking_params(
+    client: &ChainClient,
+    action_tx: &mpsc::Sender<Action>,
+) -> Option<StakingParams> {
+    match client.get_staking_params().await {
+        Ok(params) => {
+            let _ = action_tx
+                .send(Action::SetStakingParams(params.clone()))
+                .await;
+            Some(params)
+        }
+        Err(e) => {
+            tracing::warn!("Failed to get staking parameters: {}", e);
+            None
+        }
+    }
+}
+
 /// Background task for chain operations.
 pub async fn chain_task(
     mut network: Network,
-    config: ConnectionConfig,
+    mut config: ConnectionConfig,
     action_tx: mpsc::Sender<Action>,
     mut request_rx: mpsc::Receiver<ChainRequest>,
 ) {
@@ -414,7 +977,8 @@ pub async fn chain_task(
     });
 
     // Connect to chain (light client or RPC based on config)
-    let mut client = match ChainClient::connect(network, &config, status_tx.clone()).await {
+    config.endpoint_health = load_endpoint_health(network.clone());
+    let mut client = match ChainClient::connect(network.clone(), &config, status_tx.clone()).await {
         Ok(client) => {
             tracing::info!(
                 "Connected to {} Asset Hub via {} (genesis: {:?})",
@@ -422,6 +986,7 @@ pub async fn chain_task(
                 client.connection_mode(),
                 client.genesis_hash()
             );
+            save_endpoint_health(&client);
             client
         }
         Err(e) => {
@@ -435,6 +1000,8 @@ pub async fn chain_task(
         }
     };
 
+    let mut indexer = history_indexer(&client, network.clone());
+
     // Send chain info for UI display and validation
     let chain_info = match client.get_chain_info().await {
         Ok(chain_info) => {
@@ -512,6 +1079,21 @@ pub async fn chain_task(
     // Open database for caching
     let db_path = prepare_db_path();
     let mut db = db::HistoryDb::open(&db_path).ok();
+
+    // Fall back to the last cached limits if they cannot be read now
+    let mut staking_params = fetch_staking_params(&client, &action_tx).await;
+    if staking_params.is_none()
+        && let Some(cached) = db
+            .as_ref()
+            .and_then(|db| db.get_chain_metadata(network.clone()).ok().flatten())
+            .and_then(|metadata| metadata.staking_params)
+    {
+        let _ = action_tx
+            .send(Action::SetStakingParams(cached.clone()))
+            .await;
+        staking_params = Some(cached);
+    }
+
     if let (Some(ref db), Some(ref chain_info)) = (db.as_ref(), chain_info.as_ref()) {
         let (token_symbol, token_decimals, ss58_prefix) = (
             network.token_symbol().to_string(),
@@ -527,8 +1109,9 @@ pub async fn chain_task(
             token_decimals,
             era_duration_ms,
             current_era: era_info.index,
+            staking_params,
         };
-        if let Err(e) = db.set_chain_metadata(network, &metadata) {
+        if let Err(e) = db.set_chain_metadata(network.clone(), &metadata) {
             tracing::warn!("Failed to cache chain metadata: {}", e);
         }
     }
@@ -537,7 +1120,8 @@ pub async fn chain_task(
     let mut validators_cached = false;
     let mut pools_cached = false;
     if let Some(ref db) = db {
-        match StartupDataService::load(db, network, era_info.index, CachePolicy::default()) {
+        match StartupDataService::load(db, network.clone(), era_info.index, CachePolicy::default())
+        {
             Ok(startup) => {
                 if startup.validators.is_displayable() && !startup.validators.data.is_empty() {
                     let has_chain_data =
@@ -609,12 +1193,12 @@ pub async fn chain_task(
     }
 
     if !validators_cached {
-        let people_client = connect_ready_people_client(&client, network).await;
+        let people_client = connect_ready_people_client(&client, network.clone()).await;
 
         // Load cached identities immediately
         let mut identity_map: HashMap<String, String> = if let Some(ref db) = db {
             match db.get_validator_identities_within_age(
-                network,
+                network.clone(),
                 stkopt_core::DEFAULT_IDENTITY_MAX_AGE_SECS,
             ) {
                 Ok(cached) => {
@@ -731,7 +1315,10 @@ pub async fn chain_task(
         // Fetch per-validator APY inputs and enrich validators.
         let query_era = era_info.index.saturating_sub(1);
         let enrichment_outcome = match fetch_and_enrich_validators(
-            &client,
+            &IndexedValidatorApySource {
+                chain: &client,
+                indexer: indexer.as_ref(),
+            },
             &validators,
             people_client.as_ref(),
             identity_map.clone(),
@@ -792,9 +1379,10 @@ pub async fn chain_task(
             );
 
             if let Some(ref mut db) = db {
-                match db
-                    .set_validator_identities_batch(network, &enrichment_outcome.fresh_identities)
-                {
+                match db.set_validator_identities_batch(
+                    network.clone(),
+                    &enrichment_outcome.fresh_identities,
+                ) {
                     Ok(count) => {
                         tracing::info!("Updated {} cached validator identities", count);
                     }
@@ -841,7 +1429,7 @@ pub async fn chain_task(
         // Cache validators
         if let Some(ref mut db) = db {
             match db.set_cached_validators_checked(
-                network,
+                network.clone(),
                 era_info.index,
                 &display_validators,
                 validators_complete,
@@ -1030,7 +1618,7 @@ pub async fn chain_task(
             .await;
 
         if let Some(ref mut db) = db {
-            match db.set_cached_pools_at_era(network, era_info.index, &display_pools) {
+            match db.set_cached_pools_at_era(network.clone(), era_info.index, &display_pools) {
                 Ok(count) => {
                     tracing::info!("Cached {} nomination pools", count);
                 }
@@ -1043,15 +1631,51 @@ pub async fn chain_task(
         tracing::info!("Nomination pools loaded successfully");
     }
 
-    // Listen for requests from the UI
-    while let Some(request) = request_rx.recv().await {
+    // Slash history feeds the optimizer and the account's slash risk
+    fetch_slash_history(&client, &action_tx).await;
+
+    // Watch for era and account changes while the session runs
+    let (update_tx, mut update_rx) =
+        mpsc::channel::<SubscriptionUpdate>(SUBSCRIPTION_CHANNEL_CAPACITY);
+    let mut subscription = ChainSubscription::spawn(client.clone(), None, update_tx.clone());
+
+    // Listen for requests from the UI and for chain changes
+    loop {
+        let request = tokio::select! {
+            request = request_rx.recv() => match request {
+                Some(request) => request,
+                None => break,
+            },
+            Some(update) = update_rx.recv() => match update {
+                SubscriptionUpdate::EraChanged { previous, era } => {
+                    tracing::info!("Era changed from {} to {}", previous, era.index);
+                    let _ = action_tx.send(Action::SetActiveEra(era.clone())).await;
+                    refresh_era_data(
+                        &client,
+                        network.clone(),
+                        indexer.as_ref(),
+                        db.as_mut(),
+                        &era,
+                        era_duration_ms,
+                        &action_tx,
+                    )
+                    .await;
+                    continue;
+                }
+                SubscriptionUpdate::AccountChanged { account, .. } => {
+                    tracing::info!("Account {} changed on chain", account);
+                    ChainRequest::FetchAccount(account)
+                }
+            },
+        };
         match request {
             ChainRequest::FetchAccount(account) => {
                 tracing::info!("Fetching account status for {}", account);
+                subscription.watch_account(Some(account.clone()));
                 let cached_account_status = match db.as_ref().map(|db| {
                     AccountStatusService::load_cached(
                         db,
-                        network,
+                        network.clone(),
                         &account.to_string(),
                         CachePolicy::default(),
                     )
@@ -1071,17 +1695,25 @@ pub async fn chain_task(
                         .await;
                 }
 
-                let balance = match client.get_account_balance(&account).await {
-                    Ok(b) => b,
-                    Err(e) => {
-                        tracing::error!("Failed to get account balance: {}", e);
-                        stkopt_chain::AccountBalance {
-                            free: 0,
-                            reserved: 0,
-                            frozen: 0,
+                let (balance, balance_breakdown) =
+                    match client.get_balance_breakdown(&account).await {
+                        Ok(breakdown) => (breakdown.balance.clone(), Some(breakdown)),
+                        Err(e) => {
+                            tracing::warn!("Failed to get balance breakdown: {}", e);
+                            let balance = match client.get_account_balance(&account).await {
+                                Ok(b) => b,
+                                Err(e) => {
+                                    tracing::error!("Failed to get account balance: {}", e);
+                                    stkopt_chain::AccountBalance {
+                                        free: 0,
+                                        reserved: 0,
+                                        frozen: 0,
+                                    }
+                                }
+                            };
+                            (balance, None)
                         }
-                    }
-                };
+                    };
 
                 let staking_ledger = match client.get_staking_ledger(&account).await {
                     Ok(l) => l,
@@ -1107,17 +1739,67 @@ pub async fn chain_task(
                     }
                 };
 
+                let exposure = match &nominations {
+                    Some(n) if !n.targets.is_empty() => {
+                        fetch_nominator_exposure(&client, &account, &n.targets).await
+                    }
+                    _ => None,
+                };
+
+                let health = match client
+                    .get_health_account(
+                        staking_ledger.as_ref(),
+                        nominations.as_ref(),
+                        pool_membership.as_ref(),
+                        exposure.as_ref(),
+                    )
+                    .await
+                {
+                    Ok(health) => Some(health),
+                    Err(e) => {
+                        tracing::warn!("Failed to get health check data: {}", e);
+                        None
+                    }
+                };
+
+                let past_slashes = match client.get_nominator_slashes(&account).await {
+                    Ok(slashes) => Some(slashes),
+                    Err(e) => {
+                        tracing::warn!("Failed to get nominator slashes: {}", e);
+                        None
+                    }
+                };
+
+                let pool_details = match &pool_membership {
+                    Some(membership) => {
+                        match client.get_pool_member_details(&account, membership).await {
+                            Ok(details) => Some(details),
+                            Err(e) => {
+                                tracing::warn!("Failed to get pool member details: {}", e);
+                                None
+                            }
+                        }
+                    }
+                    None => None,
+                };
+
+                let active_bond = staking_ledger.as_ref().map(|l| l.active);
                 let status = AccountStatus {
                     address: account.clone(),
                     balance,
+                    balance_breakdown,
                     staking_ledger,
                     nominations,
                     pool_membership,
+                    exposure,
+                    health,
+                    past_slashes,
+                    pool_details,
                 };
 
                 if let Some(ref db) = db
                     && let Err(e) = db.set_cached_account_status(
-                        network,
+                        network.clone(),
                         &account.to_string(),
                         &cached_account_status_from_live(&status),
                     )
@@ -1129,6 +1811,76 @@ pub async fn chain_task(
                     .send(Action::SetAccountStatus(Box::new(status)))
                     .await;
                 tracing::info!("Account status updated");
+
+                if let Some(active) = active_bond {
+                    match client.get_bags_list_position(&account, active).await {
+                        Ok(position) => {
+                            let _ = action_tx.send(Action::SetBagsListPosition(position)).await;
+                        }
+                        Err(e) => tracing::warn!("Failed to get voter list position: {}", e),
+                    }
+                }
+
+                match client.get_pool_admin_roles(&account).await {
+                    Ok(pools) => {
+                        let _ = action_tx.send(Action::SetPoolAdmin(pools)).await;
+                    }
+                    Err(e) => tracing::warn!("Failed to get pool roles: {}", e),
+                }
+            }
+            ChainRequest::FetchOffenceReports => match client.get_offence_reports().await {
+                Ok(reports) => {
+                    tracing::info!("Loaded {} offence reports", reports.len());
+                    let _ = action_tx.send(Action::SetOffenceReports(reports)).await;
+                }
+                Err(e) => tracing::warn!("Failed to get offence reports: {}", e),
+            },
+            ChainRequest::FetchPortfolio(accounts) => {
+                tracing::info!("Fetching portfolio of {} saved accounts", accounts.len());
+                let addresses: Vec<AccountId32> = accounts
+                    .iter()
+                    .map(|(account, _)| account.clone())
+                    .collect();
+                let result = match client.get_accounts_status(&addresses).await {
+                    Ok(statuses) => statuses
+                        .iter()
+                        .zip(accounts)
+                        .map(|(status, (_, label))| {
+                            status.to_portfolio_account(label, network.ss58_format())
+                        })
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(|e| e.to_string()),
+                    Err(e) => {
+                        tracing::warn!("Failed to fetch portfolio: {}", e);
+                        Err(e.to_string())
+                    }
+                };
+                let _ = action_tx.send(Action::SetPortfolio(result)).await;
+            }
+            ChainRequest::CheckPayouts {
+                account,
+                start_era,
+                end_era,
+            } => {
+                tracing::info!(
+                    "Checking payouts of {} for eras {}..={}",
+                    account,
+                    start_era,
+                    end_era
+                );
+                let result = check_payouts(
+                    &client,
+                    indexer.as_ref(),
+                    network.clone(),
+                    &account,
+                    (start_era, end_era),
+                )
+                .await
+                .map_err(|e| {
+                    tracing::warn!("Payout check failed: {}", e);
+                    e.to_string()
+                });
+                let _ = action_tx.send(Action::SetPayoutChecks(result)).await;
             }
             ChainRequest::GenerateNominationQR { signer, targets } => {
                 tracing::info!("Generating nomination QR for {} validators", targets.len());
@@ -1211,7 +1963,7 @@ pub async fn chain_task(
                 if let Some(ref db) = db
                     && let Ok(cached) = HistoryService::load_cached_range(
                         db,
-                        network,
+                        network.clone(),
                         &address,
                         start_era,
                         end_era,
@@ -1236,7 +1988,7 @@ pub async fn chain_task(
                 let eras_to_fetch: Vec<u32> = if let Some(ref db) = db {
                     HistoryService::missing_eras(
                         db,
-                        network,
+                        network.clone(),
                         &address,
                         start_era,
                         end_era,
@@ -1249,6 +2001,18 @@ pub async fn chain_task(
 
                 if eras_to_fetch.is_empty() {
                     tracing::info!("All eras already cached");
+                    load_exact_history(
+                        &client,
+                        db.as_mut(),
+                        network.clone(),
+                        &account,
+                        (start_era, end_era),
+                        (current_era, current_era_start_ms, era_duration_ms),
+                        user_bonded,
+                        &cancel_rx,
+                        &action_tx,
+                    )
+                    .await;
                     let _ = action_tx.send(Action::HistoryLoadingComplete).await;
                     continue;
                 }
@@ -1262,7 +2026,13 @@ pub async fn chain_task(
                     continue;
                 }
 
-                let client = &client;
+                // Light clients lack old era state; use spot-checked indexer data
+                let source =
+                    HistoryEraSource::load(&client, indexer.as_ref(), start_era, end_era).await;
+                if source.indexed_eras() > 0 {
+                    tracing::info!("Using indexer data for {} eras", source.indexed_eras());
+                }
+                let source = &source;
                 let concurrency = 8;
                 let fetched = stream::iter(eras_to_fetch)
                     .map(|era| {
@@ -1277,8 +2047,8 @@ pub async fn chain_task(
                                 current_era_start_ms,
                                 era_duration_ms,
                                 user_bonded,
-                                |era| client.get_era_validator_reward(era),
-                                |era| client.get_era_total_stake_direct(era),
+                                |era| source.validator_reward(era),
+                                |era| source.total_stake(era),
                             )
                             .await;
                             (era, point)
@@ -1313,13 +2083,26 @@ pub async fn chain_task(
                 if let Some(ref mut db) = db
                     && !new_points.is_empty()
                 {
-                    if let Err(e) = db.insert_history_batch(network, &address, &new_points) {
+                    if let Err(e) = db.insert_history_batch(network.clone(), &address, &new_points)
+                    {
                         tracing::warn!("Failed to cache history: {}", e);
                     } else {
                         tracing::info!("Cached {} new history points", new_points.len());
                     }
                 }
 
+                load_exact_history(
+                    &client,
+                    db.as_mut(),
+                    network.clone(),
+                    &account,
+                    (start_era, end_era),
+                    (current_era, current_era_start_ms, era_duration_ms),
+                    user_bonded,
+                    &cancel_rx,
+                    &action_tx,
+                )
+                .await;
                 let _ = action_tx.send(Action::HistoryLoadingComplete).await;
                 tracing::info!("Staking history loaded");
             }
@@ -1369,6 +2152,18 @@ pub async fn chain_task(
                         signer,
                         client.create_chill_payload(signer, use_mortal_era).await,
                     ),
+                    StakingOp::Rebag { signer } => (
+                        signer,
+                        client
+                            .create_rebag_payload(signer, signer, use_mortal_era)
+                            .await,
+                    ),
+                    StakingOp::PutInFrontOf { signer, lighter } => (
+                        signer,
+                        client
+                            .create_put_in_front_of_payload(signer, lighter, use_mortal_era)
+                            .await,
+                    ),
                     StakingOp::PoolJoin {
                         signer,
                         pool_id,
@@ -1407,12 +2202,57 @@ pub async fn chain_task(
                                 .await,
                         )
                     }
+                    StakingOp::PoolSetClaimPermission { signer, permission } => (
+                        signer,
+                        client
+                            .create_pool_set_claim_permission_payload(
+                                signer,
+                                *permission,
+                                use_mortal_era,
+                            )
+                            .await,
+                    ),
+                    StakingOp::PoolMemberCall {
+                        signer,
+                        member,
+                        call,
+                    } => (
+                        signer,
+                        client
+                            .create_pool_member_call_payload(signer, member, *call, use_mortal_era)
+                            .await,
+                    ),
+                    StakingOp::PoolNominate {
+                        signer,
+                        pool_id,
+                        targets,
+                    } => (
+                        signer,
+                        client
+                            .create_pool_nominate_payload(signer, *pool_id, targets, use_mortal_era)
+                            .await,
+                    ),
+                    StakingOp::PoolAdmin {
+                        signer,
+                        pool_id,
+                        op,
+                    } => (
+                        signer,
+                        create_pool_admin_payload(&client, signer, *pool_id, op, use_mortal_era)
+                            .await,
+                    ),
+                };
+                let targets: Vec<String> = match &op {
+                    StakingOp::PoolNominate { targets, .. } => {
+                        targets.iter().map(|t| t.to_string()).collect()
+                    }
+                    _ => vec![],
                 };
 
                 match result {
                     Ok(payload) => {
                         tracing::info!("QR data generated ({} bytes)", payload.call_data.len());
-                        send_staking_qr(&action_tx, payload, signer.clone(), vec![]).await;
+                        send_staking_qr(&action_tx, payload, signer.clone(), targets).await;
                     }
                     Err(e) => {
                         tracing::error!("Failed to generate payload: {}", e);
@@ -1420,34 +2260,73 @@ pub async fn chain_task(
                     }
                 }
             }
+            ChainRequest::RefreshPayload { pending, targets } => {
+                match client
+                    .refresh_payload(&pending.signer, &pending.payload)
+                    .await
+                {
+                    Ok(payload) => {
+                        tracing::info!(
+                            "Rebuilt payload at block {:?} (nonce {})",
+                            payload.birth_block,
+                            payload.nonce
+                        );
+                        send_staking_qr(&action_tx, payload, pending.signer, targets).await;
+                    }
+                    Err(e) => {
+                        tracing::error!("Failed to rebuild payload: {}", e);
+                        let _ = action_tx
+                            .send(Action::PendingTxRebuildFailed(e.to_string()))
+                            .await;
+                    }
+                }
+            }
             ChainRequest::SubmitTransaction(extrinsic) => {
                 tracing::info!("Submitting signed extrinsic ({} bytes)", extrinsic.len());
 
                 match client.submit_signed_extrinsic(&extrinsic).await {
-                    Ok(progress) => {
+                    Ok(mut progress) => {
                         tracing::info!("Transaction submitted, waiting for inclusion...");
 
-                        match progress.wait_for_finalized().await {
-                            Ok(result) => {
-                                tracing::info!(
-                                    "Transaction finalized in block 0x{}",
-                                    hex::encode(result.block_hash)
-                                );
-                                let _ = action_tx
-                                    .send(Action::SetTxStatus(
-                                        crate::action::TxSubmissionStatus::Finalized {
-                                            block_hash: result.block_hash,
-                                        },
-                                    ))
-                                    .await;
-                            }
-                            Err(e) => {
-                                tracing::error!("Transaction failed: {}", e);
-                                let _ = action_tx
-                                    .send(Action::SetTxStatus(
-                                        crate::action::TxSubmissionStatus::Failed(e.to_string()),
-                                    ))
-                                    .await;
+                        // Report best-block inclusions as they happen, then finalization.
+                        loop {
+                            match progress.next_inclusion().await {
+                                Ok(result) if result.finalized => {
+                                    tracing::info!(
+                                        "Transaction finalized in block 0x{}",
+                                        hex::encode(result.block_hash)
+                                    );
+                                    let _ = action_tx
+                                        .send(Action::SetTxStatus(
+                                            crate::action::TxSubmissionStatus::Finalized {
+                                                block_hash: result.block_hash,
+                                                outcome: result.outcome,
+                                            },
+                                        ))
+                                        .await;
+                                    break;
+                                }
+                                Ok(result) => {
+                                    let _ = action_tx
+                                        .send(Action::SetTxStatus(
+                                            crate::action::TxSubmissionStatus::InBlock {
+                                                block_hash: result.block_hash,
+                                                outcome: result.outcome,
+                                            },
+                                        ))
+                                        .await;
+                                }
+                                Err(e) => {
+                                    tracing::error!("Transaction failed: {}", e);
+                                    let _ = action_tx
+                                        .send(Action::SetTxStatus(
+                                            crate::action::TxSubmissionStatus::Failed(
+                                                e.to_string(),
+                                            ),
+                                        ))
+                                        .await;
+                                    break;
+                                }
                             }
                         }
                     }
@@ -1464,21 +2343,25 @@ pub async fn chain_task(
                         } else if error_str.contains("1012") || error_str.contains("Pool") {
                             "Transaction pool is full. Please try again later.".to_string()
                         } else {
-                            error_str
+                            error_str.clone()
                         };
 
-                        let _ = action_tx
-                            .send(Action::SetTxStatus(
-                                crate::action::TxSubmissionStatus::Failed(user_message),
-                            ))
-                            .await;
+                        // An outdated era can be fixed by rebuilding the payload
+                        let status = if stkopt_core::is_expired_tx_error(&error_str) {
+                            crate::action::TxSubmissionStatus::Expired
+                        } else {
+                            crate::action::TxSubmissionStatus::Failed(user_message)
+                        };
+                        let _ = action_tx.send(Action::SetTxStatus(status)).await;
                     }
                 }
             }
             ChainRequest::Reconnect(new_network) => {
                 tracing::info!("Switching network from {} to {}", network, new_network);
+                save_light_client_peers(&client).await;
                 network = new_network;
-                match ChainClient::connect(network, &config, status_tx.clone()).await {
+                config.endpoint_health = load_endpoint_health(network.clone());
+                match ChainClient::connect(network.clone(), &config, status_tx.clone()).await {
                     Ok(new_client) => {
                         tracing::info!(
                             "Connected to {} Asset Hub via {} (genesis: {:?})",
@@ -1486,7 +2369,14 @@ pub async fn chain_task(
                             new_client.connection_mode(),
                             new_client.genesis_hash()
                         );
+                        save_endpoint_health(&new_client);
                         client = new_client;
+                        indexer = history_indexer(&client, network.clone());
+                        subscription = ChainSubscription::spawn(
+                            client.clone(),
+                            subscription.account(),
+                            update_tx.clone(),
+                        );
                         match client.get_chain_info().await {
                             Ok(chain_info) => {
                                 let _ = action_tx.send(Action::SetChainInfo(chain_info)).await;
@@ -1514,6 +2404,9 @@ pub async fn chain_task(
             }
         }
     }
+
+    // The UI dropped its request sender: shutting down
+    save_light_client_peers(&client).await;
 }
 
 /// Path to the legacy TUI-specific history database.
@@ -1557,7 +2450,7 @@ fn prepare_db_path() -> PathBuf {
 /// Run in update mode: fetch missing history and store to database, then exit.
 pub async fn run_update_mode(
     network: Network,
-    config: ConnectionConfig,
+    mut config: ConnectionConfig,
     address: Option<String>,
     num_eras: u32,
 ) -> Result<()> {
@@ -1585,8 +2478,12 @@ pub async fn run_update_mode(
     let (status_tx, _status_rx) = mpsc::channel::<ConnectionStatus>(1);
 
     tracing::info!("Connecting to {} Asset Hub...", network);
-    let client = ChainClient::connect(network, &config, status_tx).await?;
+    config.endpoint_health = db.get_endpoint_health(network.clone()).unwrap_or_default();
+    let client = ChainClient::connect(network.clone(), &config, status_tx).await?;
     tracing::info!("Connected via {}", client.connection_mode());
+    if let Err(e) = db.set_endpoint_health(network.clone(), &client.endpoint_health()) {
+        tracing::warn!("Failed to save endpoint health: {}", e);
+    }
 
     let current_era_info = client
         .get_active_era()
@@ -1615,7 +2512,7 @@ pub async fn run_update_mode(
 
     let missing_eras = HistoryService::missing_eras(
         &db,
-        network,
+        network.clone(),
         &address,
         start_era,
         end_era,
@@ -1635,11 +2532,17 @@ pub async fn run_update_mode(
         num_eras
     );
 
+    let indexer = history_indexer(&client, network.clone());
+    let source = HistoryEraSource::load(&client, indexer.as_ref(), start_era, end_era).await;
+    if source.indexed_eras() > 0 {
+        tracing::info!("Using indexer data for {} eras", source.indexed_eras());
+    }
+
     let mut points = Vec::new();
     let mut fetched = 0;
 
     for era in missing_eras {
-        let era_reward = match client.get_era_validator_reward(era).await {
+        let era_reward = match source.validator_reward(era).await {
             Ok(Some(reward)) => reward,
             Ok(None) => {
                 tracing::warn!("  Era {}: no reward data", era);
@@ -1651,7 +2554,7 @@ pub async fn run_update_mode(
             }
         };
 
-        let total_staked = match client.get_era_total_stake_direct(era).await {
+        let total_staked = match source.total_stake(era).await {
             Ok(staked) if staked > 0 => staked,
             Ok(_) => {
                 tracing::warn!("  Era {}: no stake data", era);
@@ -1691,7 +2594,7 @@ pub async fn run_update_mode(
     }
 
     if !points.is_empty() {
-        db.insert_history_batch(network, &address, &points)
+        db.insert_history_batch(network.clone(), &address, &points)
             .map_err(|e| color_eyre::eyre::eyre!("Failed to store history: {}", e))?;
         tracing::info!("Stored {} era records to database", points.len());
     }
@@ -1704,6 +2607,117 @@ pub async fn run_update_mode(
     Ok(())
 }
 
+/// Health check mode: print what is wrong with an account's staking setup.
+///
+/// Validator names and pool states come from the startup cache, so no full
+/// validator or pool scan is needed. Returns whether a critical issue was found.
+pub async fn run_health_check(
+    network: Network,
+    mut config: ConnectionConfig,
+    address: &str,
+) -> Result<bool> {
+    let account: AccountId32 = address
+        .parse()
+        .map_err(|_| color_eyre::eyre::eyre!("Invalid address format: {}", address))?;
+
+    let db_path = prepare_db_path();
+    let db = db::HistoryDb::open(&db_path).ok();
+    let (validators, pools) = match db
+        .as_ref()
+        .map(|db| StartupDataService::load(db, network.clone(), 0, CachePolicy::default()))
+    {
+        Some(Ok(startup)) => (startup.validators.data, startup.pools.data),
+        _ => (Vec::new(), Vec::new()),
+    };
+
+    let (status_tx, _status_rx) = mpsc::channel::<ConnectionStatus>(1);
+    tracing::info!("Connecting to {} Asset Hub...", network);
+    if let Some(db) = &db {
+        config.endpoint_health = db.get_endpoint_health(network.clone()).unwrap_or_default();
+    }
+    let client = ChainClient::connect(network.clone(), &config, status_tx).await?;
+
+    let current_era = client
+        .get_active_era()
+        .await?
+        .ok_or_else(|| color_eyre::eyre::eyre!("No active era found"))?
+        .index;
+    let ledger = client.get_staking_ledger(&account).await?;
+    let nominations = client.get_nominations(&account).await?;
+    let pool_membership = client.get_pool_membership(&account).await?;
+    let exposure = match &nominations {
+        Some(n) if !n.targets.is_empty() => Some(
+            client
+                .get_nominator_exposure(&account, &n.targets, current_era)
+                .await?,
+        ),
+        _ => None,
+    };
+    let params = client.get_staking_params().await.ok();
+    let health = client
+        .get_health_account(
+            ledger.as_ref(),
+            nominations.as_ref(),
+            pool_membership.as_ref(),
+            exposure.as_ref(),
+        )
+        .await?;
+
+    let report = check_health(&health, &validators, &pools, params.as_ref(), current_era);
+    let decimals = network.token_decimals();
+    let symbol = network.token_symbol();
+    println!(
+        "Health check for {} on {} (era {})",
+        address, network, current_era
+    );
+    if report.is_healthy() {
+        println!("No problems found");
+    }
+    for issue in &report.issues {
+        println!(
+            "[{}] {}",
+            issue.severity().label(),
+            issue.message(decimals, symbol)
+        );
+        println!("    Fix: {}", issue.fix());
+    }
+
+    if let Some(n) = nominations.as_ref() {
+        let history = client.get_slash_history().await?;
+        let past_slashes = client.get_nominator_slashes(&account).await?;
+        let prefix = network.ss58_format();
+        let targets = slash_risk_targets(
+            &n.targets,
+            exposure.as_ref(),
+            ledger.as_ref().map_or(0, |l| l.active),
+            prefix,
+        )?;
+        let risk = assess_slash_risk(
+            &encode_ss58(&account, prefix)?,
+            &targets,
+            &history,
+            past_slashes,
+        );
+        let fmt = |amount| format_token_balance(amount, decimals, Some(symbol));
+        println!(
+            "Slash risk: {} pending, {} already slashed, {} estimated loss",
+            fmt(risk.total_pending()),
+            fmt(risk.total_past()),
+            fmt(risk.total_estimated_loss())
+        );
+        for target in risk.flagged() {
+            println!(
+                "    {}: {} pending, {} past slash(es), {} offence report(s)",
+                target.validator,
+                fmt(target.pending),
+                target.slashes.len(),
+                target.offences
+            );
+        }
+    }
+    Ok(report.worst() == Some(Severity::Critical))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1755,6 +2769,7 @@ mod tests {
             tx_version: 26,
             nonce: 42,
             era: stkopt_chain::Era::Immortal,
+            birth_block: None,
             include_metadata_hash: true,
             use_asset_payment: false,
             extension_ids: vec!["CheckNonce".to_string()],
@@ -1840,6 +2855,7 @@ mod tests {
             token_decimals: network.token_decimals(),
             era_duration_ms: 24 * 60 * 60 * 1000,
             current_era: 1,
+            staking_params: None,
         };
         assert_eq!(metadata.ss58_prefix, 0);
         assert_eq!(metadata.token_symbol, "PAS");
@@ -1911,6 +2927,7 @@ mod tests {
                 reserved: 100_000,
                 frozen: 50_000,
             },
+            balance_breakdown: None,
             staking_ledger: Some(StakingLedger {
                 stash: address.clone(),
                 total: 500_000,
@@ -1933,6 +2950,10 @@ mod tests {
                 unbonding_eras: vec![(1601, 5_000), (1602, 8_000)],
                 last_recorded_reward_counter: 99_999,
             }),
+            exposure: None,
+            health: None,
+            past_slashes: None,
+            pool_details: None,
         };
 
         let cached = cached_account_status_from_live(&live);
@@ -3,4 +3,20 @@
 //! This module re-exports the unified configuration from stkopt-core.
 //! Uses the same config file format and storage location as the GPUI app.
 
-pub use stkopt_core::config::{load_config, save_config};
+pub use stkopt_core::config::{load_address_book, load_config, save_address_book, save_config};
+use stkopt_core::{AddressBookEntry, Network, NetworkConfig};
+
+/// Load address book entries saved for `network`.
+pub fn load_saved_addresses(network: Network) -> Vec<AddressBookEntry> {
+    match load_address_book() {
+        Ok(book) => book
+            .for_network(NetworkConfig::from(network))
+            .into_iter()
+            .cloned()
+            .collect(),
+        Err(e) => {
+            tracing::warn!("Failed to load address book: {}", e);
+            Vec::new()
+        }
+    }
+}
@@ -13,22 +13,26 @@ mod theme;
 mod tui;
 mod ui;
 
-use action::{Action, PendingTransaction, TxSubmissionStatus};
-use app::App;
+use action::{Action, PendingTransaction, PoolAdminOp, TxSubmissionStatus};
+use app::{App, PoolAdminState};
 use chain_task::{
-    ChainRequest, StakingOp, cached_validators_have_chain_data, chain_task, run_update_mode,
+    ChainRequest, StakingOp, cached_validators_have_chain_data, chain_task, run_health_check,
+    run_update_mode,
 };
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use color_eyre::Result;
 use event::{Event, EventHandler};
 use log_buffer::{LogBuffer, LogBufferLayer};
 use ratatui::crossterm::event::KeyCode;
 use std::path::PathBuf;
-use stkopt_core::config::get_db_path;
+use stkopt_core::config::{NetworkConfig, get_db_path};
+use stkopt_core::journal::default_journal_file_name;
 use stkopt_core::{
-    CachePolicy, HistoryService, Network, OptimizationDataSource, OptimizationResult,
-    SelectionStrategy, StartupDataService,
+    CachePolicy, HistoryService, JournalExportFormat, Network, NewTransaction,
+    OptimizationDataSource, OptimizationResult, SelectionStrategy, StartupDataService,
+    TransactionFilter, TxJournalStatus, TxMortality,
 };
+use subxt::utils::AccountId32;
 use tokio::sync::mpsc;
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
@@ -39,7 +43,7 @@ use tui::Tui;
 #[command(name = "stkopt")]
 #[command(author, version, about, long_about = None)]
 struct Args {
-    /// Network to connect to
+    /// Network to connect to; `custom` is the network defined in config.json
     #[arg(short, long, default_value = "polkadot")]
     network: NetworkArg,
 
@@ -72,12 +76,85 @@ struct Args {
     /// Force RPC mode instead of light client.
     /// Light client is default (trustless) but RPC may be needed for
     /// historical data queries or when light client has issues.
-    #[arg(long)]
+    #[arg(long, conflicts_with = "hybrid")]
     rpc: bool,
+
+    /// Hybrid mode: read over RPC, but verify every storage value with a
+    /// read proof against state roots finalized by the light client.
+    /// Gives historical queries without trusting the RPC provider.
+    #[arg(long)]
+    hybrid: bool,
+
+    /// Directory for QR codes exported from the signing popup (key: e).
+    #[arg(long = "qr-export-dir", default_value = ".")]
+    qr_export_dir: PathBuf,
+
+    /// Format for exported QR codes: auto, png, svg or gif.
+    /// Auto writes a PNG when the payload fits one QR code, else an animated GIF.
+    #[arg(long = "qr-export-format", default_value = "auto")]
+    qr_export_format: QrExportFormat,
+
+    /// Directory for transaction history exports (Transactions tab, key: e).
+    #[arg(long = "journal-export-dir", default_value = ".")]
+    journal_export_dir: PathBuf,
+
+    /// Format for transaction history exports: csv or json.
+    #[arg(long = "journal-export-format", default_value = "csv")]
+    journal_export_format: JournalExportFormat,
+
+    /// Mortality period in blocks for signed transactions on the selected network
+    /// (rounded to a power of two, 4-4096). Overrides the saved setting for this session.
+    #[arg(long = "mortality-period")]
+    mortality_period: Option<u64>,
+
+    /// Record every chain query made this session to FILE (connects via RPC).
+    /// Attach the file to bug reports so the session can be replayed.
+    #[arg(long, value_name = "FILE", conflicts_with = "replay")]
+    record: Option<std::path::PathBuf>,
+
+    /// Replay a session recorded with --record, without network access.
+    /// The network is taken from the recording.
+    #[arg(long, value_name = "FILE")]
+    replay: Option<std::path::PathBuf>,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+/// One-shot commands that run instead of the TUI.
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Check an account's staking setup and print problems with suggested fixes.
+    /// Exits with status 1 if a critical problem is found.
+    Health {
+        /// Account address to check.
+        address: String,
+    },
+}
+
+impl Args {
+    fn session(&self) -> Option<SessionMode> {
+        self.record
+            .clone()
+            .map(SessionMode::Record)
+            .or_else(|| self.replay.clone().map(SessionMode::Replay))
+    }
+
+    /// Sessions always go over RPC; otherwise the light client unless a
+    /// flag asks for RPC or hybrid.
+    fn connection_mode(&self) -> ConnectionMode {
+        if self.rpc || self.record.is_some() || self.replay.is_some() {
+            ConnectionMode::Rpc
+        } else if self.hybrid {
+            ConnectionMode::Hybrid
+        } else {
+            ConnectionMode::LightClient
+        }
+    }
 }
 
 // Re-export connection types from stkopt_chain
-use stkopt_chain::{ConnectionConfig, ConnectionMode, RpcEndpoints};
+use stkopt_chain::{ConnectionConfig, ConnectionMode, QrExportFormat, RpcEndpoints, SessionMode};
 
 fn suppress_light_client_chatter(
     mut filter: tracing_subscriber::EnvFilter,
@@ -98,12 +175,91 @@ fn suppress_light_client_chatter(
     filter
 }
 
+/// Open the staking database, creating its directory if needed.
+fn open_staking_db() -> Option<db::HistoryDb> {
+    let db_path = get_db_path().unwrap_or_else(|_| PathBuf::from("stkopt_history.db"));
+    if let Some(parent) = db_path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    match db::HistoryDb::open(&db_path) {
+        Ok(db) => Some(db),
+        Err(e) => {
+            tracing::warn!("Failed to open database {}: {}", db_path.display(), e);
+            None
+        }
+    }
+}
+
+/// Signer address as stored in the transaction journal (network SS58 format).
+fn journal_signer(network: Network, account: &AccountId32) -> String {
+    stkopt_chain::encode_ss58(account, network.ss58_format())
+        .unwrap_or_else(|_| account.to_string())
+}
+
+/// Journal entry for a signed transaction about to be submitted.
+fn journal_entry(
+    network: Network,
+    pending: &action::PendingUnsignedTx,
+    tx_hash: &[u8; 32],
+) -> NewTransaction {
+    let mortality = match pending.payload.era {
+        stkopt_chain::Era::Immortal => TxMortality::Immortal,
+        stkopt_chain::Era::Mortal { period, phase } => TxMortality::Mortal { period, phase },
+    };
+    NewTransaction {
+        signer: journal_signer(network, &pending.signer),
+        description: pending.payload.description.clone(),
+        call_data: format!("0x{}", hex::encode(&pending.payload.call_data)),
+        nonce: pending.payload.nonce,
+        mortality,
+        tx_hash: format!("0x{}", hex::encode(tx_hash)),
+    }
+}
+
+/// Journal status, block hash and dispatch result to record for a submission status.
+fn journal_update(
+    status: &TxSubmissionStatus,
+) -> Option<(TxJournalStatus, Option<String>, Option<String>)> {
+    let (status, block_hash, outcome) = match status {
+        TxSubmissionStatus::InBlock {
+            block_hash,
+            outcome,
+        } => (TxJournalStatus::InBlock, block_hash, outcome),
+        TxSubmissionStatus::Finalized {
+            block_hash,
+            outcome,
+        } => (TxJournalStatus::Finalized, block_hash, outcome),
+        TxSubmissionStatus::Failed(e) => {
+            return Some((TxJournalStatus::Failed, None, Some(e.clone())));
+        }
+        TxSubmissionStatus::Expired => {
+            return Some((
+                TxJournalStatus::Failed,
+                None,
+                Some("Transaction outdated (mortality expired)".to_string()),
+            ));
+        }
+        TxSubmissionStatus::ReadyToSubmit | TxSubmissionStatus::Submitting => return None,
+    };
+    let status = if outcome.as_ref().is_some_and(|o| !o.is_success()) {
+        TxJournalStatus::Failed
+    } else {
+        status
+    };
+    Some((
+        status,
+        Some(format!("0x{}", hex::encode(block_hash))),
+        outcome.as_ref().map(|o| o.summary()),
+    ))
+}
+
 fn optimize_nomination(
     app: &App,
     strategy: SelectionStrategy,
 ) -> (OptimizationResult, Option<String>) {
     let criteria = stkopt_core::OptimizationCriteria {
         strategy,
+        slashed: app.slashed_validators(),
         ..stkopt_core::OptimizationCriteria::default()
     };
     let optimized = stkopt_core::optimize_display_validators(&app.validators, &criteria);
@@ -132,19 +288,20 @@ fn optimize_nomination(
 
 /// Network argument that can be parsed from string.
 #[derive(Debug, Clone)]
-struct NetworkArg(Network);
+struct NetworkArg(NetworkConfig);
 
 impl std::str::FromStr for NetworkArg {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
-            "polkadot" | "dot" => Ok(NetworkArg(Network::Polkadot)),
-            "kusama" | "ksm" => Ok(NetworkArg(Network::Kusama)),
-            "westend" | "wnd" => Ok(NetworkArg(Network::Westend)),
-            "paseo" | "pas" => Ok(NetworkArg(Network::Paseo)),
+            "polkadot" | "dot" => Ok(NetworkArg(NetworkConfig::Polkadot)),
+            "kusama" | "ksm" => Ok(NetworkArg(NetworkConfig::Kusama)),
+            "westend" | "wnd" => Ok(NetworkArg(NetworkConfig::Westend)),
+            "paseo" | "pas" => Ok(NetworkArg(NetworkConfig::Paseo)),
+            "custom" => Ok(NetworkArg(NetworkConfig::Custom)),
             _ => Err(format!(
-                "Unknown network '{}'. Valid options: polkadot, kusama, westend, paseo",
+                "Unknown network '{}'. Valid options: polkadot, kusama, westend, paseo, custom",
                 s
             )),
         }
@@ -169,8 +326,8 @@ async fn main() -> Result<()> {
         .add_directive("stkopt_core=info".parse()?);
     let env_filter = suppress_light_client_chatter(env_filter);
 
-    if args.update {
-        // In update mode, log to stderr so user can see progress
+    if args.update || args.command.is_some() {
+        // In update and command modes, log to stderr so user can see progress
         tracing_subscriber::registry()
             .with(env_filter)
             .with(tracing_subscriber::fmt::layer().with_writer(std::io::stderr))
@@ -183,20 +340,42 @@ async fn main() -> Result<()> {
             .init();
     }
 
-    let network = args.network.0;
+    // Load configuration
+    let mut app_config = config::load_config().unwrap_or_default();
+    tracing::info!("Loaded {} saved account(s)", app_config.accounts.len());
+
+    // Resolve the network, taking a custom one from its config.json definition
+    let session = args.session();
+    let network = match &session {
+        Some(SessionMode::Replay(path)) => stkopt_chain::recorded_network(path)?,
+        _ => app_config.network_for(args.network.0)?.ok_or_else(|| {
+            color_eyre::eyre::eyre!(
+                "Network 'custom' needs a custom_network definition in config.json"
+            )
+        })?,
+    };
+
+    // Per-network mortality, with the command line override for this session
+    let mut mortality_periods = app_config.network_mortality_periods();
+    if let Some(period) = args.mortality_period {
+        mortality_periods.insert(
+            network.clone(),
+            stkopt_core::normalize_mortality_period(period),
+        );
+    }
 
     // Build connection configuration
     let connection_config = ConnectionConfig {
-        mode: if args.rpc {
-            ConnectionMode::Rpc
-        } else {
-            ConnectionMode::LightClient
-        },
+        mode: args.connection_mode(),
         rpc_endpoints: RpcEndpoints {
             asset_hub: args.asset_hub_url.clone(),
             relay: args.relay_url.clone(),
             people: args.people_url.clone(),
         },
+        mortality_periods,
+        session,
+        endpoint_health: Vec::new(),
+        light_client_dir: stkopt_core::config::get_light_client_dir().ok(),
     };
 
     // Handle update mode (batch mode for cron jobs)
@@ -204,6 +383,13 @@ async fn main() -> Result<()> {
         return run_update_mode(network, connection_config.clone(), args.address, args.eras).await;
     }
 
+    if let Some(Command::Health { address }) = &args.command {
+        if run_health_check(network, connection_config.clone(), address).await? {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
     // Create action channel for responses from chain task to UI
     const ACTION_CHANNEL_CAPACITY: usize = 100;
     let (action_tx, mut action_rx) = mpsc::channel::<Action>(ACTION_CHANNEL_CAPACITY);
@@ -218,12 +404,16 @@ async fn main() -> Result<()> {
     // Detect terminal theme (must be done before entering raw mode)
     let theme = theme::Theme::detect();
 
-    // Load configuration
-    let mut app_config = config::load_config().unwrap_or_default();
-    tracing::info!("Loaded {} saved account(s)", app_config.accounts.len());
-
     // Create application state
-    let mut app = App::new(network, log_buffer, theme);
+    let mut app = App::new(network.clone(), log_buffer, theme);
+    app.custom_network = match app_config.custom() {
+        Ok(custom) => custom,
+        Err(e) => {
+            tracing::warn!("Ignoring custom network: {}", e);
+            None
+        }
+    };
+    app.saved_addresses = config::load_saved_addresses(network.clone());
 
     // Load cached data from database before chain connects
     let db_path = get_db_path().unwrap_or_else(|_| PathBuf::from("stkopt_history.db"));
@@ -232,7 +422,7 @@ async fn main() -> Result<()> {
     }
     let _ = chain_task::migrate_legacy_history_db(&db_path);
     if let Ok(db) = db::HistoryDb::open(&db_path) {
-        match StartupDataService::load(&db, network, 0, CachePolicy::default()) {
+        match StartupDataService::load(&db, network.clone(), 0, CachePolicy::default()) {
             Ok(startup) => {
                 if startup.validators.is_displayable() && !startup.validators.data.is_empty() {
                     if cached_validators_have_chain_data(&startup.validators.data) {
@@ -275,7 +465,7 @@ async fn main() -> Result<()> {
         if let Ok(db) = db::HistoryDb::open(&db_path)
             && let Ok(cached_history) = HistoryService::load_latest(
                 &db,
-                network,
+                network.clone(),
                 last_addr,
                 Some(30),
                 CachePolicy::default(),
@@ -318,8 +508,15 @@ async fn main() -> Result<()> {
 
     // Spawn chain connection task
     let chain_action_tx = action_tx.clone();
-    tokio::spawn(async move {
-        chain_task(network, connection_config, chain_action_tx, request_rx).await;
+    let chain_network = network.clone();
+    let chain_handle = tokio::spawn(async move {
+        chain_task(
+            chain_network,
+            connection_config,
+            chain_action_tx,
+            request_rx,
+        )
+        .await;
     });
 
     // Send restored account request (will be processed once chain connects)
@@ -353,7 +550,11 @@ async fn main() -> Result<()> {
                                         preview.height,
                                         preview.qr_bounds,
                                     )).await;
-                                    let _ = action_tx.send(Action::SignatureScanned(data)).await;
+                                    if app.camera.account_scan {
+                                        let _ = action_tx.send(Action::AccountQrScanned(data)).await;
+                                    } else {
+                                        let _ = action_tx.send(Action::SignatureScanned(data)).await;
+                                    }
                                     // Stop scanning after successful scan
                                     if let Some(ref mut r) = qr_reader {
                                         r.stop();
@@ -508,6 +709,32 @@ async fn main() -> Result<()> {
                                 .await;
                         }
                     }
+                    Action::GenerateRebagQR => {
+                        if let Some(account) = &app.watched_account {
+                            let _ = request_tx
+                                .send(ChainRequest::ExecuteStakingOp(StakingOp::Rebag {
+                                    signer: account.clone(),
+                                }))
+                                .await;
+                        }
+                    }
+                    Action::GeneratePutInFrontOfQR => {
+                        let lighter = app
+                            .bags_position
+                            .as_ref()
+                            .and_then(|p| p.lighter_ahead.as_deref())
+                            .and_then(|a| {
+                                <subxt::utils::AccountId32 as std::str::FromStr>::from_str(a).ok()
+                            });
+                        if let (Some(account), Some(lighter)) = (&app.watched_account, lighter) {
+                            let _ = request_tx
+                                .send(ChainRequest::ExecuteStakingOp(StakingOp::PutInFrontOf {
+                                    signer: account.clone(),
+                                    lighter,
+                                }))
+                                .await;
+                        }
+                    }
                     Action::GeneratePoolJoinQR { pool_id, amount } => {
                         if let Some(account) = &app.watched_account {
                             let _ = request_tx
@@ -557,6 +784,74 @@ async fn main() -> Result<()> {
                                 .await;
                         }
                     }
+                    Action::GeneratePoolSetClaimPermissionQR { permission } => {
+                        if let Some(account) = &app.watched_account {
+                            let _ = request_tx
+                                .send(ChainRequest::ExecuteStakingOp(
+                                    StakingOp::PoolSetClaimPermission {
+                                        signer: account.clone(),
+                                        permission: *permission,
+                                    },
+                                ))
+                                .await;
+                        }
+                    }
+                    Action::GeneratePoolMemberCallQR { member, call } => {
+                        if let Some(account) = &app.watched_account {
+                            let _ = request_tx
+                                .send(ChainRequest::ExecuteStakingOp(StakingOp::PoolMemberCall {
+                                    signer: *account,
+                                    member: *member,
+                                    call: *call,
+                                }))
+                                .await;
+                        }
+                    }
+                    Action::GeneratePoolAdminQR { pool_id, op } => {
+                        if let Some(account) = &app.watched_account {
+                            let staking_op = match op {
+                                PoolAdminOp::Nominate => {
+                                    // Pools nominate the validators picked in the Nominate view
+                                    let targets: Vec<subxt::utils::AccountId32> = app
+                                        .selected_validators
+                                        .iter()
+                                        .filter_map(|&idx| app.validators.get(idx))
+                                        .filter_map(|v| {
+                                            <subxt::utils::AccountId32 as std::str::FromStr>::from_str(
+                                                &v.address,
+                                            )
+                                            .ok()
+                                        })
+                                        .collect();
+                                    (!targets.is_empty()).then(|| StakingOp::PoolNominate {
+                                        signer: account.clone(),
+                                        pool_id: *pool_id,
+                                        targets,
+                                    })
+                                }
+                                _ => Some(StakingOp::PoolAdmin {
+                                    signer: account.clone(),
+                                    pool_id: *pool_id,
+                                    op: op.clone(),
+                                }),
+                            };
+                            match staking_op {
+                                Some(staking_op) => {
+                                    let _ = request_tx
+                                        .send(ChainRequest::ExecuteStakingOp(staking_op))
+                                        .await;
+                                }
+                                None => {
+                                    let _ = action_tx
+                                        .send(Action::SetNominationStatus(Some(
+                                            "Select validators first, or press o to optimize."
+                                                .to_string(),
+                                        )))
+                                        .await;
+                                }
+                            }
+                        }
+                    }
                     Action::GenerateNominationQR => {
                         // Get selected validator addresses
                         if app.watched_account.is_none() {
@@ -572,6 +867,17 @@ async fn main() -> Result<()> {
                                     "Select validators first, or press o to optimize.".to_string(),
                                 )))
                                 .await;
+                        } else if let Some(issue) =
+                            app.nomination_issue().filter(|issue| issue.is_error())
+                        {
+                            let _ = action_tx
+                                .send(Action::SetNominationStatus(Some(
+                                    issue.message(
+                                        app.network.token_decimals(),
+                                        app.network.token_symbol(),
+                                    ),
+                                )))
+                                .await;
                         } else if let Some(account) = &app.watched_account {
                             let targets: Vec<subxt::utils::AccountId32> = app
                                 .selected_validators
@@ -647,6 +953,7 @@ async fn main() -> Result<()> {
                                         signed_extrinsic: signed.encoded,
                                         tx_hash: signed.hash,
                                         status: TxSubmissionStatus::ReadyToSubmit,
+                                        journal_id: None,
                                     });
 
                                     // Stop camera and clear scanning state
@@ -670,9 +977,25 @@ async fn main() -> Result<()> {
                             }
                         }
                     }
+                    Action::RebuildPendingTx => {
+                        if let Some(pending) = app.qr.pending_unsigned.clone() {
+                            let targets = app
+                                .qr
+                                .tx_info
+                                .as_ref()
+                                .map(|info| info.targets.clone())
+                                .unwrap_or_default();
+                            let _ = request_tx
+                                .send(ChainRequest::RefreshPayload { pending, targets })
+                                .await;
+                        }
+                    }
                     Action::SubmitTransaction => {
                         // Submit the signed transaction via chain task
-                        if let Some(ref pending_tx) = app.qr.pending_signed {
+                        if app.pending_tx_expired() {
+                            tracing::warn!("Pending transaction expired; rebuild it before submitting");
+                            let _ = action_tx.send(Action::SetTxStatus(TxSubmissionStatus::Expired)).await;
+                        } else if let Some(ref mut pending_tx) = app.qr.pending_signed {
                             let extrinsic = pending_tx.signed_extrinsic.clone();
                             let _ = action_tx.send(Action::SetTxStatus(TxSubmissionStatus::Submitting)).await;
 
@@ -682,10 +1005,126 @@ async fn main() -> Result<()> {
                                 extrinsic.len()
                             );
 
+                            // Record in the transaction journal (audit trail)
+                            if pending_tx.journal_id.is_none()
+                                && let Some(ref unsigned) = app.qr.pending_unsigned
+                                && let Some(db) = open_staking_db()
+                            {
+                                let entry = journal_entry(app.network.clone(), unsigned, &pending_tx.tx_hash);
+                                match db.record_transaction(app.network.clone(), &entry) {
+                                    Ok(id) => pending_tx.journal_id = Some(id),
+                                    Err(e) => tracing::warn!("Failed to journal transaction: {}", e),
+                                }
+                            }
+
                             // Send to chain task for submission
                             let _ = request_tx.send(ChainRequest::SubmitTransaction(extrinsic)).await;
                         }
                     }
+                    Action::SetTxStatus(status) => {
+                        let outcome = journal_update(status);
+                        if let Some((journal_status, block_hash, result)) = outcome
+                            && let Some(id) = app.qr.pending_signed.as_ref().and_then(|tx| tx.journal_id)
+                            && let Some(db) = open_staking_db()
+                        {
+                            if let Err(e) = db.set_transaction_status(
+                                id,
+                                journal_status,
+                                block_hash.as_deref(),
+                                result.as_deref(),
+                            ) {
+                                tracing::warn!("Failed to update transaction journal: {}", e);
+                            }
+                            let _ = action_tx.send(Action::LoadTransactions).await;
+                        }
+                    }
+                    Action::LoadPortfolio => {
+                        let accounts = app_config
+                            .accounts_on_network(app.network.clone())
+                            .into_iter()
+                            .filter_map(|saved| {
+                                use std::str::FromStr;
+                                match subxt::utils::AccountId32::from_str(&saved.address) {
+                                    Ok(account) => Some((account, saved.label.clone())),
+                                    Err(e) => {
+                                        tracing::warn!("Skipping saved account {}: {}", saved.address, e);
+                                        None
+                                    }
+                                }
+                            })
+                            .collect();
+                        let _ = request_tx.send(ChainRequest::FetchPortfolio(accounts)).await;
+                    }
+                    Action::LoadTransactions => {
+                        if let Some(account) = &app.watched_account
+                            && let Some(db) = open_staking_db()
+                        {
+                            let filter =
+                                TransactionFilter::for_signer(journal_signer(app.network.clone(), account));
+                            match db.get_transactions(app.network.clone(), &filter) {
+                                Ok(records) => {
+                                    let _ = action_tx.send(Action::SetTransactions(records)).await;
+                                }
+                                Err(e) => tracing::warn!("Failed to load transaction journal: {}", e),
+                            }
+                        }
+                    }
+                    Action::ExportTransactions => {
+                        let records: Vec<_> =
+                            app.filtered_transactions().into_iter().cloned().collect();
+                        let signer = app
+                            .watched_account
+                            .as_ref()
+                            .map(|account| journal_signer(app.network.clone(), account));
+                        let format = args.journal_export_format;
+                        let path = args
+                            .journal_export_dir
+                            .join(default_journal_file_name(signer.as_deref(), format));
+                        let result = stkopt_core::export_transactions(&records, format)
+                            .map_err(|e| e.to_string())
+                            .and_then(|contents| {
+                                std::fs::create_dir_all(&args.journal_export_dir)
+                                    .and_then(|_| std::fs::write(&path, contents))
+                                    .map_err(|e| e.to_string())
+                            })
+                            .map(|_| {
+                                tracing::info!(
+                                    "Exported {} transaction(s) to {}",
+                                    records.len(),
+                                    path.display()
+                                );
+                                path.display().to_string()
+                            });
+                        let _ = action_tx.send(Action::TransactionsExported(result)).await;
+                    }
+                    Action::StartAccountScan => {
+                        // Start camera capture for Vault account export QR
+                        match qr_reader::QrReader::new() {
+                            Ok(reader) => {
+                                qr_reader = Some(reader);
+                                tracing::info!("Started camera for Vault account QR scanning");
+                            }
+                            Err(e) => {
+                                tracing::error!("Failed to start camera: {}", e);
+                                let _ = action_tx.send(Action::QrScanFailed(e)).await;
+                            }
+                        }
+                    }
+                    Action::AccountQrScanned(data) => {
+                        let result = app.vault_account_from_qr(data).and_then(|account| {
+                            let mut book = config::load_address_book().unwrap_or_default();
+                            let entry = book
+                                .add_vault_account(&account, app.network.clone(), None)
+                                .map_err(|e| e.to_string())?;
+                            config::save_address_book(&book).map_err(|e| e.to_string())?;
+                            tracing::info!("Imported Vault account {} ({})", entry.label, entry.address);
+                            Ok(entry)
+                        });
+                        if let Err(ref e) = result {
+                            tracing::warn!("Vault account import failed: {}", e);
+                        }
+                        let _ = action_tx.send(Action::VaultAccountImported(result)).await;
+                    }
                     Action::StartSignatureScan => {
                         // Start camera capture for QR scanning
                         match qr_reader::QrReader::new() {
@@ -700,6 +1139,28 @@ async fn main() -> Result<()> {
                             }
                         }
                     }
+                    Action::ExportQr => {
+                        if let Some(data) = app.qr.data.clone() {
+                            // Encoding a multipart GIF can take a while; keep it off the UI loop.
+                            let format = args.qr_export_format;
+                            let dir = args.qr_export_dir.clone();
+                            let tx = action_tx.clone();
+                            tokio::task::spawn_blocking(move || {
+                                let result = stkopt_chain::write_qr_export(&data, format, &dir)
+                                    .map(|(path, export)| {
+                                        tracing::info!(
+                                            "Exported {} QR ({} frame(s)) to {}",
+                                            export.format,
+                                            export.frames,
+                                            path.display()
+                                        );
+                                        path.display().to_string()
+                                    })
+                                    .map_err(|e| e.to_string());
+                                let _ = tx.blocking_send(Action::QrExported(result));
+                            });
+                        }
+                    }
                     Action::StopSignatureScan => {
                         // Stop the QR reader
                         if let Some(ref mut reader) = qr_reader {
@@ -731,37 +1192,46 @@ async fn main() -> Result<()> {
                             }).await;
                         }
                     }
+                    Action::ToggleExcludeReported if !app.exclude_reported => {
+                        // Turning it on; the app flips the flag below
+                        let _ = request_tx.send(ChainRequest::FetchOffenceReports).await;
+                    }
+                    Action::CheckPayouts => {
+                        let eras = app.history.points.first().zip(app.history.points.last());
+                        if let (Some(account), Some((first, last))) = (&app.watched_account, eras) {
+                            let _ = request_tx.send(ChainRequest::CheckPayouts {
+                                account: account.clone(),
+                                start_era: first.era,
+                                end_era: last.era,
+                            }).await;
+                        }
+                    }
                     Action::CancelLoadingHistory => {
                         // Signal cancellation
                         let _ = history_cancel_tx.send(true);
                     }
                     Action::SwitchNetwork(network) => {
-                        let _ = request_tx.send(ChainRequest::Reconnect(*network)).await;
+                        let _ = request_tx.send(ChainRequest::Reconnect(network.clone())).await;
+                        app.saved_addresses = config::load_saved_addresses(network.clone());
                     }
                     Action::SelectAddressBookEntry(idx) => {
                         let idx = *idx;
                         tracing::info!("[ADDR] SelectAddressBookEntry idx={}, watched={}", idx, app.watched_account.is_some());
 
-                        // Determine the actual index (accounting for "My Account")
-                        let known_idx = if app.watched_account.is_some() {
-                            if idx == 0 {
-                                tracing::info!("[ADDR] My Account selected, skipping");
-                                continue;
-                            }
-                            idx - 1
-                        } else {
-                            idx
-                        };
+                        if app.watched_account.is_some() && idx == 0 {
+                            tracing::info!("[ADDR] My Account selected, skipping");
+                            continue;
+                        }
 
-                        tracing::info!("[ADDR] known_idx={}, known_addresses.len()={}", known_idx, app::KNOWN_ADDRESSES.len());
-                        if let Some((name, addr)) = app::KNOWN_ADDRESSES.get(known_idx) {
+                        // Rows: My Account, saved addresses, then known addresses
+                        if let Some((name, addr)) = app.address_book_entry(idx) {
                             use std::str::FromStr;
                             tracing::info!("[ADDR] Selected: {} ({})", name, addr);
-                            if let Ok(account) = subxt::utils::AccountId32::from_str(addr) {
-                                let _ = action_tx.send(Action::SetWatchedAccount(account, addr.to_string())).await;
+                            if let Ok(account) = subxt::utils::AccountId32::from_str(&addr) {
+                                let _ = action_tx.send(Action::SetWatchedAccount(account, addr)).await;
                             }
                         } else {
-                            tracing::warn!("[ADDR] known_idx {} out of bounds!", known_idx);
+                            tracing::warn!("[ADDR] address book index {} out of bounds!", idx);
                         }
                     }
                     Action::RemoveAccount(address) => {
@@ -803,7 +1273,11 @@ async fn main() -> Result<()> {
                         {
                             app.watched_account = None;
                             app.account_status = None;
+                            app.bags_position = None;
+                            app.pool_admin = PoolAdminState::default();
                             app.history.points.clear();
+                            app.history.exact_points.clear();
+                            app.history.payout_checks.clear();
                             app.history.loaded_for = None;
                         }
                     }
@@ -821,6 +1295,16 @@ async fn main() -> Result<()> {
     // Restore terminal
     tui.exit()?;
 
+    // Closing the request channel lets the chain task save light-client state
+    const CHAIN_SHUTDOWN_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+    drop(request_tx);
+    if tokio::time::timeout(CHAIN_SHUTDOWN_TIMEOUT, chain_handle)
+        .await
+        .is_err()
+    {
+        tracing::warn!("Chain task did not shut down in time");
+    }
+
     Ok(())
 }
 
@@ -853,47 +1337,223 @@ mod tests {
 
     // ── NetworkArg::from_str ──────────────────────────────────────────────
 
+    #[test]
+    fn test_args_qr_export_defaults() {
+        let args = Args::try_parse_from(["stkopt"]).unwrap();
+        assert_eq!(args.qr_export_dir, PathBuf::from("."));
+        assert_eq!(args.qr_export_format, QrExportFormat::Auto);
+    }
+
+    #[test]
+    fn test_args_qr_export_flags() {
+        let args = Args::try_parse_from([
+            "stkopt",
+            "--qr-export-dir",
+            "/tmp/qr",
+            "--qr-export-format",
+            "gif",
+        ])
+        .unwrap();
+        assert_eq!(args.qr_export_dir, PathBuf::from("/tmp/qr"));
+        assert_eq!(args.qr_export_format, QrExportFormat::Gif);
+        assert!(Args::try_parse_from(["stkopt", "--qr-export-format", "bmp"]).is_err());
+    }
+
+    #[test]
+    fn test_args_journal_export_flags() {
+        let args = Args::try_parse_from(["stkopt"]).unwrap();
+        assert_eq!(args.journal_export_dir, PathBuf::from("."));
+        assert_eq!(args.journal_export_format, JournalExportFormat::Csv);
+
+        let args = Args::try_parse_from([
+            "stkopt",
+            "--journal-export-dir",
+            "/tmp/audit",
+            "--journal-export-format",
+            "json",
+        ])
+        .unwrap();
+        assert_eq!(args.journal_export_dir, PathBuf::from("/tmp/audit"));
+        assert_eq!(args.journal_export_format, JournalExportFormat::Json);
+        assert!(Args::try_parse_from(["stkopt", "--journal-export-format", "xml"]).is_err());
+    }
+
+    #[test]
+    fn test_args_session_flags() {
+        let args = Args::try_parse_from(["stkopt"]).unwrap();
+        assert!(args.session().is_none());
+
+        let args = Args::try_parse_from(["stkopt", "--record", "/tmp/bug.json"]).unwrap();
+        assert_eq!(
+            args.session(),
+            Some(SessionMode::Record(PathBuf::from("/tmp/bug.json")))
+        );
+
+        let args = Args::try_parse_from(["stkopt", "--replay", "/tmp/bug.json"]).unwrap();
+        assert_eq!(
+            args.session(),
+            Some(SessionMode::Replay(PathBuf::from("/tmp/bug.json")))
+        );
+
+        assert!(
+            Args::try_parse_from(["stkopt", "--record", "a.json", "--replay", "b.json"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_args_connection_mode() {
+        let mode = |args: &[&str]| Args::try_parse_from(args).unwrap().connection_mode();
+        assert_eq!(mode(&["stkopt"]), ConnectionMode::LightClient);
+        assert_eq!(mode(&["stkopt", "--rpc"]), ConnectionMode::Rpc);
+        assert_eq!(mode(&["stkopt", "--hybrid"]), ConnectionMode::Hybrid);
+        assert_eq!(
+            mode(&["stkopt", "--hybrid", "--record", "a.json"]),
+            ConnectionMode::Rpc
+        );
+        assert!(Args::try_parse_from(["stkopt", "--rpc", "--hybrid"]).is_err());
+    }
+
+    #[test]
+    fn test_journal_update_records_in_block() {
+        let (status, block_hash, result) = journal_update(&TxSubmissionStatus::InBlock {
+            block_hash: [0x11; 32],
+            outcome: None,
+        })
+        .unwrap();
+        assert_eq!(status, TxJournalStatus::InBlock);
+        assert_eq!(block_hash, Some(format!("0x{}", "11".repeat(32))));
+        assert!(result.is_none());
+
+        let (status, ..) = journal_update(&TxSubmissionStatus::Finalized {
+            block_hash: [0x11; 32],
+            outcome: None,
+        })
+        .unwrap();
+        assert_eq!(status, TxJournalStatus::Finalized);
+    }
+
+    #[test]
+    fn test_journal_update_failures_and_pending() {
+        let (status, block_hash, result) =
+            journal_update(&TxSubmissionStatus::Failed("dropped".to_string())).unwrap();
+        assert_eq!(status, TxJournalStatus::Failed);
+        assert!(block_hash.is_none());
+        assert_eq!(result.as_deref(), Some("dropped"));
+        assert!(journal_update(&TxSubmissionStatus::Expired).is_some());
+        assert!(journal_update(&TxSubmissionStatus::Submitting).is_none());
+        assert!(journal_update(&TxSubmissionStatus::ReadyToSubmit).is_none());
+    }
+
+    #[test]
+    fn test_journal_entry_from_pending_tx() {
+        let pending = action::PendingUnsignedTx {
+            payload: stkopt_chain::UnsignedPayload {
+                call_data: vec![0x07, 0x03],
+                description: "Bond 10 DOT".to_string(),
+                metadata_hash: [0u8; 32],
+                genesis_hash: [0u8; 32],
+                block_hash: [0u8; 32],
+                spec_version: 0,
+                tx_version: 0,
+                nonce: 9,
+                era: stkopt_chain::Era::Mortal {
+                    period: 64,
+                    phase: 3,
+                },
+                birth_block: Some(3),
+                include_metadata_hash: false,
+                use_asset_payment: false,
+                extension_ids: vec![],
+            },
+            signer: AccountId32::from([0u8; 32]),
+        };
+        let entry = journal_entry(Network::Polkadot, &pending, &[0xab; 32]);
+        assert_eq!(entry.description, "Bond 10 DOT");
+        assert_eq!(entry.call_data, "0x0703");
+        assert_eq!(entry.nonce, 9);
+        assert_eq!(
+            entry.mortality,
+            TxMortality::Mortal {
+                period: 64,
+                phase: 3
+            }
+        );
+        assert_eq!(entry.tx_hash, format!("0x{}", "ab".repeat(32)));
+        // Polkadot addresses start with '1'
+        assert!(entry.signer.starts_with('1'));
+        assert_eq!(
+            entry.signer,
+            journal_signer(Network::Polkadot, &pending.signer)
+        );
+    }
+
     #[test]
     fn test_network_arg_from_str_polkadot() {
         assert_eq!(
             NetworkArg::from_str("polkadot").unwrap().0,
-            Network::Polkadot
+            NetworkConfig::Polkadot
         );
     }
 
     #[test]
     fn test_network_arg_from_str_dot() {
-        assert_eq!(NetworkArg::from_str("dot").unwrap().0, Network::Polkadot);
+        assert_eq!(
+            NetworkArg::from_str("dot").unwrap().0,
+            NetworkConfig::Polkadot
+        );
     }
 
     #[test]
     fn test_network_arg_from_str_kusama() {
-        assert_eq!(NetworkArg::from_str("kusama").unwrap().0, Network::Kusama);
+        assert_eq!(
+            NetworkArg::from_str("kusama").unwrap().0,
+            NetworkConfig::Kusama
+        );
     }
 
     #[test]
     fn test_network_arg_from_str_ksm() {
-        assert_eq!(NetworkArg::from_str("ksm").unwrap().0, Network::Kusama);
+        assert_eq!(
+            NetworkArg::from_str("ksm").unwrap().0,
+            NetworkConfig::Kusama
+        );
     }
 
     #[test]
     fn test_network_arg_from_str_westend() {
-        assert_eq!(NetworkArg::from_str("westend").unwrap().0, Network::Westend);
+        assert_eq!(
+            NetworkArg::from_str("westend").unwrap().0,
+            NetworkConfig::Westend
+        );
     }
 
     #[test]
     fn test_network_arg_from_str_wnd() {
-        assert_eq!(NetworkArg::from_str("wnd").unwrap().0, Network::Westend);
+        assert_eq!(
+            NetworkArg::from_str("wnd").unwrap().0,
+            NetworkConfig::Westend
+        );
     }
 
     #[test]
     fn test_network_arg_from_str_paseo() {
-        assert_eq!(NetworkArg::from_str("paseo").unwrap().0, Network::Paseo);
+        assert_eq!(
+            NetworkArg::from_str("paseo").unwrap().0,
+            NetworkConfig::Paseo
+        );
     }
 
     #[test]
     fn test_network_arg_from_str_pas() {
-        assert_eq!(NetworkArg::from_str("pas").unwrap().0, Network::Paseo);
+        assert_eq!(NetworkArg::from_str("pas").unwrap().0, NetworkConfig::Paseo);
+    }
+
+    #[test]
+    fn test_network_arg_from_str_custom() {
+        assert_eq!(
+            NetworkArg::from_str("Custom").unwrap().0,
+            NetworkConfig::Custom
+        );
     }
 
     #[test]
@@ -907,12 +1567,24 @@ mod tests {
     fn test_network_arg_from_str_case_insensitive() {
         assert_eq!(
             NetworkArg::from_str("PolKaDot").unwrap().0,
-            Network::Polkadot
+            NetworkConfig::Polkadot
+        );
+        assert_eq!(
+            NetworkArg::from_str("DOT").unwrap().0,
+            NetworkConfig::Polkadot
+        );
+        assert_eq!(
+            NetworkArg::from_str("KUSAMA").unwrap().0,
+            NetworkConfig::Kusama
+        );
+        assert_eq!(
+            NetworkArg::from_str("WestEnd").unwrap().0,
+            NetworkConfig::Westend
+        );
+        assert_eq!(
+            NetworkArg::from_str("PaSeO").unwrap().0,
+            NetworkConfig::Paseo
         );
-        assert_eq!(NetworkArg::from_str("DOT").unwrap().0, Network::Polkadot);
-        assert_eq!(NetworkArg::from_str("KUSAMA").unwrap().0, Network::Kusama);
-        assert_eq!(NetworkArg::from_str("WestEnd").unwrap().0, Network::Westend);
-        assert_eq!(NetworkArg::from_str("PaSeO").unwrap().0, Network::Paseo);
     }
 
     #[test]
@@ -1,7 +1,7 @@
 //! UI rendering.
 
 use crate::action::StakingInputMode;
-use crate::app::{App, InputMode, PoolSortField, ValidatorSortField, View};
+use crate::app::{App, InputMode, POOL_STATES, PoolSortField, ValidatorSortField, View};
 use crate::log_buffer::LogLevel;
 use crate::theme::Palette;
 use qrcode::{EcLevel, QrCode, Version};
@@ -10,10 +10,17 @@ use ratatui::{
     layout::{Alignment, Constraint, Layout, Rect},
     style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, Tabs},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, Tabs, Wrap},
+};
+use stkopt_chain::{
+    NominatorExposure, RewardDestination, UnbondingEntry, UnbondingSource, qr_export,
+};
+use stkopt_core::mortality::EXPIRY_WARNING_SECS;
+use stkopt_core::{
+    BagsListPosition, ClaimPermission, ConnectionStatus, HealthReport, PayoutStatus,
+    PoolAdminAction, PoolMemberCall, PoolState, Severity, SlashRiskReport, TxJournalStatus,
+    compare_rewards, era_payout_status, format_countdown, format_perbill,
 };
-use stkopt_chain::RewardDestination;
-use stkopt_core::{ConnectionStatus, PoolState};
 
 /// Safely truncate a string to a maximum number of characters (not bytes).
 /// Handles multi-byte Unicode characters correctly.
@@ -84,6 +91,11 @@ pub fn render(frame: &mut Frame, app: &mut App) {
         render_account_prompt(frame, app);
     }
 
+    // Render Vault account scan popup
+    if app.camera.account_scan {
+        render_account_scan_modal(frame, app);
+    }
+
     // Render loading spinner overlay if chain is connecting and no cached data
     if app.loading.chain && app.validators.is_empty() {
         render_loading_spinner(frame, app);
@@ -165,7 +177,7 @@ fn render_account_prompt(frame: &mut Frame, app: &App) {
         Line::from("No account configured").style(Style::default().fg(p.accent).bold()),
         Line::from(""),
         Line::from("Press 'a' to enter your stash account address"),
-        Line::from("or scan a QR code from Polkadot Vault."),
+        Line::from("or 'v' to scan an account QR from Polkadot Vault."),
         Line::from(""),
         Line::from("Press 'q' to quit").style(Style::default().fg(p.muted)),
     ];
@@ -184,6 +196,45 @@ fn render_account_prompt(frame: &mut Frame, app: &App) {
     frame.render_widget(paragraph, prompt_area);
 }
 
+/// Render the Vault account export scanner popup.
+fn render_account_scan_modal(frame: &mut Frame, app: &App) {
+    let pal = &app.palette;
+    let area = frame.area();
+
+    let modal_width = 70.min(area.width.saturating_sub(4));
+    let modal_height = 36.min(area.height.saturating_sub(2));
+    let modal_x = (area.width.saturating_sub(modal_width)) / 2;
+    let modal_y = (area.height.saturating_sub(modal_height)) / 2;
+    let modal_area = Rect::new(modal_x, modal_y, modal_width, modal_height);
+
+    frame.render_widget(Clear, modal_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(pal.primary))
+        .title(" Add Account from Vault ")
+        .style(Style::default().bg(pal.bg));
+    let inner_area = block.inner(modal_area);
+    frame.render_widget(block, modal_area);
+
+    let chunks = Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).split(inner_area);
+    render_scan_camera(frame, app, chunks[0]);
+
+    let footer = match app.address_book_status {
+        Some(ref status) if app.camera.status == Some(crate::app::CameraScanStatus::Error) => {
+            format!("{}  Esc:Close", status)
+        }
+        _ => format!(
+            "Export the account for {} in Vault  Esc:Cancel",
+            app.network
+        ),
+    };
+    frame.render_widget(
+        Paragraph::new(footer).alignment(Alignment::Center),
+        chunks[1],
+    );
+}
+
 /// Render the header with network info and era status.
 fn render_header(frame: &mut Frame, app: &App, area: Rect) {
     let p = &app.palette;
@@ -273,9 +324,332 @@ fn render_content(frame: &mut Frame, app: &mut App, area: Rect) {
         View::AccountStatus => render_account_status(frame, app, area),
         View::AccountChanges => render_account_changes(frame, app, area),
         View::AccountHistory => render_account_history(frame, app, area),
+        View::Transactions => render_transactions(frame, app, area),
+        View::Portfolio => render_portfolio(frame, app, area),
     }
 }
 
+/// Render the saved-accounts portfolio view.
+fn render_portfolio(frame: &mut Frame, app: &mut App, area: Rect) {
+    let chunks = Layout::vertical([Constraint::Length(4), Constraint::Min(0)]).split(area);
+    let pal = &app.palette;
+    let decimals = app.network.token_decimals();
+    let symbol = app.network.token_symbol();
+    let label_style = Style::default().fg(pal.fg_dim);
+    let format_apy = |apy: Option<f64>| {
+        apy.map(|apy| format!("{:.2}%", apy * 100.0))
+            .unwrap_or_else(|| "-".to_string())
+    };
+
+    let summary = app.portfolio_summary();
+    let summary_lines = vec![
+        Line::from(vec![
+            Span::styled("Bonded: ", label_style),
+            Span::raw(format!(
+                "{} {}",
+                format_balance(summary.total_bonded, decimals),
+                symbol
+            )),
+            Span::styled("   Unbonding: ", label_style),
+            Span::raw(format!(
+                "{} {}",
+                format_balance(summary.total_unbonding, decimals),
+                symbol
+            )),
+        ]),
+        Line::from(vec![
+            Span::styled("Pending rewards: ", label_style),
+            Span::raw(format!(
+                "{} {}",
+                format_balance(summary.total_pending_rewards, decimals),
+                symbol
+            )),
+            Span::styled("   Weighted APY: ", label_style),
+            Span::styled(
+                format_apy(summary.weighted_apy),
+                Style::default().fg(pal.success),
+            ),
+        ]),
+    ];
+    let summary_block = Paragraph::new(summary_lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(pal.border))
+            .title(format!(" Portfolio ({} accounts) ", summary.accounts)),
+    );
+    frame.render_widget(summary_block, chunks[0]);
+
+    let title = " Saved Accounts | Enter:Open  r:Reload ";
+    let message = if app.portfolio.loading {
+        Some("Loading saved accounts...".to_string())
+    } else if let Some(error) = &app.portfolio.error {
+        Some(format!("Failed to load portfolio: {}", error))
+    } else if app.portfolio.accounts.is_empty() {
+        Some("No saved accounts on this network. Watch an account to add it.".to_string())
+    } else {
+        None
+    };
+    if let Some(message) = message {
+        let paragraph = Paragraph::new(vec![
+            Line::from(""),
+            Line::from(Span::styled(
+                format!("  {}", message),
+                Style::default().fg(pal.fg_dim),
+            )),
+        ])
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(pal.border))
+                .title(title),
+        );
+        frame.render_widget(paragraph, chunks[1]);
+        return;
+    }
+
+    let rows: Vec<Row> = app
+        .portfolio
+        .accounts
+        .iter()
+        .map(|a| {
+            let name = match &a.label {
+                Some(label) => truncate_str(label, 24),
+                None => truncate_address(&a.address, 8, 6),
+            };
+            let pool = a
+                .pool
+                .as_ref()
+                .map(|p| format!("#{}", p.pool_id))
+                .unwrap_or_else(|| "-".to_string());
+            Row::new(vec![
+                Cell::from(name),
+                Cell::from(format_balance(a.staked(), decimals)),
+                Cell::from(format_balance(a.unbonding, decimals)),
+                Cell::from(format_balance(a.pending_rewards(), decimals)),
+                Cell::from(pool),
+                Cell::from(format_apy(a.apy(&app.validators, &app.pools))),
+            ])
+        })
+        .collect();
+
+    let header = Row::new(vec![
+        Cell::from("Account").style(Style::default().bold()),
+        Cell::from("Bonded").style(Style::default().bold()),
+        Cell::from("Unbonding").style(Style::default().bold()),
+        Cell::from("Rewards").style(Style::default().bold()),
+        Cell::from("Pool").style(Style::default().bold()),
+        Cell::from("APY").style(Style::default().bold()),
+    ])
+    .style(Style::default().fg(pal.highlight));
+
+    let widths = [
+        Constraint::Min(24),
+        Constraint::Length(12),
+        Constraint::Length(12),
+        Constraint::Length(12),
+        Constraint::Length(8),
+        Constraint::Length(8),
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(pal.border))
+                .title(title),
+        )
+        .row_highlight_style(
+            Style::default()
+                .fg(pal.selection)
+                .add_modifier(Modifier::REVERSED),
+        )
+        .highlight_symbol(">> ");
+
+    frame.render_stateful_widget(table, chunks[1], &mut app.portfolio.table_state);
+}
+
+/// Render the transaction journal view.
+fn render_transactions(frame: &mut Frame, app: &mut App, area: Rect) {
+    let searching = app.input_mode == InputMode::Searching;
+    let chunks = Layout::vertical([
+        Constraint::Length(if searching { 3 } else { 0 }),
+        Constraint::Min(0),
+        Constraint::Length(8),
+    ])
+    .split(area);
+    let (search_area, table_area, detail_area) = (chunks[0], chunks[1], chunks[2]);
+    let pal = &app.palette;
+
+    if searching {
+        let search = Paragraph::new(format!("/{}", app.search_query))
+            .style(Style::default().fg(pal.highlight))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(pal.primary))
+                    .title(" Search (Enter to confirm, Esc to cancel) "),
+            );
+        frame.render_widget(search, search_area);
+    }
+
+    let filtered = app.filtered_transactions();
+    let filter_label = app
+        .journal
+        .status_filter
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| "All".to_string());
+    let mut title = format!(
+        " Transactions ({}/{}) [{}] ",
+        filtered.len(),
+        app.journal.records.len(),
+        filter_label
+    );
+    if !app.search_query.is_empty() {
+        title.push_str(&format!("[filter: {}] ", app.search_query));
+    }
+    title.push_str("| f:Status  /:Search  e:Export  r:Reload ");
+
+    if app.watched_account.is_none() || filtered.is_empty() {
+        let message = if app.watched_account.is_none() {
+            "Watch an account to see its transaction history."
+        } else if app.journal.records.is_empty() {
+            "No transactions submitted from this account yet."
+        } else {
+            "No transactions match the current filter."
+        };
+        let paragraph = Paragraph::new(vec![
+            Line::from(""),
+            Line::from(Span::styled(
+                format!("  {}", message),
+                Style::default().fg(pal.fg_dim),
+            )),
+        ])
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(pal.border))
+                .title(title),
+        );
+        frame.render_widget(paragraph, table_area);
+        render_transaction_detail(frame, app, detail_area);
+        return;
+    }
+
+    let rows: Vec<Row> = filtered
+        .iter()
+        .map(|r| {
+            let status_style = match r.status {
+                TxJournalStatus::Submitted | TxJournalStatus::InBlock => {
+                    Style::default().fg(pal.warning)
+                }
+                TxJournalStatus::Finalized => Style::default().fg(pal.success),
+                TxJournalStatus::Failed => Style::default().fg(pal.error),
+            };
+            Row::new(vec![
+                Cell::from(r.submitted_at.clone()),
+                Cell::from(truncate_str(&r.description, 40)),
+                Cell::from(r.nonce.to_string()),
+                Cell::from(r.status.to_string()).style(status_style),
+                Cell::from(truncate_address(&r.tx_hash, 8, 6)),
+            ])
+        })
+        .collect();
+
+    let header = Row::new(vec![
+        Cell::from("Submitted (UTC)").style(Style::default().bold()),
+        Cell::from("Operation").style(Style::default().bold()),
+        Cell::from("Nonce").style(Style::default().bold()),
+        Cell::from("Status").style(Style::default().bold()),
+        Cell::from("Tx Hash").style(Style::default().bold()),
+    ])
+    .style(Style::default().fg(pal.highlight));
+
+    let widths = [
+        Constraint::Length(20),
+        Constraint::Min(24),
+        Constraint::Length(7),
+        Constraint::Length(10),
+        Constraint::Length(19),
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(pal.border))
+                .title(title),
+        )
+        .row_highlight_style(
+            Style::default()
+                .fg(pal.selection)
+                .add_modifier(Modifier::REVERSED),
+        )
+        .highlight_symbol(">> ");
+
+    frame.render_stateful_widget(table, table_area, &mut app.journal.table_state);
+    render_transaction_detail(frame, app, detail_area);
+}
+
+/// Render details of the selected journaled transaction.
+fn render_transaction_detail(frame: &mut Frame, app: &App, area: Rect) {
+    let pal = &app.palette;
+    let label_style = Style::default().fg(pal.fg_dim);
+    let selected = app
+        .journal
+        .table_state
+        .selected()
+        .and_then(|idx| app.filtered_transactions().get(idx).copied());
+
+    let mut lines = match selected {
+        Some(r) => vec![
+            Line::from(vec![
+                Span::styled("Call data: ", label_style),
+                Span::raw(truncate_str(&r.call_data, 72)),
+            ]),
+            Line::from(vec![
+                Span::styled("Mortality: ", label_style),
+                Span::raw(r.mortality.to_string()),
+                Span::styled("   Nonce: ", label_style),
+                Span::raw(r.nonce.to_string()),
+            ]),
+            Line::from(vec![
+                Span::styled("Tx hash:   ", label_style),
+                Span::raw(r.tx_hash.clone()),
+            ]),
+            Line::from(vec![
+                Span::styled("Block:     ", label_style),
+                Span::raw(r.block_hash.clone().unwrap_or_else(|| "-".to_string())),
+            ]),
+            Line::from(vec![
+                Span::styled("Result:    ", label_style),
+                Span::raw(r.dispatch_result.clone().unwrap_or_else(|| "-".to_string())),
+                Span::styled("   Completed: ", label_style),
+                Span::raw(r.completed_at.clone().unwrap_or_else(|| "-".to_string())),
+            ]),
+        ],
+        None => vec![Line::from(Span::styled(
+            "Select a transaction with ↑/↓ to see details.",
+            label_style,
+        ))],
+    };
+    if let Some(ref status) = app.journal.export_status {
+        lines.push(Line::from(Span::styled(
+            status.clone(),
+            Style::default().fg(pal.highlight),
+        )));
+    }
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(pal.border))
+            .title(" Details "),
+    );
+    frame.render_widget(paragraph, area);
+}
+
 /// Render the account changes view (staking operations).
 fn render_account_changes(frame: &mut Frame, app: &App, area: Rect) {
     let pal = &app.palette;
@@ -321,6 +695,16 @@ fn render_account_changes(frame: &mut Frame, app: &App, area: Rect) {
             "Withdraw unbonded funds that are ready",
         ),
         ("x", "Chill", "Stop nominating (chill)"),
+        (
+            "g",
+            "Rebag",
+            "Move into the voter list bag matching your stake",
+        ),
+        (
+            "f",
+            "Put In Front",
+            "Move ahead of a lighter voter in your bag",
+        ),
     ];
 
     let mut lines = Vec::new();
@@ -344,10 +728,72 @@ fn render_account_changes(frame: &mut Frame, app: &App, area: Rect) {
         lines.push(Line::from(""));
     }
 
+    let schedule = app.unbonding_schedule();
+    if !schedule.is_empty() {
+        let decimals = app.network.token_decimals();
+        let symbol = app.network.token_symbol();
+        lines.push(Line::from(Span::styled(
+            "  Unbonding Schedule:",
+            Style::default().fg(pal.primary).bold(),
+        )));
+        lines.push(Line::from(""));
+        for entry in &schedule {
+            lines.push(unbonding_line(app, entry, decimals, symbol));
+        }
+        let ready = |pool: bool| {
+            schedule.iter().any(|e| {
+                e.is_withdrawable() && matches!(e.source, UnbondingSource::Pool(_)) == pool
+            })
+        };
+        let mut hints = Vec::new();
+        if ready(false) {
+            hints.push("press w for staked funds");
+        }
+        if ready(true) {
+            hints.push("press W on the Pools tab for pool funds");
+        }
+        if !hints.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                format!("  Ready to withdraw: {}", hints.join(", ")),
+                Style::default().fg(pal.success),
+            )));
+        }
+    }
+
     let p = Paragraph::new(lines);
     frame.render_widget(p, inner);
 }
 
+/// One unbonding chunk with its projected unlock date.
+fn unbonding_line<'a>(app: &App, entry: &UnbondingEntry, decimals: u8, symbol: &str) -> Line<'a> {
+    let pal = &app.palette;
+    let source = match entry.source {
+        UnbondingSource::Direct => "Staked".to_string(),
+        UnbondingSource::Pool(id) => format!("Pool #{}", id),
+    };
+    let (status, color) = if entry.is_withdrawable() {
+        ("withdrawable now".to_string(), pal.success)
+    } else if app.era_duration_ms > 0 {
+        (
+            format!("{} ({} eras)", entry.display_date(), entry.remaining_eras),
+            pal.muted,
+        )
+    } else {
+        (format!("in {} eras", entry.remaining_eras), pal.muted)
+    };
+    Line::from(vec![
+        Span::raw(format!(
+            "      {:<9} {:>12} {}  era {:<6} ",
+            source,
+            format_balance(entry.value, decimals),
+            symbol,
+            entry.era
+        )),
+        Span::styled(status, Style::default().fg(color)),
+    ])
+}
+
 /// Format balance with proper decimals.
 fn format_balance(balance: u128, decimals: u8) -> String {
     let divisor = 10u128.pow(decimals as u32);
@@ -363,6 +809,12 @@ fn format_balance(balance: u128, decimals: u8) -> String {
     }
 }
 
+/// Format a signed reward difference with a leading sign and four decimals.
+fn format_reward_difference(diff: i128, decimals: u8) -> String {
+    let divisor = 10f64.powi(decimals as i32);
+    format!("{:+.4}", diff as f64 / divisor)
+}
+
 /// Render the validators view with table.
 fn render_validators(frame: &mut Frame, app: &mut App, area: Rect) {
     let decimals = app.network.token_decimals();
@@ -573,7 +1025,9 @@ fn render_pools(frame: &mut Frame, app: &mut App, area: Rect) {
 
     // 1. Status Panel (only if account is watched)
     if app.watched_account.is_some() {
-        constraints.push(Constraint::Length(8));
+        // One more line for the roles held in pools
+        let admin_line = u16::from(!app.pool_admin.pools.is_empty());
+        constraints.push(Constraint::Length(8 + admin_line));
     } else {
         constraints.push(Constraint::Length(0));
     }
@@ -781,21 +1235,54 @@ fn render_pool_status(frame: &mut Frame, app: &App, area: Rect) {
                 ),
             ]));
 
-            // Line 2: Stake & Rewards
+            // Line 2: Stake & Rewards, in balance once the pool's ratio is known
+            let details = status.pool_details.as_ref();
+            let active = match details {
+                Some(details) => {
+                    format!("{} {}", format_balance(details.balance, decimals), symbol)
+                }
+                None => format!("{} points", format_balance(membership.points, decimals)),
+            };
+            let rewards = match details {
+                Some(details) => Span::styled(
+                    format!(
+                        "{} {} ",
+                        format_balance(details.pending_rewards, decimals),
+                        symbol
+                    ),
+                    Style::default().fg(pal.success),
+                ),
+                None => Span::styled("Claimable ", Style::default().fg(pal.success)),
+            };
             lines.push(Line::from(vec![
                 Span::styled("Active Stake: ", Style::default().fg(pal.fg_dim)),
-                Span::styled(
-                    format!("{} {}", format_balance(membership.points, decimals), symbol),
-                    Style::default().fg(pal.primary).bold(),
-                ),
+                Span::styled(active, Style::default().fg(pal.primary).bold()),
                 Span::raw("    "),
                 Span::styled("Rewards: ", Style::default().fg(pal.fg_dim)),
-                Span::styled("Claimable ", Style::default().fg(pal.success)),
+                rewards,
                 Span::raw("(use 'C')"),
             ]));
 
             // Line 3: Unbonding
-            if !membership.unbonding_eras.is_empty() {
+            if let Some(details) = details.filter(|d| !d.unbonding.is_empty()) {
+                let per_era: Vec<String> = details
+                    .unbonding
+                    .iter()
+                    .map(|u| format!("era {}: {}", u.era, format_balance(u.balance, decimals)))
+                    .collect();
+                lines.push(Line::from(vec![
+                    Span::styled("Unbonding: ", Style::default().fg(pal.fg_dim)),
+                    Span::styled(
+                        format!(
+                            "{} {}",
+                            format_balance(details.total_unbonding(), decimals),
+                            symbol
+                        ),
+                        Style::default().fg(pal.warning),
+                    ),
+                    Span::raw(format!(" ({})", per_era.join(", "))),
+                ]));
+            } else if !membership.unbonding_eras.is_empty() {
                 let total_unbonding: u128 =
                     membership.unbonding_eras.iter().map(|(_, val)| val).sum();
                 lines.push(Line::from(vec![
@@ -813,7 +1300,14 @@ fn render_pool_status(frame: &mut Frame, app: &App, area: Rect) {
                 ]));
             }
 
-            // Line 4: Operations
+            if let Some(details) = details {
+                lines.push(Line::from(vec![
+                    Span::styled("Claim Permission: ", Style::default().fg(pal.fg_dim)),
+                    Span::raw(details.claim_permission.label()),
+                ]));
+            }
+
+            // Line 5: Operations
             lines.push(Line::from(""));
             lines.push(Line::from(vec![
                 Span::styled("Actions: ", Style::default().fg(pal.fg_dim)),
@@ -824,7 +1318,11 @@ fn render_pool_status(frame: &mut Frame, app: &App, area: Rect) {
                 Span::styled("C", Style::default().fg(pal.highlight).bold()),
                 Span::raw(":Claim  "),
                 Span::styled("W", Style::default().fg(pal.highlight).bold()),
-                Span::raw(":Withdraw"),
+                Span::raw(":Withdraw  "),
+                Span::styled("P", Style::default().fg(pal.highlight).bold()),
+                Span::raw(":Claim Permission  "),
+                Span::styled("M", Style::default().fg(pal.highlight).bold()),
+                Span::raw(":Member Rewards"),
             ]));
         } else {
             // Not in a pool
@@ -839,6 +1337,13 @@ fn render_pool_status(frame: &mut Frame, app: &App, area: Rect) {
                 Span::styled("Select a pool", Style::default().bold()),
                 Span::raw(" from the list below and press "),
                 Span::styled("j", Style::default().fg(pal.highlight).bold()),
+                Span::raw(", or press "),
+                Span::styled("A", Style::default().fg(pal.highlight).bold()),
+                Span::raw(" to create one."),
+            ]));
+            lines.push(Line::from(vec![
+                Span::raw("To compound or pay out another member's rewards: press "),
+                Span::styled("M", Style::default().fg(pal.highlight).bold()),
                 Span::raw("."),
             ]));
         }
@@ -847,6 +1352,24 @@ fn render_pool_status(frame: &mut Frame, app: &App, area: Rect) {
         lines.push(Line::from("Loading account status..."));
     }
 
+    if !app.pool_admin.pools.is_empty() {
+        let held: Vec<String> = app
+            .pool_admin
+            .pools
+            .iter()
+            .map(|pool| {
+                let roles: Vec<&str> = pool.roles.iter().map(|r| r.label()).collect();
+                format!("{} of #{}", roles.join("/"), pool.pool_id)
+            })
+            .collect();
+        lines.push(Line::from(vec![
+            Span::styled("Pool Roles: ", Style::default().fg(pal.fg_dim)),
+            Span::raw(format!("{}  ", held.join(", "))),
+            Span::styled("A", Style::default().fg(pal.highlight).bold()),
+            Span::raw(":Administer"),
+        ]));
+    }
+
     let p = Paragraph::new(lines).block(Block::default().borders(Borders::NONE));
     frame.render_widget(p, inner_area);
 }
@@ -915,7 +1438,13 @@ fn render_nominate(frame: &mut Frame, app: &mut App, area: Rect) {
         Span::styled("c", Style::default().fg(pal.primary).bold()),
         Span::raw(": Clear  "),
         Span::styled("g", Style::default().fg(pal.primary).bold()),
-        Span::raw(": Generate QR"),
+        Span::raw(": Generate QR  "),
+        Span::styled("x", Style::default().fg(pal.primary).bold()),
+        Span::raw(if app.exclude_reported {
+            ": Skip reported (on)"
+        } else {
+            ": Skip reported (off)"
+        }),
     ]));
 
     let info_panel = Paragraph::new(info_lines).block(
@@ -1034,10 +1563,15 @@ fn render_account_status(frame: &mut Frame, app: &mut App, area: Rect) {
     // Main: Split horizontally (Left: Info, Right: Address Book)
 
     let main_area = if app.input_mode == InputMode::EnteringAccount {
-        let chunks = if app.validation_error.is_some() {
+        let warning = app
+            .validation_error
+            .is_none()
+            .then(|| app.account_input_warning())
+            .flatten();
+        let chunks = if app.validation_error.is_some() || warning.is_some() {
             Layout::vertical([
                 Constraint::Length(3), // Input box
-                Constraint::Length(2), // Error message
+                Constraint::Length(2), // Error or warning message
                 Constraint::Min(0),    // Rest of content
             ])
             .split(area)
@@ -1056,20 +1590,25 @@ fn render_account_status(frame: &mut Frame, app: &mut App, area: Rect) {
             );
         frame.render_widget(input, chunks[0]);
 
-        // Render validation error if present
-        if let Some(ref error) = app.validation_error {
-            let error_lines = vec![Line::from(vec![
-                Span::styled("✗ ", Style::default().fg(pal.error)),
-                Span::styled(error, Style::default().fg(pal.error)),
+        // Render validation error, or a prefix warning for a valid address
+        let message = match (&app.validation_error, &warning) {
+            (Some(error), _) => Some(("✗ ", error, pal.error)),
+            (None, Some(warning)) => Some(("! ", warning, pal.warning)),
+            (None, None) => None,
+        };
+        if let Some((marker, text, color)) = message {
+            let message_lines = vec![Line::from(vec![
+                Span::styled(marker, Style::default().fg(color)),
+                Span::styled(text.as_str(), Style::default().fg(color)),
             ])];
-            let error_para = Paragraph::new(error_lines)
-                .style(Style::default().fg(pal.error))
+            let message_para = Paragraph::new(message_lines)
+                .style(Style::default().fg(color))
                 .block(
                     Block::default()
                         .borders(Borders::NONE)
                         .style(Style::default().bg(pal.bg)),
                 );
-            frame.render_widget(error_para, chunks[1]);
+            frame.render_widget(message_para, chunks[1]);
             chunks[2]
         } else {
             chunks[1]
@@ -1082,7 +1621,10 @@ fn render_account_status(frame: &mut Frame, app: &mut App, area: Rect) {
         Layout::vertical([Constraint::Percentage(50), Constraint::Percentage(50)]).split(main_area);
 
     let left_area = chunks[0];
-    let right_area = chunks[1];
+    let bottom_chunks =
+        Layout::horizontal([Constraint::Min(0), Constraint::Length(44)]).split(chunks[1]);
+    let right_area = bottom_chunks[0];
+    render_network_info(frame, app, bottom_chunks[1]);
 
     // Determine focus colors
     let left_border = if app.account_panel_focus == 0 {
@@ -1107,7 +1649,9 @@ fn render_account_status(frame: &mut Frame, app: &mut App, area: Rect) {
             lines.push(Line::from(vec![
                 Span::raw("  Press "),
                 Span::styled("a", Style::default().fg(pal.highlight).bold()),
-                Span::raw(" to enter an account address"),
+                Span::raw(" to enter an account address, "),
+                Span::styled("v", Style::default().fg(pal.highlight).bold()),
+                Span::raw(" to scan one from Vault"),
             ]));
         }
         (Some(account), None) => {
@@ -1140,8 +1684,11 @@ fn render_account_status(frame: &mut Frame, app: &mut App, area: Rect) {
             let reserved = status.balance.reserved;
             let frozen = status.balance.frozen;
             let total = free + reserved;
-            // Transferable is usually free - frozen (simplified)
-            let transferable = free.saturating_sub(frozen);
+            // Without the breakdown, transferable is approximated as free - frozen
+            let transferable = status
+                .balance_breakdown
+                .as_ref()
+                .map_or(free.saturating_sub(frozen), |b| b.transferable());
             let bonded = if let Some(l) = &status.staking_ledger {
                 l.active
             } else {
@@ -1177,6 +1724,44 @@ fn render_account_status(frame: &mut Frame, app: &mut App, area: Rect) {
                     Style::default().fg(pal.accent),
                 ),
             ]));
+            if let Some(breakdown) = &status.balance_breakdown {
+                lines.push(Line::from(vec![
+                    Span::raw("    Bondable:     "),
+                    Span::styled(
+                        format!(
+                            "{} {}",
+                            format_balance(breakdown.bondable(), decimals),
+                            symbol
+                        ),
+                        Style::default().fg(pal.success),
+                    ),
+                ]));
+                // Locks and freezes overlap, so each is shown on its own
+                for (kind, restriction) in breakdown.restrictions() {
+                    lines.push(Line::from(vec![
+                        Span::styled(format!("      {:<8}", kind), Style::default().fg(pal.muted)),
+                        Span::raw(format!(
+                            "{} {} ({})",
+                            format_balance(restriction.amount, decimals),
+                            symbol,
+                            restriction.reason
+                        )),
+                    ]));
+                }
+                for schedule in &breakdown.vesting {
+                    lines.push(Line::from(vec![
+                        Span::styled("      Vesting ", Style::default().fg(pal.muted)),
+                        Span::raw(format!(
+                            "{} {} from block {}, {} {} per block",
+                            format_balance(schedule.locked, decimals),
+                            symbol,
+                            schedule.starting_block,
+                            format_balance(schedule.per_block, decimals),
+                            symbol
+                        )),
+                    ]));
+                }
+            }
             lines.push(Line::from(""));
 
             // Staking section
@@ -1193,13 +1778,12 @@ fn render_account_status(frame: &mut Frame, app: &mut App, area: Rect) {
                             Style::default().fg(pal.accent),
                         ),
                     ]));
-                    for chunk in &ledger.unlocking {
-                        lines.push(Line::from(format!(
-                            "      - {} {} (era {})",
-                            format_balance(chunk.value, decimals),
-                            symbol,
-                            chunk.era
-                        )));
+                    let schedule = app.unbonding_schedule();
+                    let direct = schedule
+                        .iter()
+                        .filter(|e| e.source == UnbondingSource::Direct);
+                    for entry in direct {
+                        lines.push(unbonding_line(app, entry, decimals, symbol));
                     }
                 } else {
                     lines.push(Line::from("    No unlocking chunks"));
@@ -1220,6 +1804,9 @@ fn render_account_status(frame: &mut Frame, app: &mut App, area: Rect) {
                     nominations.targets.len(),
                     nominations.submitted_in
                 )));
+                if let Some(exposure) = &status.exposure {
+                    render_nominator_exposure(&mut lines, app, exposure, decimals, symbol);
+                }
             } else {
                 lines.push(Line::from("    No nominations"));
             }
@@ -1252,6 +1839,21 @@ fn render_account_status(frame: &mut Frame, app: &mut App, area: Rect) {
                 lines.push(Line::from("    Not a pool member"));
             }
 
+            if let Some(report) = app.health_report() {
+                lines.push(Line::from(""));
+                render_health_report(&mut lines, app, &report, decimals, symbol);
+            }
+
+            if let Some(risk) = app.slash_risk() {
+                lines.push(Line::from(""));
+                render_slash_risk(&mut lines, app, &risk, decimals, symbol);
+            }
+
+            if let Some(position) = &app.bags_position {
+                lines.push(Line::from(""));
+                render_bags_position(&mut lines, app, position);
+            }
+
             lines.push(Line::from(""));
             lines.push(Line::from(vec![
                 Span::raw("  Press "),
@@ -1288,6 +1890,14 @@ fn render_account_status(frame: &mut Frame, app: &mut App, area: Rect) {
         ]));
     }
 
+    // Add saved addresses (e.g. imported from Vault)
+    for entry in &app.saved_addresses {
+        address_rows.push(Row::new(vec![
+            Cell::from(entry.label.clone()).style(Style::default().fg(pal.accent)),
+            Cell::from(entry.address.clone()),
+        ]));
+    }
+
     // Add known addresses
     for (name, addr) in crate::app::KNOWN_ADDRESSES {
         address_rows.push(Row::new(vec![Cell::from(*name), Cell::from(*addr)]));
@@ -1300,17 +1910,20 @@ fn render_account_status(frame: &mut Frame, app: &mut App, area: Rect) {
         " Addresses "
     };
 
+    let mut address_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(right_border))
+        .title(address_title);
+    if let Some(ref status) = app.address_book_status {
+        address_block = address_block.title_bottom(format!(" {} ", status));
+    }
+
     let address_table = Table::new(
         address_rows,
         [Constraint::Percentage(30), Constraint::Percentage(70)],
     )
     .header(Row::new(vec!["Name", "Address"]).style(Style::default().fg(pal.muted)))
-    .block(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(right_border))
-            .title(address_title),
-    )
+    .block(address_block)
     .row_highlight_style(
         Style::default()
             .fg(pal.selection)
@@ -1321,6 +1934,295 @@ fn render_account_status(frame: &mut Frame, app: &mut App, area: Rect) {
     frame.render_stateful_widget(address_table, right_area, &mut app.address_book_state);
 }
 
+/// Append which nominated validators carry the account's stake this era.
+fn render_nominator_exposure(
+    lines: &mut Vec<Line<'_>>,
+    app: &App,
+    exposure: &NominatorExposure,
+    decimals: u8,
+    symbol: &str,
+) {
+    let pal = &app.palette;
+    if exposure.is_active() {
+        lines.push(Line::from(vec![
+            Span::raw(format!("    Active in era {}: ", exposure.era)),
+            Span::styled(
+                format!(
+                    "{} {}",
+                    format_balance(exposure.active_stake(), decimals),
+                    symbol
+                ),
+                Style::default().fg(pal.success),
+            ),
+        ]));
+    }
+    for backing in &exposure.backing {
+        let address = stkopt_chain::encode_ss58(&backing.validator, app.network.ss58_format())
+            .unwrap_or_else(|_| backing.validator.to_string());
+        let name = app
+            .validators
+            .iter()
+            .find(|v| v.address == address)
+            .and_then(|v| v.name.clone())
+            .unwrap_or_else(|| truncate_address(&address, 6, 6));
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("      {:<20}", truncate_str(&name, 20)),
+                Style::default().fg(pal.muted),
+            ),
+            Span::raw(format!(
+                "{} {} (page {}/{})",
+                format_balance(backing.value, decimals),
+                symbol,
+                backing.page + 1,
+                backing.page_count
+            )),
+        ]));
+    }
+    if !exposure.inactive.is_empty() {
+        lines.push(Line::from(Span::styled(
+            format!(
+                "    {} nominated validator(s) not elected",
+                exposure.inactive.len()
+            ),
+            Style::default().fg(pal.muted),
+        )));
+    }
+    for warning in exposure.warnings(true) {
+        lines.push(Line::from(Span::styled(
+            format!("    ⚠ {}", warning),
+            Style::default().fg(pal.warning),
+        )));
+    }
+}
+
+/// Append the account's health check findings, most urgent first.
+fn render_health_report(
+    lines: &mut Vec<Line<'_>>,
+    app: &App,
+    report: &HealthReport,
+    decimals: u8,
+    symbol: &str,
+) {
+    let pal = &app.palette;
+    lines.push(Line::from(Span::styled(
+        "  Health Check",
+        Style::default().fg(pal.primary).bold(),
+    )));
+    if report.is_healthy() {
+        lines.push(Line::from(Span::styled(
+            "    ✓ No problems found",
+            Style::default().fg(pal.success),
+        )));
+        return;
+    }
+    for issue in &report.issues {
+        let color = match issue.severity() {
+            Severity::Critical => pal.error,
+            Severity::Warning => pal.warning,
+            Severity::Info => pal.muted,
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("    {:<9}", issue.severity().label()),
+                Style::default().fg(color).bold(),
+            ),
+            Span::raw(issue.message(decimals, symbol)),
+        ]));
+        lines.push(Line::from(Span::styled(
+            format!("             → {}", issue.fix()),
+            Style::default().fg(pal.muted),
+        )));
+    }
+}
+
+/// Append the watched account's voter list bag and rank.
+fn render_bags_position(lines: &mut Vec<Line<'_>>, app: &App, position: &BagsListPosition) {
+    let pal = &app.palette;
+    let bag = |upper: u64| {
+        if upper == u64::MAX {
+            "top bag".to_string()
+        } else {
+            format!("bag ≤ {}", upper)
+        }
+    };
+    lines.push(Line::from(Span::styled(
+        "  Voter List",
+        Style::default().fg(pal.primary).bold(),
+    )));
+    let rank = position
+        .rank
+        .map(|rank| format!("position {}", rank))
+        .unwrap_or_else(|| format!("beyond position {}", stkopt_chain::MAX_BAG_WALK));
+    lines.push(Line::from(format!(
+        "    In {}, {}",
+        bag(position.bag_upper),
+        rank
+    )));
+    if position.needs_rebag() {
+        lines.push(Line::from(Span::styled(
+            format!(
+                "    Stake belongs in {}; press g to rebag",
+                bag(position.expected_bag)
+            ),
+            Style::default().fg(pal.warning),
+        )));
+    } else {
+        lines.push(Line::from(Span::styled(
+            "    ✓ In the bag matching your stake",
+            Style::default().fg(pal.success),
+        )));
+    }
+    if let Some(lighter) = &position.lighter_ahead {
+        lines.push(Line::from(Span::styled(
+            format!(
+                "    Lighter voter ahead ({}); press f to move in front",
+                truncate_address(lighter, 6, 6)
+            ),
+            Style::default().fg(pal.warning),
+        )));
+    }
+}
+
+/// Append pending and past slashes of the nominations, riskiest first.
+fn render_slash_risk(
+    lines: &mut Vec<Line<'_>>,
+    app: &App,
+    risk: &SlashRiskReport,
+    decimals: u8,
+    symbol: &str,
+) {
+    let pal = &app.palette;
+    let amount = |value| format!("{} {}", format_balance(value, decimals), symbol);
+    lines.push(Line::from(Span::styled(
+        "  Slash Risk",
+        Style::default().fg(pal.primary).bold(),
+    )));
+    if risk.total_pending() > 0 {
+        lines.push(Line::from(Span::styled(
+            format!("    Pending slashes: {}", amount(risk.total_pending())),
+            Style::default().fg(pal.error).bold(),
+        )));
+    }
+    if !risk.past_slashes.is_empty() {
+        lines.push(Line::from(Span::styled(
+            format!(
+                "    Already slashed: {} in {} era(s)",
+                amount(risk.total_past()),
+                risk.past_slashes.len()
+            ),
+            Style::default().fg(pal.warning),
+        )));
+    }
+    if risk.flagged().next().is_none() {
+        lines.push(Line::from(Span::styled(
+            "    ✓ No slashes or offences against nominated validators",
+            Style::default().fg(pal.success),
+        )));
+        return;
+    }
+    for target in risk.flagged() {
+        let name = app
+            .validators
+            .iter()
+            .find(|v| v.address == target.validator)
+            .and_then(|v| v.name.clone())
+            .unwrap_or_else(|| truncate_address(&target.validator, 6, 6));
+        let mut details = Vec::new();
+        if target.pending > 0 {
+            details.push(format!("{} pending", amount(target.pending)));
+        }
+        if let Some(worst) = target.slashes.iter().max_by_key(|s| s.fraction) {
+            details.push(format!(
+                "{} slash(es), worst {:.2}% in era {}",
+                target.slashes.len(),
+                worst.fraction as f64 / 10_000_000.0,
+                worst.era
+            ));
+        }
+        if target.offences > 0 {
+            details.push(format!("{} offence report(s)", target.offences));
+        }
+        let color = if target.pending > 0 {
+            pal.error
+        } else {
+            pal.warning
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("    {:<20}", truncate_str(&name, 20)),
+                Style::default().fg(color),
+            ),
+            Span::raw(details.join(", ")),
+        ]));
+    }
+    lines.push(Line::from(Span::styled(
+        format!(
+            "    Estimated loss: {}",
+            amount(risk.total_estimated_loss())
+        ),
+        Style::default().fg(pal.muted),
+    )));
+}
+
+/// Render the network's staking limits.
+fn render_network_info(frame: &mut Frame, app: &App, area: Rect) {
+    let pal = &app.palette;
+    let decimals = app.network.token_decimals();
+    let symbol = app.network.token_symbol();
+    let amount = |value| format!("{} {}", format_balance(value, decimals), symbol);
+
+    let lines = match &app.staking_params {
+        None => vec![Line::from(Span::styled(
+            "  Loading staking limits...",
+            Style::default().fg(pal.muted),
+        ))],
+        Some(params) => {
+            let nominators = match params.max_nominators {
+                Some(max) => format!("{} / {}", params.nominator_count, max),
+                None => params.nominator_count.to_string(),
+            };
+            let rows = [
+                ("Min nominator bond", amount(params.min_nominator_bond)),
+                ("Min active stake", amount(params.min_active_stake)),
+                ("Nominators", nominators),
+                (
+                    "Exposure page size",
+                    params.max_exposure_page_size.to_string(),
+                ),
+                ("Pool min join bond", amount(params.min_join_bond)),
+                ("Pool min create bond", amount(params.min_create_bond)),
+                (
+                    "Bonding duration",
+                    format!("{} eras", params.bonding_duration),
+                ),
+            ];
+            let mut lines: Vec<Line> = rows
+                .into_iter()
+                .map(|(label, value)| {
+                    Line::from(vec![
+                        Span::styled(format!(" {:<21}", label), Style::default().fg(pal.muted)),
+                        Span::raw(value),
+                    ])
+                })
+                .collect();
+            if params.nominators_full() {
+                lines.push(Line::from(Span::styled(
+                    " Nominator limit reached",
+                    Style::default().fg(pal.warning),
+                )));
+            }
+            lines
+        }
+    };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(pal.border))
+        .title(format!(" {} Staking ", app.network));
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
 /// Render the staking history view with bar chart and table.
 fn render_account_history(frame: &mut Frame, app: &App, area: Rect) {
     let pal = &app.palette;
@@ -1329,7 +2231,7 @@ fn render_account_history(frame: &mut Frame, app: &App, area: Rect) {
         .constraints([
             Constraint::Length(4), // Title/controls
             Constraint::Min(6),    // Main content (split horizontally)
-            Constraint::Length(5), // Stats
+            Constraint::Length(7), // Stats
         ])
         .split(area);
 
@@ -1382,9 +2284,15 @@ fn render_account_history(frame: &mut Frame, app: &App, area: Rect) {
             Span::raw("Press "),
             Span::styled("l", Style::default().fg(pal.success).bold()),
             Span::raw(format!(
-                " to reload the last {} days",
+                " to reload the last {} days, ",
                 app.history.lookback_days
             )),
+            Span::styled("p", Style::default().fg(pal.success).bold()),
+            Span::raw(if app.history.checking_payouts {
+                " checking payouts…"
+            } else {
+                " to check payouts"
+            }),
         ]));
     }
 
@@ -1393,7 +2301,7 @@ fn render_account_history(frame: &mut Frame, app: &App, area: Rect) {
             Block::default()
                 .borders(Borders::ALL)
                 .border_style(Style::default().fg(pal.border))
-                .title(" Staking History | l:Load  c:Cancel  q:Quit "),
+                .title(" Staking History | l:Load  c:Cancel  p:Payouts  q:Quit "),
         )
         .alignment(Alignment::Center);
     frame.render_widget(title_para, chunks[0]);
@@ -1494,10 +2402,15 @@ fn render_reward_bar_chart(frame: &mut Frame, app: &App, area: Rect) {
     let decimals = app.network.token_decimals();
     let symbol = app.network.token_symbol();
 
+    let has_exact = !app.history.exact_points.is_empty();
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(pal.border))
-        .title(format!(" Daily {} Rewards (━ 7-era trend) ", symbol));
+        .title(if has_exact {
+            format!(" Daily {} Rewards (━ 7-era trend, ● exact) ", symbol)
+        } else {
+            format!(" Daily {} Rewards (━ 7-era trend) ", symbol)
+        });
 
     let inner_area = block.inner(area);
     frame.render_widget(block, area);
@@ -1531,11 +2444,29 @@ fn render_reward_bar_chart(frame: &mut Frame, app: &App, area: Rect) {
         return;
     }
 
+    // Exact rewards aligned with the estimated points, where known
+    let exact: Vec<Option<f64>> = app
+        .history
+        .points
+        .iter()
+        .map(|p| {
+            app.history
+                .exact_points
+                .iter()
+                .find(|x| x.era == p.era)
+                .map(|x| x.reward as f64 / divisor as f64)
+        })
+        .collect();
+
     // Calculate trend line (7-era moving average)
     let trend = calculate_trend(&rewards, 7);
 
     // Y-axis: find nice ticks
-    let max_reward = rewards.iter().cloned().fold(0.0_f64, f64::max);
+    let max_reward = rewards
+        .iter()
+        .chain(exact.iter().flatten())
+        .cloned()
+        .fold(0.0_f64, f64::max);
     let y_ticks = calculate_y_ticks(max_reward, 5);
     let y_max = *y_ticks.last().unwrap_or(&max_reward.max(0.01));
 
@@ -1547,6 +2478,7 @@ fn render_reward_bar_chart(frame: &mut Frame, app: &App, area: Rect) {
     // Build bars data and trend points
     let mut bar_data: Vec<(f64, f64)> = Vec::new(); // (normalized height, actual value)
     let mut trend_data: Vec<f64> = Vec::new();
+    let mut exact_data: Vec<Option<f64>> = Vec::new(); // normalized exact reward
 
     for i in 0..num_bars {
         let start_idx = (i as f64 * points_per_bar) as usize;
@@ -1565,6 +2497,16 @@ fn render_reward_bar_chart(frame: &mut Frame, app: &App, area: Rect) {
             } else {
                 trend_data.push(normalized);
             }
+
+            let exact_slice: Vec<f64> = exact[start_idx.min(exact.len())..end_idx.min(exact.len())]
+                .iter()
+                .flatten()
+                .copied()
+                .collect();
+            exact_data.push((!exact_slice.is_empty()).then(|| {
+                let avg = exact_slice.iter().sum::<f64>() / exact_slice.len() as f64;
+                (avg / y_max).min(1.0)
+            }));
         }
     }
 
@@ -1625,8 +2567,19 @@ fn render_reward_bar_chart(frame: &mut Frame, app: &App, area: Rect) {
                 pal.graph_low
             };
 
-            // Draw bar or trend line
-            if is_trend_row && row_from_bottom >= bar_height {
+            // Exact reward marker sits where the top of an exact bar would be
+            let is_exact_row = exact_data
+                .get(bar_idx)
+                .copied()
+                .flatten()
+                .is_some_and(|exact| {
+                    (exact * graph_height as f64).ceil().max(1.0) as usize - 1 == row_from_bottom
+                });
+
+            // Draw exact marker, bar or trend line
+            if is_exact_row {
+                line_spans.push(Span::styled("●", Style::default().fg(pal.success)));
+            } else if is_trend_row && row_from_bottom >= bar_height {
                 // Trend line (when above bar)
                 line_spans.push(Span::styled("━", Style::default().fg(pal.warning)));
             } else {
@@ -1703,6 +2656,21 @@ fn render_history_table(frame: &mut Frame, app: &App, area: Rect) {
         .map(|p| {
             let apy_str = format!("{:.2}%", p.apy * 100.0);
             let reward_str = format!("{} {}", format_balance(p.reward, decimals), symbol);
+            let exact = app.history.exact_points.iter().find(|x| x.era == p.era);
+            let exact_str = exact
+                .map(|x| format_balance(x.reward, decimals))
+                .unwrap_or_else(|| "-".to_string());
+            let (diff_str, diff_style) = match exact {
+                Some(x) => {
+                    let diff = x.reward as i128 - p.reward as i128;
+                    let color = if diff < 0 { pal.warning } else { pal.success };
+                    (
+                        format_reward_difference(diff, decimals),
+                        Style::default().fg(color),
+                    )
+                }
+                None => ("-".to_string(), Style::default().fg(pal.muted)),
+            };
 
             // Color APY based on value
             let apy_style = if p.apy * 100.0 >= 15.0 {
@@ -1713,11 +2681,23 @@ fn render_history_table(frame: &mut Frame, app: &App, area: Rect) {
                 Style::default().fg(pal.graph_low)
             };
 
+            let (payout_str, payout_color) =
+                match era_payout_status(&app.history.payout_checks, p.era) {
+                    Some(PayoutStatus::Paid) => ("paid", pal.success),
+                    Some(PayoutStatus::Short) => ("short", pal.warning),
+                    Some(PayoutStatus::Unclaimed) => ("unclaimed", pal.warning),
+                    Some(PayoutStatus::Missing) => ("missing", pal.error),
+                    None => ("-", pal.muted),
+                };
+
             Row::new(vec![
                 Cell::from(p.era.to_string()),
                 Cell::from(p.date.clone().unwrap_or_default()),
                 Cell::from(reward_str),
+                Cell::from(exact_str),
+                Cell::from(diff_str).style(diff_style),
                 Cell::from(apy_str).style(apy_style),
+                Cell::from(payout_str).style(Style::default().fg(payout_color)),
             ])
         })
         .collect();
@@ -1725,8 +2705,11 @@ fn render_history_table(frame: &mut Frame, app: &App, area: Rect) {
     let header = Row::new(vec![
         Cell::from("Era").style(Style::default().bold()),
         Cell::from("Date").style(Style::default().bold()),
-        Cell::from(format!("Reward ({})", symbol)).style(Style::default().bold()),
+        Cell::from(format!("Estimated ({})", symbol)).style(Style::default().bold()),
+        Cell::from("Exact").style(Style::default().bold()),
+        Cell::from("Δ").style(Style::default().bold()),
         Cell::from("APY").style(Style::default().bold()),
+        Cell::from("Payout").style(Style::default().bold()),
     ])
     .style(Style::default().fg(pal.highlight));
 
@@ -1735,6 +2718,9 @@ fn render_history_table(frame: &mut Frame, app: &App, area: Rect) {
         Constraint::Length(12),
         Constraint::Length(18),
         Constraint::Length(10),
+        Constraint::Length(10),
+        Constraint::Length(10),
+        Constraint::Length(8),
     ];
 
     let table = Table::new(rows, widths)
@@ -1797,6 +2783,62 @@ fn render_history_stats(frame: &mut Frame, app: &App, area: Rect) {
             ),
         ]));
 
+        let comparisons = compare_rewards(&app.history.points, &app.history.exact_points);
+        if !comparisons.is_empty() {
+            let exact_total: u128 = comparisons.iter().map(|c| c.exact).sum();
+            let diff: i128 = comparisons.iter().map(|c| c.difference()).sum();
+            lines.push(Line::from(vec![
+                Span::styled("  Exact Rewards: ", Style::default().fg(pal.fg_dim)),
+                Span::styled(
+                    format!("{} {}", format_balance(exact_total, decimals), symbol),
+                    Style::default().fg(pal.success).bold(),
+                ),
+                Span::styled(
+                    format!(
+                        " over {} eras ({} {} vs estimate)",
+                        comparisons.len(),
+                        format_reward_difference(diff, decimals),
+                        symbol
+                    ),
+                    Style::default().fg(pal.muted),
+                ),
+            ]));
+        }
+
+        if let Some(error) = &app.history.payout_error {
+            lines.push(Line::from(vec![
+                Span::styled("  Payouts: ", Style::default().fg(pal.fg_dim)),
+                Span::styled(
+                    format!("check failed: {}", error),
+                    Style::default().fg(pal.error),
+                ),
+            ]));
+        } else if !app.history.payout_checks.is_empty() {
+            let checks = &app.history.payout_checks;
+            let flagged: Vec<_> = checks.iter().filter(|c| c.is_flagged()).collect();
+            let shortfall: u128 = flagged.iter().map(|c| c.shortfall()).sum();
+            let (summary, color) = if flagged.is_empty() {
+                (format!("all {} paid", checks.len()), pal.success)
+            } else {
+                (
+                    format!("{} of {} unpaid or short", flagged.len(), checks.len()),
+                    pal.warning,
+                )
+            };
+            lines.push(Line::from(vec![
+                Span::styled("  Payouts: ", Style::default().fg(pal.fg_dim)),
+                Span::styled(summary, Style::default().fg(color).bold()),
+                Span::styled(
+                    format!(
+                        " (shortfall {} {})",
+                        format_balance(shortfall, decimals),
+                        symbol
+                    ),
+                    Style::default().fg(pal.muted),
+                ),
+            ]));
+        }
+
         if let Some(last) = app.history.points.last() {
             lines.push(Line::from(vec![
                 Span::styled("  Latest Era: ", Style::default().fg(pal.fg_dim)),
@@ -1886,15 +2928,43 @@ fn render_qr_modal(frame: &mut Frame, app: &App) {
     }
 
     // Footer
-    let footer = if app.qr.pending_signed.is_some() {
-        "Tab:View  Enter:Submit  Esc:Close"
+    let hints = if app.pending_tx_expired() {
+        "r:Rebuild  Tab:View  e:Export  Esc:Close"
+    } else if app.qr.pending_signed.is_some() {
+        "Tab:View  Enter:Submit  e:Export  Esc:Close"
     } else if app.qr.pending_unsigned.is_some() {
-        "Tab:View  s:Scan  Esc:Close"
+        "Tab:View  s:Scan  e:Export  Esc:Close"
     } else {
-        "Tab:View  Esc:Close"
+        "Tab:View  e:Export  Esc:Close"
+    };
+    let footer = match app.qr.export_status {
+        Some(ref status) => format!("{}  {}", status, hints),
+        None => hints.to_string(),
     };
+    let mut footer_spans = Vec::new();
+    if let Some(remaining) = app.pending_tx_remaining_secs() {
+        let (text, style) = if remaining == 0 {
+            (
+                "Expired - rebuild to re-sign".to_string(),
+                Style::default().fg(pal.error).bold(),
+            )
+        } else if remaining < EXPIRY_WARNING_SECS {
+            (
+                format!("Expires in {}", format_countdown(remaining)),
+                Style::default().fg(pal.warning).bold(),
+            )
+        } else {
+            (
+                format!("Expires in {}", format_countdown(remaining)),
+                Style::default().fg(pal.muted),
+            )
+        };
+        footer_spans.push(Span::styled(text, style));
+        footer_spans.push(Span::raw("  "));
+    }
+    footer_spans.push(Span::raw(footer));
     frame.render_widget(
-        Paragraph::new(footer).alignment(Alignment::Center),
+        Paragraph::new(Line::from(footer_spans)).alignment(Alignment::Center),
         chunks[2],
     );
 }
@@ -2028,6 +3098,19 @@ fn render_qr_details(frame: &mut Frame, app: &App, area: Rect) {
             Style::default().fg(pal.fg_dim),
         )]));
         lines.push(Line::from(format!("  Nonce: {}", tx_info.nonce)));
+        if let Some(window) = app
+            .qr
+            .pending_unsigned
+            .as_ref()
+            .and_then(|pending| pending.payload.mortality_window())
+        {
+            lines.push(Line::from(format!(
+                "  Mortality: {} blocks from #{} (expires at #{})",
+                window.period,
+                window.birth_block,
+                window.expiry_block()
+            )));
+        }
         lines.push(Line::from(format!(
             "  Spec Version: {}",
             tx_info.spec_version
@@ -2173,8 +3256,13 @@ fn render_scan_camera(frame: &mut Frame, app: &App, area: Rect) {
 
     // Header
     lines.push(Line::from(""));
+    let title = if app.camera.account_scan {
+        "Scan Account QR from Vault"
+    } else {
+        "Scan Signed Transaction from Vault"
+    };
     lines.push(Line::from(Span::styled(
-        "Scan Signed Transaction from Vault",
+        title,
         Style::default().fg(pal.primary).bold(),
     )));
     lines.push(Line::from(""));
@@ -2371,10 +3459,41 @@ fn render_submit_tab(frame: &mut Frame, app: &App, area: Rect) {
                 ),
                 Style::default().fg(pal.warning),
             ),
-            TxSubmissionStatus::Finalized { block_hash } => (
+            TxSubmissionStatus::InBlock {
+                block_hash,
+                outcome: Some(outcome),
+            } if !outcome.is_success() => (
+                format!(
+                    "✗ In block 0x{}... but dispatch failed",
+                    hex::encode(&block_hash[..4])
+                ),
+                Style::default().fg(pal.error).bold(),
+            ),
+            TxSubmissionStatus::InBlock { block_hash, .. } => (
+                format!(
+                    "⏳ In block 0x{}..., awaiting finality",
+                    hex::encode(&block_hash[..4])
+                ),
+                Style::default().fg(pal.warning),
+            ),
+            TxSubmissionStatus::Finalized {
+                block_hash,
+                outcome: Some(outcome),
+            } if !outcome.is_success() => (
+                format!(
+                    "✗ Finalized in 0x{}... but dispatch failed",
+                    hex::encode(&block_hash[..4])
+                ),
+                Style::default().fg(pal.error).bold(),
+            ),
+            TxSubmissionStatus::Finalized { block_hash, .. } => (
                 format!("✓ Finalized in 0x{}...", hex::encode(&block_hash[..4])),
                 Style::default().fg(pal.success).bold(),
             ),
+            TxSubmissionStatus::Expired => (
+                "✗ Transaction expired before submission".to_string(),
+                Style::default().fg(pal.error).bold(),
+            ),
             TxSubmissionStatus::Failed(err) => (
                 format!("✗ Failed: {}", truncate_str(err, 40)),
                 Style::default().fg(pal.error),
@@ -2382,6 +3501,37 @@ fn render_submit_tab(frame: &mut Frame, app: &App, area: Rect) {
         };
 
         lines.push(Line::from(Span::styled(status_text, status_style)));
+
+        // Show what the extrinsic actually did once it is included
+        if let TxSubmissionStatus::InBlock {
+            outcome: Some(outcome),
+            ..
+        }
+        | TxSubmissionStatus::Finalized {
+            outcome: Some(outcome),
+            ..
+        } = &tx.status
+        {
+            if let Some(failure) = &outcome.failure {
+                lines.push(Line::from(Span::styled(
+                    truncate_str(&failure.to_string(), 70),
+                    Style::default().fg(pal.error),
+                )));
+            }
+            let decimals = app.network.token_decimals();
+            for event in outcome.reported_events() {
+                let text = match event.amount {
+                    Some(amount) => format!(
+                        "{} {} {}",
+                        event.name(),
+                        format_balance(amount, decimals),
+                        app.network.token_symbol()
+                    ),
+                    None => event.name(),
+                };
+                lines.push(Line::from(Span::styled(text, Style::default().fg(pal.fg))));
+            }
+        }
         lines.push(Line::from(""));
 
         // Show action hint based on status
@@ -2393,6 +3543,16 @@ fn render_submit_tab(frame: &mut Frame, app: &App, area: Rect) {
                     Style::default().fg(pal.highlight).bold(),
                 )));
             }
+            TxSubmissionStatus::Finalized {
+                outcome: Some(outcome),
+                ..
+            } if !outcome.is_success() => {
+                lines.push(Line::from(""));
+                lines.push(Line::from(Span::styled(
+                    "Press Esc to close and fix the error above.",
+                    Style::default().fg(pal.muted),
+                )));
+            }
             TxSubmissionStatus::Finalized { .. } => {
                 lines.push(Line::from(""));
                 lines.push(Line::from(Span::styled(
@@ -2400,6 +3560,13 @@ fn render_submit_tab(frame: &mut Frame, app: &App, area: Rect) {
                     Style::default().fg(pal.success),
                 )));
             }
+            TxSubmissionStatus::Expired => {
+                lines.push(Line::from(""));
+                lines.push(Line::from(Span::styled(
+                    "Press 'r' to rebuild with a fresh nonce and block, then scan and sign again.",
+                    Style::default().fg(pal.highlight).bold(),
+                )));
+            }
             TxSubmissionStatus::Failed(_) => {
                 lines.push(Line::from(""));
                 lines.push(Line::from(Span::styled(
@@ -2464,7 +3631,7 @@ fn calculate_chunk_size(max_qr_height: usize, max_qr_width: usize) -> usize {
 /// - Data is raw binary (not hex-encoded)
 #[allow(clippy::too_many_arguments)]
 fn render_multipart_qr(
-    lines: &mut Vec<Line<'static>>,
+    lines: &mut Vec<Line<'_>>,
     qr_width: &mut u16,
     data: &[u8],
     max_qr_height: usize,
@@ -2485,45 +3652,16 @@ fn render_multipart_qr(
     let raw_chunk_size = target_qr_bytes.saturating_sub(5);
     let raw_chunk_size = raw_chunk_size.max(50); // Minimum 50 bytes per chunk
 
-    // Calculate number of parts needed
-    let total_parts = raw_bytes.len().div_ceil(raw_chunk_size);
-    let total_parts = total_parts.clamp(1, 65535) as u16; // Max 2 bytes
-
-    // Get current frame (cycle through parts)
+    // Build this frame with the shared UOS multipart header:
+    // [0x00][total_frames:2 BE][frame_index:2 BE][frame_data]
+    let total_parts = qr_export::uos_multipart_frame_count(raw_bytes.len(), raw_chunk_size);
     let frame_idx = (current_frame % (total_parts as usize)) as u16;
+    let frame_bytes = qr_export::uos_multipart_frame(raw_bytes, raw_chunk_size, current_frame);
 
-    // Extract chunk for this frame
-    let start = (frame_idx as usize) * raw_chunk_size;
-    let end = (start + raw_chunk_size).min(raw_bytes.len());
-    let chunk = &raw_bytes[start..end];
-
-    // Build frame with UOS multipart header:
-    // [0x00][total_frames:2 BE][frame_index:2 BE][frame_data]
-    let mut frame_bytes = Vec::with_capacity(5 + chunk.len());
-    frame_bytes.push(0x00); // Multipart marker
-    frame_bytes.extend_from_slice(&total_parts.to_be_bytes());
-    frame_bytes.extend_from_slice(&frame_idx.to_be_bytes());
-    frame_bytes.extend_from_slice(chunk);
-
-    // Determine QR version based on target chunk size to ensure consistent dimensions
-    // across all frames, even if the last frame has less data.
-    // Version capacity (EcLevel::L): v6=106, v7=122, v8=152, v9=180, v10=213, v11=251, v12=287
-    let full_frame_size = 5 + raw_chunk_size;
-    let qr_version = if full_frame_size <= 106 {
-        6
-    } else if full_frame_size <= 122 {
-        7
-    } else if full_frame_size <= 152 {
-        8
-    } else if full_frame_size <= 180 {
-        9
-    } else if full_frame_size <= 213 {
-        10
-    } else if full_frame_size <= 251 {
-        11
-    } else {
-        12
-    };
+    // Fixed QR version from the full chunk size keeps frame dimensions consistent
+    // even if the last frame has less data.
+    let qr_version =
+        qr_export::multipart_qr_version(qr_export::UOS_MULTIPART_HEADER_LEN + raw_chunk_size);
 
     // Copy colors to avoid borrow issues with closures
     let primary = pal.primary;
@@ -2675,7 +3813,7 @@ fn render_help_modal(frame: &mut Frame, app: &App) {
 
     // Calculate centered modal area
     let modal_width = 55.min(area.width.saturating_sub(4));
-    let modal_height = 48.min(area.height.saturating_sub(4));
+    let modal_height = 58.min(area.height.saturating_sub(4));
     let modal_x = (area.width.saturating_sub(modal_width)) / 2;
     let modal_y = (area.height.saturating_sub(modal_height)) / 2;
     let modal_area = Rect::new(modal_x, modal_y, modal_width, modal_height);
@@ -2705,7 +3843,7 @@ fn render_help_modal(frame: &mut Frame, app: &App) {
             Span::styled("Previous tab", desc_style),
         ]),
         Line::from(vec![
-            Span::styled("  1-6       ", key_style),
+            Span::styled("  1-8       ", key_style),
             Span::styled("Jump to tab", desc_style),
         ]),
         Line::from(vec![
@@ -2729,6 +3867,10 @@ fn render_help_modal(frame: &mut Frame, app: &App) {
             Span::styled("  a         ", key_style),
             Span::styled("Enter account address", desc_style),
         ]),
+        Line::from(vec![
+            Span::styled("  v         ", key_style),
+            Span::styled("Scan Vault account QR", desc_style),
+        ]),
         Line::from(vec![
             Span::styled("  c         ", key_style),
             Span::styled("Clear account", desc_style),
@@ -2770,6 +3912,14 @@ fn render_help_modal(frame: &mut Frame, app: &App) {
             Span::styled("  x         ", key_style),
             Span::styled("Chill", desc_style),
         ]),
+        Line::from(vec![
+            Span::styled("  g         ", key_style),
+            Span::styled("Rebag in voter list", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  f         ", key_style),
+            Span::styled("Put in front of lighter voter", desc_style),
+        ]),
         Line::from(""),
         Line::from(Span::styled(
             "  Account History Tab",
@@ -2783,6 +3933,44 @@ fn render_help_modal(frame: &mut Frame, app: &App) {
             Span::styled("  c         ", key_style),
             Span::styled("Cancel loading", desc_style),
         ]),
+        Line::from(vec![
+            Span::styled("  p         ", key_style),
+            Span::styled("Check payouts", desc_style),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            "  Transactions Tab",
+            Style::default().fg(pal.primary).bold(),
+        )),
+        Line::from(vec![
+            Span::styled("  f         ", key_style),
+            Span::styled("Cycle status filter", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  /         ", key_style),
+            Span::styled("Search operations", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  e         ", key_style),
+            Span::styled("Export filtered list", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  r         ", key_style),
+            Span::styled("Reload", desc_style),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            "  Portfolio Tab",
+            Style::default().fg(pal.primary).bold(),
+        )),
+        Line::from(vec![
+            Span::styled("  Enter     ", key_style),
+            Span::styled("Open account", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  r         ", key_style),
+            Span::styled("Reload", desc_style),
+        ]),
         Line::from(""),
         Line::from(Span::styled(
             "  Nominate Tab",
@@ -2808,6 +3996,10 @@ fn render_help_modal(frame: &mut Frame, app: &App) {
             Span::styled("  g         ", key_style),
             Span::styled("Generate QR code", desc_style),
         ]),
+        Line::from(vec![
+            Span::styled("  x         ", key_style),
+            Span::styled("Skip validators with offence reports", desc_style),
+        ]),
         Line::from(""),
         Line::from(Span::styled(
             "  Validators / Pools",
@@ -2854,6 +4046,14 @@ fn render_help_modal(frame: &mut Frame, app: &App) {
             Span::styled("  W         ", key_style),
             Span::styled("Withdraw unbonded pool", desc_style),
         ]),
+        Line::from(vec![
+            Span::styled("  P         ", key_style),
+            Span::styled("Set pool claim permission", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  A         ", key_style),
+            Span::styled("Administer pools you hold a role in", desc_style),
+        ]),
         Line::from(""),
         Line::from(Span::styled(
             "  Log Viewer",
@@ -3157,14 +4357,150 @@ fn render_strategy_menu(frame: &mut Frame, app: &App) {
     frame.render_widget(paragraph, modal_area);
 }
 
+/// Pool and admin call choice of the pool admin modal.
+fn render_pool_admin_menu<'a>(app: &App, lines: &mut Vec<Line<'a>>) {
+    let pal = &app.palette;
+    let admin = &app.pool_admin;
+    if let Some(pool) = admin.pool() {
+        let roles: Vec<&str> = pool.roles.iter().map(|r| r.label()).collect();
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!(
+                    "Pool #{} {}",
+                    pool.pool_id,
+                    pool.name.as_deref().unwrap_or("")
+                ),
+                Style::default().bold(),
+            ),
+            Span::styled(
+                format!("  ({})", roles.join(", ")),
+                Style::default().fg(pal.fg_dim),
+            ),
+        ]));
+        let commission = &pool.commission;
+        lines.push(Line::from(Span::styled(
+            format!(
+                "Commission {} (max {})  Unclaimed {} {}",
+                commission
+                    .current
+                    .map_or("none".to_string(), format_perbill),
+                commission.max.map_or("none".to_string(), format_perbill),
+                format_balance(commission.pending, app.network.token_decimals()),
+                app.network.token_symbol()
+            ),
+            Style::default().fg(pal.fg_dim),
+        )));
+        lines.push(Line::from(""));
+    }
+
+    for (i, action) in admin.actions().into_iter().enumerate() {
+        let is_selected = i == admin.selected_action;
+        let marker = if is_selected { "▶ " } else { "  " };
+        let style = if is_selected {
+            Style::default().fg(pal.success).bold()
+        } else {
+            Style::default()
+        };
+        lines.push(Line::from(Span::styled(
+            format!("{}{}", marker, action.label()),
+            style,
+        )));
+    }
+
+    lines.push(Line::from(""));
+    let hint = if admin.pools.len() > 1 {
+        "Up/Down:Call  Left/Right:Pool  Enter:Select  Esc:Cancel"
+    } else {
+        "Up/Down:Call  Enter:Select  Esc:Cancel"
+    };
+    lines.push(Line::from(Span::styled(
+        hint,
+        Style::default().fg(pal.muted),
+    )));
+}
+
+/// Argument entry of the pool admin modal.
+fn render_pool_admin_input<'a>(app: &App, lines: &mut Vec<Line<'a>>) {
+    let pal = &app.palette;
+    let admin = &app.pool_admin;
+    let Some(action) = admin.action() else {
+        return;
+    };
+    let title = match (action, admin.pool()) {
+        (PoolAdminAction::Create, _) | (_, None) => action.label().to_string(),
+        (_, Some(pool)) => format!("Pool #{}: {}", pool.pool_id, action.label()),
+    };
+    lines.push(Line::from(Span::styled(title, Style::default().bold())));
+    lines.push(Line::from(""));
+
+    if action == PoolAdminAction::SetState {
+        for (i, state) in POOL_STATES.iter().enumerate() {
+            let is_selected = i == admin.state_index;
+            let checkbox = if is_selected { "[x]" } else { "[ ]" };
+            let style = if is_selected {
+                Style::default().fg(pal.success).bold()
+            } else {
+                Style::default()
+            };
+            lines.push(Line::from(vec![
+                Span::styled(checkbox, style),
+                Span::raw(format!(" {:?}", state)),
+            ]));
+        }
+    } else {
+        let prompt = match action {
+            PoolAdminAction::SetMetadata => "Enter the pool name".to_string(),
+            PoolAdminAction::SetCommission => "Enter commission % (empty removes it)".to_string(),
+            PoolAdminAction::SetCommissionMax => "Enter max commission %".to_string(),
+            PoolAdminAction::SetCommissionChangeRate => {
+                "Enter max increase % and min delay blocks".to_string()
+            }
+            PoolAdminAction::UpdateRoles => {
+                "Enter root nominator bouncer (- keeps, none removes)".to_string()
+            }
+            PoolAdminAction::Create => format!(
+                "Enter bond ({}) [root nominator bouncer] (- is you)",
+                app.network.token_symbol()
+            ),
+            _ => String::new(),
+        };
+        lines.push(Line::from(prompt));
+        lines.push(Line::from(vec![
+            Span::styled("> ", Style::default().fg(pal.accent)),
+            Span::styled(
+                format!("{} _", admin.input),
+                Style::default().fg(pal.highlight).bold(),
+            ),
+        ]));
+    }
+
+    lines.push(Line::from(match &admin.error {
+        Some(error) => Span::styled(error.clone(), Style::default().fg(pal.error)),
+        None => Span::raw(""),
+    }));
+    let hint = if action == PoolAdminAction::SetState {
+        "arrows:Cycle  Enter:Confirm  Esc:Back"
+    } else {
+        "Enter:Confirm  Esc:Back"
+    };
+    lines.push(Line::from(Span::styled(
+        hint,
+        Style::default().fg(pal.muted),
+    )));
+}
+
 /// Render modal for staking operations.
 fn render_staking_modal(frame: &mut Frame, app: &App) {
     let pal = &app.palette;
     let area = frame.area();
 
-    // Center the modal
+    // Center the modal; the pool admin menu lists up to nine calls
     let modal_width = 60;
-    let modal_height = 12;
+    let modal_height = if app.staking_input_mode == StakingInputMode::PoolAdmin {
+        19
+    } else {
+        14
+    };
     let x = (area.width.saturating_sub(modal_width)) / 2;
     let y = (area.height.saturating_sub(modal_height)) / 2;
     let modal_area = Rect::new(x, y, modal_width, modal_height);
@@ -3212,6 +4548,15 @@ fn render_staking_modal(frame: &mut Frame, app: &App) {
             app.pool_input_amount.clone(),
         ),
         StakingInputMode::SetPayee => (" Set Rewards Destination ", String::new(), String::new()),
+        StakingInputMode::SetClaimPermission => {
+            (" Set Pool Claim Permission ", String::new(), String::new())
+        }
+        StakingInputMode::PoolAdmin | StakingInputMode::PoolAdminInput => {
+            (" Pool Administration ", String::new(), String::new())
+        }
+        StakingInputMode::PoolMemberCall => {
+            (" Another Member's Rewards ", String::new(), String::new())
+        }
         _ => return,
     };
 
@@ -3256,6 +4601,67 @@ fn render_staking_modal(frame: &mut Frame, app: &App) {
                 Style::default().fg(pal.muted),
             )));
         }
+        StakingInputMode::SetClaimPermission => {
+            lines.push(Line::from("Who may claim or compound your rewards:"));
+            lines.push(Line::from(""));
+
+            for permission in ClaimPermission::ALL {
+                let is_selected = app.claim_permission == permission;
+                let checkbox = if is_selected { "[x]" } else { "[ ]" };
+                let style = if is_selected {
+                    Style::default().fg(pal.success).bold()
+                } else {
+                    Style::default()
+                };
+                lines.push(Line::from(vec![
+                    Span::styled(checkbox, style),
+                    Span::raw(format!(" {}", permission.label())),
+                ]));
+            }
+
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                "P/arrows:Cycle  Enter:Confirm  Esc:Cancel",
+                Style::default().fg(pal.muted),
+            )));
+        }
+        StakingInputMode::PoolAdmin => render_pool_admin_menu(app, &mut lines),
+        StakingInputMode::PoolAdminInput => render_pool_admin_input(app, &mut lines),
+        StakingInputMode::PoolMemberCall => {
+            let state = &app.pool_member_call;
+            lines.push(Line::from("Allowed by the member's claim permission:"));
+            lines.push(Line::from(""));
+            for call in PoolMemberCall::ALL {
+                let is_selected = state.call == call;
+                let checkbox = if is_selected { "[x]" } else { "[ ]" };
+                let style = if is_selected {
+                    Style::default().fg(pal.success).bold()
+                } else {
+                    Style::default()
+                };
+                lines.push(Line::from(vec![
+                    Span::styled(checkbox, style),
+                    Span::raw(format!(" {}", call.label())),
+                ]));
+            }
+            lines.push(Line::from(""));
+            lines.push(Line::from("Enter the member's address"));
+            lines.push(Line::from(vec![
+                Span::styled("> ", Style::default().fg(pal.accent)),
+                Span::styled(
+                    format!("{} _", state.input),
+                    Style::default().fg(pal.highlight).bold(),
+                ),
+            ]));
+            lines.push(Line::from(match &state.error {
+                Some(error) => Span::styled(error.clone(), Style::default().fg(pal.error)),
+                None => Span::raw(""),
+            }));
+            lines.push(Line::from(Span::styled(
+                "arrows:Cycle  Enter:Confirm  Esc:Cancel",
+                Style::default().fg(pal.muted),
+            )));
+        }
         _ => {
             // Amount input modes
             for line in content.split('\n') {
@@ -3269,6 +4675,20 @@ fn render_staking_modal(frame: &mut Frame, app: &App) {
                     Style::default().fg(pal.highlight).bold(),
                 ),
             ]));
+            match app.staking_input_issue() {
+                Some(issue) => {
+                    let color = if issue.is_error() {
+                        pal.error
+                    } else {
+                        pal.warning
+                    };
+                    lines.push(Line::from(Span::styled(
+                        issue.message(app.network.token_decimals(), symbol),
+                        Style::default().fg(color),
+                    )));
+                }
+                None => lines.push(Line::from("")),
+            }
             lines.push(Line::from(""));
             lines.push(Line::from(Span::styled(
                 "Enter:Confirm  Esc:Cancel",
@@ -3279,7 +4699,8 @@ fn render_staking_modal(frame: &mut Frame, app: &App) {
 
     let paragraph = Paragraph::new(lines)
         .block(block)
-        .alignment(Alignment::Center);
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
 
     frame.render_widget(paragraph, modal_area);
 }
@@ -3348,6 +4769,14 @@ mod tests {
         assert_eq!(truncate_address("", 4, 4), "");
     }
 
+    // ─── format_reward_difference ───
+
+    #[test]
+    fn test_format_reward_difference_signs() {
+        assert_eq!(format_reward_difference(12_345_000, 10), "+0.0012");
+        assert_eq!(format_reward_difference(-5_000_000_000, 10), "-0.5000");
+    }
+
     // ─── format_balance ───
 
     #[test]